@@ -34,6 +34,9 @@ object PrettyPrinter extends ByteStringPrettyPrinter {
         case Some(v) => s"Write(${buildString(v)})"
       }
     case Transform.TransformInstance.AddU64(TransformAddUInt64(x)) => s"AddU64($x)"
+    case Transform.TransformInstance.Delete(_)                     => "Delete"
+    case Transform.TransformInstance.ListAppend(TransformListAppend(items)) =>
+      s"ListAppend(${items.size} items)"
   }
 
   def buildString(v: Option[ProtocolVersion]): String = v match {
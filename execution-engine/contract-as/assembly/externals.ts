@@ -59,6 +59,9 @@ export declare function put_key(name_ptr: usize, name_size: usize, key_ptr: usiz
 @external("env", "remove_key")
 export declare function remove_key(name_ptr: usize, name_size: u32): void;
 /** @hidden */
+@external("env", "remove_key_checked")
+export declare function remove_key_checked(name_ptr: usize, name_size: u32): i32;
+/** @hidden */
 @external("env", "revert")
 export declare function revert(err_code: i32): void;
 /** @hidden */
@@ -128,6 +131,16 @@ export declare function upgrade_contract_at_uref(
     key_size: u32
 ): i32;
 /** @hidden */
+@external("env", "upgrade_contract_at_uref_with_named_keys")
+export declare function upgrade_contract_at_uref_with_named_keys(
+    name_ptr: usize,
+    name_size: u32,
+    key_ptr: usize,
+    key_size: u32,
+    named_keys_ptr: usize,
+    named_keys_size: u32
+): i32;
+/** @hidden */
 @external("env", "get_system_contract")
 export declare function get_system_contract(system_contract_index: u32, dest_ptr: usize, dest_size: u32): i32;
 /** @hidden */
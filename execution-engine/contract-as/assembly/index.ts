@@ -366,6 +366,17 @@ export function removeKey(name: String): void{
   externals.remove_key(nameBytes.dataStart, nameBytes.length);
 }
 
+/**
+ * Removes the [[Key]] stored under `name` as per [[removeKey]].
+ *
+ * @returns `true` if there was a key stored under `name`.
+ */
+export function removeKeyChecked(name: String): bool {
+  var nameBytes = toBytesString(name);
+  let ret = externals.remove_key_checked(nameBytes.dataStart, nameBytes.length);
+  return ret == 0;
+}
+
 /**
  * Returns the named keys of the current context.
  *
@@ -426,3 +437,31 @@ export function upgradeContractAtURef(name: String, uref: URef): void {
   if(error !== null)
     error.revert();
 }
+
+/**
+ * Upgrades the contract under `uref` as per [[upgradeContractAtURef]], adding the given named keys
+ * to the original contract's named_keys.
+ *
+ * @param name Name of the exported function
+ * @param uref URef under which the contract to upgrade is stored
+ * @param namedKeysBytes Serialized bytes of named keys to add to the contract's named keys. Use
+ * {@link toBytesMap} to serialize pairs.
+ */
+export function upgradeContractAtURefWithNamedKeys(name: String, uref: URef, namedKeysBytes: u8[]): void {
+  const nameBytes = toBytesString(name);
+  const key = Key.fromURef(uref);
+  const keyBytes = key.toBytes();
+  let ret = externals.upgrade_contract_at_uref_with_named_keys(
+      nameBytes.dataStart,
+      nameBytes.length,
+      keyBytes.dataStart,
+      keyBytes.length,
+      <usize>namedKeysBytes.dataStart,
+      namedKeysBytes.length
+  );
+  if (ret < 1)
+    return;
+  const error = Error.fromResult(ret);
+  if(error !== null)
+    error.revert();
+}
@@ -1,25 +1,57 @@
-//! Home of [`ArgsParser`], a trait used for parsing contract arguments from n-ary tuples.
+//! Home of [`ArgsParser`], a trait used for parsing contract arguments from n-ary tuples and
+//! [`RuntimeArgs`].
 
 // Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
 #[rustfmt::skip]
 use alloc::vec;
 use alloc::vec::Vec;
 
-use casperlabs_types::{bytesrepr::ToBytes, CLTyped, CLValue, CLValueError};
+use casperlabs_types::{bytesrepr::ToBytes, CLTyped, CLValue, CLValueError, RuntimeArgs};
 
 /// Types which implement [`ArgsParser`] can be parsed into an ABI-compliant byte representation
 /// suitable for passing as arguments to a contract.
 ///
 /// It is primarily implemented for n-ary tuples of values which themselves implement [`ToBytes`]
-/// and [`CLTyped`].
+/// and [`CLTyped`], which are parsed to positional arguments, and for [`RuntimeArgs`], which can
+/// hold named arguments as built by [`runtime_args!`](casperlabs_types::runtime_args).
 pub trait ArgsParser {
-    /// Parses the arguments to a `Vec` of [`CLValue`]s.
-    fn parse(self) -> Result<Vec<CLValue>, CLValueError>;
+    /// Parses the arguments to [`RuntimeArgs`].
+    fn parse(self) -> Result<RuntimeArgs, CLValueError>;
+}
+
+/// Builds the arguments of a contract call from a comma-separated list of values of any types
+/// implementing [`ToBytes`] and [`CLTyped`].
+///
+/// The result is a tuple implementing [`ArgsParser`], so unlike writing the tuple out by hand, a
+/// single argument doesn't need a trailing comma.
+///
+/// ```rust,no_run
+/// # use casperlabs_contract::{args, contract_api::runtime};
+/// # use casperlabs_types::{ContractRef, U512};
+/// # let contract_ref = ContractRef::Hash([1; 32]);
+/// let amount = U512::from(100);
+/// let balance: U512 = runtime::call_contract(contract_ref.clone(), args!(amount));
+/// let _: () = runtime::call_contract(contract_ref, args!("transfer", amount, 1u64));
+/// ```
+#[macro_export]
+macro_rules! args {
+    () => {
+        ()
+    };
+    ($($arg:expr),+ $(,)?) => {
+        ($($arg,)+)
+    };
 }
 
 impl ArgsParser for () {
-    fn parse(self) -> Result<Vec<CLValue>, CLValueError> {
-        Ok(Vec::new())
+    fn parse(self) -> Result<RuntimeArgs, CLValueError> {
+        Ok(RuntimeArgs::new())
+    }
+}
+
+impl ArgsParser for RuntimeArgs {
+    fn parse(self) -> Result<RuntimeArgs, CLValueError> {
+        Ok(self)
     }
 }
 
@@ -27,9 +59,10 @@ macro_rules! impl_argsparser_tuple {
     ( $($name:ident)+) => (
         impl<$($name: CLTyped + ToBytes),*> ArgsParser for ($($name,)*) {
             #[allow(non_snake_case)]
-            fn parse(self) -> Result<Vec<CLValue>, CLValueError> {
+            fn parse(self) -> Result<RuntimeArgs, CLValueError> {
                 let ($($name,)+) = self;
-                Ok(vec![$(CLValue::from_t($name)?,)+])
+                let values: Vec<CLValue> = vec![$(CLValue::from_t($name)?,)+];
+                Ok(RuntimeArgs::from(values))
             }
         }
     );
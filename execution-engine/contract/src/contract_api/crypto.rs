@@ -0,0 +1,27 @@
+//! Functions for hashing data on the host, which costs far less gas than hashing it in Wasm.
+
+use casperlabs_types::BLAKE2B_DIGEST_LENGTH;
+
+use crate::ext_ffi;
+
+/// The length in bytes of a Keccak-256 digest.
+pub const KECCAK256_DIGEST_LENGTH: usize = 32;
+
+/// Returns the 32-byte BLAKE2b digest of `data`.
+pub fn blake2b(data: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let mut digest = [0u8; BLAKE2B_DIGEST_LENGTH];
+    unsafe {
+        ext_ffi::blake2b256(data.as_ptr(), data.len(), digest.as_mut_ptr());
+    }
+    digest
+}
+
+/// Returns the Keccak-256 digest of `data`, i.e. the hash used by Ethereum rather than the
+/// standardized SHA3-256.
+pub fn keccak256(data: &[u8]) -> [u8; KECCAK256_DIGEST_LENGTH] {
+    let mut digest = [0u8; KECCAK256_DIGEST_LENGTH];
+    unsafe {
+        ext_ffi::keccak256(data.as_ptr(), data.len(), digest.as_mut_ptr());
+    }
+    digest
+}
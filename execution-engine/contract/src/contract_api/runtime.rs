@@ -4,23 +4,30 @@
 #[rustfmt::skip]
 use alloc::vec;
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
-use core::mem::MaybeUninit;
+use core::{convert::TryFrom, mem::MaybeUninit};
 
 use casperlabs_types::{
     account::PublicKey,
     api_error,
-    bytesrepr::{self, FromBytes},
-    ApiError, BlockTime, CLTyped, CLValue, ContractRef, Key, Phase, URef,
-    BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    bytesrepr::{self, FromBytes, U64_SERIALIZED_LENGTH},
+    key_versions_name, versioned_key_name, ApiError, BlockTime, CLType, CLTyped, CLValue,
+    CLValueEnvelope, CLValueError, CallStackElement, ContractRef, ContractVersion, EngineFeature,
+    Key, Phase, ProtocolInfo, URef, UpdateKeyError, WasmCosts, BLOCKTIME_SERIALIZED_LENGTH,
+    PAYMENT_PURSE_ARG, PHASE_SERIALIZED_LENGTH, U512,
 };
 
-use crate::{args_parser::ArgsParser, contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
+use crate::{
+    args_parser::ArgsParser,
+    contract_api::{self, storage},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
 
 /// Returns the given [`CLValue`] to the host, terminating the currently running module.
 ///
-/// Note this function is only relevant to contracts stored on chain which are invoked via
-/// [`call_contract`] and can thus return a value to their caller.  The return value of a directly
-/// deployed contract is never used.
+/// A contract invoked via [`call_contract`] returns the value to its caller.  Session code returns
+/// it to the deploy result instead, where it is available without querying global state, while the
+/// value returned by payment code is ignored.
 pub fn ret(value: CLValue) -> ! {
     let (ptr, size, _bytes) = contract_api::to_ptr(value);
     unsafe {
@@ -33,53 +40,174 @@ pub fn ret(value: CLValue) -> ! {
 /// The provided `ApiError` is returned in the form of a numeric exit code to the caller via the
 /// deploy response.
 pub fn revert<T: Into<ApiError>>(error: T) -> ! {
+    revert_with_payload(error, &[])
+}
+
+/// Stops execution of a contract and reverts execution effects with a given [`ApiError`] and
+/// `payload`, e.g. the name of the argument or key which caused the error.
+///
+/// As well as the numeric exit code, the caller receives `payload` via the deploy response.  Only
+/// the first [`MAX_REVERT_PAYLOAD_LENGTH`](casperlabs_types::MAX_REVERT_PAYLOAD_LENGTH) bytes of
+/// `payload` are kept.
+pub fn revert_with_payload<T: Into<ApiError>>(error: T, payload: &[u8]) -> ! {
     unsafe {
-        ext_ffi::revert(error.into().into());
+        ext_ffi::revert_with_payload(error.into().into(), payload.as_ptr(), payload.len());
     }
 }
 
+/// The size of the buffer into which [`call_contract`] has the host write the result of the call.
+const CALL_CONTRACT_BUFFER_SIZE: usize = 1024;
+
 /// Calls the given stored contract, passing the given arguments to it.
 ///
 /// If the stored contract calls [`ret`], then that value is returned from `call_contract`.  If the
 /// stored contract calls [`revert`], then execution stops and `call_contract` doesn't return.
 /// Otherwise `call_contract` returns `()`.
-#[allow(clippy::ptr_arg)]
 pub fn call_contract<A: ArgsParser, T: CLTyped + FromBytes>(c_ptr: ContractRef, args: A) -> T {
+    call_contract_with_buffer_size(c_ptr, args, CALL_CONTRACT_BUFFER_SIZE)
+}
+
+/// Calls the given stored contract as per [`call_contract`], providing a buffer of `buffer_size`
+/// bytes for the result.
+///
+/// A result which fits in the buffer is written there directly by the host.  A larger one is read
+/// from the host buffer in a second call, so contracts expecting large results can avoid that by
+/// providing a large enough buffer.
+pub fn call_contract_with_buffer_size<A: ArgsParser, T: CLTyped + FromBytes>(
+    c_ptr: ContractRef,
+    args: A,
+    buffer_size: usize,
+) -> T {
     let contract_key: Key = c_ptr.into();
     let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
     let (args_ptr, args_size, _bytes2) = ArgsParser::parse(args)
         .map(contract_api::to_ptr)
         .unwrap_or_revert();
 
-    let bytes_written = {
-        let mut bytes_written = MaybeUninit::uninit();
+    let mut serialized_result: Vec<u8> = Vec::with_capacity(buffer_size);
+    let result_size = {
+        let mut result_size = MaybeUninit::uninit();
         let ret = unsafe {
-            ext_ffi::call_contract(
+            ext_ffi::call_contract_into(
                 key_ptr,
                 key_size,
                 args_ptr,
                 args_size,
-                bytes_written.as_mut_ptr(),
+                serialized_result.as_mut_ptr(),
+                serialized_result.capacity(),
+                result_size.as_mut_ptr(),
             )
         };
         api_error::result_from(ret).unwrap_or_revert();
-        unsafe { bytes_written.assume_init() }
+        unsafe { result_size.assume_init() }
     };
 
-    let serialized_result = if bytes_written == 0 {
-        // If no bytes were written, the host buffer hasn't been set and hence shouldn't be read.
-        vec![]
-    } else {
-        // NOTE: this is a copy of the contents of `read_host_buffer()`.  Calling that directly from
-        // here causes several contracts to fail with a Wasmi `Unreachable` error.
-        let bytes_non_null_ptr = contract_api::alloc_bytes(bytes_written);
-        let mut dest: Vec<u8> = unsafe {
-            Vec::from_raw_parts(bytes_non_null_ptr.as_ptr(), bytes_written, bytes_written)
+    deserialize_call_result(serialized_result, result_size)
+}
+
+/// Calls a version of the contract package under `package`, passing the given arguments to it as
+/// per [`call_contract`].
+///
+/// The version called is `maybe_version`, or the highest enabled version of the package if that's
+/// `None`.  The call fails with [`ApiError::ContractVersionDisabled`] if the given version has
+/// been disabled, rather than calling another version instead.
+pub fn call_versioned_contract<A: ArgsParser, T: CLTyped + FromBytes>(
+    package: Key,
+    maybe_version: Option<ContractVersion>,
+    args: A,
+) -> T {
+    let (package_ptr, package_size, _bytes1) = contract_api::to_ptr(package);
+    let (version_ptr, version_size, _bytes2) = contract_api::to_ptr(maybe_version);
+    let (args_ptr, args_size, _bytes3) = ArgsParser::parse(args)
+        .map(contract_api::to_ptr)
+        .unwrap_or_revert();
+
+    let mut serialized_result: Vec<u8> = Vec::with_capacity(CALL_CONTRACT_BUFFER_SIZE);
+    let result_size = {
+        let mut result_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::call_versioned_contract(
+                package_ptr,
+                package_size,
+                version_ptr,
+                version_size,
+                args_ptr,
+                args_size,
+                serialized_result.as_mut_ptr(),
+                serialized_result.capacity(),
+                result_size.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { result_size.assume_init() }
+    };
+
+    deserialize_call_result(serialized_result, result_size)
+}
+
+/// Calls the given stored contract as per [`call_contract`], allowing it to spend up to `limit`
+/// motes from `purse`.
+///
+/// The host moves `limit` motes from `purse` into a new temporary purse, which the callee obtains
+/// via [`get_payment_purse`].  Once the callee returns, whatever it hasn't spent is moved back to
+/// `purse` and the temporary purse is deleted, so it can't be used after the call even if the
+/// callee kept a copy of it.  The call fails with [`ApiError::Transfer`] if `purse` holds less
+/// than `limit`.
+pub fn call_contract_with_payment<A: ArgsParser, T: CLTyped + FromBytes>(
+    c_ptr: ContractRef,
+    args: A,
+    purse: URef,
+    limit: U512,
+) -> T {
+    let contract_key: Key = c_ptr.into();
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(contract_key);
+    let (args_ptr, args_size, _bytes2) = ArgsParser::parse(args)
+        .map(contract_api::to_ptr)
+        .unwrap_or_revert();
+    let (payment_ptr, payment_size, _bytes3) = contract_api::to_ptr((purse, limit));
+
+    let mut serialized_result: Vec<u8> = Vec::with_capacity(CALL_CONTRACT_BUFFER_SIZE);
+    let result_size = {
+        let mut result_size = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::call_contract_with_payment(
+                key_ptr,
+                key_size,
+                args_ptr,
+                args_size,
+                payment_ptr,
+                payment_size,
+                serialized_result.as_mut_ptr(),
+                serialized_result.capacity(),
+                result_size.as_mut_ptr(),
+            )
         };
-        read_host_buffer_into(&mut dest).unwrap_or_revert();
-        dest
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { result_size.assume_init() }
     };
 
+    deserialize_call_result(serialized_result, result_size)
+}
+
+/// Returns the temporary purse passed to the current contract by a caller using
+/// [`call_contract_with_payment`], or `None` if it was called without a payment.
+pub fn get_payment_purse() -> Option<URef> {
+    get_named_arg::<URef>(PAYMENT_PURSE_ARG)
+        .map(|purse| purse.unwrap_or_revert_with(ApiError::InvalidArgument))
+}
+
+/// Deserializes the `result_size` bytes of a contract's result, which the host has written to
+/// `serialized_result` if it had the capacity, or to the host buffer otherwise.
+fn deserialize_call_result<T: FromBytes>(mut serialized_result: Vec<u8>, result_size: usize) -> T {
+    if result_size <= serialized_result.capacity() {
+        // The host has written the result directly into the buffer.
+        unsafe { serialized_result.set_len(result_size) };
+    } else {
+        // The result didn't fit, so the host has left it in the host buffer instead.
+        serialized_result.resize(result_size, 0);
+        read_host_buffer_into(&mut serialized_result).unwrap_or_revert();
+    }
+
     bytesrepr::deserialize(serialized_result).unwrap_or_revert()
 }
 
@@ -101,7 +229,44 @@ pub fn upgrade_contract_at_uref(name: &str, uref: URef) {
     }
 }
 
-fn get_arg_size(i: u32) -> Option<usize> {
+/// Upgrades the contract under `uref` as per [`upgrade_contract_at_uref`], adding `named_keys` to
+/// the original contract's named_keys.  Entries of `named_keys` replace any of the original
+/// named_keys with the same name.
+///
+/// The identity of `uref` is unchanged, so existing [`ContractRef`]s to the contract call the
+/// upgraded version.
+pub fn upgrade_contract_at_uref_with_named_keys(
+    name: &str,
+    uref: URef,
+    named_keys: BTreeMap<String, Key>,
+) {
+    let (name_ptr, name_size, _bytes1) = contract_api::to_ptr(name);
+    let key: Key = uref.into();
+    let (key_ptr, key_size, _bytes2) = contract_api::to_ptr(key);
+    let (keys_ptr, keys_size, _bytes3) = contract_api::to_ptr(named_keys);
+    let result_value = unsafe {
+        ext_ffi::upgrade_contract_at_uref_with_named_keys(
+            name_ptr, name_size, key_ptr, key_size, keys_ptr, keys_size,
+        )
+    };
+    match api_error::result_from(result_value) {
+        Ok(()) => (),
+        Err(error) => revert(error),
+    }
+}
+
+/// Returns the number of arguments passed to the host for the current module invocation.
+pub fn get_args_len() -> usize {
+    let mut args_len: usize = 0;
+    unsafe { ext_ffi::get_args_len(&mut args_len as *mut usize) };
+    args_len
+}
+
+/// Returns the size in bytes of the serialized i-th argument passed to the host for the current
+/// module invocation, or `None` if there is no such argument.
+///
+/// Note that a present argument may have a size of 0, e.g. if it is `()`.
+pub fn get_arg_size(i: u32) -> Option<usize> {
     let mut arg_size: usize = 0;
     let ret = unsafe { ext_ffi::get_arg_size(i as usize, &mut arg_size as *mut usize) };
     match api_error::result_from(ret) {
@@ -134,6 +299,126 @@ pub fn get_arg<T: FromBytes>(i: u32) -> Option<Result<T, bytesrepr::Error>> {
     Some(bytesrepr::deserialize(arg_bytes))
 }
 
+/// Returns the i-th argument passed to the host for the current module invocation, reverting with
+/// `missing_error` if there is no such argument or with `invalid_error` if it can't be
+/// deserialized as a `T`.
+///
+/// ```rust,no_run
+/// # use casperlabs_contract::contract_api::runtime;
+/// # use casperlabs_types::{ApiError, U512};
+/// let amount: U512 =
+///     runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+/// ```
+pub fn get_arg_checked<T: FromBytes>(
+    i: u32,
+    missing_error: ApiError,
+    invalid_error: ApiError,
+) -> T {
+    get_arg(i)
+        .unwrap_or_revert_with(missing_error)
+        .unwrap_or_revert_with(invalid_error)
+}
+
+/// Returns the [`CLType`] of the i-th argument passed to the host for the current module
+/// invocation, or `None` if there is no such argument.
+///
+/// [`CLType::Any`] is returned for an argument passed without its type.
+pub fn get_arg_type(i: u32) -> Option<CLType> {
+    let envelope = get_arg_envelope(i)?;
+    Some(envelope.cl_type().cloned().unwrap_or(CLType::Any))
+}
+
+/// Returns the i-th argument passed to the host for the current module invocation, checking its
+/// type against `T` before deserializing it.
+///
+/// Returns `None` if there is no such argument, `Some(Err(CLValueError::Type(_)))` naming both the
+/// expected and the actual type if the argument is of a different type, and
+/// `Some(Err(CLValueError::Serialization(_)))` if it can't be deserialized as a `T`, e.g.
+/// [`bytesrepr::Error::LeftOverBytes`] holding the number of trailing bytes.
+///
+/// ```rust,no_run
+/// # use casperlabs_contract::contract_api::runtime;
+/// # use casperlabs_types::{ApiError, CLValueError, U512};
+/// let amount: U512 = match runtime::get_typed_arg(0) {
+///     Some(Ok(amount)) => amount,
+///     Some(Err(CLValueError::Type(_))) => runtime::revert(ApiError::InvalidArgument),
+///     Some(Err(CLValueError::Serialization(error))) => runtime::revert(error),
+///     None => runtime::revert(ApiError::MissingArgument),
+/// };
+/// ```
+pub fn get_typed_arg<T: CLTyped + FromBytes>(i: u32) -> Option<Result<T, CLValueError>> {
+    get_arg_envelope(i).map(CLValueEnvelope::into_t)
+}
+
+fn get_arg_envelope(i: u32) -> Option<CLValueEnvelope> {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_arg_envelope(i as usize, output_size.as_mut_ptr()) };
+        match api_error::result_from(ret) {
+            Ok(_) => unsafe { output_size.assume_init() },
+            Err(ApiError::MissingArgument) => return None,
+            Err(e) => revert(e),
+        }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    // The host writes the envelope as its serialized bytes.
+    let envelope_bytes: Vec<u8> = bytesrepr::deserialize(buf).unwrap_or_revert();
+    Some(bytesrepr::deserialize(envelope_bytes).unwrap_or_revert())
+}
+
+/// Returns the size in bytes of the serialized argument named `name` passed to the host for the
+/// current module invocation, or `None` if there is no such argument.
+pub fn get_named_arg_size(name: &str) -> Option<usize> {
+    let mut arg_size: usize = 0;
+    let ret = unsafe {
+        ext_ffi::get_named_arg_size(name.as_ptr(), name.len(), &mut arg_size as *mut usize)
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => Some(arg_size),
+        Err(ApiError::MissingArgument) => None,
+        Err(e) => revert(e),
+    }
+}
+
+/// Returns the argument named `name` passed to the host for the current module invocation.
+///
+/// Returns `None` if no argument of that name was passed, and `Some(Err(_))` if the argument can't
+/// be deserialized as a `T`.  Arguments passed by name can also be read by their position via
+/// [`get_arg`], in the order in which they were given.
+///
+/// ```rust,no_run
+/// # use casperlabs_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+/// # use casperlabs_types::{ApiError, U512};
+/// let amount: U512 = runtime::get_named_arg("amount")
+///     .unwrap_or_revert_with(ApiError::MissingArgument)
+///     .unwrap_or_revert_with(ApiError::InvalidArgument);
+/// ```
+pub fn get_named_arg<T: FromBytes>(name: &str) -> Option<Result<T, bytesrepr::Error>> {
+    let arg_size = get_named_arg_size(name)?;
+    let arg_bytes = if arg_size > 0 {
+        let res = {
+            let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+            let ret = unsafe {
+                ext_ffi::get_named_arg(
+                    name.as_ptr(),
+                    name.len(),
+                    data_non_null_ptr.as_ptr(),
+                    arg_size,
+                )
+            };
+            let data =
+                unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+            api_error::result_from(ret).map(|_| data)
+        };
+        // Assumed to be safe as `get_named_arg_size` checks the argument already
+        res.unwrap_or_revert()
+    } else {
+        // Avoids allocation with 0 bytes and a call to get_named_arg
+        Vec::new()
+    };
+    Some(bytesrepr::deserialize(arg_bytes))
+}
+
 /// Returns the caller of the current context, i.e. the [`PublicKey`] of the account which made the
 /// deploy request.
 pub fn get_caller() -> PublicKey {
@@ -147,6 +432,63 @@ pub fn get_caller() -> PublicKey {
     bytesrepr::deserialize(buf).unwrap_or_revert()
 }
 
+/// Returns the [`PublicKey`] of the account which made the deploy request.
+///
+/// Unlike an argument, this can't be chosen by the deployer.  It's the same in stored contracts
+/// called via [`call_contract`], in which case it's not the public key of the calling contract.
+pub fn get_account_public_key() -> PublicKey {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_account_public_key(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the call stack of the current context.
+///
+/// The first element is the account which made the deploy request, and the last is the entity
+/// currently executing.  Every stored contract called via [`call_contract`] in between appears in
+/// the order in which the calls were made.
+pub fn get_caller_chain() -> Vec<CallStackElement> {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_caller_chain(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the protocol version in effect and the name of the chain on which the contract is
+/// executing.
+pub fn get_protocol_data() -> ProtocolInfo {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_protocol_data(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the costs of executing Wasm in effect for the current protocol version, e.g. to estimate
+/// the gas charged for a known number of opcodes or bytes copied.
+pub fn get_wasm_costs() -> WasmCosts {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret = unsafe { ext_ffi::get_wasm_costs(output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
 /// Returns the current [`BlockTime`].
 pub fn get_blocktime() -> BlockTime {
     let dest_non_null_ptr = contract_api::alloc_bytes(BLOCKTIME_SERIALIZED_LENGTH);
@@ -161,6 +503,52 @@ pub fn get_blocktime() -> BlockTime {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the height of the current block.
+pub fn get_block_height() -> u64 {
+    let dest_non_null_ptr = contract_api::alloc_bytes(U64_SERIALIZED_LENGTH);
+    let bytes = unsafe {
+        ext_ffi::get_block_height(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(
+            dest_non_null_ptr.as_ptr(),
+            U64_SERIALIZED_LENGTH,
+            U64_SERIALIZED_LENGTH,
+        )
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Returns the hash of the deploy being executed.
+///
+/// It's unique to the deploy, so can be used to derive deploy-unique identifiers.  It's the same in
+/// stored contracts called via [`call_contract`].
+pub fn get_deploy_hash() -> [u8; 32] {
+    let mut deploy_hash = [0u8; 32];
+    unsafe { ext_ffi::get_deploy_hash(deploy_hash.as_mut_ptr()) };
+    deploy_hash
+}
+
+/// Returns 32 pseudo-random bytes, which differ on each call within a deploy and between stored
+/// contracts called by it.
+///
+/// The bytes are derived from the deploy hash, the current call depth and the number of previous
+/// calls made during the current [`Phase`], so re-executing a deploy yields the same bytes.
+///
+/// **This is not secure randomness.**  Anyone who knows the deploy hash can compute the bytes in
+/// advance, and the account sending the deploy can choose them by varying the deploy.  They must
+/// not be used where an outcome is worth manipulating, e.g. to pick the winner of a lottery.
+pub fn random_bytes() -> [u8; 32] {
+    let mut random_bytes = [0u8; 32];
+    unsafe { ext_ffi::random_bytes(random_bytes.as_mut_ptr()) };
+    random_bytes
+}
+
+/// Returns whether `feature` is turned on in the configuration of the engine executing the
+/// contract.
+pub fn is_feature_enabled(feature: EngineFeature) -> bool {
+    let ret = unsafe { ext_ffi::is_feature_enabled(feature.into()) };
+    ret != 0
+}
+
 /// Returns the current [`Phase`].
 pub fn get_phase() -> Phase {
     let dest_non_null_ptr = contract_api::alloc_bytes(PHASE_SERIALIZED_LENGTH);
@@ -222,7 +610,71 @@ pub fn put_key(name: &str, key: Key) {
     unsafe { ext_ffi::put_key(name_ptr, name_size, key_ptr, key_size) };
 }
 
-/// Removes the [`Key`] stored under `name` in the current context's named keys.
+/// Stores the given [`Key`] under `name` in the current context's named keys, but only if the key
+/// currently stored under `name` is `expected`.  If `expected` is `None`, `name` must not currently
+/// be in the named keys.
+///
+/// Unlike [`put_key`], this observes updates to the named keys made by nested calls to the same
+/// account or contract, so it can be used to prevent concurrent updates from being lost.
+pub fn update_key(name: &str, expected: Option<Key>, key: Key) -> Result<(), UpdateKeyError> {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let (expected_ptr, expected_size, _bytes2) = contract_api::to_ptr(expected);
+    let (key_ptr, key_size, _bytes3) = contract_api::to_ptr(key);
+    let result = unsafe {
+        ext_ffi::update_key(
+            name_ptr,
+            name_size,
+            expected_ptr,
+            expected_size,
+            key_ptr,
+            key_size,
+        )
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(UpdateKeyError::try_from(result).unwrap_or_revert())
+    }
+}
+
+/// Stores the given [`Key`] under `name` in the current context's named keys, archiving the key
+/// previously stored under `name` so that it stays readable with [`get_key_version`].  Returns the
+/// version of `key`: `1` if there was no key under `name`, or one more than the version of the
+/// previous key.
+///
+/// The previous key is archived under `name#<version>` within the same host call, and the version
+/// count is kept in a read-only [`URef`] under `name#versions`.  [`remove_key`] removes both along
+/// with `name`.  A key put under `name` by other means, e.g. [`put_key`], is replaced rather than
+/// archived.
+///
+/// The names of the archived versions and of the version count are reserved: putting, updating or
+/// removing a key under one of them directly reverts with [`ApiError::PermissionDenied`].
+pub fn put_key_versioned(name: &str, key: Key) -> u32 {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let (key_ptr, key_size, _bytes2) = contract_api::to_ptr(key);
+    let version = unsafe { ext_ffi::put_key_versioned(name_ptr, name_size, key_ptr, key_size) };
+    version as u32
+}
+
+/// Returns version `version` of the [`Key`] stored under `name` with [`put_key_versioned`], or
+/// `None` if there is no such version.  The latest version is the key currently under `name`.
+pub fn get_key_version(name: &str, version: u32) -> Option<Key> {
+    let versions_uref = get_key(&key_versions_name(name))?.into_uref()?;
+    let latest_version: u32 = storage::read(versions_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert_with(ApiError::ValueNotFound);
+    // Every version but the latest is archived.
+    if version == 0 || version > latest_version {
+        None
+    } else if version == latest_version {
+        get_key(name)
+    } else {
+        get_key(&versioned_key_name(name, version))
+    }
+}
+
+/// Removes the [`Key`] stored under `name` in the current context's named keys.  If it was stored
+/// with [`put_key_versioned`], its archived versions are removed too.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
 /// currently-executing module is a direct call or a sub-call respectively.
@@ -231,10 +683,21 @@ pub fn remove_key(name: &str) {
     unsafe { ext_ffi::remove_key(name_ptr, name_size) }
 }
 
+/// Removes the [`Key`] stored under `name` as per [`remove_key`], returning `true` if there was
+/// such a key.
+pub fn remove_key_checked(name: &str) -> bool {
+    let (name_ptr, name_size, _bytes) = contract_api::to_ptr(name);
+    let result = unsafe { ext_ffi::remove_key_checked(name_ptr, name_size) };
+    result == 0
+}
+
 /// Returns the named keys of the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
 /// currently-executing module is a direct call or a sub-call respectively.
+///
+/// The host serializes the named keys in lexicographic order of their names, so the serialized form
+/// of the returned map doesn't depend on the order in which the keys were put.
 pub fn list_named_keys() -> BTreeMap<String, Key> {
     let (total_keys, result_size) = {
         let mut total_keys = MaybeUninit::uninit();
@@ -253,6 +716,78 @@ pub fn list_named_keys() -> BTreeMap<String, Key> {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// The number of named keys read from the host at a time by the iterator returned by
+/// [`named_keys_iter`].
+pub const NAMED_KEYS_CHUNK_SIZE: usize = 16;
+
+/// Returns up to `max` of the current context's named keys in order of their names, starting with
+/// the one at `start_index`, along with whether any further named keys follow them.
+///
+/// The host charges for each byte of the chunk it copies, so reading a few named keys this way is
+/// cheaper than [`list_named_keys`] for a context holding many.
+pub fn list_named_keys_chunk(start_index: usize, max: usize) -> (Vec<(String, Key)>, bool) {
+    let result_size = {
+        let mut result_size = MaybeUninit::uninit();
+        let ret =
+            unsafe { ext_ffi::list_named_keys_chunk(start_index, max, result_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { result_size.assume_init() }
+    };
+    let bytes = read_host_buffer(result_size).unwrap_or_revert();
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// An iterator over the current context's named keys in order of their names, reading them from
+/// the host in chunks as it advances.
+///
+/// Each chunk reflects the named keys as they are when it's read, so keys put or removed while
+/// iterating may or may not be visited.
+pub struct NamedKeysIter {
+    chunk_size: usize,
+    next_index: usize,
+    chunk: vec::IntoIter<(String, Key)>,
+    more: bool,
+}
+
+impl NamedKeysIter {
+    /// Constructs an iterator reading `chunk_size` named keys from the host at a time.  A
+    /// `chunk_size` of zero is treated as one.
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        NamedKeysIter {
+            chunk_size: chunk_size.max(1),
+            next_index: 0,
+            chunk: Vec::new().into_iter(),
+            more: true,
+        }
+    }
+}
+
+impl Iterator for NamedKeysIter {
+    type Item = (String, Key);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(named_key) = self.chunk.next() {
+            return Some(named_key);
+        }
+        if !self.more {
+            return None;
+        }
+        let (chunk, more) = list_named_keys_chunk(self.next_index, self.chunk_size);
+        self.next_index += chunk.len();
+        self.more = more;
+        self.chunk = chunk.into_iter();
+        self.chunk.next()
+    }
+}
+
+/// Returns an iterator over the current context's named keys in order of their names.
+///
+/// Unlike [`list_named_keys`], the iterator reads [`NAMED_KEYS_CHUNK_SIZE`] named keys from the
+/// host at a time, so a contract only pays for the chunks it visits.
+pub fn named_keys_iter() -> NamedKeysIter {
+    NamedKeysIter::with_chunk_size(NAMED_KEYS_CHUNK_SIZE)
+}
+
 /// Validates uref against named keys.
 pub fn is_valid_uref(uref: URef) -> bool {
     let (uref_ptr, uref_size, _bytes) = contract_api::to_ptr(uref);
@@ -260,6 +795,18 @@ pub fn is_valid_uref(uref: URef) -> bool {
     result != 0
 }
 
+/// Emits an event under `topic`, recorded in the execution results of the deploy if it succeeds.
+///
+/// `data` is usually serialized with [`bytesrepr`], so that consumers of the event can deserialize
+/// it.  Reverts with [`ApiError::EventLimitExceeded`] if the deploy has already emitted as many
+/// events, or as many bytes of them, as the node allows.
+pub fn emit_event(topic: &str, data: &[u8]) {
+    let (topic_ptr, topic_size, _bytes1) = contract_api::to_ptr(topic);
+    let (data_ptr, data_size, _bytes2) = contract_api::to_ptr(data.to_vec());
+    let ret = unsafe { ext_ffi::emit_event(topic_ptr, topic_size, data_ptr, data_size) };
+    api_error::result_from(ret).unwrap_or_revert()
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
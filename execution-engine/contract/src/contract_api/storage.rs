@@ -6,7 +6,8 @@ use core::{convert::From, mem::MaybeUninit};
 use casperlabs_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    AccessRights, ApiError, CLTyped, CLValue, ContractRef, Key, URef, UREF_SERIALIZED_LENGTH,
+    AccessRights, ApiError, CLTyped, CLValue, ContractRef, ContractVersion, Key, URef,
+    KEY_HASH_LENGTH, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -102,6 +103,23 @@ pub fn add<T: CLTyped + ToBytes>(uref: URef, value: T) {
     }
 }
 
+/// Appends `item` to the list currently under `uref` in the global state.
+///
+/// Unlike reading the list, pushing `item` and writing it back, this costs the same however long
+/// the list is, and appends to the same list from concurrent deploys don't conflict.
+pub fn list_append<T: CLTyped + ToBytes>(uref: URef, item: T) {
+    let key = Key::from(uref);
+    let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(key);
+
+    let cl_value = CLValue::from_t(item).unwrap_or_revert();
+    let (cl_value_ptr, cl_value_size, _bytes2) = contract_api::to_ptr(cl_value);
+
+    unsafe {
+        // Could panic if the value under `uref` isn't a list of values of type `T`.
+        ext_ffi::list_append(key_ptr, key_size, cl_value_ptr, cl_value_size);
+    }
+}
+
 /// Adds `value` to the one currently under `key` in the context-local partition of global state.
 pub fn add_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
     let (key_ptr, key_size, _bytes1) = contract_api::to_ptr(key);
@@ -114,6 +132,28 @@ pub fn add_local<K: ToBytes, V: CLTyped + ToBytes>(key: K, value: V) {
     }
 }
 
+/// Removes the value under `uref` from the global state.
+///
+/// Only the mint may delete values, so this reverts with `InvalidContext` when called by any
+/// other contract.
+pub fn delete(uref: URef) {
+    let key = Key::from(uref);
+    let (key_ptr, key_size, _bytes) = contract_api::to_ptr(key);
+
+    unsafe {
+        ext_ffi::delete(key_ptr, key_size);
+    }
+}
+
+/// Removes the value under `key` from the context-local partition of global state.
+pub fn delete_local<K: ToBytes>(key: K) {
+    let (key_ptr, key_size, _bytes) = contract_api::to_ptr(key);
+
+    unsafe {
+        ext_ffi::delete_local(key_ptr, key_size);
+    }
+}
+
 /// Stores the serialized bytes of an exported, non-mangled `extern "C"` function as a new contract
 /// under a [`URef`] generated by the host.
 pub fn store_function(name: &str, named_keys: BTreeMap<String, Key>) -> ContractRef {
@@ -138,6 +178,62 @@ pub fn store_function_at_hash(name: &str, named_keys: BTreeMap<String, Key>) ->
     ContractRef::Hash(addr)
 }
 
+/// Creates a new contract package without any versions.  Returns the key of the package, which
+/// is what callers of [`runtime::call_versioned_contract`] need, and its access key, which is
+/// needed to add and disable versions.
+pub fn create_contract_package() -> (Key, URef) {
+    let mut dest = [0u8; KEY_HASH_LENGTH + UREF_SERIALIZED_LENGTH];
+    let ret = unsafe { ext_ffi::create_contract_package(dest.as_mut_ptr(), dest.len()) };
+    api_error::result_from(ret).unwrap_or_revert();
+    let (package_hash, access_key): ([u8; KEY_HASH_LENGTH], URef) =
+        bytesrepr::deserialize(dest.to_vec()).unwrap_or_revert();
+    (Key::Hash(package_hash), access_key)
+}
+
+/// Adds the given contract, which has to be stored at a hash, as the next version of the contract
+/// package under `package`.  Returns the new version.
+pub fn add_contract_version(
+    package: Key,
+    access_key: URef,
+    contract: ContractRef,
+) -> ContractVersion {
+    let (package_ptr, package_size, _bytes1) = contract_api::to_ptr(package);
+    let (access_key_ptr, access_key_size, _bytes2) = contract_api::to_ptr(access_key);
+    let contract_key: Key = contract.into();
+    let (contract_key_ptr, contract_key_size, _bytes3) = contract_api::to_ptr(contract_key);
+    let mut version = MaybeUninit::uninit();
+    let ret = unsafe {
+        ext_ffi::add_contract_version(
+            package_ptr,
+            package_size,
+            access_key_ptr,
+            access_key_size,
+            contract_key_ptr,
+            contract_key_size,
+            version.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert();
+    unsafe { version.assume_init() }
+}
+
+/// Disables `version` of the contract package under `package`, so that calls to that version
+/// fail from now on.
+pub fn disable_contract_version(package: Key, access_key: URef, version: ContractVersion) {
+    let (package_ptr, package_size, _bytes1) = contract_api::to_ptr(package);
+    let (access_key_ptr, access_key_size, _bytes2) = contract_api::to_ptr(access_key);
+    let ret = unsafe {
+        ext_ffi::disable_contract_version(
+            package_ptr,
+            package_size,
+            access_key_ptr,
+            access_key_size,
+            version,
+        )
+    };
+    api_error::result_from(ret).unwrap_or_revert()
+}
+
 /// Returns a new unforgeable pointer, where the value is initialized to `init`.
 pub fn new_uref<T: CLTyped + ToBytes>(init: T) -> URef {
     let uref_non_null_ptr = contract_api::alloc_bytes(UREF_SERIALIZED_LENGTH);
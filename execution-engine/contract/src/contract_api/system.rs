@@ -4,7 +4,8 @@ use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 
 use casperlabs_types::{
-    account::PublicKey, api_error, bytesrepr, ApiError, ContractRef, SystemContractType,
+    account::{PublicKey, Weight},
+    api_error, bytesrepr, AccessRights, ApiError, ContractRef, Key, SystemContractType,
     TransferResult, TransferredTo, URef, U512, UREF_SERIALIZED_LENGTH,
 };
 
@@ -15,11 +16,19 @@ use crate::{
 };
 
 /// Name of the reference to the Mint contract in the named keys.
+#[deprecated(note = "use `get_system_contract(SystemContractType::Mint)` instead")]
 pub const MINT_NAME: &str = "mint";
 /// Name of the reference to the Proof of Stake contract in the named keys.
+#[deprecated(note = "use `get_system_contract(SystemContractType::ProofOfStake)` instead")]
 pub const POS_NAME: &str = "pos";
 
-fn get_system_contract(system_contract: SystemContractType) -> ContractRef {
+/// Returns a read-only pointer to the given system contract.
+///
+/// The pointer is resolved from the protocol data rather than from the named keys of the account,
+/// so works even if the account has no named keys referring to the system contracts.
+///
+/// Any failure will trigger [`revert`](runtime::revert) with an appropriate [`ApiError`].
+pub fn get_system_contract(system_contract: SystemContractType) -> ContractRef {
     let system_contract_index = system_contract.into();
     let uref: URef = {
         let result = {
@@ -83,7 +92,18 @@ pub fn create_purse() -> URef {
     }
 }
 
-/// Returns the balance in motes of the given purse.
+/// Creates a new empty purse and returns its [`URef`] with at most the given `access_rights`.
+///
+/// Passing [`AccessRights::ADD`] gives a deposit-only purse: motes can be transferred into it, but
+/// never out of it.  An existing purse can be restricted the same way via
+/// [`URef::into_add_only`].
+pub fn create_purse_with_rights(access_rights: AccessRights) -> URef {
+    create_purse().with_access_rights(access_rights)
+}
+
+/// Returns the balance in motes of the given purse, or `None` if the purse doesn't exist.
+///
+/// Reverts with [`ApiError::NoAccessRights`] if `purse` doesn't grant read access.
 pub fn get_balance(purse: URef) -> Option<U512> {
     let (purse_ptr, purse_size, _bytes) = contract_api::to_ptr(purse);
 
@@ -111,6 +131,40 @@ pub fn transfer_to_account(target: PublicKey, amount: U512) -> TransferResult {
     TransferredTo::result_from(return_code)
 }
 
+/// Transfers `amount` of motes from the default purse of the account to `target` account like
+/// [`transfer_to_account`], but if `target` does not exist it will be created with `target` as its
+/// only associated key of `initial_weight`, and with the given action thresholds.
+///
+/// The thresholds are validated as if set via
+/// [`account::set_action_threshold`](crate::contract_api::account::set_action_threshold) on the new
+/// account, even if `target` exists.  If they are invalid, nothing is transferred and the
+/// corresponding error, e.g. [`ApiError::InsufficientTotalWeight`], is returned.
+pub fn transfer_to_account_with_config(
+    target: PublicKey,
+    amount: U512,
+    initial_weight: Weight,
+    deployment_threshold: Weight,
+    key_management_threshold: Weight,
+) -> TransferResult {
+    let (target_ptr, target_size, _bytes1) = contract_api::to_ptr(target);
+    let (amount_ptr, amount_size, _bytes2) = contract_api::to_ptr(amount);
+    let mut transferred_to = MaybeUninit::uninit();
+    let ret = unsafe {
+        ext_ffi::transfer_to_account_with_config(
+            target_ptr,
+            target_size,
+            amount_ptr,
+            amount_size,
+            initial_weight.value().into(),
+            deployment_threshold.value().into(),
+            key_management_threshold.value().into(),
+            transferred_to.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(ret)?;
+    TransferredTo::result_from(unsafe { transferred_to.assume_init() })
+}
+
 /// Transfers `amount` of motes from `source` purse to `target` account.  If `target` does not exist
 /// it will be created.
 pub fn transfer_from_purse_to_account(
@@ -136,6 +190,10 @@ pub fn transfer_from_purse_to_account(
 
 /// Transfers `amount` of motes from `source` purse to `target` purse.  If `target` does not exist
 /// the transfer fails.
+///
+/// `source` must carry write rights and `target` add rights, otherwise the mint's
+/// `InvalidAccessRights` error is returned.  Any other failure is returned as
+/// [`ApiError::Transfer`].
 pub fn transfer_from_purse_to_purse(
     source: URef,
     target: URef,
@@ -154,9 +212,139 @@ pub fn transfer_from_purse_to_purse(
             amount_size,
         )
     };
-    if result == 0 {
-        Ok(())
-    } else {
-        Err(ApiError::Transfer)
+    api_error::result_from(result)
+}
+
+/// Transfers `amount` of motes from `source` purse to `target` purse, tagging the transfer with the
+/// given `id`.  If an `id` is given, the amount is added to the mint's record of the transfers into
+/// `target` under that `id`, once per call, even if the same `id` is used several times.  If
+/// `target` does not exist the transfer fails.
+///
+/// Access rights are checked as for [`transfer_from_purse_to_purse`].
+pub fn transfer_from_purse_to_purse_with_id(
+    source: URef,
+    target: URef,
+    amount: U512,
+    id: Option<u64>,
+) -> Result<(), ApiError> {
+    let (source_ptr, source_size, _bytes1) = contract_api::to_ptr(source);
+    let (target_ptr, target_size, _bytes2) = contract_api::to_ptr(target);
+    let (amount_ptr, amount_size, _bytes3) = contract_api::to_ptr(amount);
+    let (id_ptr, id_size, _bytes4) = contract_api::to_ptr(id);
+    let result = unsafe {
+        ext_ffi::transfer_from_purse_to_purse_with_id(
+            source_ptr,
+            source_size,
+            target_ptr,
+            target_size,
+            amount_ptr,
+            amount_size,
+            id_ptr,
+            id_size,
+        )
+    };
+    api_error::result_from(result)
+}
+
+/// Deletes `purse`, moving any remaining motes to `refund_to`.
+///
+/// `purse` must carry [`AccessRights::READ_ADD_WRITE`](casperlabs_types::AccessRights), otherwise
+/// [`ApiError::NoAccessRights`] is returned.  Once deleted, the purse can no longer be used as the
+/// source or target of a transfer.
+pub fn delete_purse(purse: URef, refund_to: URef) -> Result<(), ApiError> {
+    let (purse_ptr, purse_size, _bytes1) = contract_api::to_ptr(purse);
+    let (refund_ptr, refund_size, _bytes2) = contract_api::to_ptr(refund_to);
+    let result = unsafe { ext_ffi::delete_purse(purse_ptr, purse_size, refund_ptr, refund_size) };
+    api_error::result_from(result)
+}
+
+/// Destroys `amount` motes held in `purse`, reducing the total supply by the same amount.
+///
+/// `purse` must carry [`AccessRights::READ_ADD_WRITE`](casperlabs_types::AccessRights), otherwise
+/// [`ApiError::NoAccessRights`] is returned.  Burning more than the balance of `purse` returns
+/// `ApiError::Mint` with the code of the mint's `InsufficientFunds` error, as a transfer would.
+pub fn burn(purse: URef, amount: U512) -> Result<(), ApiError> {
+    let (purse_ptr, purse_size, _bytes1) = contract_api::to_ptr(purse);
+    let (amount_ptr, amount_size, _bytes2) = contract_api::to_ptr(amount);
+    let result = unsafe { ext_ffi::burn(purse_ptr, purse_size, amount_ptr, amount_size) };
+    api_error::result_from(result)
+}
+
+/// Returns the purse bound to the contract stored under `contract`, creating an empty one the
+/// first time it's asked for.
+///
+/// The same purse is returned on every call, so payers can transfer to a contract without it
+/// publishing a purse first.  Only the contract itself gets the purse with full access rights;
+/// any other caller gets it with [`AccessRights::ADD`], enough to transfer into it.
+///
+/// A contract is identified by its address, so one stored under a [`URef`] gets the same purse
+/// whether `contract` is that `URef`, with any access rights, or a [`Key::Hash`] of its address
+/// as found on the call stack.
+///
+/// Reverts with [`ApiError::InvalidArgument`] if `contract` is an account or local key.
+pub fn get_or_create_contract_purse(contract: Key) -> URef {
+    let (key_ptr, key_size, _bytes) = contract_api::to_ptr(contract);
+    let purse_non_null_ptr = contract_api::alloc_bytes(UREF_SERIALIZED_LENGTH);
+    unsafe {
+        let ret = ext_ffi::get_or_create_contract_purse(
+            key_ptr,
+            key_size,
+            purse_non_null_ptr.as_ptr(),
+            UREF_SERIALIZED_LENGTH,
+        );
+        let bytes = Vec::from_raw_parts(
+            purse_non_null_ptr.as_ptr(),
+            UREF_SERIALIZED_LENGTH,
+            UREF_SERIALIZED_LENGTH,
+        );
+        api_error::result_from(ret).unwrap_or_revert();
+        bytesrepr::deserialize(bytes).unwrap_or_revert()
+    }
+}
+
+/// Creates an empty sub-purse of `parent`, which can draw up to `cap` motes in total from it.
+///
+/// Transfers out of the sub-purse pull from `parent` first, as far as the remaining cap and the
+/// balance of `parent` allow, and take the rest from the sub-purse's own balance.
+///
+/// `parent` must carry [`AccessRights::READ_ADD_WRITE`](casperlabs_types::AccessRights), otherwise
+/// [`ApiError::NoAccessRights`] is returned.  Sub-purses can't have sub-purses of their own: if
+/// `parent` is one, `ApiError::Mint` is returned with the code of the mint's `NestedSubPurse`
+/// error.
+pub fn create_sub_purse(parent: URef, cap: U512) -> Result<URef, ApiError> {
+    let (parent_ptr, parent_size, _bytes1) = contract_api::to_ptr(parent);
+    let (cap_ptr, cap_size, _bytes2) = contract_api::to_ptr(cap);
+    let sub_purse_non_null_ptr = contract_api::alloc_bytes(UREF_SERIALIZED_LENGTH);
+    unsafe {
+        let ret = ext_ffi::create_sub_purse(
+            parent_ptr,
+            parent_size,
+            cap_ptr,
+            cap_size,
+            sub_purse_non_null_ptr.as_ptr(),
+            UREF_SERIALIZED_LENGTH,
+        );
+        let bytes = Vec::from_raw_parts(
+            sub_purse_non_null_ptr.as_ptr(),
+            UREF_SERIALIZED_LENGTH,
+            UREF_SERIALIZED_LENGTH,
+        );
+        api_error::result_from(ret)?;
+        Ok(bytesrepr::deserialize(bytes).unwrap_or_revert())
     }
 }
+
+/// Revokes `sub_purse`, so that transfers out of it no longer pull from `parent`.  Motes already
+/// pulled into `sub_purse` are left where they are.
+///
+/// `parent` must carry [`AccessRights::READ_ADD_WRITE`](casperlabs_types::AccessRights), otherwise
+/// [`ApiError::NoAccessRights`] is returned.  If `sub_purse` isn't an unrevoked sub-purse of
+/// `parent`, `ApiError::Mint` is returned with the code of the mint's `SubPurseNotFound` error.
+pub fn revoke_sub_purse(parent: URef, sub_purse: URef) -> Result<(), ApiError> {
+    let (parent_ptr, parent_size, _bytes1) = contract_api::to_ptr(parent);
+    let (sub_purse_ptr, sub_purse_size, _bytes2) = contract_api::to_ptr(sub_purse);
+    let result = unsafe {
+        ext_ffi::revoke_sub_purse(parent_ptr, parent_size, sub_purse_ptr, sub_purse_size)
+    };
+    api_error::result_from(result)
+}
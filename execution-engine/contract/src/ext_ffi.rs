@@ -13,7 +13,10 @@ extern "C" {
         value_size: usize,
     );
     pub fn add(key_ptr: *const u8, key_size: usize, value_ptr: *const u8, value_size: usize);
+    pub fn list_append(key_ptr: *const u8, key_size: usize, item_ptr: *const u8, item_size: usize);
     pub fn add_local(key_ptr: *const u8, key_size: usize, value_ptr: *const u8, value_size: usize);
+    pub fn delete(key_ptr: *const u8, key_size: usize);
+    pub fn delete_local(key_ptr: *const u8, key_size: usize);
     pub fn new_uref(uref_ptr: *mut u8, value_ptr: *const u8, value_size: usize);
     pub fn store_function(
         function_name_ptr: *const u8,
@@ -30,8 +33,18 @@ extern "C" {
         hash_ptr: *const u8,
     );
     pub fn load_named_keys(total_keys: *mut usize, result_size: *mut usize) -> i32;
+    pub fn list_named_keys_chunk(start_index: usize, max: usize, result_size: *mut usize) -> i32;
     pub fn get_arg(index: usize, dest_ptr: *mut u8, dest_size: usize) -> i32;
     pub fn get_arg_size(index: usize, dest_size: *mut usize) -> i32;
+    pub fn get_args_len(dest_len: *mut usize);
+    pub fn get_named_arg(
+        name_ptr: *const u8,
+        name_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn get_named_arg_size(name_ptr: *const u8, name_size: usize, dest_size: *mut usize) -> i32;
+    pub fn get_arg_envelope(index: usize, output_size: *mut usize) -> i32;
     pub fn ret(value_ptr: *const u8, value_size: usize) -> !;
     pub fn call_contract(
         key_ptr: *const u8,
@@ -40,6 +53,17 @@ extern "C" {
         args_size: usize,
         result_size: *mut usize,
     ) -> i32;
+    pub fn call_contract_into(
+        key_ptr: *const u8,
+        key_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    pub fn get_protocol_data(output_size: *mut usize) -> i32;
+    pub fn get_wasm_costs(output_size: *mut usize) -> i32;
     pub fn get_key(
         name_ptr: *const u8,
         name_size: usize,
@@ -49,7 +73,22 @@ extern "C" {
     ) -> i32;
     pub fn has_key(name_ptr: *const u8, name_size: usize) -> i32;
     pub fn put_key(name_ptr: *const u8, name_size: usize, key_ptr: *const u8, key_size: usize);
+    pub fn update_key(
+        name_ptr: *const u8,
+        name_size: usize,
+        expected_ptr: *const u8,
+        expected_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+    ) -> i32;
+    pub fn put_key_versioned(
+        name_ptr: *const u8,
+        name_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+    ) -> i32;
     pub fn remove_key(name_ptr: *const u8, name_size: usize);
+    pub fn remove_key_checked(name_ptr: *const u8, name_size: usize) -> i32;
     pub fn revert(status: u32) -> !;
     pub fn is_valid_uref(uref_ptr: *const u8, uref_size: usize) -> i32;
     pub fn add_associated_key(
@@ -65,7 +104,11 @@ extern "C" {
     ) -> i32;
     pub fn set_action_threshold(permission_level: u32, threshold: i32) -> i32;
     pub fn get_caller(output_size: *mut usize) -> i32;
+    pub fn get_caller_chain(output_size: *mut usize) -> i32;
     pub fn get_blocktime(dest_ptr: *const u8);
+    pub fn get_block_height(dest_ptr: *mut u8);
+    pub fn get_deploy_hash(dest_ptr: *mut u8);
+    pub fn get_account_public_key(output_size: *mut usize) -> i32;
     pub fn create_purse(purse_ptr: *const u8, purse_size: usize) -> i32;
     pub fn transfer_to_account(
         target_ptr: *const u8,
@@ -73,6 +116,16 @@ extern "C" {
         amount_ptr: *const u8,
         amount_size: usize,
     ) -> i32;
+    pub fn transfer_to_account_with_config(
+        target_ptr: *const u8,
+        target_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        initial_weight: i32,
+        deployment_threshold: i32,
+        key_management_threshold: i32,
+        transferred_to: *mut i32,
+    ) -> i32;
     pub fn transfer_from_purse_to_account(
         source_ptr: *const u8,
         source_size: usize,
@@ -89,6 +142,93 @@ extern "C" {
         amount_ptr: *const u8,
         amount_size: usize,
     ) -> i32;
+    pub fn transfer_from_purse_to_purse_with_id(
+        source_ptr: *const u8,
+        source_size: usize,
+        target_ptr: *const u8,
+        target_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        id_ptr: *const u8,
+        id_size: usize,
+    ) -> i32;
+    pub fn delete_purse(
+        purse_ptr: *const u8,
+        purse_size: usize,
+        refund_ptr: *const u8,
+        refund_size: usize,
+    ) -> i32;
+    pub fn burn(
+        purse_ptr: *const u8,
+        purse_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+    ) -> i32;
+    pub fn get_or_create_contract_purse(
+        contract_key_ptr: *const u8,
+        contract_key_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn create_sub_purse(
+        parent_ptr: *const u8,
+        parent_size: usize,
+        cap_ptr: *const u8,
+        cap_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+    ) -> i32;
+    pub fn revoke_sub_purse(
+        parent_ptr: *const u8,
+        parent_size: usize,
+        sub_purse_ptr: *const u8,
+        sub_purse_size: usize,
+    ) -> i32;
+    pub fn emit_event(
+        topic_ptr: *const u8,
+        topic_size: usize,
+        data_ptr: *const u8,
+        data_size: usize,
+    ) -> i32;
+    pub fn create_contract_package(dest_ptr: *mut u8, dest_size: usize) -> i32;
+    pub fn add_contract_version(
+        package_ptr: *const u8,
+        package_size: usize,
+        access_key_ptr: *const u8,
+        access_key_size: usize,
+        contract_key_ptr: *const u8,
+        contract_key_size: usize,
+        version_ptr: *mut u32,
+    ) -> i32;
+    pub fn disable_contract_version(
+        package_ptr: *const u8,
+        package_size: usize,
+        access_key_ptr: *const u8,
+        access_key_size: usize,
+        version: u32,
+    ) -> i32;
+    pub fn call_versioned_contract(
+        package_ptr: *const u8,
+        package_size: usize,
+        version_ptr: *const u8,
+        version_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    pub fn call_contract_with_payment(
+        key_ptr: *const u8,
+        key_size: usize,
+        args_ptr: *const u8,
+        args_size: usize,
+        payment_ptr: *const u8,
+        payment_size: usize,
+        dest_ptr: *mut u8,
+        dest_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
     pub fn get_balance(purse_ptr: *const u8, purse_size: usize, result_size: *mut usize) -> i32;
     pub fn get_phase(dest_ptr: *mut u8);
     pub fn upgrade_contract_at_uref(
@@ -97,6 +237,14 @@ extern "C" {
         key_ptr: *const u8,
         key_size: usize,
     ) -> i32;
+    pub fn upgrade_contract_at_uref_with_named_keys(
+        name_ptr: *const u8,
+        name_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+    ) -> i32;
     pub fn get_system_contract(
         system_contract_index: u32,
         dest_ptr: *mut u8,
@@ -104,6 +252,11 @@ extern "C" {
     ) -> i32;
     pub fn get_main_purse(dest_ptr: *mut u8);
     pub fn read_host_buffer(dest_ptr: *mut u8, dest_size: usize, bytes_written: *mut usize) -> i32;
+    pub fn blake2b256(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8);
+    pub fn keccak256(in_ptr: *const u8, in_size: usize, out_ptr: *mut u8);
+    pub fn revert_with_payload(status: u32, payload_ptr: *const u8, payload_size: usize) -> !;
+    pub fn is_feature_enabled(feature: u32) -> i32;
+    pub fn random_bytes(out_ptr: *mut u8);
     #[cfg(feature = "test-support")]
     pub fn print(text_ptr: *const u8, text_size: usize);
 }
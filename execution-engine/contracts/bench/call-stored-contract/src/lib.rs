@@ -0,0 +1,31 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, Key};
+
+enum Arg {
+    ContractName = 0,
+    TotalCalls,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_name: String = runtime::get_arg(Arg::ContractName as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let total_calls: u32 = runtime::get_arg(Arg::TotalCalls as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let contract_ref = runtime::get_key(&contract_name)
+        .and_then(Key::to_contract_ref)
+        .unwrap_or_revert_with(ApiError::GetKey);
+
+    for _ in 0..total_calls {
+        runtime::call_contract::<_, ()>(contract_ref.clone(), ());
+    }
+}
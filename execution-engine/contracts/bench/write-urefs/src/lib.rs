@@ -0,0 +1,28 @@
+#![no_std]
+
+extern crate contract;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+enum Arg {
+    TotalURefs = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let total_urefs: u32 = runtime::get_arg(Arg::TotalURefs as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    for i in 0..total_urefs {
+        let uref = storage::new_uref(u64::from(i));
+        let value: u64 = storage::read(uref)
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(ApiError::ValueNotFound);
+        storage::write(uref, value + 1);
+    }
+}
@@ -0,0 +1,46 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+#[repr(u16)]
+enum Args {
+    AccountPublicKeys = 0,
+    Amount = 1,
+}
+
+#[repr(u32)]
+enum CustomError {
+    MissingAccountPublicKeys = 1,
+    InvalidAccountPublicKeys = 2,
+    MissingAmount = 3,
+    InvalidAmount = 4,
+}
+
+/// Executes mote transfers to each of the supplied public keys.
+/// Transfers the requested amount to every account.
+#[no_mangle]
+pub fn delegate() {
+    let public_keys: Vec<PublicKey> = runtime::get_arg(Args::AccountPublicKeys as u32)
+        .unwrap_or_revert_with(ApiError::User(CustomError::MissingAccountPublicKeys as u16))
+        .unwrap_or_revert_with(ApiError::User(CustomError::InvalidAccountPublicKeys as u16));
+    let transfer_amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::User(CustomError::MissingAmount as u16))
+        .unwrap_or_revert_with(ApiError::User(CustomError::InvalidAmount as u16));
+    for public_key in public_keys {
+        system::transfer_to_account(public_key, transfer_amount).unwrap_or_revert();
+    }
+}
+
+#[cfg(not(feature = "lib"))]
+#[no_mangle]
+pub extern "C" fn call() {
+    delegate();
+}
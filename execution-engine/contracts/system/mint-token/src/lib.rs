@@ -13,13 +13,20 @@ use types::{
     account::PublicKey,
     bytesrepr::{FromBytes, ToBytes},
     system_contract_errors::mint::Error,
-    ApiError, CLTyped, CLValue, Key, URef, U512,
+    ApiError, CLTyped, CLValue, CallStackElement, Key, URef, KEY_HASH_LENGTH, U512,
 };
 
 const METHOD_MINT: &str = "mint";
 const METHOD_CREATE: &str = "create";
 const METHOD_BALANCE: &str = "balance";
 const METHOD_TRANSFER: &str = "transfer";
+const METHOD_TRANSFER_WITH_ID: &str = "transfer_with_id";
+const METHOD_DELETE_PURSE: &str = "delete_purse";
+const METHOD_TOTAL_SUPPLY: &str = "total_supply";
+const METHOD_BURN: &str = "burn";
+const METHOD_GET_OR_CREATE_CONTRACT_PURSE: &str = "get_or_create_contract_purse";
+const METHOD_CREATE_SUB_PURSE: &str = "create_sub_purse";
+const METHOD_REVOKE_SUB_PURSE: &str = "revoke_sub_purse";
 
 pub struct MintContract;
 
@@ -28,9 +35,21 @@ impl RuntimeProvider for MintContract {
         runtime::get_caller()
     }
 
+    fn get_immediate_caller(&self) -> Option<CallStackElement> {
+        runtime::get_caller_chain().into_iter().rev().nth(1)
+    }
+
+    fn get_key(&self, name: &str) -> Option<Key> {
+        runtime::get_key(name)
+    }
+
     fn put_key(&mut self, name: &str, key: Key) {
         runtime::put_key(name, key)
     }
+
+    fn remove_key(&mut self, name: &str) {
+        runtime::remove_key(name)
+    }
 }
 
 impl StorageProvider for MintContract {
@@ -62,6 +81,15 @@ impl StorageProvider for MintContract {
         storage::add(uref, value);
         Ok(())
     }
+
+    fn delete_local<K: ToBytes>(&mut self, key: K) {
+        storage::delete_local(key)
+    }
+
+    fn delete(&mut self, uref: URef) -> Result<(), Error> {
+        storage::delete(uref);
+        Ok(())
+    }
 }
 
 impl Mint for MintContract {}
@@ -113,6 +141,90 @@ pub fn delegate() {
             let ret = CLValue::from_t(result).unwrap_or_revert();
             runtime::ret(ret);
         }
+        // Type: `fn transfer_with_id(source: URef, target: URef, amount: U512, id: Option<u64>)
+        // -> Result<(), Error>`
+        METHOD_TRANSFER_WITH_ID => {
+            let source: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let target: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(3)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let id: Option<u64> = runtime::get_arg(4)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> =
+                mint_contract.transfer_with_id(source, target, amount, id);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn delete_purse(purse: URef, refund_to: URef) -> Result<(), Error>`
+        METHOD_DELETE_PURSE => {
+            let purse: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let refund_to: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.delete_purse(purse, refund_to);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn total_supply() -> U512`
+        METHOD_TOTAL_SUPPLY => {
+            let total_supply: U512 = mint_contract.total_supply().unwrap_or_revert();
+            let ret = CLValue::from_t(total_supply).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn burn(purse: URef, amount: U512) -> Result<(), Error>`
+        METHOD_BURN => {
+            let purse: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.burn(purse, amount);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn get_or_create_contract_purse(contract_hash: [u8; 32]) -> Result<URef, Error>`
+        METHOD_GET_OR_CREATE_CONTRACT_PURSE => {
+            let contract_hash: [u8; KEY_HASH_LENGTH] = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<URef, Error> =
+                mint_contract.get_or_create_contract_purse(contract_hash);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn create_sub_purse(parent: URef, cap: U512) -> Result<URef, Error>`
+        METHOD_CREATE_SUB_PURSE => {
+            let parent: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let cap: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<URef, Error> = mint_contract.create_sub_purse(parent, cap);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
+        // Type: `fn revoke_sub_purse(parent: URef, sub_purse: URef) -> Result<(), Error>`
+        METHOD_REVOKE_SUB_PURSE => {
+            let parent: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let sub_purse: URef = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: Result<(), Error> = mint_contract.revoke_sub_purse(parent, sub_purse);
+            let ret = CLValue::from_t(result).unwrap_or_revert();
+            runtime::ret(ret);
+        }
 
         _ => panic!("Unknown method name!"),
     }
@@ -16,14 +16,19 @@ use types::{
 
 const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
+const POS_COMMUNITY_PURSE: &str = "pos_community_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
+const POS_GAS_PRICE: &str = "pos_gas_price";
 const POS_FUNCTION_NAME: &str = "pos_ext";
 
 #[repr(u32)]
 enum Args {
     MintURef = 0,
     GenesisValidators = 1,
+    UnbondingDelay = 2,
+    GasPrice = 3,
 }
 
 #[no_mangle]
@@ -43,6 +48,14 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
 
+    let unbonding_delay: u64 = runtime::get_arg(Args::UnbondingDelay as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let gas_price: u64 = runtime::get_arg(Args::GasPrice as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
     let stakes = Stakes::new(genesis_validators);
 
     // Add genesis validators to PoS contract object.
@@ -57,18 +70,32 @@ pub extern "C" fn call() {
     let bonding_purse = mint_purse(&mint, total_bonds);
     let payment_purse = mint_purse(&mint, U512::zero());
     let rewards_purse = mint_purse(&mint, U512::zero());
+    let community_purse = mint_purse(&mint, U512::zero());
 
     // Include PoS purses in its named_keys
     [
         (POS_BONDING_PURSE, bonding_purse),
         (POS_PAYMENT_PURSE, payment_purse),
         (POS_REWARDS_PURSE, rewards_purse),
+        (POS_COMMUNITY_PURSE, community_purse),
     ]
     .iter()
     .for_each(|(name, uref)| {
         named_keys.insert(String::from(*name), Key::URef(*uref));
     });
 
+    // Store the unbonding delay read-only, so the PoS contract can't change it.
+    let unbonding_delay_uref = storage::new_uref(unbonding_delay).into_read();
+    named_keys.insert(
+        String::from(POS_UNBONDING_DELAY),
+        Key::URef(unbonding_delay_uref),
+    );
+
+    // Likewise the gas price, which the engine and standard payment convert between motes and gas
+    // with.
+    let gas_price_uref = storage::new_uref(gas_price).into_read();
+    named_keys.insert(String::from(POS_GAS_PRICE), Key::URef(gas_price_uref));
+
     let uref: URef = storage::store_function(POS_FUNCTION_NAME, named_keys)
         .into_uref()
         .unwrap_or_revert_with(ApiError::UnexpectedContractRefVariant);
@@ -5,6 +5,7 @@ extern crate alloc;
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     string::String,
+    vec::Vec,
 };
 
 use contract::{
@@ -12,22 +13,31 @@ use contract::{
     unwrap_or_revert::UnwrapOrRevert,
 };
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    commission_key_name, Commission, CommissionsProvider, GasPriceProvider, MintProvider,
+    ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider, Withdrawals,
+    WithdrawalsProvider,
 };
 use types::{
-    account::PublicKey, system_contract_errors::pos::Error, ApiError, BlockTime, CLValue, Key,
-    Phase, TransferResult, URef, U512,
+    account::PublicKey, system_contract_errors::pos::Error, ApiError, BlockTime, CLValue,
+    EngineFeature, Key, Phase, TransferResult, URef, U512,
 };
 
 const METHOD_BOND: &str = "bond";
 const METHOD_UNBOND: &str = "unbond";
+const METHOD_WITHDRAW_UNBONDED: &str = "withdraw_unbonded";
 const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
 const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
 const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
 const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+const METHOD_SET_COMMISSION: &str = "set_commission";
+const METHOD_GET_GAS_PRICE: &str = "get_gas_price";
+const METHOD_SLASH: &str = "slash";
+const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
 
 const BONDING_KEY: u8 = 1;
-const UNBONDING_KEY: u8 = 2;
+
+const UNBONDING_DELAY_KEY: &str = "pos_unbonding_delay";
+const GAS_PRICE_KEY: &str = "pos_gas_price";
 
 pub struct ProofOfStakeContract;
 
@@ -63,22 +73,10 @@ impl QueueProvider for ProofOfStakeContract {
             .unwrap_or_default()
     }
 
-    /// Reads unbonding queue from the local state of the contract.
-    fn read_unbonding(&mut self) -> Queue {
-        storage::read_local(&UNBONDING_KEY)
-            .unwrap_or_default()
-            .unwrap_or_default()
-    }
-
     /// Writes bonding queue to the local state of the contract.
     fn write_bonding(&mut self, queue: Queue) {
         storage::write_local(BONDING_KEY, queue);
     }
-
-    /// Writes unbonding queue to the local state of the contract.
-    fn write_unbonding(&mut self, queue: Queue) {
-        storage::write_local(UNBONDING_KEY, queue);
-    }
 }
 
 impl RuntimeProvider for ProofOfStakeContract {
@@ -102,6 +100,10 @@ impl RuntimeProvider for ProofOfStakeContract {
         runtime::get_blocktime()
     }
 
+    fn get_block_height(&self) -> u64 {
+        runtime::get_block_height()
+    }
+
     fn get_caller(&self) -> PublicKey {
         runtime::get_caller()
     }
@@ -155,6 +157,76 @@ impl StakesProvider for ProofOfStakeContract {
     }
 }
 
+impl WithdrawalsProvider for ProofOfStakeContract {
+    /// Reads the queued withdrawals from the contract's known urefs.
+    fn read_withdrawals(&self) -> Result<Withdrawals, Error> {
+        let named_keys = runtime::list_named_keys();
+        Withdrawals::from_names(named_keys.keys().map(String::as_str))
+    }
+
+    /// Writes the queued withdrawals to the contract's known urefs.
+    fn write_withdrawals(&mut self, withdrawals: &Withdrawals) {
+        // Encode the withdrawals as a set of uref names.
+        let mut new_urefs: BTreeSet<String> = withdrawals.strings().collect();
+        // Remove and add urefs to update the contract's known urefs accordingly.
+        for (name, _) in runtime::list_named_keys() {
+            if name.starts_with("w_") && !new_urefs.remove(&name) {
+                runtime::remove_key(&name);
+            }
+        }
+        for name in new_urefs {
+            runtime::put_key(&name, Key::Hash([0; 32]));
+        }
+    }
+
+    /// Reads the unbonding delay set at genesis, defaulting to zero if it was never set.
+    fn read_unbonding_delay(&mut self) -> Result<u64, Error> {
+        match runtime::get_key(UNBONDING_DELAY_KEY) {
+            None => Ok(0),
+            Some(Key::URef(uref)) => storage::read(uref)
+                .map_err(|_| Error::UnbondingDelayDeserializationFailed)?
+                .ok_or(Error::UnbondingDelayDeserializationFailed),
+            Some(_) => Err(Error::UnbondingDelayDeserializationFailed),
+        }
+    }
+}
+
+impl CommissionsProvider for ProofOfStakeContract {
+    /// Reads the validator's commission rate from the uref stored in the contract's known urefs.
+    fn read_commission(&mut self, validator: &PublicKey) -> Result<Option<Commission>, Error> {
+        match runtime::get_key(&commission_key_name(validator)) {
+            None => Ok(None),
+            Some(Key::URef(uref)) => storage::read(uref)
+                .map_err(|_| Error::CommissionsDeserializationFailed)?
+                .ok_or(Error::CommissionsDeserializationFailed)
+                .map(Some),
+            Some(_) => Err(Error::CommissionsDeserializationFailed),
+        }
+    }
+
+    /// Writes the validator's commission rate to the uref stored in the contract's known urefs,
+    /// creating it if the validator never set one.
+    fn write_commission(&mut self, validator: &PublicKey, commission: Commission) {
+        let name = commission_key_name(validator);
+        match runtime::get_key(&name) {
+            Some(Key::URef(uref)) => storage::write(uref, commission),
+            _ => runtime::put_key(&name, storage::new_uref(commission).into()),
+        }
+    }
+}
+
+impl GasPriceProvider for ProofOfStakeContract {
+    /// Reads the gas price set at genesis.
+    fn read_gas_price(&mut self) -> Result<u64, Error> {
+        match runtime::get_key(GAS_PRICE_KEY) {
+            Some(Key::URef(uref)) => storage::read(uref)
+                .map_err(|_| Error::GasPriceDeserializationFailed)?
+                .ok_or(Error::GasPriceDeserializationFailed),
+            _ => Err(Error::GasPriceDeserializationFailed),
+        }
+    }
+}
+
 impl ProofOfStake for ProofOfStakeContract {}
 
 pub fn delegate() {
@@ -167,8 +239,8 @@ pub fn delegate() {
     match method_name.as_str() {
         // Type of this method: `fn bond(amount: U512, purse: URef)`
         METHOD_BOND => {
-            if !cfg!(feature = "enable-bonding") {
-                runtime::revert(ApiError::Unhandled)
+            if !runtime::is_feature_enabled(EngineFeature::EnableBonding) {
+                runtime::revert(Error::BondingDisabled)
             }
 
             let validator = runtime::get_caller();
@@ -184,8 +256,8 @@ pub fn delegate() {
         }
         // Type of this method: `fn unbond(amount: Option<U512>)`
         METHOD_UNBOND => {
-            if !cfg!(feature = "enable-bonding") {
-                runtime::revert(ApiError::Unhandled)
+            if !runtime::is_feature_enabled(EngineFeature::EnableBonding) {
+                runtime::revert(Error::BondingDisabled)
             }
 
             let validator = runtime::get_caller();
@@ -196,6 +268,15 @@ pub fn delegate() {
                 .unbond(validator, maybe_amount)
                 .unwrap_or_revert();
         }
+        // Type of this method: `fn withdraw_unbonded()`
+        METHOD_WITHDRAW_UNBONDED => {
+            if !runtime::is_feature_enabled(EngineFeature::EnableBonding) {
+                runtime::revert(Error::BondingDisabled)
+            }
+
+            let validator = runtime::get_caller();
+            pos_contract.withdraw_unbonded(validator).unwrap_or_revert();
+        }
         // Type of this method: `fn get_payment_purse() -> URef`
         METHOD_GET_PAYMENT_PURSE => {
             let rights_controlled_purse = pos_contract.get_payment_purse().unwrap_or_revert();
@@ -232,6 +313,37 @@ pub fn delegate() {
                 .finalize_payment(amount_spent, account)
                 .unwrap_or_revert();
         }
+        // Type of this method: `fn set_commission(rate: u32)`
+        METHOD_SET_COMMISSION => {
+            let validator = runtime::get_caller();
+            let rate: u32 = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            pos_contract
+                .set_commission(validator, rate)
+                .unwrap_or_revert();
+        }
+        // Type of this method: `fn get_gas_price() -> u64`
+        METHOD_GET_GAS_PRICE => {
+            let gas_price = pos_contract.get_gas_price().unwrap_or_revert();
+            let return_value = CLValue::from_t(gas_price).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
+        // Type of this method: `fn slash(validators: Vec<PublicKey>) -> Vec<U512>`
+        METHOD_SLASH => {
+            let validators: Vec<PublicKey> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let slashed_amounts = pos_contract.slash(validators).unwrap_or_revert();
+            let return_value = CLValue::from_t(slashed_amounts).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
+        // Type of this method: `fn distribute_rewards() -> BTreeMap<PublicKey, (U512, U512)>`
+        METHOD_DISTRIBUTE_REWARDS => {
+            let splits = pos_contract.distribute_rewards().unwrap_or_revert();
+            let return_value = CLValue::from_t(splits).unwrap_or_revert();
+            runtime::ret(return_value);
+        }
         _ => {}
     }
 }
@@ -7,9 +7,20 @@ use contract::{
     contract_api::{account, runtime, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::{ApiError, URef, U512};
+use types::{ApiError, Key, URef, U512};
 
 const GET_PAYMENT_PURSE: &str = "get_payment_purse";
+const SET_REFUND_PURSE: &str = "set_refund_purse";
+const GET_GAS_PRICE: &str = "get_gas_price";
+
+/// The name of the argument which, if given in place of the amount, is the amount of gas to pay
+/// for at the current gas price.
+const ARG_GAS: &str = "gas";
+
+enum Arg {
+    Amount = 0,
+    RefundPurse = 1,
+}
 
 struct StandardPaymentContract;
 
@@ -17,6 +28,16 @@ impl AccountProvider for StandardPaymentContract {
     fn get_main_purse(&self) -> Result<URef, ApiError> {
         Ok(account::get_main_purse())
     }
+
+    fn can_add_to_purse(&self, purse: URef) -> bool {
+        let main_purse = account::get_main_purse();
+        let can_add = |uref: &URef| uref.addr() == purse.addr() && uref.is_addable();
+        can_add(&main_purse)
+            || runtime::list_named_keys()
+                .values()
+                .filter_map(Key::as_uref)
+                .any(can_add)
+    }
 }
 
 impl MintProvider for StandardPaymentContract {
@@ -36,6 +57,17 @@ impl ProofOfStakeProvider for StandardPaymentContract {
         let payment_purse = runtime::call_contract(pos_pointer, (GET_PAYMENT_PURSE,));
         Ok(payment_purse)
     }
+
+    fn set_refund_purse(&mut self, purse: URef) -> Result<(), ApiError> {
+        let pos_pointer = system::get_proof_of_stake();
+        runtime::call_contract(pos_pointer, (SET_REFUND_PURSE, purse))
+    }
+
+    fn get_gas_price(&mut self) -> Result<u64, ApiError> {
+        let pos_pointer = system::get_proof_of_stake();
+        let gas_price = runtime::call_contract(pos_pointer, (GET_GAS_PRICE,));
+        Ok(gas_price)
+    }
 }
 
 impl StandardPayment for StandardPaymentContract {}
@@ -43,11 +75,23 @@ impl StandardPayment for StandardPaymentContract {}
 pub fn delegate() {
     let mut standard_payment_contract = StandardPaymentContract;
 
-    let amount: U512 = runtime::get_arg(0)
+    let refund_purse: Option<URef> = runtime::get_arg(Arg::RefundPurse as u32)
+        .map(|arg| arg.unwrap_or_revert_with(ApiError::InvalidArgument));
+
+    if let Some(gas) = runtime::get_named_arg::<U512>(ARG_GAS) {
+        let gas = gas.unwrap_or_revert_with(ApiError::InvalidArgument);
+        standard_payment_contract
+            .pay_gas(gas, refund_purse)
+            .unwrap_or_revert();
+        return;
+    }
+
+    let amount: U512 = runtime::get_arg(Arg::Amount as u32)
         .unwrap_or_revert_with(ApiError::MissingArgument)
         .unwrap_or_revert_with(ApiError::InvalidArgument);
-
-    standard_payment_contract.pay(amount).unwrap_or_revert();
+    standard_payment_contract
+        .pay(amount, refund_purse)
+        .unwrap_or_revert();
 }
 
 #[cfg(not(feature = "lib"))]
@@ -0,0 +1,75 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, ApiError, ContractRef, Key, URef, U512};
+
+const CONTRACT_NAME: &str = "add_only_purse_holder";
+const METHOD_GET_DEPOSIT_PURSE: &str = "get_deposit_purse";
+const METHOD_WITHDRAW: &str = "withdraw";
+const COMMAND_DEPOSIT: &str = "deposit";
+const COMMAND_WITHDRAW: &str = "withdraw";
+const COMMAND_WITHDRAW_VIA_HOLDER: &str = "withdraw_via_holder";
+const COMMAND_WITHDRAW_ESCALATED: &str = "withdraw_escalated";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    NotAddOnly = 1,
+}
+
+fn contract_ref() -> ContractRef {
+    match runtime::get_key(CONTRACT_NAME).unwrap_or_revert_with(ApiError::GetKey) {
+        Key::Hash(hash) => ContractRef::Hash(hash),
+        _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+    }
+}
+
+fn get_deposit_purse() -> URef {
+    let purse: URef = runtime::call_contract(contract_ref(), (METHOD_GET_DEPOSIT_PURSE,));
+    if purse.access_rights() != AccessRights::ADD {
+        runtime::revert(ApiError::User(Error::NotAddOnly as u16));
+    }
+    purse
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+
+    match command.as_str() {
+        COMMAND_DEPOSIT => {
+            system::transfer_from_purse_to_purse(main_purse, get_deposit_purse(), amount)
+                .unwrap_or_revert()
+        }
+        // Should fail, as the deposit purse was handed out with add rights only.
+        COMMAND_WITHDRAW => {
+            system::transfer_from_purse_to_purse(get_deposit_purse(), main_purse, amount)
+                .unwrap_or_revert()
+        }
+        // Should fail, as the holder itself only has add rights on the deposit purse.
+        COMMAND_WITHDRAW_VIA_HOLDER => runtime::call_contract(
+            contract_ref(),
+            (METHOD_WITHDRAW, main_purse.into_add_only(), amount),
+        ),
+        // Should fail, as the caller was only granted add rights on the deposit purse.
+        COMMAND_WITHDRAW_ESCALATED => {
+            let purse = URef::new(get_deposit_purse().addr(), AccessRights::READ_ADD_WRITE);
+            system::transfer_from_purse_to_purse(purse, main_purse, amount).unwrap_or_revert()
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
@@ -0,0 +1,65 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, ApiError, CLValue, Key, URef, U512};
+
+const CONTRACT_NAME: &str = "add_only_purse_holder";
+const ENTRY_FUNCTION_NAME: &str = "apply_method";
+const DEPOSIT_PURSE_NAME: &str = "deposit_purse";
+const METHOD_GET_DEPOSIT_PURSE: &str = "get_deposit_purse";
+const METHOD_WITHDRAW: &str = "withdraw";
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+}
+
+fn deposit_purse() -> URef {
+    runtime::get_key(DEPOSIT_PURSE_NAME)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::GetKey)
+}
+
+#[no_mangle]
+pub extern "C" fn apply_method() {
+    let method_name: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method_name.as_str() {
+        // Returns the deposit purse, so that the caller can pay into it.
+        METHOD_GET_DEPOSIT_PURSE => {
+            runtime::ret(CLValue::from_t(deposit_purse()).unwrap_or_revert())
+        }
+        // Should fail, as the deposit purse is held with add rights only.
+        METHOD_WITHDRAW => {
+            let target: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            system::transfer_from_purse_to_purse(deposit_purse(), target, amount).unwrap_or_revert()
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownMethod as u16)),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let deposit_purse = system::create_purse_with_rights(AccessRights::ADD);
+
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(String::from(DEPOSIT_PURSE_NAME), deposit_purse.into());
+    let key = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, named_keys).into();
+
+    runtime::put_key(CONTRACT_NAME, key);
+    runtime::put_key(DEPOSIT_PURSE_NAME, deposit_purse.into());
+}
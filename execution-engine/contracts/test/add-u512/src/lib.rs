@@ -0,0 +1,35 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+const TOTAL_KEY: &str = "total";
+
+enum Arg {
+    Initial = 0,
+    Amounts = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let initial: U512 = runtime::get_arg(Arg::Initial as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amounts: Vec<U512> = runtime::get_arg(Arg::Amounts as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let total = storage::new_uref(initial);
+    for amount in amounts {
+        storage::add(total, amount);
+    }
+
+    runtime::put_key(TOTAL_KEY, total.into());
+}
@@ -0,0 +1,47 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{account::PublicKey, ApiError, U512};
+
+#[repr(u16)]
+enum Args {
+    Allowances = 0,
+    Spenders = 1,
+    Name = 2,
+    ExpectedAllowance = 3,
+}
+
+#[repr(u16)]
+enum Error {
+    CallerNotSpender = 0,
+    UnexpectedAllowance = 1,
+}
+
+/// Checks that the caller is one of the given spenders, and that the allowance under the given
+/// name is the expected one, or absent if `None` is expected.
+#[no_mangle]
+pub extern "C" fn call() {
+    let allowances: BTreeMap<String, U512> = runtime::get_arg(Args::Allowances as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let spenders: Vec<PublicKey> = runtime::get_arg(Args::Spenders as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let name: String = runtime::get_arg(Args::Name as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let expected_allowance: Option<U512> = runtime::get_arg(Args::ExpectedAllowance as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if !spenders.contains(&runtime::get_caller()) {
+        runtime::revert(ApiError::User(Error::CallerNotSpender as u16));
+    }
+    if allowances.get(&name) != expected_allowance.as_ref() {
+        runtime::revert(ApiError::User(Error::UnexpectedAllowance as u16));
+    }
+}
@@ -0,0 +1,54 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use blake2::{
+    digest::{Input, VariableOutput},
+    VarBlake2b,
+};
+
+use contract::{
+    contract_api::{crypto, runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, BLAKE2B_DIGEST_LENGTH};
+
+const DIGEST_NAME: &str = "digest";
+const MODE_HOST: &str = "host";
+const MODE_WASM: &str = "wasm";
+
+#[repr(u16)]
+enum Error {
+    UnknownMode = 0,
+}
+
+fn wasm_blake2b(data: &[u8]) -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    let mut digest = [0u8; BLAKE2B_DIGEST_LENGTH];
+    // Safe to unwrap here because the digest length is constant and valid
+    let mut hasher = VarBlake2b::new(BLAKE2B_DIGEST_LENGTH).unwrap();
+    hasher.input(data);
+    hasher.variable_result(|hash| digest.clone_from_slice(hash));
+    digest
+}
+
+/// Hashes the given data either via the host function or via a pure-Rust implementation running
+/// in Wasm, so that the gas costs of the two can be compared.
+#[no_mangle]
+pub extern "C" fn call() {
+    let mode: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let data: Vec<u8> = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let digest = match mode.as_str() {
+        MODE_HOST => crypto::blake2b(&data),
+        MODE_WASM => wasm_blake2b(&data),
+        _ => runtime::revert(ApiError::User(Error::UnknownMode as u16)),
+    };
+
+    runtime::put_key(DIGEST_NAME, storage::new_uref(digest).into());
+}
@@ -0,0 +1,46 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, BlockTime, Key};
+
+const DEADLINE_KEY: &str = "deadline";
+
+const COMMAND_SET_DEADLINE: &str = "set_deadline";
+const COMMAND_CHECK_DEADLINE: &str = "check_deadline";
+
+#[repr(u16)]
+enum Error {
+    DeadlineNotPassed = 0,
+    UnknownCommand = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if command == COMMAND_SET_DEADLINE {
+        let deadline: u64 = runtime::get_arg(1)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument);
+        runtime::put_key(DEADLINE_KEY, storage::new_uref(deadline).into());
+    } else if command == COMMAND_CHECK_DEADLINE {
+        let deadline_uref = runtime::get_key(DEADLINE_KEY)
+            .and_then(Key::into_uref)
+            .unwrap_or_revert_with(ApiError::GetKey);
+        let deadline: u64 = storage::read_or_revert(deadline_uref);
+        if runtime::get_blocktime() <= BlockTime::new(deadline) {
+            runtime::revert(ApiError::User(Error::DeadlineNotPassed as u16));
+        }
+    } else {
+        runtime::revert(ApiError::User(Error::UnknownCommand as u16));
+    }
+}
@@ -0,0 +1,33 @@
+#![no_std]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+#[repr(u16)]
+enum Args {
+    Amount = 0,
+    AddOnly = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let add_only: bool = runtime::get_arg(Args::AddOnly as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+    let purse = if add_only {
+        // Burning through an ADD-only uref should revert
+        main_purse.into_add_only()
+    } else {
+        main_purse
+    };
+
+    system::burn(purse, amount).unwrap_or_revert();
+}
@@ -0,0 +1,63 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+const SPEND_FUNCTION_NAME: &str = "spend";
+const SOURCE_PURSE_KEY: &str = "source_purse";
+const SPENDER_KEY: &str = "spender";
+const PAYMENT_PURSE_KEY: &str = "payment_purse";
+const RECIPIENT_ADDR: PublicKey = PublicKey::ed25519_from([7u8; 32]);
+
+#[repr(u16)]
+enum Args {
+    SourceBalance = 0,
+    Limit = 1,
+    Amount = 2,
+}
+
+/// Transfers the amount given as the only argument from the payment purse to the recipient, and
+/// keeps a copy of the payment purse in the contract's named keys.
+#[no_mangle]
+pub extern "C" fn spend() {
+    let amount: U512 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let payment_purse =
+        runtime::get_payment_purse().unwrap_or_revert_with(ApiError::MissingArgument);
+    runtime::put_key(PAYMENT_PURSE_KEY, payment_purse.into());
+    if !amount.is_zero() {
+        system::transfer_from_purse_to_account(payment_purse, RECIPIENT_ADDR, amount)
+            .unwrap_or_revert();
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let source_balance: U512 = runtime::get_arg(Args::SourceBalance as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let limit: U512 = runtime::get_arg(Args::Limit as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let source_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(account::get_main_purse(), source_purse, source_balance)
+        .unwrap_or_revert();
+    runtime::put_key(SOURCE_PURSE_KEY, source_purse.into());
+
+    let spender = storage::store_function_at_hash(SPEND_FUNCTION_NAME, BTreeMap::new());
+    runtime::put_key(SPENDER_KEY, spender.clone().into());
+
+    runtime::call_contract_with_payment::<_, ()>(spender, (amount,), source_purse, limit);
+}
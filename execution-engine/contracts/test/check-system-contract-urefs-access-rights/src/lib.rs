@@ -1,18 +1,16 @@
 #![no_std]
 
 use contract::contract_api::runtime;
-use types::{Key, URef};
 
-#[allow(clippy::redundant_closure)]
 #[no_mangle]
 pub extern "C" fn call() {
-    let named_keys = runtime::list_named_keys();
-    let mut access_rights_iter = named_keys
-        .values()
-        .filter_map(Key::as_uref)
-        .map(URef::access_rights);
+    let access_rights_iter = runtime::named_keys_iter()
+        .filter_map(|(_name, key)| key.into_uref())
+        .map(|uref| uref.access_rights());
 
-    assert!(access_rights_iter.all(|ar| ar.is_readable()));
-    assert!(access_rights_iter.all(|ar| !ar.is_addable()));
-    assert!(access_rights_iter.all(|ar| !ar.is_writeable()));
+    for access_rights in access_rights_iter {
+        assert!(access_rights.is_readable());
+        assert!(!access_rights.is_addable());
+        assert!(!access_rights.is_writeable());
+    }
 }
@@ -0,0 +1,91 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, ContractVersion, Key, URef};
+
+const PACKAGE_NAME: &str = "contract_package";
+const ACCESS_KEY_NAME: &str = "contract_package_access";
+const VERSION_1_FUNCTION_NAME: &str = "version_1";
+const VERSION_2_FUNCTION_NAME: &str = "version_2";
+const COMMAND_INSTALL_V1: &str = "install_v1";
+const COMMAND_INSTALL_V2: &str = "install_v2";
+const COMMAND_DISABLE: &str = "disable";
+const COMMAND_CALL: &str = "call";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    UnexpectedVersion = 1,
+    UnexpectedResult = 2,
+}
+
+#[no_mangle]
+pub extern "C" fn version_1() {
+    runtime::ret(CLValue::from_t(String::from("v1")).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn version_2() {
+    runtime::ret(CLValue::from_t(String::from("v2")).unwrap_or_revert())
+}
+
+fn package() -> Key {
+    runtime::get_key(PACKAGE_NAME).unwrap_or_revert_with(ApiError::GetKey)
+}
+
+fn access_key() -> URef {
+    runtime::get_key(ACCESS_KEY_NAME)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::GetKey)
+}
+
+fn add_version(package: Key, access_key: URef, name: &str, expected_version: ContractVersion) {
+    let contract = storage::store_function_at_hash(name, BTreeMap::new());
+    let version = storage::add_contract_version(package, access_key, contract);
+    if version != expected_version {
+        runtime::revert(ApiError::User(Error::UnexpectedVersion as u16));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_INSTALL_V1 => {
+            let (package, access_key) = storage::create_contract_package();
+            add_version(package, access_key, VERSION_1_FUNCTION_NAME, 1);
+            runtime::put_key(PACKAGE_NAME, package);
+            runtime::put_key(ACCESS_KEY_NAME, access_key.into());
+        }
+        COMMAND_INSTALL_V2 => add_version(package(), access_key(), VERSION_2_FUNCTION_NAME, 2),
+        COMMAND_DISABLE => {
+            let version: ContractVersion = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            storage::disable_contract_version(package(), access_key(), version);
+        }
+        COMMAND_CALL => {
+            let maybe_version: Option<ContractVersion> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let expected_result: String = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let result: String = runtime::call_versioned_contract(package(), maybe_version, ());
+            if result != expected_result {
+                runtime::revert(ApiError::User(Error::UnexpectedResult as u16));
+            }
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
@@ -0,0 +1,86 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, ApiError, ContractRef, Key, URef, U512};
+
+const CONTRACT_NAME: &str = "contract_purse_holder";
+const METHOD_WITHDRAW: &str = "withdraw";
+const COMMAND_DEPOSIT: &str = "deposit";
+const COMMAND_WITHDRAW: &str = "withdraw";
+const COMMAND_WITHDRAW_VIA_HOLDER: &str = "withdraw_via_holder";
+const COMMAND_DEPOSIT_VIA_UREF: &str = "deposit_via_uref";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    NotAddOnly = 1,
+}
+
+fn contract_key() -> Key {
+    runtime::get_key(CONTRACT_NAME).unwrap_or_revert_with(ApiError::GetKey)
+}
+
+fn get_contract_purse() -> URef {
+    get_contract_purse_of(contract_key())
+}
+
+fn get_contract_purse_of(contract_key: Key) -> URef {
+    let purse = system::get_or_create_contract_purse(contract_key);
+    if purse.access_rights() != AccessRights::ADD {
+        runtime::revert(ApiError::User(Error::NotAddOnly as u16));
+    }
+    purse
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+
+    match command.as_str() {
+        COMMAND_DEPOSIT => {
+            system::transfer_from_purse_to_purse(main_purse, get_contract_purse(), amount)
+                .unwrap_or_revert()
+        }
+        // Names the contract by a `URef` of its address rather than by its hash.
+        COMMAND_DEPOSIT_VIA_UREF => {
+            let contract_addr = match contract_key() {
+                Key::Hash(hash) => hash,
+                _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+            };
+            let contract_key = Key::URef(URef::new(contract_addr, AccessRights::READ));
+            let contract_purse = get_contract_purse_of(contract_key);
+            system::transfer_from_purse_to_purse(main_purse, contract_purse, amount)
+                .unwrap_or_revert()
+        }
+        // Should fail, as the contract purse is handed out to callers with add rights only.
+        COMMAND_WITHDRAW => {
+            system::transfer_from_purse_to_purse(get_contract_purse(), main_purse, amount)
+                .unwrap_or_revert()
+        }
+        COMMAND_WITHDRAW_VIA_HOLDER => {
+            let contract_ref = match contract_key() {
+                Key::Hash(hash) => ContractRef::Hash(hash),
+                _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+            };
+            runtime::call_contract(
+                contract_ref,
+                (METHOD_WITHDRAW, main_purse.into_add_only(), amount),
+            )
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
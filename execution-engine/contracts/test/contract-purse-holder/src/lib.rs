@@ -0,0 +1,57 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CallStackElement, Key, URef, U512};
+
+const CONTRACT_NAME: &str = "contract_purse_holder";
+const ENTRY_FUNCTION_NAME: &str = "apply_method";
+const METHOD_WITHDRAW: &str = "withdraw";
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+    NotCalledAsContract = 1,
+}
+
+/// Returns the purse bound to this contract, which it holds with full access rights.
+fn own_purse() -> URef {
+    match runtime::get_caller_chain().last() {
+        Some(CallStackElement::Contract { contract_hash, .. }) => {
+            system::get_or_create_contract_purse(Key::Hash(*contract_hash))
+        }
+        _ => runtime::revert(ApiError::User(Error::NotCalledAsContract as u16)),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn apply_method() {
+    let method_name: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method_name.as_str() {
+        METHOD_WITHDRAW => {
+            let target: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let amount: U512 = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            system::transfer_from_purse_to_purse(own_purse(), target, amount).unwrap_or_revert()
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownMethod as u16)),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let key = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, BTreeMap::new()).into();
+    runtime::put_key(CONTRACT_NAME, key);
+}
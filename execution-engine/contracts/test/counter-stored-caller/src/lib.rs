@@ -0,0 +1,13 @@
+#![no_std]
+
+use contract::contract_api::runtime;
+use types::{AccessRights, ApiError, ContractRef, URef};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let uref: URef =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+    let counter = ContractRef::URef(URef::new(uref.addr(), AccessRights::READ));
+
+    runtime::call_contract(counter, ())
+}
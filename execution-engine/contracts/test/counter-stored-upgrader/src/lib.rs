@@ -0,0 +1,41 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key, URef};
+
+const ENTRY_FUNCTION_NAME: &str = "counter_by_step";
+const COUNT_KEY: &str = "count";
+const STEP_KEY: &str = "step";
+const STEP: i32 = 2;
+
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant)
+}
+
+#[no_mangle]
+pub extern "C" fn counter_by_step() {
+    let step: i32 = storage::read_or_revert(get_uref(STEP_KEY));
+    storage::add(get_uref(COUNT_KEY), step);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let uref: URef =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+
+    // The upgraded contract keeps its `count`, and is given a `step` alongside it
+    let mut named_keys: BTreeMap<String, Key> = BTreeMap::new();
+    named_keys.insert(String::from(STEP_KEY), storage::new_uref(STEP).into());
+
+    runtime::upgrade_contract_at_uref_with_named_keys(ENTRY_FUNCTION_NAME, uref, named_keys);
+}
@@ -0,0 +1,37 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key, URef};
+
+const ENTRY_FUNCTION_NAME: &str = "counter";
+const CONTRACT_NAME: &str = "counter_stored";
+const COUNT_KEY: &str = "count";
+
+#[no_mangle]
+pub extern "C" fn counter() {
+    let count: URef = runtime::get_key(COUNT_KEY)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    storage::add(count, 1);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut named_keys: BTreeMap<String, Key> = BTreeMap::new();
+    named_keys.insert(String::from(COUNT_KEY), storage::new_uref(0).into());
+
+    let key = storage::store_function(ENTRY_FUNCTION_NAME, named_keys)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedContractRefVariant)
+        .into();
+
+    runtime::put_key(CONTRACT_NAME, key);
+}
@@ -0,0 +1,15 @@
+#![no_std]
+
+use contract::contract_api::{runtime, storage};
+use types::Key;
+
+const NAMED_UREF_NAME: &str = "named_uref";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let named_uref = storage::new_uref(1u64);
+    // These are never put under a named key, so nothing can reach them once this returns.
+    storage::new_uref(2u64);
+    storage::new_uref(3u64);
+    runtime::put_key(NAMED_UREF_NAME, Key::from(named_uref));
+}
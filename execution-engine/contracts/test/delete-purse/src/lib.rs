@@ -0,0 +1,91 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{account, runtime, system},
+    ext_ffi,
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::ToBytes, ApiError, Key, URef, U512};
+
+const PURSE_TO_DELETE: &str = "purse:to_delete";
+const METHOD_CREATE: &str = "create";
+const METHOD_DELETE: &str = "delete";
+const METHOD_DELETE_ADD_ONLY: &str = "delete_add_only";
+const METHOD_DELETE_ACCOUNT: &str = "delete_account";
+
+#[repr(u16)]
+enum Args {
+    Method = 0,
+    Amount = 1,
+}
+
+#[repr(u16)]
+enum Error {
+    TransferToDeletedPurse = 0,
+    BalanceOfDeletedPurse = 1,
+    UnknownMethod = 2,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+fn get_purse_to_delete() -> URef {
+    runtime::get_key(PURSE_TO_DELETE)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method: String = runtime::get_arg(Args::Method as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+
+    match method.as_str() {
+        METHOD_CREATE => {
+            let purse = system::create_purse();
+            system::transfer_from_purse_to_purse(main_purse, purse, amount).unwrap_or_revert();
+            runtime::put_key(PURSE_TO_DELETE, purse.into());
+        }
+        METHOD_DELETE => {
+            let purse = get_purse_to_delete();
+            system::delete_purse(purse, main_purse).unwrap_or_revert();
+
+            // The deleted purse should no longer be usable
+            if system::transfer_from_purse_to_purse(main_purse, purse, amount).is_ok() {
+                runtime::revert(Error::TransferToDeletedPurse);
+            }
+            if system::get_balance(purse).is_some() {
+                runtime::revert(Error::BalanceOfDeletedPurse);
+            }
+        }
+        METHOD_DELETE_ADD_ONLY => {
+            // Deleting through an ADD-only uref should fail
+            let purse = get_purse_to_delete().into_add_only();
+            system::delete_purse(purse, main_purse).unwrap_or_revert();
+        }
+        METHOD_DELETE_ACCOUNT => {
+            // The account is writeable by its own session, but only the mint may delete
+            let account_key = Key::Account(runtime::get_caller())
+                .into_bytes()
+                .unwrap_or_revert();
+            unsafe {
+                ext_ffi::delete(account_key.as_ptr(), account_key.len());
+            }
+        }
+        _ => runtime::revert(Error::UnknownMethod),
+    }
+}
@@ -0,0 +1,80 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, ApiError, ContractRef, Key, URef};
+
+const CONTRACT_NAME: &str = "downgrade_uref_stored";
+const READ_ONLY_UREF_NAME: &str = "read_only_uref";
+const METHOD_NEW_READ_ONLY: &str = "new_read_only";
+const METHOD_ESCALATE: &str = "escalate";
+const COMMAND_READ: &str = "read";
+const COMMAND_WRITE: &str = "write";
+const COMMAND_WRITE_ESCALATED: &str = "write_escalated";
+const COMMAND_ESCALATE: &str = "escalate";
+const INITIAL_VALUE: u64 = 1;
+const NEW_VALUE: u64 = 2;
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    NotReadOnly = 1,
+    UnexpectedValue = 2,
+}
+
+fn contract_ref() -> ContractRef {
+    match runtime::get_key(CONTRACT_NAME).unwrap_or_revert_with(ApiError::GetKey) {
+        Key::Hash(hash) => ContractRef::Hash(hash),
+        _ => runtime::revert(ApiError::UnexpectedKeyVariant),
+    }
+}
+
+fn new_read_only_uref() -> URef {
+    let uref: URef = runtime::call_contract(contract_ref(), (METHOD_NEW_READ_ONLY,));
+    if uref.access_rights() != AccessRights::READ {
+        runtime::revert(ApiError::User(Error::NotReadOnly as u16));
+    }
+    uref
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_READ => {
+            let uref = new_read_only_uref();
+            let value: u64 = storage::read(uref)
+                .unwrap_or_revert()
+                .unwrap_or_revert_with(ApiError::ValueNotFound);
+            if value != INITIAL_VALUE {
+                runtime::revert(ApiError::User(Error::UnexpectedValue as u16));
+            }
+            runtime::put_key(READ_ONLY_UREF_NAME, uref.into());
+        }
+        // Should fail, as the uref was returned read-only.
+        COMMAND_WRITE => storage::write(new_read_only_uref(), NEW_VALUE),
+        // Should fail, as the caller was only granted the returned read-only rights.
+        COMMAND_WRITE_ESCALATED => {
+            let uref = new_read_only_uref();
+            storage::write(
+                URef::new(uref.addr(), AccessRights::READ_ADD_WRITE),
+                NEW_VALUE,
+            )
+        }
+        // Should fail, as the callee returns more rights than it was given.
+        COMMAND_ESCALATE => {
+            let uref = storage::new_uref(INITIAL_VALUE).into_read_only();
+            let _: URef = runtime::call_contract(contract_ref(), (METHOD_ESCALATE, uref));
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
@@ -0,0 +1,50 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{AccessRights, ApiError, CLValue, URef};
+
+const CONTRACT_NAME: &str = "downgrade_uref_stored";
+const ENTRY_FUNCTION_NAME: &str = "apply_method";
+const METHOD_NEW_READ_ONLY: &str = "new_read_only";
+const METHOD_ESCALATE: &str = "escalate";
+const INITIAL_VALUE: u64 = 1;
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn apply_method() {
+    let method_name: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let uref = match method_name.as_str() {
+        // Returns a new uref holding `INITIAL_VALUE`, downgraded to read-only.
+        METHOD_NEW_READ_ONLY => storage::new_uref(INITIAL_VALUE).into_read_only(),
+        // Returns the given uref with more rights than it was passed with.
+        METHOD_ESCALATE => {
+            let uref: URef = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            URef::new(uref.addr(), AccessRights::READ_ADD_WRITE)
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownMethod as u16)),
+    };
+
+    runtime::ret(CLValue::from_t(uref).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let key = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, BTreeMap::new()).into();
+    runtime::put_key(CONTRACT_NAME, key);
+}
@@ -1,16 +1,12 @@
 #![no_std]
 
-use contract::{
-    contract_api::{runtime, system},
-    unwrap_or_revert::UnwrapOrRevert,
-};
+use contract::contract_api::{runtime, system};
 use types::{account::PublicKey, ApiError, U512};
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let amount: U512 = runtime::get_arg(0)
-        .unwrap_or_revert_with(ApiError::MissingArgument)
-        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
 
     let public_key = PublicKey::ed25519_from([42; 32]);
     let result = system::transfer_to_account(public_key, amount);
@@ -0,0 +1,83 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::ToBytes, ApiError};
+
+const METHOD_EMIT: &str = "emit";
+const METHOD_FLOOD: &str = "flood";
+const METHOD_EMIT_AND_REVERT: &str = "emit_and_revert";
+const METHOD_EMIT_AND_CALL_REVERTING: &str = "emit_and_call_reverting";
+const TOPIC_FIRST: &str = "first";
+const TOPIC_NESTED: &str = "nested";
+const TOPIC_LAST: &str = "last";
+const TOPIC_FLOOD: &str = "flood";
+
+#[repr(u16)]
+enum Args {
+    Method = 0,
+    Count = 1,
+}
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+    Reverted = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn emit_nested_ext() {
+    runtime::emit_event(TOPIC_NESTED, &[]);
+}
+
+#[no_mangle]
+pub extern "C" fn emit_nested_and_revert_ext() {
+    runtime::emit_event(TOPIC_NESTED, &[]);
+    runtime::revert(ApiError::User(Error::Reverted as u16));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method: String = runtime::get_arg(Args::Method as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let count: u32 = runtime::get_arg(Args::Count as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method.as_str() {
+        // Emits an event carrying `count`, then one from a stored contract, then one more
+        METHOD_EMIT => {
+            let data = count.to_bytes().unwrap_or_revert();
+            runtime::emit_event(TOPIC_FIRST, &data);
+            let pointer = storage::store_function_at_hash("emit_nested_ext", BTreeMap::new());
+            runtime::call_contract::<_, ()>(pointer, ());
+            runtime::emit_event(TOPIC_LAST, &[]);
+        }
+        METHOD_FLOOD => {
+            for index in 0..count {
+                let data = index.to_bytes().unwrap_or_revert();
+                runtime::emit_event(TOPIC_FLOOD, &data);
+            }
+        }
+        METHOD_EMIT_AND_REVERT => {
+            runtime::emit_event(TOPIC_FIRST, &[]);
+            runtime::revert(ApiError::User(Error::Reverted as u16));
+        }
+        // Emits an event, then calls a stored contract which emits one and reverts
+        METHOD_EMIT_AND_CALL_REVERTING => {
+            runtime::emit_event(TOPIC_FIRST, &[]);
+            let pointer =
+                storage::store_function_at_hash("emit_nested_and_revert_ext", BTreeMap::new());
+            runtime::call_contract::<_, ()>(pointer, ());
+            runtime::emit_event(TOPIC_LAST, &[]);
+        }
+        _ => runtime::revert(ApiError::User(Error::UnknownMethod as u16)),
+    }
+}
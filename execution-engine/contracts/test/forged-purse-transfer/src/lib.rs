@@ -0,0 +1,63 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, AccessRights, ApiError, URef, U512, UREF_ADDR_LENGTH};
+
+const COMMAND_PASSED: &str = "passed";
+const COMMAND_FORGED: &str = "forged";
+const COMMAND_FORGED_ADD_ONLY: &str = "forged_add_only";
+
+#[repr(u32)]
+enum Args {
+    Command = 0,
+    Source = 1,
+    Target = 2,
+    Amount = 3,
+}
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+}
+
+fn get_addr_arg() -> [u8; UREF_ADDR_LENGTH] {
+    runtime::get_arg(Args::Source as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Returns the source purse, either as passed in the arguments or fabricated from the address
+/// passed in the arguments.
+fn source_purse(command: &str) -> URef {
+    match command {
+        COMMAND_PASSED => runtime::get_arg(Args::Source as u32)
+            .unwrap_or_revert_with(ApiError::MissingArgument)
+            .unwrap_or_revert_with(ApiError::InvalidArgument),
+        COMMAND_FORGED => URef::new(get_addr_arg(), AccessRights::READ_ADD_WRITE),
+        COMMAND_FORGED_ADD_ONLY => URef::new(get_addr_arg(), AccessRights::ADD),
+        _ => runtime::revert(ApiError::User(Error::UnknownCommand as u16)),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Args::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let target: PublicKey = runtime::get_arg(Args::Target as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let source = source_purse(&command);
+    system::transfer_from_purse_to_account(source, target, amount).unwrap_or_revert();
+}
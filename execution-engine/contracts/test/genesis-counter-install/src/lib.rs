@@ -0,0 +1,44 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, Key, URef};
+
+const ENTRY_FUNCTION_NAME: &str = "counter";
+const COUNT_KEY: &str = "count";
+
+#[no_mangle]
+pub extern "C" fn counter() {
+    let count: URef = runtime::get_key(COUNT_KEY)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+    storage::add(count, 1);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let initial_count: i32 =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+
+    let mut named_keys: BTreeMap<String, Key> = BTreeMap::new();
+    named_keys.insert(
+        String::from(COUNT_KEY),
+        storage::new_uref(initial_count).into(),
+    );
+
+    let key: Key = storage::store_function(ENTRY_FUNCTION_NAME, named_keys)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedContractRefVariant)
+        .into();
+
+    let return_value = CLValue::from_t(key).unwrap_or_revert();
+
+    runtime::ret(return_value);
+}
@@ -0,0 +1,40 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::{runtime, storage};
+use types::ApiError;
+
+const ARGS_LEN_KEY: &str = "args_len";
+const ARG_SIZES_KEY: &str = "arg_sizes";
+
+#[repr(u16)]
+enum Error {
+    MissingArgument = 0,
+    UnexpectedArgument = 1,
+}
+
+fn revert(error: Error) -> ! {
+    runtime::revert(ApiError::User(error as u16))
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let args_len = runtime::get_args_len();
+
+    let arg_sizes: Vec<u32> = (0..args_len as u32)
+        .map(|index| match runtime::get_arg_size(index) {
+            Some(arg_size) => arg_size as u32,
+            None => revert(Error::MissingArgument),
+        })
+        .collect();
+
+    if runtime::get_arg_size(args_len as u32).is_some() {
+        revert(Error::UnexpectedArgument);
+    }
+
+    runtime::put_key(ARGS_LEN_KEY, storage::new_uref(args_len as u32).into());
+    runtime::put_key(ARG_SIZES_KEY, storage::new_uref(arg_sizes).into());
+}
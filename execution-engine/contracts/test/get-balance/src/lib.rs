@@ -0,0 +1,48 @@
+#![no_std]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+#[repr(u16)]
+enum Args {
+    Amount = 0,
+    AddOnly = 1,
+}
+
+#[repr(u16)]
+enum Error {
+    Transfer = 0,
+    MissingBalance = 1,
+    BalanceMismatch = 2,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let add_only: bool = runtime::get_arg(Args::AddOnly as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(main_purse, purse, amount)
+        .unwrap_or_revert_with(ApiError::User(Error::Transfer as u16));
+
+    let purse = if add_only {
+        // Reading the balance through an ADD-only uref should revert
+        purse.into_add_only()
+    } else {
+        purse
+    };
+
+    let balance = system::get_balance(purse)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingBalance as u16));
+    if balance != amount {
+        runtime::revert(ApiError::User(Error::BalanceMismatch as u16));
+    }
+}
@@ -0,0 +1,104 @@
+#![no_std]
+
+extern crate alloc;
+
+// Can be removed once https://github.com/rust-lang/rustfmt/issues/3362 is resolved.
+#[rustfmt::skip]
+use alloc::vec;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use contract::{
+    args,
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, CallStackElement, ContractRef, KEY_HASH_LENGTH};
+
+const LEVEL_2_FUNCTION_NAME: &str = "level_2";
+const LEVEL_3_FUNCTION_NAME: &str = "level_3";
+
+#[repr(u16)]
+enum Error {
+    SessionCallStack = 0,
+    Level2CallStack = 1,
+    NestedCallStack = 2,
+    CallStackAfterReturn = 3,
+}
+
+fn revert(error: Error) -> ! {
+    runtime::revert(ApiError::User(error as u16))
+}
+
+fn contract_element(hash: [u8; KEY_HASH_LENGTH]) -> CallStackElement {
+    CallStackElement::Contract {
+        contract_hash: hash,
+        seed: hash,
+    }
+}
+
+fn store_function(name: &str) -> [u8; KEY_HASH_LENGTH] {
+    match storage::store_function_at_hash(name, BTreeMap::new()) {
+        ContractRef::Hash(hash) => hash,
+        ContractRef::URef(_) => runtime::revert(ApiError::UnexpectedContractRefVariant),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn level_3() {
+    let call_stack = runtime::get_caller_chain();
+    runtime::ret(CLValue::from_t(call_stack).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn level_2() {
+    let level_3_hash: [u8; KEY_HASH_LENGTH] =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+
+    let call_stack = runtime::get_caller_chain();
+    if call_stack.len() != 2 || call_stack[0] != CallStackElement::Account(runtime::get_caller()) {
+        revert(Error::Level2CallStack);
+    }
+
+    // The nested call should see our call stack with only the called contract pushed onto it
+    let nested_call_stack: Vec<CallStackElement> =
+        runtime::call_contract(ContractRef::Hash(level_3_hash), args!());
+    let mut expected_nested_call_stack = call_stack.clone();
+    expected_nested_call_stack.push(contract_element(level_3_hash));
+    if nested_call_stack != expected_nested_call_stack {
+        revert(Error::NestedCallStack);
+    }
+
+    if runtime::get_caller_chain() != call_stack {
+        revert(Error::CallStackAfterReturn);
+    }
+
+    runtime::ret(CLValue::from_t(nested_call_stack).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let account = CallStackElement::Account(runtime::get_caller());
+
+    let call_stack = runtime::get_caller_chain();
+    if call_stack != vec![account] {
+        revert(Error::SessionCallStack);
+    }
+
+    let level_2_hash = store_function(LEVEL_2_FUNCTION_NAME);
+    let level_3_hash = store_function(LEVEL_3_FUNCTION_NAME);
+
+    let nested_call_stack: Vec<CallStackElement> =
+        runtime::call_contract(ContractRef::Hash(level_2_hash), args!(level_3_hash));
+    let expected_nested_call_stack = vec![
+        account,
+        contract_element(level_2_hash),
+        contract_element(level_3_hash),
+    ];
+    if nested_call_stack != expected_nested_call_stack {
+        revert(Error::NestedCallStack);
+    }
+
+    if runtime::get_caller_chain() != call_stack {
+        revert(Error::CallStackAfterReturn);
+    }
+}
@@ -0,0 +1,65 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError};
+
+const DEPLOY_HASH_KEY: &str = "deploy_hash";
+const ACCOUNT_PUBLIC_KEY_KEY: &str = "account_public_key";
+const NESTED_FUNCTION_NAME: &str = "nested";
+
+#[repr(u16)]
+enum Error {
+    NestedDeployHashMismatch = 0,
+    NestedAccountPublicKeyMismatch = 1,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+enum Arg {
+    DeployHash = 0,
+    AccountPublicKey = 1,
+}
+
+/// Checks that a stored contract sees the same deploy hash and account as the session code which
+/// called it.
+#[no_mangle]
+pub extern "C" fn nested() {
+    let deploy_hash: [u8; 32] = runtime::get_arg(Arg::DeployHash as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let account_public_key: PublicKey = runtime::get_arg(Arg::AccountPublicKey as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if runtime::get_deploy_hash() != deploy_hash {
+        runtime::revert(Error::NestedDeployHashMismatch);
+    }
+    if runtime::get_account_public_key() != account_public_key {
+        runtime::revert(Error::NestedAccountPublicKeyMismatch);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let deploy_hash = runtime::get_deploy_hash();
+    let account_public_key = runtime::get_account_public_key();
+    runtime::put_key(DEPLOY_HASH_KEY, storage::new_uref(deploy_hash).into());
+    runtime::put_key(
+        ACCOUNT_PUBLIC_KEY_KEY,
+        storage::new_uref(account_public_key).into(),
+    );
+
+    let nested = storage::store_function_at_hash(NESTED_FUNCTION_NAME, BTreeMap::new());
+    runtime::call_contract::<_, ()>(nested, (deploy_hash, account_public_key));
+}
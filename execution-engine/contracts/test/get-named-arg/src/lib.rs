@@ -0,0 +1,41 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, U512};
+
+const ARG_GREETING: &str = "greeting";
+const ARG_AMOUNT: &str = "amount";
+
+#[repr(u16)]
+enum Error {
+    MissingGreeting = 0,
+    MissingAmount,
+    InvalidGreeting,
+    InvalidAmount,
+    GreetingNotAtPosition0,
+    AmountNotAtPosition1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let greeting: String = runtime::get_named_arg(ARG_GREETING)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingGreeting as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidGreeting as u16));
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT)
+        .unwrap_or_revert_with(ApiError::User(Error::MissingAmount as u16))
+        .unwrap_or_revert_with(ApiError::User(Error::InvalidAmount as u16));
+
+    // Named arguments can also be read by the position in which they were given.
+    let greeting_at_0: Option<String> = runtime::get_arg(0).and_then(Result::ok);
+    if greeting_at_0 != Some(greeting) {
+        runtime::revert(ApiError::User(Error::GreetingNotAtPosition0 as u16));
+    }
+    let amount_at_1: Option<U512> = runtime::get_arg(1).and_then(Result::ok);
+    if amount_at_1 != Some(amount) {
+        runtime::revert(ApiError::User(Error::AmountNotAtPosition1 as u16));
+    }
+}
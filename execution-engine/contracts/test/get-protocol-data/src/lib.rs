@@ -0,0 +1,31 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+/// Reverted with if the chain name doesn't match, distinct from any protocol major version used in
+/// tests.
+const UNEXPECTED_CHAIN_NAME: u16 = u16::max_value();
+
+enum Arg {
+    ExpectedChainName = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let expected_chain_name: String = runtime::get_arg(Arg::ExpectedChainName as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let protocol_info = runtime::get_protocol_data();
+    if protocol_info.chain_name() != expected_chain_name {
+        runtime::revert(ApiError::User(UNEXPECTED_CHAIN_NAME));
+    }
+
+    let major = protocol_info.protocol_version().value().major;
+    runtime::revert(ApiError::User(major as u16));
+}
@@ -0,0 +1,49 @@
+#![no_std]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, SystemContractType, U512};
+
+/// The names under which new accounts are given references to the system contracts.
+const SYSTEM_CONTRACT_NAMES: [&str; 2] = ["mint", "pos"];
+const METHOD_BALANCE: &str = "balance";
+
+#[repr(u16)]
+enum Error {
+    BalanceNotFound = 0,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // The system contracts are resolved from the protocol data, so don't rely on these
+    for name in SYSTEM_CONTRACT_NAMES.iter() {
+        runtime::remove_key(name);
+    }
+
+    for system_contract in &[
+        SystemContractType::Mint,
+        SystemContractType::ProofOfStake,
+        SystemContractType::StandardPayment,
+    ] {
+        let access_rights = system::get_system_contract(*system_contract)
+            .into_uref()
+            .unwrap_or_revert_with(ApiError::UnexpectedContractRefVariant)
+            .access_rights();
+        assert!(access_rights.is_readable());
+        assert!(!access_rights.is_addable());
+        assert!(!access_rights.is_writeable());
+    }
+
+    let mint = system::get_system_contract(SystemContractType::Mint);
+    let main_purse = account::get_main_purse();
+    let balance: Option<U512> = runtime::call_contract(mint, (METHOD_BALANCE, main_purse));
+    balance.unwrap_or_revert_with(Error::BalanceNotFound);
+}
@@ -0,0 +1,14 @@
+#![no_std]
+
+use contract::contract_api::{runtime, storage, system};
+use types::{Key, U512};
+
+const METHOD_TOTAL_SUPPLY: &str = "total_supply";
+const TOTAL_SUPPLY_UREF_NAME: &str = "total_supply";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let total_supply: U512 = runtime::call_contract(system::get_mint(), (METHOD_TOTAL_SUPPLY,));
+    let total_supply_uref = storage::new_uref(total_supply);
+    runtime::put_key(TOTAL_SUPPLY_UREF_NAME, Key::from(total_supply_uref));
+}
@@ -0,0 +1,14 @@
+#![no_std]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{bytesrepr::ToBytes, ApiError};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let wasm_costs = runtime::get_wasm_costs();
+
+    // The contract deliberately reverts with the cost of a regular opcode as its user error code,
+    // and with the serialized costs as the payload.
+    let payload = wasm_costs.to_bytes().unwrap_or_revert();
+    runtime::revert_with_payload(ApiError::User(wasm_costs.regular as u16), &payload);
+}
@@ -0,0 +1,87 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key};
+
+const COMMAND_CHECK: &str = "check";
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_HAS_KEY: &str = "has_key";
+const COMMAND_LIST_NAMED_KEYS: &str = "list_named_keys";
+
+const ASCII_NAME: &str = "hello";
+// Contains multi-byte UTF-8 sequences.
+const NON_ASCII_NAME: &str = "schlüssel-鍵-🔑";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    PresentBeforePut = 1,
+    MissingAfterPut = 2,
+    NotRemoved = 3,
+    PresentAfterRemove = 4,
+    RemovedTwice = 5,
+}
+
+enum Arg {
+    Command = 0,
+    KeyCount = 1,
+}
+
+fn revert(error: Error) -> ! {
+    runtime::revert(ApiError::User(error as u16))
+}
+
+fn check(name: &str) {
+    if runtime::has_key(name) {
+        revert(Error::PresentBeforePut);
+    }
+    runtime::put_key(name, storage::new_uref(()).into());
+    if !runtime::has_key(name) {
+        revert(Error::MissingAfterPut);
+    }
+    if !runtime::remove_key_checked(name) {
+        revert(Error::NotRemoved);
+    }
+    if runtime::has_key(name) {
+        revert(Error::PresentAfterRemove);
+    }
+    if runtime::remove_key_checked(name) {
+        revert(Error::RemovedTwice);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Arg::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_CHECK => {
+            check(ASCII_NAME);
+            check(NON_ASCII_NAME);
+        }
+        COMMAND_PUT_KEYS => {
+            let key_count: u32 = runtime::get_arg(Arg::KeyCount as u32)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            for i in 0..key_count {
+                runtime::put_key(&format!("key-{}", i), Key::Hash([0; 32]));
+            }
+        }
+        COMMAND_HAS_KEY => {
+            runtime::has_key(ASCII_NAME);
+        }
+        COMMAND_LIST_NAMED_KEYS => {
+            runtime::list_named_keys();
+        }
+        _ => revert(Error::UnknownCommand),
+    }
+}
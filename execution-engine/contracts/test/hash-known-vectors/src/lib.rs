@@ -0,0 +1,30 @@
+#![no_std]
+
+use contract::contract_api::{crypto, runtime};
+use types::ApiError;
+
+const INPUT: &[u8] = b"abc";
+const EXPECTED_BLAKE2B_DIGEST: [u8; 32] = [
+    0xbd, 0xdd, 0x81, 0x3c, 0x63, 0x42, 0x39, 0x72, 0x31, 0x71, 0xef, 0x3f, 0xee, 0x98, 0x57, 0x9b,
+    0x94, 0x96, 0x4e, 0x3b, 0xb1, 0xcb, 0x3e, 0x42, 0x72, 0x62, 0xc8, 0xc0, 0x68, 0xd5, 0x23, 0x19,
+];
+const EXPECTED_KECCAK256_DIGEST: [u8; 32] = [
+    0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67,
+    0xc0, 0xd1, 0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+];
+
+#[repr(u16)]
+enum Error {
+    Blake2bMismatch = 0,
+    Keccak256Mismatch = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    if crypto::blake2b(INPUT) != EXPECTED_BLAKE2B_DIGEST {
+        runtime::revert(ApiError::User(Error::Blake2bMismatch as u16));
+    }
+    if crypto::keccak256(INPUT) != EXPECTED_KECCAK256_DIGEST {
+        runtime::revert(ApiError::User(Error::Keccak256Mismatch as u16));
+    }
+}
@@ -0,0 +1,28 @@
+#![no_std]
+
+use core::convert::TryFrom;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, EngineFeature};
+
+enum Arg {
+    Feature = 0,
+    Expected = 1,
+}
+
+/// Reverts unless the engine feature with the index given as the first argument is turned on or
+/// off as given by the second argument.
+#[no_mangle]
+pub extern "C" fn call() {
+    let feature_index: u32 = runtime::get_arg(Arg::Feature as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let expected: bool = runtime::get_arg(Arg::Expected as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let feature = EngineFeature::try_from(feature_index).unwrap_or_revert();
+    if runtime::is_feature_enabled(feature) != expected {
+        runtime::revert(ApiError::User(0));
+    }
+}
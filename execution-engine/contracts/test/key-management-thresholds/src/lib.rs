@@ -70,6 +70,46 @@ pub extern "C" fn call() {
         // Sets action threshodl
         account::set_action_threshold(ActionType::KeyManagement, Weight::new(100))
             .unwrap_or_revert();
+    } else if stage == "test-lockout-prevented" {
+        // Has to be executed by an account with a single key of weight 1 and thresholds of 1
+        let heavy_key = PublicKey::ed25519_from([45; 32]);
+        account::add_associated_key(heavy_key, Weight::new(2)).unwrap_or_revert();
+
+        match account::set_action_threshold(ActionType::KeyManagement, Weight::new(4)) {
+            Ok(_) => runtime::revert(ApiError::User(600)),
+            Err(SetThresholdFailure::InsufficientTotalWeight) => {}
+            Err(_) => runtime::revert(ApiError::User(601)),
+        }
+        // A threshold equal to the total weight is reachable
+        account::set_action_threshold(ActionType::KeyManagement, Weight::new(3)).unwrap_or_revert();
+        account::set_action_threshold(ActionType::KeyManagement, Weight::new(2)).unwrap_or_revert();
+        match account::set_action_threshold(ActionType::Deployment, Weight::new(3)) {
+            Ok(_) => runtime::revert(ApiError::User(602)),
+            Err(SetThresholdFailure::DeploymentThreshold) => {}
+            Err(_) => runtime::revert(ApiError::User(603)),
+        }
+        account::set_action_threshold(ActionType::Deployment, Weight::new(2)).unwrap_or_revert();
+        match account::set_action_threshold(ActionType::KeyManagement, Weight::new(1)) {
+            Ok(_) => runtime::revert(ApiError::User(604)),
+            Err(SetThresholdFailure::KeyManagementThreshold) => {}
+            Err(_) => runtime::revert(ApiError::User(605)),
+        }
+
+        // Without the heavy key, the total weight would fall below the thresholds
+        match account::remove_associated_key(heavy_key) {
+            Ok(_) => runtime::revert(ApiError::User(606)),
+            Err(RemoveKeyFailure::ThresholdViolation) => {}
+            Err(_) => runtime::revert(ApiError::User(607)),
+        }
+        match account::update_associated_key(heavy_key, Weight::new(0)) {
+            Ok(_) => runtime::revert(ApiError::User(608)),
+            Err(UpdateKeyFailure::ThresholdViolation) => {}
+            Err(_) => runtime::revert(ApiError::User(609)),
+        }
+        // Leaves a total weight equal to the thresholds
+        account::update_associated_key(heavy_key, Weight::new(1)).unwrap_or_revert();
+    } else if stage == "test-insufficient-total-weight" {
+        account::set_action_threshold(ActionType::KeyManagement, Weight::new(2)).unwrap_or_revert();
     } else {
         runtime::revert(ApiError::User(1))
     }
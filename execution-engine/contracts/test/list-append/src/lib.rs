@@ -0,0 +1,52 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key, URef};
+
+const LIST_NAME: &str = "list";
+
+const COMMAND_CREATE: &str = "create";
+const COMMAND_APPEND: &str = "append";
+const COMMAND_OVERWRITE: &str = "overwrite";
+
+enum Arg {
+    Command = 0,
+    Value,
+}
+
+fn get_value() -> u64 {
+    runtime::get_arg(Arg::Value as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+fn get_list_uref() -> URef {
+    runtime::get_key(LIST_NAME)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::GetKey)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Arg::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_CREATE => {
+            let list_uref = storage::new_uref(Vec::<u64>::new());
+            runtime::put_key(LIST_NAME, Key::from(list_uref));
+        }
+        COMMAND_APPEND => storage::list_append(get_list_uref(), get_value()),
+        // Replaces the list with a plain `u64`
+        COMMAND_OVERWRITE => storage::write(get_list_uref(), get_value()),
+        _ => runtime::revert(ApiError::InvalidArgument),
+    }
+}
@@ -14,6 +14,7 @@ pub const LOCAL_KEY: [u8; 32] = [66u8; 32];
 
 const CMD_WRITE: &str = "write";
 const CMD_ADD: &str = "add";
+const CMD_READ: &str = "read";
 
 const INITIAL_VALUE: u64 = 10;
 const ADD_VALUE: u64 = 5;
@@ -29,5 +30,9 @@ pub extern "C" fn call() {
         storage::write_local(LOCAL_KEY, INITIAL_VALUE);
     } else if command == CMD_ADD {
         storage::add_local(LOCAL_KEY, ADD_VALUE);
+    } else if command == CMD_READ {
+        let _value: u64 = storage::read_local(&LOCAL_KEY)
+            .unwrap_or_revert()
+            .unwrap_or_revert_with(ApiError::ValueNotFound);
     }
 }
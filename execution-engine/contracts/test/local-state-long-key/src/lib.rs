@@ -0,0 +1,15 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use contract::contract_api::storage;
+use types::KEY_LOCAL_MAX_KEY_BYTES;
+
+#[no_mangle]
+pub extern "C" fn call() {
+    // Serializing the `Vec` adds a length prefix, pushing the key bytes over the limit
+    let key = vec![1u8; KEY_LOCAL_MAX_KEY_BYTES];
+    storage::write_local(key, ());
+}
@@ -1,16 +1,20 @@
 #![no_std]
 
-use contract::{
-    contract_api::{account, runtime},
-    unwrap_or_revert::UnwrapOrRevert,
-};
-use types::{ApiError, URef};
+use contract::contract_api::{account, runtime};
+use types::{ApiError, CLType, CLValueError, URef};
 
 #[no_mangle]
 pub extern "C" fn call() {
-    let known_main_purse: URef = runtime::get_arg(0)
-        .unwrap_or_revert_with(ApiError::MissingArgument)
-        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let known_main_purse: URef = match runtime::get_typed_arg(0) {
+        Some(Ok(purse)) => purse,
+        Some(Err(CLValueError::Type(mismatch))) => {
+            assert_eq!(mismatch.expected, CLType::URef);
+            assert_eq!(runtime::get_arg_type(0), Some(mismatch.found));
+            runtime::revert(ApiError::InvalidArgument)
+        }
+        Some(Err(CLValueError::Serialization(error))) => runtime::revert(error),
+        None => runtime::revert(ApiError::MissingArgument),
+    };
     let main_purse: URef = account::get_main_purse();
     assert_eq!(
         main_purse, known_main_purse,
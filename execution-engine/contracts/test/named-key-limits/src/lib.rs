@@ -0,0 +1,93 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, format, string::String};
+
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, ContractRef, Key};
+
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_PUT_KEY_WITH_NAME_LENGTH: &str = "put_key_with_name_length";
+const COMMAND_REMOVE_AND_PUT: &str = "remove_and_put";
+const COMMAND_PUT_KEYS_NESTED: &str = "put_keys_nested";
+const COMMAND_STORE_WITH_NAMED_KEYS: &str = "store_with_named_keys";
+const COMMAND_CREATE_PURSES: &str = "create_purses";
+const PUT_KEYS_FUNCTION_NAME: &str = "put_keys_ext";
+const REPLACEMENT_KEY_NAME: &str = "replacement_key";
+
+enum Arg {
+    Command = 0,
+    First = 1,
+    Second = 2,
+}
+
+fn get_u32_arg(arg: Arg) -> u32 {
+    runtime::get_arg(arg as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+fn key_name(index: u32) -> String {
+    format!("key_{}", index)
+}
+
+/// Puts `count` keys named `key_<index>`, with indices starting at `offset`.
+fn put_keys(offset: u32, count: u32) {
+    for index in offset..offset + count {
+        runtime::put_key(&key_name(index), Key::Hash([0; 32]));
+    }
+}
+
+/// Puts keys into the named keys of the stored contract, taking the offset and count of the keys
+/// as arguments.
+#[no_mangle]
+pub extern "C" fn put_keys_ext() {
+    let offset: u32 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let count: u32 = runtime::get_arg(1)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    put_keys(offset, count);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Arg::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if command == COMMAND_PUT_KEYS {
+        put_keys(0, get_u32_arg(Arg::First));
+    } else if command == COMMAND_PUT_KEY_WITH_NAME_LENGTH {
+        let name: String = (0..get_u32_arg(Arg::First)).map(|_| 'a').collect();
+        runtime::put_key(&name, Key::Hash([0; 32]));
+    } else if command == COMMAND_REMOVE_AND_PUT {
+        runtime::remove_key(&key_name(0));
+        runtime::put_key(REPLACEMENT_KEY_NAME, Key::Hash([0; 32]));
+    } else if command == COMMAND_PUT_KEYS_NESTED {
+        // Each call puts `count` keys into the contract's own named keys, which start out empty.
+        let calls = get_u32_arg(Arg::First);
+        let count = get_u32_arg(Arg::Second);
+        let contract_ref = storage::store_function_at_hash(PUT_KEYS_FUNCTION_NAME, BTreeMap::new());
+        for call_index in 0..calls {
+            runtime::call_contract::<_, ()>(contract_ref.clone(), (call_index * count, count));
+        }
+    } else if command == COMMAND_STORE_WITH_NAMED_KEYS {
+        let named_keys = (0..get_u32_arg(Arg::First))
+            .map(|index| (key_name(index), Key::Hash([0; 32])))
+            .collect();
+        storage::store_function_at_hash(PUT_KEYS_FUNCTION_NAME, named_keys);
+    } else if command == COMMAND_CREATE_PURSES {
+        // The mint keeps a named key per purse, but the purses aren't put in the account's.
+        for _ in 0..get_u32_arg(Arg::First) {
+            system::create_purse();
+        }
+    } else {
+        runtime::revert(ApiError::User(0));
+    }
+}
@@ -0,0 +1,93 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{format, string::String, vec::Vec};
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, Key};
+
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_CHECK_CHUNKS: &str = "check_chunks";
+const COMMAND_TAKE_THREE: &str = "take_three";
+const COMMAND_LIST_NAMED_KEYS: &str = "list_named_keys";
+
+#[repr(u16)]
+enum Error {
+    UnknownCommand = 0,
+    ChunkTooLong = 1,
+    PartialChunkBeforeEnd = 2,
+    ChunksMismatch = 3,
+    IterMismatch = 4,
+    TooFewKeys = 5,
+}
+
+enum Arg {
+    Command = 0,
+    Count = 1,
+}
+
+fn revert(error: Error) -> ! {
+    runtime::revert(ApiError::User(error as u16))
+}
+
+fn get_count() -> u32 {
+    runtime::get_arg(Arg::Count as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+/// Reads the named keys chunk by chunk, checking that only the last chunk is shorter than
+/// `chunk_size`, and that both the chunks and the iterator yield what `list_named_keys` does.
+fn check_chunks(chunk_size: usize) {
+    let expected: Vec<(String, Key)> = runtime::list_named_keys().into_iter().collect();
+
+    let mut chunked = Vec::new();
+    loop {
+        let (chunk, more) = runtime::list_named_keys_chunk(chunked.len(), chunk_size);
+        if chunk.len() > chunk_size {
+            revert(Error::ChunkTooLong);
+        }
+        if more && chunk.len() < chunk_size {
+            revert(Error::PartialChunkBeforeEnd);
+        }
+        chunked.extend(chunk);
+        if !more {
+            break;
+        }
+    }
+    if chunked != expected {
+        revert(Error::ChunksMismatch);
+    }
+
+    let iterated: Vec<(String, Key)> =
+        runtime::NamedKeysIter::with_chunk_size(chunk_size).collect();
+    if iterated != expected {
+        revert(Error::IterMismatch);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Arg::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_PUT_KEYS => {
+            for i in 0..get_count() {
+                runtime::put_key(&format!("key-{}", i), Key::Hash([0; 32]));
+            }
+        }
+        COMMAND_CHECK_CHUNKS => check_chunks(get_count() as usize),
+        COMMAND_TAKE_THREE => {
+            if runtime::named_keys_iter().take(3).count() != 3 {
+                revert(Error::TooFewKeys);
+            }
+        }
+        COMMAND_LIST_NAMED_KEYS => {
+            runtime::list_named_keys();
+        }
+        _ => revert(Error::UnknownCommand),
+    }
+}
@@ -24,13 +24,19 @@ fn unbond(pos: &ContractRef, amount: Option<U512>) {
     runtime::call_contract::<_, ()>(pos.clone(), (POS_UNBOND, amount));
 }
 
+fn withdraw_unbonded(pos: &ContractRef) {
+    runtime::call_contract::<_, ()>(pos.clone(), (POS_WITHDRAW_UNBONDED,));
+}
+
 const POS_BOND: &str = "bond";
 const POS_UNBOND: &str = "unbond";
+const POS_WITHDRAW_UNBONDED: &str = "withdraw_unbonded";
 
 const TEST_BOND: &str = "bond";
 const TEST_BOND_FROM_MAIN_PURSE: &str = "bond-from-main-purse";
 const TEST_SEED_NEW_ACCOUNT: &str = "seed_new_account";
 const TEST_UNBOND: &str = "unbond";
+const TEST_WITHDRAW_UNBONDED: &str = "withdraw_unbonded";
 
 #[no_mangle]
 pub extern "C" fn call() {
@@ -71,6 +77,8 @@ pub extern "C" fn call() {
             .unwrap_or_revert_with(ApiError::MissingArgument)
             .unwrap_or_revert_with(ApiError::InvalidArgument);
         unbond(&pos_pointer, maybe_amount);
+    } else if command == TEST_WITHDRAW_UNBONDED {
+        withdraw_unbonded(&pos_pointer);
     } else {
         runtime::revert(ApiError::User(Error::UnknownCommand as u16));
     }
@@ -0,0 +1,21 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::contract_api::{runtime, storage, system};
+use types::{account::PublicKey, U512};
+
+const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
+const REWARD_SPLITS_KEY: &str = "reward_splits";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let pos = system::get_proof_of_stake();
+    let splits: BTreeMap<PublicKey, (U512, U512)> =
+        runtime::call_contract(pos, (METHOD_DISTRIBUTE_REWARDS,));
+
+    let splits_uref = storage::new_uref(splits);
+    runtime::put_key(REWARD_SPLITS_KEY, splits_uref.into());
+}
@@ -0,0 +1,19 @@
+#![no_std]
+
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+const POS_SET_COMMISSION: &str = "set_commission";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let rate: u32 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let pos_pointer = system::get_proof_of_stake();
+    runtime::call_contract::<_, ()>(pos_pointer, (POS_SET_COMMISSION, rate));
+}
@@ -0,0 +1,27 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+const METHOD_SLASH: &str = "slash";
+const SLASHED_AMOUNTS_KEY: &str = "slashed_amounts";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let validators: Vec<PublicKey> = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let pos = system::get_proof_of_stake();
+    let slashed_amounts: Vec<U512> = runtime::call_contract(pos, (METHOD_SLASH, validators));
+
+    let slashed_amounts_uref = storage::new_uref(slashed_amounts);
+    runtime::put_key(SLASHED_AMOUNTS_KEY, slashed_amounts_uref.into());
+}
@@ -0,0 +1,95 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{key_versions_name, versioned_key_name, ApiError, Key};
+
+const CONFIG_NAME: &str = "config";
+const CONFIG_VERSIONS: [Key; 3] = [Key::Hash([1; 32]), Key::Hash([2; 32]), Key::Hash([3; 32])];
+const COMMAND_PUT: &str = "put";
+const COMMAND_REMOVE: &str = "remove";
+const COMMAND_WRITE_VERSIONS: &str = "write_versions";
+const COMMAND_PUT_VERSIONS: &str = "put_versions";
+const COMMAND_REMOVE_VERSIONS: &str = "remove_versions";
+const COMMAND_PUT_ARCHIVED: &str = "put_archived";
+
+#[repr(u16)]
+enum Args {
+    Command = 0,
+}
+
+#[repr(u16)]
+enum Error {
+    UnexpectedVersion = 0,
+    UnexpectedKey = 1,
+    VersionNotRemoved = 2,
+    UnknownCommand = 3,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let command: String = runtime::get_arg(Args::Command as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match command.as_str() {
+        COMMAND_PUT => put_config_versions(),
+        COMMAND_REMOVE => {
+            runtime::remove_key(CONFIG_NAME);
+            for version in 1..=CONFIG_VERSIONS.len() as u32 {
+                if runtime::get_key_version(CONFIG_NAME, version).is_some() {
+                    runtime::revert(Error::VersionNotRemoved);
+                }
+            }
+        }
+        // The version count and the archived versions can only be changed by `put_key_versioned`
+        // and `remove_key` on `CONFIG_NAME` itself, so each of these reverts.
+        COMMAND_WRITE_VERSIONS => {
+            let versions_uref = runtime::get_key(&key_versions_name(CONFIG_NAME))
+                .and_then(Key::into_uref)
+                .unwrap_or_revert_with(ApiError::GetKey);
+            storage::write(versions_uref, 1u32);
+        }
+        COMMAND_PUT_VERSIONS => {
+            runtime::put_key(&key_versions_name(CONFIG_NAME), Key::Hash([0; 32]))
+        }
+        COMMAND_REMOVE_VERSIONS => runtime::remove_key(&key_versions_name(CONFIG_NAME)),
+        COMMAND_PUT_ARCHIVED => {
+            runtime::put_key(&versioned_key_name(CONFIG_NAME, 1), Key::Hash([0; 32]))
+        }
+        _ => runtime::revert(Error::UnknownCommand),
+    }
+}
+
+fn put_config_versions() {
+    for (index, key) in CONFIG_VERSIONS.iter().enumerate() {
+        if runtime::put_key_versioned(CONFIG_NAME, *key) != index as u32 + 1 {
+            runtime::revert(Error::UnexpectedVersion);
+        }
+    }
+
+    for (index, key) in CONFIG_VERSIONS.iter().enumerate() {
+        if runtime::get_key_version(CONFIG_NAME, index as u32 + 1) != Some(*key) {
+            runtime::revert(Error::UnexpectedKey);
+        }
+    }
+    let next_version = CONFIG_VERSIONS.len() as u32 + 1;
+    if runtime::get_key_version(CONFIG_NAME, 0).is_some()
+        || runtime::get_key_version(CONFIG_NAME, next_version).is_some()
+        || runtime::get_key(CONFIG_NAME) != Some(CONFIG_VERSIONS[2])
+    {
+        runtime::revert(Error::UnexpectedKey);
+    }
+}
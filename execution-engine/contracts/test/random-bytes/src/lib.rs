@@ -0,0 +1,40 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::CLValue;
+
+const RANDOM_1_KEY: &str = "random_1";
+const RANDOM_2_KEY: &str = "random_2";
+const NESTED_1_KEY: &str = "nested_1";
+const NESTED_2_KEY: &str = "nested_2";
+const NESTED_FUNCTION_NAME: &str = "nested";
+
+fn put_value(name: &str, random_bytes: [u8; 32]) {
+    runtime::put_key(name, storage::new_uref(random_bytes).into());
+}
+
+/// Returns random bytes to the caller.
+#[no_mangle]
+pub extern "C" fn nested() {
+    let return_value = CLValue::from_t(runtime::random_bytes()).unwrap_or_revert();
+    runtime::ret(return_value)
+}
+
+/// Stores random bytes from two calls in the session code and from two calls to a stored
+/// contract.
+#[no_mangle]
+pub extern "C" fn call() {
+    put_value(RANDOM_1_KEY, runtime::random_bytes());
+    put_value(RANDOM_2_KEY, runtime::random_bytes());
+
+    let nested = storage::store_function_at_hash(NESTED_FUNCTION_NAME, BTreeMap::new());
+    put_value(NESTED_1_KEY, runtime::call_contract(nested.clone(), ()));
+    put_value(NESTED_2_KEY, runtime::call_contract(nested, ()));
+}
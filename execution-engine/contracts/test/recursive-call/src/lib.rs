@@ -0,0 +1,62 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, ContractRef, KEY_HASH_LENGTH};
+
+const RECURSE_FUNCTION_NAME: &str = "recurse";
+
+enum Arg {
+    ContractHash = 0,
+    RemainingCalls = 1,
+}
+
+fn store_recurse() -> [u8; KEY_HASH_LENGTH] {
+    match storage::store_function_at_hash(RECURSE_FUNCTION_NAME, BTreeMap::new()) {
+        ContractRef::Hash(hash) => hash,
+        ContractRef::URef(_) => runtime::revert(ApiError::UnexpectedContractRefVariant),
+    }
+}
+
+fn call_recurse(contract_hash: [u8; KEY_HASH_LENGTH], remaining_calls: u32) {
+    runtime::call_contract::<_, ()>(
+        ContractRef::Hash(contract_hash),
+        (contract_hash, remaining_calls),
+    );
+}
+
+/// Calls itself until `remaining_calls` reaches zero.
+#[no_mangle]
+pub extern "C" fn recurse() {
+    let contract_hash: [u8; KEY_HASH_LENGTH] = runtime::get_arg(Arg::ContractHash as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let remaining_calls: u32 = runtime::get_arg(Arg::RemainingCalls as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    if remaining_calls > 0 {
+        call_recurse(contract_hash, remaining_calls - 1);
+    }
+}
+
+/// Reaches the call depth given as the only argument, where this session code runs at depth 1.
+#[no_mangle]
+pub extern "C" fn call() {
+    let call_depth: u32 = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    if call_depth <= 1 {
+        return;
+    }
+
+    let contract_hash = store_recurse();
+    // The first call is at depth 2, so `call_depth - 2` calls remain after it
+    call_recurse(contract_hash, call_depth - 2);
+}
@@ -0,0 +1,43 @@
+#![no_std]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue, U512};
+
+#[repr(u16)]
+enum Args {
+    FirstAmount = 0,
+    SecondAmount = 1,
+}
+
+#[repr(u16)]
+enum Error {
+    Transfer = 0,
+    MissingBalance = 1,
+}
+
+fn get_amount(arg: Args) -> U512 {
+    runtime::get_arg(arg as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument)
+}
+
+fn fund_purse(amount: U512) -> U512 {
+    let main_purse = account::get_main_purse();
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(main_purse, purse, amount)
+        .unwrap_or_revert_with(ApiError::User(Error::Transfer as u16));
+    system::get_balance(purse).unwrap_or_revert_with(ApiError::User(Error::MissingBalance as u16))
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let first_amount = get_amount(Args::FirstAmount);
+    let second_amount = get_amount(Args::SecondAmount);
+
+    let balance_sum = fund_purse(first_amount) + fund_purse(second_amount);
+    let return_value = CLValue::from_t(balance_sum).unwrap_or_revert();
+    runtime::ret(return_value)
+}
@@ -0,0 +1,60 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, Key};
+
+const CONTRACT_NAME: &str = "return_large_blob";
+
+#[repr(u16)]
+enum Error {
+    UnexpectedBlob = 0,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+enum Arg {
+    BlobSize = 0,
+    BufferSize = 1,
+    Iterations = 2,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let blob_size: u32 = runtime::get_arg(Arg::BlobSize as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let buffer_size: u32 = runtime::get_arg(Arg::BufferSize as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let iterations: u32 = runtime::get_arg(Arg::Iterations as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let contract_ref = runtime::get_key(CONTRACT_NAME)
+        .and_then(Key::to_contract_ref)
+        .unwrap_or_revert_with(ApiError::GetKey);
+
+    for _ in 0..iterations {
+        let blob: Vec<u8> = runtime::call_contract_with_buffer_size(
+            contract_ref.clone(),
+            (blob_size,),
+            buffer_size as usize,
+        );
+        let is_expected_blob = blob.len() == blob_size as usize
+            && blob
+                .iter()
+                .enumerate()
+                .all(|(index, byte)| *byte == index as u8);
+        if !is_expected_blob {
+            runtime::revert(Error::UnexpectedBlob);
+        }
+    }
+}
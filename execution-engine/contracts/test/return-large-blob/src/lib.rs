@@ -0,0 +1,33 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, CLValue};
+
+const CONTRACT_NAME: &str = "return_large_blob";
+const ENTRY_FUNCTION_NAME: &str = "return_blob";
+
+enum Arg {
+    BlobSize = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn return_blob() {
+    let blob_size: u32 = runtime::get_arg(Arg::BlobSize as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let blob: Vec<u8> = (0..blob_size).map(|index| index as u8).collect();
+    runtime::ret(CLValue::from_t(blob).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_ref = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, BTreeMap::new());
+    runtime::put_key(CONTRACT_NAME, contract_ref.into());
+}
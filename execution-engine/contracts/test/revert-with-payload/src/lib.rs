@@ -0,0 +1,17 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use contract::contract_api::runtime;
+use types::ApiError;
+
+const REVERT_ERROR: ApiError = ApiError::User(0);
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let payload: Vec<u8> =
+        runtime::get_arg_checked(0, ApiError::MissingArgument, ApiError::InvalidArgument);
+    runtime::revert_with_payload(REVERT_ERROR, &payload)
+}
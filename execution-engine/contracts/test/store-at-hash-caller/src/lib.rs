@@ -0,0 +1,24 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::{ApiError, ContractRef};
+
+enum Arg {
+    ContractHash = 0,
+    Greeting = 1,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: [u8; 32] = runtime::get_arg(Arg::ContractHash as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let greeting: String = runtime::get_arg(Arg::Greeting as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    runtime::call_contract::<_, ()>(ContractRef::Hash(contract_hash), (greeting,));
+}
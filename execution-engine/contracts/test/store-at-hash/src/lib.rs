@@ -0,0 +1,65 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, Key};
+
+const CONTRACT_NAME: &str = "store_at_hash";
+const ENTRY_FUNCTION_NAME: &str = "greet";
+const GREETING_KEY: &str = "greeting";
+const MARKER_KEY: &str = "marker";
+const INITIAL_GREETING: &str = "hello";
+const MARKER: Key = Key::Hash([7; 32]);
+
+#[repr(u16)]
+enum Error {
+    MissingMarker = 0,
+    UnexpectedGreeting = 1,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+enum Arg {
+    Greeting = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn greet() {
+    if runtime::get_key(MARKER_KEY) != Some(MARKER) {
+        runtime::revert(Error::MissingMarker);
+    }
+
+    let greeting_uref = runtime::get_key(GREETING_KEY)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert_with(ApiError::GetKey);
+    let current_greeting: String = storage::read_or_revert(greeting_uref);
+    if current_greeting != INITIAL_GREETING {
+        runtime::revert(Error::UnexpectedGreeting);
+    }
+
+    let new_greeting: String = runtime::get_arg(Arg::Greeting as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    storage::write(greeting_uref, new_greeting);
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut named_keys = BTreeMap::new();
+    let greeting_uref = storage::new_uref(String::from(INITIAL_GREETING));
+    named_keys.insert(String::from(GREETING_KEY), greeting_uref.into());
+    named_keys.insert(String::from(MARKER_KEY), MARKER);
+
+    let contract_ref = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, named_keys);
+    runtime::put_key(CONTRACT_NAME, contract_ref.into());
+}
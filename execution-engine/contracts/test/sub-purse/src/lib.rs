@@ -0,0 +1,96 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, URef, U512};
+
+const PARENT_PURSE: &str = "purse:parent";
+const SUB_PURSE: &str = "purse:sub";
+const RECIPIENT_PURSE: &str = "purse:recipient";
+const METHOD_CREATE: &str = "create";
+const METHOD_FUND: &str = "fund";
+const METHOD_SPEND: &str = "spend";
+const METHOD_REVOKE: &str = "revoke";
+const METHOD_NEST: &str = "nest";
+const METHOD_DELETE: &str = "delete";
+
+#[repr(u16)]
+enum Args {
+    Method = 0,
+    Amount = 1,
+    Cap = 2,
+}
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+fn get_purse(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert_with(ApiError::GetKey)
+        .into_uref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method: String = runtime::get_arg(Args::Method as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+
+    match method.as_str() {
+        // Funds a new parent purse with `amount` and creates a sub-purse of it capped at `cap`
+        METHOD_CREATE => {
+            let cap: U512 = runtime::get_arg(Args::Cap as u32)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let parent = system::create_purse();
+            system::transfer_from_purse_to_purse(main_purse, parent, amount).unwrap_or_revert();
+            let sub_purse = system::create_sub_purse(parent, cap).unwrap_or_revert();
+            runtime::put_key(PARENT_PURSE, parent.into());
+            runtime::put_key(SUB_PURSE, sub_purse.into());
+            runtime::put_key(RECIPIENT_PURSE, system::create_purse().into());
+        }
+        METHOD_FUND => {
+            let sub_purse = get_purse(SUB_PURSE);
+            system::transfer_from_purse_to_purse(main_purse, sub_purse, amount).unwrap_or_revert();
+        }
+        METHOD_SPEND => {
+            let sub_purse = get_purse(SUB_PURSE);
+            let recipient = get_purse(RECIPIENT_PURSE);
+            system::transfer_from_purse_to_purse(sub_purse, recipient, amount).unwrap_or_revert();
+        }
+        METHOD_REVOKE => {
+            let parent = get_purse(PARENT_PURSE);
+            let sub_purse = get_purse(SUB_PURSE);
+            system::revoke_sub_purse(parent, sub_purse).unwrap_or_revert();
+        }
+        METHOD_NEST => {
+            let sub_purse = get_purse(SUB_PURSE);
+            system::create_sub_purse(sub_purse, amount).unwrap_or_revert();
+        }
+        METHOD_DELETE => {
+            let sub_purse = get_purse(SUB_PURSE);
+            system::delete_purse(sub_purse, main_purse).unwrap_or_revert();
+        }
+        _ => runtime::revert(Error::UnknownMethod),
+    }
+}
@@ -0,0 +1,48 @@
+#![no_std]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, U512};
+
+const PURSE_TARGET: &str = "purse:target";
+
+#[repr(u16)]
+enum Args {
+    Amount = 0,
+    TransferId = 1,
+    TransferCount = 2,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let transfer_id: Option<u64> = runtime::get_arg(Args::TransferId as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let transfer_count: u32 = runtime::get_arg(Args::TransferCount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let main_purse = account::get_main_purse();
+    // Reused by later deploys, so that they transfer into a purse which already has records
+    let target_purse = match runtime::get_key(PURSE_TARGET) {
+        Some(key) => key
+            .into_uref()
+            .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant),
+        None => {
+            let target_purse = system::create_purse();
+            runtime::put_key(PURSE_TARGET, target_purse.into());
+            target_purse
+        }
+    };
+
+    // Every transfer uses the same id, so each should still be recorded individually
+    for _ in 0..transfer_count {
+        system::transfer_from_purse_to_purse_with_id(main_purse, target_purse, amount, transfer_id)
+            .unwrap_or_revert();
+    }
+}
@@ -0,0 +1,48 @@
+#![no_std]
+
+use contract::{
+    contract_api::{runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{
+    account::{PublicKey, Weight},
+    ApiError, U512,
+};
+
+#[repr(u32)]
+enum Args {
+    AccountPublicKey = 0,
+    Amount = 1,
+    InitialWeight = 2,
+    DeploymentThreshold = 3,
+    KeyManagementThreshold = 4,
+}
+
+fn get_weight(arg: Args) -> Weight {
+    let value: u8 = runtime::get_arg(arg as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    Weight::new(value)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let public_key: PublicKey = runtime::get_arg(Args::AccountPublicKey as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let amount: U512 = runtime::get_arg(Args::Amount as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+    let initial_weight = get_weight(Args::InitialWeight);
+    let deployment_threshold = get_weight(Args::DeploymentThreshold);
+    let key_management_threshold = get_weight(Args::KeyManagementThreshold);
+
+    system::transfer_to_account_with_config(
+        public_key,
+        amount,
+        initial_weight,
+        deployment_threshold,
+        key_management_threshold,
+    )
+    .unwrap_or_revert();
+}
@@ -0,0 +1,17 @@
+#![no_std]
+
+use contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use types::ApiError;
+
+const CONTRACT_NAME: &str = "update_key_stored";
+const METHOD_UPDATE_WITH_NESTED_CONFLICT: &str = "update_with_nested_conflict";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_key = runtime::get_key(CONTRACT_NAME).unwrap_or_revert_with(ApiError::GetKey);
+    let contract = contract_key
+        .to_contract_ref()
+        .unwrap_or_revert_with(ApiError::UnexpectedKeyVariant);
+
+    runtime::call_contract::<_, ()>(contract, (METHOD_UPDATE_WITH_NESTED_CONFLICT, contract_key));
+}
@@ -0,0 +1,81 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{ApiError, ContractRef, Key, UpdateKeyError};
+
+const CONTRACT_NAME: &str = "update_key_stored";
+const ENTRY_FUNCTION_NAME: &str = "delegate";
+const ENTRY_NAME: &str = "entry";
+const INITIAL_ENTRY: Key = Key::Hash([1; 32]);
+const NESTED_ENTRY: Key = Key::Hash([2; 32]);
+const OUTER_ENTRY: Key = Key::Hash([3; 32]);
+
+const METHOD_UPDATE: &str = "update";
+const METHOD_UPDATE_WITH_NESTED_CONFLICT: &str = "update_with_nested_conflict";
+
+#[repr(u16)]
+enum Error {
+    UnknownMethod = 0,
+    NestedUpdateFailed = 1,
+    ConflictNotDetected = 2,
+}
+
+impl Into<ApiError> for Error {
+    fn into(self) -> ApiError {
+        ApiError::User(self as u16)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn delegate() {
+    let method: String = runtime::get_arg(0)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    match method.as_str() {
+        // Type of this method: `fn update(expected: Option<Key>, new: Key)`
+        METHOD_UPDATE => {
+            let expected: Option<Key> = runtime::get_arg(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let new: Key = runtime::get_arg(2)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            runtime::update_key(ENTRY_NAME, expected, new)
+                .unwrap_or_else(|_| runtime::revert(Error::NestedUpdateFailed));
+        }
+        // Type of this method: `fn update_with_nested_conflict(this: Key)`
+        METHOD_UPDATE_WITH_NESTED_CONFLICT => {
+            let this: ContractRef = runtime::get_arg::<Key>(1)
+                .unwrap_or_revert_with(ApiError::MissingArgument)
+                .unwrap_or_revert_with(ApiError::InvalidArgument)
+                .to_contract_ref()
+                .unwrap_or_revert_with(ApiError::InvalidArgument);
+            let expected = runtime::get_key(ENTRY_NAME);
+
+            // The nested call updates the entry while this call still holds the previous value.
+            runtime::call_contract::<_, ()>(this, (METHOD_UPDATE, expected, NESTED_ENTRY));
+
+            match runtime::update_key(ENTRY_NAME, expected, OUTER_ENTRY) {
+                Err(UpdateKeyError::KeyMismatch) => {}
+                _ => runtime::revert(Error::ConflictNotDetected),
+            }
+        }
+        _ => runtime::revert(Error::UnknownMethod),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(String::from(ENTRY_NAME), INITIAL_ENTRY);
+    let key = storage::store_function_at_hash(ENTRY_FUNCTION_NAME, named_keys).into();
+    runtime::put_key(CONTRACT_NAME, key);
+}
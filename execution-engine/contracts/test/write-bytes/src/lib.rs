@@ -0,0 +1,26 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::ApiError;
+
+#[repr(u16)]
+enum Args {
+    Length = 0,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let length: u32 = runtime::get_arg(Args::Length as u32)
+        .unwrap_or_revert_with(ApiError::MissingArgument)
+        .unwrap_or_revert_with(ApiError::InvalidArgument);
+
+    let uref = storage::new_uref(());
+    storage::write(uref, vec![0u8; length as usize]);
+}
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+
+use engine_shared::{additive_map::AdditiveMap, transform::Transform};
+use types::Key;
+
+use super::{execution_result::ExecutionResult, op::Op};
+
+/// Whether applying `first` then `second` to a value gives the same result as applying them the
+/// other way round.
+///
+/// `Transform::Identity` leaves the value as it is, so commutes with any transform.  That doesn't
+/// mean the deploy didn't read the value though, which is checked from its ops instead.
+fn transforms_commute(first: &Transform, second: &Transform) -> bool {
+    match (first, second) {
+        (Transform::Identity, _) | (_, Transform::Identity) => true,
+        (Transform::AddInt32(_), Transform::AddInt32(_))
+        | (Transform::AddUInt64(_), Transform::AddUInt64(_))
+        | (Transform::AddUInt128(_), Transform::AddUInt128(_))
+        | (Transform::AddUInt256(_), Transform::AddUInt256(_))
+        | (Transform::AddUInt512(_), Transform::AddUInt512(_))
+        | (Transform::AddKeys(_), Transform::AddKeys(_)) => true,
+        // Writing the same value twice, or deleting twice, leaves the same state in either order.
+        (Transform::Write(_), Transform::Write(_)) | (Transform::Delete, Transform::Delete) => {
+            first == second
+        }
+        _ => false,
+    }
+}
+
+/// What became of a single deploy of a batch run by
+/// [`EngineState::run_deploys`](super::EngineState::run_deploys).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeployOutcome {
+    /// The deploy's effect is included in the merged effect of the batch.
+    Executed,
+    /// The deploy's effect was left out of the merged effect of the batch, as its transform of
+    /// `key` doesn't commute with that of the earlier deploy at index `conflicting_deploy`, or as
+    /// it read `key`, which that deploy wrote or added to.
+    Skipped { key: Key, conflicting_deploy: usize },
+}
+
+impl DeployOutcome {
+    pub fn is_executed(&self) -> bool {
+        match self {
+            DeployOutcome::Executed => true,
+            DeployOutcome::Skipped { .. } => false,
+        }
+    }
+
+    pub fn is_skipped(&self) -> bool {
+        !self.is_executed()
+    }
+}
+
+/// The outcome of [`EngineState::run_deploys`](super::EngineState::run_deploys).
+///
+/// The execution results and outcomes line up by index with the deploys of the batch.
+#[derive(Debug)]
+pub struct BatchResult {
+    execution_results: Vec<ExecutionResult>,
+    outcomes: Vec<DeployOutcome>,
+    transforms: AdditiveMap<Key, Transform>,
+}
+
+impl BatchResult {
+    /// Merges the effects of `execution_results` in order, skipping any deploy whose effect
+    /// doesn't commute with that of a deploy merged before it, or which read a key that such a
+    /// deploy changed.
+    pub(crate) fn new(execution_results: Vec<ExecutionResult>) -> Self {
+        let mut outcomes = Vec::with_capacity(execution_results.len());
+        let mut ops: AdditiveMap<Key, Op> = AdditiveMap::new();
+        let mut transforms = AdditiveMap::new();
+        // The index of the deploy which last transformed each merged key.
+        let mut merged_by: HashMap<Key, usize> = HashMap::new();
+
+        for (index, execution_result) in execution_results.iter().enumerate() {
+            let deploy_ops = &execution_result.effect().ops;
+            let deploy_transforms = &execution_result.effect().transforms;
+
+            // Every deploy is executed against the same pre-state, so one which read a key changed
+            // by an earlier deploy may have acted on a stale value.
+            let read_conflict = deploy_ops.iter().find_map(|(key, op)| {
+                if *op != Op::Read {
+                    return None;
+                }
+                match ops.get(key)? {
+                    Op::Write | Op::Add => (),
+                    Op::Read | Op::NoOp => return None,
+                }
+                let conflicting_deploy = *merged_by.get(key)?;
+                Some(DeployOutcome::Skipped {
+                    key: *key,
+                    conflicting_deploy,
+                })
+            });
+            let conflict = read_conflict.or_else(|| {
+                deploy_transforms.iter().find_map(|(key, transform)| {
+                    let merged_transform = transforms.get(key)?;
+                    if transforms_commute(merged_transform, transform) {
+                        return None;
+                    }
+                    let conflicting_deploy = *merged_by.get(key)?;
+                    Some(DeployOutcome::Skipped {
+                        key: *key,
+                        conflicting_deploy,
+                    })
+                })
+            });
+            if let Some(outcome) = conflict {
+                outcomes.push(outcome);
+                continue;
+            }
+
+            for (key, op) in deploy_ops.iter() {
+                ops.insert_add(*key, *op);
+            }
+            for (key, transform) in deploy_transforms.iter() {
+                transforms.insert_add(*key, transform.clone());
+                if *transform != Transform::Identity {
+                    merged_by.insert(*key, index);
+                }
+            }
+            outcomes.push(DeployOutcome::Executed);
+        }
+
+        BatchResult {
+            execution_results,
+            outcomes,
+            transforms,
+        }
+    }
+
+    pub fn execution_results(&self) -> &[ExecutionResult] {
+        &self.execution_results
+    }
+
+    pub fn into_execution_results(self) -> Vec<ExecutionResult> {
+        self.execution_results
+    }
+
+    pub fn outcomes(&self) -> &[DeployOutcome] {
+        &self.outcomes
+    }
+
+    /// Returns the indices of the deploys whose effects were merged.
+    pub fn executed(&self) -> impl Iterator<Item = usize> + '_ {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| outcome.is_executed())
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the indices of the deploys whose effects were left out due to conflicts.
+    pub fn skipped(&self) -> impl Iterator<Item = usize> + '_ {
+        self.outcomes
+            .iter()
+            .enumerate()
+            .filter(|(_, outcome)| outcome.is_skipped())
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the merged transforms of all executed deploys, which can be committed in one go.
+    pub fn transforms(&self) -> &AdditiveMap<Key, Transform> {
+        &self.transforms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::{gas::Gas, stored_value::StoredValue};
+    use types::{CLValue, U512};
+
+    use super::*;
+    use crate::engine_state::execution_effect::ExecutionEffect;
+
+    const KEY_1: Key = Key::Hash([1; 32]);
+    const KEY_2: Key = Key::Hash([2; 32]);
+
+    fn write(value: u64) -> Transform {
+        Transform::Write(StoredValue::CLValue(CLValue::from_t(value).unwrap()))
+    }
+
+    /// Returns the op the tracking copy records along with `transform`.
+    fn op_of(transform: &Transform) -> Op {
+        match transform {
+            Transform::Identity => Op::Read,
+            Transform::Write(_) | Transform::Delete => Op::Write,
+            _ => Op::Add,
+        }
+    }
+
+    fn execution_result(transforms: Vec<(Key, Transform)>) -> ExecutionResult {
+        let mut effect = ExecutionEffect::default();
+        for (key, transform) in transforms {
+            effect.ops.insert(key, op_of(&transform));
+            effect.transforms.insert(key, transform);
+        }
+        ExecutionResult::Success {
+            effect,
+            cost: Gas::default(),
+            ret: None,
+            phases: None,
+        }
+    }
+
+    #[test]
+    fn should_merge_commutative_transforms() {
+        let batch_result = BatchResult::new(vec![
+            execution_result(vec![(KEY_1, Transform::AddUInt512(U512::from(1)))]),
+            execution_result(vec![
+                (KEY_1, Transform::AddUInt512(U512::from(2))),
+                (KEY_2, Transform::Identity),
+            ]),
+            execution_result(vec![(KEY_2, write(3))]),
+        ]);
+
+        assert_eq!(batch_result.outcomes(), &[DeployOutcome::Executed; 3]);
+        assert_eq!(
+            batch_result.transforms().get(&KEY_1),
+            Some(&Transform::AddUInt512(U512::from(3)))
+        );
+        assert_eq!(batch_result.transforms().get(&KEY_2), Some(&write(3)));
+    }
+
+    #[test]
+    fn should_skip_deploys_reading_keys_changed_by_earlier_deploys() {
+        let batch_result = BatchResult::new(vec![
+            execution_result(vec![(KEY_1, write(1))]),
+            execution_result(vec![(KEY_2, Transform::AddUInt64(1))]),
+            execution_result(vec![(KEY_1, Transform::Identity)]),
+            execution_result(vec![(KEY_2, Transform::Identity)]),
+        ]);
+
+        assert_eq!(
+            batch_result.outcomes(),
+            &[
+                DeployOutcome::Executed,
+                DeployOutcome::Executed,
+                DeployOutcome::Skipped {
+                    key: KEY_1,
+                    conflicting_deploy: 0
+                },
+                DeployOutcome::Skipped {
+                    key: KEY_2,
+                    conflicting_deploy: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_merge_identical_writes() {
+        let batch_result = BatchResult::new(vec![
+            execution_result(vec![(KEY_1, write(1))]),
+            execution_result(vec![(KEY_1, write(1))]),
+        ]);
+
+        assert_eq!(batch_result.skipped().count(), 0);
+        assert_eq!(batch_result.transforms().get(&KEY_1), Some(&write(1)));
+    }
+
+    #[test]
+    fn should_skip_deploys_with_conflicting_transforms() {
+        let batch_result = BatchResult::new(vec![
+            execution_result(vec![(KEY_1, write(1))]),
+            execution_result(vec![(KEY_2, Transform::AddUInt64(1))]),
+            execution_result(vec![(KEY_2, Transform::AddUInt64(2)), (KEY_1, write(2))]),
+            execution_result(vec![(KEY_2, write(3))]),
+        ]);
+
+        assert_eq!(
+            batch_result.outcomes(),
+            &[
+                DeployOutcome::Executed,
+                DeployOutcome::Executed,
+                DeployOutcome::Skipped {
+                    key: KEY_1,
+                    conflicting_deploy: 0
+                },
+                DeployOutcome::Skipped {
+                    key: KEY_2,
+                    conflicting_deploy: 1
+                },
+            ]
+        );
+        assert_eq!(batch_result.executed().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(batch_result.execution_results().len(), 4);
+        // Nothing of the skipped deploys is merged
+        assert_eq!(batch_result.transforms().get(&KEY_1), Some(&write(1)));
+        assert_eq!(
+            batch_result.transforms().get(&KEY_2),
+            Some(&Transform::AddUInt64(1))
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::{cell::RefCell, mem, rc::Rc};
+
+use types::{ApiError, Key, Phase};
+
+/// An event emitted via `runtime::emit_event` by payment or session code, or by a contract either
+/// of them called.
+///
+/// Events are recorded in the execution results rather than in global state, for consumption by
+/// off-chain indexers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractEvent {
+    /// The key of the account or contract whose code emitted the event.
+    pub emitter: Key,
+    /// The phase in which the event was emitted.
+    pub phase: Phase,
+    /// The topic given by the emitter, e.g. `"transfer"`.
+    pub topic: String,
+    /// The data given by the emitter, usually serialized with `bytesrepr`.
+    pub data: Vec<u8>,
+}
+
+impl ContractEvent {
+    /// Returns the number of bytes of the event counted against
+    /// [`EngineConfig::max_event_bytes`](super::EngineConfig::max_event_bytes).
+    fn size(&self) -> usize {
+        self.topic.len() + self.data.len()
+    }
+}
+
+/// The events emitted during a single execution, shared by the runtimes of the contracts called
+/// during it so that nested calls' events are recorded in the order they were emitted.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EventLog(Rc<RefCell<Vec<ContractEvent>>>);
+
+impl EventLog {
+    /// Appends `event`, unless that would take the log over `max_events` events or `max_bytes`
+    /// bytes in total, in which case [`ApiError::EventLimitExceeded`] is returned.
+    pub(crate) fn push(
+        &self,
+        event: ContractEvent,
+        max_events: usize,
+        max_bytes: usize,
+    ) -> Result<(), ApiError> {
+        let mut events = self.0.borrow_mut();
+        let total_bytes: usize = events.iter().map(ContractEvent::size).sum();
+        if events.len() >= max_events || total_bytes + event.size() > max_bytes {
+            return Err(ApiError::EventLimitExceeded);
+        }
+        events.push(event);
+        Ok(())
+    }
+
+    /// Returns the number of events emitted so far.
+    pub(crate) fn event_count(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Discards all but the first `event_count` events, e.g. those emitted by a failed call.
+    pub(crate) fn truncate(&self, event_count: usize) {
+        self.0.borrow_mut().truncate(event_count)
+    }
+
+    /// Returns the events emitted so far, leaving the log empty.
+    pub(crate) fn take(&self) -> Vec<ContractEvent> {
+        mem::take(&mut *self.0.borrow_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(topic: &str, data: &[u8]) -> ContractEvent {
+        ContractEvent {
+            emitter: Key::Hash([1; 32]),
+            phase: Phase::Session,
+            topic: topic.to_string(),
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn should_enforce_limits() {
+        let log = EventLog::default();
+        assert_eq!(log.push(event("a", &[1, 2]), 2, 6), Ok(()));
+        // Three more bytes would exceed the limit of six
+        let result = log.push(event("b", &[3, 4]), 2, 5);
+        assert_eq!(result, Err(ApiError::EventLimitExceeded));
+        assert_eq!(log.push(event("b", &[3, 4]), 2, 6), Ok(()));
+        let result = log.push(event("", &[]), 2, 6);
+        assert_eq!(result, Err(ApiError::EventLimitExceeded));
+
+        let topics: Vec<String> = log.take().into_iter().map(|event| event.topic).collect();
+        assert_eq!(topics, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(log.event_count(), 0);
+    }
+
+    #[test]
+    fn should_discard_events_after_truncation_point() {
+        let log = EventLog::default();
+        let nested_log = log.clone();
+        log.push(event("outer", &[]), 10, 100).unwrap();
+        let event_count = log.event_count();
+        nested_log.push(event("inner", &[]), 10, 100).unwrap();
+        assert_eq!(log.event_count(), 2);
+
+        log.truncate(event_count);
+        assert_eq!(log.take(), vec![event("outer", &[])]);
+    }
+}
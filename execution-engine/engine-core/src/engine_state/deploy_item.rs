@@ -6,6 +6,16 @@ use crate::{engine_state::executable_deploy_item::ExecutableDeployItem, DeployHa
 
 type GasPrice = u64;
 
+/// A signature over a deploy's hash by one of its signers.  Corresponds to the `DeployApproval` ipc
+/// protobuf message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Approval {
+    pub public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+    /// The algorithm tag, either `"ed25519"` or `"secp256k1"`.
+    pub algorithm: String,
+}
+
 /// Represents a deploy to be executed.  Corresponds to the similarly-named ipc protobuf message.
 #[derive(Clone, PartialEq, Eq)]
 pub struct DeployItem {
@@ -15,6 +25,7 @@ pub struct DeployItem {
     pub gas_price: GasPrice,
     pub authorization_keys: BTreeSet<PublicKey>,
     pub deploy_hash: DeployHash,
+    pub approvals: Vec<Approval>,
 }
 
 impl DeployItem {
@@ -26,6 +37,7 @@ impl DeployItem {
         gas_price: GasPrice,
         authorization_keys: BTreeSet<PublicKey>,
         deploy_hash: DeployHash,
+        approvals: Vec<Approval>,
     ) -> Self {
         DeployItem {
             address,
@@ -34,6 +46,7 @@ impl DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            approvals,
         }
     }
 }
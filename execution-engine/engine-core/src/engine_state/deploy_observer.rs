@@ -0,0 +1,248 @@
+use std::{
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use engine_shared::gas::Gas;
+
+use crate::{engine_state::execution_result::ExecutionResult, DeployHash};
+
+/// The number of buckets of [`DeployMetrics::duration_histogram`].
+pub const TOTAL_DURATION_BUCKETS: usize = 6;
+
+/// The upper bounds in microseconds of the buckets of [`DeployMetrics::duration_histogram`].
+/// Durations above the last bound are counted in the final bucket.
+pub const DURATION_BUCKET_BOUNDS_MICROS: [u64; TOTAL_DURATION_BUCKETS - 1] =
+    [100, 1_000, 10_000, 100_000, 1_000_000];
+
+/// Callbacks run by [`EngineState`](super::EngineState) around the execution of every deploy,
+/// e.g. to collect metrics.
+///
+/// Both callbacks do nothing by default.  A panic in either is caught and logged, so that it
+/// doesn't abort the execution of the deploy.
+pub trait DeployObserver: Debug + Send + Sync {
+    /// Called before `deploy_hash` is executed.
+    fn on_deploy_start(&self, _deploy_hash: &DeployHash) {}
+
+    /// Called after `deploy_hash` was executed with `result`, which took `duration`.
+    fn on_deploy_finish(
+        &self,
+        _deploy_hash: &DeployHash,
+        _result: &ExecutionResult,
+        _duration: Duration,
+    ) {
+    }
+}
+
+/// A [`DeployObserver`] which does nothing.  This is the observer an
+/// [`EngineState`](super::EngineState) is created with.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoOpObserver;
+
+impl DeployObserver for NoOpObserver {}
+
+/// Metrics aggregated over the deploys seen by a [`MetricsObserver`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeployMetrics {
+    /// The number of deploys which started executing.
+    pub deploys_started: u64,
+    /// The number of deploys which finished executing successfully.
+    pub deploys_succeeded: u64,
+    /// The number of deploys which finished executing with an error.
+    pub deploys_failed: u64,
+    /// The total gas charged for the finished deploys.
+    pub total_gas: Gas,
+    /// The most gas charged for a single deploy.
+    pub max_gas: Gas,
+    /// The total number of transforms in the effects of the finished deploys.
+    pub total_transforms: u64,
+    /// The total time spent executing the finished deploys.
+    pub total_duration: Duration,
+    /// The number of finished deploys per duration bucket, as bounded by
+    /// [`DURATION_BUCKET_BOUNDS_MICROS`].
+    pub duration_histogram: [u64; TOTAL_DURATION_BUCKETS],
+}
+
+impl DeployMetrics {
+    /// Returns the number of deploys which finished executing, successfully or not.
+    pub fn deploys_finished(&self) -> u64 {
+        self.deploys_succeeded + self.deploys_failed
+    }
+
+    fn record(&mut self, result: &ExecutionResult, duration: Duration) {
+        if result.is_success() {
+            self.deploys_succeeded += 1;
+        } else {
+            self.deploys_failed += 1;
+        }
+        let gas = result.cost();
+        self.total_gas = self.total_gas + gas;
+        self.max_gas = self.max_gas.max(gas);
+        self.total_transforms += result.effect().transforms.len() as u64;
+        self.total_duration += duration;
+
+        let micros = duration.as_micros();
+        let bucket = DURATION_BUCKET_BOUNDS_MICROS
+            .iter()
+            .position(|bound| micros <= u128::from(*bound))
+            .unwrap_or_else(|| DURATION_BUCKET_BOUNDS_MICROS.len());
+        self.duration_histogram[bucket] += 1;
+    }
+}
+
+/// A [`DeployObserver`] aggregating [`DeployMetrics`].
+///
+/// Clones share the same metrics, so a clone can be installed on the engine while the original is
+/// kept to read them.
+#[derive(Clone, Debug, Default)]
+pub struct MetricsObserver {
+    metrics: Arc<Mutex<DeployMetrics>>,
+}
+
+impl MetricsObserver {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a copy of the metrics aggregated so far.
+    pub fn metrics(&self) -> DeployMetrics {
+        self.metrics.lock().unwrap().clone()
+    }
+
+    /// Clears the metrics aggregated so far.
+    pub fn reset(&self) {
+        *self.metrics.lock().unwrap() = Default::default();
+    }
+}
+
+impl DeployObserver for MetricsObserver {
+    fn on_deploy_start(&self, _deploy_hash: &DeployHash) {
+        self.metrics.lock().unwrap().deploys_started += 1;
+    }
+
+    fn on_deploy_finish(
+        &self,
+        _deploy_hash: &DeployHash,
+        result: &ExecutionResult,
+        duration: Duration,
+    ) {
+        self.metrics.lock().unwrap().record(result, duration);
+    }
+}
+
+/// Runs [`DeployObserver::on_deploy_start`], logging rather than propagating a panic.
+pub(crate) fn notify_deploy_start(observer: &dyn DeployObserver, deploy_hash: &DeployHash) {
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| observer.on_deploy_start(deploy_hash)));
+    if outcome.is_err() {
+        log::warn!(
+            "Deploy observer panicked in on_deploy_start: {:?}",
+            observer
+        );
+    }
+}
+
+/// Runs [`DeployObserver::on_deploy_finish`], logging rather than propagating a panic.
+pub(crate) fn notify_deploy_finish(
+    observer: &dyn DeployObserver,
+    deploy_hash: &DeployHash,
+    result: &ExecutionResult,
+    duration: Duration,
+) {
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+        observer.on_deploy_finish(deploy_hash, result, duration)
+    }));
+    if outcome.is_err() {
+        log::warn!(
+            "Deploy observer panicked in on_deploy_finish: {:?}",
+            observer
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::U512;
+
+    use super::*;
+    use crate::{engine_state::Error, execution};
+
+    const DEPLOY_HASH: DeployHash = [1; 32];
+
+    #[derive(Debug)]
+    struct PanickingObserver;
+
+    impl DeployObserver for PanickingObserver {
+        fn on_deploy_start(&self, _deploy_hash: &DeployHash) {
+            panic!("on_deploy_start")
+        }
+
+        fn on_deploy_finish(&self, _: &DeployHash, _: &ExecutionResult, _: Duration) {
+            panic!("on_deploy_finish")
+        }
+    }
+
+    fn success(cost: u64) -> ExecutionResult {
+        ExecutionResult::Success {
+            effect: Default::default(),
+            cost: Gas::new(U512::from(cost)),
+            ret: None,
+            phases: None,
+        }
+    }
+
+    fn failure(cost: u64) -> ExecutionResult {
+        ExecutionResult::Failure {
+            error: Error::Exec(execution::Error::GasLimit),
+            effect: Default::default(),
+            cost: Gas::new(U512::from(cost)),
+            phases: None,
+        }
+    }
+
+    #[test]
+    fn should_aggregate_metrics() {
+        let observer = MetricsObserver::new();
+        let results = [
+            (success(10), Duration::from_micros(50)),
+            (failure(30), Duration::from_millis(5)),
+            (success(20), Duration::from_secs(2)),
+        ];
+        for (result, duration) in results.iter() {
+            notify_deploy_start(&observer, &DEPLOY_HASH);
+            notify_deploy_finish(&observer, &DEPLOY_HASH, result, *duration);
+        }
+
+        let metrics = observer.metrics();
+        assert_eq!(metrics.deploys_started, 3);
+        assert_eq!(metrics.deploys_finished(), 3);
+        assert_eq!(metrics.deploys_succeeded, 2);
+        assert_eq!(metrics.deploys_failed, 1);
+        assert_eq!(metrics.total_gas, Gas::new(U512::from(60)));
+        assert_eq!(metrics.max_gas, Gas::new(U512::from(30)));
+        assert_eq!(metrics.duration_histogram, [1, 0, 1, 0, 0, 1]);
+
+        observer.reset();
+        assert_eq!(observer.metrics(), DeployMetrics::default());
+    }
+
+    #[test]
+    fn should_share_metrics_between_clones() {
+        let observer = MetricsObserver::new();
+        let installed: Box<dyn DeployObserver> = Box::new(observer.clone());
+        installed.on_deploy_start(&DEPLOY_HASH);
+        assert_eq!(observer.metrics().deploys_started, 1);
+    }
+
+    #[test]
+    fn should_not_propagate_observer_panics() {
+        notify_deploy_start(&PanickingObserver, &DEPLOY_HASH);
+        notify_deploy_finish(
+            &PanickingObserver,
+            &DEPLOY_HASH,
+            &success(1),
+            Duration::default(),
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use std::{cmp::Ordering, collections::BTreeSet};
+
+use engine_shared::{newtypes::CorrelationId, stored_value::StoredValue, transform::Transform};
+use engine_storage::global_state::StateReader;
+use mint::TOTAL_SUPPLY_KEY;
+use types::{account::PublicKey, CLValue, Key, URef, U512, UREF_ADDR_LENGTH};
+
+use super::execution_effect::ExecutionEffect;
+use crate::tracking_copy::TrackingCopy;
+
+/// The change in a purse's balance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BalanceDelta {
+    Increase(U512),
+    Decrease(U512),
+}
+
+impl BalanceDelta {
+    fn new(before: U512, after: U512) -> Self {
+        match after.cmp(&before) {
+            Ordering::Less => BalanceDelta::Decrease(before - after),
+            Ordering::Equal | Ordering::Greater => BalanceDelta::Increase(after - before),
+        }
+    }
+}
+
+/// A single effect of an execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EffectsSummaryEntry {
+    /// The balance held under `purse` changed.
+    ///
+    /// Balances are those held under the urefs named by the mint, so `purse` is the `URef` under
+    /// which the mint holds the balance rather than the purse `URef` itself.
+    BalanceChange { purse: URef, delta: BalanceDelta },
+    /// A named key was added to an account.
+    NamedKeyAdded {
+        account: PublicKey,
+        name: String,
+        key: Key,
+    },
+    /// A contract was stored under a previously unused key.
+    ContractStored { hash: Key },
+    /// A transform which doesn't fit any of the other kinds of entry.
+    Other { key: Key, transform: Transform },
+}
+
+/// A higher-level view of the transforms produced by an execution.
+///
+/// Entries are ordered by the key they refer to.  `Transform::Identity`s only record that a value
+/// was read, so they don't appear in the summary.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EffectsSummary {
+    entries: Vec<EffectsSummaryEntry>,
+}
+
+impl EffectsSummary {
+    /// Summarizes the transforms of `effect`, reading the values they apply to from `pre_state`,
+    /// which should be a tracking copy of the state they were produced against.  `mint` is the
+    /// uref of the mint contract, whose named keys tell which urefs hold balances.
+    pub fn new<R>(
+        correlation_id: CorrelationId,
+        effect: &ExecutionEffect,
+        mint: URef,
+        pre_state: &TrackingCopy<R>,
+    ) -> Result<Self, R::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+    {
+        let balance_addrs = balance_addrs(correlation_id, effect, mint, pre_state)?;
+
+        let mut sorted_transforms: Vec<(&Key, &Transform)> = effect.transforms.iter().collect();
+        sorted_transforms.sort_by_key(|(key, _)| **key);
+
+        let mut entries = Vec::new();
+        for (key, transform) in sorted_transforms {
+            let pre_state_value = match transform {
+                Transform::Identity => continue,
+                Transform::Write(_) => StateReader::read(&pre_state, correlation_id, key)?,
+                _ => None,
+            };
+            entries.extend(summarize(*key, transform, pre_state_value, &balance_addrs));
+        }
+        Ok(EffectsSummary { entries })
+    }
+
+    pub fn entries(&self) -> &[EffectsSummaryEntry] {
+        &self.entries
+    }
+
+    pub fn balance_changes(&self) -> impl Iterator<Item = (URef, BalanceDelta)> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            EffectsSummaryEntry::BalanceChange { purse, delta } => Some((*purse, *delta)),
+            _ => None,
+        })
+    }
+
+    pub fn named_keys_added(&self) -> impl Iterator<Item = (PublicKey, &str, Key)> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            EffectsSummaryEntry::NamedKeyAdded { account, name, key } => {
+                Some((*account, name.as_str(), *key))
+            }
+            _ => None,
+        })
+    }
+
+    pub fn contracts_stored(&self) -> impl Iterator<Item = Key> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            EffectsSummaryEntry::ContractStored { hash } => Some(*hash),
+            _ => None,
+        })
+    }
+
+    pub fn others(&self) -> impl Iterator<Item = (Key, &Transform)> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            EffectsSummaryEntry::Other { key, transform } => Some((*key, transform)),
+            _ => None,
+        })
+    }
+}
+
+/// Returns the addresses of the urefs under which the mint holds balances, both those named by the
+/// mint in `pre_state` and those named by it in `effect`, e.g. those of purses it created.
+fn balance_addrs<R>(
+    correlation_id: CorrelationId,
+    effect: &ExecutionEffect,
+    mint: URef,
+    pre_state: &TrackingCopy<R>,
+) -> Result<BTreeSet<[u8; UREF_ADDR_LENGTH]>, R::Error>
+where
+    R: StateReader<Key, StoredValue>,
+{
+    let mint_key = Key::URef(mint).normalize();
+    let mut named_keys = match StateReader::read(&pre_state, correlation_id, &mint_key)? {
+        Some(StoredValue::Contract(contract)) => contract.named_keys().to_owned(),
+        _ => Default::default(),
+    };
+    match effect.transforms.get(&mint_key) {
+        Some(Transform::AddKeys(added_keys)) => named_keys.extend(added_keys.to_owned()),
+        Some(Transform::Write(StoredValue::Contract(contract))) => {
+            named_keys = contract.named_keys().to_owned()
+        }
+        _ => (),
+    }
+    // The total supply is a `U512` named by the mint too, but not the balance of any purse
+    let balance_addrs = named_keys
+        .iter()
+        .filter(|(name, _)| name.as_str() != TOTAL_SUPPLY_KEY)
+        .filter_map(|(_, key)| key.as_uref().map(URef::addr))
+        .collect();
+    Ok(balance_addrs)
+}
+
+fn as_balance(cl_value: &CLValue) -> Option<U512> {
+    cl_value.to_owned().into_t().ok()
+}
+
+/// Returns the entries for `transform` applied at `key`, or `None` if it doesn't fit any of the
+/// known patterns.
+fn summarize_known(
+    key: Key,
+    transform: &Transform,
+    pre_state_value: Option<&StoredValue>,
+    balance_addrs: &BTreeSet<[u8; UREF_ADDR_LENGTH]>,
+) -> Option<Vec<EffectsSummaryEntry>> {
+    match (key, transform, pre_state_value) {
+        (Key::URef(purse), Transform::AddUInt512(amount), _)
+            if balance_addrs.contains(&purse.addr()) =>
+        {
+            let delta = BalanceDelta::Increase(*amount);
+            Some(vec![EffectsSummaryEntry::BalanceChange { purse, delta }])
+        }
+        (Key::URef(purse), Transform::Write(StoredValue::CLValue(cl_value)), pre_state_value)
+            if balance_addrs.contains(&purse.addr()) =>
+        {
+            let after = as_balance(cl_value)?;
+            let before = match pre_state_value {
+                Some(StoredValue::CLValue(cl_value)) => as_balance(cl_value)?,
+                Some(_) => return None,
+                None => U512::zero(),
+            };
+            let delta = BalanceDelta::new(before, after);
+            Some(vec![EffectsSummaryEntry::BalanceChange { purse, delta }])
+        }
+        (Key::Account(account), Transform::AddKeys(named_keys), _) => Some(
+            named_keys
+                .iter()
+                .map(|(name, key)| EffectsSummaryEntry::NamedKeyAdded {
+                    account,
+                    name: name.to_owned(),
+                    key: *key,
+                })
+                .collect(),
+        ),
+        (
+            Key::Account(account),
+            Transform::Write(StoredValue::Account(new_account)),
+            Some(StoredValue::Account(old_account)),
+        ) => {
+            let entries: Vec<EffectsSummaryEntry> = new_account
+                .named_keys()
+                .iter()
+                .filter(|(name, _)| !old_account.named_keys().contains_key(*name))
+                .map(|(name, key)| EffectsSummaryEntry::NamedKeyAdded {
+                    account,
+                    name: name.to_owned(),
+                    key: *key,
+                })
+                .collect();
+            if entries.is_empty() {
+                None
+            } else {
+                Some(entries)
+            }
+        }
+        (hash, Transform::Write(StoredValue::Contract(_)), None) => {
+            Some(vec![EffectsSummaryEntry::ContractStored { hash }])
+        }
+        _ => None,
+    }
+}
+
+fn summarize(
+    key: Key,
+    transform: &Transform,
+    pre_state_value: Option<StoredValue>,
+    balance_addrs: &BTreeSet<[u8; UREF_ADDR_LENGTH]>,
+) -> Vec<EffectsSummaryEntry> {
+    summarize_known(key, transform, pre_state_value.as_ref(), balance_addrs).unwrap_or_else(|| {
+        vec![EffectsSummaryEntry::Other {
+            key,
+            transform: transform.to_owned(),
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, iter};
+
+    use engine_shared::{account::Account, contract::Contract};
+    use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider};
+    use types::{AccessRights, ProtocolVersion};
+
+    use super::*;
+
+    const PUBLIC_KEY: PublicKey = PublicKey::ed25519_from([1; 32]);
+    const MINT: URef = URef::new([9; 32], AccessRights::READ_ADD_WRITE);
+
+    fn balance(value: u64) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(U512::from(value)).unwrap())
+    }
+
+    /// Returns a mint contract naming the given urefs.
+    fn mint_contract(named_urefs: &[(&str, URef)]) -> StoredValue {
+        let named_keys = named_urefs
+            .iter()
+            .map(|(name, uref)| (name.to_string(), Key::URef(*uref)))
+            .collect();
+        StoredValue::Contract(Contract::new(
+            Vec::new(),
+            named_keys,
+            ProtocolVersion::V1_0_0,
+        ))
+    }
+
+    fn summarize_against(
+        pairs: &[(Key, StoredValue)],
+        transforms: AdditiveMap<Key, Transform>,
+    ) -> EffectsSummary {
+        let correlation_id = CorrelationId::new();
+        let (global_state, root_hash) = InMemoryGlobalState::from_pairs(correlation_id, pairs)
+            .expect("should create global state");
+        let reader = global_state
+            .checkout(root_hash)
+            .expect("should checkout")
+            .expect("should have root");
+        let pre_state = TrackingCopy::new(reader);
+        let effect = ExecutionEffect::new(AdditiveMap::new(), transforms);
+        EffectsSummary::new(correlation_id, &effect, MINT, &pre_state)
+            .expect("should summarize effects")
+    }
+
+    #[test]
+    fn should_summarize_balance_changes() {
+        let source = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let target = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let new_purse = URef::new([4; 32], AccessRights::READ_ADD_WRITE);
+        let mint_key = Key::URef(MINT).normalize();
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(Key::URef(source), Transform::Write(balance(70)));
+        transforms.insert(Key::URef(target), Transform::AddUInt512(U512::from(30)));
+        transforms.insert(Key::URef(new_purse), Transform::Write(balance(5)));
+        // The mint names the balance of the purse it creates
+        transforms.insert(
+            mint_key,
+            Transform::AddKeys(iter::once(("new".to_string(), Key::URef(new_purse))).collect()),
+        );
+        let pairs = [
+            (Key::URef(source), balance(100)),
+            (
+                mint_key,
+                mint_contract(&[("source", source), ("target", target)]),
+            ),
+        ];
+        let summary = summarize_against(&pairs, transforms);
+
+        let balance_changes: Vec<(URef, BalanceDelta)> = summary.balance_changes().collect();
+        assert_eq!(
+            balance_changes,
+            vec![
+                (source, BalanceDelta::Decrease(U512::from(30))),
+                (target, BalanceDelta::Increase(U512::from(30))),
+                (new_purse, BalanceDelta::Increase(U512::from(5))),
+            ]
+        );
+        // The mint's named keys changing doesn't fit any of the known patterns
+        assert_eq!(summary.entries().len(), 4);
+    }
+
+    #[test]
+    fn should_not_take_other_u512s_as_balances() {
+        let total_supply = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let counter = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let mint_key = Key::URef(MINT).normalize();
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(
+            Key::URef(total_supply),
+            Transform::AddUInt512(U512::from(5)),
+        );
+        transforms.insert(Key::URef(counter), Transform::Write(balance(1)));
+        let pairs = [(mint_key, mint_contract(&[(TOTAL_SUPPLY_KEY, total_supply)]))];
+        let summary = summarize_against(&pairs, transforms);
+
+        assert_eq!(summary.balance_changes().count(), 0);
+        assert_eq!(summary.others().count(), 2);
+    }
+
+    #[test]
+    fn should_summarize_named_keys_and_contracts() {
+        let purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let account = Account::create(PUBLIC_KEY, BTreeMap::new(), purse);
+        let contract_hash = Key::Hash([5; 32]);
+        let contract = Contract::new(Vec::new(), BTreeMap::new(), ProtocolVersion::V1_0_0);
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(
+            Key::Account(PUBLIC_KEY),
+            Transform::AddKeys(iter::once(("contract".to_string(), contract_hash)).collect()),
+        );
+        transforms.insert(
+            contract_hash,
+            Transform::Write(StoredValue::Contract(contract)),
+        );
+        let summary = summarize_against(
+            &[(Key::Account(PUBLIC_KEY), StoredValue::Account(account))],
+            transforms,
+        );
+
+        let named_keys_added: Vec<(PublicKey, &str, Key)> = summary.named_keys_added().collect();
+        assert_eq!(
+            named_keys_added,
+            vec![(PUBLIC_KEY, "contract", contract_hash)]
+        );
+        let contracts_stored: Vec<Key> = summary.contracts_stored().collect();
+        assert_eq!(contracts_stored, vec![contract_hash]);
+    }
+
+    #[test]
+    fn should_keep_unknown_transforms_and_skip_reads() {
+        let uref = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        let value = StoredValue::CLValue(CLValue::from_t("text".to_string()).unwrap());
+
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(Key::URef(uref), Transform::Write(value.clone()));
+        transforms.insert(Key::Hash([6; 32]), Transform::Identity);
+        let summary = summarize_against(&[], transforms);
+
+        assert_eq!(
+            summary.entries(),
+            &[EffectsSummaryEntry::Other {
+                key: Key::URef(uref),
+                transform: Transform::Write(value),
+            }]
+        );
+    }
+}
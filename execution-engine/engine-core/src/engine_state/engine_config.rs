@@ -1,9 +1,60 @@
+use types::EngineFeature;
+
+/// The default maximum depth of nested contract calls, counting the session or payment code as
+/// depth 1.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 10;
+
+/// The default maximum number of named keys an account or contract can hold.
+pub const DEFAULT_MAX_NAMED_KEYS: usize = 10_000;
+
+/// The default maximum length in bytes of the name of a named key.
+pub const DEFAULT_MAX_NAMED_KEY_NAME_LENGTH: usize = 256;
+
+/// The default maximum number of preprocessed modules held in the engine's module cache.
+pub const DEFAULT_MODULE_CACHE_SIZE: usize = 32;
+
+/// The default maximum number of events a single payment or session execution can emit.
+pub const DEFAULT_MAX_EVENTS: usize = 100;
+
+/// The default maximum total size in bytes of the topics and data of the events a single payment
+/// or session execution can emit.
+pub const DEFAULT_MAX_EVENT_BYTES: usize = 16 * 1024;
+
 /// The runtime configuration of the execution engine
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone)]
 pub struct EngineConfig {
     // feature flags go here
     use_system_contracts: bool,
     enable_bonding: bool,
+    prune_unreachable_urefs: bool,
+    max_call_depth: usize,
+    max_named_keys: usize,
+    max_named_key_name_length: usize,
+    module_cache_size: usize,
+    max_events: usize,
+    max_event_bytes: usize,
+    trace_host_functions: bool,
+    record_cache_stats: bool,
+    verify_approvals: bool,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        EngineConfig {
+            use_system_contracts: false,
+            enable_bonding: false,
+            prune_unreachable_urefs: false,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            max_named_keys: DEFAULT_MAX_NAMED_KEYS,
+            max_named_key_name_length: DEFAULT_MAX_NAMED_KEY_NAME_LENGTH,
+            module_cache_size: DEFAULT_MODULE_CACHE_SIZE,
+            max_events: DEFAULT_MAX_EVENTS,
+            max_event_bytes: DEFAULT_MAX_EVENT_BYTES,
+            trace_host_functions: false,
+            record_cache_stats: false,
+            verify_approvals: false,
+        }
+    }
 }
 
 impl EngineConfig {
@@ -29,4 +80,129 @@ impl EngineConfig {
         self.enable_bonding = enable_bonding;
         self
     }
+
+    /// Whether `feature` is turned on.  Contracts can query this via the `is_feature_enabled` host
+    /// function.
+    pub fn is_feature_enabled(self, feature: EngineFeature) -> bool {
+        match feature {
+            EngineFeature::UseSystemContracts => self.use_system_contracts,
+            EngineFeature::EnableBonding => self.enable_bonding,
+        }
+    }
+
+    /// Whether writes to `URef`s which were created during an execution but which aren't
+    /// reachable from any other written key are dropped from the execution's effects.
+    pub fn prune_unreachable_urefs(self) -> bool {
+        self.prune_unreachable_urefs
+    }
+
+    pub fn with_prune_unreachable_urefs(mut self, prune_unreachable_urefs: bool) -> EngineConfig {
+        self.prune_unreachable_urefs = prune_unreachable_urefs;
+        self
+    }
+
+    /// The maximum depth of nested contract calls, counting the session or payment code as depth
+    /// 1.  A `call_contract` which would exceed it fails with `Error::CallDepthExceeded`.
+    pub fn max_call_depth(self) -> usize {
+        self.max_call_depth
+    }
+
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> EngineConfig {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
+    /// The maximum number of named keys an account or contract can hold.  A `put_key` which would
+    /// exceed it reverts with `ApiError::TooManyNamedKeys`, while replacing an existing key always
+    /// succeeds.  The mint and proof of stake contracts aren't limited.
+    pub fn max_named_keys(self) -> usize {
+        self.max_named_keys
+    }
+
+    pub fn with_max_named_keys(mut self, max_named_keys: usize) -> EngineConfig {
+        self.max_named_keys = max_named_keys;
+        self
+    }
+
+    /// The maximum length in bytes of the name of a named key.  A `put_key` with a longer name
+    /// reverts with `ApiError::NamedKeyNameTooLong`.
+    pub fn max_named_key_name_length(self) -> usize {
+        self.max_named_key_name_length
+    }
+
+    pub fn with_max_named_key_name_length(
+        mut self,
+        max_named_key_name_length: usize,
+    ) -> EngineConfig {
+        self.max_named_key_name_length = max_named_key_name_length;
+        self
+    }
+
+    /// The maximum number of preprocessed session and payment modules the engine caches, keyed by
+    /// the hash of their bytes and of the wasm costs.  Zero disables the cache.
+    pub fn module_cache_size(self) -> usize {
+        self.module_cache_size
+    }
+
+    pub fn with_module_cache_size(mut self, module_cache_size: usize) -> EngineConfig {
+        self.module_cache_size = module_cache_size;
+        self
+    }
+
+    /// The maximum number of events a single payment or session execution can emit.  An
+    /// `emit_event` beyond it fails with `ApiError::EventLimitExceeded`.
+    pub fn max_events(self) -> usize {
+        self.max_events
+    }
+
+    pub fn with_max_events(mut self, max_events: usize) -> EngineConfig {
+        self.max_events = max_events;
+        self
+    }
+
+    /// The maximum total size in bytes of the topics and data of the events a single payment or
+    /// session execution can emit.  An `emit_event` beyond it fails with
+    /// `ApiError::EventLimitExceeded`.
+    pub fn max_event_bytes(self) -> usize {
+        self.max_event_bytes
+    }
+
+    pub fn with_max_event_bytes(mut self, max_event_bytes: usize) -> EngineConfig {
+        self.max_event_bytes = max_event_bytes;
+        self
+    }
+
+    /// Whether the host functions called by payment and session code are recorded in the
+    /// execution results, for debugging.  The trace doesn't affect global state.
+    pub fn trace_host_functions(self) -> bool {
+        self.trace_host_functions
+    }
+
+    pub fn with_trace_host_functions(mut self, trace_host_functions: bool) -> EngineConfig {
+        self.trace_host_functions = trace_host_functions;
+        self
+    }
+
+    /// Whether the number of global state reads served from the tracking copy's cache, and the
+    /// number which missed it, are recorded in the execution results, for profiling.
+    pub fn record_cache_stats(self) -> bool {
+        self.record_cache_stats
+    }
+
+    pub fn with_record_cache_stats(mut self, record_cache_stats: bool) -> EngineConfig {
+        self.record_cache_stats = record_cache_stats;
+        self
+    }
+
+    /// Whether a deploy must carry a valid approval by each of its authorization keys, and none by
+    /// any other key, to be executed.  When off, approvals aren't checked at all, as when the node
+    /// has verified them already.
+    pub fn verify_approvals(self) -> bool {
+        self.verify_approvals
+    }
+
+    pub fn with_verify_approvals(mut self, verify_approvals: bool) -> EngineConfig {
+        self.verify_approvals = verify_approvals;
+        self
+    }
 }
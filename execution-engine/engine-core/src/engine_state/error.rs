@@ -1,9 +1,12 @@
 use failure::Fail;
 
-use engine_shared::newtypes::Blake2bHash;
+use engine_shared::{account::AuthorizationError, newtypes::Blake2bHash};
 use types::{bytesrepr, system_contract_errors::mint};
 
-use crate::execution;
+use crate::{
+    engine_state::{genesis::GenesisError, signature::SignatureError},
+    execution,
+};
 use types::ProtocolVersion;
 
 #[derive(Fail, Debug)]
@@ -14,6 +17,8 @@ pub enum Error {
     InvalidPublicKeyLength { expected: usize, actual: usize },
     #[fail(display = "Invalid protocol version: {}", _0)]
     InvalidProtocolVersion(ProtocolVersion),
+    #[fail(display = "Failed to read protocol data of version {}: {}", _0, _1)]
+    ProtocolDataRead(ProtocolVersion, String),
     #[fail(display = "Invalid upgrade config")]
     InvalidUpgradeConfig,
     #[fail(display = "Wasm preprocessing error: {}", _0)]
@@ -26,6 +31,8 @@ pub enum Error {
     Storage(engine_storage::error::Error),
     #[fail(display = "Authorization failure: not authorized.")]
     Authorization,
+    #[fail(display = "Authorization failure: {}", _0)]
+    InvalidAuthorization(AuthorizationError),
     #[fail(display = "Insufficient payment")]
     InsufficientPayment,
     #[fail(display = "Deploy error")]
@@ -38,6 +45,12 @@ pub enum Error {
     Serialization(bytesrepr::Error),
     #[fail(display = "Mint error: {}", _0)]
     Mint(mint::Error),
+    #[fail(display = "Signature error: {}", _0)]
+    SignatureError(SignatureError),
+    #[fail(display = "Genesis contract {} failed: {}", name, error)]
+    GenesisContract { name: String, error: Box<Error> },
+    #[fail(display = "Genesis error: {}", _0)]
+    Genesis(GenesisError),
 }
 
 impl From<engine_wasm_prep::PreprocessingError> for Error {
@@ -76,6 +89,18 @@ impl From<mint::Error> for Error {
     }
 }
 
+impl From<SignatureError> for Error {
+    fn from(error: SignatureError) -> Self {
+        Error::SignatureError(error)
+    }
+}
+
+impl From<GenesisError> for Error {
+    fn from(error: GenesisError) -> Self {
+        Error::Genesis(error)
+    }
+}
+
 impl From<!> for Error {
     fn from(error: !) -> Self {
         match error {}
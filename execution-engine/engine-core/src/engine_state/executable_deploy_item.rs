@@ -27,4 +27,13 @@ impl ExecutableDeployItem {
             ExecutableDeployItem::StoredContractByURef { args, .. } => args,
         }
     }
+
+    pub fn args_mut(&mut self) -> &mut Vec<u8> {
+        match self {
+            ExecutableDeployItem::ModuleBytes { args, .. } => args,
+            ExecutableDeployItem::StoredContractByHash { args, .. } => args,
+            ExecutableDeployItem::StoredContractByName { args, .. } => args,
+            ExecutableDeployItem::StoredContractByURef { args, .. } => args,
+        }
+    }
 }
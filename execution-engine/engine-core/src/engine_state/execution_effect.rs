@@ -1,16 +1,35 @@
 use engine_shared::{additive_map::AdditiveMap, transform::Transform};
 use types::Key;
 
-use super::op::Op;
+use super::{contract_event::ContractEvent, host_function_trace::HostFunctionCall, op::Op};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExecutionEffect {
     pub ops: AdditiveMap<Key, Op>,
     pub transforms: AdditiveMap<Key, Transform>,
+    /// Human-readable remarks about the execution which don't affect global state, e.g. that a
+    /// requested refund purse was disregarded.
+    pub notes: Vec<String>,
+    /// The host functions called during the execution, if the engine is configured to trace
+    /// them, and empty otherwise.  Like `notes`, the trace doesn't affect global state.
+    pub host_trace: Vec<HostFunctionCall>,
+    /// The number of reads served from the tracking copy's cache and the number which missed it,
+    /// if the engine is configured to record them, and `None` otherwise.
+    pub cache_stats: Option<(u64, u64)>,
+    /// The events emitted during a successful execution, in the order they were emitted.  Like
+    /// `notes`, events don't affect global state.
+    pub events: Vec<ContractEvent>,
 }
 
 impl ExecutionEffect {
     pub fn new(ops: AdditiveMap<Key, Op>, transforms: AdditiveMap<Key, Transform>) -> Self {
-        ExecutionEffect { ops, transforms }
+        ExecutionEffect {
+            ops,
+            transforms,
+            notes: Vec::new(),
+            host_trace: Vec::new(),
+            cache_stats: None,
+            events: Vec::new(),
+        }
     }
 }
@@ -1,10 +1,16 @@
-use super::{error, execution_effect::ExecutionEffect, op::Op, CONV_RATE};
+use std::collections::BTreeSet;
+
+use super::{
+    contract_event::ContractEvent, effects_summary::EffectsSummary, error,
+    execution_effect::ExecutionEffect, host_function_trace::HostFunctionCall, op::Op,
+};
+use crate::tracking_copy::TrackingCopy;
 use engine_shared::{
     additive_map::AdditiveMap, gas::Gas, motes::Motes, newtypes::CorrelationId,
     stored_value::StoredValue, transform::Transform,
 };
 use engine_storage::global_state::StateReader;
-use types::{CLValue, Key};
+use types::{CLValue, Key, URef};
 
 fn make_payment_error_effects(
     max_payment_cost: Motes,
@@ -38,6 +44,49 @@ fn make_payment_error_effects(
     ExecutionEffect::new(ops, transforms)
 }
 
+/// The costs of the phases of a deploy, which add up to [`PhaseCosts::total`].
+///
+/// The deploy is charged only for its payment and session, so the cost of its execution result is
+/// [`PhaseCosts::charged`], which falls short of the total by the cost of finalization.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PhaseCosts {
+    pub payment: Gas,
+    pub session: Gas,
+    /// The gas spent finalizing the payment, i.e. paying the validator's reward and the refund.
+    /// Finalization isn't charged for, as it is run by the system rather than by the deploy.
+    pub finalize: Gas,
+}
+
+impl PhaseCosts {
+    /// Returns the gas spent by all the phases of the deploy, including finalization.
+    pub fn total(&self) -> Gas {
+        self.payment + self.session + self.finalize
+    }
+
+    /// Returns the cost the deploy is charged, i.e. that of its payment and session.
+    pub fn charged(&self) -> Gas {
+        self.payment + self.session
+    }
+}
+
+/// The parts of the effect of a deploy contributed by each of its phases, before redundant writes
+/// are reduced.  They hold only ops and transforms.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhaseEffects {
+    pub payment: ExecutionEffect,
+    /// The effect of the session code, which is empty if it failed, as none of it is kept.
+    pub session: ExecutionEffect,
+    /// The effect of finalizing the payment, i.e. of paying the validator's reward and the refund.
+    pub finalize: ExecutionEffect,
+}
+
+/// The costs and effects of the phases of a deploy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeployPhases {
+    pub costs: PhaseCosts,
+    pub effects: PhaseEffects,
+}
+
 #[derive(Debug)]
 pub enum ExecutionResult {
     /// An error condition that happened during execution
@@ -45,9 +94,20 @@ pub enum ExecutionResult {
         error: error::Error,
         effect: ExecutionEffect,
         cost: Gas,
+        /// The costs and effects of the phases of the deploy, or `None` if the result isn't that
+        /// of a whole deploy.
+        phases: Option<Box<DeployPhases>>,
     },
     /// Execution was finished successfully
-    Success { effect: ExecutionEffect, cost: Gas },
+    Success {
+        effect: ExecutionEffect,
+        cost: Gas,
+        /// The value passed to `runtime::ret` by the session code, if it called it.
+        ret: Option<CLValue>,
+        /// The costs and effects of the phases of the deploy, or `None` if the result isn't that
+        /// of a whole deploy.
+        phases: Option<Box<DeployPhases>>,
+    },
 }
 
 pub enum ForcedTransferResult {
@@ -66,6 +126,7 @@ impl ExecutionResult {
             error,
             effect: Default::default(),
             cost: Gas::default(),
+            phases: None,
         }
     }
 
@@ -108,26 +169,167 @@ impl ExecutionResult {
 
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
-            ExecutionResult::Failure { error, effect, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                phases,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                phases,
+            },
+            ExecutionResult::Success {
+                effect,
+                ret,
+                phases,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                ret,
+                phases,
             },
-            ExecutionResult::Success { effect, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
     pub fn with_effect(self, effect: ExecutionEffect) -> Self {
         match self {
-            ExecutionResult::Failure { error, cost, .. } => ExecutionResult::Failure {
+            ExecutionResult::Failure {
+                error,
+                cost,
+                phases,
+                ..
+            } => ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                phases,
+            },
+            ExecutionResult::Success {
+                cost, ret, phases, ..
+            } => ExecutionResult::Success {
+                effect,
+                cost,
+                ret,
+                phases,
             },
-            ExecutionResult::Success { cost, .. } => ExecutionResult::Success { effect, cost },
         }
     }
 
+    /// Returns the value the session code returned via `runtime::ret`, if it succeeded and
+    /// called it.
+    pub fn ret(&self) -> Option<&CLValue> {
+        match self {
+            ExecutionResult::Failure { .. } => None,
+            ExecutionResult::Success { ret, .. } => ret.as_ref(),
+        }
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.effect().notes
+    }
+
+    /// Returns the host functions called during the execution, in the order they were called, or
+    /// an empty slice if the engine isn't configured to trace them.
+    pub fn host_trace(&self) -> &[HostFunctionCall] {
+        &self.effect().host_trace
+    }
+
+    pub fn with_host_trace(mut self, host_trace: Vec<HostFunctionCall>) -> Self {
+        match &mut self {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect.host_trace = host_trace
+            }
+        }
+        self
+    }
+
+    pub fn events(&self) -> &[ContractEvent] {
+        &self.effect().events
+    }
+
+    pub fn with_events(mut self, events: Vec<ContractEvent>) -> Self {
+        match &mut self {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect.events = events
+            }
+        }
+        self
+    }
+
+    pub fn cache_stats(&self) -> Option<(u64, u64)> {
+        self.effect().cache_stats
+    }
+
+    pub fn with_cache_stats(mut self, cache_stats: Option<(u64, u64)>) -> Self {
+        match &mut self {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect.cache_stats = cache_stats
+            }
+        }
+        self
+    }
+
+    /// Returns the costs and effects of the phases of the deploy, or `None` if the result isn't
+    /// that of a whole deploy, e.g. if the deploy was rejected before it was executed.
+    pub fn phases(&self) -> Option<&DeployPhases> {
+        match self {
+            ExecutionResult::Failure { phases, .. } | ExecutionResult::Success { phases, .. } => {
+                phases.as_deref()
+            }
+        }
+    }
+
+    /// Returns the costs of the phases of the deploy, or `None` if the result isn't that of a whole
+    /// deploy.
+    pub fn phase_costs(&self) -> Option<PhaseCosts> {
+        self.phases().map(|phases| phases.costs)
+    }
+
+    pub fn payment_cost(&self) -> Option<Gas> {
+        self.phase_costs().map(|phase_costs| phase_costs.payment)
+    }
+
+    pub fn session_cost(&self) -> Option<Gas> {
+        self.phase_costs().map(|phase_costs| phase_costs.session)
+    }
+
+    pub fn finalize_cost(&self) -> Option<Gas> {
+        self.phase_costs().map(|phase_costs| phase_costs.finalize)
+    }
+
+    /// Returns the parts of the effect contributed by each phase of the deploy, or `None` if the
+    /// result isn't that of a whole deploy.
+    pub fn phase_effects(&self) -> Option<&PhaseEffects> {
+        self.phases().map(|phases| &phases.effects)
+    }
+
+    pub fn payment_effect(&self) -> Option<&ExecutionEffect> {
+        self.phase_effects()
+            .map(|phase_effects| &phase_effects.payment)
+    }
+
+    pub fn session_effect(&self) -> Option<&ExecutionEffect> {
+        self.phase_effects()
+            .map(|phase_effects| &phase_effects.session)
+    }
+
+    pub fn finalize_effect(&self) -> Option<&ExecutionEffect> {
+        self.phase_effects()
+            .map(|phase_effects| &phase_effects.finalize)
+    }
+
+    pub fn with_phases(mut self, costs: PhaseCosts, effects: PhaseEffects) -> Self {
+        match &mut self {
+            ExecutionResult::Failure { phases, .. } | ExecutionResult::Success { phases, .. } => {
+                *phases = Some(Box::new(DeployPhases { costs, effects }))
+            }
+        }
+        self
+    }
+
     pub fn error(&self) -> Option<&error::Error> {
         match self {
             ExecutionResult::Failure { error, .. } => Some(error),
@@ -135,6 +337,21 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns a higher-level summary of the effects, reading the values the transforms apply to
+    /// from `pre_state`, which should be a tracking copy of the state the deploy was executed
+    /// against.  `mint` is the uref of the mint contract.
+    pub fn effects_summary<R>(
+        &self,
+        correlation_id: CorrelationId,
+        mint: URef,
+        pre_state: &TrackingCopy<R>,
+    ) -> Result<EffectsSummary, R::Error>
+    where
+        R: StateReader<Key, StoredValue>,
+    {
+        EffectsSummary::new(correlation_id, self.effect(), mint, pre_state)
+    }
+
     /// Consumes [`ExecutionResult`] instance and optionally returns [`error::Error`] instance for
     /// [`ExecutionResult::Failure`] variant.
     pub fn take_error(self) -> Option<error::Error> {
@@ -147,10 +364,11 @@ impl ExecutionResult {
     pub fn check_forced_transfer(
         &self,
         payment_purse_balance: Motes,
+        gas_price: u64,
     ) -> Option<ForcedTransferResult> {
-        let payment_result_cost = match Motes::from_gas(self.cost(), CONV_RATE) {
+        let payment_result_cost = match Motes::from_gas(self.cost(), gas_price) {
             Some(cost) => cost,
-            // Multiplying cost by CONV_RATE overflowed the U512 range
+            // Multiplying cost by gas_price overflowed the U512 range
             None => return Some(ForcedTransferResult::InsufficientPayment),
         };
         // payment_code_spec_3_b_ii: if (balance of PoS pay purse) < (gas spent during
@@ -176,6 +394,7 @@ impl ExecutionResult {
     pub fn new_payment_code_error(
         error: error::Error,
         max_payment_cost: Motes,
+        gas_price: u64,
         account_main_purse_balance: Motes,
         account_main_purse: Key,
         rewards_purse: Key,
@@ -186,11 +405,12 @@ impl ExecutionResult {
             account_main_purse,
             rewards_purse,
         );
-        let cost = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+        let cost = Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default();
         ExecutionResult::Failure {
             error,
             effect,
             cost,
+            phases: None,
         }
     }
 }
@@ -200,12 +420,15 @@ pub enum ExecutionResultBuilderError {
     MissingPaymentExecutionResult,
     MissingSessionExecutionResult,
     MissingFinalizeExecutionResult,
+    /// The effect built for a failed session contains a transform made by the session alone.
+    SessionEffectIncluded(Key),
 }
 
 pub struct ExecutionResultBuilder {
     payment_execution_result: Option<ExecutionResult>,
     session_execution_result: Option<ExecutionResult>,
     finalize_execution_result: Option<ExecutionResult>,
+    notes: Vec<String>,
 }
 
 impl Default for ExecutionResultBuilder {
@@ -214,6 +437,7 @@ impl Default for ExecutionResultBuilder {
             payment_execution_result: None,
             session_execution_result: None,
             finalize_execution_result: None,
+            notes: Vec::new(),
         }
     }
 }
@@ -228,6 +452,11 @@ impl ExecutionResultBuilder {
         self
     }
 
+    /// Sets the result of executing the session code.
+    ///
+    /// If it is a failure, its effect should hold whatever the session did before failing.  None
+    /// of it is included in the built result, which leaves only the payment and finalization
+    /// effects, i.e. the charge to the account and the validator's reward.
     pub fn set_session_execution_result(
         &mut self,
         session_execution_result: ExecutionResult,
@@ -244,18 +473,37 @@ impl ExecutionResultBuilder {
         self
     }
 
+    pub fn add_note(&mut self, note: String) -> &mut ExecutionResultBuilder {
+        self.notes.push(note);
+        self
+    }
+
+    /// Returns the cost the deploy is charged, i.e. that of its payment and session.
     pub fn total_cost(&self) -> Gas {
-        let payment_cost = self
+        self.phase_costs().charged()
+    }
+
+    fn phase_costs(&self) -> PhaseCosts {
+        let payment = self
             .payment_execution_result
             .as_ref()
             .map(ExecutionResult::cost)
             .unwrap_or_default();
-        let session_cost = self
+        let session = self
             .session_execution_result
             .as_ref()
             .map(ExecutionResult::cost)
             .unwrap_or_default();
-        payment_cost + session_cost
+        let finalize = self
+            .finalize_execution_result
+            .as_ref()
+            .map(ExecutionResult::cost)
+            .unwrap_or_default();
+        PhaseCosts {
+            payment,
+            session,
+            finalize,
+        }
     }
 
     pub fn build<R: StateReader<Key, StoredValue>>(
@@ -263,21 +511,41 @@ impl ExecutionResultBuilder {
         reader: &R,
         correlation_id: CorrelationId,
     ) -> Result<ExecutionResult, ExecutionResultBuilderError> {
-        let cost = self.total_cost();
+        let phase_costs = self.phase_costs();
+        let cost = phase_costs.charged();
+        let mut phase_effects = PhaseEffects::default();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
 
+        // The keys transformed by payment and finalization, which a failed session may share
+        let mut non_session_keys = BTreeSet::new();
+        let mut host_trace = Vec::new();
+        let mut cache_stats = None;
+        let mut events = Vec::new();
+
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
             cost,
+            ret: None,
+            phases: None,
         };
 
         match self.payment_execution_result {
             Some(result) => {
+                phase_effects.payment = Self::phase_effect(result.effect());
                 if result.is_failure() {
-                    return Ok(result);
+                    // Neither session nor finalization is run after a failed payment
+                    let phase_costs = PhaseCosts {
+                        payment: result.cost(),
+                        ..PhaseCosts::default()
+                    };
+                    return Ok(result.with_phases(phase_costs, phase_effects));
                 } else {
+                    non_session_keys.extend(result.effect().transforms.keys().cloned());
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    host_trace.extend_from_slice(result.host_trace());
+                    Self::add_cache_stats(&mut cache_stats, result.cache_stats());
+                    events.extend_from_slice(result.events());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingPaymentExecutionResult),
@@ -285,13 +553,37 @@ impl ExecutionResultBuilder {
 
         // session_code_spec_3: only include session exec effects if there is no session
         // exec error
+        let mut failed_session_effect = None;
         match self.session_execution_result {
+            Some(ExecutionResult::Failure { error, effect, .. }) => {
+                // Whatever the session did before failing, e.g. the writes made before running
+                // out of gas, is dropped, leaving only the payment and finalization effects
+                ret = ExecutionResult::Failure {
+                    error,
+                    effect: Default::default(),
+                    cost,
+                    phases: None,
+                };
+                host_trace.extend_from_slice(&effect.host_trace);
+                Self::add_cache_stats(&mut cache_stats, effect.cache_stats);
+                // A failed deploy reports no events, not even those emitted by the payment code
+                events.clear();
+                failed_session_effect = Some(effect);
+            }
             Some(result) => {
-                if result.is_failure() {
-                    ret = result.with_cost(cost);
-                } else {
-                    Self::add_effects(&mut ops, &mut transforms, result.effect());
-                }
+                phase_effects.session = Self::phase_effect(result.effect());
+                Self::add_effects(&mut ops, &mut transforms, result.effect());
+                host_trace.extend_from_slice(result.host_trace());
+                Self::add_cache_stats(&mut cache_stats, result.cache_stats());
+                events.extend_from_slice(result.events());
+                // The value returned by the session is kept, while whatever payment and
+                // finalization returned isn't
+                ret = ExecutionResult::Success {
+                    effect: Default::default(),
+                    cost,
+                    ret: result.ret().cloned(),
+                    phases: None,
+                };
             }
             None => return Err(ExecutionResultBuilderError::MissingSessionExecutionResult),
         };
@@ -304,16 +596,35 @@ impl ExecutionResultBuilder {
                         error::Error::Finalization,
                     ));
                 } else {
+                    phase_effects.finalize = Self::phase_effect(result.effect());
+                    non_session_keys.extend(result.effect().transforms.keys().cloned());
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
+                    Self::add_cache_stats(&mut cache_stats, result.cache_stats());
                 }
             }
             None => return Err(ExecutionResultBuilderError::MissingFinalizeExecutionResult),
         }
 
+        if let Some(session_effect) = failed_session_effect {
+            Self::check_session_effect_excluded(&transforms, &session_effect, &non_session_keys)?;
+        }
+
         // Remove redundant writes to allow more opportunity to commute
-        let reduced_effect = Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        let mut reduced_effect =
+            Self::reduce_identity_writes(ops, transforms, reader, correlation_id);
+        reduced_effect.notes = self.notes;
+        reduced_effect.host_trace = host_trace;
+        reduced_effect.cache_stats = cache_stats;
+        reduced_effect.events = events;
+
+        Ok(ret
+            .with_effect(reduced_effect)
+            .with_phases(phase_costs, phase_effects))
+    }
 
-        Ok(ret.with_effect(reduced_effect))
+    /// Returns the ops and transforms of `effect`, leaving out what isn't attributed to phases.
+    fn phase_effect(effect: &ExecutionEffect) -> ExecutionEffect {
+        ExecutionEffect::new(effect.ops.clone(), effect.transforms.clone())
     }
 
     fn add_effects(
@@ -329,6 +640,32 @@ impl ExecutionResultBuilder {
         }
     }
 
+    /// Adds the hits and misses of `stats` to `total`, if they were recorded.
+    fn add_cache_stats(total: &mut Option<(u64, u64)>, stats: Option<(u64, u64)>) {
+        if let Some((hits, misses)) = stats {
+            let (total_hits, total_misses) = total.get_or_insert((0, 0));
+            *total_hits += hits;
+            *total_misses += misses;
+        }
+    }
+
+    /// Checks that no key transformed only by a failed session is transformed by the effect built
+    /// for it.
+    fn check_session_effect_excluded(
+        transforms: &AdditiveMap<Key, Transform>,
+        session_effect: &ExecutionEffect,
+        non_session_keys: &BTreeSet<Key>,
+    ) -> Result<(), ExecutionResultBuilderError> {
+        match session_effect
+            .transforms
+            .keys()
+            .find(|key| !non_session_keys.contains(key) && transforms.get(*key).is_some())
+        {
+            Some(key) => Err(ExecutionResultBuilderError::SessionEffectIncluded(*key)),
+            None => Ok(()),
+        }
+    }
+
     /// In the case we are writing the same value as was there originally,
     /// it is equivalent to having a `Transform::Identity` and `Op::Read`.
     /// This function makes that reduction before returning the `ExecutionEffect`.
@@ -363,3 +700,213 @@ impl ExecutionResultBuilder {
         ExecutionEffect::new(ops, transforms)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use matches::assert_matches;
+
+    use engine_storage::global_state::{in_memory::InMemoryGlobalState, StateProvider};
+    use types::{AccessRights, URef, U512};
+
+    use super::*;
+    use crate::execution;
+
+    fn purse_key(seed: u8) -> Key {
+        Key::URef(URef::new([seed; 32], AccessRights::READ_ADD_WRITE))
+    }
+
+    fn effect_of(entries: &[(Key, Transform)]) -> ExecutionEffect {
+        let mut ops = AdditiveMap::new();
+        let mut transforms = AdditiveMap::new();
+        for (key, transform) in entries.iter().cloned() {
+            ops.insert(key, Op::Add);
+            transforms.insert(key, transform);
+        }
+        ExecutionEffect::new(ops, transforms)
+    }
+
+    fn add(value: u64) -> Transform {
+        Transform::AddUInt512(U512::from(value))
+    }
+
+    fn build(
+        builder: ExecutionResultBuilder,
+    ) -> Result<ExecutionResult, ExecutionResultBuilderError> {
+        let global_state = InMemoryGlobalState::empty().expect("should create global state");
+        let reader = global_state
+            .checkout(global_state.empty_root())
+            .expect("should checkout")
+            .expect("should have root");
+        builder.build(&reader, CorrelationId::new())
+    }
+
+    /// Returns a builder whose payment adds to `purse_key(1)` and whose finalization adds to
+    /// `purse_key(2)` and `purse_key(3)`, i.e. the validator's reward and the refund.
+    fn builder_with_session(session_result: ExecutionResult) -> ExecutionResultBuilder {
+        let payment_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(1), add(10))]),
+            cost: Gas::new(U512::from(1)),
+            ret: Some(CLValue::from_t(1u8).unwrap()),
+            phases: None,
+        };
+        let finalize_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(2), add(7)), (purse_key(3), add(3))]),
+            cost: Gas::new(U512::from(2)),
+            ret: None,
+            phases: None,
+        };
+        let mut builder = ExecutionResultBuilder::new();
+        builder
+            .set_payment_execution_result(payment_result)
+            .set_session_execution_result(session_result)
+            .set_finalize_execution_result(finalize_result);
+        builder
+    }
+
+    #[test]
+    fn gas_limit_failure_should_only_have_payment_and_finalization_effects() {
+        let session_result = ExecutionResult::Failure {
+            error: error::Error::Exec(execution::Error::GasLimit),
+            effect: effect_of(&[(purse_key(1), add(100)), (purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+
+        assert_matches!(
+            result.error(),
+            Some(error::Error::Exec(execution::Error::GasLimit))
+        );
+        assert_eq!(result.cost(), Gas::new(U512::from(6)));
+        let transforms = &result.effect().transforms;
+        assert_eq!(transforms.len(), 3);
+        assert_eq!(transforms.get(&purse_key(1)), Some(&add(10)));
+        assert_eq!(transforms.get(&purse_key(2)), Some(&add(7)));
+        assert_eq!(transforms.get(&purse_key(3)), Some(&add(3)));
+        assert!(transforms.get(&purse_key(4)).is_none());
+    }
+
+    #[test]
+    fn gas_limit_failure_should_still_have_payment_cost() {
+        let session_result = ExecutionResult::Failure {
+            error: error::Error::Exec(execution::Error::GasLimit),
+            effect: effect_of(&[(purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+
+        assert_eq!(result.payment_cost(), Some(Gas::new(U512::from(1))));
+        assert_eq!(result.session_cost(), Some(Gas::new(U512::from(5))));
+        let session_effect = result.session_effect().expect("should have phases");
+        assert!(session_effect.transforms.is_empty());
+        let payment_effect = result.payment_effect().expect("should have phases");
+        assert_eq!(payment_effect.transforms.get(&purse_key(1)), Some(&add(10)));
+    }
+
+    #[test]
+    fn successful_session_should_have_its_effects_included() {
+        let session_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(1), add(100)), (purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            ret: None,
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+
+        assert!(result.is_success());
+        let transforms = &result.effect().transforms;
+        assert_eq!(transforms.len(), 4);
+        assert_eq!(transforms.get(&purse_key(1)), Some(&add(110)));
+        assert_eq!(transforms.get(&purse_key(4)), Some(&add(100)));
+    }
+
+    #[test]
+    fn should_attribute_costs_and_effects_to_phases() {
+        let session_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(1), add(100)), (purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            ret: None,
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+
+        let phase_costs = result.phase_costs().expect("should have phase costs");
+        assert_eq!(phase_costs.payment, Gas::new(U512::from(1)));
+        assert_eq!(phase_costs.session, Gas::new(U512::from(5)));
+        assert_eq!(phase_costs.finalize, Gas::new(U512::from(2)));
+        // Finalization is run by the system, so isn't charged for
+        assert_eq!(phase_costs.charged(), result.cost());
+        assert_eq!(result.cost(), Gas::new(U512::from(6)));
+        assert_eq!(phase_costs.total(), Gas::new(U512::from(8)));
+
+        let phase_effects = result.phase_effects().expect("should have phase effects");
+        let payment_transforms = &phase_effects.payment.transforms;
+        assert_eq!(payment_transforms.len(), 1);
+        assert_eq!(payment_transforms.get(&purse_key(1)), Some(&add(10)));
+        let session_transforms = &phase_effects.session.transforms;
+        assert_eq!(session_transforms.len(), 2);
+        assert_eq!(session_transforms.get(&purse_key(1)), Some(&add(100)));
+        let finalize_transforms = &phase_effects.finalize.transforms;
+        assert_eq!(finalize_transforms.len(), 2);
+        assert_eq!(finalize_transforms.get(&purse_key(3)), Some(&add(3)));
+    }
+
+    #[test]
+    fn should_only_keep_value_returned_by_session() {
+        let session_return_value = CLValue::from_t(U512::from(42)).unwrap();
+        let session_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            ret: Some(session_return_value.clone()),
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+        assert_eq!(result.ret(), Some(&session_return_value));
+
+        let session_result = ExecutionResult::Success {
+            effect: effect_of(&[(purse_key(4), add(100))]),
+            cost: Gas::new(U512::from(5)),
+            ret: None,
+            phases: None,
+        };
+
+        let result = build(builder_with_session(session_result)).expect("should build");
+        assert_eq!(result.ret(), None);
+    }
+
+    #[test]
+    fn should_detect_session_effect_included_in_failure() {
+        let session_effect = effect_of(&[(purse_key(1), add(100)), (purse_key(4), add(100))]);
+        let non_session_keys = iter::once(purse_key(1)).collect();
+
+        let excluded = effect_of(&[(purse_key(1), add(10))]);
+        assert_eq!(
+            ExecutionResultBuilder::check_session_effect_excluded(
+                &excluded.transforms,
+                &session_effect,
+                &non_session_keys
+            ),
+            Ok(())
+        );
+
+        let included = effect_of(&[(purse_key(1), add(10)), (purse_key(4), add(100))]);
+        assert_eq!(
+            ExecutionResultBuilder::check_session_effect_excluded(
+                &included.transforms,
+                &session_effect,
+                &non_session_keys
+            ),
+            Err(ExecutionResultBuilderError::SessionEffectIncluded(
+                purse_key(4)
+            ))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt, iter};
+use std::{collections::BTreeMap, fmt, iter};
 
 use num_traits::Zero;
 use rand::{
@@ -13,16 +13,23 @@ use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
 
 use crate::engine_state::execution_effect::ExecutionEffect;
 
+/// The name under which the system account holds the chain name given at genesis.
+pub const CHAIN_NAME_KEY: &str = "chain_name";
 pub const PLACEHOLDER_KEY: Key = Key::Hash([0u8; 32]);
 pub const POS_BONDING_PURSE: &str = "pos_bonding_purse";
+pub const POS_COMMUNITY_PURSE: &str = "pos_community_purse";
 pub const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
+pub const POS_REFUND_PURSE: &str = "pos_refund_purse";
 pub const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
+pub const POS_UNBONDING_DELAY: &str = "pos_unbonding_delay";
+pub const POS_GAS_PRICE: &str = "pos_gas_price";
 
 pub enum GenesisResult {
     RootNotFound,
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
     Serialization(bytesrepr::Error),
+    Overflow,
     Success {
         post_state_hash: Blake2bHash,
         effect: ExecutionEffect,
@@ -38,6 +45,7 @@ impl fmt::Display for GenesisResult {
                 write!(f, "Type mismatch: {:?}", type_mismatch)
             }
             GenesisResult::Serialization(error) => write!(f, "Serialization error: {:?}", error),
+            GenesisResult::Overflow => write!(f, "Overflow"),
             GenesisResult::Success {
                 post_state_hash,
                 effect,
@@ -46,13 +54,52 @@ impl fmt::Display for GenesisResult {
     }
 }
 
+/// An error in the accounts or settings of a [`GenesisConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenesisError {
+    /// The balance or bonded amount of the account under the given public key overflows when
+    /// added to the amounts of the accounts listed before it.
+    InvalidAccount(PublicKey),
+    /// The total supply recorded by the mint after creating the genesis purses differs from the
+    /// sum of the balances and bonded amounts of the accounts.
+    TotalSupplyMismatch { expected: U512, actual: U512 },
+    /// The mint holds no record of the total supply after creating the genesis purses.
+    MissingTotalSupply,
+    /// The gas price is zero, so execution would be free.
+    ZeroGasPrice,
+}
+
+impl fmt::Display for GenesisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            GenesisError::InvalidAccount(public_key) => write!(
+                f,
+                "Invalid genesis account {}: amounts overflow",
+                public_key
+            ),
+            GenesisError::TotalSupplyMismatch { expected, actual } => write!(
+                f,
+                "Total supply mismatch: expected {}, but the mint recorded {}",
+                expected, actual
+            ),
+            GenesisError::MissingTotalSupply => {
+                write!(f, "The mint recorded no total supply at genesis")
+            }
+            GenesisError::ZeroGasPrice => write!(f, "Invalid genesis gas price: must be non-zero"),
+        }
+    }
+}
+
 impl GenesisResult {
     pub fn from_commit_result(commit_result: CommitResult, effect: ExecutionEffect) -> Self {
         match commit_result {
             CommitResult::RootNotFound => GenesisResult::RootNotFound,
             CommitResult::KeyNotFound(key) => GenesisResult::KeyNotFound(key),
-            CommitResult::TypeMismatch(type_mismatch) => GenesisResult::TypeMismatch(type_mismatch),
+            CommitResult::TypeMismatch { type_mismatch, .. } => {
+                GenesisResult::TypeMismatch(type_mismatch)
+            }
             CommitResult::Serialization(error) => GenesisResult::Serialization(error),
+            CommitResult::Overflow => GenesisResult::Overflow,
             CommitResult::Success { state_root, .. } => GenesisResult::Success {
                 post_state_hash: state_root,
                 effect,
@@ -109,6 +156,62 @@ impl Distribution<GenesisAccount> for Standard {
     }
 }
 
+/// A contract installed at genesis, after the system contracts.
+///
+/// The installer is executed by the system account with the given serialized args, and must
+/// return the key of the contract it stores.  That key is put under `name` in the system account's
+/// named keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenesisContract {
+    name: String,
+    installer_bytes: Vec<u8>,
+    args: Vec<u8>,
+}
+
+impl GenesisContract {
+    pub fn new(name: String, installer_bytes: Vec<u8>, args: Vec<u8>) -> Self {
+        GenesisContract {
+            name,
+            installer_bytes,
+            args,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn installer_bytes(&self) -> &[u8] {
+        self.installer_bytes.as_slice()
+    }
+
+    pub fn args(&self) -> &[u8] {
+        self.args.as_slice()
+    }
+}
+
+impl Distribution<GenesisContract> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GenesisContract {
+        let mut count = rng.gen_range(1, 100);
+        let name = iter::repeat(())
+            .map(|_| rng.gen::<char>())
+            .take(count)
+            .collect();
+
+        count = rng.gen_range(1000, 10_000);
+        let installer_bytes = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+
+        count = rng.gen_range(0, 100);
+        let args = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+
+        GenesisContract {
+            name,
+            installer_bytes,
+            args,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenesisConfig {
     name: String,
@@ -119,6 +222,9 @@ pub struct GenesisConfig {
     standard_payment_installer_bytes: Vec<u8>,
     accounts: Vec<GenesisAccount>,
     wasm_costs: WasmCosts,
+    unbonding_delay: u64,
+    gas_price: u64,
+    genesis_contracts: Vec<GenesisContract>,
 }
 
 impl GenesisConfig {
@@ -132,6 +238,9 @@ impl GenesisConfig {
         standard_payment_installer_bytes: Vec<u8>,
         accounts: Vec<GenesisAccount>,
         wasm_costs: WasmCosts,
+        unbonding_delay: u64,
+        gas_price: u64,
+        genesis_contracts: Vec<GenesisContract>,
     ) -> Self {
         GenesisConfig {
             name,
@@ -142,6 +251,9 @@ impl GenesisConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            unbonding_delay,
+            gas_price,
+            genesis_contracts,
         }
     }
 
@@ -173,18 +285,69 @@ impl GenesisConfig {
         self.wasm_costs
     }
 
-    pub fn get_bonded_validators(&self) -> impl Iterator<Item = (PublicKey, Motes)> + '_ {
+    /// Returns the number of blocks after which unbonded stake can be withdrawn from the PoS.
+    pub fn unbonding_delay(&self) -> u64 {
+        self.unbonding_delay
+    }
+
+    /// Returns the number of motes charged per unit of gas, which must not be zero.
+    pub fn gas_price(&self) -> u64 {
+        self.gas_price
+    }
+
+    /// Returns the accounts ordered by public key, with an account listed more than once merged
+    /// into one holding the sum of its balances and the sum of its bonded amounts.  This allows a
+    /// validator's purse to be topped up by listing it again with only a balance.
+    ///
+    /// The balance and bonded amount of an account are independent, so either or both can be
+    /// zero.
+    pub fn merged_accounts(&self) -> Result<Vec<GenesisAccount>, GenesisError> {
+        let mut merged: BTreeMap<PublicKey, GenesisAccount> = BTreeMap::new();
+        for account in &self.accounts {
+            let public_key = account.public_key();
+            let merged_account = match merged.get(&public_key) {
+                Some(existing) => {
+                    let invalid_account = || GenesisError::InvalidAccount(public_key);
+                    let balance = existing
+                        .balance()
+                        .checked_add(account.balance())
+                        .ok_or_else(invalid_account)?;
+                    let bonded_amount = existing
+                        .bonded_amount()
+                        .checked_add(account.bonded_amount())
+                        .ok_or_else(invalid_account)?;
+                    GenesisAccount::new(public_key, balance, bonded_amount)
+                }
+                None => *account,
+            };
+            merged.insert(public_key, merged_account);
+        }
+        Ok(merged.into_iter().map(|(_, account)| account).collect())
+    }
+
+    /// Returns the sum of the balances and bonded amounts of all the accounts, which is the total
+    /// supply of the mint after genesis.
+    pub fn total_supply(&self) -> Result<Motes, GenesisError> {
+        self.accounts
+            .iter()
+            .try_fold(Motes::zero(), |total_supply, account| {
+                total_supply
+                    .checked_add(account.balance())
+                    .and_then(|total_supply| total_supply.checked_add(account.bonded_amount()))
+                    .ok_or_else(|| GenesisError::InvalidAccount(account.public_key()))
+            })
+    }
+
+    /// Returns the merged accounts with a non-zero bonded amount, mapped to that amount.
+    pub fn get_bonded_validators(&self) -> Result<BTreeMap<PublicKey, Motes>, GenesisError> {
         let zero = Motes::zero();
-        self.accounts.iter().filter_map(move |genesis_account| {
-            if genesis_account.bonded_amount() > zero {
-                Some((
-                    genesis_account.public_key(),
-                    genesis_account.bonded_amount(),
-                ))
-            } else {
-                None
-            }
-        })
+        let bonded_validators = self
+            .merged_accounts()?
+            .into_iter()
+            .filter(|account| account.bonded_amount() > zero)
+            .map(|account| (account.public_key(), account.bonded_amount()))
+            .collect();
+        Ok(bonded_validators)
     }
 
     pub fn accounts(&self) -> &[GenesisAccount] {
@@ -194,6 +357,17 @@ impl GenesisConfig {
     pub fn push_account(&mut self, account: GenesisAccount) {
         self.accounts.push(account);
     }
+
+    /// Returns the contracts installed at genesis, in the order they're installed.
+    pub fn genesis_contracts(&self) -> &[GenesisContract] {
+        self.genesis_contracts.as_slice()
+    }
+
+    /// Returns this config with `genesis_contract` installed after any contracts already in it.
+    pub fn with_genesis_contract(mut self, genesis_contract: GenesisContract) -> Self {
+        self.genesis_contracts.push(genesis_contract);
+        self
+    }
 }
 
 impl Distribution<GenesisConfig> for Standard {
@@ -233,8 +407,21 @@ impl Distribution<GenesisConfig> for Standard {
             max_stack_height: rng.gen(),
             opcodes_mul: rng.gen(),
             opcodes_div: rng.gen(),
+            storage_per_byte: rng.gen(),
+            native_transfer: rng.gen(),
+            hash_per_byte: rng.gen(),
+            max_initial_memory_pages: rng.gen(),
+            max_memory_pages: rng.gen(),
+            preprocess_per_byte: rng.gen(),
         };
 
+        let unbonding_delay = rng.gen();
+
+        let gas_price = rng.gen_range(1, u64::max_value());
+
+        count = rng.gen_range(0, 3);
+        let genesis_contracts = iter::repeat(()).map(|_| rng.gen()).take(count).collect();
+
         GenesisConfig {
             name,
             timestamp,
@@ -244,6 +431,77 @@ impl Distribution<GenesisConfig> for Standard {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            unbonding_delay,
+            gas_price,
+            genesis_contracts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC_KEY_1: PublicKey = PublicKey::ed25519_from([1; 32]);
+    const PUBLIC_KEY_2: PublicKey = PublicKey::ed25519_from([2; 32]);
+
+    fn genesis_account(public_key: PublicKey, balance: u64, bonded_amount: u64) -> GenesisAccount {
+        GenesisAccount::new(
+            public_key,
+            Motes::new(balance.into()),
+            Motes::new(bonded_amount.into()),
+        )
+    }
+
+    fn config_with_accounts(accounts: Vec<GenesisAccount>) -> GenesisConfig {
+        GenesisConfig {
+            accounts,
+            ..rand::random()
         }
     }
+
+    #[test]
+    fn should_merge_accounts_listed_more_than_once() {
+        let genesis_config = config_with_accounts(vec![
+            genesis_account(PUBLIC_KEY_2, 0, 10),
+            genesis_account(PUBLIC_KEY_1, 5, 0),
+            genesis_account(PUBLIC_KEY_2, 20, 0),
+        ]);
+
+        let merged_accounts = genesis_config.merged_accounts().expect("should merge");
+        assert_eq!(
+            merged_accounts,
+            vec![
+                genesis_account(PUBLIC_KEY_1, 5, 0),
+                genesis_account(PUBLIC_KEY_2, 20, 10),
+            ]
+        );
+
+        let bonded_validators = genesis_config.get_bonded_validators().unwrap();
+        assert_eq!(bonded_validators.len(), 1);
+        assert_eq!(bonded_validators[&PUBLIC_KEY_2], Motes::new(10.into()));
+
+        let total_supply = genesis_config.total_supply().unwrap();
+        assert_eq!(total_supply, Motes::new(35.into()));
+    }
+
+    #[test]
+    fn should_name_account_whose_amounts_overflow() {
+        let max_motes = Motes::new(U512::max_value());
+        let genesis_config = config_with_accounts(vec![
+            GenesisAccount::new(PUBLIC_KEY_1, max_motes, Motes::zero()),
+            genesis_account(PUBLIC_KEY_2, 0, 1),
+        ]);
+        let expected_error = GenesisError::InvalidAccount(PUBLIC_KEY_2);
+        assert_eq!(genesis_config.total_supply(), Err(expected_error));
+        assert!(genesis_config.merged_accounts().is_ok());
+
+        let genesis_config = config_with_accounts(vec![
+            GenesisAccount::new(PUBLIC_KEY_1, Motes::zero(), max_motes),
+            genesis_account(PUBLIC_KEY_1, 0, 1),
+        ]);
+        let expected_error = GenesisError::InvalidAccount(PUBLIC_KEY_1);
+        assert_eq!(genesis_config.merged_accounts(), Err(expected_error));
+        assert_eq!(genesis_config.get_bonded_validators(), Err(expected_error));
+    }
 }
@@ -0,0 +1,121 @@
+use std::{cell::RefCell, mem, rc::Rc};
+
+use engine_shared::gas::Gas;
+use types::Phase;
+
+/// The maximum number of bytes of each argument kept by a [`HostFunctionCall`].  Longer arguments
+/// are truncated.
+pub const MAX_TRACED_ARG_BYTES: usize = 64;
+
+/// A call to a host function made by payment or session code, or by a contract either of them
+/// called, as recorded when [`EngineConfig::trace_host_functions`] is turned on.
+///
+/// [`EngineConfig::trace_host_functions`]: super::EngineConfig::trace_host_functions
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HostFunctionCall {
+    /// The name the host function is imported under, e.g. `"get_main_purse"`.
+    pub name: &'static str,
+    /// The phase in which the host function was called.
+    pub phase: Phase,
+    /// The bytes the host function read from Wasm memory, e.g. the serialized keys or urefs it
+    /// was passed, each truncated to [`MAX_TRACED_ARG_BYTES`].
+    pub args: Vec<Vec<u8>>,
+    /// The gas charged during the call, including that of any contract it called.
+    pub gas: Gas,
+    /// The value returned to Wasm, usually an `ApiError` code, or `None` if the host function
+    /// returns nothing or the call trapped, e.g. by reverting.
+    pub result: Option<i32>,
+}
+
+#[derive(Debug, Default)]
+struct Calls {
+    calls: Vec<HostFunctionCall>,
+    // The indices into `calls` of the calls which haven't returned yet, innermost last
+    open: Vec<usize>,
+}
+
+/// The host function calls of a single execution, shared by the runtimes of the contracts called
+/// during it so that nested calls are recorded in the order they were made.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HostFunctionTrace(Rc<RefCell<Calls>>);
+
+impl HostFunctionTrace {
+    /// Records the start of a call to the host function `name`, returning the index to finish it
+    /// with.
+    pub(crate) fn start(&self, name: &'static str, phase: Phase) -> usize {
+        let mut calls = self.0.borrow_mut();
+        let index = calls.calls.len();
+        calls.calls.push(HostFunctionCall {
+            name,
+            phase,
+            args: Vec::new(),
+            gas: Gas::default(),
+            result: None,
+        });
+        calls.open.push(index);
+        index
+    }
+
+    /// Records `bytes` read from Wasm memory as an argument of the innermost open call.
+    pub(crate) fn record_arg(&self, bytes: &[u8]) {
+        let mut calls = self.0.borrow_mut();
+        if let Some(index) = calls.open.last().copied() {
+            let len = bytes.len().min(MAX_TRACED_ARG_BYTES);
+            calls.calls[index].args.push(bytes[..len].to_vec());
+        }
+    }
+
+    /// Records that the call at `index` returned `result` after charging `gas`.
+    pub(crate) fn finish(&self, index: usize, gas: Gas, result: Option<i32>) {
+        let mut calls = self.0.borrow_mut();
+        calls.open.retain(|open_index| *open_index != index);
+        let call = &mut calls.calls[index];
+        call.gas = gas;
+        call.result = result;
+    }
+
+    /// Returns the calls recorded so far, leaving the trace empty.
+    pub(crate) fn take(&self) -> Vec<HostFunctionCall> {
+        let mut calls = self.0.borrow_mut();
+        calls.open.clear();
+        mem::take(&mut calls.calls)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::U512;
+
+    use super::*;
+
+    #[test]
+    fn should_record_nested_calls_in_order() {
+        let trace = HostFunctionTrace::default();
+        let outer = trace.start("call_contract", Phase::Session);
+        trace.record_arg(&[1, 2, 3]);
+        let inner = trace.start("get_main_purse", Phase::Session);
+        trace.finish(inner, Gas::new(U512::from(1)), None);
+        trace.record_arg(&[4]);
+        trace.finish(outer, Gas::new(U512::from(10)), Some(0));
+
+        let calls = trace.take();
+        let names: Vec<_> = calls.iter().map(|call| call.name).collect();
+        assert_eq!(names, vec!["call_contract", "get_main_purse"]);
+        assert_eq!(calls[0].args, vec![vec![1, 2, 3], vec![4]]);
+        assert!(calls[1].args.is_empty());
+        assert_eq!(calls[0].result, Some(0));
+        assert_eq!(calls[1].gas, Gas::new(U512::from(1)));
+        assert!(trace.take().is_empty());
+    }
+
+    #[test]
+    fn should_truncate_args() {
+        let trace = HostFunctionTrace::default();
+        let index = trace.start("write", Phase::Session);
+        trace.record_arg(&[7; MAX_TRACED_ARG_BYTES + 1]);
+        trace.finish(index, Gas::default(), Some(0));
+
+        let calls = trace.take();
+        assert_eq!(calls[0].args, vec![vec![7; MAX_TRACED_ARG_BYTES]]);
+    }
+}
@@ -1,4 +1,8 @@
+pub mod batch;
+pub mod contract_event;
 pub mod deploy_item;
+pub mod deploy_observer;
+pub mod effects_summary;
 pub mod engine_config;
 mod error;
 pub mod executable_deploy_item;
@@ -6,21 +10,31 @@ pub mod execute_request;
 pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
+pub mod host_function_trace;
+pub mod module_cache;
 pub mod op;
+pub mod preprocessing_cost;
+pub mod pruning;
 pub mod query;
+pub mod signature;
+pub mod simulation;
 pub mod system_contract_cache;
+pub mod transfer_request;
 pub mod upgrade;
 pub mod utils;
 
 use std::{
     cell::RefCell,
     collections::{BTreeMap, BTreeSet, HashMap},
+    convert::TryFrom,
     rc::Rc,
+    time::Instant,
 };
 
 use num_traits::Zero;
 use parity_wasm::elements::Module;
 
+use ::mint::Mint;
 use contract::args_parser::ArgsParser;
 use engine_shared::{
     account::Account,
@@ -31,7 +45,7 @@ use engine_shared::{
     newtypes::{Blake2bHash, CorrelationId},
     stored_value::StoredValue,
     transform::Transform,
-    wasm,
+    wasm, TypeMismatch,
 };
 use engine_storage::{
     global_state::{CommitResult, StateProvider, StateReader},
@@ -40,8 +54,11 @@ use engine_storage::{
 use engine_wasm_prep::{wasm_costs::WasmCosts, Preprocessor};
 use proof_of_stake::Stakes;
 use types::{
-    account::PublicKey, bytesrepr::ToBytes, system_contract_errors::mint, AccessRights, BlockTime,
-    Key, Phase, ProtocolVersion, URef, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
+    account::{ActionType, PublicKey},
+    bytesrepr::{self, ToBytes},
+    system_contract_errors::mint,
+    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
+    KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
 };
 
 pub use self::{
@@ -50,20 +67,29 @@ pub use self::{
 };
 use crate::{
     engine_state::{
-        deploy_item::DeployItem,
+        batch::BatchResult,
+        deploy_item::{Approval, DeployItem},
+        deploy_observer::{self, DeployObserver, NoOpObserver},
         error::Error::MissingSystemContract,
         executable_deploy_item::ExecutableDeployItem,
         execute_request::ExecuteRequest,
         execution_result::{ExecutionResult, ForcedTransferResult},
         genesis::{
-            GenesisAccount, GenesisConfig, GenesisResult, PLACEHOLDER_KEY, POS_BONDING_PURSE,
-            POS_PAYMENT_PURSE, POS_REWARDS_PURSE,
+            GenesisAccount, GenesisConfig, GenesisError, GenesisResult, CHAIN_NAME_KEY,
+            PLACEHOLDER_KEY, POS_BONDING_PURSE, POS_COMMUNITY_PURSE, POS_GAS_PRICE,
+            POS_PAYMENT_PURSE, POS_REFUND_PURSE, POS_REWARDS_PURSE, POS_UNBONDING_DELAY,
         },
+        module_cache::ModuleCache,
         query::{QueryRequest, QueryResult},
+        signature::{self, SignatureAlgorithm, SignatureError},
+        simulation::SimulationResult,
         system_contract_cache::SystemContractCache,
+        transfer_request::TransferRequest,
         upgrade::{UpgradeConfig, UpgradeResult},
     },
-    execution::{self, AddressGenerator, Executor, MINT_NAME, POS_NAME},
+    execution::{self, AddressGenerator, Executor, FN_STORE_ID_INITIAL, MINT_NAME, POS_NAME},
+    runtime::extract_access_rights_from_keys,
+    runtime_context::{self, RuntimeContext},
     tracking_copy::{TrackingCopy, TrackingCopyExt},
     KnownKeys,
 };
@@ -76,12 +102,15 @@ pub const CONV_RATE: u64 = 10;
 pub const SYSTEM_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
 const GENESIS_INITIAL_BLOCKTIME: u64 = 0;
+const GENESIS_INITIAL_BLOCK_HEIGHT: u64 = 0;
 const MINT_METHOD_NAME: &str = "mint";
 
 #[derive(Debug)]
 pub struct EngineState<S> {
     config: EngineConfig,
     system_contract_cache: SystemContractCache,
+    module_cache: ModuleCache,
+    observer: Box<dyn DeployObserver>,
     state: S,
 }
 
@@ -92,17 +121,34 @@ where
 {
     pub fn new(state: S, config: EngineConfig) -> EngineState<S> {
         let system_contract_cache = Default::default();
+        let module_cache = ModuleCache::new(config.module_cache_size());
         EngineState {
             config,
             system_contract_cache,
+            module_cache,
+            observer: Box::new(NoOpObserver),
             state,
         }
     }
 
+    /// Replaces the observer notified around the execution of every deploy run via
+    /// [`EngineState::run_execute`].
+    pub fn set_observer(&mut self, observer: Box<dyn DeployObserver>) {
+        self.observer = observer;
+    }
+
     pub fn config(&self) -> &EngineConfig {
         &self.config
     }
 
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn module_cache(&self) -> &ModuleCache {
+        &self.module_cache
+    }
+
     pub fn wasm_costs(
         &self,
         protocol_version: ProtocolVersion,
@@ -124,6 +170,56 @@ where
         }
     }
 
+    /// Verifies a single approval of a deploy, i.e. that `signature` over the serialized
+    /// `deploy_header` was made by `public_key` using the algorithm tagged `algorithm`, which must
+    /// be either `"ed25519"` or `"secp256k1"`.
+    ///
+    /// Deploys with several approvals are checked by calling this once per approval, as
+    /// [`verify_approvals`](EngineState::verify_approvals) does.
+    pub fn validate_signatures(
+        &self,
+        deploy_header: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+        algorithm: &str,
+    ) -> Result<(), Error> {
+        let algorithm = SignatureAlgorithm::try_from(algorithm)?;
+        signature::verify(algorithm, public_key, deploy_header, signature)?;
+        Ok(())
+    }
+
+    /// Verifies that `approvals` hold a valid signature over `deploy_hash` by each of
+    /// `authorization_keys`, and none by any other key.
+    ///
+    /// With [`EngineConfig::verify_approvals`] on, [`deploy`](EngineState::deploy) fails the
+    /// preconditions of deploys whose approvals don't pass this.
+    pub fn verify_approvals(
+        &self,
+        deploy_hash: &[u8],
+        authorization_keys: &BTreeSet<PublicKey>,
+        approvals: &[Approval],
+    ) -> Result<(), Error> {
+        let mut approved_keys = BTreeSet::new();
+        for approval in approvals {
+            self.validate_signatures(
+                deploy_hash,
+                &approval.signature,
+                &approval.public_key,
+                &approval.algorithm,
+            )?;
+            let algorithm = SignatureAlgorithm::try_from(approval.algorithm.as_str())?;
+            let signer = signature::authorization_key(algorithm, &approval.public_key)?;
+            if !authorization_keys.contains(&signer) {
+                return Err(SignatureError::UnauthorizedSigner(signer).into());
+            }
+            approved_keys.insert(signer);
+        }
+        match authorization_keys.difference(&approved_keys).next() {
+            Some(unapproved_key) => Err(SignatureError::MissingApproval(*unapproved_key).into()),
+            None => Ok(()),
+        }
+    }
+
     pub fn commit_genesis(
         &self,
         correlation_id: CorrelationId,
@@ -132,6 +228,7 @@ where
         // Preliminaries
         let executor = Executor::new(self.config);
         let blocktime = BlockTime::new(GENESIS_INITIAL_BLOCKTIME);
+        let block_height = GENESIS_INITIAL_BLOCK_HEIGHT;
         let gas_limit = Gas::new(std::u64::MAX.into());
         let phase = Phase::System;
 
@@ -141,6 +238,14 @@ where
         let wasm_costs = genesis_config.wasm_costs();
         let preprocessor = Preprocessor::new(wasm_costs);
 
+        // Validate the config before executing anything.  An account listed more than once gets a
+        // single purse holding the sum of its balances.
+        if genesis_config.gas_price() == 0 {
+            return Err(GenesisError::ZeroGasPrice.into());
+        }
+        let genesis_accounts = genesis_config.merged_accounts()?;
+        let expected_total_supply = genesis_config.total_supply()?;
+
         // Spec #3: Create "virtual system account" object.
         let virtual_system_account = {
             let named_keys = BTreeMap::new();
@@ -235,6 +340,7 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -254,7 +360,8 @@ where
             // Spec #6: Compute initially bonded validators as the contents of accounts_path
             // filtered to non-zero staked amounts.
             let bonded_validators: BTreeMap<PublicKey, U512> = genesis_config
-                .get_bonded_validators()
+                .get_bonded_validators()?
+                .into_iter()
                 .map(|(k, v)| (k, v.value()))
                 .collect();
 
@@ -287,9 +394,10 @@ where
                     &virtual_system_account,
                     dummy_authorization_keys,
                     blocktime,
+                    block_height,
                     install_deploy_hash,
                     gas_limit,
-                    address_generator,
+                    Rc::clone(&address_generator),
                     protocol_version,
                     correlation_id,
                     Rc::clone(&tracking_copy),
@@ -326,10 +434,35 @@ where
                     .expect("should convert")
                     .expect("should convert");
                 let rewards_purse: URef = runtime
+                    .call_contract(mint_reference.into(), zero_args.clone())?
+                    .into_t::<Result<URef, mint::Error>>()
+                    .expect("should convert")
+                    .expect("should convert");
+                let community_purse: URef = runtime
                     .call_contract(mint_reference.into(), zero_args)?
                     .into_t::<Result<URef, mint::Error>>()
                     .expect("should convert")
                     .expect("should convert");
+                let unbonding_delay_uref = {
+                    let addr = address_generator.borrow_mut().create_address();
+                    let uref = URef::new(addr, AccessRights::READ_ADD_WRITE);
+                    let value =
+                        CLValue::from_t(genesis_config.unbonding_delay()).expect("should convert");
+                    tracking_copy
+                        .borrow_mut()
+                        .write(uref.into(), StoredValue::CLValue(value));
+                    uref.with_access_rights(AccessRights::READ)
+                };
+                let gas_price_uref = {
+                    let addr = address_generator.borrow_mut().create_address();
+                    let uref = URef::new(addr, AccessRights::READ_ADD_WRITE);
+                    let value =
+                        CLValue::from_t(genesis_config.gas_price()).expect("should convert");
+                    tracking_copy
+                        .borrow_mut()
+                        .write(uref.into(), StoredValue::CLValue(value));
+                    uref.with_access_rights(AccessRights::READ)
+                };
 
                 let named_keys = {
                     let mut tmp: BTreeMap<String, Key> =
@@ -338,6 +471,9 @@ where
                         (POS_BONDING_PURSE, bonding_purse),
                         (POS_PAYMENT_PURSE, payment_purse),
                         (POS_REWARDS_PURSE, rewards_purse),
+                        (POS_COMMUNITY_PURSE, community_purse),
+                        (POS_UNBONDING_DELAY, unbonding_delay_uref),
+                        (POS_GAS_PRICE, gas_price_uref),
                     ]
                     .iter()
                     .for_each(|(name, uref)| {
@@ -359,7 +495,12 @@ where
                 let proof_of_stake_installer_module =
                     preprocessor.preprocess(proof_of_stake_installer_bytes)?;
                 let args = {
-                    let args = (mint_reference, bonded_validators);
+                    let args = (
+                        mint_reference,
+                        bonded_validators,
+                        genesis_config.unbonding_delay(),
+                        genesis_config.gas_price(),
+                    );
                     ArgsParser::parse(args)
                         .expect("args should convert to `Vec<CLValue>`")
                         .into_bytes()
@@ -376,6 +517,7 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -433,6 +575,7 @@ where
                     &virtual_system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
                     install_deploy_hash,
                     gas_limit,
                     address_generator,
@@ -481,22 +624,75 @@ where
             ret
         };
 
+        // Record the chain name for contracts to read via `get_protocol_data`
+        let chain_name_uref = {
+            let addr = address_generator.borrow_mut().create_address();
+            let uref = URef::new(addr, AccessRights::READ);
+            let value = CLValue::from_t(genesis_config.name().to_string()).expect("should convert");
+            tracking_copy
+                .borrow_mut()
+                .write(uref.into(), StoredValue::CLValue(value));
+            uref
+        };
+
         // Create known keys for system account
-        let system_account_named_keys = {
+        let mut system_account_named_keys = {
             let mut ret = BTreeMap::new();
             ret.insert(MINT_NAME.to_string(), Key::URef(mint_reference));
             ret.insert(POS_NAME.to_string(), Key::URef(proof_of_stake_reference));
+            ret.insert(CHAIN_NAME_KEY.to_string(), Key::URef(chain_name_uref));
             ret
         };
 
+        // Execute the installers of the genesis contracts, each of which returns the key of the
+        // contract it stored.  The system account holds these keys under the contracts' names.
+        for genesis_contract in genesis_config.genesis_contracts() {
+            let install = || -> Result<Key, Error> {
+                let installer_module =
+                    preprocessor.preprocess(genesis_contract.installer_bytes())?;
+                let args = genesis_contract.args().to_vec();
+                let mut named_keys = BTreeMap::new();
+                let authorization_keys = BTreeSet::new();
+                let install_deploy_hash = install_deploy_hash.into();
+                let address_generator = Rc::clone(&address_generator);
+                let tracking_copy = Rc::clone(&tracking_copy);
+                let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
+
+                let key = executor.exec_system(
+                    installer_module,
+                    args,
+                    &mut named_keys,
+                    initial_base_key,
+                    &virtual_system_account,
+                    authorization_keys,
+                    blocktime,
+                    block_height,
+                    install_deploy_hash,
+                    gas_limit,
+                    address_generator,
+                    protocol_version,
+                    correlation_id,
+                    tracking_copy,
+                    phase,
+                    protocol_data,
+                    system_contract_cache,
+                )?;
+                Ok(key)
+            };
+
+            let key = install().map_err(|error| Error::GenesisContract {
+                name: genesis_contract.name().to_string(),
+                error: Box::new(error),
+            })?;
+            system_account_named_keys.insert(genesis_contract.name().to_string(), key);
+        }
+
         // Create accounts
         {
             // Collect chainspec accounts and their known keys with the genesis account and its
             // known keys
             let accounts = {
-                let mut ret: Vec<(GenesisAccount, KnownKeys)> = genesis_config
-                    .accounts()
-                    .to_vec()
+                let mut ret: Vec<(GenesisAccount, KnownKeys)> = genesis_accounts
                     .into_iter()
                     .map(|account| (account, account_named_keys.clone()))
                     .collect();
@@ -554,6 +750,7 @@ where
                         &virtual_system_account,
                         authorization_keys,
                         blocktime,
+                        block_height,
                         purse_creation_deploy_hash,
                         gas_limit,
                         address_generator,
@@ -579,6 +776,7 @@ where
                         &virtual_system_account,
                         authorization_keys,
                         blocktime,
+                        block_height,
                         purse_creation_deploy_hash,
                         gas_limit,
                         address_generator,
@@ -606,6 +804,37 @@ where
             }
         }
 
+        // Check the mint recorded exactly the balances of the account purses and the bonds held
+        // by the PoS bonding purse
+        {
+            let mint_contract = tracking_copy
+                .borrow_mut()
+                .get_contract(correlation_id, Key::URef(mint_reference))?;
+            let total_supply_key = mint_contract.named_keys().get(::mint::TOTAL_SUPPLY_KEY);
+            let actual_total_supply: U512 = match total_supply_key {
+                Some(key) => match tracking_copy.borrow_mut().read(correlation_id, key) {
+                    Ok(Some(StoredValue::CLValue(cl_value))) => {
+                        cl_value.into_t().map_err(execution::Error::from)?
+                    }
+                    Ok(Some(other)) => {
+                        return Err(Error::Exec(execution::Error::TypeMismatch(
+                            TypeMismatch::new("CLValue".to_string(), other.type_name()),
+                        )))
+                    }
+                    Ok(None) => return Err(Error::Exec(execution::Error::KeyNotFound(*key))),
+                    Err(error) => return Err(Error::Exec(error.into())),
+                },
+                None => return Err(GenesisError::MissingTotalSupply.into()),
+            };
+            if actual_total_supply != expected_total_supply.value() {
+                return Err(GenesisError::TotalSupplyMismatch {
+                    expected: expected_total_supply.value(),
+                    actual: actual_total_supply,
+                }
+                .into());
+            }
+        }
+
         // Spec #15: Commit the transforms.
         let effects = tracking_copy.borrow().effect();
 
@@ -726,6 +955,7 @@ where
                 };
 
                 let blocktime = BlockTime::default();
+                let block_height = 0;
 
                 let deploy_hash = {
                     // seeds address generator w/ protocol version
@@ -757,6 +987,7 @@ where
                     &system_account,
                     authorization_keys,
                     blocktime,
+                    block_height,
                     deploy_hash,
                     gas_limit,
                     address_generator,
@@ -819,11 +1050,34 @@ where
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
     ) -> Result<Vec<ExecutionResult>, RootNotFound> {
-        // TODO: do not unwrap
-        let wasm_costs = self
-            .wasm_costs(exec_request.protocol_version)
-            .unwrap()
-            .unwrap();
+        // Deploys are charged according to the wasm costs of the protocol version they're executed
+        // under, so that blocks from before an upgrade replay with the costs they were run with.
+        let protocol_version = exec_request.protocol_version;
+        let wasm_costs = match self.wasm_costs(protocol_version) {
+            Ok(Some(wasm_costs)) => wasm_costs,
+            Ok(None) => {
+                return Ok(exec_request
+                    .take_deploys()
+                    .into_iter()
+                    .map(|_| {
+                        let error = Error::InvalidProtocolVersion(protocol_version);
+                        ExecutionResult::precondition_failure(error)
+                    })
+                    .collect());
+            }
+            Err(error) => {
+                // The error can't be cloned, so each deploy reports its description.
+                let message = error.to_string();
+                return Ok(exec_request
+                    .take_deploys()
+                    .into_iter()
+                    .map(|_| {
+                        let error = Error::ProtocolDataRead(protocol_version, message.clone());
+                        ExecutionResult::precondition_failure(error)
+                    })
+                    .collect());
+            }
+        };
         let executor = Executor::new(self.config);
         let preprocessor = Preprocessor::new(wasm_costs);
 
@@ -831,15 +1085,30 @@ where
 
         for deploy_item in exec_request.take_deploys() {
             let result = match deploy_item {
-                Ok(deploy_item) => self.deploy(
-                    correlation_id,
-                    &executor,
-                    &preprocessor,
-                    exec_request.protocol_version,
-                    exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
-                    deploy_item,
-                ),
+                Ok(deploy_item) => {
+                    let deploy_hash = deploy_item.deploy_hash;
+                    deploy_observer::notify_deploy_start(self.observer.as_ref(), &deploy_hash);
+                    let start = Instant::now();
+                    let result = self.deploy(
+                        correlation_id,
+                        &executor,
+                        &preprocessor,
+                        exec_request.protocol_version,
+                        exec_request.parent_state_hash,
+                        BlockTime::new(exec_request.block_time),
+                        exec_request.block_height,
+                        deploy_item,
+                    );
+                    if let Ok(execution_result) = &result {
+                        deploy_observer::notify_deploy_finish(
+                            self.observer.as_ref(),
+                            &deploy_hash,
+                            execution_result,
+                            start.elapsed(),
+                        );
+                    }
+                    result
+                }
                 Err(exec_result) => Ok(exec_result), /* this will get pushed into the results vec
                                                       * below */
             };
@@ -854,6 +1123,268 @@ where
         Ok(results)
     }
 
+    /// Executes the deploys of `exec_request` in the same way as [`EngineState::run_execute`] and
+    /// merges their effects into one which can be committed in one go.
+    ///
+    /// Every deploy is executed against the request's parent state hash.  Their effects are then
+    /// merged in order, and a deploy is skipped, i.e. left out of the merged effect, if any of its
+    /// transforms doesn't commute with the merged transform of the same key, e.g. if one of them
+    /// is a write, or if it read a key which an earlier merged deploy wrote or added to.  `Add*`
+    /// transforms of the same key are summed.  A deploy which writes a key only read by an earlier
+    /// one isn't skipped, as running the two in order would have the same effect.
+    pub fn run_deploys(
+        &self,
+        correlation_id: CorrelationId,
+        exec_request: ExecuteRequest,
+    ) -> Result<BatchResult, RootNotFound> {
+        let execution_results = self.run_execute(correlation_id, exec_request)?;
+        Ok(BatchResult::new(execution_results))
+    }
+
+    /// Executes `deploy_item` against the state at `prestate_hash` in the same way as
+    /// [`EngineState::run_execute`], but returns a [`SimulationResult`] whose effect can't be
+    /// committed.  Useful for estimating gas and previewing changes before submitting a deploy.
+    pub fn simulate_deploy(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Blake2bHash,
+        blocktime: BlockTime,
+        block_height: u64,
+        deploy_item: DeployItem,
+    ) -> Result<SimulationResult, RootNotFound> {
+        let wasm_costs = match self.wasm_costs(protocol_version) {
+            Ok(Some(wasm_costs)) => wasm_costs,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return Ok(ExecutionResult::precondition_failure(error).into());
+            }
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error).into()),
+        };
+        let executor = Executor::new(self.config);
+        let preprocessor = Preprocessor::new(wasm_costs);
+
+        let execution_result = self.deploy(
+            correlation_id,
+            &executor,
+            &preprocessor,
+            protocol_version,
+            prestate_hash,
+            blocktime,
+            block_height,
+            deploy_item,
+        )?;
+        Ok(execution_result.into())
+    }
+
+    /// Executes `transfer_request` natively, i.e. by calling the mint directly against the state at
+    /// the request's parent state hash rather than by running payment and session Wasm.
+    ///
+    /// The source account is charged the fixed `native_transfer` cost from the wasm costs of the
+    /// request's protocol version, which is paid into the PoS rewards purse.  If the transfer
+    /// itself then fails, only the payment of that cost is kept in the returned effect.
+    pub fn transfer(
+        &self,
+        correlation_id: CorrelationId,
+        transfer_request: TransferRequest,
+    ) -> Result<ExecutionResult, RootNotFound> {
+        let TransferRequest {
+            parent_state_hash,
+            block_time,
+            block_height,
+            protocol_version,
+            source,
+            target,
+            amount,
+            authorization_keys,
+            deploy_hash,
+        } = transfer_request;
+
+        let tracking_copy = match self.tracking_copy(parent_state_hash) {
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+            Ok(None) => return Err(RootNotFound::new(parent_state_hash)),
+            Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
+        };
+
+        let account: Account = match tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, source)
+        {
+            Ok(account) => account,
+            Err(_) => return Ok(ExecutionResult::precondition_failure(Error::Authorization)),
+        };
+        if let Err(error) = account.authorize(&authorization_keys, ActionType::Deployment) {
+            let error = Error::InvalidAuthorization(error);
+            return Ok(ExecutionResult::precondition_failure(error));
+        }
+
+        let protocol_data = match self.state.get_protocol_data(protocol_version) {
+            Ok(Some(protocol_data)) => protocol_data,
+            Ok(None) => {
+                let error = Error::InvalidProtocolVersion(protocol_version);
+                return Ok(ExecutionResult::precondition_failure(error));
+            }
+            Err(error) => {
+                return Ok(ExecutionResult::precondition_failure(Error::Exec(
+                    error.into(),
+                )));
+            }
+        };
+
+        let mint_reference = protocol_data.mint();
+        let mint_contract = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(mint_reference))
+        {
+            Ok(contract) => contract,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+        };
+
+        let proof_of_stake_contract = match tracking_copy
+            .borrow_mut()
+            .get_contract(correlation_id, Key::URef(protocol_data.proof_of_stake()))
+        {
+            Ok(contract) => contract,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
+        };
+
+        let rewards_purse: URef = match proof_of_stake_contract.named_keys().get(POS_REWARDS_PURSE)
+        {
+            Some(Key::URef(uref)) => *uref,
+            _ => return Ok(ExecutionResult::precondition_failure(Error::Deploy)),
+        };
+
+        let gas_price = match get_gas_price(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            proof_of_stake_contract.named_keys(),
+        ) {
+            Ok(gas_price) => gas_price,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+        };
+
+        let cost = Gas::new(U512::from(protocol_data.wasm_costs().native_transfer));
+        let fee = Motes::from_gas(cost, gas_price).expect("motes overflow");
+
+        let target_key = Key::Account(target);
+        let maybe_target_account =
+            match tracking_copy.borrow_mut().read(correlation_id, &target_key) {
+                Ok(Some(StoredValue::Account(target_account))) => Some(target_account),
+                Ok(Some(_)) => {
+                    let error = execution::Error::AccountNotFound(target_key);
+                    return Ok(ExecutionResult::precondition_failure(error.into()));
+                }
+                Ok(None) => None,
+                Err(error) => {
+                    return Ok(ExecutionResult::precondition_failure(Error::Exec(
+                        error.into(),
+                    )));
+                }
+            };
+
+        let source_purse = account.main_purse();
+        let mut mint_named_keys = mint_contract.take_named_keys();
+        let access_rights = {
+            let mut keys: Vec<Key> = mint_named_keys.values().cloned().collect();
+            keys.push(Key::URef(source_purse));
+            keys.push(Key::URef(rewards_purse));
+            extract_access_rights_from_keys(keys)
+        };
+        let base_key = Key::URef(mint_reference);
+        let address_generator = AddressGenerator::new(&deploy_hash, Phase::Session);
+
+        let mut mint_context = RuntimeContext::new(
+            Rc::clone(&tracking_copy),
+            &mut mint_named_keys,
+            access_rights,
+            RuntimeArgs::new(),
+            authorization_keys,
+            &account,
+            base_key,
+            runtime_context::new_call_stack(&account, base_key),
+            BlockTime::new(block_time),
+            block_height,
+            deploy_hash,
+            cost,
+            Gas::default(),
+            FN_STORE_ID_INITIAL,
+            Rc::new(RefCell::new(address_generator)),
+            protocol_version,
+            correlation_id,
+            Phase::Session,
+            protocol_data,
+        );
+
+        // The cost is charged up front, in the same way as the payment code of a deploy is run
+        // before its session code.
+        match mint_context.transfer(source_purse, rewards_purse, fee.value()) {
+            Ok(()) => (),
+            Err(mint::Error::InsufficientFunds) => {
+                return Ok(ExecutionResult::precondition_failure(
+                    Error::InsufficientPayment,
+                ))
+            }
+            Err(error) => {
+                let error = execution::Error::SystemContract(error.into());
+                return Ok(ExecutionResult::precondition_failure(error.into()));
+            }
+        }
+        let payment_effect = tracking_copy.borrow().effect();
+
+        let transfer_result = match maybe_target_account {
+            Some(target_account) => {
+                let target_purse = target_account.main_purse_add_only();
+                if target_purse.addr() == source_purse.addr() {
+                    Ok(())
+                } else {
+                    mint_context.transfer(source_purse, target_purse, amount)
+                }
+            }
+            None => match mint_context.mint(U512::zero()) {
+                Ok(target_purse) => {
+                    let result = mint_context.transfer(source_purse, target_purse, amount);
+                    if result.is_ok() {
+                        // As for accounts created by `transfer_to_account`, the mint and PoS are
+                        // kept in the named keys for backwards compatibility.
+                        let named_keys = vec![
+                            (
+                                String::from(MINT_NAME),
+                                Key::URef(mint_reference.into_read()),
+                            ),
+                            (
+                                String::from(POS_NAME),
+                                Key::URef(protocol_data.proof_of_stake().into_read()),
+                            ),
+                        ]
+                        .into_iter()
+                        .collect();
+                        let target_account = Account::create(target, named_keys, target_purse);
+                        tracking_copy
+                            .borrow_mut()
+                            .write(target_key, StoredValue::Account(target_account));
+                    }
+                    result
+                }
+                Err(error) => Err(error),
+            },
+        };
+
+        match transfer_result {
+            Ok(()) => Ok(ExecutionResult::Success {
+                effect: tracking_copy.borrow().effect(),
+                cost,
+                ret: None,
+                phases: None,
+            }),
+            Err(error) => Ok(ExecutionResult::Failure {
+                error: Error::Exec(execution::Error::SystemContract(error.into())),
+                effect: payment_effect,
+                cost,
+                phases: None,
+            }),
+        }
+    }
+
     pub fn get_module(
         &self,
         tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
@@ -865,7 +1396,9 @@ where
     ) -> Result<Module, error::Error> {
         let stored_contract_key = match deploy_item {
             ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
-                let module = preprocessor.preprocess(&module_bytes)?;
+                let module = self
+                    .module_cache
+                    .get_or_preprocess(&module_bytes, preprocessor)?;
                 return Ok(module);
             }
             ExecutableDeployItem::StoredContractByHash { hash, .. } => {
@@ -976,6 +1509,7 @@ where
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_item: DeployItem,
     ) -> Result<ExecutionResult, RootNotFound> {
         // spec: https://casperlabs.atlassian.net/wiki/spaces/EN/pages/123404576/Payment+code+execution+specification
@@ -986,6 +1520,14 @@ where
         let authorization_keys = deploy_item.authorization_keys;
         let deploy_hash = deploy_item.deploy_hash;
 
+        if self.config.verify_approvals() {
+            if let Err(error) =
+                self.verify_approvals(&deploy_hash, &authorization_keys, &deploy_item.approvals)
+            {
+                return Ok(ExecutionResult::precondition_failure(error));
+            }
+        }
+
         // Create tracking copy (which functions as a deploy context)
         // validation_spec_2: prestate_hash check
         let tracking_copy = match self.tracking_copy(prestate_hash) {
@@ -1019,39 +1561,15 @@ where
             }
         };
 
-        // Authorize using provided authorization keys
+        // Authorize using provided authorization keys, checking their total weight against the
+        // deploy threshold
         // validation_spec_3: account validity
-        if !account.can_authorize(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
-                crate::engine_state::error::Error::Authorization,
-            ));
-        }
-
-        // Check total key weight against deploy threshold
         // validation_spec_4: deploy validity
-        if !account.can_deploy_with(&authorization_keys) {
-            return Ok(ExecutionResult::precondition_failure(
-                // TODO?:this doesn't happen in execution any longer, should error variant be moved
-                execution::Error::DeploymentAuthorizationFailure.into(),
-            ));
+        if let Err(error) = account.authorize(&authorization_keys, ActionType::Deployment) {
+            let error = Error::InvalidAuthorization(error);
+            return Ok(ExecutionResult::precondition_failure(error));
         }
 
-        // Create session code `A` from provided session bytes
-        // validation_spec_1: valid wasm bytes
-        let session_module = match self.get_module(
-            Rc::clone(&tracking_copy),
-            &session,
-            &account,
-            correlation_id,
-            preprocessor,
-            &protocol_version,
-        ) {
-            Ok(module) => module,
-            Err(error) => {
-                return Ok(ExecutionResult::precondition_failure(error));
-            }
-        };
-
         // Obtain current protocol data for given version
         let protocol_data = match self.state.get_protocol_data(protocol_version) {
             Ok(Some(protocol_data)) => protocol_data,
@@ -1112,6 +1630,17 @@ where
             }
         };
 
+        // Get the number of motes charged per unit of gas, which all conversions between payment
+        // and gas below use
+        let gas_price = match get_gas_price(
+            correlation_id,
+            &mut tracking_copy.borrow_mut(),
+            proof_of_stake_contract.named_keys(),
+        ) {
+            Ok(gas_price) => gas_price,
+            Err(error) => return Ok(ExecutionResult::precondition_failure(error)),
+        };
+
         // Get rewards purse balance key
         // payment_code_spec_6: system contract validity
         let rewards_purse_balance_key: Key = {
@@ -1171,6 +1700,54 @@ where
             ));
         }
 
+        // Session code supplied as module bytes is charged for preprocessing by length, so reject
+        // a deploy whose payment can't cover that before doing any of the work.  Standard payment
+        // pays at most the amount passed to it, custom payment at most the main purse balance.
+        let session_preprocessing_cost = match session {
+            ExecutableDeployItem::ModuleBytes {
+                ref module_bytes, ..
+            } => {
+                let wasm_costs = protocol_data.wasm_costs();
+                let minimum_payment = preprocessing_cost::estimated_minimum_payment(
+                    wasm_costs,
+                    module_bytes.len(),
+                    gas_price,
+                );
+                let payment_ceiling = match payment {
+                    ExecutableDeployItem::ModuleBytes {
+                        ref module_bytes,
+                        ref args,
+                    } if module_bytes.is_empty() => get_requested_payment_amount(args)
+                        .map(Motes::new)
+                        .unwrap_or_default(),
+                    _ => account_main_purse_balance,
+                };
+                if payment_ceiling < minimum_payment {
+                    return Ok(ExecutionResult::precondition_failure(
+                        Error::InsufficientPayment,
+                    ));
+                }
+                preprocessing_cost::preprocessing_cost(wasm_costs, module_bytes.len())
+            }
+            _ => Gas::default(),
+        };
+
+        // Create session code `A` from provided session bytes
+        // validation_spec_1: valid wasm bytes
+        let session_module = match self.get_module(
+            Rc::clone(&tracking_copy),
+            &session,
+            &account,
+            correlation_id,
+            preprocessor,
+            &protocol_version,
+        ) {
+            Ok(module) => module,
+            Err(error) => {
+                return Ok(ExecutionResult::precondition_failure(error));
+            }
+        };
+
         // Finalization is executed by system account (currently genesis account)
         // payment_code_spec_5: system executes finalization
         let system_account = Account::new(
@@ -1184,11 +1761,20 @@ where
         // [`ExecutionResultBuilder`] handles merging of multiple execution results
         let mut execution_result_builder = execution_result::ExecutionResultBuilder::new();
 
+        // Standard payment takes an optional refund purse as its second argument
+        let requested_refund_purse = match payment {
+            ExecutableDeployItem::ModuleBytes {
+                ref module_bytes,
+                ref args,
+            } if module_bytes.is_empty() => get_requested_refund_purse(args),
+            _ => None,
+        };
+
         // Execute provided payment code
         let payment_result = {
             // payment_code_spec_1: init pay environment w/ gas limit == (max_payment_cost /
             // conv_rate)
-            let pay_gas_limit = Gas::from_motes(max_payment_cost, CONV_RATE).unwrap_or_default();
+            let pay_gas_limit = Gas::from_motes(max_payment_cost, gas_price).unwrap_or_default();
 
             let module_bytes_is_empty = match payment {
                 ExecutableDeployItem::ModuleBytes {
@@ -1252,6 +1838,7 @@ where
                     &account,
                     authorization_keys.clone(),
                     blocktime,
+                    block_height,
                     deploy_hash,
                     pay_gas_limit,
                     Rc::new(RefCell::new(address_generator)),
@@ -1273,11 +1860,14 @@ where
                     Ok(()) => ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         cost: runtime.context().gas_counter(),
+                        ret: None,
+                        phases: None,
                     },
                     Err(error) => ExecutionResult::Failure {
                         error: error.into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        phases: None,
                     },
                 }
             } else {
@@ -1288,6 +1878,7 @@ where
                     &account,
                     authorization_keys.clone(),
                     blocktime,
+                    block_height,
                     deploy_hash,
                     pay_gas_limit,
                     protocol_version,
@@ -1335,7 +1926,9 @@ where
             }
         };
 
-        if let Some(forced_transfer) = payment_result.check_forced_transfer(payment_purse_balance) {
+        if let Some(forced_transfer) =
+            payment_result.check_forced_transfer(payment_purse_balance, gas_price)
+        {
             let error = match forced_transfer {
                 ForcedTransferResult::InsufficientPayment => Error::InsufficientPayment,
                 ForcedTransferResult::PaymentFailure => payment_result.take_error().unwrap(),
@@ -1343,6 +1936,7 @@ where
             return Ok(ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
+                gas_price,
                 account_main_purse_balance,
                 account_main_purse_balance_key,
                 rewards_purse_balance_key,
@@ -1360,35 +1954,53 @@ where
             // payment code execution) * conv_rate, yes session
             // session_code_spec_1: gas limit = ((balance of PoS payment purse) / conv_rate)
             // - (gas spent during payment execution)
-            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, CONV_RATE)
+            let session_gas_limit: Gas = Gas::from_motes(payment_purse_balance, gas_price)
                 .unwrap_or_default()
                 - payment_result_cost;
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-            executor.exec(
-                session_module,
-                session.take_args(),
-                address,
-                &account,
-                authorization_keys.clone(),
-                blocktime,
-                deploy_hash,
-                session_gas_limit,
-                protocol_version,
-                correlation_id,
-                Rc::clone(&session_tc),
-                Phase::Session,
-                protocol_data,
-                system_contract_cache,
-            )
+            // The session's preprocessing is paid for out of its gas limit before any of it runs
+            if session_preprocessing_cost > session_gas_limit {
+                ExecutionResult::Failure {
+                    error: Error::Exec(execution::Error::GasLimit),
+                    effect: Default::default(),
+                    cost: session_gas_limit,
+                    phases: None,
+                }
+            } else {
+                let session_result = executor.exec(
+                    session_module,
+                    session.take_args(),
+                    address,
+                    &account,
+                    authorization_keys.clone(),
+                    blocktime,
+                    block_height,
+                    deploy_hash,
+                    session_gas_limit - session_preprocessing_cost,
+                    protocol_version,
+                    correlation_id,
+                    Rc::clone(&session_tc),
+                    Phase::Session,
+                    protocol_data,
+                    system_contract_cache,
+                );
+                let session_cost = session_result.cost() + session_preprocessing_cost;
+                session_result.with_cost(session_cost)
+            }
         };
 
-        let post_session_rc = if session_result.is_failure() {
-            // If session code fails we do not include its effects,
-            // so we start again from the post-payment state.
-            Rc::new(RefCell::new(post_payment_tc.fork()))
+        let (session_result, post_session_rc) = if session_result.is_failure() {
+            // If session code fails we do not include its effects, so we start again from the
+            // post-payment state.  The effects it made before failing are passed on regardless,
+            // so that the builder can check none of them make it into the deploy's effect.
+            let attempted_effect = session_tc.borrow().effect();
+            (
+                session_result.with_effect(attempted_effect),
+                Rc::new(RefCell::new(post_payment_tc.fork())),
+            )
         } else {
-            session_tc
+            (session_result, session_tc)
         };
 
         // NOTE: session_code_spec_3: (do not include session execution effects in
@@ -1420,7 +2032,7 @@ where
 
             let proof_of_stake_args = {
                 //((gas spent during payment code execution) + (gas spent during session code execution)) * conv_rate
-                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), CONV_RATE).expect("motes overflow");
+                let finalize_cost_motes: Motes = Motes::from_gas(execution_result_builder.total_cost(), gas_price).expect("motes overflow");
                 let args = ("finalize_payment", finalize_cost_motes.value(), account_addr);
                 ArgsParser::parse(args)
                     .expect("args should convert to `Vec<CLValue>`")
@@ -1440,6 +2052,26 @@ where
 
             let mut proof_of_stake_keys = proof_of_stake_contract.named_keys().to_owned();
 
+            // Standard payment disregards a refund purse the account can't add to, and PoS falls
+            // back to the main purse if the refund purse isn't a purse at all
+            if let Some(refund_purse) = requested_refund_purse {
+                let is_refund_purse_set = proof_of_stake_keys
+                    .get(POS_REFUND_PURSE)
+                    .and_then(Key::as_uref)
+                    .map(|uref| uref.addr())
+                    == Some(refund_purse.addr());
+                let is_purse = post_session_tc
+                    .fork()
+                    .get_purse_balance_key(correlation_id, mint_reference, Key::URef(refund_purse))
+                    .is_ok();
+                if !is_refund_purse_set || !is_purse {
+                    execution_result_builder.add_note(format!(
+                        "Refund purse {} is invalid, refunding to the account's main purse instead",
+                        refund_purse
+                    ));
+                }
+            }
+
             let base_key = Key::from(proof_of_stake_reference);
             let gas_limit = Gas::new(U512::from(std::u64::MAX));
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
@@ -1452,6 +2084,7 @@ where
                 &system_account,
                 authorization_keys,
                 blocktime,
+                block_height,
                 deploy_hash,
                 gas_limit,
                 protocol_version,
@@ -1465,11 +2098,25 @@ where
 
         execution_result_builder.set_finalize_execution_result(finalize_result);
 
-        // We panic here to indicate that the builder was not used properly.
-        let ret = execution_result_builder
+        // We panic here to indicate that the builder was not used properly, or that the effects of
+        // a failed session would otherwise have been committed.
+        let mut ret = execution_result_builder
             .build(tracking_copy.borrow().reader(), correlation_id)
             .expect("ExecutionResultBuilder not initialized properly");
 
+        if self.config.prune_unreachable_urefs() {
+            let mut effect = ret.effect().clone();
+            let pre_state = tracking_copy.borrow();
+            if let Err(error) =
+                pruning::prune_unreachable_urefs(correlation_id, pre_state.reader(), &mut effect)
+            {
+                return Ok(ExecutionResult::precondition_failure(Error::Exec(
+                    error.into(),
+                )));
+            }
+            ret = ret.with_effect(effect);
+        }
+
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
@@ -1487,12 +2134,15 @@ where
         Error: From<S::Error>,
     {
         match self.state.commit(correlation_id, pre_state_hash, effects)? {
-            CommitResult::Success { state_root, .. } => {
+            CommitResult::Success {
+                state_root, stats, ..
+            } => {
                 let bonded_validators =
                     self.get_bonded_validators(correlation_id, protocol_version, state_root)?;
                 Ok(CommitResult::Success {
                     state_root,
                     bonded_validators,
+                    stats,
                 })
             }
             commit_result => Ok(commit_result),
@@ -1541,3 +2191,41 @@ where
         Ok(bonded_validators)
     }
 }
+
+/// Returns the number of motes charged per unit of gas, as stored by the proof of stake contract
+/// at genesis, or [`CONV_RATE`] if the contract predates the setting.
+fn get_gas_price<R>(
+    correlation_id: CorrelationId,
+    tracking_copy: &mut TrackingCopy<R>,
+    proof_of_stake_named_keys: &BTreeMap<String, Key>,
+) -> Result<u64, Error>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    let gas_price_key = match proof_of_stake_named_keys.get(POS_GAS_PRICE) {
+        Some(key) => key.normalize(),
+        None => return Ok(CONV_RATE),
+    };
+    match tracking_copy
+        .get(correlation_id, &gas_price_key)
+        .map_err(Into::<execution::Error>::into)?
+    {
+        Some(StoredValue::CLValue(cl_value)) => cl_value
+            .into_t()
+            .map_err(|error| execution::Error::CLValue(error).into()),
+        _ => Err(Error::Deploy),
+    }
+}
+
+/// Returns the amount passed as the first argument to standard payment, if any.
+fn get_requested_payment_amount(args: &[u8]) -> Option<U512> {
+    let args: RuntimeArgs = bytesrepr::deserialize(args.to_vec()).ok()?;
+    args.get_by_index(0)?.clone().into_t().ok()
+}
+
+/// Returns the refund purse passed as the second argument to standard payment, if any.
+fn get_requested_refund_purse(args: &[u8]) -> Option<URef> {
+    let args: RuntimeArgs = bytesrepr::deserialize(args.to_vec()).ok()?;
+    args.get_by_index(1)?.clone().into_t().ok()
+}
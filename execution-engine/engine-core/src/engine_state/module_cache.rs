@@ -0,0 +1,241 @@
+use std::sync::{Arc, Mutex};
+
+use linked_hash_map::LinkedHashMap;
+use parity_wasm::elements::Module;
+
+use engine_shared::newtypes::Blake2bHash;
+use engine_wasm_prep::{PreprocessingError, Preprocessor};
+use types::bytesrepr::ToBytes;
+
+/// Identifies a module by the hash of its bytes and by the hash of the settings of the
+/// preprocessor it was preprocessed with, so that a change of the wasm costs misses the cache.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct ModuleCacheKey {
+    module_hash: Blake2bHash,
+    preprocessor_hash: Blake2bHash,
+}
+
+impl ModuleCacheKey {
+    /// Returns `None` if the settings of `preprocessor` can't be serialized, in which case the
+    /// module isn't cached.
+    fn new(module_bytes: &[u8], preprocessor: &Preprocessor) -> Option<Self> {
+        let mut preprocessor_bytes = preprocessor.wasm_costs().to_bytes().ok()?;
+        preprocessor_bytes.push(preprocessor.allow_floats() as u8);
+        Some(ModuleCacheKey {
+            module_hash: Blake2bHash::new(module_bytes),
+            preprocessor_hash: Blake2bHash::new(&preprocessor_bytes),
+        })
+    }
+}
+
+/// A cache of preprocessed modules, which spares re-preprocessing the same session or payment code
+/// for every deploy.
+///
+/// Holds at most `capacity` modules, above which the least-recently-used one is evicted.  A
+/// capacity of zero disables caching.
+#[derive(Clone, Debug)]
+pub struct ModuleCache {
+    capacity: usize,
+    modules: Arc<Mutex<LinkedHashMap<ModuleCacheKey, Module>>>,
+}
+
+impl ModuleCache {
+    /// Creates an empty cache holding at most `capacity` modules.
+    pub fn new(capacity: usize) -> Self {
+        ModuleCache {
+            capacity,
+            modules: Default::default(),
+        }
+    }
+
+    /// Returns the maximum number of modules held by the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of modules currently held by the cache.
+    pub fn len(&self) -> usize {
+        self.modules.lock().unwrap().len()
+    }
+
+    /// Returns `true` if the cache holds no modules.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the cache holds the module preprocessed from `module_bytes` by
+    /// `preprocessor`.
+    pub fn has(&self, module_bytes: &[u8], preprocessor: &Preprocessor) -> bool {
+        match ModuleCacheKey::new(module_bytes, preprocessor) {
+            Some(key) => self.modules.lock().unwrap().contains_key(&key),
+            None => false,
+        }
+    }
+
+    /// Returns a clone of the module preprocessed from `module_bytes` by `preprocessor`,
+    /// preprocessing and caching it first if the cache doesn't hold it yet.
+    ///
+    /// Modules failing to preprocess aren't cached.
+    pub fn get_or_preprocess(
+        &self,
+        module_bytes: &[u8],
+        preprocessor: &Preprocessor,
+    ) -> Result<Module, PreprocessingError> {
+        if self.capacity == 0 {
+            return preprocessor.preprocess(module_bytes);
+        }
+        let key = match ModuleCacheKey::new(module_bytes, preprocessor) {
+            Some(key) => key,
+            None => return preprocessor.preprocess(module_bytes),
+        };
+
+        if let Some(module) = self.modules.lock().unwrap().get_refresh(&key) {
+            return Ok(module.clone());
+        }
+
+        // The lock isn't held while preprocessing, so that deploys of different modules don't
+        // wait for each other.  Concurrent misses of the same module preprocess it more than once.
+        let module = preprocessor.preprocess(module_bytes)?;
+
+        let mut modules = self.modules.lock().unwrap();
+        modules.insert(key, module.clone());
+        while modules.len() > self.capacity {
+            modules.pop_front();
+        }
+        Ok(module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use parity_wasm::builder;
+
+    use engine_shared::test_utils;
+    use engine_wasm_prep::Preprocessor;
+
+    use super::ModuleCache;
+
+    fn module_bytes(initial_memory_pages: u32) -> Vec<u8> {
+        let module = builder::module()
+            .memory()
+            .with_min(initial_memory_pages)
+            .build()
+            .build();
+        parity_wasm::serialize(module).expect("should serialize module")
+    }
+
+    fn preprocessor() -> Preprocessor {
+        Preprocessor::new(test_utils::wasm_costs_mock())
+    }
+
+    #[test]
+    fn should_cache_preprocessed_module() {
+        let cache = ModuleCache::new(2);
+        let module_bytes = module_bytes(1);
+        let preprocessor = preprocessor();
+
+        assert!(!cache.has(&module_bytes, &preprocessor));
+        let module = cache
+            .get_or_preprocess(&module_bytes, &preprocessor)
+            .expect("should preprocess");
+        assert!(cache.has(&module_bytes, &preprocessor));
+        assert_eq!(module, preprocessor.preprocess(&module_bytes).unwrap());
+
+        let cached_module = cache
+            .get_or_preprocess(&module_bytes, &preprocessor)
+            .expect("should get cached module");
+        assert_eq!(cached_module, module);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn should_miss_cache_for_different_wasm_costs() {
+        let cache = ModuleCache::new(2);
+        let module_bytes = module_bytes(1);
+        let preprocessor = preprocessor();
+        let free_preprocessor = Preprocessor::new(test_utils::wasm_costs_free());
+
+        cache
+            .get_or_preprocess(&module_bytes, &preprocessor)
+            .expect("should preprocess");
+        assert!(!cache.has(&module_bytes, &free_preprocessor));
+
+        let module = cache
+            .get_or_preprocess(&module_bytes, &free_preprocessor)
+            .expect("should preprocess");
+        assert_eq!(module, free_preprocessor.preprocess(&module_bytes).unwrap());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn should_evict_least_recently_used_module() {
+        let cache = ModuleCache::new(2);
+        let preprocessor = preprocessor();
+        let (module_1, module_2, module_3) = (module_bytes(1), module_bytes(2), module_bytes(3));
+
+        for module_bytes in &[&module_1, &module_2, &module_1, &module_3] {
+            cache
+                .get_or_preprocess(module_bytes, &preprocessor)
+                .expect("should preprocess");
+        }
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.has(&module_1, &preprocessor));
+        assert!(!cache.has(&module_2, &preprocessor));
+        assert!(cache.has(&module_3, &preprocessor));
+    }
+
+    #[test]
+    fn should_not_cache_with_zero_capacity() {
+        let cache = ModuleCache::new(0);
+        let module_bytes = module_bytes(1);
+        let preprocessor = preprocessor();
+
+        cache
+            .get_or_preprocess(&module_bytes, &preprocessor)
+            .expect("should preprocess");
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn should_not_cache_invalid_module() {
+        let cache = ModuleCache::new(2);
+        let preprocessor = preprocessor();
+
+        assert!(cache.get_or_preprocess(&[0, 1, 2], &preprocessor).is_err());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn should_share_cache_between_threads() {
+        let cache = ModuleCache::new(2);
+        let module_bytes = module_bytes(1);
+        let expected_module = preprocessor().preprocess(&module_bytes).unwrap();
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = cache.clone();
+                let module_bytes = module_bytes.clone();
+                thread::spawn(move || {
+                    let preprocessor = preprocessor();
+                    (0..10)
+                        .map(|_| {
+                            cache
+                                .get_or_preprocess(&module_bytes, &preprocessor)
+                                .expect("should preprocess")
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            for module in handle.join().expect("thread should not panic") {
+                assert_eq!(module, expected_module);
+            }
+        }
+        assert_eq!(cache.len(), 1);
+    }
+}
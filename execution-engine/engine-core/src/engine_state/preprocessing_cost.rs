@@ -0,0 +1,49 @@
+use engine_shared::{gas::Gas, motes::Motes};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::U512;
+
+/// The number of bytes every session module is charged for on top of its own length, covering
+/// the fixed overhead of deserializing and instrumenting a module.
+pub const PREPROCESSING_BASE_BYTES: u64 = 1_024;
+
+/// Returns the gas charged for preprocessing a session module `module_length` bytes long.
+pub fn preprocessing_cost(wasm_costs: &WasmCosts, module_length: usize) -> Gas {
+    let charged_bytes = U512::from(module_length) + U512::from(PREPROCESSING_BASE_BYTES);
+    Gas::new(charged_bytes * U512::from(wasm_costs.preprocess_per_byte))
+}
+
+/// Returns the least a deploy's payment must cover, in motes, for its session module of
+/// `module_length` bytes to be preprocessed at all.
+///
+/// This only depends on the length of the module, so can be checked before the module is parsed.
+/// It never exceeds what is actually charged for a session module of that length, since every
+/// session's cost includes its [`preprocessing_cost`].
+pub fn estimated_minimum_payment(
+    wasm_costs: &WasmCosts,
+    module_length: usize,
+    gas_price: u64,
+) -> Motes {
+    Motes::from_gas(preprocessing_cost(wasm_costs, module_length), gas_price)
+        .expect("motes overflow")
+}
+
+#[cfg(test)]
+mod tests {
+    use engine_shared::test_utils;
+
+    use super::*;
+
+    #[test]
+    fn should_charge_base_bytes_for_empty_module() {
+        let wasm_costs = WasmCosts {
+            preprocess_per_byte: 2,
+            ..test_utils::wasm_costs_mock()
+        };
+        let expected_cost = Gas::new(U512::from(2 * PREPROCESSING_BASE_BYTES));
+        assert_eq!(preprocessing_cost(&wasm_costs, 0), expected_cost);
+        assert_eq!(
+            preprocessing_cost(&test_utils::wasm_costs_free(), 100),
+            Gas::default()
+        );
+    }
+}
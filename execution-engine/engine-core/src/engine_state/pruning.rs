@@ -0,0 +1,97 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use engine_shared::{newtypes::CorrelationId, stored_value::StoredValue, transform::Transform};
+use engine_storage::global_state::StateReader;
+use types::Key;
+
+use super::execution_effect::ExecutionEffect;
+use crate::{execution::Error, runtime::extract_urefs};
+
+/// Returns the keys referenced by `transform`, i.e. the named keys and main purse of a written
+/// account, the named keys of a written contract, any `URef`s held in a written or appended
+/// `CLValue` and the keys given in an `AddKeys`.
+fn referenced_keys(transform: &Transform) -> Result<Vec<Key>, Error> {
+    let keys = match transform {
+        Transform::Write(StoredValue::Account(account)) => account
+            .named_keys()
+            .values()
+            .cloned()
+            .chain(Some(Key::URef(account.main_purse())))
+            .collect(),
+        Transform::Write(StoredValue::Contract(contract)) => {
+            contract.named_keys().values().cloned().collect()
+        }
+        Transform::Write(StoredValue::CLValue(cl_value)) => extract_urefs(cl_value)?
+            .into_iter()
+            .map(Key::URef)
+            .collect(),
+        Transform::AddKeys(keys) => keys.values().cloned().collect(),
+        Transform::ListAppend(items) => {
+            let mut keys = vec![];
+            for item in items {
+                keys.extend(extract_urefs(item)?.into_iter().map(Key::URef));
+            }
+            keys
+        }
+        _ => vec![],
+    };
+    Ok(keys)
+}
+
+/// Removes from `effect` the writes to `URef`s which were created during the execution but which
+/// can't be reached from any other key the execution wrote to.
+///
+/// A `URef` counts as created during the execution if `effect` writes to it and `pre_state`, the
+/// state the execution ran against, holds no value under it.  Every other transformed key is a
+/// root, and a created `URef` is kept if it's reachable from a root via named keys, a main purse,
+/// `URef`s held in `CLValue`s or keys added by `AddKeys`.
+///
+/// If the `URef`s held in any written value can't be determined, `effect` is left unchanged.
+pub fn prune_unreachable_urefs<R>(
+    correlation_id: CorrelationId,
+    pre_state: &R,
+    effect: &mut ExecutionEffect,
+) -> Result<(), R::Error>
+where
+    R: StateReader<Key, StoredValue>,
+{
+    let mut created = BTreeSet::new();
+    for (key, transform) in effect.transforms.iter() {
+        if let (Key::URef(_), Transform::Write(_)) = (key, transform) {
+            if pre_state.read(correlation_id, key)?.is_none() {
+                created.insert(*key);
+            }
+        }
+    }
+    if created.is_empty() {
+        return Ok(());
+    }
+
+    let mut reachable = BTreeSet::new();
+    let mut queue: VecDeque<&Transform> = effect
+        .transforms
+        .iter()
+        .filter(|(key, _)| !created.contains(*key))
+        .map(|(_, transform)| transform)
+        .collect();
+    while let Some(transform) = queue.pop_front() {
+        let keys = match referenced_keys(transform) {
+            Ok(keys) => keys,
+            Err(_) => return Ok(()),
+        };
+        for key in keys {
+            let key = key.normalize();
+            if created.contains(&key) && reachable.insert(key) {
+                if let Some(transform) = effect.transforms.get(&key) {
+                    queue.push_back(transform);
+                }
+            }
+        }
+    }
+
+    for key in created.difference(&reachable) {
+        effect.transforms.remove(key);
+        effect.ops.remove(key);
+    }
+    Ok(())
+}
@@ -0,0 +1,329 @@
+use std::{convert::TryFrom, fmt};
+
+use failure::Fail;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::account::PublicKey;
+
+const ED25519: &str = "ed25519";
+const SECP256K1: &str = "secp256k1";
+
+const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
+const ED25519_SIGNATURE_LENGTH: usize = 64;
+const SECP256K1_COMPRESSED_PUBLIC_KEY_LENGTH: usize = 33;
+const SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH: usize = 65;
+const SECP256K1_SIGNATURE_LENGTH: usize = 64;
+
+/// The algorithm a deploy approval was signed with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    Ed25519,
+    Secp256k1,
+}
+
+impl fmt::Display for SignatureAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureAlgorithm::Ed25519 => write!(f, "{}", ED25519),
+            SignatureAlgorithm::Secp256k1 => write!(f, "{}", SECP256K1),
+        }
+    }
+}
+
+impl TryFrom<&str> for SignatureAlgorithm {
+    type Error = SignatureError;
+
+    fn try_from(tag: &str) -> Result<Self, Self::Error> {
+        match tag {
+            ED25519 => Ok(SignatureAlgorithm::Ed25519),
+            SECP256K1 => Ok(SignatureAlgorithm::Secp256k1),
+            _ => Err(SignatureError::UnknownAlgorithm(tag.to_string())),
+        }
+    }
+}
+
+#[derive(Fail, Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    #[fail(display = "Unknown signature algorithm: {}", _0)]
+    UnknownAlgorithm(String),
+    #[fail(
+        display = "Invalid {} public key length: expected {}, actual {}",
+        algorithm, expected, actual
+    )]
+    InvalidPublicKeyLength {
+        algorithm: SignatureAlgorithm,
+        expected: usize,
+        actual: usize,
+    },
+    #[fail(
+        display = "Invalid {} signature length: expected {}, actual {}",
+        algorithm, expected, actual
+    )]
+    InvalidSignatureLength {
+        algorithm: SignatureAlgorithm,
+        expected: usize,
+        actual: usize,
+    },
+    #[fail(display = "Invalid {} public key: not a point on the curve", _0)]
+    InvalidPublicKey(SignatureAlgorithm),
+    #[fail(display = "Invalid {} signature: malformed encoding", _0)]
+    InvalidSignature(SignatureAlgorithm),
+    #[fail(display = "{} signature verification failed", _0)]
+    VerificationFailed(SignatureAlgorithm),
+    #[fail(
+        display = "Approval by {} which is not an authorization key of the deploy",
+        _0
+    )]
+    UnauthorizedSigner(PublicKey),
+    #[fail(display = "Missing approval by authorization key {}", _0)]
+    MissingApproval(PublicKey),
+}
+
+/// Verifies `signature` over `payload` against `public_key`.
+///
+/// Ed25519 signatures are verified over `payload` itself.  Secp256k1 signatures are ECDSA
+/// signatures in 64-byte compact form over the BLAKE2b-256 hash of `payload`, and `public_key` may
+/// be in either compressed or uncompressed SEC1 form.
+pub fn verify(
+    algorithm: SignatureAlgorithm,
+    public_key: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<(), SignatureError> {
+    match algorithm {
+        SignatureAlgorithm::Ed25519 => verify_ed25519(public_key, payload, signature),
+        SignatureAlgorithm::Secp256k1 => verify_secp256k1(public_key, payload, signature),
+    }
+}
+
+/// Returns the account key which an approval by `public_key` authorizes a deploy as: the key itself
+/// for Ed25519, and the BLAKE2b-256 hash of its compressed form for Secp256k1, whose public keys
+/// are longer than an account key.
+pub fn authorization_key(
+    algorithm: SignatureAlgorithm,
+    public_key: &[u8],
+) -> Result<PublicKey, SignatureError> {
+    match algorithm {
+        SignatureAlgorithm::Ed25519 => PublicKey::ed25519_try_from(public_key).map_err(|_| {
+            SignatureError::InvalidPublicKeyLength {
+                algorithm,
+                expected: ED25519_PUBLIC_KEY_LENGTH,
+                actual: public_key.len(),
+            }
+        }),
+        SignatureAlgorithm::Secp256k1 => {
+            let public_key = secp256k1::PublicKey::from_slice(public_key)
+                .map_err(|_| SignatureError::InvalidPublicKey(algorithm))?;
+            let hash = Blake2bHash::new(&public_key.serialize());
+            Ok(PublicKey::ed25519_from(hash.value()))
+        }
+    }
+}
+
+fn check_signature_length(
+    algorithm: SignatureAlgorithm,
+    signature: &[u8],
+    expected: usize,
+) -> Result<(), SignatureError> {
+    if signature.len() != expected {
+        return Err(SignatureError::InvalidSignatureLength {
+            algorithm,
+            expected,
+            actual: signature.len(),
+        });
+    }
+    Ok(())
+}
+
+fn verify_ed25519(
+    public_key: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<(), SignatureError> {
+    let algorithm = SignatureAlgorithm::Ed25519;
+    if public_key.len() != ED25519_PUBLIC_KEY_LENGTH {
+        return Err(SignatureError::InvalidPublicKeyLength {
+            algorithm,
+            expected: ED25519_PUBLIC_KEY_LENGTH,
+            actual: public_key.len(),
+        });
+    }
+    check_signature_length(algorithm, signature, ED25519_SIGNATURE_LENGTH)?;
+
+    let public_key = ed25519_dalek::PublicKey::from_bytes(public_key)
+        .map_err(|_| SignatureError::InvalidPublicKey(algorithm))?;
+    let signature = ed25519_dalek::Signature::from_bytes(signature)
+        .map_err(|_| SignatureError::InvalidSignature(algorithm))?;
+    public_key
+        .verify(payload, &signature)
+        .map_err(|_| SignatureError::VerificationFailed(algorithm))
+}
+
+fn verify_secp256k1(
+    public_key: &[u8],
+    payload: &[u8],
+    signature: &[u8],
+) -> Result<(), SignatureError> {
+    let algorithm = SignatureAlgorithm::Secp256k1;
+    if public_key.len() != SECP256K1_COMPRESSED_PUBLIC_KEY_LENGTH
+        && public_key.len() != SECP256K1_UNCOMPRESSED_PUBLIC_KEY_LENGTH
+    {
+        return Err(SignatureError::InvalidPublicKeyLength {
+            algorithm,
+            expected: SECP256K1_COMPRESSED_PUBLIC_KEY_LENGTH,
+            actual: public_key.len(),
+        });
+    }
+    check_signature_length(algorithm, signature, SECP256K1_SIGNATURE_LENGTH)?;
+
+    let public_key = secp256k1::PublicKey::from_slice(public_key)
+        .map_err(|_| SignatureError::InvalidPublicKey(algorithm))?;
+    let signature = secp256k1::Signature::from_compact(signature)
+        .map_err(|_| SignatureError::InvalidSignature(algorithm))?;
+    // Safe to unwrap here because the digest length is constant and valid
+    let message = secp256k1::Message::from_slice(&Blake2bHash::new(payload).value()).unwrap();
+    secp256k1::Secp256k1::verification_only()
+        .verify(&message, &signature, &public_key)
+        .map_err(|_| SignatureError::VerificationFailed(algorithm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &[u8] = b"casperlabs deploy header";
+
+    const ED25519_PUBLIC_KEY: &str =
+        "8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c";
+    const ED25519_SIGNATURE: &str =
+        "2e0338ab9727be5af0f29f84ca673d23e38d59d33324235ae5221219389a6329\
+         8f46fd33f96c04782a3db6df0ec8f2184aecd8978386ece6a46b7c475bc14702";
+
+    const SECP256K1_PUBLIC_KEY: &str =
+        "031b84c5567b126440995d3ed5aaba0565d71e1834604819ff9c17f5e9d5dd078f";
+    const SECP256K1_SIGNATURE: &str =
+        "33d0737a9da93640aebebcdbfe2b3c479d524e7f50bf84894dcb7cc9e190ecfa\
+         4d92f214b0af931653595f837d9a33ffd092723d3d8798d9eeef6ff69a377cb6";
+
+    fn decode(hex: &str) -> Vec<u8> {
+        base16::decode(hex).expect("should decode hex")
+    }
+
+    #[test]
+    fn should_parse_algorithm_tags() {
+        assert_eq!(
+            SignatureAlgorithm::try_from("ed25519"),
+            Ok(SignatureAlgorithm::Ed25519)
+        );
+        assert_eq!(
+            SignatureAlgorithm::try_from("secp256k1"),
+            Ok(SignatureAlgorithm::Secp256k1)
+        );
+        assert_eq!(
+            SignatureAlgorithm::try_from("rsa"),
+            Err(SignatureError::UnknownAlgorithm("rsa".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_verify_ed25519_signature() {
+        let algorithm = SignatureAlgorithm::Ed25519;
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        let signature = decode(ED25519_SIGNATURE);
+        assert_eq!(verify(algorithm, &public_key, PAYLOAD, &signature), Ok(()));
+    }
+
+    #[test]
+    fn should_verify_secp256k1_signature() {
+        let algorithm = SignatureAlgorithm::Secp256k1;
+        let public_key = decode(SECP256K1_PUBLIC_KEY);
+        let signature = decode(SECP256K1_SIGNATURE);
+        assert_eq!(verify(algorithm, &public_key, PAYLOAD, &signature), Ok(()));
+    }
+
+    #[test]
+    fn should_fail_to_verify_tampered_payload() {
+        let mut tampered_payload = PAYLOAD.to_vec();
+        tampered_payload[0] ^= 1;
+
+        let vectors = [
+            (
+                SignatureAlgorithm::Ed25519,
+                ED25519_PUBLIC_KEY,
+                ED25519_SIGNATURE,
+            ),
+            (
+                SignatureAlgorithm::Secp256k1,
+                SECP256K1_PUBLIC_KEY,
+                SECP256K1_SIGNATURE,
+            ),
+        ];
+        for (algorithm, public_key, signature) in vectors.iter() {
+            let public_key = decode(public_key);
+            let signature = decode(signature);
+            assert_eq!(
+                verify(*algorithm, &public_key, &tampered_payload, &signature),
+                Err(SignatureError::VerificationFailed(*algorithm))
+            );
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_with_wrong_lengths() {
+        let algorithm = SignatureAlgorithm::Ed25519;
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        let signature = decode(ED25519_SIGNATURE);
+        assert_eq!(
+            verify(algorithm, &public_key[1..], PAYLOAD, &signature),
+            Err(SignatureError::InvalidPublicKeyLength {
+                algorithm,
+                expected: ED25519_PUBLIC_KEY_LENGTH,
+                actual: ED25519_PUBLIC_KEY_LENGTH - 1,
+            })
+        );
+
+        let algorithm = SignatureAlgorithm::Secp256k1;
+        let public_key = decode(SECP256K1_PUBLIC_KEY);
+        let signature = decode(SECP256K1_SIGNATURE);
+        assert_eq!(
+            verify(algorithm, &public_key, PAYLOAD, &signature[1..]),
+            Err(SignatureError::InvalidSignatureLength {
+                algorithm,
+                expected: SECP256K1_SIGNATURE_LENGTH,
+                actual: SECP256K1_SIGNATURE_LENGTH - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn should_derive_authorization_keys() {
+        let public_key = decode(ED25519_PUBLIC_KEY);
+        assert_eq!(
+            authorization_key(SignatureAlgorithm::Ed25519, &public_key)
+                .expect("should derive key")
+                .as_bytes(),
+            public_key.as_slice()
+        );
+
+        let public_key = decode(SECP256K1_PUBLIC_KEY);
+        assert_eq!(
+            authorization_key(SignatureAlgorithm::Secp256k1, &public_key),
+            Ok(PublicKey::ed25519_from(
+                Blake2bHash::new(&public_key).value()
+            ))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_with_invalid_point() {
+        let algorithm = SignatureAlgorithm::Secp256k1;
+        // The compressed form's prefix byte must be 0x02 or 0x03.
+        let mut public_key = decode(SECP256K1_PUBLIC_KEY);
+        public_key[0] = 0x05;
+        let signature = decode(SECP256K1_SIGNATURE);
+        assert_eq!(
+            verify(algorithm, &public_key, PAYLOAD, &signature),
+            Err(SignatureError::InvalidPublicKey(algorithm))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use engine_shared::{additive_map::AdditiveMap, gas::Gas, transform::Transform};
+use types::Key;
+
+use super::{error, execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op};
+
+/// The effect of a simulated deploy.
+///
+/// Unlike [`ExecutionEffect`], the transforms can't be moved out of a `SimulatedEffect`, so it
+/// can't be passed on to [`EngineState::apply_effect`](super::EngineState::apply_effect).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulatedEffect(ExecutionEffect);
+
+impl SimulatedEffect {
+    pub fn ops(&self) -> &AdditiveMap<Key, Op> {
+        &self.0.ops
+    }
+
+    pub fn transforms(&self) -> &AdditiveMap<Key, Transform> {
+        &self.0.transforms
+    }
+}
+
+/// The outcome of [`EngineState::simulate_deploy`](super::EngineState::simulate_deploy).
+#[derive(Debug)]
+pub struct SimulationResult {
+    error: Option<error::Error>,
+    effect: SimulatedEffect,
+    cost: Gas,
+}
+
+impl SimulationResult {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns the error the deploy would have failed with if it had been executed.
+    pub fn error(&self) -> Option<&error::Error> {
+        self.error.as_ref()
+    }
+
+    pub fn effect(&self) -> &SimulatedEffect {
+        &self.effect
+    }
+
+    /// Returns the gas the deploy would have been charged if it had been executed.
+    pub fn cost(&self) -> Gas {
+        self.cost
+    }
+}
+
+impl From<ExecutionResult> for SimulationResult {
+    fn from(execution_result: ExecutionResult) -> Self {
+        match execution_result {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                cost,
+                ..
+            } => SimulationResult {
+                error: Some(error),
+                effect: SimulatedEffect(effect),
+                cost,
+            },
+            ExecutionResult::Success { effect, cost, .. } => SimulationResult {
+                error: None,
+                effect: SimulatedEffect(effect),
+                cost,
+            },
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::BTreeSet;
+
+use engine_shared::newtypes::Blake2bHash;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+use crate::DeployHash;
+
+/// A request to transfer `amount` motes from the main purse of the `source` account to the main
+/// purse of the `target` account, creating the target account if it doesn't exist.
+///
+/// Unlike a deploy, a transfer is executed natively by `EngineState::transfer` without any payment
+/// or session Wasm.
+pub struct TransferRequest {
+    pub parent_state_hash: Blake2bHash,
+    pub block_time: u64,
+    pub block_height: u64,
+    pub protocol_version: ProtocolVersion,
+    pub source: PublicKey,
+    pub target: PublicKey,
+    pub amount: U512,
+    pub authorization_keys: BTreeSet<PublicKey>,
+    pub deploy_hash: DeployHash,
+}
@@ -14,6 +14,7 @@ pub enum UpgradeResult {
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
     Serialization(bytesrepr::Error),
+    Overflow,
     Success {
         post_state_hash: Blake2bHash,
         effect: ExecutionEffect,
@@ -29,6 +30,7 @@ impl fmt::Display for UpgradeResult {
                 write!(f, "Type mismatch: {:?}", type_mismatch)
             }
             UpgradeResult::Serialization(error) => write!(f, "Serialization error: {:?}", error),
+            UpgradeResult::Overflow => write!(f, "Overflow"),
             UpgradeResult::Success {
                 post_state_hash,
                 effect,
@@ -42,8 +44,11 @@ impl UpgradeResult {
         match commit_result {
             CommitResult::RootNotFound => UpgradeResult::RootNotFound,
             CommitResult::KeyNotFound(key) => UpgradeResult::KeyNotFound(key),
-            CommitResult::TypeMismatch(type_mismatch) => UpgradeResult::TypeMismatch(type_mismatch),
+            CommitResult::TypeMismatch { type_mismatch, .. } => {
+                UpgradeResult::TypeMismatch(type_mismatch)
+            }
             CommitResult::Serialization(error) => UpgradeResult::Serialization(error),
+            CommitResult::Overflow => UpgradeResult::Overflow,
             CommitResult::Success { state_root, .. } => UpgradeResult::Success {
                 post_state_hash: state_root,
                 effect,
@@ -11,8 +11,20 @@ use crate::{Address, ADDRESS_LENGTH};
 
 const SEED_LENGTH: usize = 32;
 
-/// An [`AddressGenerator`] generates [`URef`](types::URef) addresses.
-pub struct AddressGenerator(ChaChaRng);
+/// The length of the random bytes returned by [`AddressGenerator::random_bytes`].
+pub const RANDOM_BYTES_LENGTH: usize = 32;
+
+/// The number of bytes hashed to produce each set of random bytes: the seed, the call depth and
+/// the number of previous calls, the latter two as `u64`s.
+pub const RANDOM_BYTES_HASHED_LENGTH: usize = SEED_LENGTH + 2 * 8;
+
+/// An [`AddressGenerator`] generates [`URef`](types::URef) addresses, and the pseudo-random bytes
+/// returned to contracts.
+pub struct AddressGenerator {
+    rng: ChaChaRng,
+    seed: [u8; SEED_LENGTH],
+    random_bytes_count: u64,
+}
 
 impl AddressGenerator {
     /// Creates an [`AddressGenerator`] from a 32-byte hash digest and [`Phase`].
@@ -25,9 +37,27 @@ impl AddressGenerator {
 
     pub fn create_address(&mut self) -> Address {
         let mut buff = [0u8; ADDRESS_LENGTH];
-        self.0.fill_bytes(&mut buff);
+        self.rng.fill_bytes(&mut buff);
         buff
     }
+
+    /// Returns the hash of the generator's seed, `call_depth` and the number of previous calls.
+    ///
+    /// This doesn't advance the stream of addresses.  The bytes are reproducible by anyone who
+    /// knows the seed, so they aren't suitable where unpredictability matters.
+    pub fn random_bytes(&mut self, call_depth: usize) -> [u8; RANDOM_BYTES_LENGTH] {
+        let mut data = Vec::with_capacity(RANDOM_BYTES_HASHED_LENGTH);
+        data.extend_from_slice(&self.seed);
+        data.extend_from_slice(&(call_depth as u64).to_le_bytes());
+        data.extend_from_slice(&self.random_bytes_count.to_le_bytes());
+        self.random_bytes_count += 1;
+
+        let mut random_bytes = [0u8; RANDOM_BYTES_LENGTH];
+        let mut hasher = VarBlake2b::new(RANDOM_BYTES_LENGTH).unwrap();
+        hasher.input(data);
+        hasher.variable_result(|hash| random_bytes.clone_from_slice(hash));
+        random_bytes
+    }
 }
 
 /// A builder for [`AddressGenerator`].
@@ -51,7 +81,11 @@ impl AddressGeneratorBuilder {
         let mut hasher = VarBlake2b::new(SEED_LENGTH).unwrap();
         hasher.input(self.data);
         hasher.variable_result(|hash| seed.clone_from_slice(hash));
-        AddressGenerator(ChaChaRng::from_seed(seed))
+        AddressGenerator {
+            rng: ChaChaRng::from_seed(seed),
+            seed,
+            random_bytes_count: 0,
+        }
     }
 }
 
@@ -103,4 +137,27 @@ mod tests {
             "different phase should have different output"
         );
     }
+
+    #[test]
+    fn should_generate_different_random_bytes_on_each_call_and_depth() {
+        let mut ag = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let first = ag.random_bytes(1);
+        let second = ag.random_bytes(1);
+        let nested = ag.random_bytes(2);
+        assert_ne!(first, second);
+        assert_ne!(first, nested);
+        assert_ne!(second, nested);
+
+        let mut ag_same_seed = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        assert_eq!(ag_same_seed.random_bytes(1), first);
+    }
+
+    #[test]
+    fn should_not_advance_addresses_when_generating_random_bytes() {
+        let mut ag_a = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let mut ag_b = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        ag_a.random_bytes(1);
+
+        assert_eq!(ag_a.create_address(), ag_b.create_address());
+    }
 }
@@ -10,9 +10,80 @@ use types::{
 
 use crate::resolvers::error::ResolverError;
 
+/// The kind of a trap raised by executing the Wasm code of a contract, as opposed to one raised by
+/// a host function the contract called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrapKind {
+    /// An `unreachable` instruction was executed, e.g. by a failed assertion or other panic.
+    Unreachable,
+    /// A load or store accessed linear memory out of bounds.
+    MemoryOutOfBounds,
+    /// An indirect call accessed the table out of bounds.
+    TableOutOfBounds,
+    /// An indirect call went through an uninitialized table element.
+    ElemUninitialized,
+    /// An integer division or remainder by zero.
+    DivisionByZero,
+    /// A float couldn't be converted to an integer, as it was NaN or out of range.
+    InvalidConversionToInt,
+    /// The call stack or value stack of the interpreter was exhausted, or the stack height limit
+    /// injected during preprocessing was exceeded.
+    StackOverflow,
+    /// An indirect call's signature didn't match that of the called function.
+    UnexpectedSignature,
+}
+
+/// The classification of a trap raised while executing a contract.
+enum TrapClass {
+    /// A trap of the contract's Wasm code, or of a contract called from it.
+    Trap(TrapKind),
+    /// An error raised by a host function, which is passed through as is.
+    HostError,
+}
+
+impl TrapClass {
+    /// Classifies `trap_kind`, looking inside a host error for a trap raised by a contract called
+    /// from the trapping one or by the stack height limiter.
+    fn of(trap_kind: &wasmi::TrapKind) -> Self {
+        let trap_kind = match trap_kind {
+            wasmi::TrapKind::Unreachable => TrapKind::Unreachable,
+            wasmi::TrapKind::MemoryAccessOutOfBounds => TrapKind::MemoryOutOfBounds,
+            wasmi::TrapKind::TableAccessOutOfBounds => TrapKind::TableOutOfBounds,
+            wasmi::TrapKind::ElemUninitialized => TrapKind::ElemUninitialized,
+            wasmi::TrapKind::DivisionByZero => TrapKind::DivisionByZero,
+            wasmi::TrapKind::InvalidConversionToInt => TrapKind::InvalidConversionToInt,
+            wasmi::TrapKind::StackOverflow => TrapKind::StackOverflow,
+            wasmi::TrapKind::UnexpectedSignature => TrapKind::UnexpectedSignature,
+            wasmi::TrapKind::Host(host_error) => match host_error.downcast_ref::<Error>() {
+                Some(Error::Trap(trap_kind)) => *trap_kind,
+                _ => return TrapClass::HostError,
+            },
+        };
+        TrapClass::Trap(trap_kind)
+    }
+}
+
+impl fmt::Display for TrapKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            TrapKind::Unreachable => "unreachable instruction executed",
+            TrapKind::MemoryOutOfBounds => "out of bounds memory access",
+            TrapKind::TableOutOfBounds => "out of bounds table access",
+            TrapKind::ElemUninitialized => "uninitialized table element",
+            TrapKind::DivisionByZero => "integer division by zero",
+            TrapKind::InvalidConversionToInt => "invalid conversion to integer",
+            TrapKind::StackOverflow => "stack overflow",
+            TrapKind::UnexpectedSignature => "indirect call signature mismatch",
+        };
+        write!(f, "{}", description)
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     Interpreter(wasmi::Error),
+    /// The Wasm code of a contract trapped.
+    Trap(TrapKind),
     Storage(engine_storage::error::Error),
     BytesRepr(bytesrepr::Error),
     KeyNotFound(Key),
@@ -31,11 +102,20 @@ pub enum Error {
     Resolver(ResolverError),
     /// Reverts execution with a provided status
     Revert(u32),
+    /// Reverts execution with a provided status and a payload of at most
+    /// `MAX_REVERT_PAYLOAD_LENGTH` bytes.
+    RevertWithPayload {
+        status: u32,
+        payload: Vec<u8>,
+    },
     AddKeyFailure(AddKeyFailure),
     RemoveKeyFailure(RemoveKeyFailure),
     UpdateKeyFailure(UpdateKeyFailure),
     SetThresholdFailure(SetThresholdFailure),
     SystemContract(system_contract_errors::Error),
+    /// No longer raised: deploys failing authorization are rejected with
+    /// `engine_state::Error::InvalidAuthorization` instead.
+    #[deprecated(note = "deploys failing authorization fail with `InvalidAuthorization`")]
     DeploymentAuthorizationFailure,
     ExpectedReturnValue,
     UnexpectedReturnValue,
@@ -47,11 +127,20 @@ pub enum Error {
     CLValue(CLValueError),
     HostBufferEmpty,
     UnsupportedWasmStart,
+    /// A `call_contract` would have exceeded `EngineConfig::max_call_depth`.
+    CallDepthExceeded,
+    /// An unsigned addition to a `U512` would have overflowed.
+    Overflow,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Error::BytesRepr(error) => write!(f, "{}", error),
+            Error::CLValue(error) => write!(f, "{}", error),
+            Error::Trap(trap_kind) => write!(f, "Trap: {}", trap_kind),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
@@ -65,7 +154,13 @@ impl From<!> for Error {
 
 impl From<wasmi::Error> for Error {
     fn from(e: wasmi::Error) -> Self {
-        Error::Interpreter(e)
+        match &e {
+            wasmi::Error::Trap(trap) => match TrapClass::of(trap.kind()) {
+                TrapClass::Trap(trap_kind) => Error::Trap(trap_kind),
+                TrapClass::HostError => Error::Interpreter(e),
+            },
+            _ => Error::Interpreter(e),
+        }
     }
 }
 
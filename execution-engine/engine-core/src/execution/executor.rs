@@ -14,12 +14,13 @@ use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
     account::PublicKey,
     bytesrepr::{self, FromBytes},
-    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion,
+    BlockTime, CLTyped, CLValue, Key, Phase, ProtocolVersion, RuntimeArgs,
 };
 
 use crate::{
     engine_state::{
-        execution_result::ExecutionResult, system_contract_cache::SystemContractCache, EngineConfig,
+        execution_effect::ExecutionEffect, execution_result::ExecutionResult,
+        system_contract_cache::SystemContractCache, EngineConfig,
     },
     execution::{address_generator::AddressGenerator, Error, FN_STORE_ID_INITIAL},
     runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
@@ -48,6 +49,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: Default::default(),
                     cost: $cost,
+                    phases: None,
                 };
             }
         }
@@ -62,6 +64,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: $effect,
                     cost: $cost,
+                    phases: None,
                 };
             }
         }
@@ -90,6 +93,7 @@ impl Executor {
         account: &Account,
         authorized_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -123,9 +127,10 @@ impl Executor {
         // Snapshot of effects before execution, so in case of error
         // only nonce update can be returned.
         let effects_snapshot = tc.borrow().effect();
+        let initial_cache_stats = tc.borrow().cache_stats();
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
+        let args: RuntimeArgs = if args.is_empty() {
+            RuntimeArgs::new()
         } else {
             // TODO: figure out how this works with the cost model
             // https://casperlabs.atlassian.net/browse/EE-239
@@ -134,14 +139,16 @@ impl Executor {
         };
 
         let context = RuntimeContext::new(
-            tc,
+            Rc::clone(&tc),
             &mut named_keys,
             access_rights,
             args.clone(),
             authorized_keys,
             &account,
             base_key,
+            runtime_context::new_call_stack(account, base_key),
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -161,18 +168,61 @@ impl Executor {
             context,
         );
 
+        let result = self.run(
+            &mut runtime,
+            &instance,
+            base_key,
+            &args,
+            protocol_version,
+            effects_snapshot,
+        );
+        let cache_stats = if self.config.record_cache_stats() {
+            let (hits, misses) = tc.borrow().cache_stats();
+            Some((hits - initial_cache_stats.0, misses - initial_cache_stats.1))
+        } else {
+            None
+        };
+        // Events are only reported by successful executions
+        let events = if result.is_success() {
+            runtime.take_events()
+        } else {
+            Vec::new()
+        };
+        result
+            .with_host_trace(runtime.take_host_function_trace())
+            .with_cache_stats(cache_stats)
+            .with_events(events)
+    }
+
+    /// Runs the code `runtime` was created for, either by calling the host-side mint or proof of
+    /// stake for `base_key` or by invoking the `call` export of `instance`.
+    fn run<R>(
+        &self,
+        runtime: &mut Runtime<R>,
+        instance: &ModuleRef,
+        base_key: Key,
+        args: &RuntimeArgs,
+        protocol_version: ProtocolVersion,
+        effects_snapshot: ExecutionEffect,
+    ) -> ExecutionResult
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
         if !self.config.use_system_contracts() {
             if runtime.is_mint(base_key) {
                 match runtime.call_host_mint(
                     protocol_version,
                     runtime.context().named_keys().to_owned(),
-                    &args,
+                    args.values(),
                     Default::default(),
                 ) {
                     Ok(_value) => {
                         return ExecutionResult::Success {
                             effect: runtime.context().effect(),
                             cost: runtime.context().gas_counter(),
+                            ret: None,
+                            phases: None,
                         }
                     }
                     Err(error) => {
@@ -180,6 +230,7 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            phases: None,
                         }
                     }
                 }
@@ -187,13 +238,15 @@ impl Executor {
                 match runtime.call_host_proof_of_stake(
                     protocol_version,
                     runtime.context().named_keys().to_owned(),
-                    &args,
+                    args.values(),
                     Default::default(),
                 ) {
                     Ok(_value) => {
                         return ExecutionResult::Success {
                             effect: runtime.context().effect(),
                             cost: runtime.context().gas_counter(),
+                            ret: None,
+                            phases: None,
                         }
                     }
                     Err(error) => {
@@ -201,21 +254,50 @@ impl Executor {
                             error: error.into(),
                             effect: effects_snapshot,
                             cost: runtime.context().gas_counter(),
+                            phases: None,
                         }
                     }
                 }
             }
         }
 
-        on_fail_charge!(
-            instance.invoke_export("call", &[], &mut runtime),
-            runtime.context().gas_counter(),
-            effects_snapshot
-        );
+        let error = match instance.invoke_export("call", &[], &mut *runtime) {
+            Err(error) => error,
+            Ok(_) => {
+                return ExecutionResult::Success {
+                    effect: runtime.context().effect(),
+                    cost: runtime.context().gas_counter(),
+                    ret: None,
+                    phases: None,
+                }
+            }
+        };
 
-        ExecutionResult::Success {
-            effect: runtime.context().effect(),
+        // A call to `ret` ends the execution successfully, with the returned value attached to
+        // the result rather than handed to a caller.
+        let exec_err = match error
+            .as_host_error()
+            .and_then(|host_error| host_error.downcast_ref::<Error>())
+        {
+            Some(Error::Ret(_)) => match runtime.take_host_buffer() {
+                Some(ret) => {
+                    return ExecutionResult::Success {
+                        effect: runtime.context().effect(),
+                        cost: runtime.context().gas_counter(),
+                        ret: Some(ret),
+                        phases: None,
+                    }
+                }
+                None => Error::ExpectedReturnValue,
+            },
+            _ => error.into(),
+        };
+        log::warn!("Execution failed: {:?}", exec_err);
+        ExecutionResult::Failure {
+            error: exec_err.into(),
+            effect: effects_snapshot,
             cost: runtime.context().gas_counter(),
+            phases: None,
         }
     }
 
@@ -228,6 +310,7 @@ impl Executor {
         account: &Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -265,8 +348,8 @@ impl Executor {
         // can be returned.
         let effects_snapshot = state.borrow().effect();
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
+        let args: RuntimeArgs = if args.is_empty() {
+            RuntimeArgs::new()
         } else {
             let gas = Gas::new(args.len().into());
             on_fail_charge!(bytesrepr::deserialize(args), gas, effects_snapshot)
@@ -280,7 +363,9 @@ impl Executor {
             authorization_keys,
             &account,
             base_key,
+            runtime_context::new_call_stack(account, base_key),
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -307,13 +392,15 @@ impl Executor {
             match runtime.call_host_proof_of_stake(
                 protocol_version,
                 runtime.context().named_keys().to_owned(),
-                &args,
+                args.values(),
                 Default::default(),
             ) {
                 Ok(_value) => {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         cost: runtime.context().gas_counter(),
+                        ret: None,
+                        phases: None,
                     }
                 }
                 Err(error) => {
@@ -321,6 +408,7 @@ impl Executor {
                         error: error.into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        phases: None,
                     }
                 }
             }
@@ -332,6 +420,8 @@ impl Executor {
                 return ExecutionResult::Success {
                     effect: runtime.context().effect(),
                     cost: runtime.context().gas_counter(),
+                    ret: None,
+                    phases: None,
                 }
             }
         };
@@ -343,6 +433,8 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         cost: runtime.context().gas_counter(),
+                        ret: None,
+                        phases: None,
                     };
                 }
                 Error::Revert(status) => {
@@ -350,6 +442,19 @@ impl Executor {
                         error: Error::Revert(*status).into(),
                         effect: effects_snapshot,
                         cost: runtime.context().gas_counter(),
+                        phases: None,
+                    };
+                }
+                Error::RevertWithPayload { status, payload } => {
+                    let error = Error::RevertWithPayload {
+                        status: *status,
+                        payload: payload.clone(),
+                    };
+                    return ExecutionResult::Failure {
+                        error: error.into(),
+                        effect: effects_snapshot,
+                        cost: runtime.context().gas_counter(),
+                        phases: None,
                     };
                 }
                 _ => {}
@@ -357,9 +462,10 @@ impl Executor {
         }
 
         ExecutionResult::Failure {
-            error: Error::Interpreter(error).into(),
+            error: Error::from(error).into(),
             effect: effects_snapshot,
             cost: runtime.context().gas_counter(),
+            phases: None,
         }
     }
 
@@ -372,6 +478,7 @@ impl Executor {
         account: &'a Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         address_generator: Rc<RefCell<AddressGenerator>>,
@@ -395,8 +502,8 @@ impl Executor {
                 extract_access_rights_from_keys(keys)
             };
 
-        let args: Vec<CLValue> = if args.is_empty() {
-            Vec::new()
+        let args: RuntimeArgs = if args.is_empty() {
+            RuntimeArgs::new()
         } else {
             bytesrepr::deserialize(args)?
         };
@@ -411,7 +518,9 @@ impl Executor {
             authorization_keys,
             account,
             base_key,
+            runtime_context::new_call_stack(account, base_key),
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -445,6 +554,7 @@ impl Executor {
         account: &Account,
         authorization_keys: BTreeSet<PublicKey>,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         address_generator: Rc<RefCell<AddressGenerator>>,
@@ -468,6 +578,7 @@ impl Executor {
             account,
             authorization_keys,
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             address_generator,
@@ -502,7 +613,13 @@ impl Executor {
                 .take_host_buffer()
                 .ok_or(Error::ExpectedReturnValue)?,
             Some(Error::Revert(code)) => return Err(Error::Revert(*code)),
-            _ => return Err(Error::Interpreter(error)),
+            Some(Error::RevertWithPayload { status, payload }) => {
+                return Err(Error::RevertWithPayload {
+                    status: *status,
+                    payload: payload.clone(),
+                })
+            }
+            _ => return Err(error.into()),
         };
 
         let ret = return_value.into_t()?;
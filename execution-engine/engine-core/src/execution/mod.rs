@@ -6,8 +6,10 @@ mod executor;
 mod tests;
 
 pub use self::{
-    address_generator::{AddressGenerator, AddressGeneratorBuilder},
-    error::Error,
+    address_generator::{
+        AddressGenerator, AddressGeneratorBuilder, RANDOM_BYTES_HASHED_LENGTH, RANDOM_BYTES_LENGTH,
+    },
+    error::{Error, TrapKind},
     executor::Executor,
 };
 
@@ -1,7 +1,7 @@
 use engine_shared::{gas::Gas, transform::Transform};
 use types::{Key, U512};
 
-use super::Error;
+use super::{Error, TrapKind};
 use crate::engine_state::{
     execution_effect::ExecutionEffect, execution_result::ExecutionResult, op::Op,
 };
@@ -15,6 +15,8 @@ fn on_fail_charge_test_helper<T>(
     ExecutionResult::Success {
         effect: Default::default(),
         cost: success_cost,
+        ret: None,
+        phases: None,
     }
 }
 
@@ -55,6 +57,8 @@ fn on_fail_charge_with_action() {
         ExecutionResult::Success {
             effect: Default::default(),
             cost: Gas::default(),
+            ret: None,
+            phases: None,
         }
     };
     match f() {
@@ -67,3 +71,36 @@ fn on_fail_charge_with_action() {
         }
     }
 }
+
+fn host_trap(error: Error) -> wasmi::Error {
+    wasmi::Error::Trap(wasmi::Trap::new(wasmi::TrapKind::Host(Box::new(error))))
+}
+
+#[test]
+fn should_classify_traps() {
+    let trap = wasmi::Error::Trap(wasmi::Trap::new(wasmi::TrapKind::DivisionByZero));
+    match Error::from(trap) {
+        Error::Trap(TrapKind::DivisionByZero) => {}
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    // A trap raised by a called contract is classified the same as one raised by the caller
+    match Error::from(host_trap(Error::Trap(TrapKind::Unreachable))) {
+        Error::Trap(TrapKind::Unreachable) => {}
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn should_pass_other_host_errors_through() {
+    match Error::from(host_trap(Error::Revert(1))) {
+        Error::Interpreter(error) => match error
+            .as_host_error()
+            .and_then(|host_error| host_error.downcast_ref::<Error>())
+        {
+            Some(Error::Revert(1)) => {}
+            other => panic!("unexpected host error: {:?}", other),
+        },
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
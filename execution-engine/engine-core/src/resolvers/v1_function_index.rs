@@ -43,10 +43,137 @@ pub enum FunctionIndex {
     GetMainPurseIndex,
     GetArgSizeFuncIndex,
     ReadHostBufferIndex,
+    TransferFromPurseToPurseWithIdIndex,
+    DeleteFuncIndex,
+    DeleteLocalFuncIndex,
+    DeletePurseIndex,
+    GetCallerChainIndex,
+    GetArgsLenFuncIndex,
+    GetBlockHeightIndex,
+    UpdateKeyFuncIndex,
+    CallContractIntoFuncIndex,
+    GetProtocolDataIndex,
+    ListAppendFuncIndex,
+    Blake2b256Index,
+    Keccak256Index,
+    RevertWithPayloadFuncIndex,
+    GetNamedArgSizeFuncIndex,
+    GetNamedArgFuncIndex,
+    GetArgEnvelopeFuncIndex,
+    GetDeployHashIndex,
+    GetAccountPublicKeyIndex,
+    ListNamedKeysChunkIndex,
+    IsFeatureEnabledIndex,
+    RandomBytesIndex,
+    TransferToAccountWithConfigIndex,
+    BurnIndex,
+    PutKeyVersionedFuncIndex,
+    GetOrCreateContractPurseIndex,
+    CreateContractPackageIndex,
+    AddContractVersionIndex,
+    DisableContractVersionIndex,
+    CallVersionedContractIndex,
+    GetWasmCostsIndex,
+    CallContractWithPaymentIndex,
+    CreateSubPurseIndex,
+    RevokeSubPurseIndex,
+    EmitEventIndex,
+    UpgradeContractAtURefWithNamedKeysIndex,
+    RemoveKeyCheckedFuncIndex,
+    StackOverflowIndex,
     #[cfg(feature = "test-support")]
     PrintIndex,
 }
 
+impl FunctionIndex {
+    /// Returns the name under which the host function is imported by Wasm modules.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FunctionIndex::WriteFuncIndex => "write",
+            FunctionIndex::WriteLocalFuncIndex => "write_local",
+            FunctionIndex::ReadFuncIndex => "read_value",
+            FunctionIndex::ReadLocalFuncIndex => "read_value_local",
+            FunctionIndex::AddFuncIndex => "add",
+            FunctionIndex::AddLocalFuncIndex => "add_local",
+            FunctionIndex::NewFuncIndex => "new_uref",
+            FunctionIndex::RetFuncIndex => "ret",
+            FunctionIndex::CallContractFuncIndex => "call_contract",
+            FunctionIndex::GetArgFuncIndex => "get_arg",
+            FunctionIndex::GetKeyFuncIndex => "get_key",
+            FunctionIndex::GasFuncIndex => "gas",
+            FunctionIndex::HasKeyFuncIndex => "has_key",
+            FunctionIndex::PutKeyFuncIndex => "put_key",
+            FunctionIndex::StoreFnIndex => "store_function",
+            FunctionIndex::StoreFnAtHashIndex => "store_function_at_hash",
+            FunctionIndex::IsValidURefFnIndex => "is_valid_uref",
+            FunctionIndex::RevertFuncIndex => "revert",
+            FunctionIndex::AddAssociatedKeyFuncIndex => "add_associated_key",
+            FunctionIndex::RemoveAssociatedKeyFuncIndex => "remove_associated_key",
+            FunctionIndex::UpdateAssociatedKeyFuncIndex => "update_associated_key",
+            FunctionIndex::SetActionThresholdFuncIndex => "set_action_threshold",
+            FunctionIndex::LoadNamedKeysFuncIndex => "load_named_keys",
+            FunctionIndex::RemoveKeyFuncIndex => "remove_key",
+            FunctionIndex::GetCallerIndex => "get_caller",
+            FunctionIndex::GetBlocktimeIndex => "get_blocktime",
+            FunctionIndex::CreatePurseIndex => "create_purse",
+            FunctionIndex::TransferToAccountIndex => "transfer_to_account",
+            FunctionIndex::TransferFromPurseToAccountIndex => "transfer_from_purse_to_account",
+            FunctionIndex::TransferFromPurseToPurseIndex => "transfer_from_purse_to_purse",
+            FunctionIndex::GetBalanceIndex => "get_balance",
+            FunctionIndex::GetPhaseIndex => "get_phase",
+            FunctionIndex::UpgradeContractAtURefIndex => "upgrade_contract_at_uref",
+            FunctionIndex::GetSystemContractIndex => "get_system_contract",
+            FunctionIndex::GetMainPurseIndex => "get_main_purse",
+            FunctionIndex::GetArgSizeFuncIndex => "get_arg_size",
+            FunctionIndex::ReadHostBufferIndex => "read_host_buffer",
+            FunctionIndex::TransferFromPurseToPurseWithIdIndex => {
+                "transfer_from_purse_to_purse_with_id"
+            }
+            FunctionIndex::DeleteFuncIndex => "delete",
+            FunctionIndex::DeleteLocalFuncIndex => "delete_local",
+            FunctionIndex::DeletePurseIndex => "delete_purse",
+            FunctionIndex::GetCallerChainIndex => "get_caller_chain",
+            FunctionIndex::GetArgsLenFuncIndex => "get_args_len",
+            FunctionIndex::GetBlockHeightIndex => "get_block_height",
+            FunctionIndex::UpdateKeyFuncIndex => "update_key",
+            FunctionIndex::CallContractIntoFuncIndex => "call_contract_into",
+            FunctionIndex::GetProtocolDataIndex => "get_protocol_data",
+            FunctionIndex::ListAppendFuncIndex => "list_append",
+            FunctionIndex::Blake2b256Index => "blake2b256",
+            FunctionIndex::Keccak256Index => "keccak256",
+            FunctionIndex::RevertWithPayloadFuncIndex => "revert_with_payload",
+            FunctionIndex::GetNamedArgSizeFuncIndex => "get_named_arg_size",
+            FunctionIndex::GetNamedArgFuncIndex => "get_named_arg",
+            FunctionIndex::GetArgEnvelopeFuncIndex => "get_arg_envelope",
+            FunctionIndex::GetDeployHashIndex => "get_deploy_hash",
+            FunctionIndex::GetAccountPublicKeyIndex => "get_account_public_key",
+            FunctionIndex::ListNamedKeysChunkIndex => "list_named_keys_chunk",
+            FunctionIndex::IsFeatureEnabledIndex => "is_feature_enabled",
+            FunctionIndex::RandomBytesIndex => "random_bytes",
+            FunctionIndex::TransferToAccountWithConfigIndex => "transfer_to_account_with_config",
+            FunctionIndex::BurnIndex => "burn",
+            FunctionIndex::PutKeyVersionedFuncIndex => "put_key_versioned",
+            FunctionIndex::GetOrCreateContractPurseIndex => "get_or_create_contract_purse",
+            FunctionIndex::CreateContractPackageIndex => "create_contract_package",
+            FunctionIndex::AddContractVersionIndex => "add_contract_version",
+            FunctionIndex::DisableContractVersionIndex => "disable_contract_version",
+            FunctionIndex::CallVersionedContractIndex => "call_versioned_contract",
+            FunctionIndex::GetWasmCostsIndex => "get_wasm_costs",
+            FunctionIndex::CallContractWithPaymentIndex => "call_contract_with_payment",
+            FunctionIndex::CreateSubPurseIndex => "create_sub_purse",
+            FunctionIndex::RevokeSubPurseIndex => "revoke_sub_purse",
+            FunctionIndex::EmitEventIndex => "emit_event",
+            FunctionIndex::UpgradeContractAtURefWithNamedKeysIndex => {
+                "upgrade_contract_at_uref_with_named_keys"
+            }
+            FunctionIndex::RemoveKeyCheckedFuncIndex => "remove_key_checked",
+            FunctionIndex::StackOverflowIndex => "stack_overflow",
+            #[cfg(feature = "test-support")]
+            FunctionIndex::PrintIndex => "print",
+        }
+    }
+}
+
 impl Into<usize> for FunctionIndex {
     fn into(self) -> usize {
         // NOTE: This can't fail as `FunctionIndex` is represented by usize,
@@ -67,6 +194,10 @@ mod tests {
     use super::FunctionIndex;
     use std::convert::TryFrom;
 
+    use wasmi::{ModuleImportResolver, Signature};
+
+    use crate::resolvers::v1_resolver::RuntimeModuleImportResolver;
+
     #[test]
     fn primitive_to_enum() {
         FunctionIndex::try_from(19).expect("Unable to create enum from number");
@@ -82,4 +213,20 @@ mod tests {
     fn invalid_index() {
         assert!(FunctionIndex::try_from(123_456_789usize).is_err());
     }
+
+    #[test]
+    fn name_should_resolve() {
+        let resolver = RuntimeModuleImportResolver::default();
+        let signature = Signature::new(&[][..], None);
+        let indices = (0..).map(FunctionIndex::try_from).take_while(Result::is_ok);
+        for index in indices.map(Result::unwrap) {
+            let resolved = resolver.resolve_func(index.name(), &signature);
+            assert!(
+                resolved.is_ok(),
+                "{:?} should resolve as {}",
+                index,
+                index.name()
+            );
+        }
+    }
 }
@@ -1,19 +1,19 @@
-use std::convert::TryFrom;
+use std::{collections::BTreeMap, convert::TryFrom};
 
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use types::{
-    account::PublicKey,
+    account::{PublicKey, Weight},
     api_error,
     bytesrepr::{self, ToBytes},
-    Key, TransferredTo, U512,
+    ContractVersion, Key, TransferredTo, URef, U512,
 };
 
 use engine_shared::{gas::Gas, stored_value::StoredValue};
 use engine_storage::global_state::StateReader;
 
-use super::{args::Args, Error, Runtime};
-use crate::resolvers::v1_function_index::FunctionIndex;
+use super::{args::Args, blake2b256, keccak256, Error, Runtime};
+use crate::{execution::TrapKind, resolvers::v1_function_index::FunctionIndex};
 
 impl<'a, R> Externals for Runtime<'a, R>
 where
@@ -26,6 +26,36 @@ where
         args: RuntimeArgs,
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
+        // `gas` is called by the code the module was instrumented with rather than by the contract,
+        // so it's left out of the trace
+        let trace = match self.host_function_trace.clone() {
+            Some(trace) if func != FunctionIndex::GasFuncIndex => trace,
+            _ => return self.invoke_host_function(func, args),
+        };
+
+        let gas_counter_before = self.context.gas_counter();
+        let call_index = trace.start(func.name(), self.context.phase());
+        let outcome = self.invoke_host_function(func, args);
+        let gas = self.context.gas_counter() - gas_counter_before;
+        let result = match outcome {
+            Ok(Some(RuntimeValue::I32(value))) => Some(value),
+            _ => None,
+        };
+        trace.finish(call_index, gas, result);
+        outcome
+    }
+}
+
+impl<'a, R> Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<Error>,
+{
+    fn invoke_host_function(
+        &mut self,
+        func: FunctionIndex,
+        args: RuntimeArgs,
+    ) -> Result<Option<RuntimeValue>, Trap> {
         match func {
             FunctionIndex::ReadFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
@@ -83,6 +113,16 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::ListAppendFuncIndex => {
+                // args(0) = pointer to key in Wasm memory
+                // args(1) = size of key
+                // args(2) = pointer to item
+                // args(3) = size of item
+                let (key_ptr, key_size, item_ptr, item_size) = Args::parse(args)?;
+                self.list_append(key_ptr, key_size, item_ptr, item_size)?;
+                Ok(None)
+            }
+
             FunctionIndex::AddLocalFuncIndex => {
                 // args(0) = pointer to key in Wasm memory
                 // args(1) = size of key
@@ -93,6 +133,22 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::DeleteFuncIndex => {
+                // args(0) = pointer to key in Wasm memory
+                // args(1) = size of key
+                let (key_ptr, key_size) = Args::parse(args)?;
+                self.delete(key_ptr, key_size)?;
+                Ok(None)
+            }
+
+            FunctionIndex::DeleteLocalFuncIndex => {
+                // args(0) = pointer to key in Wasm memory
+                // args(1) = size of key
+                let (key_bytes_ptr, key_bytes_size) = Args::parse(args)?;
+                self.delete_local(key_bytes_ptr, key_bytes_size)?;
+                Ok(None)
+            }
+
             FunctionIndex::NewFuncIndex => {
                 // args(0) = pointer to uref destination in Wasm memory
                 // args(1) = pointer to initial value
@@ -110,6 +166,13 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::GetArgsLenFuncIndex => {
+                // args(0) = pointer to the number of host runtime args (output)
+                let len_ptr = Args::parse(args)?;
+                self.get_args_len(len_ptr)?;
+                Ok(None)
+            }
+
             FunctionIndex::GetArgFuncIndex => {
                 // args(0) = index of host runtime arg to load
                 // args(1) = pointer to destination in Wasm memory
@@ -144,6 +207,31 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::CallContractIntoFuncIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to function arguments in Wasm memory
+                // args(3) = size of arguments
+                // args(4) = pointer to output buffer for the result
+                // args(5) = size of output buffer
+                // args(6) = pointer to result size (output)
+                let args: (u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+                let (key_ptr, key_size, args_ptr, args_size, dest_ptr, dest_size, result_size_ptr) =
+                    args;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+
+                let ret = self.call_contract_into_buffer(
+                    key_contract,
+                    args_bytes,
+                    dest_ptr,
+                    dest_size as usize,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::GetKeyFuncIndex => {
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
@@ -185,6 +273,36 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::UpdateKeyFuncIndex => {
+                // args(0) = pointer to key name in Wasm memory
+                // args(1) = size of key name
+                // args(2) = pointer to serialized expected `Option<Key>` in Wasm memory
+                // args(3) = size of serialized expected `Option<Key>`
+                // args(4) = pointer to new key in Wasm memory
+                // args(5) = size of new key
+                let (name_ptr, name_size, expected_ptr, expected_size, key_ptr, key_size) =
+                    Args::parse(args)?;
+                let result = self.update_key(
+                    name_ptr,
+                    name_size,
+                    expected_ptr,
+                    expected_size,
+                    key_ptr,
+                    key_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(result)))
+            }
+
+            FunctionIndex::PutKeyVersionedFuncIndex => {
+                // args(0) = pointer to key name in Wasm memory
+                // args(1) = size of key name
+                // args(2) = pointer to key in Wasm memory
+                // args(3) = size of key
+                let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
+                let version = self.put_key_versioned(name_ptr, name_size, key_ptr, key_size)?;
+                Ok(Some(RuntimeValue::I32(version)))
+            }
+
             FunctionIndex::RemoveKeyFuncIndex => {
                 // args(0) = pointer to key name in Wasm memory
                 // args(1) = size of key name
@@ -193,6 +311,20 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::RemoveKeyCheckedFuncIndex => {
+                // args(0) = pointer to key name in Wasm memory
+                // args(1) = size of key name
+                let (name_ptr, name_size) = Args::parse(args)?;
+                let result = self.remove_key_checked(name_ptr, name_size)?;
+                Ok(Some(RuntimeValue::I32(result)))
+            }
+
+            FunctionIndex::StackOverflowIndex => {
+                // Called by the stack height limiter injected during preprocessing in place of
+                // trapping with `unreachable`.
+                Err(Error::Trap(TrapKind::StackOverflow).into())
+            }
+
             FunctionIndex::GetCallerIndex => {
                 // args(0) = pointer where a size of serialized bytes will be stored
                 let output_size = Args::parse(args)?;
@@ -200,6 +332,27 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::GetCallerChainIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_caller_chain(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetProtocolDataIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_protocol_data(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetWasmCostsIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_wasm_costs(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::GetBlocktimeIndex => {
                 // args(0) = pointer to Wasm memory where to write.
                 let dest_ptr = Args::parse(args)?;
@@ -207,6 +360,13 @@ where
                 Ok(None)
             }
 
+            FunctionIndex::GetBlockHeightIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_block_height(dest_ptr)?;
+                Ok(None)
+            }
+
             FunctionIndex::GasFuncIndex => {
                 let gas_arg: u32 = Args::parse(args)?;
                 self.gas(Gas::new(gas_arg.into()))?;
@@ -400,6 +560,242 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::TransferFromPurseToPurseWithIdIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of a source purse
+                // args(1) = length of array of bytes in Wasm memory of a source purse
+                // args(2) = pointer to array of bytes in Wasm memory of a target purse
+                // args(3) = length of array of bytes in Wasm memory of a target purse
+                // args(4) = pointer to array of bytes in Wasm memory of an amount
+                // args(5) = length of array of bytes in Wasm memory of an amount
+                // args(6) = pointer to array of bytes in Wasm memory of an optional transfer id
+                // args(7) = length of array of bytes in Wasm memory of an optional transfer id
+                let (
+                    source_ptr,
+                    source_size,
+                    target_ptr,
+                    target_size,
+                    amount_ptr,
+                    amount_size,
+                    id_ptr,
+                    id_size,
+                ) = Args::parse(args)?;
+                let ret = self.transfer_from_purse_to_purse_with_id(
+                    source_ptr,
+                    source_size,
+                    target_ptr,
+                    target_size,
+                    amount_ptr,
+                    amount_size,
+                    id_ptr,
+                    id_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DeletePurseIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the purse to delete
+                // args(1) = length of array of bytes in Wasm memory of the purse to delete
+                // args(2) = pointer to array of bytes in Wasm memory of the refund purse
+                // args(3) = length of array of bytes in Wasm memory of the refund purse
+                let (purse_ptr, purse_size, refund_ptr, refund_size) = Args::parse(args)?;
+                let ret = self.delete_purse(purse_ptr, purse_size, refund_ptr, refund_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::BurnIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the purse to burn from
+                // args(1) = length of array of bytes in Wasm memory of the purse to burn from
+                // args(2) = pointer to array of bytes in Wasm memory of the amount to burn
+                // args(3) = length of array of bytes in Wasm memory of the amount to burn
+                let (purse_ptr, purse_size, amount_ptr, amount_size) = Args::parse(args)?;
+                let ret = self.burn(purse_ptr, purse_size, amount_ptr, amount_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetOrCreateContractPurseIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the contract's key
+                // args(1) = length of array of bytes in Wasm memory of the contract's key
+                // args(2) = pointer to array for return value
+                // args(3) = length of array for return value
+                let (key_ptr, key_size, dest_ptr, dest_size) = Args::parse(args)?;
+                let ret =
+                    self.get_or_create_contract_purse(key_ptr, key_size, dest_ptr, dest_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CreateSubPurseIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the parent purse
+                // args(1) = length of array of bytes in Wasm memory of the parent purse
+                // args(2) = pointer to array of bytes in Wasm memory of the cap
+                // args(3) = length of array of bytes in Wasm memory of the cap
+                // args(4) = pointer to array for return value
+                // args(5) = length of array for return value
+                let (parent_ptr, parent_size, cap_ptr, cap_size, dest_ptr, dest_size) =
+                    Args::parse(args)?;
+                let ret = self.create_sub_purse(
+                    parent_ptr,
+                    parent_size,
+                    cap_ptr,
+                    cap_size,
+                    dest_ptr,
+                    dest_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::RevokeSubPurseIndex => {
+                // args(0) = pointer to array of bytes in Wasm memory of the parent purse
+                // args(1) = length of array of bytes in Wasm memory of the parent purse
+                // args(2) = pointer to array of bytes in Wasm memory of the sub-purse
+                // args(3) = length of array of bytes in Wasm memory of the sub-purse
+                let (parent_ptr, parent_size, sub_purse_ptr, sub_purse_size) = Args::parse(args)?;
+                let ret =
+                    self.revoke_sub_purse(parent_ptr, parent_size, sub_purse_ptr, sub_purse_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::EmitEventIndex => {
+                // args(0) = pointer to the serialized topic in Wasm memory
+                // args(1) = size of the serialized topic
+                // args(2) = pointer to the serialized data in Wasm memory
+                // args(3) = size of the serialized data
+                let (topic_ptr, topic_size, data_ptr, data_size) = Args::parse(args)?;
+                let ret = self.emit_event(topic_ptr, topic_size, data_ptr, data_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CreateContractPackageIndex => {
+                // args(0) = pointer to array for return value
+                // args(1) = length of array for return value
+                let (dest_ptr, dest_size) = Args::parse(args)?;
+                let ret = self.create_contract_package(dest_ptr, dest_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::AddContractVersionIndex => {
+                // args(0) = pointer to package key in Wasm memory
+                // args(1) = size of package key
+                // args(2) = pointer to access key in Wasm memory
+                // args(3) = size of access key
+                // args(4) = pointer to contract key in Wasm memory
+                // args(5) = size of contract key
+                // args(6) = pointer to new version (output)
+                let args: (u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+                let (package_ptr, package_size, access_ptr, access_size, key_ptr, key_size, dest) =
+                    args;
+                let ret = self.add_contract_version(
+                    package_ptr,
+                    package_size,
+                    access_ptr,
+                    access_size,
+                    key_ptr,
+                    key_size,
+                    dest,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DisableContractVersionIndex => {
+                // args(0) = pointer to package key in Wasm memory
+                // args(1) = size of package key
+                // args(2) = pointer to access key in Wasm memory
+                // args(3) = size of access key
+                // args(4) = version to disable
+                let (package_ptr, package_size, access_ptr, access_size, version) =
+                    Args::parse(args)?;
+                let ret = self.disable_contract_version(
+                    package_ptr,
+                    package_size,
+                    access_ptr,
+                    access_size,
+                    version,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallVersionedContractIndex => {
+                // args(0) = pointer to package key in Wasm memory
+                // args(1) = size of package key
+                // args(2) = pointer to serialized `Option<ContractVersion>` in Wasm memory
+                // args(3) = size of serialized version
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to output buffer for the result
+                // args(7) = size of output buffer
+                // args(8) = pointer to result size (output)
+                let args: (u32, u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+                let (
+                    package_ptr,
+                    package_size,
+                    version_ptr,
+                    version_size,
+                    args_ptr,
+                    args_size,
+                    dest_ptr,
+                    dest_size,
+                    result_size_ptr,
+                ) = args;
+
+                let package_key: Key = self.key_from_mem(package_ptr, package_size)?;
+                let maybe_version: Option<ContractVersion> = {
+                    let bytes = self.bytes_from_mem(version_ptr, version_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+
+                let ret = self.call_versioned_contract(
+                    package_key,
+                    maybe_version,
+                    args_bytes,
+                    dest_ptr,
+                    dest_size as usize,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallContractWithPaymentIndex => {
+                // args(0) = pointer to key where contract is at in global state
+                // args(1) = size of key
+                // args(2) = pointer to function arguments in Wasm memory
+                // args(3) = size of arguments
+                // args(4) = pointer to serialized `(URef, U512)` of source purse and limit
+                // args(5) = size of serialized payment
+                // args(6) = pointer to output buffer for the result
+                // args(7) = size of output buffer
+                // args(8) = pointer to result size (output)
+                let args: (u32, u32, u32, u32, u32, u32, u32, u32, u32) = Args::parse(args)?;
+                let (
+                    key_ptr,
+                    key_size,
+                    args_ptr,
+                    args_size,
+                    payment_ptr,
+                    payment_size,
+                    dest_ptr,
+                    dest_size,
+                    result_size_ptr,
+                ) = args;
+
+                let key_contract: Key = self.key_from_mem(key_ptr, key_size)?;
+                let args_bytes: Vec<u8> = self.bytes_from_mem(args_ptr, args_size as usize)?;
+                let (purse, limit): (URef, U512) = {
+                    let bytes = self.bytes_from_mem(payment_ptr, payment_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+
+                let ret = self.call_contract_with_payment(
+                    key_contract,
+                    args_bytes,
+                    purse,
+                    limit,
+                    dest_ptr,
+                    dest_size as usize,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             FunctionIndex::GetBalanceIndex => {
                 // args(0) = pointer to purse input
                 // args(1) = length of purse
@@ -422,7 +818,34 @@ where
                 // args(2) = pointer to key in Wasm memory
                 // args(3) = size of key
                 let (name_ptr, name_size, key_ptr, key_size) = Args::parse(args)?;
-                let ret = self.upgrade_contract_at_uref(name_ptr, name_size, key_ptr, key_size)?;
+                let ret = self.upgrade_contract_at_uref(
+                    name_ptr,
+                    name_size,
+                    key_ptr,
+                    key_size,
+                    BTreeMap::new(),
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::UpgradeContractAtURefWithNamedKeysIndex => {
+                // args(0) = pointer to name in Wasm memory
+                // args(1) = size of name in Wasm memory
+                // args(2) = pointer to key in Wasm memory
+                // args(3) = size of key
+                // args(4) = pointer to additional named keys in Wasm memory
+                // args(5) = size of additional named keys
+                let (name_ptr, name_size, key_ptr, key_size, named_keys_ptr, named_keys_size) =
+                    Args::parse(args)?;
+                let additional_named_keys =
+                    self.named_keys_from_mem(named_keys_ptr, named_keys_size)?;
+                let ret = self.upgrade_contract_at_uref(
+                    name_ptr,
+                    name_size,
+                    key_ptr,
+                    key_size,
+                    additional_named_keys,
+                )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
@@ -449,6 +872,143 @@ where
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
 
+            FunctionIndex::Blake2b256Index => {
+                // args(0) = pointer to input in Wasm memory
+                // args(1) = size of input
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (in_ptr, in_size, out_ptr) = Args::parse(args)?;
+                self.hash(blake2b256, in_ptr, in_size, out_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::Keccak256Index => {
+                // args(0) = pointer to input in Wasm memory
+                // args(1) = size of input
+                // args(2) = pointer to Wasm memory where to write the 32-byte digest
+                let (in_ptr, in_size, out_ptr) = Args::parse(args)?;
+                self.hash(keccak256, in_ptr, in_size, out_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::RevertWithPayloadFuncIndex => {
+                // args(0) = status u32
+                // args(1) = pointer to payload in Wasm memory
+                // args(2) = size of payload
+                let (status, payload_ptr, payload_size) = Args::parse(args)?;
+                Err(self.revert_with_payload(status, payload_ptr, payload_size))
+            }
+
+            FunctionIndex::GetNamedArgSizeFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of name
+                // args(2) = pointer to a argument size (output)
+                let (name_ptr, name_size, size_ptr) = Args::parse(args)?;
+                let ret = self.get_named_arg_size(name_ptr, name_size, size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetNamedArgFuncIndex => {
+                // args(0) = pointer to name of host runtime arg to load
+                // args(1) = size of name
+                // args(2) = pointer to destination in Wasm memory
+                // args(3) = size of destination pointer memory
+                let (name_ptr, name_size, dest_ptr, dest_size): (_, _, _, u32) = Args::parse(args)?;
+                let ret = self.get_named_arg(name_ptr, name_size, dest_ptr, dest_size as usize)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetArgEnvelopeFuncIndex => {
+                // args(0) = index of host runtime arg to load
+                // args(1) = pointer where a size of serialized bytes will be stored
+                let (index, output_size): (u32, _) = Args::parse(args)?;
+                let ret = self.get_arg_envelope(index as usize, output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::GetDeployHashIndex => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.get_deploy_hash(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetAccountPublicKeyIndex => {
+                // args(0) = pointer where a size of serialized bytes will be stored
+                let output_size = Args::parse(args)?;
+                let ret = self.get_account_public_key(output_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::ListNamedKeysChunkIndex => {
+                // args(0) = index of the first named key in the chunk
+                // args(1) = maximum number of named keys in the chunk
+                // args(2) = pointer to amount of serialized bytes (output)
+                let (start_index, max, result_size_ptr) = Args::parse(args)?;
+                let ret = self.list_named_keys_chunk(start_index, max, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::IsFeatureEnabledIndex => {
+                // args(0) = index of the engine feature
+                let feature_index = Args::parse(args)?;
+                let ret = self.is_feature_enabled(feature_index);
+                Ok(Some(RuntimeValue::I32(ret)))
+            }
+
+            FunctionIndex::RandomBytesIndex => {
+                // args(0) = pointer to Wasm memory where to write the random bytes
+                let dest_ptr = Args::parse(args)?;
+                self.random_bytes(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::TransferToAccountWithConfigIndex => {
+                // args(0) = pointer to array of bytes of a public key
+                // args(1) = length of array of bytes of a public key
+                // args(2) = pointer to array of bytes of an amount
+                // args(3) = length of array of bytes of an amount
+                // args(4) = weight of the associated key of a new account
+                // args(5) = deployment threshold of a new account
+                // args(6) = key management threshold of a new account
+                // args(7) = pointer to Wasm memory where to write the `TransferredTo` value
+                let (
+                    key_ptr,
+                    key_size,
+                    amount_ptr,
+                    amount_size,
+                    initial_weight,
+                    deployment_threshold,
+                    key_management_threshold,
+                    transferred_to_ptr,
+                ): (u32, u32, u32, u32, u8, u8, u8, u32) = Args::parse(args)?;
+                let public_key: PublicKey = {
+                    let bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let amount: U512 = {
+                    let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+                    bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+                };
+                let ret = match self.transfer_to_account_with_config(
+                    public_key,
+                    amount,
+                    Weight::new(initial_weight),
+                    Weight::new(deployment_threshold),
+                    Weight::new(key_management_threshold),
+                )? {
+                    Ok(transferred_to) => {
+                        // Wasm is little-endian
+                        let transferred_to_bytes = (transferred_to as i32).to_le_bytes();
+                        self.memory
+                            .set(transferred_to_ptr, &transferred_to_bytes)
+                            .map_err(Error::Interpreter)?;
+                        Ok(())
+                    }
+                    Err(error) => Err(error),
+                };
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
             #[cfg(feature = "test-support")]
             FunctionIndex::PrintIndex => {
                 let (text_ptr, text_size) = Args::parse(args)?;
@@ -5,7 +5,7 @@ use types::{
     account::PublicKey,
     bytesrepr::{FromBytes, ToBytes},
     system_contract_errors::mint::Error,
-    CLTyped, CLValue, Key, URef,
+    CLTyped, CLValue, CallStackElement, Key, URef,
 };
 
 use crate::{execution, runtime_context::RuntimeContext};
@@ -19,10 +19,22 @@ where
         self.get_caller()
     }
 
+    fn get_immediate_caller(&self) -> Option<CallStackElement> {
+        self.call_stack().iter().rev().nth(1).cloned()
+    }
+
+    fn get_key(&self, name: &str) -> Option<Key> {
+        self.named_keys_get(name).cloned()
+    }
+
     fn put_key(&mut self, name: &str, key: Key) {
         // TODO: update RuntimeProvider to better handle errors
         self.put_key(name.to_string(), key).expect("should put key")
     }
+
+    fn remove_key(&mut self, name: &str) {
+        self.remove_key(name).expect("should remove key")
+    }
 }
 
 // TODO: update Mint + StorageProvider to better handle errors
@@ -82,6 +94,16 @@ where
         self.add_gs(Key::URef(uref), StoredValue::CLValue(cl_value))
             .map_err(|_| Error::Storage)
     }
+
+    fn delete_local<K: ToBytes>(&mut self, key: K) {
+        let key_bytes = key.to_bytes().expect("should serialize");
+        self.delete_ls(&key_bytes)
+            .expect("should delete local state")
+    }
+
+    fn delete(&mut self, uref: URef) -> Result<(), Error> {
+        self.delete_gs(Key::URef(uref)).map_err(|_| Error::Storage)
+    }
 }
 
 impl<'a, R> Mint for RuntimeContext<'a, R>
@@ -13,26 +13,41 @@ use std::{
 
 use itertools::Itertools;
 use parity_wasm::elements::Module;
+use tiny_keccak::{Hasher, Keccak};
 use wasmi::{ImportsBuilder, MemoryRef, ModuleInstance, ModuleRef, Trap, TrapKind};
 
 use ::mint::Mint;
 use contract::args_parser::ArgsParser;
-use engine_shared::{account::Account, contract::Contract, gas::Gas, stored_value::StoredValue};
+use engine_shared::{
+    account::{Account, ActionThresholds, AssociatedKeys},
+    contract::Contract,
+    gas::Gas,
+    newtypes::Blake2bHash,
+    stored_value::StoredValue,
+};
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use proof_of_stake::ProofOfStake;
 use standard_payment::StandardPayment;
 use types::{
-    account::{ActionType, PublicKey, Weight},
+    account::{ActionType, PublicKey, SetThresholdFailure, Weight},
     bytesrepr::{self, FromBytes, ToBytes},
-    system_contract_errors,
-    system_contract_errors::mint,
-    AccessRights, ApiError, CLType, CLTyped, CLValue, Key, ProtocolVersion, SystemContractType,
-    TransferResult, TransferredTo, URef, U128, U256, U512,
+    key_versions_name, system_contract_errors,
+    system_contract_errors::{mint, pos},
+    versioned_key_name, AccessRights, ApiError, CLType, CLTyped, CLValue, CLValueEnvelope,
+    ContractPackage, ContractVersion, EngineFeature, Key, ProtocolInfo, ProtocolVersion,
+    RuntimeArgs, SystemContractType, TransferResult, TransferredTo, URef, KEY_HASH_LENGTH,
+    KEY_LOCAL_MAX_KEY_BYTES, MAX_REVERT_PAYLOAD_LENGTH, PAYMENT_PURSE_ARG, U128, U256, U512,
 };
 
 use crate::{
-    engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-    execution::{Error, MINT_NAME, POS_NAME},
+    engine_state::{
+        contract_event::{ContractEvent, EventLog},
+        genesis::CHAIN_NAME_KEY,
+        host_function_trace::{HostFunctionCall, HostFunctionTrace},
+        system_contract_cache::SystemContractCache,
+        EngineConfig, SYSTEM_ACCOUNT_ADDR,
+    },
+    execution::{Error, MINT_NAME, POS_NAME, RANDOM_BYTES_HASHED_LENGTH},
     resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
     runtime_context::RuntimeContext,
     Address,
@@ -45,6 +60,13 @@ pub struct Runtime<'a, R> {
     module: Module,
     host_buffer: Option<CLValue>,
     context: RuntimeContext<'a, R>,
+    // The depth of nested contract calls of this runtime, where the session or payment code runs
+    // at depth 1
+    call_depth: usize,
+    // The host function calls made so far, if the engine is configured to trace them
+    host_function_trace: Option<HostFunctionTrace>,
+    // The events emitted so far, shared with the runtimes of nested calls
+    events: EventLog,
 }
 
 /// Rename function called `name` in the `module` to `call`.
@@ -93,6 +115,22 @@ pub fn key_to_tuple(key: Key) -> Option<([u8; 32], AccessRights)> {
     }
 }
 
+/// Converts the result of a transfer between purses via the mint into the result returned to the
+/// calling contract.
+///
+/// A source purse without write rights or a target purse without add rights is reported as the
+/// mint's `InvalidAccessRights` error, so that contracts can tell it apart from any other failure,
+/// which is reported as `ApiError::Transfer`.
+fn purse_transfer_result(result: Result<(), Error>) -> Result<(), ApiError> {
+    match result {
+        Ok(()) => Ok(()),
+        Err(Error::SystemContract(system_contract_errors::Error::Mint(
+            mint::Error::InvalidAccessRights,
+        ))) => Err(mint::Error::InvalidAccessRights.into()),
+        Err(_) => Err(ApiError::Transfer),
+    }
+}
+
 /// Groups a collection of urefs by their addresses and accumulates access
 /// rights per key
 pub fn extract_access_rights_from_urefs<I: IntoIterator<Item = URef>>(
@@ -133,7 +171,7 @@ pub fn extract_access_rights_from_keys<I: IntoIterator<Item = Key>>(
 }
 
 #[allow(clippy::cognitive_complexity)]
-fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
+pub(crate) fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
     match cl_value.cl_type() {
         CLType::Bool
         | CLType::I32
@@ -1339,6 +1377,21 @@ fn extract_urefs(cl_value: &CLValue) -> Result<Vec<URef>, Error> {
     }
 }
 
+/// The length in bytes of the digests computed by the hashing host functions.
+const HASH_DIGEST_LENGTH: usize = 32;
+
+pub(crate) fn blake2b256(data: &[u8]) -> [u8; HASH_DIGEST_LENGTH] {
+    Blake2bHash::new(data).value()
+}
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; HASH_DIGEST_LENGTH] {
+    let mut digest = [0u8; HASH_DIGEST_LENGTH];
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    keccak.finalize(&mut digest);
+    digest
+}
+
 impl<'a, R> Runtime<'a, R>
 where
     R: StateReader<Key, StoredValue>,
@@ -1351,6 +1404,11 @@ where
         module: Module,
         context: RuntimeContext<'a, R>,
     ) -> Self {
+        let host_function_trace = if config.trace_host_functions() {
+            Some(HostFunctionTrace::default())
+        } else {
+            None
+        };
         Runtime {
             config,
             system_contract_cache,
@@ -1358,6 +1416,9 @@ where
             module,
             host_buffer: None,
             context,
+            call_depth: 1,
+            host_function_trace,
+            events: EventLog::default(),
         }
     }
 
@@ -1403,8 +1464,40 @@ where
         }
     }
 
+    /// Charges for writing `byte_count` bytes to global state at the per-byte storage rate.  This
+    /// must be called before the write, so that running out of gas leaves no partial transform.
+    fn charge_storage(&mut self, byte_count: u32) -> Result<(), Trap> {
+        let storage_per_byte = self.protocol_data().wasm_costs().storage_per_byte;
+        let cost = U512::from(storage_per_byte) * U512::from(byte_count);
+        self.gas(Gas::new(cost))
+    }
+
+    /// Hashes the `in_size` bytes at `in_ptr` with `hash_function` and writes the digest to
+    /// `out_ptr`, charging for each byte hashed at the rate set in the wasm costs.
+    fn hash(
+        &mut self,
+        hash_function: fn(&[u8]) -> [u8; HASH_DIGEST_LENGTH],
+        in_ptr: u32,
+        in_size: u32,
+        out_ptr: u32,
+    ) -> Result<(), Trap> {
+        let hash_per_byte = self.protocol_data().wasm_costs().hash_per_byte;
+        let cost = U512::from(hash_per_byte) * U512::from(in_size);
+        self.gas(Gas::new(cost))?;
+
+        let data = self.bytes_from_mem(in_ptr, in_size as usize)?;
+        let digest = hash_function(&data);
+        self.memory
+            .set(out_ptr, &digest)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     fn bytes_from_mem(&self, ptr: u32, size: usize) -> Result<Vec<u8>, Error> {
-        self.memory.get(ptr, size).map_err(Into::into)
+        let bytes = self.memory.get(ptr, size)?;
+        if let Some(trace) = &self.host_function_trace {
+            trace.record_arg(&bytes);
+        }
+        Ok(bytes)
     }
 
     /// Reads key (defined as `key_ptr` and `key_size` tuple) from Wasm memory.
@@ -1413,6 +1506,17 @@ where
         bytesrepr::deserialize(bytes).map_err(Into::into)
     }
 
+    /// Reads named keys (defined as `named_keys_ptr` and `named_keys_size` tuple) from Wasm
+    /// memory.
+    fn named_keys_from_mem(
+        &mut self,
+        named_keys_ptr: u32,
+        named_keys_size: u32,
+    ) -> Result<BTreeMap<String, Key>, Error> {
+        let bytes = self.bytes_from_mem(named_keys_ptr, named_keys_size as usize)?;
+        bytesrepr::deserialize(bytes).map_err(Into::into)
+    }
+
     /// Reads `CLValue` (defined as `cl_value_ptr` and `cl_value_size` tuple) from Wasm memory.
     fn cl_value_from_mem(
         &mut self,
@@ -1462,7 +1566,7 @@ where
     }
 
     fn get_arg_size(&mut self, index: usize, size_ptr: u32) -> Result<Result<(), ApiError>, Trap> {
-        let arg_size = match self.context.args().get(index) {
+        let arg_size = match self.context.args().get_by_index(index) {
             Some(arg) if arg.inner_bytes().len() > u32::max_value() as usize => {
                 return Ok(Err(ApiError::OutOfMemory))
             }
@@ -1479,13 +1583,21 @@ where
         Ok(Ok(()))
     }
 
+    fn get_args_len(&mut self, len_ptr: u32) -> Result<(), Trap> {
+        let args_len = self.context.args().len() as u32;
+        let args_len_bytes = args_len.to_le_bytes(); // Wasm is little-endian
+        self.memory
+            .set(len_ptr, &args_len_bytes)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     fn get_arg(
         &mut self,
         index: usize,
         output_ptr: u32,
         output_size: usize,
     ) -> Result<Result<(), ApiError>, Trap> {
-        let arg = match self.context.args().get(index) {
+        let arg = match self.context.args().get_by_index(index) {
             Some(arg) => arg,
             None => return Ok(Err(ApiError::MissingArgument)),
         };
@@ -1504,6 +1616,70 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the size of the argument named by the string in Wasm memory at `name_ptr` to
+    /// `size_ptr`, as `get_arg_size` does for the argument with the same position.
+    fn get_named_arg_size(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        match self.context.args().index_of(&name) {
+            Some(index) => self.get_arg_size(index, size_ptr),
+            None => Ok(Err(ApiError::MissingArgument)),
+        }
+    }
+
+    /// Writes the argument named by the string in Wasm memory at `name_ptr` to `output_ptr`, as
+    /// `get_arg` does for the argument with the same position.
+    fn get_named_arg(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        output_ptr: u32,
+        output_size: usize,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        match self.context.args().index_of(&name) {
+            Some(index) => self.get_arg(index, output_ptr, output_size),
+            None => Ok(Err(ApiError::MissingArgument)),
+        }
+    }
+
+    /// Writes the argument at `index` along with its type, i.e. as a typed [`CLValueEnvelope`], to
+    /// the host buffer.  The envelope is written as its serialized bytes, since it has no
+    /// `CLType` of its own.
+    fn get_arg_envelope(
+        &mut self,
+        index: usize,
+        output_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let arg = match self.context.args().get_by_index(index) {
+            Some(arg) => arg.clone(),
+            None => return Ok(Err(ApiError::MissingArgument)),
+        };
+        let envelope_bytes = CLValueEnvelope::Typed(arg)
+            .into_bytes()
+            .map_err(Error::BytesRepr)?;
+        let value = CLValue::from_t(envelope_bytes).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len();
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+        Ok(Ok(()))
+    }
+
     /// Load the uref known by the given name into the Wasm memory
     fn load_key(
         &mut self,
@@ -1562,17 +1738,148 @@ where
         key_ptr: u32,
         key_size: u32,
     ) -> Result<(), Trap> {
+        self.charge_storage(name_size.saturating_add(key_size))?;
         let name = self.string_from_mem(name_ptr, name_size)?;
+        self.check_key_name_not_reserved(&name)?;
+        self.check_named_key_limits(&name)?;
         let key = self.key_from_mem(key_ptr, key_size)?;
         self.context.put_key(name, key).map_err(Into::into)
     }
 
-    fn remove_key(&mut self, name_ptr: u32, name_size: u32) -> Result<(), Trap> {
+    /// Puts the key at [key_ptr] under the name at [name_ptr] in the current context's named keys,
+    /// archiving the key currently stored under that name as one of its versions.  Returns the
+    /// version of the new key.
+    fn put_key_versioned(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+    ) -> Result<i32, Trap> {
+        self.charge_storage(name_size.saturating_add(key_size))?;
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        self.check_key_name_not_reserved(&name)?;
+        // After the first version, archiving the current key is what adds a named key.
+        match self.context.key_versions(&name)? {
+            Some((_, version)) => {
+                self.check_named_key_limits(&versioned_key_name(&name, version))?;
+            }
+            // The first version adds its version count as well as the key itself.
+            None => {
+                self.check_named_key_name_length(&name)?;
+                self.check_named_key_name_length(&key_versions_name(&name))?;
+                let named_keys = self.context.named_keys();
+                let added = if named_keys.contains_key(&name) { 1 } else { 2 };
+                if named_keys.len() + added > self.config.max_named_keys() {
+                    return Err(Error::Revert(ApiError::TooManyNamedKeys.into()).into());
+                }
+            }
+        }
+        let key = self.key_from_mem(key_ptr, key_size)?;
+        let version = self.context.put_key_versioned(name, key)?;
+        // For all practical purposes the number of versions fits in an i32.
+        Ok(version as i32)
+    }
+
+    /// Checks that putting a key under `name` keeps the current context's named keys within the
+    /// limits set in the engine config, reverting with `ApiError::NamedKeyNameTooLong` or
+    /// `ApiError::TooManyNamedKeys` otherwise.  Replacing an existing key doesn't add to the count.
+    ///
+    /// The mint and proof of stake contracts are exempt, as they keep a named key per purse and
+    /// per bond.
+    fn check_named_key_limits(&self, name: &str) -> Result<(), Error> {
+        let base_key = self.context.base_key();
+        if self.is_mint(base_key) || self.is_proof_of_stake(base_key) {
+            return Ok(());
+        }
+        self.check_named_key_name_length(name)?;
+        let named_keys = self.context.named_keys();
+        if !named_keys.contains_key(name) && named_keys.len() >= self.config.max_named_keys() {
+            return Err(Error::Revert(ApiError::TooManyNamedKeys.into()));
+        }
+        Ok(())
+    }
+
+    /// Checks that `named_keys`, given to a newly stored or upgraded contract, are within the
+    /// limits set in the engine config, as per `check_named_key_limits`.
+    fn check_contract_named_keys_limits(
+        &self,
+        named_keys: &BTreeMap<String, Key>,
+    ) -> Result<(), Error> {
+        for name in named_keys.keys() {
+            self.check_named_key_name_length(name)?;
+        }
+        if named_keys.len() > self.config.max_named_keys() {
+            return Err(Error::Revert(ApiError::TooManyNamedKeys.into()));
+        }
+        Ok(())
+    }
+
+    /// Checks that `name` isn't reserved for the archived versions and version counts of keys put
+    /// with `put_key_versioned`, reverting with `ApiError::PermissionDenied` otherwise.
+    fn check_key_name_not_reserved(&mut self, name: &str) -> Result<(), Error> {
+        if self.context.is_reserved_key_name(name)? {
+            return Err(Error::Revert(ApiError::PermissionDenied.into()));
+        }
+        Ok(())
+    }
+
+    fn check_named_key_name_length(&self, name: &str) -> Result<(), Error> {
+        if name.len() > self.config.max_named_key_name_length() {
+            return Err(Error::Revert(ApiError::NamedKeyNameTooLong.into()));
+        }
+        Ok(())
+    }
+
+    /// Puts the key at [key_ptr] under the name at [name_ptr] in the current context's named keys
+    /// if the key currently stored under that name matches the `Option<Key>` at [expected_ptr].
+    /// Returns `0` on success, or the `UpdateKeyError` code otherwise.
+    fn update_key(
+        &mut self,
+        name_ptr: u32,
+        name_size: u32,
+        expected_ptr: u32,
+        expected_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+    ) -> Result<i32, Trap> {
+        self.charge_storage(name_size.saturating_add(key_size))?;
         let name = self.string_from_mem(name_ptr, name_size)?;
-        self.context.remove_key(&name)?;
+        self.check_key_name_not_reserved(&name)?;
+        self.check_named_key_limits(&name)?;
+        let expected: Option<Key> = {
+            let bytes = self.bytes_from_mem(expected_ptr, expected_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        let new_key = self.key_from_mem(key_ptr, key_size)?;
+        match self.context.update_key(name, expected, new_key)? {
+            Ok(()) => Ok(0),
+            // `UpdateKeyError` is represented as i32 with variants starting at `1`.
+            Err(error) => Ok(error as i32),
+        }
+    }
+
+    /// Removes the key under the name at [name_ptr] from the current context's named keys, along
+    /// with its archived versions if it was put there by `put_key_versioned`.
+    fn remove_key(&mut self, name_ptr: u32, name_size: u32) -> Result<(), Trap> {
+        self.remove_key_checked(name_ptr, name_size)?;
         Ok(())
     }
 
+    /// Removes the key under the name at [name_ptr] as per `remove_key`.  Returns `0` if there was
+    /// such a key, or `1` otherwise.
+    fn remove_key_checked(&mut self, name_ptr: u32, name_size: u32) -> Result<i32, Trap> {
+        let name = self.string_from_mem(name_ptr, name_size)?;
+        self.check_key_name_not_reserved(&name)?;
+        let existed = self.context.named_keys_contains_key(&name);
+        self.context.remove_key_versioned(&name)?;
+        if existed {
+            Ok(0)
+        } else {
+            Ok(1)
+        }
+    }
+
     /// Writes runtime context's account main purse to [dest_ptr] in the Wasm memory.
     fn get_main_purse(&mut self, dest_ptr: u32) -> Result<(), Trap> {
         let purse = self.context.get_main_purse()?;
@@ -1585,11 +1892,29 @@ where
     /// Writes caller (deploy) account public key to [dest_ptr] in the Wasm
     /// memory.
     fn get_caller(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        let caller = self.context.get_caller();
+        self.write_public_key_to_host_buffer(caller, output_size)
+    }
+
+    /// Writes the public key of the account which made the deploy to the host buffer, and the size
+    /// of its serialized form to `output_size` in Wasm memory.
+    ///
+    /// The account is passed on to stored contracts, so this is the same in nested calls.
+    fn get_account_public_key(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        let public_key = self.context.account().public_key();
+        self.write_public_key_to_host_buffer(public_key, output_size)
+    }
+
+    fn write_public_key_to_host_buffer(
+        &mut self,
+        public_key: PublicKey,
+        output_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
         if !self.can_write_to_host_buffer() {
             // Exit early if the host buffer is already occupied
             return Ok(Err(ApiError::HostBufferFull));
         }
-        let value = CLValue::from_t(self.context.get_caller()).map_err(Error::CLValue)?;
+        let value = CLValue::from_t(public_key).map_err(Error::CLValue)?;
         let value_size = value.inner_bytes().len();
 
         // Save serialized public key into host buffer
@@ -1605,6 +1930,98 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes the call stack of the current context, starting with the deploy's account and ending
+    /// with the currently executing entity, to the host buffer.
+    fn get_caller_chain(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let call_stack = self.context.call_stack().to_vec();
+        let value = CLValue::from_t(call_stack).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len();
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+        Ok(Ok(()))
+    }
+
+    /// Writes the protocol version and chain name to the host buffer, and the size of their
+    /// serialized form to `output_size` in Wasm memory.
+    fn get_protocol_data(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let chain_name = match self.chain_name()? {
+            Some(chain_name) => chain_name,
+            None => return Ok(Err(ApiError::MissingKey)),
+        };
+        let protocol_info = ProtocolInfo::new(self.context.protocol_version(), chain_name);
+        let value = CLValue::from_t(protocol_info).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len() as u32;
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+        Ok(Ok(()))
+    }
+
+    /// Writes the wasm costs of the current protocol version to the host buffer, and the size of
+    /// their serialized form to `output_size` in Wasm memory.
+    fn get_wasm_costs(&mut self, output_size: u32) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let wasm_costs = *self.protocol_data().wasm_costs();
+        let value = CLValue::from_t(wasm_costs).map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len() as u32;
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size, &output_size_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+        Ok(Ok(()))
+    }
+
+    /// Returns the chain name recorded under the system account's named keys at genesis, or `None`
+    /// if there is none.
+    fn chain_name(&mut self) -> Result<Option<String>, Error> {
+        let system_account = match self
+            .context
+            .read_gs_direct(&Key::Account(SYSTEM_ACCOUNT_ADDR))?
+        {
+            Some(StoredValue::Account(account)) => account,
+            _ => return Ok(None),
+        };
+        let chain_name_key = match system_account.named_keys().get(CHAIN_NAME_KEY) {
+            Some(key) => *key,
+            None => return Ok(None),
+        };
+        match self.context.read_gs_direct(&chain_name_key)? {
+            Some(StoredValue::CLValue(cl_value)) => {
+                cl_value.into_t().map(Some).map_err(Error::CLValue)
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// Writes runtime context's phase to [dest_ptr] in the Wasm memory.
     fn get_phase(&mut self, dest_ptr: u32) -> Result<(), Trap> {
         let phase = self.context.phase();
@@ -1626,6 +2043,28 @@ where
             .map_err(|e| Error::Interpreter(e).into())
     }
 
+    /// Writes the hash of the deploy being executed to [dest_ptr] in Wasm memory.
+    ///
+    /// Stored contracts run as part of the same deploy, so this is the same in nested calls.
+    fn get_deploy_hash(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let deploy_hash = self.context.get_deployhash();
+        self.memory
+            .set(dest_ptr, &deploy_hash)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
+    /// Writes current block height to [dest_ptr] in Wasm memory.
+    fn get_block_height(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let block_height = self
+            .context
+            .get_block_height()
+            .into_bytes()
+            .map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &block_height)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(&mut self, value_ptr: u32, value_size: usize) -> Trap {
@@ -1644,7 +2083,15 @@ where
                     Some(buf) => extract_urefs(buf),
                     None => Ok(vec![]),
                 };
-                match urefs {
+                // The caller is granted the rights of the returned urefs, so they can't carry more
+                // rights than the callee holds.
+                let validated_urefs = urefs.and_then(|urefs| {
+                    for uref in &urefs {
+                        self.context.validate_uref(uref)?;
+                    }
+                    Ok(urefs)
+                });
+                match validated_urefs {
                     Ok(urefs) => Error::Ret(urefs).into(),
                     Err(e) => e.into(),
                 }
@@ -1692,6 +2139,13 @@ where
         const METHOD_CREATE: &str = "create";
         const METHOD_BALANCE: &str = "balance";
         const METHOD_TRANSFER: &str = "transfer";
+        const METHOD_TRANSFER_WITH_ID: &str = "transfer_with_id";
+        const METHOD_DELETE_PURSE: &str = "delete_purse";
+        const METHOD_TOTAL_SUPPLY: &str = "total_supply";
+        const METHOD_BURN: &str = "burn";
+        const METHOD_GET_OR_CREATE_CONTRACT_PURSE: &str = "get_or_create_contract_purse";
+        const METHOD_CREATE_SUB_PURSE: &str = "create_sub_purse";
+        const METHOD_REVOKE_SUB_PURSE: &str = "revoke_sub_purse";
 
         let state = self.context.state();
         let access_rights = {
@@ -1704,7 +2158,9 @@ where
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
         let base_key = self.protocol_data().mint().into();
+        let call_stack = self.context.call_stack_with_callee(base_key);
         let blocktime = self.context.get_blocktime();
+        let block_height = self.context.get_block_height();
         let deploy_hash = self.context.get_deployhash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
@@ -1718,11 +2174,13 @@ where
             state,
             &mut named_keys,
             access_rights,
-            args.to_owned(),
+            RuntimeArgs::from(args.to_vec()),
             authorization_keys,
             account,
             base_key,
+            call_stack,
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -1763,6 +2221,59 @@ where
                 let result: Result<(), mint::Error> = mint_context.transfer(source, target, amount);
                 CLValue::from_t(result).map_err(Self::reverter)?
             }
+            // Type: `fn transfer_with_id(source: URef, target: URef, amount: U512, id: Option<u64>)
+            // -> Result<(), Error>`
+            METHOD_TRANSFER_WITH_ID => {
+                let source: URef = Self::get_argument(&args, 1)?;
+                let target: URef = Self::get_argument(&args, 2)?;
+                let amount: U512 = Self::get_argument(&args, 3)?;
+                let id: Option<u64> = Self::get_argument(&args, 4)?;
+                let result: Result<(), mint::Error> =
+                    mint_context.transfer_with_id(source, target, amount, id);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn delete_purse(purse: URef, refund_to: URef) -> Result<(), Error>`
+            METHOD_DELETE_PURSE => {
+                let purse: URef = Self::get_argument(&args, 1)?;
+                let refund_to: URef = Self::get_argument(&args, 2)?;
+                let result: Result<(), mint::Error> = mint_context.delete_purse(purse, refund_to);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn total_supply() -> U512`
+            METHOD_TOTAL_SUPPLY => {
+                let total_supply: U512 = mint_context.total_supply().map_err(Self::reverter)?;
+                CLValue::from_t(total_supply).map_err(Self::reverter)?
+            }
+            // Type: `fn burn(purse: URef, amount: U512) -> Result<(), Error>`
+            METHOD_BURN => {
+                let purse: URef = Self::get_argument(&args, 1)?;
+                let amount: U512 = Self::get_argument(&args, 2)?;
+                let result: Result<(), mint::Error> = mint_context.burn(purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn get_or_create_contract_purse(contract_hash: [u8; 32])
+            // -> Result<URef, Error>`
+            METHOD_GET_OR_CREATE_CONTRACT_PURSE => {
+                let contract_hash: [u8; KEY_HASH_LENGTH] = Self::get_argument(&args, 1)?;
+                let result: Result<URef, mint::Error> =
+                    mint_context.get_or_create_contract_purse(contract_hash);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn create_sub_purse(parent: URef, cap: U512) -> Result<URef, Error>`
+            METHOD_CREATE_SUB_PURSE => {
+                let parent: URef = Self::get_argument(&args, 1)?;
+                let cap: U512 = Self::get_argument(&args, 2)?;
+                let result: Result<URef, mint::Error> = mint_context.create_sub_purse(parent, cap);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
+            // Type: `fn revoke_sub_purse(parent: URef, sub_purse: URef) -> Result<(), Error>`
+            METHOD_REVOKE_SUB_PURSE => {
+                let parent: URef = Self::get_argument(&args, 1)?;
+                let sub_purse: URef = Self::get_argument(&args, 2)?;
+                let result: Result<(), mint::Error> =
+                    mint_context.revoke_sub_purse(parent, sub_purse);
+                CLValue::from_t(result).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1780,10 +2291,15 @@ where
     ) -> Result<CLValue, Error> {
         const METHOD_BOND: &str = "bond";
         const METHOD_UNBOND: &str = "unbond";
+        const METHOD_WITHDRAW_UNBONDED: &str = "withdraw_unbonded";
         const METHOD_GET_PAYMENT_PURSE: &str = "get_payment_purse";
         const METHOD_SET_REFUND_PURSE: &str = "set_refund_purse";
         const METHOD_GET_REFUND_PURSE: &str = "get_refund_purse";
         const METHOD_FINALIZE_PAYMENT: &str = "finalize_payment";
+        const METHOD_SET_COMMISSION: &str = "set_commission";
+        const METHOD_GET_GAS_PRICE: &str = "get_gas_price";
+        const METHOD_SLASH: &str = "slash";
+        const METHOD_DISTRIBUTE_REWARDS: &str = "distribute_rewards";
 
         let state = self.context.state();
         let access_rights = {
@@ -1796,7 +2312,9 @@ where
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
         let base_key = self.protocol_data().proof_of_stake().into();
+        let call_stack = self.context.call_stack_with_callee(base_key);
         let blocktime = self.context.get_blocktime();
+        let block_height = self.context.get_block_height();
         let deploy_hash = self.context.get_deployhash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
@@ -1810,11 +2328,13 @@ where
             state,
             &mut named_keys,
             access_rights,
-            args.to_owned(),
+            RuntimeArgs::from(args.to_vec()),
             authorization_keys,
             account,
             base_key,
+            call_stack,
             blocktime,
+            block_height,
             deploy_hash,
             gas_limit,
             gas_counter,
@@ -1839,8 +2359,7 @@ where
         let ret: CLValue = match method_name.as_str() {
             METHOD_BOND => {
                 if !self.config.enable_bonding() {
-                    let err = Error::Revert(ApiError::Unhandled.into());
-                    return Err(err);
+                    return Err(Self::reverter(pos::Error::BondingDisabled));
                 }
 
                 let validator: PublicKey = runtime.context.get_caller();
@@ -1853,8 +2372,7 @@ where
             }
             METHOD_UNBOND => {
                 if !self.config.enable_bonding() {
-                    let err = Error::Revert(ApiError::Unhandled.into());
-                    return Err(err);
+                    return Err(Self::reverter(pos::Error::BondingDisabled));
                 }
 
                 let validator: PublicKey = runtime.context.get_caller();
@@ -1864,6 +2382,17 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_WITHDRAW_UNBONDED => {
+                if !self.config.enable_bonding() {
+                    return Err(Self::reverter(pos::Error::BondingDisabled));
+                }
+
+                let validator: PublicKey = runtime.context.get_caller();
+                runtime
+                    .withdraw_unbonded(validator)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
             METHOD_GET_PAYMENT_PURSE => {
                 let rights_controlled_purse =
                     runtime.get_payment_purse().map_err(Self::reverter)?;
@@ -1886,6 +2415,27 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(()).map_err(Self::reverter)?
             }
+            METHOD_SET_COMMISSION => {
+                let validator: PublicKey = runtime.context.get_caller();
+                let rate: u32 = Self::get_argument(&args, 1)?;
+                runtime
+                    .set_commission(validator, rate)
+                    .map_err(Self::reverter)?;
+                CLValue::from_t(()).map_err(Self::reverter)?
+            }
+            METHOD_GET_GAS_PRICE => {
+                let gas_price = runtime.get_gas_price().map_err(Self::reverter)?;
+                CLValue::from_t(gas_price).map_err(Self::reverter)?
+            }
+            METHOD_SLASH => {
+                let validators: Vec<PublicKey> = Self::get_argument(&args, 1)?;
+                let slashed_amounts = runtime.slash(validators).map_err(Self::reverter)?;
+                CLValue::from_t(slashed_amounts).map_err(Self::reverter)?
+            }
+            METHOD_DISTRIBUTE_REWARDS => {
+                let splits = runtime.distribute_rewards().map_err(Self::reverter)?;
+                CLValue::from_t(splits).map_err(Self::reverter)?
+            }
             _ => CLValue::from_t(()).map_err(Self::reverter)?,
         };
         let urefs = extract_urefs(&ret)?;
@@ -1895,16 +2445,32 @@ where
     }
 
     pub fn call_host_standard_payment(&mut self) -> Result<(), Error> {
-        let first_arg = match self.context.args().first() {
+        const ARG_GAS: &str = "gas";
+
+        let refund_purse = match self.context.args().get_by_index(1) {
+            Some(cl_value) => Some(cl_value.clone().into_t()?),
+            None => None,
+        };
+        if let Some(gas_arg) = self.context.args().get(ARG_GAS) {
+            let gas = gas_arg.clone().into_t()?;
+            return self.pay_gas(gas, refund_purse).map_err(Self::reverter);
+        }
+
+        let first_arg = match self.context.args().get_by_index(0) {
             Some(cl_value) => cl_value.clone(),
             None => return Err(Error::InvalidContext),
         };
         let amount = first_arg.into_t()?;
-        self.pay(amount).map_err(Self::reverter)
+        self.pay(amount, refund_purse).map_err(Self::reverter)
     }
 
     /// Calls contract living under a `key`, with supplied `args`.
     pub fn call_contract(&mut self, key: Key, args_bytes: Vec<u8>) -> Result<CLValue, Error> {
+        let call_depth = self.call_depth + 1;
+        if call_depth > self.config.max_call_depth() {
+            return Err(Error::CallDepthExceeded);
+        }
+
         let contract = match self.context.read_gs(&key)? {
             Some(StoredValue::Contract(contract)) => contract,
             Some(_) => {
@@ -1926,11 +2492,11 @@ where
             });
         }
 
-        let args: Vec<CLValue> = bytesrepr::deserialize(args_bytes)?;
+        let args: RuntimeArgs = bytesrepr::deserialize(args_bytes)?;
 
         let mut extra_urefs = vec![];
         // A loop is needed to be able to use the '?' operator
-        for arg in &args {
+        for arg in args.values() {
             extra_urefs.extend(
                 extract_urefs(arg)?
                     .into_iter()
@@ -1946,14 +2512,14 @@ where
                 return self.call_host_mint(
                     self.context.protocol_version(),
                     contract.take_named_keys(),
-                    &args,
+                    args.values(),
                     &extra_urefs,
                 );
             } else if self.is_proof_of_stake(key) {
                 return self.call_host_proof_of_stake(
                     self.context.protocol_version(),
                     contract.take_named_keys(),
-                    &args,
+                    args.values(),
                     &extra_urefs,
                 );
             }
@@ -1995,7 +2561,9 @@ where
             self.context.authorization_keys().clone(),
             &self.context.account(),
             key,
+            self.context.call_stack_with_callee(key),
             self.context.get_blocktime(),
+            self.context.get_block_height(),
             self.context.get_deployhash(),
             self.context.gas_limit(),
             self.context.gas_counter(),
@@ -2014,8 +2582,12 @@ where
             module,
             host_buffer,
             context,
+            call_depth,
+            host_function_trace: self.host_function_trace.clone(),
+            events: self.events.clone(),
         };
 
+        let event_count = self.events.event_count();
         let result = instance.invoke_export("call", &[], &mut runtime);
 
         // The `runtime`'s context was initialized with our counter from before the call and any gas
@@ -2032,6 +2604,15 @@ where
             Ok(_) => return Ok(runtime.take_host_buffer().unwrap_or(CLValue::from_t(())?)),
         };
 
+        // The events emitted by a failed call are discarded along with its effects
+        match error
+            .as_host_error()
+            .and_then(|host_error| host_error.downcast_ref::<Error>())
+        {
+            Some(Error::Ret(_)) => {}
+            _ => self.events.truncate(event_count),
+        }
+
         if let Some(host_error) = error.as_host_error() {
             // If the "error" was in fact a trap caused by calling `ret` then
             // this is normal operation and we should return the value captured
@@ -2051,6 +2632,17 @@ where
                     // InterpreterError.
                     return Err(Error::Revert(*status));
                 }
+                Error::RevertWithPayload { status, payload } => {
+                    return Err(Error::RevertWithPayload {
+                        status: *status,
+                        payload: payload.clone(),
+                    });
+                }
+                Error::CallDepthExceeded => {
+                    // Propagate as is, so that the deploy fails with this error rather than with
+                    // one nested interpreter error per level of the call stack.
+                    return Err(Error::CallDepthExceeded);
+                }
                 Error::InvalidContext => {
                     // TODO: https://casperlabs.atlassian.net/browse/EE-771
                     return Err(Error::InvalidContext);
@@ -2059,7 +2651,7 @@ where
             }
         }
 
-        Err(Error::Interpreter(error))
+        Err(error.into())
     }
 
     fn call_contract_host_buffer(
@@ -2091,7 +2683,130 @@ where
         Ok(Ok(()))
     }
 
-    fn load_named_keys(
+    /// Calls the contract under `key` and writes its serialized result directly into the buffer
+    /// of `dest_size` bytes at `dest_ptr`, sparing the caller a separate `read_host_buffer` call.
+    ///
+    /// If the result doesn't fit in that buffer, it's written to the host buffer instead.  Either
+    /// way the size of the result is written to `result_size_ptr`, so the caller can tell which
+    /// happened.
+    fn call_contract_into_buffer(
+        &mut self,
+        key: Key,
+        args_bytes: Vec<u8>,
+        dest_ptr: u32,
+        dest_size: usize,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let result = self.call_contract(key, args_bytes)?;
+        let result_size = result.inner_bytes().len();
+        if result_size > u32::max_value() as usize {
+            return Ok(Err(ApiError::OutOfMemory));
+        }
+
+        if result_size <= dest_size {
+            if let Err(error) = self.memory.set(dest_ptr, result.inner_bytes()) {
+                return Err(Error::Interpreter(error));
+            }
+        } else if let Err(error) = self.write_host_buffer(result) {
+            return Ok(Err(error));
+        }
+
+        let result_size_bytes = (result_size as u32).to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(result_size_ptr, &result_size_bytes) {
+            return Err(Error::Interpreter(error));
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Calls the version of the contract package under `package_key` given by `maybe_version`,
+    /// or its highest enabled version if that's `None`, as per [`Self::call_contract_into_buffer`].
+    ///
+    /// A disabled version is never called, even if a lower version is still enabled.
+    fn call_versioned_contract(
+        &mut self,
+        package_key: Key,
+        maybe_version: Option<ContractVersion>,
+        args_bytes: Vec<u8>,
+        dest_ptr: u32,
+        dest_size: usize,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let package_hash = match package_key {
+            Key::Hash(package_hash) => package_hash,
+            _ => return Ok(Err(ApiError::UnexpectedKeyVariant)),
+        };
+        let package = match self.context.read_contract_package(package_hash)? {
+            Some(package) => package,
+            None => return Ok(Err(ApiError::ContractNotFound)),
+        };
+        let contract_key = match package.resolve(maybe_version) {
+            Ok(contract_key) => contract_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        self.call_contract_into_buffer(
+            contract_key,
+            args_bytes,
+            dest_ptr,
+            dest_size,
+            result_size_ptr,
+        )
+    }
+
+    /// Calls the contract under `key` as per [`Self::call_contract_into_buffer`], passing it a
+    /// temporary purse funded with `limit` motes from `purse` as the argument named
+    /// [`PAYMENT_PURSE_ARG`].
+    ///
+    /// Whatever is left in the temporary purse once the callee returns is moved back to `purse`
+    /// and the temporary purse is deleted, so a copy of its `URef` kept by the callee can't be
+    /// used afterwards.  If the callee reverts, the whole deploy fails and the funding of the
+    /// temporary purse is discarded along with the rest of its effects.
+    #[allow(clippy::too_many_arguments)]
+    fn call_contract_with_payment(
+        &mut self,
+        key: Key,
+        args_bytes: Vec<u8>,
+        purse: URef,
+        limit: U512,
+        dest_ptr: u32,
+        dest_size: usize,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        self.context.validate_uref(&purse)?;
+
+        let mint_contract_key: Key = self.get_mint_contract_uref().into();
+        let payment_purse = self.mint_create(mint_contract_key)?;
+        let funding = self.mint_transfer(mint_contract_key, purse, payment_purse, limit);
+        if let Err(error) = purse_transfer_result(funding) {
+            return Ok(Err(error));
+        }
+
+        let mut args: RuntimeArgs = bytesrepr::deserialize(args_bytes)?;
+        args.insert(PAYMENT_PURSE_ARG, payment_purse);
+        let args_bytes = args.into_bytes()?;
+
+        let result =
+            self.call_contract_into_buffer(key, args_bytes, dest_ptr, dest_size, result_size_ptr)?;
+
+        // The callee may have deleted the purse itself, leaving nothing to sweep.
+        if self.get_balance(payment_purse)?.is_some() {
+            self.mint_delete_purse(mint_contract_key, payment_purse, purse)?;
+        }
+
+        Ok(result)
+    }
+
+    fn load_named_keys(
         &mut self,
         total_keys_ptr: u32,
         result_size_ptr: u32,
@@ -2112,6 +2827,7 @@ where
             return Ok(Ok(()));
         }
 
+        // The named keys are a `BTreeMap`, so they are serialized in order of their names.
         let named_keys =
             CLValue::from_t(self.context.named_keys().clone()).map_err(Error::CLValue)?;
 
@@ -2128,11 +2844,81 @@ where
         Ok(Ok(()))
     }
 
+    /// Writes up to `max` of the current context's named keys, starting with the one at
+    /// `start_index` in order of their names, to the host buffer along with whether any named keys
+    /// follow them.  Copying the chunk is charged per serialized byte at the memcpy rate.
+    fn list_named_keys_chunk(
+        &mut self,
+        start_index: u32,
+        max: u32,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let (chunk, more) = {
+            let named_keys = self.context.named_keys();
+            let chunk: Vec<(String, Key)> = named_keys
+                .iter()
+                .skip(start_index as usize)
+                .take(max as usize)
+                .map(|(name, key)| (name.clone(), *key))
+                .collect();
+            let end_index = (start_index as usize).saturating_add(chunk.len());
+            (chunk, named_keys.len() > end_index)
+        };
+        let chunk = CLValue::from_t((chunk, more)).map_err(Error::CLValue)?;
+
+        let length = chunk.inner_bytes().len() as u32;
+        let memcpy = self.protocol_data().wasm_costs().memcpy;
+        self.gas(Gas::new(U512::from(memcpy) * U512::from(length)))?;
+
+        if let Err(error) = self.write_host_buffer(chunk) {
+            return Ok(Err(error));
+        }
+
+        let length_bytes = length.to_le_bytes();
+        if let Err(error) = self.memory.set(result_size_ptr, &length_bytes) {
+            return Err(Error::Interpreter(error).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Returns `1` if the feature with the index `feature_index` is turned on in the engine's
+    /// configuration, or `0` if it's turned off or unknown to this version of the engine.
+    fn is_feature_enabled(&self, feature_index: u32) -> i32 {
+        let enabled = EngineFeature::try_from(feature_index)
+            .map(|feature| self.config.is_feature_enabled(feature))
+            .unwrap_or(false);
+        i32::from(enabled)
+    }
+
+    /// Writes `RANDOM_BYTES_LENGTH` pseudo-random bytes to `dest_ptr` in Wasm memory.  The cost is
+    /// fixed: hashing `RANDOM_BYTES_HASHED_LENGTH` bytes at the rate set in the wasm costs.
+    fn random_bytes(&mut self, dest_ptr: u32) -> Result<(), Trap> {
+        let hash_per_byte = self.protocol_data().wasm_costs().hash_per_byte;
+        let cost = U512::from(hash_per_byte) * U512::from(RANDOM_BYTES_HASHED_LENGTH);
+        self.gas(Gas::new(cost))?;
+
+        let random_bytes = self
+            .context
+            .address_generator()
+            .borrow_mut()
+            .random_bytes(self.call_depth);
+        self.memory
+            .set(dest_ptr, &random_bytes)
+            .map_err(|e| Error::Interpreter(e).into())
+    }
+
     fn store_function(
         &mut self,
         fn_bytes: Vec<u8>,
         named_keys: BTreeMap<String, Key>,
     ) -> Result<[u8; 32], Error> {
+        self.check_contract_named_keys_limits(&named_keys)?;
         let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
         let contract_addr = self
             .context
@@ -2148,6 +2934,7 @@ where
         fn_bytes: Vec<u8>,
         named_keys: BTreeMap<String, Key>,
     ) -> Result<[u8; 32], Error> {
+        self.check_contract_named_keys_limits(&named_keys)?;
         let contract = Contract::new(fn_bytes, named_keys, self.context.protocol_version());
         let new_hash = self
             .context
@@ -2166,6 +2953,7 @@ where
     /// Generates new unforgable reference and adds it to the context's
     /// access_rights set.
     fn new_uref(&mut self, uref_ptr: u32, value_ptr: u32, value_size: u32) -> Result<(), Trap> {
+        self.charge_storage(value_size)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?; // read initial value from memory
         let uref = self.context.new_uref(StoredValue::CLValue(cl_value))?;
         self.memory
@@ -2181,6 +2969,7 @@ where
         value_ptr: u32,
         value_size: u32,
     ) -> Result<(), Trap> {
+        self.charge_storage(value_size)?;
         let key = self.key_from_mem(key_ptr, key_size)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
         self.context
@@ -2188,6 +2977,15 @@ where
             .map_err(Into::into)
     }
 
+    /// Reverts if a key for the "local cluster" of GlobalState is too long.  This is checked
+    /// before reading the key from memory so that the outcome doesn't depend on its contents.
+    fn check_local_key_size(key_size: u32) -> Result<(), Error> {
+        if key_size as usize > KEY_LOCAL_MAX_KEY_BYTES {
+            return Err(Error::Revert(ApiError::LocalKeyTooLong.into()));
+        }
+        Ok(())
+    }
+
     /// Writes `value` under a key derived from `key` in the "local cluster" of
     /// GlobalState
     fn write_local(
@@ -2197,6 +2995,8 @@ where
         value_ptr: u32,
         value_size: u32,
     ) -> Result<(), Trap> {
+        Self::check_local_key_size(key_size)?;
+        self.charge_storage(value_size)?;
         let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
         self.context
@@ -2204,6 +3004,23 @@ where
             .map_err(Into::into)
     }
 
+    /// Removes the value that `key` points at.  Only the mint may delete values, i.e. the balances
+    /// of the purses it deletes, so no other contract can delete e.g. its own account.
+    fn delete(&mut self, key_ptr: u32, key_size: u32) -> Result<(), Trap> {
+        if !self.is_mint(self.context.base_key()) {
+            return Err(Error::InvalidContext.into());
+        }
+        let key = self.key_from_mem(key_ptr, key_size)?;
+        self.context.delete_gs(key).map_err(Into::into)
+    }
+
+    /// Removes the value under a key derived from `key` in the "local cluster" of GlobalState
+    fn delete_local(&mut self, key_ptr: u32, key_size: u32) -> Result<(), Trap> {
+        Self::check_local_key_size(key_size)?;
+        let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
+        self.context.delete_ls(&key_bytes).map_err(Into::into)
+    }
+
     /// Adds `value` to the cell that `key` points at.
     fn add(
         &mut self,
@@ -2212,6 +3029,7 @@ where
         value_ptr: u32,
         value_size: u32,
     ) -> Result<(), Trap> {
+        self.charge_storage(value_size)?;
         let key = self.key_from_mem(key_ptr, key_size)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
         self.context
@@ -2219,6 +3037,20 @@ where
             .map_err(Into::into)
     }
 
+    /// Appends `item` to the list that `key` points at.
+    fn list_append(
+        &mut self,
+        key_ptr: u32,
+        key_size: u32,
+        item_ptr: u32,
+        item_size: u32,
+    ) -> Result<(), Trap> {
+        self.charge_storage(item_size)?;
+        let key = self.key_from_mem(key_ptr, key_size)?;
+        let item = self.cl_value_from_mem(item_ptr, item_size)?;
+        self.context.list_append_gs(key, item).map_err(Into::into)
+    }
+
     /// Adds `value` to the cell pointed to by a key derived from `key` in the "local cluster" of
     /// GlobalState
     pub fn add_local(
@@ -2228,6 +3060,8 @@ where
         value_ptr: u32,
         value_size: u32,
     ) -> Result<(), Trap> {
+        Self::check_local_key_size(key_size)?;
+        self.charge_storage(value_size)?;
         let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
         let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
         self.context
@@ -2283,6 +3117,10 @@ where
             return Ok(Err(ApiError::HostBufferFull));
         }
 
+        if key_size as usize > KEY_LOCAL_MAX_KEY_BYTES {
+            return Ok(Err(ApiError::LocalKeyTooLong));
+        }
+
         let key_bytes = self.bytes_from_mem(key_ptr, key_size as usize)?;
 
         let cl_value = match self.context.read_ls(&key_bytes)? {
@@ -2308,6 +3146,22 @@ where
         Error::Revert(status).into()
     }
 
+    /// Reverts contract execution with a status and a payload specified, truncating the payload to
+    /// `MAX_REVERT_PAYLOAD_LENGTH` bytes.
+    ///
+    /// No gas is charged for the payload, so it has no bearing on the cost of the deploy or on the
+    /// refund of unspent payment.
+    fn revert_with_payload(&mut self, status: u32, payload_ptr: u32, payload_size: u32) -> Trap {
+        let payload_size = cmp::min(payload_size as usize, MAX_REVERT_PAYLOAD_LENGTH);
+        if payload_size == 0 {
+            return self.revert(status);
+        }
+        match self.bytes_from_mem(payload_ptr, payload_size) {
+            Ok(payload) => Error::RevertWithPayload { status, payload }.into(),
+            Err(error) => error.into(),
+        }
+    }
+
     fn add_associated_key(
         &mut self,
         public_key_ptr: u32,
@@ -2464,6 +3318,109 @@ where
         Ok(result.map_err(system_contract_errors::Error::from)?)
     }
 
+    fn mint_transfer_with_id(
+        &mut self,
+        mint_contract_key: Key,
+        source: URef,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<(), Error> {
+        let args_bytes = {
+            let args = ("transfer_with_id", source, target, amount, id);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<(), mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
+    /// Calls the "delete_purse" method on the mint contract at the given mint contract key
+    fn mint_delete_purse(
+        &mut self,
+        mint_contract_key: Key,
+        purse: URef,
+        refund_to: URef,
+    ) -> Result<(), Error> {
+        let args_bytes = {
+            let args = ("delete_purse", purse, refund_to);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<(), mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
+    /// Calls the "burn" method on the mint contract at the given mint contract key
+    fn mint_burn(
+        &mut self,
+        mint_contract_key: Key,
+        purse: URef,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let args_bytes = {
+            let args = ("burn", purse, amount);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<(), mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
+    /// Calls the "get_or_create_contract_purse" method on the mint contract at the given mint
+    /// contract key
+    fn mint_get_or_create_contract_purse(
+        &mut self,
+        mint_contract_key: Key,
+        contract_hash: [u8; KEY_HASH_LENGTH],
+    ) -> Result<URef, Error> {
+        let args_bytes = {
+            let args = ("get_or_create_contract_purse", contract_hash);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<URef, mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
+    /// Calls the "create_sub_purse" method on the mint contract at the given mint contract key
+    fn mint_create_sub_purse(
+        &mut self,
+        mint_contract_key: Key,
+        parent: URef,
+        cap: U512,
+    ) -> Result<URef, Error> {
+        let args_bytes = {
+            let args = ("create_sub_purse", parent, cap);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<URef, mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
+    /// Calls the "revoke_sub_purse" method on the mint contract at the given mint contract key
+    fn mint_revoke_sub_purse(
+        &mut self,
+        mint_contract_key: Key,
+        parent: URef,
+        sub_purse: URef,
+    ) -> Result<(), Error> {
+        let args_bytes = {
+            let args = ("revoke_sub_purse", parent, sub_purse);
+            ArgsParser::parse(args)?.into_bytes()?
+        };
+
+        let result = self.call_contract(mint_contract_key, args_bytes)?;
+        let result: Result<(), mint::Error> = result.into_t()?;
+        Ok(result.map_err(system_contract_errors::Error::from)?)
+    }
+
     /// Creates a new account at a given public key, transferring a given amount
     /// of motes from the given source purse to the new account's purse.
     fn transfer_to_new_account(
@@ -2471,6 +3428,8 @@ where
         source: URef,
         target: PublicKey,
         amount: U512,
+        initial_weight: Weight,
+        action_thresholds: ActionThresholds,
     ) -> Result<TransferResult, Error> {
         let mint_contract_key = self.get_mint_contract_uref().into();
 
@@ -2511,7 +3470,14 @@ where
                     }
                 })
                 .collect();
-                let account = Account::create(target, named_keys, target_purse);
+                let associated_keys = AssociatedKeys::new(target, initial_weight);
+                let account = Account::new(
+                    target,
+                    named_keys,
+                    target_purse,
+                    associated_keys,
+                    action_thresholds,
+                );
                 self.context.write_account(target_key, account)?;
                 Ok(Ok(TransferredTo::NewAccount))
             }
@@ -2550,6 +3516,40 @@ where
         self.transfer_from_purse_to_account(source, target, amount)
     }
 
+    /// Transfers `amount` of motes from the main purse of the account to `target` account like
+    /// [`Runtime::transfer_to_account`], but if that account does not exist, creates one with
+    /// `target` associated with `initial_weight` and with the given action thresholds.
+    ///
+    /// The thresholds are validated like when set on an existing account before anything is
+    /// transferred, even if the target account exists.
+    fn transfer_to_account_with_config(
+        &mut self,
+        target: PublicKey,
+        amount: U512,
+        initial_weight: Weight,
+        deployment_threshold: Weight,
+        key_management_threshold: Weight,
+    ) -> Result<TransferResult, Error> {
+        let action_thresholds =
+            match ActionThresholds::new(deployment_threshold, key_management_threshold) {
+                Ok(action_thresholds) => action_thresholds,
+                Err(error) => return Ok(Err(error.into())),
+            };
+        // The key management threshold is at least the deployment threshold, and `target` is the
+        // only associated key of a new account.
+        if key_management_threshold > initial_weight {
+            return Ok(Err(SetThresholdFailure::InsufficientTotalWeight.into()));
+        }
+        let source = self.context.get_main_purse()?;
+        self.transfer_from_purse_to_account_with_config(
+            source,
+            target,
+            amount,
+            initial_weight,
+            action_thresholds,
+        )
+    }
+
     /// Transfers `amount` of motes from `source` purse to `target` account.
     /// If that account does not exist, creates one.
     fn transfer_from_purse_to_account(
@@ -2558,13 +3558,45 @@ where
         target: PublicKey,
         amount: U512,
     ) -> Result<TransferResult, Error> {
+        self.transfer_from_purse_to_account_with_config(
+            source,
+            target,
+            amount,
+            Weight::new(1),
+            ActionThresholds::default(),
+        )
+    }
+
+    /// Transfers `amount` of motes from `source` purse to `target` account.  If that account does
+    /// not exist, creates one with `target` associated with `initial_weight` and with
+    /// `action_thresholds`.
+    ///
+    /// Fails with [`Error::ForgedReference`] if `source` isn't known to the current context.
+    fn transfer_from_purse_to_account_with_config(
+        &mut self,
+        source: URef,
+        target: PublicKey,
+        amount: U512,
+        initial_weight: Weight,
+        action_thresholds: ActionThresholds,
+    ) -> Result<TransferResult, Error> {
+        // Checked up front, as a transfer to the source purse's own account returns before the
+        // mint gets to validate it.
+        self.context.validate_uref(&source)?;
+
         let target_key = Key::Account(target);
         // Look up the account at the given public key's address
         match self.context.read_account(&target_key)? {
             None => {
                 // If no account exists, create a new account and transfer the amount to its
                 // purse.
-                self.transfer_to_new_account(source, target, amount)
+                self.transfer_to_new_account(
+                    source,
+                    target,
+                    amount,
+                    initial_weight,
+                    action_thresholds,
+                )
             }
             Some(StoredValue::Account(account)) => {
                 let target = account.main_purse_add_only();
@@ -2581,7 +3613,8 @@ where
         }
     }
 
-    /// Transfers `amount` of motes from `source` purse to `target` purse.
+    /// Transfers `amount` of motes from `source` purse to `target` purse.  Fails with
+    /// [`Error::ForgedReference`] if `source` isn't known to the current context.
     fn transfer_from_purse_to_purse(
         &mut self,
         source_ptr: u32,
@@ -2606,18 +3639,282 @@ where
             bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
         };
 
+        self.context.validate_uref(&source)?;
+
         let mint_contract_key = self.get_mint_contract_uref().into();
 
-        if self
-            .mint_transfer(mint_contract_key, source, target, amount)
-            .is_ok()
-        {
-            Ok(Ok(()))
-        } else {
-            Ok(Err(ApiError::Transfer))
+        let result = self.mint_transfer(mint_contract_key, source, target, amount);
+        Ok(purse_transfer_result(result))
+    }
+
+    /// Transfers `amount` of motes from `source` purse to `target` purse, recording the transfer
+    /// with the given `id` in the execution effect.  Fails with [`Error::ForgedReference`] if
+    /// `source` isn't known to the current context.
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_from_purse_to_purse_with_id(
+        &mut self,
+        source_ptr: u32,
+        source_size: u32,
+        target_ptr: u32,
+        target_size: u32,
+        amount_ptr: u32,
+        amount_size: u32,
+        id_ptr: u32,
+        id_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let source: URef = {
+            let bytes = self.bytes_from_mem(source_ptr, source_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let target: URef = {
+            let bytes = self.bytes_from_mem(target_ptr, target_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let amount: U512 = {
+            let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let id: Option<u64> = {
+            let bytes = self.bytes_from_mem(id_ptr, id_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        self.context.validate_uref(&source)?;
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        let result = self.mint_transfer_with_id(mint_contract_key, source, target, amount, id);
+        Ok(purse_transfer_result(result))
+    }
+
+    /// Deletes the purse, moving its remaining balance to the refund purse.  Fails with
+    /// [`ApiError::NoAccessRights`] unless the caller holds full access rights on the purse.
+    fn delete_purse(
+        &mut self,
+        purse_ptr: u32,
+        purse_size: u32,
+        refund_ptr: u32,
+        refund_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let purse: URef = {
+            let bytes = self.bytes_from_mem(purse_ptr, purse_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let refund_to: URef = {
+            let bytes = self.bytes_from_mem(refund_ptr, refund_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        self.context.validate_uref(&purse)?;
+        if purse.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Ok(Err(ApiError::NoAccessRights));
+        }
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        match self.mint_delete_purse(mint_contract_key, purse, refund_to) {
+            Ok(()) => Ok(Ok(())),
+            Err(Error::SystemContract(system_contract_errors::Error::Mint(mint_error))) => {
+                Ok(Err(mint_error.into()))
+            }
+            Err(error) => Err(error),
         }
     }
 
+    /// Destroys the given amount of motes held in the purse.  Fails with
+    /// [`ApiError::NoAccessRights`] unless the caller holds full access rights on the purse, and
+    /// with the mint's `InsufficientFunds` error if the purse holds less than the amount.
+    fn burn(
+        &mut self,
+        purse_ptr: u32,
+        purse_size: u32,
+        amount_ptr: u32,
+        amount_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let purse: URef = {
+            let bytes = self.bytes_from_mem(purse_ptr, purse_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let amount: U512 = {
+            let bytes = self.bytes_from_mem(amount_ptr, amount_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        self.context.validate_uref(&purse)?;
+        if purse.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Ok(Err(ApiError::NoAccessRights));
+        }
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        match self.mint_burn(mint_contract_key, purse, amount) {
+            Ok(()) => Ok(Ok(())),
+            Err(Error::SystemContract(system_contract_errors::Error::Mint(mint_error))) => {
+                Ok(Err(mint_error.into()))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Writes the purse bound to the contract stored under the given key to `dest_ptr`, creating
+    /// the purse if it doesn't exist yet.  The purse is returned with full access rights only if
+    /// the caller is that contract.
+    fn get_or_create_contract_purse(
+        &mut self,
+        contract_key_ptr: u32,
+        contract_key_size: u32,
+        dest_ptr: u32,
+        dest_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let contract_key: Key = {
+            let bytes = self.bytes_from_mem(contract_key_ptr, contract_key_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        // A contract is identified by its address, as on the call stack, so the one stored under
+        // a `URef` gets the same purse whether it's named by that `URef`, with any access rights,
+        // or by a `Key::Hash` of its address.
+        let contract_hash = match contract_key {
+            Key::Hash(hash) => hash,
+            Key::URef(uref) => uref.addr(),
+            Key::Account(_) | Key::Local { .. } => return Ok(Err(ApiError::InvalidArgument)),
+        };
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        let purse = match self.mint_get_or_create_contract_purse(mint_contract_key, contract_hash) {
+            Ok(purse) => purse,
+            Err(Error::SystemContract(system_contract_errors::Error::Mint(mint_error))) => {
+                return Ok(Err(mint_error.into()))
+            }
+            Err(error) => return Err(error),
+        };
+
+        let purse_bytes = purse.into_bytes().map_err(Error::BytesRepr)?;
+        if purse_bytes.len() != dest_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(dest_ptr, &purse_bytes)
+            .map_err(Error::Interpreter)?;
+        Ok(Ok(()))
+    }
+
+    /// Writes a new sub-purse of the parent purse to `dest_ptr`.  Transfers out of the sub-purse
+    /// pull from the parent until the cap is reached.  Fails with [`ApiError::NoAccessRights`]
+    /// unless the caller holds full access rights on the parent.
+    fn create_sub_purse(
+        &mut self,
+        parent_ptr: u32,
+        parent_size: u32,
+        cap_ptr: u32,
+        cap_size: u32,
+        dest_ptr: u32,
+        dest_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let parent: URef = {
+            let bytes = self.bytes_from_mem(parent_ptr, parent_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let cap: U512 = {
+            let bytes = self.bytes_from_mem(cap_ptr, cap_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        self.context.validate_uref(&parent)?;
+        if parent.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Ok(Err(ApiError::NoAccessRights));
+        }
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        let sub_purse = match self.mint_create_sub_purse(mint_contract_key, parent, cap) {
+            Ok(sub_purse) => sub_purse,
+            Err(Error::SystemContract(system_contract_errors::Error::Mint(mint_error))) => {
+                return Ok(Err(mint_error.into()))
+            }
+            Err(error) => return Err(error),
+        };
+
+        let sub_purse_bytes = sub_purse.into_bytes().map_err(Error::BytesRepr)?;
+        if sub_purse_bytes.len() != dest_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(dest_ptr, &sub_purse_bytes)
+            .map_err(Error::Interpreter)?;
+        Ok(Ok(()))
+    }
+
+    /// Revokes the sub-purse, so that transfers out of it no longer pull from the parent purse.
+    /// Fails with [`ApiError::NoAccessRights`] unless the caller holds full access rights on the
+    /// parent.
+    fn revoke_sub_purse(
+        &mut self,
+        parent_ptr: u32,
+        parent_size: u32,
+        sub_purse_ptr: u32,
+        sub_purse_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let parent: URef = {
+            let bytes = self.bytes_from_mem(parent_ptr, parent_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        let sub_purse: URef = {
+            let bytes = self.bytes_from_mem(sub_purse_ptr, sub_purse_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+
+        self.context.validate_uref(&parent)?;
+        self.context.validate_uref(&sub_purse)?;
+        if parent.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Ok(Err(ApiError::NoAccessRights));
+        }
+
+        let mint_contract_key = self.get_mint_contract_uref().into();
+
+        match self.mint_revoke_sub_purse(mint_contract_key, parent, sub_purse) {
+            Ok(()) => Ok(Ok(())),
+            Err(Error::SystemContract(system_contract_errors::Error::Mint(mint_error))) => {
+                Ok(Err(mint_error.into()))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Emits an event with the topic and data at the given pointers, checking it against the
+    /// limits on events set in the engine config and charging for it byte by byte at the storage
+    /// rate.
+    fn emit_event(
+        &mut self,
+        topic_ptr: u32,
+        topic_size: u32,
+        data_ptr: u32,
+        data_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        self.charge_storage(topic_size.saturating_add(data_size))?;
+        let topic = self.string_from_mem(topic_ptr, topic_size)?;
+        let data: Vec<u8> = {
+            let bytes = self.bytes_from_mem(data_ptr, data_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        let event = ContractEvent {
+            emitter: self.context.base_key(),
+            phase: self.context.phase(),
+            topic,
+            data,
+        };
+        let max_events = self.config.max_events();
+        let max_event_bytes = self.config.max_event_bytes();
+        Ok(self.events.push(event, max_events, max_event_bytes))
+    }
+
     fn get_balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let seed = self.get_mint_contract_uref().addr();
 
@@ -2670,6 +3967,12 @@ where
             }
         };
 
+        // The caller has to hold the purse uref, and reading its balance needs read access
+        self.context.validate_uref(&purse)?;
+        if !purse.is_readable() {
+            return Ok(Err(ApiError::NoAccessRights));
+        }
+
         let balance = match self.get_balance(purse)? {
             Some(balance) => balance,
             None => return Ok(Err(ApiError::InvalidPurse)),
@@ -2694,16 +3997,23 @@ where
     }
 
     /// If key is in named_keys with AccessRights::Write, processes bytes from calling contract
-    /// and writes them at the provided uref, overwriting existing value if any
+    /// and writes them at the provided uref, overwriting existing value if any.
+    ///
+    /// The upgraded contract keeps the named keys of the existing one, with the additional named
+    /// keys added to them, replacing any of the same name.
     fn upgrade_contract_at_uref(
         &mut self,
         name_ptr: u32,
         name_size: u32,
         key_ptr: u32,
         key_size: u32,
+        additional_named_keys: BTreeMap<String, Key>,
     ) -> Result<Result<(), ApiError>, Trap> {
         let key = self.key_from_mem(key_ptr, key_size)?;
-        let named_keys = match self.context.read_gs(&key)? {
+        for named_key in additional_named_keys.values() {
+            self.context.validate_key(named_key)?;
+        }
+        let mut named_keys = match self.context.read_gs(&key)? {
             None => Err(Error::KeyNotFound(key)),
             Some(StoredValue::Contract(contract)) => Ok(contract.named_keys().clone()),
             Some(_) => Err(Error::FunctionNotFound(format!(
@@ -2711,6 +4021,8 @@ where
                 key
             ))),
         }?;
+        named_keys.extend(additional_named_keys);
+        self.check_contract_named_keys_limits(&named_keys)?;
         let bytes = self.get_function_by_name(name_ptr, name_size)?;
         match self
             .context
@@ -2721,6 +4033,122 @@ where
         }
     }
 
+    /// Creates a contract package without any versions, and writes its hash followed by its
+    /// serialized access key to `dest_ptr`.
+    fn create_contract_package(
+        &mut self,
+        dest_ptr: u32,
+        dest_size: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let (package_hash, access_key) = self.context.create_contract_package()?;
+        let output_bytes = (package_hash, access_key)
+            .into_bytes()
+            .map_err(Error::BytesRepr)?;
+        if output_bytes.len() != dest_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(dest_ptr, &output_bytes)
+            .map_err(Error::Interpreter)?;
+        Ok(Ok(()))
+    }
+
+    /// Reads the contract package under the key at `package_ptr` for updating it, which requires
+    /// the access key at `access_key_ptr` to be the package's, and to be writeable.
+    fn contract_package_for_update(
+        &mut self,
+        package_ptr: u32,
+        package_size: u32,
+        access_key_ptr: u32,
+        access_key_size: u32,
+    ) -> Result<Result<([u8; KEY_HASH_LENGTH], ContractPackage), ApiError>, Error> {
+        let package_hash = match self.key_from_mem(package_ptr, package_size)? {
+            Key::Hash(package_hash) => package_hash,
+            _ => return Ok(Err(ApiError::UnexpectedKeyVariant)),
+        };
+        let access_key: URef = {
+            let bytes = self.bytes_from_mem(access_key_ptr, access_key_size as usize)?;
+            bytesrepr::deserialize(bytes).map_err(Error::BytesRepr)?
+        };
+        self.context.validate_uref(&access_key)?;
+        let package = match self.context.read_contract_package(package_hash)? {
+            Some(package) => package,
+            None => return Ok(Err(ApiError::ContractNotFound)),
+        };
+        if !access_key.is_writeable() || access_key.addr() != package.access_key().addr() {
+            return Ok(Err(ApiError::PermissionDenied));
+        }
+        Ok(Ok((package_hash, package)))
+    }
+
+    /// Adds the contract under the key at `contract_key_ptr` as the next version of the contract
+    /// package under the key at `package_ptr`, writing the new version to `version_ptr`.  The
+    /// contract has to be stored at a hash, so that it can't be changed after being added.
+    #[allow(clippy::too_many_arguments)]
+    fn add_contract_version(
+        &mut self,
+        package_ptr: u32,
+        package_size: u32,
+        access_key_ptr: u32,
+        access_key_size: u32,
+        contract_key_ptr: u32,
+        contract_key_size: u32,
+        version_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let (package_hash, mut package) = match self.contract_package_for_update(
+            package_ptr,
+            package_size,
+            access_key_ptr,
+            access_key_size,
+        )? {
+            Ok(found) => found,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        let contract_key = match self.key_from_mem(contract_key_ptr, contract_key_size)? {
+            contract_key @ Key::Hash(_) => contract_key,
+            _ => return Ok(Err(ApiError::UnexpectedKeyVariant)),
+        };
+        match self.context.read_gs(&contract_key)? {
+            Some(StoredValue::Contract(_)) => (),
+            _ => return Ok(Err(ApiError::ContractNotFound)),
+        }
+
+        let version = package.add_version(contract_key);
+        self.context.write_contract_package(package_hash, package)?;
+
+        let version_bytes = version.to_le_bytes(); // Wasm is little-endian
+        self.memory
+            .set(version_ptr, &version_bytes)
+            .map_err(Error::Interpreter)?;
+        Ok(Ok(()))
+    }
+
+    /// Disables `version` of the contract package under the key at `package_ptr`.
+    fn disable_contract_version(
+        &mut self,
+        package_ptr: u32,
+        package_size: u32,
+        access_key_ptr: u32,
+        access_key_size: u32,
+        version: ContractVersion,
+    ) -> Result<Result<(), ApiError>, Error> {
+        let (package_hash, mut package) = match self.contract_package_for_update(
+            package_ptr,
+            package_size,
+            access_key_ptr,
+            access_key_size,
+        )? {
+            Ok(found) => found,
+            Err(error) => return Ok(Err(error)),
+        };
+        if let Err(error) = package.disable_version(version) {
+            return Ok(Err(error));
+        }
+        self.context.write_contract_package(package_hash, package)?;
+        Ok(Ok(()))
+    }
+
     fn get_system_contract(
         &mut self,
         system_contract_index: u32,
@@ -2747,6 +4175,20 @@ where
         self.host_buffer.take()
     }
 
+    /// Returns the host function calls recorded so far, or an empty trace if the engine isn't
+    /// configured to trace them.
+    pub fn take_host_function_trace(&mut self) -> Vec<HostFunctionCall> {
+        self.host_function_trace
+            .as_ref()
+            .map(HostFunctionTrace::take)
+            .unwrap_or_default()
+    }
+
+    /// Returns the events emitted so far, including those of nested calls, leaving none behind.
+    pub fn take_events(&mut self) -> Vec<ContractEvent> {
+        self.events.take()
+    }
+
     /// Checks if a write to host buffer can happen.
     ///
     /// This will check if the host buffer is empty.
@@ -2818,7 +4260,7 @@ mod tests {
 
     use types::{gens::*, CLType, CLValue, Key, URef};
 
-    use super::extract_urefs;
+    use super::{blake2b256, extract_urefs, keccak256};
 
     fn cl_value_with_urefs_arb() -> impl Strategy<Value = (CLValue, Vec<URef>)> {
         // If compiler brings you here it most probably means you've added a variant to `CLType`
@@ -2960,4 +4402,16 @@ mod tests {
             assert_eq!(extracted_urefs, urefs);
         }
     }
+
+    #[test]
+    fn should_hash_known_vectors() {
+        assert_eq!(
+            base16::encode_lower(&blake2b256(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        assert_eq!(
+            base16::encode_lower(&keccak256(b"abc")),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
 }
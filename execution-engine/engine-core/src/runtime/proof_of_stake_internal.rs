@@ -6,17 +6,22 @@ use std::{
 use engine_shared::stored_value::StoredValue;
 use engine_storage::global_state::StateReader;
 use proof_of_stake::{
-    MintProvider, ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider,
+    commission_key_name, Commission, CommissionsProvider, GasPriceProvider, MintProvider,
+    ProofOfStake, Queue, QueueProvider, RuntimeProvider, Stakes, StakesProvider, Withdrawals,
+    WithdrawalsProvider,
 };
 use types::{
     account::PublicKey, bytesrepr::ToBytes, system_contract_errors::pos::Error, ApiError,
     BlockTime, CLValue, Key, Phase, TransferredTo, URef, U512,
 };
 
-use crate::{execution, runtime::Runtime};
+use crate::{
+    engine_state::genesis::{POS_GAS_PRICE, POS_UNBONDING_DELAY},
+    execution,
+    runtime::Runtime,
+};
 
 const BONDING_KEY: u8 = 1;
-const UNBONDING_KEY: u8 = 2;
 
 // TODO: Update MintProvider to better handle errors
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -70,14 +75,6 @@ where
         }
     }
 
-    fn read_unbonding(&mut self) -> Queue {
-        let key = UNBONDING_KEY.to_bytes().expect("should serialize");
-        match self.context.read_ls(&key) {
-            Ok(Some(cl_value)) => cl_value.into_t().expect("should convert"),
-            _ => Queue::default(),
-        }
-    }
-
     fn write_bonding(&mut self, queue: Queue) {
         let key = BONDING_KEY.to_bytes().expect("should serialize");
         let value = CLValue::from_t(queue).expect("should convert");
@@ -85,14 +82,6 @@ where
             .write_ls(&key, value)
             .expect("should write local state")
     }
-
-    fn write_unbonding(&mut self, queue: Queue) {
-        let key = UNBONDING_KEY.to_bytes().expect("should serialize");
-        let value = CLValue::from_t(queue).expect("should convert");
-        self.context
-            .write_ls(&key, value)
-            .expect("should write local state")
-    }
 }
 
 // TODO: Update RuntimeProvider to better handle errors
@@ -123,6 +112,10 @@ where
         self.context.get_blocktime()
     }
 
+    fn get_block_height(&self) -> u64 {
+        self.context.get_block_height()
+    }
+
     fn get_caller(&self) -> PublicKey {
         self.context.get_caller()
     }
@@ -198,6 +191,105 @@ where
     }
 }
 
+impl<'a, R> WithdrawalsProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_withdrawals(&self) -> Result<Withdrawals, Error> {
+        Withdrawals::from_names(self.context.named_keys().keys().map(String::as_str))
+    }
+
+    fn write_withdrawals(&mut self, withdrawals: &Withdrawals) {
+        // Encode the withdrawals as a set of uref names.
+        let mut new_urefs: BTreeSet<String> = withdrawals.strings().collect();
+        // Remove and add urefs to update the contract's known urefs accordingly.
+        let mut removes = Vec::new();
+        for (name, _) in self.context.named_keys().iter() {
+            if name.starts_with("w_") && !new_urefs.remove(name) {
+                removes.push(name.to_owned())
+            }
+        }
+        for name in removes.iter() {
+            self.context.remove_key(name).expect("should remove key")
+        }
+        for name in new_urefs {
+            self.context
+                .put_key(name, Key::Hash([0; 32]))
+                .expect("should put key")
+        }
+    }
+
+    fn read_unbonding_delay(&mut self) -> Result<u64, Error> {
+        let key = match self.context.named_keys_get(POS_UNBONDING_DELAY) {
+            Some(key) => *key,
+            None => return Ok(0),
+        };
+        match self.context.read_gs(&key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map_err(|_| Error::UnbondingDelayDeserializationFailed),
+            _ => Err(Error::UnbondingDelayDeserializationFailed),
+        }
+    }
+}
+
+impl<'a, R> CommissionsProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_commission(&mut self, validator: &PublicKey) -> Result<Option<Commission>, Error> {
+        let key = match self.context.named_keys_get(&commission_key_name(validator)) {
+            Some(key) => *key,
+            None => return Ok(None),
+        };
+        match self.context.read_gs(&key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map(Some)
+                .map_err(|_| Error::CommissionsDeserializationFailed),
+            _ => Err(Error::CommissionsDeserializationFailed),
+        }
+    }
+
+    fn write_commission(&mut self, validator: &PublicKey, commission: Commission) {
+        let name = commission_key_name(validator);
+        let value = StoredValue::CLValue(CLValue::from_t(commission).expect("should convert"));
+        match self.context.named_keys_get(&name).cloned() {
+            Some(key @ Key::URef(_)) => self
+                .context
+                .write_gs(key, value)
+                .expect("should write commission"),
+            _ => {
+                let uref = self.context.new_uref(value).expect("should create uref");
+                self.context
+                    .put_key(name, Key::URef(uref))
+                    .expect("should put key")
+            }
+        }
+    }
+}
+
+impl<'a, R> GasPriceProvider for Runtime<'a, R>
+where
+    R: StateReader<Key, StoredValue>,
+    R::Error: Into<execution::Error>,
+{
+    fn read_gas_price(&mut self) -> Result<u64, Error> {
+        let key = match self.context.named_keys_get(POS_GAS_PRICE) {
+            Some(key) => *key,
+            None => return Err(Error::GasPriceDeserializationFailed),
+        };
+        match self.context.read_gs(&key) {
+            Ok(Some(StoredValue::CLValue(cl_value))) => cl_value
+                .into_t()
+                .map_err(|_| Error::GasPriceDeserializationFailed),
+            _ => Err(Error::GasPriceDeserializationFailed),
+        }
+    }
+}
+
 impl<'a, R> ProofOfStake for Runtime<'a, R>
 where
     R: StateReader<Key, StoredValue>,
@@ -4,15 +4,21 @@ use contract::args_parser::ArgsParser;
 use engine_shared::stored_value::StoredValue;
 use engine_storage::global_state::StateReader;
 use standard_payment::{AccountProvider, MintProvider, ProofOfStakeProvider, StandardPayment};
-use types::{bytesrepr::ToBytes, system_contract_errors, ApiError, Key, URef, U512};
+use types::{bytesrepr::ToBytes, system_contract_errors, AccessRights, ApiError, Key, URef, U512};
 
 use crate::{execution, runtime::Runtime};
 
+const SET_REFUND_PURSE: &str = "set_refund_purse";
+
 lazy_static! {
     static ref SERIALIZED_GET_PAYMENT_PURSE: Vec<u8> = ArgsParser::parse(("get_payment_purse",))
         .expect("args should convert to `Vec<CLValue>`")
         .into_bytes()
         .expect("args should serialize");
+    static ref SERIALIZED_GET_GAS_PRICE: Vec<u8> = ArgsParser::parse(("get_gas_price",))
+        .expect("args should convert to `Vec<CLValue>`")
+        .into_bytes()
+        .expect("args should serialize");
 }
 
 impl<'a, R> AccountProvider for Runtime<'a, R>
@@ -25,6 +31,11 @@ where
             .get_main_purse()
             .map_err(|_| ApiError::InvalidPurse)
     }
+
+    fn can_add_to_purse(&self, purse: URef) -> bool {
+        let purse = URef::new(purse.addr(), AccessRights::ADD);
+        self.context.validate_uref(&purse).is_ok()
+    }
 }
 
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -68,6 +79,37 @@ where
         let payment_purse_ref: URef = cl_value.into_t()?;
         Ok(payment_purse_ref)
     }
+
+    fn set_refund_purse(&mut self, purse: URef) -> Result<(), ApiError> {
+        let pos_contract_key = Key::from(self.get_pos_contract_uref());
+        let args_bytes = ArgsParser::parse((SET_REFUND_PURSE, purse))
+            .expect("args should convert to `Vec<CLValue>`")
+            .into_bytes()
+            .expect("args should serialize");
+
+        self.call_contract(pos_contract_key, args_bytes)
+            .map_err(|_| {
+                ApiError::ProofOfStake(
+                    system_contract_errors::pos::Error::SetRefundPurseCalledOutsidePayment as u8,
+                )
+            })?;
+        Ok(())
+    }
+
+    fn get_gas_price(&mut self) -> Result<u64, ApiError> {
+        let pos_contract_key = Key::from(self.get_pos_contract_uref());
+
+        let cl_value = self
+            .call_contract(pos_contract_key, SERIALIZED_GET_GAS_PRICE.clone())
+            .map_err(|_| {
+                ApiError::ProofOfStake(
+                    system_contract_errors::pos::Error::GasPriceDeserializationFailed as u8,
+                )
+            })?;
+
+        let gas_price: u64 = cl_value.into_t()?;
+        Ok(gas_price)
+    }
 }
 
 impl<'a, R> StandardPayment for Runtime<'a, R>
@@ -13,7 +13,7 @@ use blake2::{
 
 use engine_shared::{
     account::Account, contract::Contract, gas::Gas, newtypes::CorrelationId,
-    stored_value::StoredValue,
+    stored_value::StoredValue, TypeMismatch,
 };
 use engine_storage::{global_state::StateReader, protocol_data::ProtocolData};
 use types::{
@@ -22,8 +22,9 @@ use types::{
         UpdateKeyFailure, Weight,
     },
     bytesrepr::{self, ToBytes},
-    AccessRights, BlockTime, CLType, CLValue, Key, Phase, ProtocolVersion, URef,
-    KEY_LOCAL_SEED_LENGTH,
+    key_versions_name, versioned_key_name, AccessRights, BlockTime, CLType, CLValue,
+    CallStackElement, ContractPackage, Key, Phase, ProtocolVersion, RuntimeArgs, URef,
+    UpdateKeyError, KEY_HASH_LENGTH, KEY_LOCAL_SEED_LENGTH,
 };
 
 use crate::{
@@ -50,6 +51,37 @@ pub(crate) fn attenuate_uref_for_account(account: &Account, uref: URef) -> URef
     }
 }
 
+/// Returns the call stack of code running under `base_key` on behalf of `account`.
+///
+/// This is the account itself, followed by the contract stored under `base_key` if the code is not
+/// running directly under the account.
+pub fn new_call_stack(account: &Account, base_key: Key) -> Vec<CallStackElement> {
+    let public_key = account.public_key();
+    let mut call_stack = vec![CallStackElement::Account(public_key)];
+    if base_key != Key::Account(public_key) {
+        call_stack.push(contract_call_stack_element(base_key));
+    }
+    call_stack
+}
+
+/// Returns the call stack element of the contract stored under `key`.
+fn contract_call_stack_element(key: Key) -> CallStackElement {
+    CallStackElement::Contract {
+        contract_hash: contract_hash(key),
+        seed: key.local_seed(),
+    }
+}
+
+/// Returns the hash identifying the contract stored under `key`, as recorded on the call stack.
+fn contract_hash(key: Key) -> [u8; KEY_HASH_LENGTH] {
+    match key {
+        Key::Account(PublicKey::Ed25519(bytes)) => bytes.value(),
+        Key::Hash(hash) => hash,
+        Key::URef(uref) => uref.addr(),
+        Key::Local { hash, .. } => hash,
+    }
+}
+
 /// Holds information specific to the deployed contract.
 pub struct RuntimeContext<'a, R> {
     state: Rc<RefCell<TrackingCopy<R>>>,
@@ -59,12 +91,15 @@ pub struct RuntimeContext<'a, R> {
     access_rights: HashMap<Address, HashSet<AccessRights>>,
     // Original account for read only tasks taken before execution
     account: &'a Account,
-    args: Vec<CLValue>,
+    args: RuntimeArgs,
     authorization_keys: BTreeSet<PublicKey>,
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
     base_key: Key,
+    // The entities which led to the current entity being run, ending with the current entity
+    call_stack: Vec<CallStackElement>,
     blocktime: BlockTime,
+    block_height: u64,
     deploy_hash: [u8; 32],
     gas_limit: Gas,
     gas_counter: Gas,
@@ -86,11 +121,13 @@ where
         state: Rc<RefCell<TrackingCopy<R>>>,
         named_keys: &'a mut BTreeMap<String, Key>,
         access_rights: HashMap<Address, HashSet<AccessRights>>,
-        args: Vec<CLValue>,
+        args: RuntimeArgs,
         authorization_keys: BTreeSet<PublicKey>,
         account: &'a Account,
         base_key: Key,
+        call_stack: Vec<CallStackElement>,
         blocktime: BlockTime,
+        block_height: u64,
         deploy_hash: [u8; 32],
         gas_limit: Gas,
         gas_counter: Gas,
@@ -109,8 +146,10 @@ where
             account,
             authorization_keys,
             blocktime,
+            block_height,
             deploy_hash,
             base_key,
+            call_stack,
             gas_limit,
             gas_counter,
             fn_store_id,
@@ -211,6 +250,10 @@ where
         self.blocktime
     }
 
+    pub fn get_block_height(&self) -> u64 {
+        self.block_height
+    }
+
     pub fn get_deployhash(&self) -> [u8; 32] {
         self.deploy_hash
     }
@@ -223,7 +266,7 @@ where
         &self.account
     }
 
-    pub fn args(&self) -> &Vec<CLValue> {
+    pub fn args(&self) -> &RuntimeArgs {
         &self.args
     }
 
@@ -256,12 +299,18 @@ where
     }
 
     pub fn seed(&self) -> [u8; KEY_LOCAL_SEED_LENGTH] {
-        match self.base_key {
-            Key::Account(PublicKey::Ed25519(bytes)) => bytes.value(),
-            Key::Hash(bytes) => bytes,
-            Key::URef(uref) => uref.addr(),
-            Key::Local { seed, .. } => seed,
-        }
+        self.base_key.local_seed()
+    }
+
+    pub fn call_stack(&self) -> &[CallStackElement] {
+        &self.call_stack
+    }
+
+    /// Returns the call stack of a contract stored under `key` which is called from this context.
+    pub fn call_stack_with_callee(&self, key: Key) -> Vec<CallStackElement> {
+        let mut call_stack = self.call_stack.clone();
+        call_stack.push(contract_call_stack_element(key));
+        call_stack
     }
 
     pub fn protocol_version(&self) -> ProtocolVersion {
@@ -319,6 +368,150 @@ where
         Ok(())
     }
 
+    /// Puts `new_key` to the map of named keys of current context under `name`, but only if the
+    /// key currently stored under `name` is `expected`, or if there is no such key when `expected`
+    /// is `None`.
+    ///
+    /// The current key is read from global state rather than from the named keys cached in this
+    /// context, so updates made by nested calls to the same account or contract are observed.
+    pub fn update_key(
+        &mut self,
+        name: String,
+        expected: Option<Key>,
+        new_key: Key,
+    ) -> Result<Result<(), UpdateKeyError>, Error> {
+        let current = self.current_named_key(&name)?;
+        match (current, expected) {
+            (None, Some(_)) => return Ok(Err(UpdateKeyError::KeyMissing)),
+            (current, expected) if current != expected => {
+                return Ok(Err(UpdateKeyError::KeyMismatch))
+            }
+            _ => {}
+        }
+        self.put_key(name, new_key)?;
+        Ok(Ok(()))
+    }
+
+    /// Puts `key` to the map of named keys of current context under `name`, archiving the key
+    /// previously put under `name` by this method under [`versioned_key_name`] with its version.
+    /// Returns the version of `key`, which is `1` the first time this is called for `name`.
+    ///
+    /// The version count is stored in a `URef` under [`key_versions_name`], which is created
+    /// along with the first version.  The `URef` is only ever readable by contract code, so that
+    /// only this method can change the count.  A key put under `name` by other means is replaced
+    /// rather than archived.
+    pub fn put_key_versioned(&mut self, name: String, key: Key) -> Result<u32, Error> {
+        let version = match self.key_versions(&name)? {
+            Some((versions_uref, current_version)) => {
+                if let Some(current_key) = self.current_named_key(&name)? {
+                    self.put_key(versioned_key_name(&name, current_version), current_key)?;
+                }
+                let version = current_version + 1;
+                self.write_key_versions(versions_uref, version)?;
+                version
+            }
+            None => {
+                let versions_uref = {
+                    let addr = self.address_generator.borrow_mut().create_address();
+                    URef::new(addr, AccessRights::READ)
+                };
+                self.insert_uref(versions_uref);
+                self.write_key_versions(versions_uref, 1)?;
+                self.put_key(key_versions_name(&name), Key::URef(versions_uref))?;
+                1
+            }
+        };
+        self.put_key(name, key)?;
+        Ok(version)
+    }
+
+    /// Writes the version count of a key put by
+    /// [`put_key_versioned`](RuntimeContext::put_key_versioned), bypassing the access rights check
+    /// as the context only holds the read right to `versions_uref`.
+    fn write_key_versions(&mut self, versions_uref: URef, versions: u32) -> Result<(), Error> {
+        let versions_value = StoredValue::CLValue(CLValue::from_t(versions)?);
+        self.state
+            .borrow_mut()
+            .write(Key::URef(versions_uref), versions_value);
+        Ok(())
+    }
+
+    /// Whether `name` is reserved for the bookkeeping of
+    /// [`put_key_versioned`](RuntimeContext::put_key_versioned), i.e. is the [`key_versions_name`]
+    /// of any key, or the [`versioned_key_name`] of an archived version of a key put that way.
+    /// Contract code can't put, update or remove keys under such names directly.
+    pub fn is_reserved_key_name(&mut self, name: &str) -> Result<bool, Error> {
+        let (base_name, suffix) = match name.rfind('#') {
+            Some(index) => (&name[..index], &name[index + 1..]),
+            None => return Ok(false),
+        };
+        if name == key_versions_name(base_name) {
+            return Ok(true);
+        }
+        match suffix.parse() {
+            Ok(version) if name == versioned_key_name(base_name, version) => {
+                Ok(self.key_versions(base_name)?.is_some())
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Returns the `URef` holding the version count of the key put under `name` by
+    /// [`put_key_versioned`](RuntimeContext::put_key_versioned) along with the count, or `None` if
+    /// no key was put under `name` that way, as currently held in global state.
+    pub fn key_versions(&mut self, name: &str) -> Result<Option<(URef, u32)>, Error> {
+        let versions_uref = match self.current_named_key(&key_versions_name(name))? {
+            Some(Key::URef(uref)) => uref,
+            Some(other) => {
+                return Err(Error::TypeMismatch(TypeMismatch::new(
+                    "URef".to_string(),
+                    other.type_string(),
+                )))
+            }
+            None => return Ok(None),
+        };
+        let cl_value: CLValue = self.read_gs_typed(&Key::URef(versions_uref))?;
+        Ok(Some((versions_uref, cl_value.into_t()?)))
+    }
+
+    /// Removes the key under `name` from the map of named keys of current context.  If it was put
+    /// there by [`put_key_versioned`](RuntimeContext::put_key_versioned), its archived versions
+    /// and its version count are removed too.
+    pub fn remove_key_versioned(&mut self, name: &str) -> Result<(), Error> {
+        if let Some((_, versions)) = self.key_versions(name)? {
+            for version in 1..versions {
+                self.remove_key(&versioned_key_name(name, version))?;
+            }
+            self.remove_key(&key_versions_name(name))?;
+        }
+        self.remove_key(name)
+    }
+
+    /// Returns the key stored under `name` in the named keys of the account or contract stored
+    /// under the base key, as currently held in global state.
+    fn current_named_key(&mut self, name: &str) -> Result<Option<Key>, Error> {
+        self.with_current_named_keys(|named_keys| named_keys.get(name).cloned())
+    }
+
+    fn with_current_named_keys<T>(
+        &mut self,
+        f: impl FnOnce(&BTreeMap<String, Key>) -> T,
+    ) -> Result<T, Error> {
+        let base_key = self.base_key();
+        let maybe_stored_value = self
+            .state
+            .borrow_mut()
+            .read(self.correlation_id, &base_key)
+            .map_err(Into::into)?;
+        let ret = match maybe_stored_value {
+            Some(StoredValue::Account(account)) => f(account.named_keys()),
+            Some(StoredValue::Contract(contract)) => f(contract.named_keys()),
+            // E.g. the virtual system account at genesis isn't stored in global state.
+            _ => f(self.named_keys),
+        };
+        Ok(ret)
+    }
+
     pub fn read_ls(&mut self, key: &[u8]) -> Result<Option<CLValue>, Error> {
         let seed = self.seed();
         self.read_ls_with_seed(seed, key)
@@ -353,6 +546,13 @@ where
         Ok(())
     }
 
+    pub fn delete_ls(&mut self, key_bytes: &[u8]) -> Result<(), Error> {
+        let seed = self.seed();
+        let key = Key::local(seed, key_bytes);
+        self.state.borrow_mut().delete(key);
+        Ok(())
+    }
+
     pub fn read_gs(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         self.validate_readable(key)?;
         self.validate_key(key)?;
@@ -401,6 +601,13 @@ where
         Ok(())
     }
 
+    pub fn delete_gs(&mut self, key: Key) -> Result<(), Error> {
+        self.validate_writeable(&key)?;
+        self.validate_key(&key)?;
+        self.state.borrow_mut().delete(key);
+        Ok(())
+    }
+
     pub fn read_account(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         if let Key::Account(_) = key {
             self.validate_key(key)?;
@@ -437,6 +644,47 @@ where
         Ok(new_hash)
     }
 
+    /// Stores a new contract package without any versions at a newly generated hash.  Returns the
+    /// hash, along with the newly created access key of the package.
+    pub fn create_contract_package(&mut self) -> Result<([u8; KEY_HASH_LENGTH], URef), Error> {
+        let access_key = self.new_uref(StoredValue::CLValue(CLValue::from_t(())?))?;
+        let package_hash = self.new_function_address()?;
+        let package = ContractPackage::new(access_key);
+        self.write_contract_package(package_hash, package)?;
+        Ok((package_hash, access_key))
+    }
+
+    /// Reads the contract package stored under `package_hash`, or returns `None` if there is no
+    /// value under it.
+    pub fn read_contract_package(
+        &mut self,
+        package_hash: [u8; KEY_HASH_LENGTH],
+    ) -> Result<Option<ContractPackage>, Error> {
+        let key = Key::Hash(package_hash);
+        match self.read_gs(&key)? {
+            Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value.into_t()?)),
+            Some(_) => Err(Error::FunctionNotFound(format!(
+                "Value at {:?} is not a contract package",
+                key
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes `package` under `package_hash`.  The caller has to check that the current context
+    /// holds the access key of the package.
+    pub fn write_contract_package(
+        &mut self,
+        package_hash: [u8; KEY_HASH_LENGTH],
+        package: ContractPackage,
+    ) -> Result<(), Error> {
+        let value = StoredValue::CLValue(CLValue::from_t(package)?);
+        self.state
+            .borrow_mut()
+            .write(Key::Hash(package_hash), value);
+        Ok(())
+    }
+
     pub fn insert_key(&mut self, name: String, key: Key) {
         if let Key::URef(uref) = key {
             self.insert_uref(uref);
@@ -633,6 +881,26 @@ where
         self.add_unsafe(key, value)
     }
 
+    /// Appends `item` to the list under `key`.  If the value under `key` isn't a list of values
+    /// of the same type as `item`, `TypeMismatch` is returned.
+    pub fn list_append_gs(&mut self, key: Key, item: CLValue) -> Result<(), Error> {
+        self.validate_addable(&key)?;
+        self.validate_key(&key)?;
+        self.validate_value(&StoredValue::CLValue(item.clone()))?;
+        let result = self
+            .state
+            .borrow_mut()
+            .list_append(self.correlation_id, key, item);
+        match result {
+            Err(storage_error) => Err(storage_error.into()),
+            Ok(AddResult::Success) => Ok(()),
+            Ok(AddResult::KeyNotFound(key)) => Err(Error::KeyNotFound(key)),
+            Ok(AddResult::TypeMismatch(type_mismatch)) => Err(Error::TypeMismatch(type_mismatch)),
+            Ok(AddResult::Serialization(error)) => Err(Error::BytesRepr(error)),
+            Ok(AddResult::Overflow) => Err(Error::Overflow),
+        }
+    }
+
     pub fn add_ls(&mut self, key_bytes: &[u8], cl_value: CLValue) -> Result<(), Error> {
         let seed = self.seed();
         let key = Key::local(seed, key_bytes);
@@ -646,6 +914,7 @@ where
             Ok(AddResult::KeyNotFound(key)) => Err(Error::KeyNotFound(key)),
             Ok(AddResult::TypeMismatch(type_mismatch)) => Err(Error::TypeMismatch(type_mismatch)),
             Ok(AddResult::Serialization(error)) => Err(Error::BytesRepr(error)),
+            Ok(AddResult::Overflow) => Err(Error::Overflow),
         }
     }
 
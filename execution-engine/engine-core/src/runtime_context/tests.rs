@@ -24,10 +24,11 @@ use types::{
     account::{
         ActionType, AddKeyFailure, PublicKey, RemoveKeyFailure, SetThresholdFailure, Weight,
     },
-    AccessRights, BlockTime, CLValue, Key, Phase, ProtocolVersion, URef, KEY_LOCAL_SEED_LENGTH,
+    key_versions_name, versioned_key_name, AccessRights, BlockTime, CLValue, CallStackElement, Key,
+    Phase, ProtocolVersion, RuntimeArgs, URef, UpdateKeyError, KEY_LOCAL_SEED_LENGTH,
 };
 
-use super::{attenuate_uref_for_account, Address, Error, RuntimeContext};
+use super::{attenuate_uref_for_account, new_call_stack, Address, Error, RuntimeContext};
 use crate::{
     engine_state::SYSTEM_ACCOUNT_ADDR, execution::AddressGenerator,
     runtime::extract_access_rights_from_keys, tracking_copy::TrackingCopy,
@@ -117,11 +118,13 @@ fn mock_runtime_context<'a>(
         Rc::new(RefCell::new(tc)),
         named_keys,
         access_rights,
-        Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from([0; 32])]),
         &account,
         base_key,
+        new_call_stack(&account, base_key),
         BlockTime::new(0),
+        0,
         [1u8; 32],
         Gas::default(),
         Gas::default(),
@@ -448,11 +451,13 @@ fn contract_key_addable_valid() {
         Rc::clone(&tc),
         &mut uref_map,
         access_rights,
-        Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         contract_key,
+        new_call_stack(&account, contract_key),
         BlockTime::new(0),
+        0,
         DEPLOY_HASH,
         Gas::default(),
         Gas::default(),
@@ -510,11 +515,13 @@ fn contract_key_addable_invalid() {
         Rc::clone(&tc),
         &mut uref_map,
         access_rights,
-        Vec::new(),
+        RuntimeArgs::new(),
         BTreeSet::from_iter(vec![PublicKey::ed25519_from(base_acc_addr)]),
         &account,
         other_contract_key,
+        new_call_stack(&account, other_contract_key),
         BlockTime::new(0),
+        0,
         DEPLOY_HASH,
         Gas::default(),
         Gas::default(),
@@ -921,6 +928,130 @@ fn remove_uref_works() {
     assert!(!account.named_keys().contains_key(&uref_name));
 }
 
+#[test]
+fn update_key_compares_and_swaps() {
+    let access_rights = HashMap::new();
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let name = "Foo".to_owned();
+        let key_1 = Key::Hash([1; 32]);
+        let key_2 = Key::Hash([2; 32]);
+
+        // A key is expected, but there is none.
+        let result = runtime_context.update_key(name.clone(), Some(key_1), key_2)?;
+        assert_eq!(result, Err(UpdateKeyError::KeyMissing));
+        assert!(!runtime_context.named_keys_contains_key(&name));
+
+        // No key is expected, and there is none.
+        let result = runtime_context.update_key(name.clone(), None, key_1)?;
+        assert_eq!(result, Ok(()));
+        assert_eq!(runtime_context.named_keys_get(&name), Some(&key_1));
+
+        // No key is expected, but there is one.
+        let result = runtime_context.update_key(name.clone(), None, key_2)?;
+        assert_eq!(result, Err(UpdateKeyError::KeyMismatch));
+
+        // A different key is expected.
+        let result = runtime_context.update_key(name.clone(), Some(key_2), key_2)?;
+        assert_eq!(result, Err(UpdateKeyError::KeyMismatch));
+        assert_eq!(runtime_context.named_keys_get(&name), Some(&key_1));
+
+        let result = runtime_context.update_key(name.clone(), Some(key_1), key_2)?;
+        assert_eq!(result, Ok(()));
+        assert_eq!(runtime_context.named_keys_get(&name), Some(&key_2));
+        Ok(())
+    };
+    test(access_rights, query).expect("should update key");
+}
+
+#[test]
+fn put_key_versioned_archives_previous_keys() {
+    let access_rights = HashMap::new();
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let name = "Foo".to_owned();
+        let keys = [Key::Hash([1; 32]), Key::Hash([2; 32]), Key::Hash([3; 32])];
+
+        for (index, key) in keys.iter().enumerate() {
+            let version = runtime_context.put_key_versioned(name.clone(), *key)?;
+            assert_eq!(version, index as u32 + 1);
+        }
+
+        // The latest key stays under `name`, and only the previous ones are archived.
+        assert_eq!(runtime_context.named_keys_get(&name), Some(&keys[2]));
+        for (index, key) in keys[..2].iter().enumerate() {
+            let archived_name = versioned_key_name(&name, index as u32 + 1);
+            assert_eq!(runtime_context.named_keys_get(&archived_name), Some(key));
+        }
+        let archived_name = versioned_key_name(&name, 3);
+        assert!(!runtime_context.named_keys_contains_key(&archived_name));
+        let (_, versions) = runtime_context
+            .key_versions(&name)?
+            .expect("should have versions");
+        assert_eq!(versions, 3);
+
+        runtime_context.remove_key_versioned(&name)?;
+        assert!(!runtime_context.named_keys_contains_key(&name));
+        for version in 1..=3 {
+            let archived_name = versioned_key_name(&name, version);
+            assert!(!runtime_context.named_keys_contains_key(&archived_name));
+        }
+        assert!(!runtime_context.named_keys_contains_key(&key_versions_name(&name)));
+        Ok(())
+    };
+    test(access_rights, query).expect("should put versioned keys");
+}
+
+#[test]
+fn put_key_versioned_reserves_names_of_versions() {
+    let access_rights = HashMap::new();
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let name = "Foo".to_owned();
+        runtime_context.put_key(name.clone(), Key::Hash([1; 32]))?;
+        assert!(runtime_context.is_reserved_key_name(&key_versions_name(&name))?);
+        assert!(!runtime_context.is_reserved_key_name(&versioned_key_name(&name, 1))?);
+
+        runtime_context.put_key_versioned(name.clone(), Key::Hash([2; 32]))?;
+        runtime_context.put_key_versioned(name.clone(), Key::Hash([3; 32]))?;
+        assert!(runtime_context.is_reserved_key_name(&versioned_key_name(&name, 1))?);
+        assert!(runtime_context.is_reserved_key_name(&versioned_key_name(&name, 7))?);
+        assert!(!runtime_context.is_reserved_key_name(&name)?);
+        assert!(!runtime_context.is_reserved_key_name("Foo#+1")?);
+
+        // The version count isn't writeable through its `URef`.
+        let (versions_uref, _) = runtime_context
+            .key_versions(&name)?
+            .expect("should have versions");
+        assert_eq!(versions_uref.access_rights(), AccessRights::READ);
+        let versions_value = StoredValue::CLValue(CLValue::from_t(1u32)?);
+        assert!(runtime_context
+            .write_gs(Key::URef(versions_uref), versions_value)
+            .is_err());
+        Ok(())
+    };
+    test(access_rights, query).expect("should reserve names");
+}
+
+#[test]
+fn remove_key_versioned_keeps_lookalikes_of_unversioned_keys() {
+    let access_rights = HashMap::new();
+    let query = |mut runtime_context: RuntimeContext<InMemoryGlobalStateView>| {
+        let name = "Foo".to_owned();
+        let archived_name = versioned_key_name(&name, 1);
+        runtime_context.put_key(name.clone(), Key::Hash([1; 32]))?;
+        runtime_context.put_key(archived_name.clone(), Key::Hash([2; 32]))?;
+        assert_eq!(runtime_context.key_versions(&name)?, None);
+
+        // `Foo#1` wasn't archived by `put_key_versioned`, so it isn't removed along with `Foo`.
+        runtime_context.remove_key_versioned(&name)?;
+        assert!(!runtime_context.named_keys_contains_key(&name));
+        assert_eq!(
+            runtime_context.named_keys_get(&archived_name),
+            Some(&Key::Hash([2; 32]))
+        );
+        Ok(())
+    };
+    test(access_rights, query).expect("should remove unversioned key");
+}
+
 #[test]
 fn validate_valid_purse_of_an_account() {
     // Tests that URef which matches a purse of a given context gets validated
@@ -974,3 +1105,23 @@ fn attenuate_uref_for_user_account() {
     let access_rights = attenuated_uref.access_rights();
     assert_eq!(access_rights, AccessRights::READ);
 }
+
+#[test]
+fn call_stack_with_callee_appends_callee() {
+    let contract_hash = [3u8; 32];
+    let query_result = test(HashMap::new(), |rc| {
+        let call_stack = rc.call_stack().to_vec();
+        let callee_call_stack = rc.call_stack_with_callee(Key::Hash(contract_hash));
+        Ok((call_stack, callee_call_stack))
+    });
+    let (call_stack, callee_call_stack) = query_result.expect("should get call stacks");
+
+    let account = CallStackElement::Account(PublicKey::ed25519_from([0u8; 32]));
+    assert_eq!(call_stack, vec![account]);
+
+    let callee = CallStackElement::Contract {
+        contract_hash,
+        seed: contract_hash,
+    };
+    assert_eq!(callee_call_stack, vec![account, callee]);
+}
@@ -19,14 +19,21 @@ use engine_shared::{
     transform::{self, Transform},
     TypeMismatch,
 };
-use engine_storage::global_state::StateReader;
-use types::{bytesrepr, CLType, CLValueError, Key};
+use engine_storage::{
+    global_state::{ProvingStateReader, StateReader},
+    trie::TrieMerkleProof,
+};
+use types::{bytesrepr, CLType, CLValue, CLValueError, Key};
 
 use crate::engine_state::{execution_effect::ExecutionEffect, op::Op};
 
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
 
+/// The default limit on the total size of the values a [`TrackingCopy`] keeps in its read cache.
+// TODO: Should `max_cache_size` be fraction of wasm memory limit?
+pub const DEFAULT_MAX_CACHE_SIZE: usize = 1024 * 16;
+
 #[derive(Debug)]
 pub enum TrackingCopyQueryResult {
     Success(StoredValue),
@@ -100,6 +107,7 @@ pub struct TrackingCopyCache<M> {
     current_cache_size: usize,
     reads_cached: LinkedHashMap<Key, StoredValue>,
     muts_cached: HashMap<Key, StoredValue>,
+    deletes_cached: HashSet<Key>,
     meter: M,
 }
 
@@ -114,6 +122,7 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
             current_cache_size: 0,
             reads_cached: LinkedHashMap::new(),
             muts_cached: HashMap::new(),
+            deletes_cached: HashSet::new(),
             meter,
         }
     }
@@ -136,9 +145,25 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
 
     /// Inserts `key` and `value` pair to Write/Add cache.
     pub fn insert_write(&mut self, key: Key, value: StoredValue) {
+        self.deletes_cached.remove(&key);
         self.muts_cached.insert(key, value);
     }
 
+    /// Marks `key` as deleted, so that it is no longer found in the cache or the underlying state.
+    pub fn insert_delete(&mut self, key: Key) {
+        self.muts_cached.remove(&key);
+        if let Some(value) = self.reads_cached.remove(&key) {
+            let element_size = Meter::measure(&self.meter, &key, &value);
+            self.current_cache_size -= element_size;
+        }
+        self.deletes_cached.insert(key);
+    }
+
+    /// Returns `true` if `key` has been deleted.
+    pub fn is_deleted(&self, key: &Key) -> bool {
+        self.deletes_cached.contains(key)
+    }
+
     /// Gets value from `key` in the cache.
     pub fn get(&mut self, key: &Key) -> Option<&StoredValue> {
         if let Some(value) = self.muts_cached.get(&key) {
@@ -154,6 +179,8 @@ pub struct TrackingCopy<R> {
     cache: TrackingCopyCache<HeapSize>,
     ops: AdditiveMap<Key, Op>,
     fns: AdditiveMap<Key, Transform>,
+    cache_hits: u64,
+    cache_misses: u64,
 }
 
 #[derive(Debug)]
@@ -162,6 +189,8 @@ pub enum AddResult {
     KeyNotFound(Key),
     TypeMismatch(TypeMismatch),
     Serialization(bytesrepr::Error),
+    /// An unsigned addition to a `U512` would have overflowed.
+    Overflow,
 }
 
 impl From<CLValueError> for AddResult {
@@ -179,13 +208,19 @@ impl From<CLValueError> for AddResult {
 
 impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
     pub fn new(reader: R) -> TrackingCopy<R> {
+        TrackingCopy::with_max_cache_size(reader, DEFAULT_MAX_CACHE_SIZE)
+    }
+
+    /// Creates a new `TrackingCopy` whose read cache holds values of up to `max_cache_size` in
+    /// total.  Zero disables the read cache, though values written or added are still held.
+    pub fn with_max_cache_size(reader: R, max_cache_size: usize) -> TrackingCopy<R> {
         TrackingCopy {
             reader,
-            cache: TrackingCopyCache::new(1024 * 16, HeapSize), /* TODO: Should `max_cache_size`
-                                                                 * be fraction of wasm memory
-                                                                 * limit? */
+            cache: TrackingCopyCache::new(max_cache_size, HeapSize),
             ops: AdditiveMap::new(),
             fns: AdditiveMap::new(),
+            cache_hits: 0,
+            cache_misses: 0,
         }
     }
 
@@ -214,9 +249,15 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, R::Error> {
+        if self.cache.is_deleted(key) {
+            self.cache_hits += 1;
+            return Ok(None);
+        }
         if let Some(value) = self.cache.get(key) {
+            self.cache_hits += 1;
             return Ok(Some(value.to_owned()));
         }
+        self.cache_misses += 1;
         if let Some(value) = self.reader.read(correlation_id, key)? {
             self.cache.insert_read(*key, value.to_owned());
             Ok(Some(value))
@@ -225,6 +266,12 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         }
     }
 
+    /// Returns the number of reads, adds and appends so far which were served from the cache and
+    /// the number which went to the underlying state, in that order.
+    pub fn cache_stats(&self) -> (u64, u64) {
+        (self.cache_hits, self.cache_misses)
+    }
+
     pub fn read(
         &mut self,
         correlation_id: CorrelationId,
@@ -247,6 +294,14 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         self.fns.insert_add(normalized_key, Transform::Write(value));
     }
 
+    /// Removes the value under `key`, so that it can no longer be read.
+    pub fn delete(&mut self, key: Key) {
+        let normalized_key = key.normalize();
+        self.cache.insert_delete(normalized_key);
+        self.ops.insert_add(normalized_key, Op::Write);
+        self.fns.insert_add(normalized_key, Transform::Delete);
+    }
+
     /// Ok(None) represents missing key to which we want to "add" some value.
     /// Ok(Some(unit)) represents successful operation.
     /// Err(error) is reserved for unexpected errors when accessing global
@@ -310,17 +365,45 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
             _ => return mismatch(),
         };
 
+        Ok(self.apply_additive_transform(normalized_key, current_value, transform))
+    }
+
+    /// Appends `item` to the `CLType::List` under `key`.
+    ///
+    /// Like additions, appends to the same key combine rather than conflict.
+    pub fn list_append(
+        &mut self,
+        correlation_id: CorrelationId,
+        key: Key,
+        item: CLValue,
+    ) -> Result<AddResult, R::Error> {
+        let normalized_key = key.normalize();
+        let current_value = match self.get(correlation_id, &normalized_key)? {
+            None => return Ok(AddResult::KeyNotFound(normalized_key)),
+            Some(current_value) => current_value,
+        };
+        let transform = Transform::ListAppend(vec![item]);
+        Ok(self.apply_additive_transform(normalized_key, current_value, transform))
+    }
+
+    fn apply_additive_transform(
+        &mut self,
+        normalized_key: Key,
+        current_value: StoredValue,
+        transform: Transform,
+    ) -> AddResult {
         match transform.clone().apply(current_value) {
             Ok(new_value) => {
                 self.cache.insert_write(normalized_key, new_value);
                 self.ops.insert_add(normalized_key, Op::Add);
                 self.fns.insert_add(normalized_key, transform);
-                Ok(AddResult::Success)
+                AddResult::Success
             }
             Err(transform::Error::TypeMismatch(type_mismatch)) => {
-                Ok(AddResult::TypeMismatch(type_mismatch))
+                AddResult::TypeMismatch(type_mismatch)
             }
-            Err(transform::Error::Serialization(error)) => Ok(AddResult::Serialization(error)),
+            Err(transform::Error::Serialization(error)) => AddResult::Serialization(error),
+            Err(transform::Error::Overflow) => AddResult::Overflow,
         }
     }
 
@@ -412,6 +495,9 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
         correlation_id: CorrelationId,
         key: &Key,
     ) -> Result<Option<StoredValue>, Self::Error> {
+        if self.cache.is_deleted(key) {
+            return Ok(None);
+        }
         if let Some(value) = self.cache.muts_cached.get(key) {
             return Ok(Some(value.to_owned()));
         }
@@ -422,3 +508,17 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
         }
     }
 }
+
+impl<R: ProvingStateReader<Key, StoredValue>> ProvingStateReader<Key, StoredValue>
+    for &TrackingCopy<R>
+{
+    /// Proofs are made against the underlying state root, so the TrackingCopy's own effects are
+    /// not reflected in them.
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        self.reader.read_with_proof(correlation_id, key)
+    }
+}
@@ -143,6 +143,34 @@ fn tracking_copy_write() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_delete() {
+    let correlation_id = CorrelationId::new();
+    let zero = StoredValue::CLValue(CLValue::from_t(0_i32).unwrap());
+    let db = CountingDb::new_init(zero.clone());
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(zero.clone()));
+
+    // deleting hides the value from later reads
+    tc.delete(k);
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), None);
+    // delete creates a Transform and a write Op
+    assert_eq!(tc.fns.len(), 1);
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Delete));
+    assert_eq!(tc.ops.len(), 1);
+    assert_eq!(tc.ops.get(&k), Some(&Op::Write));
+    // adding to a deleted value fails
+    let add = tc.add(correlation_id, k, zero.clone()).unwrap();
+    assert_matches!(add, AddResult::KeyNotFound(_));
+
+    // writing again makes the value readable
+    tc.write(k, zero.clone());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(zero.clone()));
+    assert_eq!(tc.fns.get(&k), Some(&Transform::Write(zero)));
+}
+
 #[test]
 fn tracking_copy_add_i32() {
     let correlation_id = CorrelationId::new();
@@ -229,6 +257,38 @@ fn tracking_copy_add_named_key() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Add));
 }
 
+#[test]
+fn tracking_copy_list_append() {
+    let correlation_id = CorrelationId::new();
+    let list = CLValue::from_t(vec![1u64]).unwrap();
+    let db = CountingDb::new_init(StoredValue::CLValue(list));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::URef(URef::new([0u8; 32], AccessRights::READ_ADD_WRITE));
+    let item_1 = CLValue::from_t(2u64).unwrap();
+    let item_2 = CLValue::from_t(3u64).unwrap();
+
+    // appending an item of the wrong type should fail
+    let failed_append = tc.list_append(correlation_id, k, CLValue::from_t(3_i32).unwrap());
+    assert_matches!(failed_append, Ok(AddResult::TypeMismatch(_)));
+    assert_eq!(tc.ops.is_empty(), true);
+    assert_eq!(tc.fns.is_empty(), true);
+
+    // successive appends combine into a single transform
+    let append = tc.list_append(correlation_id, k, item_1.clone());
+    assert_matches!(append, Ok(AddResult::Success));
+    let append = tc.list_append(correlation_id, k, item_2.clone());
+    assert_matches!(append, Ok(AddResult::Success));
+    assert_eq!(tc.fns.len(), 1);
+    assert_eq!(
+        tc.fns.get(&k.normalize()),
+        Some(&Transform::ListAppend(vec![item_1, item_2]))
+    );
+    assert_eq!(tc.ops.get(&k.normalize()), Some(&Op::Add));
+
+    let expected_list = StoredValue::CLValue(CLValue::from_t(vec![1u64, 2, 3]).unwrap());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(expected_list));
+}
+
 #[test]
 fn tracking_copy_rw() {
     let correlation_id = CorrelationId::new();
@@ -285,7 +345,143 @@ fn tracking_copy_aw() {
     assert_eq!(tc.ops.get(&k), Some(&Op::Write));
 }
 
+#[test]
+fn tracking_copy_cache_stats() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::new(db);
+    let k = Key::Hash([0u8; 32]);
+    let zero = StoredValue::CLValue(CLValue::from_t(0_i32).unwrap());
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+    let three = StoredValue::CLValue(CLValue::from_t(3_i32).unwrap());
+    let seven = StoredValue::CLValue(CLValue::from_t(7_i32).unwrap());
+
+    // the second read of an unmodified key is served from the cache
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(zero.clone()));
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(zero));
+    assert_eq!(tc.cache_stats(), (1, 1));
+
+    // a read after a write observes the written value
+    tc.write(k, one.clone());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(one));
+
+    // reads after adds observe the accumulated value
+    let _ = tc.add(correlation_id, k, three.clone());
+    let _ = tc.add(correlation_id, k, three);
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(seven));
+
+    assert_eq!(tc.cache_stats(), (5, 1));
+    assert_eq!(counter.get(), 1);
+}
+
+#[test]
+fn tracking_copy_without_read_cache() {
+    let correlation_id = CorrelationId::new();
+    let counter = Rc::new(Cell::new(0));
+    let db = CountingDb::new(Rc::clone(&counter));
+    let mut tc = TrackingCopy::with_max_cache_size(db, 0);
+    let k = Key::Hash([0u8; 32]);
+    let one = StoredValue::CLValue(CLValue::from_t(1_i32).unwrap());
+
+    // every read of an unmodified key goes to the DB
+    let _ = tc.read(correlation_id, &k);
+    let _ = tc.read(correlation_id, &k);
+    assert_eq!(tc.cache_stats(), (0, 2));
+
+    // but written values are still held
+    tc.write(k, one.clone());
+    assert_eq!(tc.read(correlation_id, &k).unwrap(), Some(one));
+    assert_eq!(tc.cache_stats(), (1, 2));
+    assert_eq!(counter.get(), 2);
+}
+
+/// An operation on one of `CACHE_TEST_KEY_COUNT` keys, for comparing tracking copies with and
+/// without a read cache.
+#[derive(Clone, Debug)]
+enum TrackingCopyOp {
+    Read(u8),
+    Write(u8, i32),
+    Add(u8, i32),
+    Delete(u8),
+}
+
+const CACHE_TEST_KEY_COUNT: u8 = 4;
+
+fn cache_test_key(index: u8) -> Key {
+    Key::Hash([index; 32])
+}
+
+fn tracking_copy_op_arb() -> impl Strategy<Value = TrackingCopyOp> {
+    let index = 0..CACHE_TEST_KEY_COUNT;
+    prop_oneof![
+        index.clone().prop_map(TrackingCopyOp::Read),
+        (index.clone(), any::<i32>()).prop_map(|(i, v)| TrackingCopyOp::Write(i, v)),
+        (index.clone(), -1_000..1_000).prop_map(|(i, v)| TrackingCopyOp::Add(i, v)),
+        index.prop_map(TrackingCopyOp::Delete),
+    ]
+}
+
+/// Applies `op` to `tc`, returning what a read or add reported.
+fn apply_tracking_copy_op<R: StateReader<Key, StoredValue>>(
+    tc: &mut TrackingCopy<R>,
+    op: &TrackingCopyOp,
+) -> Option<String>
+where
+    R::Error: std::fmt::Debug,
+{
+    let correlation_id = CorrelationId::new();
+    let to_stored_value = |value: i32| StoredValue::CLValue(CLValue::from_t(value).unwrap());
+    match *op {
+        TrackingCopyOp::Read(index) => {
+            let value = tc.read(correlation_id, &cache_test_key(index)).unwrap();
+            Some(format!("{:?}", value))
+        }
+        TrackingCopyOp::Write(index, value) => {
+            tc.write(cache_test_key(index), to_stored_value(value));
+            None
+        }
+        TrackingCopyOp::Add(index, value) => {
+            let result = tc.add(
+                correlation_id,
+                cache_test_key(index),
+                to_stored_value(value),
+            );
+            Some(format!("{:?}", result.unwrap()))
+        }
+        TrackingCopyOp::Delete(index) => {
+            tc.delete(cache_test_key(index));
+            None
+        }
+    }
+}
+
 proptest! {
+    #[test]
+    fn should_have_same_effect_with_and_without_read_cache(
+        ops in vec(tracking_copy_op_arb(), 0..50)
+    ) {
+        let correlation_id = CorrelationId::new();
+        // The last key is initially missing
+        let pairs: Vec<(Key, StoredValue)> = (0..CACHE_TEST_KEY_COUNT - 1)
+            .map(|index| {
+                let value = StoredValue::CLValue(CLValue::from_t(i32::from(index)).unwrap());
+                (cache_test_key(index), value)
+            })
+            .collect();
+        let (gs, root_hash) = InMemoryGlobalState::from_pairs(correlation_id, &pairs).unwrap();
+        let mut cached_tc = TrackingCopy::new(gs.checkout(root_hash).unwrap().unwrap());
+        let mut uncached_tc =
+            TrackingCopy::with_max_cache_size(gs.checkout(root_hash).unwrap().unwrap(), 0);
+
+        for op in &ops {
+            let cached_result = apply_tracking_copy_op(&mut cached_tc, op);
+            let uncached_result = apply_tracking_copy_op(&mut uncached_tc, op);
+            assert_eq!(cached_result, uncached_result, "results of {:?}", op);
+        }
+        assert_eq!(cached_tc.effect(), uncached_tc.effect());
+    }
+
     #[test]
     fn query_empty_path(k in key_arb(), missing_key in key_arb(), v in stored_value_arb()) {
         let correlation_id = CorrelationId::new();
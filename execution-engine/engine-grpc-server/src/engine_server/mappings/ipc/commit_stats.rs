@@ -0,0 +1,40 @@
+use engine_storage::trie_store::WriteStats;
+
+use crate::engine_server::ipc::CommitStats;
+
+impl From<WriteStats> for CommitStats {
+    fn from(stats: WriteStats) -> Self {
+        let mut pb_stats = CommitStats::new();
+        pb_stats.set_new_nodes(stats.new_nodes as u64);
+        pb_stats.set_bytes_written(stats.bytes_written);
+        pb_stats
+    }
+}
+
+impl From<CommitStats> for WriteStats {
+    fn from(pb_stats: CommitStats) -> Self {
+        WriteStats {
+            new_nodes: pb_stats.get_new_nodes() as usize,
+            bytes_written: pb_stats.get_bytes_written(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::any, proptest};
+
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    proptest! {
+        #[test]
+        fn round_trip(new_nodes in any::<u32>(), bytes_written in any::<u64>()) {
+            let stats = WriteStats {
+                new_nodes: new_nodes as usize,
+                bytes_written,
+            };
+            test_utils::protobuf_round_trip::<WriteStats, CommitStats>(stats);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     convert::{TryFrom, TryInto},
 };
 
-use engine_core::engine_state::deploy_item::DeployItem;
+use engine_core::engine_state::deploy_item::{Approval, DeployItem};
 use types::account::PublicKey;
 
 use crate::engine_server::{ipc, mappings::MappingError};
@@ -42,6 +42,11 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             MappingError::invalid_deploy_hash_length(pb_deploy_item.deploy_hash.len())
         })?;
 
+        let approvals = Into::<Vec<_>>::into(pb_deploy_item.take_approvals())
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
         Ok(DeployItem::new(
             address,
             session,
@@ -49,10 +54,31 @@ impl TryFrom<ipc::DeployItem> for DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            approvals,
         ))
     }
 }
 
+impl From<ipc::DeployApproval> for Approval {
+    fn from(mut pb_approval: ipc::DeployApproval) -> Self {
+        Approval {
+            public_key: pb_approval.take_public_key(),
+            signature: pb_approval.take_signature(),
+            algorithm: pb_approval.take_algorithm(),
+        }
+    }
+}
+
+impl From<Approval> for ipc::DeployApproval {
+    fn from(approval: Approval) -> Self {
+        let mut result = ipc::DeployApproval::new();
+        result.set_public_key(approval.public_key);
+        result.set_signature(approval.signature);
+        result.set_algorithm(approval.algorithm);
+        result
+    }
+}
+
 impl From<DeployItem> for ipc::DeployItem {
     fn from(deploy_item: DeployItem) -> Self {
         let mut result = ipc::DeployItem::new();
@@ -68,6 +94,7 @@ impl From<DeployItem> for ipc::DeployItem {
                 .collect(),
         );
         result.set_deploy_hash(deploy_item.deploy_hash.to_vec());
+        result.set_approvals(deploy_item.approvals.into_iter().map(Into::into).collect());
         result
     }
 }
@@ -12,11 +12,14 @@ use crate::engine_server::ipc::{DeployError_OutOfGasError, DeployResult};
 impl From<ExecutionResult> for DeployResult {
     fn from(execution_result: ExecutionResult) -> DeployResult {
         match execution_result {
-            ExecutionResult::Success { effect, cost } => detail::execution_success(effect, cost),
+            ExecutionResult::Success { effect, cost, .. } => {
+                detail::execution_success(effect, cost)
+            }
             ExecutionResult::Failure {
                 error,
                 effect,
                 cost,
+                ..
             } => (error, effect, cost).into(),
         }
     }
@@ -31,11 +34,13 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             error @ EngineStateError::InvalidHashLength { .. }
             | error @ EngineStateError::InvalidPublicKeyLength { .. }
             | error @ EngineStateError::InvalidProtocolVersion { .. }
+            | error @ EngineStateError::ProtocolDataRead(..)
             | error @ EngineStateError::InvalidUpgradeConfig
             | error @ EngineStateError::WasmPreprocessing(_)
             | error @ EngineStateError::WasmSerialization(_)
-            | error @ EngineStateError::Exec(ExecutionError::DeploymentAuthorizationFailure)
-            | error @ EngineStateError::Authorization => {
+            | error @ EngineStateError::Authorization
+            | error @ EngineStateError::InvalidAuthorization(_)
+            | error @ EngineStateError::SignatureError(_) => {
                 detail::precondition_error(error.to_string())
             }
             EngineStateError::Storage(storage_error) => {
@@ -48,7 +53,9 @@ impl From<(EngineStateError, ExecutionEffect, Gas)> for DeployResult {
             | error @ EngineStateError::Deploy
             | error @ EngineStateError::Finalization
             | error @ EngineStateError::Serialization(_)
-            | error @ EngineStateError::Mint(_) => detail::execution_error(error, effect, cost),
+            | error @ EngineStateError::Mint(_)
+            | error @ EngineStateError::GenesisContract { .. }
+            | error @ EngineStateError::Genesis(_) => detail::execution_error(error, effect, cost),
             EngineStateError::Exec(exec_error) => (exec_error, effect, cost).into(),
         }
     }
@@ -64,6 +71,10 @@ impl From<(ExecutionError, ExecutionEffect, Gas)> for DeployResult {
             ExecutionError::Revert(status) => {
                 detail::execution_error(format!("Exit code: {}", status), effect, cost)
             }
+            ExecutionError::RevertWithPayload { status, payload } => {
+                let msg = detail::revert_with_payload_message(status, &payload);
+                detail::execution_error(msg, effect, cost)
+            }
             ExecutionError::Interpreter(error) => {
                 // If the error happens during contract execution it's mapped to HostError and
                 // wrapped in Interpreter error, so we may end up with
@@ -76,16 +87,24 @@ impl From<(ExecutionError, ExecutionEffect, Gas)> for DeployResult {
                     .and_then(|host_error| host_error.downcast_ref::<ExecutionError>())
                 {
                     Some(&ExecutionError::Revert(status)) => format!("Exit code: {}", status),
+                    Some(ExecutionError::RevertWithPayload { status, payload }) => {
+                        detail::revert_with_payload_message(*status, payload)
+                    }
                     Some(&ExecutionError::KeyNotFound(key)) => format!("Key {:?} not found.", key),
                     Some(&ExecutionError::InvalidContext) => {
                         // TODO: https://casperlabs.atlassian.net/browse/EE-771
                         "Invalid execution context.".to_string()
                     }
+                    Some(error @ ExecutionError::BytesRepr(_))
+                    | Some(error @ ExecutionError::CLValue(_)) => error.to_string(),
                     Some(other) => format!("{:?}", other),
                     None => format!("{:?}", error),
                 };
                 detail::execution_error(msg, effect, cost)
             }
+            error @ ExecutionError::BytesRepr(_)
+            | error @ ExecutionError::CLValue(_)
+            | error @ ExecutionError::Trap(_) => detail::execution_error(error, effect, cost),
             // TODO(mateusz.gorski): Be more specific about execution errors
             other => detail::execution_error(format!("{:?}", other), effect, cost),
         }
@@ -119,6 +138,15 @@ mod detail {
         deploy_result(DeployErrorType::Exec(msg.to_string()), effect, cost)
     }
 
+    /// Formats the message of a revert with a payload, which is hex-encoded.
+    pub(super) fn revert_with_payload_message(status: u32, payload: &[u8]) -> String {
+        format!(
+            "Exit code: {}, payload: {}",
+            status,
+            base16::encode_lower(payload)
+        )
+    }
+
     /// Constructs an instance of `DeployResult` with an error set to
     /// `DeployError_OutOfGasError`.
     pub(super) fn out_of_gas_error(effect: ExecutionEffect, cost: Gas) -> DeployResult {
@@ -163,6 +191,7 @@ mod detail {
 mod tests {
     use std::convert::TryInto;
 
+    use engine_core::execution::TrapKind;
     use engine_shared::{additive_map::AdditiveMap, transform::Transform};
     use types::{bytesrepr::Error as BytesReprError, AccessRights, Key, URef, U512};
 
@@ -183,6 +212,8 @@ mod tests {
         let execution_result = ExecutionResult::Success {
             effect: execution_effect,
             cost,
+            ret: None,
+            phases: None,
         };
         let mut ipc_deploy_result: DeployResult = execution_result.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -208,6 +239,7 @@ mod tests {
             error: error.into(),
             effect: Default::default(),
             cost: expected_cost,
+            phases: None,
         };
         let mut ipc_deploy_result: DeployResult = execution_failure.into();
         assert!(ipc_deploy_result.has_execution_result());
@@ -251,6 +283,7 @@ mod tests {
             error: EngineStateError::Exec(revert_error),
             effect: Default::default(),
             cost: Gas::new(amount),
+            phases: None,
         };
         let mut ipc_result: DeployResult = exec_result.into();
         assert!(
@@ -271,4 +304,47 @@ mod tests {
             format!("Exit code: {}", REVERT)
         );
     }
+
+    #[test]
+    fn revert_with_payload_error_maps_to_execution_error() {
+        const REVERT: u32 = 10;
+        let revert_error = ExecutionError::RevertWithPayload {
+            status: REVERT,
+            payload: vec![0xca, 0xfe],
+        };
+        let exec_result = ExecutionResult::Failure {
+            error: EngineStateError::Exec(revert_error),
+            effect: Default::default(),
+            cost: Gas::new(U512::from(15)),
+            phases: None,
+        };
+        let mut ipc_result: DeployResult = exec_result.into();
+        assert_eq!(
+            ipc_result
+                .mut_execution_result()
+                .get_error()
+                .get_exec_error()
+                .get_message(),
+            format!("Exit code: {}, payload: cafe", REVERT)
+        );
+    }
+
+    #[test]
+    fn trap_error_maps_to_execution_error() {
+        let exec_result = ExecutionResult::Failure {
+            error: EngineStateError::Exec(ExecutionError::Trap(TrapKind::DivisionByZero)),
+            effect: Default::default(),
+            cost: Gas::new(U512::from(15)),
+            phases: None,
+        };
+        let mut ipc_result: DeployResult = exec_result.into();
+        assert_eq!(
+            ipc_result
+                .mut_execution_result()
+                .get_error()
+                .get_exec_error()
+                .get_message(),
+            "Trap: integer division by zero"
+        );
+    }
 }
@@ -30,6 +30,8 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
 
         let block_time = request.get_block_time();
 
+        let block_height = request.get_block_height();
+
         let deploys = Into::<Vec<_>>::into(request.take_deploys())
             .into_iter()
             .map(|deploy_item| {
@@ -44,6 +46,7 @@ impl TryFrom<ipc::ExecuteRequest> for ExecuteRequest {
         Ok(ExecuteRequest::new(
             parent_state_hash,
             block_time,
+            block_height,
             deploys,
             protocol_version,
         ))
@@ -55,6 +58,7 @@ impl From<ExecuteRequest> for ipc::ExecuteRequest {
         let mut result = ipc::ExecuteRequest::new();
         result.set_parent_state_hash(req.parent_state_hash.to_vec());
         result.set_block_time(req.block_time);
+        result.set_block_height(req.block_height);
         result.set_deploys(
             req.deploys
                 .into_iter()
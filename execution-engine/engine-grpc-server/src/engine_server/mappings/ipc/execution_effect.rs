@@ -1,5 +1,7 @@
-use engine_core::engine_state::{execution_effect::ExecutionEffect, op::Op};
-use types::Key;
+use engine_core::engine_state::{
+    contract_event::ContractEvent, execution_effect::ExecutionEffect, op::Op,
+};
+use types::{Key, Phase};
 
 use crate::engine_server::{
     ipc::{self, AddOp, NoOp, OpEntry, ReadOp, WriteOp},
@@ -23,20 +25,109 @@ impl From<(Key, Op)> for OpEntry {
     }
 }
 
+impl From<ContractEvent> for ipc::ContractEvent {
+    fn from(event: ContractEvent) -> ipc::ContractEvent {
+        let mut pb_event = ipc::ContractEvent::new();
+        pb_event.set_emitter(event.emitter.into());
+        pb_event.set_topic(event.topic);
+        pb_event.set_data(event.data);
+        let pb_phase = match event.phase {
+            Phase::System => ipc::ContractEvent_Phase::SYSTEM,
+            Phase::Payment => ipc::ContractEvent_Phase::PAYMENT,
+            Phase::Session => ipc::ContractEvent_Phase::SESSION,
+            Phase::FinalizePayment => ipc::ContractEvent_Phase::FINALIZE_PAYMENT,
+        };
+        pb_event.set_phase(pb_phase);
+        pb_event
+    }
+}
+
 impl From<ExecutionEffect> for ipc::ExecutionEffect {
     fn from(execution_effect: ExecutionEffect) -> ipc::ExecutionEffect {
         let mut pb_execution_effect = ipc::ExecutionEffect::new();
 
-        let pb_op_map: Vec<OpEntry> = execution_effect.ops.into_iter().map(Into::into).collect();
+        // Entries are sorted by key so that the serialized effect is the same for equal effects.
+        let pb_op_map: Vec<OpEntry> = execution_effect
+            .ops
+            .into_sorted_vec()
+            .into_iter()
+            .map(Into::into)
+            .collect();
         pb_execution_effect.set_op_map(pb_op_map.into());
 
         let pb_transform_map: Vec<ProbufTransformEntry> = execution_effect
             .transforms
+            .into_sorted_vec()
             .into_iter()
             .map(Into::into)
             .collect();
         pb_execution_effect.set_transform_map(pb_transform_map.into());
 
+        let pb_events: Vec<ipc::ContractEvent> = execution_effect
+            .events
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        pb_execution_effect.set_events(pb_events.into());
+
         pb_execution_effect
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use protobuf::Message;
+
+    use engine_shared::{additive_map::AdditiveMap, newtypes::Blake2bHash, transform::Transform};
+    use types::{AccessRights, URef};
+
+    use super::*;
+
+    /// Builds an effect from `entries`, inserting them in the given order, and hashes its
+    /// serialized protobuf message.
+    fn serialized_effect_hash<'a>(entries: impl Iterator<Item = &'a (Key, i32)>) -> Blake2bHash {
+        let mut ops = AdditiveMap::new();
+        let mut transforms = AdditiveMap::new();
+        for (key, value) in entries {
+            ops.insert(*key, Op::Add);
+            transforms.insert(*key, Transform::AddInt32(*value));
+        }
+        let pb_execution_effect: ipc::ExecutionEffect =
+            ExecutionEffect::new(ops, transforms).into();
+        let bytes = pb_execution_effect
+            .write_to_bytes()
+            .expect("should serialize");
+        Blake2bHash::new(&bytes)
+    }
+
+    #[test]
+    fn should_serialize_equal_effects_identically() {
+        let entries: Vec<(Key, i32)> = (0..32u8)
+            .map(|index| {
+                let key = Key::URef(URef::new([index; 32], AccessRights::ADD));
+                (key, i32::from(index))
+            })
+            .collect();
+
+        let expected_hash = serialized_effect_hash(entries.iter());
+        assert_eq!(serialized_effect_hash(entries.iter()), expected_hash);
+        assert_eq!(serialized_effect_hash(entries.iter().rev()), expected_hash);
+    }
+
+    #[test]
+    fn should_map_event_phase() {
+        let event = ContractEvent {
+            emitter: Key::Hash([1; 32]),
+            phase: Phase::FinalizePayment,
+            topic: "topic".to_string(),
+            data: vec![1, 2, 3],
+        };
+        let pb_event: ipc::ContractEvent = event.into();
+        assert_eq!(
+            pb_event.get_phase(),
+            ipc::ContractEvent_Phase::FINALIZE_PAYMENT
+        );
+        assert_eq!(pb_event.get_topic(), "topic");
+        assert_eq!(pb_event.get_data(), &[1, 2, 3]);
+    }
+}
@@ -1,9 +1,12 @@
 use std::convert::{TryFrom, TryInto};
 
-use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, GenesisConfig, GenesisContract},
+    CONV_RATE,
+};
 
 use crate::engine_server::{
-    ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig},
+    ipc::{ChainSpec_GenesisAccount, ChainSpec_GenesisConfig, ChainSpec_GenesisContract},
     mappings::MappingError,
 };
 
@@ -32,6 +35,17 @@ impl From<GenesisConfig> for ChainSpec_GenesisConfig {
         pb_genesis_config
             .mut_costs()
             .set_wasm(genesis_config.wasm_costs().into());
+        pb_genesis_config.set_unbonding_delay(genesis_config.unbonding_delay());
+        pb_genesis_config.set_gas_price(genesis_config.gas_price());
+        {
+            let genesis_contracts = genesis_config
+                .genesis_contracts()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect::<Vec<ChainSpec_GenesisContract>>();
+            pb_genesis_config.set_genesis_contracts(genesis_contracts.into());
+        }
         pb_genesis_config
     }
 }
@@ -52,6 +66,17 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
         let mint_initializer_bytes = pb_genesis_config.mint_installer;
         let proof_of_stake_initializer_bytes = pb_genesis_config.pos_installer;
         let standard_payment_installer_bytes = pb_genesis_config.standard_payment_installer;
+        let unbonding_delay = pb_genesis_config.get_unbonding_delay();
+        // A gas price of zero means the field wasn't set, so the default rate applies.
+        let gas_price = match pb_genesis_config.get_gas_price() {
+            0 => CONV_RATE,
+            gas_price => gas_price,
+        };
+        let genesis_contracts = pb_genesis_config
+            .take_genesis_contracts()
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<GenesisContract>>();
         Ok(GenesisConfig::new(
             name,
             timestamp,
@@ -61,6 +86,9 @@ impl TryFrom<ChainSpec_GenesisConfig> for GenesisConfig {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            unbonding_delay,
+            gas_price,
+            genesis_contracts,
         ))
     }
 }
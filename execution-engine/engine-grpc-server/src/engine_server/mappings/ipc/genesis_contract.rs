@@ -0,0 +1,38 @@
+use engine_core::engine_state::genesis::GenesisContract;
+
+use crate::engine_server::ipc::ChainSpec_GenesisContract;
+
+impl From<GenesisContract> for ChainSpec_GenesisContract {
+    fn from(genesis_contract: GenesisContract) -> Self {
+        let mut pb_genesis_contract = ChainSpec_GenesisContract::new();
+
+        pb_genesis_contract.set_name(genesis_contract.name().to_string());
+        pb_genesis_contract.set_installer(genesis_contract.installer_bytes().to_vec());
+        pb_genesis_contract.set_args(genesis_contract.args().to_vec());
+
+        pb_genesis_contract
+    }
+}
+
+impl From<ChainSpec_GenesisContract> for GenesisContract {
+    fn from(mut pb_genesis_contract: ChainSpec_GenesisContract) -> Self {
+        let name = pb_genesis_contract.take_name();
+        let installer_bytes = pb_genesis_contract.take_installer();
+        let args = pb_genesis_contract.take_args();
+        GenesisContract::new(name, installer_bytes, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine_server::mappings::test_utils;
+
+    #[test]
+    fn round_trip() {
+        let genesis_contract = rand::random();
+        test_utils::protobuf_round_trip::<GenesisContract, ChainSpec_GenesisContract>(
+            genesis_contract,
+        );
+    }
+}
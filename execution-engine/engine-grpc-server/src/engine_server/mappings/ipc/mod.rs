@@ -2,6 +2,7 @@
 //! defined in protobuf/io/casperlabs/ipc/ipc.proto
 
 mod bond;
+mod commit_stats;
 mod deploy_item;
 mod deploy_result;
 mod executable_deploy_item;
@@ -9,6 +10,7 @@ mod execute_request;
 mod execution_effect;
 mod genesis_account;
 mod genesis_config;
+mod genesis_contract;
 mod query_request;
 mod upgrade_request;
 mod wasm_costs;
@@ -15,6 +15,12 @@ impl From<WasmCosts> for ChainSpec_CostTable_WasmCosts {
             max_stack_height: wasm_costs.max_stack_height,
             opcodes_mul: wasm_costs.opcodes_mul,
             opcodes_div: wasm_costs.opcodes_div,
+            storage_per_byte: wasm_costs.storage_per_byte,
+            native_transfer: wasm_costs.native_transfer,
+            hash_per_byte: wasm_costs.hash_per_byte,
+            max_initial_memory_pages: wasm_costs.max_initial_memory_pages,
+            max_memory_pages: wasm_costs.max_memory_pages,
+            preprocess_per_byte: wasm_costs.preprocess_per_byte,
             ..Default::default()
         }
     }
@@ -33,6 +39,12 @@ impl From<ChainSpec_CostTable_WasmCosts> for WasmCosts {
             max_stack_height: pb_wasm_costs.max_stack_height,
             opcodes_mul: pb_wasm_costs.opcodes_mul,
             opcodes_div: pb_wasm_costs.opcodes_div,
+            storage_per_byte: pb_wasm_costs.storage_per_byte,
+            native_transfer: pb_wasm_costs.native_transfer,
+            hash_per_byte: pb_wasm_costs.hash_per_byte,
+            max_initial_memory_pages: pb_wasm_costs.max_initial_memory_pages,
+            max_memory_pages: pb_wasm_costs.max_memory_pages,
+            preprocess_per_byte: pb_wasm_costs.preprocess_per_byte,
         }
     }
 }
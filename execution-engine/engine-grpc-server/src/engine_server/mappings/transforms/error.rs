@@ -4,7 +4,9 @@ use engine_shared::{transform, TypeMismatch};
 
 use crate::engine_server::{
     mappings::ParsingError,
-    transforms::{self, TransformFailure, TransformFailure_oneof_failure_instance},
+    transforms::{
+        self, TransformFailure, TransformFailure_oneof_failure_instance, TransformOverflow,
+    },
 };
 
 impl From<TypeMismatch> for transforms::TypeMismatch {
@@ -23,6 +25,9 @@ impl From<transform::Error> for TransformFailure {
             transform::Error::TypeMismatch(type_mismatch) => {
                 pb_transform_failure.set_type_mismatch(type_mismatch.into())
             }
+            transform::Error::Overflow => {
+                pb_transform_failure.set_overflow(TransformOverflow::new())
+            }
             transform::Error::Serialization(_error) => panic!("don't break the API"),
         }
         pb_transform_failure
@@ -45,6 +50,7 @@ impl TryFrom<TransformFailure> for transform::Error {
                 let type_mismatch = TypeMismatch { expected, found };
                 Ok(transform::Error::TypeMismatch(type_mismatch))
             }
+            TransformFailure_oneof_failure_instance::overflow(_) => Ok(transform::Error::Overflow),
         }
     }
 }
@@ -61,5 +67,8 @@ mod tests {
             "found".to_string(),
         ));
         test_utils::protobuf_round_trip::<transform::Error, TransformFailure>(error);
+        test_utils::protobuf_round_trip::<transform::Error, TransformFailure>(
+            transform::Error::Overflow,
+        );
     }
 }
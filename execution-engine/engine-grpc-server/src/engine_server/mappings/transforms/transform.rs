@@ -8,7 +8,7 @@ use types::{CLType, CLValue, U128, U256, U512};
 
 use crate::engine_server::{
     mappings::{state::NamedKeyMap, ParsingError},
-    state::NamedKey,
+    state::{self, NamedKey},
     transforms::{self, Transform_oneof_transform_instance},
 };
 
@@ -32,6 +32,13 @@ impl From<Transform> for transforms::Transform {
                 let pb_named_keys: Vec<NamedKey> = NamedKeyMap::new(keys_map).into();
                 pb_transform.mut_add_keys().set_value(pb_named_keys.into());
             }
+            Transform::ListAppend(items) => {
+                let pb_items: Vec<state::CLValue> = items.into_iter().map(Into::into).collect();
+                pb_transform.mut_list_append().set_value(pb_items.into());
+            }
+            Transform::Delete => {
+                pb_transform.set_delete(Default::default());
+            }
             Transform::Failure(transform_error) => pb_transform.set_failure(transform_error.into()),
             Transform::AddUInt128(uint128) => {
                 pb_transform.mut_add_big_int().set_value(uint128.into());
@@ -95,6 +102,16 @@ impl TryFrom<transforms::Transform> for Transform {
                 let value = StoredValue::try_from(pb_write.take_value())?;
                 Transform::Write(value)
             }
+            Transform_oneof_transform_instance::list_append(pb_list_append) => {
+                let items = pb_list_append
+                    .value
+                    .into_vec()
+                    .into_iter()
+                    .map(CLValue::try_from)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Transform::ListAppend(items)
+            }
+            Transform_oneof_transform_instance::delete(_) => Transform::Delete,
             Transform_oneof_transform_instance::failure(pb_failure) => {
                 let error = TransformError::try_from(pb_failure)?;
                 Transform::Failure(error)
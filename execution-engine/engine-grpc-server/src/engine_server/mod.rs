@@ -260,10 +260,13 @@ where
                 Ok(CommitResult::Success {
                     state_root,
                     bonded_validators,
+                    stats,
                 }) => {
                     let properties = {
                         let mut tmp = BTreeMap::new();
                         tmp.insert("post-state-hash", format!("{:?}", state_root));
+                        tmp.insert("new-nodes", stats.new_nodes.to_string());
+                        tmp.insert("bytes-written", stats.bytes_written.to_string());
                         tmp.insert("success", true.to_string());
                         tmp
                     };
@@ -277,6 +280,7 @@ where
                     let commit_result = ret.mut_success();
                     commit_result.set_poststate_hash(state_root.to_vec());
                     commit_result.set_bonded_validators(bonds);
+                    commit_result.set_stats(stats.into());
                 }
                 Ok(CommitResult::RootNotFound) => {
                     warn!("RootNotFound");
@@ -286,8 +290,8 @@ where
                     warn!("{:?} not found", key);
                     ret.set_key_not_found(key.into());
                 }
-                Ok(CommitResult::TypeMismatch(type_mismatch)) => {
-                    warn!("{:?}", type_mismatch);
+                Ok(CommitResult::TypeMismatch { key, type_mismatch }) => {
+                    warn!("{:?} at {:?}", type_mismatch, key);
                     ret.set_type_mismatch(type_mismatch.into());
                 }
                 Ok(CommitResult::Serialization(error)) => {
@@ -295,6 +299,11 @@ where
                     ret.mut_failed_transform()
                         .set_message(format!("{:?}", error));
                 }
+                Ok(CommitResult::Overflow) => {
+                    warn!("Overflow");
+                    ret.mut_failed_transform()
+                        .set_message("Overflow".to_string());
+                }
                 Err(error) => {
                     warn!("State error {:?} when applying transforms", error);
                     ret.mut_failed_transform()
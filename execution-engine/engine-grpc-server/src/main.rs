@@ -7,6 +7,7 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    thread,
     time::Duration,
 };
 
@@ -22,7 +23,8 @@ use engine_shared::{
     socket,
 };
 use engine_storage::{
-    global_state::lmdb::LmdbGlobalState, transaction_source::lmdb::LmdbEnvironment,
+    global_state::lmdb::{LmdbGlobalState, PruneResult},
+    transaction_source::lmdb::LmdbEnvironment,
     trie_store::lmdb::LmdbTrieStore,
 };
 
@@ -107,6 +109,14 @@ const ARG_ENABLE_BONDING: &str = "enable-bonding";
 const ARG_ENABLE_BONDING_SHORT: &str = "b";
 const ARG_ENABLE_BONDING_HELP: &str = "Enable bonding";
 
+// pruning
+const ARG_PRUNE_KEEP_LATEST: &str = "prune-keep-latest";
+const ARG_PRUNE_KEEP_LATEST_VALUE: &str = "NUM";
+const ARG_PRUNE_KEEP_LATEST_HELP: &str =
+    "Prunes the tries of all but the NUM most recently committed roots in the background";
+const ARG_PRUNE_KEEP_LATEST_EXPECT: &str = "expected valid number of roots to keep";
+const PRUNE_INTERVAL_SECONDS: u64 = 600;
+
 // runnable
 const SIGINT_HANDLE_EXPECT: &str = "Error setting Ctrl-C handler";
 const RUNNABLE_CHECK_INTERVAL_SECONDS: u64 = 3;
@@ -135,7 +145,13 @@ fn main() {
 
     let engine_config: EngineConfig = get_engine_config(&arg_matches);
 
-    let _server = get_grpc_server(&socket, data_dir, map_size, thread_count, engine_config);
+    let global_state = get_global_state(data_dir, map_size);
+
+    if let Some(keep_latest) = get_prune_keep_latest(&arg_matches) {
+        start_pruning(global_state.clone(), keep_latest);
+    }
+
+    let _server = get_grpc_server(&socket, global_state, thread_count, engine_config);
 
     log_listening_message(&socket);
 
@@ -239,6 +255,13 @@ fn get_args() -> ArgMatches<'static> {
                 .long(ARG_ENABLE_BONDING)
                 .help(ARG_ENABLE_BONDING_HELP),
         )
+        .arg(
+            Arg::with_name(ARG_PRUNE_KEEP_LATEST)
+                .long(ARG_PRUNE_KEEP_LATEST)
+                .takes_value(true)
+                .value_name(ARG_PRUNE_KEEP_LATEST_VALUE)
+                .help(ARG_PRUNE_KEEP_LATEST_HELP),
+        )
         .arg(
             Arg::with_name(ARG_SOCKET)
                 .required(true)
@@ -299,6 +322,13 @@ fn get_thread_count(arg_matches: &ArgMatches) -> usize {
         .expect(ARG_THREAD_COUNT_EXPECT)
 }
 
+/// Parses prune-keep-latest argument, if given
+fn get_prune_keep_latest(arg_matches: &ArgMatches) -> Option<usize> {
+    arg_matches
+        .value_of(ARG_PRUNE_KEEP_LATEST)
+        .map(|keep_latest| keep_latest.parse().expect(ARG_PRUNE_KEEP_LATEST_EXPECT))
+}
+
 /// Returns an [`EngineConfig`].
 fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     // feature flags go here
@@ -307,17 +337,17 @@ fn get_engine_config(arg_matches: &ArgMatches) -> EngineConfig {
     EngineConfig::new()
         .with_use_system_contracts(use_system_contracts)
         .with_enable_bonding(enable_bonding)
+        .with_verify_approvals(true)
 }
 
 /// Builds and returns a gRPC server.
 fn get_grpc_server(
     socket: &socket::Socket,
-    data_dir: PathBuf,
-    map_size: usize,
+    global_state: LmdbGlobalState,
     thread_count: usize,
     engine_config: EngineConfig,
 ) -> grpc::Server {
-    let engine_state = get_engine_state(data_dir, map_size, engine_config);
+    let engine_state = EngineState::new(global_state, engine_config);
 
     engine_server::new(socket.as_str(), thread_count, engine_state)
         .build()
@@ -325,11 +355,7 @@ fn get_grpc_server(
 }
 
 /// Builds and returns engine global state
-fn get_engine_state(
-    data_dir: PathBuf,
-    map_size: usize,
-    engine_config: EngineConfig,
-) -> EngineState<LmdbGlobalState> {
+fn get_global_state(data_dir: PathBuf, map_size: usize) -> LmdbGlobalState {
     let environment = {
         let ret = LmdbEnvironment::new(&data_dir, map_size).expect(LMDB_ENVIRONMENT_EXPECT);
         Arc::new(ret)
@@ -347,10 +373,32 @@ fn get_engine_state(
         Arc::new(ret)
     };
 
-    let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
-        .expect(LMDB_GLOBAL_STATE_EXPECT);
+    LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
+        .expect(LMDB_GLOBAL_STATE_EXPECT)
+}
 
-    EngineState::new(global_state, engine_config)
+/// Spawns a thread which prunes all but the `keep_latest` most recently committed roots of
+/// `global_state` every [`PRUNE_INTERVAL_SECONDS`]
+fn start_pruning(global_state: LmdbGlobalState, keep_latest: usize) {
+    let interval = Duration::from_secs(PRUNE_INTERVAL_SECONDS);
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        match global_state.prune_roots(keep_latest) {
+            Ok(PruneResult::Pruned {
+                pruned_roots,
+                deleted_tries,
+            }) => info!(
+                "pruned {} roots, deleting {} tries",
+                pruned_roots.len(),
+                deleted_tries
+            ),
+            // Tried again at the next interval
+            Ok(PruneResult::RootPinned(root_hash)) => {
+                info!("pruning postponed, as root {} is pinned", root_hash)
+            }
+            Err(error) => error!("pruning failed: {:?}", error),
+        }
+    });
 }
 
 /// Builds and returns log settings
@@ -1,7 +1,10 @@
 mod action_thresholds;
 mod associated_keys;
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
 
 use types::{
     account::{
@@ -15,6 +18,28 @@ use types::{
 pub use action_thresholds::ActionThresholds;
 pub use associated_keys::AssociatedKeys;
 
+/// The reason a deploy's authorization keys don't authorize an action on behalf of an account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorizationError {
+    /// At least one of the keys isn't associated with the account.
+    InvalidAssociatedKey,
+    /// The combined weight of the keys is below the account's threshold for the action.
+    InsufficientWeight,
+}
+
+impl fmt::Display for AuthorizationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthorizationError::InvalidAssociatedKey => {
+                write!(f, "authorization key is not associated with the account")
+            }
+            AuthorizationError::InsufficientWeight => {
+                write!(f, "insufficient weight of authorization keys")
+            }
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Account {
     public_key: PublicKey,
@@ -171,21 +196,20 @@ impl Account {
     }
 
     /// Checks whether all authorization keys are associated with this account
+    #[deprecated(note = "use `authorize`, which also reports why authorization failed")]
     pub fn can_authorize(&self, authorization_keys: &BTreeSet<PublicKey>) -> bool {
-        !authorization_keys.is_empty()
-            && authorization_keys
-                .iter()
-                .all(|e| self.associated_keys.contains_key(e))
+        match self.authorize(authorization_keys, ActionType::Deployment) {
+            Err(AuthorizationError::InvalidAssociatedKey) => false,
+            _ => !authorization_keys.is_empty(),
+        }
     }
 
-    /// Checks whether the sum of the weights of all authorization keys is
-    /// greater or equal to deploy threshold.
+    /// Checks whether the authorization keys are all associated with this account and the sum of
+    /// their weights is greater or equal to deploy threshold.
+    #[deprecated(note = "use `authorize`, which also reports why authorization failed")]
     pub fn can_deploy_with(&self, authorization_keys: &BTreeSet<PublicKey>) -> bool {
-        let total_weight = self
-            .associated_keys
-            .calculate_keys_weight(authorization_keys);
-
-        total_weight >= *self.action_thresholds().deployment()
+        self.authorize(authorization_keys, ActionType::Deployment)
+            .is_ok()
     }
 
     /// Checks whether the sum of the weights of all authorization keys is
@@ -197,6 +221,38 @@ impl Account {
 
         total_weight >= *self.action_thresholds().key_management()
     }
+
+    /// Checks whether `authorization_keys` authorize an action of `action_type` on behalf of this
+    /// account: every key must be associated with the account, and their combined weight must meet
+    /// the account's threshold for `action_type`.
+    ///
+    /// A key not associated with the account is rejected rather than ignored, even if the others
+    /// carry enough weight.  Being a set, each key's weight is only counted once.
+    pub fn authorize(
+        &self,
+        authorization_keys: &BTreeSet<PublicKey>,
+        action_type: ActionType,
+    ) -> Result<(), AuthorizationError> {
+        if !authorization_keys
+            .iter()
+            .all(|key| self.associated_keys.contains_key(key))
+        {
+            return Err(AuthorizationError::InvalidAssociatedKey);
+        }
+
+        let threshold = match action_type {
+            ActionType::Deployment => self.action_thresholds.deployment(),
+            ActionType::KeyManagement => self.action_thresholds.key_management(),
+        };
+        let total_weight = self
+            .associated_keys
+            .calculate_keys_weight(authorization_keys);
+        // Without any keys nothing is authorized, whatever the threshold
+        if authorization_keys.is_empty() || total_weight < *threshold {
+            return Err(AuthorizationError::InsufficientWeight);
+        }
+        Ok(())
+    }
 }
 
 impl ToBytes for Account {
@@ -305,7 +361,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn associated_keys_can_authorize_keys() {
+    fn account_should_authorize_associated_keys() {
         let key_1 = PublicKey::ed25519_from([0; 32]);
         let key_2 = PublicKey::ed25519_from([1; 32]);
         let key_3 = PublicKey::ed25519_from([2; 32]);
@@ -323,37 +379,77 @@ mod tests {
             BTreeMap::new(),
             URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
             keys,
-            // deploy: 33 (3*11)
-            ActionThresholds::new(Weight::new(33), Weight::new(48))
+            ActionThresholds::new(Weight::new(1), Weight::new(1))
                 .expect("should create thresholds"),
         );
+        let authorize = |public_keys: Vec<PublicKey>| {
+            account.authorize(&BTreeSet::from_iter(public_keys), ActionType::Deployment)
+        };
 
-        assert!(account.can_authorize(&BTreeSet::from_iter(vec![key_3, key_2, key_1])));
-        assert!(account.can_authorize(&BTreeSet::from_iter(vec![key_1, key_3, key_2])));
+        assert_eq!(authorize(vec![key_3, key_2, key_1]), Ok(()));
+        assert_eq!(authorize(vec![key_1, key_3, key_2]), Ok(()));
 
-        assert!(account.can_authorize(&BTreeSet::from_iter(vec![key_1, key_2])));
-        assert!(account.can_authorize(&BTreeSet::from_iter(vec![key_1])));
+        assert_eq!(authorize(vec![key_1, key_2]), Ok(()));
+        assert_eq!(authorize(vec![key_1]), Ok(()));
+
+        assert_eq!(
+            authorize(vec![key_1, key_2, PublicKey::ed25519_from([42; 32])]),
+            Err(AuthorizationError::InvalidAssociatedKey)
+        );
+        assert_eq!(
+            authorize(vec![PublicKey::ed25519_from([42; 32]), key_1, key_2]),
+            Err(AuthorizationError::InvalidAssociatedKey)
+        );
+        assert_eq!(
+            authorize(vec![
+                PublicKey::ed25519_from([43; 32]),
+                PublicKey::ed25519_from([44; 32]),
+                PublicKey::ed25519_from([42; 32])
+            ]),
+            Err(AuthorizationError::InvalidAssociatedKey)
+        );
+        assert_eq!(
+            authorize(vec![]),
+            Err(AuthorizationError::InsufficientWeight)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_checks_should_agree_with_authorize() {
+        let key_1 = PublicKey::ed25519_from([1u8; 32]);
+        let key_2 = PublicKey::ed25519_from([2u8; 32]);
+        let mut associated_keys = AssociatedKeys::new(key_1, Weight::new(1));
+        associated_keys
+            .add_key(key_2, Weight::new(1))
+            .expect("should add key");
+        let account = Account::new(
+            PublicKey::ed25519_from([0u8; 32]),
+            BTreeMap::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::new(Weight::new(2), Weight::new(2))
+                .expect("should create thresholds"),
+        );
+
+        let keys = BTreeSet::from_iter(vec![key_1]);
+        assert!(account.can_authorize(&keys));
+        assert!(!account.can_deploy_with(&keys));
+
+        let keys = BTreeSet::from_iter(vec![key_1, key_2]);
+        assert!(account.can_authorize(&keys));
+        assert!(account.can_deploy_with(&keys));
+
+        let keys = BTreeSet::from_iter(vec![key_1, key_2, PublicKey::ed25519_from([42; 32])]);
+        assert!(!account.can_authorize(&keys));
+        assert!(!account.can_deploy_with(&keys));
 
-        assert!(!account.can_authorize(&BTreeSet::from_iter(vec![
-            key_1,
-            key_2,
-            PublicKey::ed25519_from([42; 32])
-        ])));
-        assert!(!account.can_authorize(&BTreeSet::from_iter(vec![
-            PublicKey::ed25519_from([42; 32]),
-            key_1,
-            key_2
-        ])));
-        assert!(!account.can_authorize(&BTreeSet::from_iter(vec![
-            PublicKey::ed25519_from([43; 32]),
-            PublicKey::ed25519_from([44; 32]),
-            PublicKey::ed25519_from([42; 32])
-        ])));
         assert!(!account.can_authorize(&BTreeSet::new()));
+        assert!(!account.can_deploy_with(&BTreeSet::new()));
     }
 
     #[test]
-    fn account_can_deploy_with() {
+    fn account_should_authorize_deploy_with_threshold_weight() {
         let associated_keys = {
             let mut res = AssociatedKeys::new(PublicKey::ed25519_from([1u8; 32]), Weight::new(1));
             res.add_key(PublicKey::ed25519_from([2u8; 32]), Weight::new(11))
@@ -374,26 +470,39 @@ mod tests {
                 .expect("should create thresholds"),
         );
 
+        let authorize = |public_keys: Vec<PublicKey>| {
+            account.authorize(&BTreeSet::from_iter(public_keys), ActionType::Deployment)
+        };
+
         // sum: 22, required 33 - can't deploy
-        assert!(!account.can_deploy_with(&BTreeSet::from_iter(vec![
-            PublicKey::ed25519_from([3u8; 32]),
-            PublicKey::ed25519_from([2u8; 32]),
-        ])));
+        assert_eq!(
+            authorize(vec![
+                PublicKey::ed25519_from([3u8; 32]),
+                PublicKey::ed25519_from([2u8; 32]),
+            ]),
+            Err(AuthorizationError::InsufficientWeight)
+        );
 
         // sum: 33, required 33 - can deploy
-        assert!(account.can_deploy_with(&BTreeSet::from_iter(vec![
-            PublicKey::ed25519_from([4u8; 32]),
-            PublicKey::ed25519_from([3u8; 32]),
-            PublicKey::ed25519_from([2u8; 32]),
-        ])));
+        assert_eq!(
+            authorize(vec![
+                PublicKey::ed25519_from([4u8; 32]),
+                PublicKey::ed25519_from([3u8; 32]),
+                PublicKey::ed25519_from([2u8; 32]),
+            ]),
+            Ok(())
+        );
 
         // sum: 34, required 33 - can deploy
-        assert!(account.can_deploy_with(&BTreeSet::from_iter(vec![
-            PublicKey::ed25519_from([2u8; 32]),
-            PublicKey::ed25519_from([1u8; 32]),
-            PublicKey::ed25519_from([4u8; 32]),
-            PublicKey::ed25519_from([3u8; 32]),
-        ])));
+        assert_eq!(
+            authorize(vec![
+                PublicKey::ed25519_from([2u8; 32]),
+                PublicKey::ed25519_from([1u8; 32]),
+                PublicKey::ed25519_from([4u8; 32]),
+                PublicKey::ed25519_from([3u8; 32]),
+            ]),
+            Ok(())
+        );
     }
 
     #[test]
@@ -440,6 +549,30 @@ mod tests {
         ])));
     }
 
+    #[test]
+    fn account_should_authorize_with_combined_weight() {
+        let heavy_key = PublicKey::ed25519_from([2u8; 32]);
+        let identity_key = PublicKey::ed25519_from([1u8; 32]);
+        let unknown_key = PublicKey::ed25519_from([3u8; 32]);
+        let account = account_with_heavy_key(heavy_key, Weight::new(3), Weight::new(3));
+        let keys = |public_keys: &[PublicKey]| public_keys.iter().copied().collect::<BTreeSet<_>>();
+
+        // sum: 3, required 3
+        let result = account.authorize(&keys(&[identity_key, heavy_key]), ActionType::Deployment);
+        assert_eq!(result, Ok(()));
+
+        // sum: 2, required 3
+        let result = account.authorize(&keys(&[heavy_key]), ActionType::KeyManagement);
+        assert_eq!(result, Err(AuthorizationError::InsufficientWeight));
+        let result = account.authorize(&BTreeSet::new(), ActionType::Deployment);
+        assert_eq!(result, Err(AuthorizationError::InsufficientWeight));
+
+        // The unknown key isn't ignored, though the others meet the threshold
+        let all_keys = keys(&[identity_key, heavy_key, unknown_key]);
+        let result = account.authorize(&all_keys, ActionType::Deployment);
+        assert_eq!(result, Err(AuthorizationError::InvalidAssociatedKey));
+    }
+
     #[test]
     fn set_action_threshold_higher_than_total_weight() {
         let identity_key = PublicKey::ed25519_from([1u8; 32]);
@@ -645,4 +778,107 @@ mod tests {
             .update_associated_key(key_1, Weight::new(1))
             .expect("should work");
     }
+
+    /// Returns an account whose identity key has weight 1 and whose only other key, `heavy_key`,
+    /// has weight 2, with the given thresholds.
+    fn account_with_heavy_key(
+        heavy_key: PublicKey,
+        deployment: Weight,
+        key_management: Weight,
+    ) -> Account {
+        let identity_key = PublicKey::ed25519_from([1u8; 32]);
+        let mut associated_keys = AssociatedKeys::new(identity_key, Weight::new(1));
+        associated_keys
+            .add_key(heavy_key, Weight::new(2))
+            .expect("should add heavy key");
+        Account::new(
+            identity_key,
+            BTreeMap::new(),
+            URef::new([0u8; 32], AccessRights::READ_ADD_WRITE),
+            associated_keys,
+            ActionThresholds::new(deployment, key_management).expect("should create thresholds"),
+        )
+    }
+
+    #[test]
+    fn set_action_threshold_equal_to_total_weight() {
+        let heavy_key = PublicKey::ed25519_from([2u8; 32]);
+        let mut account = account_with_heavy_key(heavy_key, Weight::new(1), Weight::new(1));
+
+        account
+            .set_action_threshold(ActionType::KeyManagement, Weight::new(1 + 2))
+            .expect("should set key management threshold to total weight");
+        account
+            .set_action_threshold(ActionType::Deployment, Weight::new(1 + 2))
+            .expect("should set deployment threshold to total weight");
+        assert_eq!(
+            account.action_thresholds(),
+            &ActionThresholds::new(Weight::new(3), Weight::new(3)).unwrap()
+        );
+
+        assert_eq!(
+            account
+                .set_action_threshold(ActionType::KeyManagement, Weight::new(1 + 2 + 1))
+                .unwrap_err(),
+            SetThresholdFailure::InsufficientTotalWeight,
+        );
+    }
+
+    #[test]
+    fn set_action_threshold_should_keep_deployment_at_most_key_management() {
+        let heavy_key = PublicKey::ed25519_from([2u8; 32]);
+        let mut account = account_with_heavy_key(heavy_key, Weight::new(2), Weight::new(2));
+
+        assert_eq!(
+            account
+                .set_action_threshold(ActionType::Deployment, Weight::new(3))
+                .unwrap_err(),
+            SetThresholdFailure::DeploymentThreshold,
+        );
+        assert_eq!(
+            account
+                .set_action_threshold(ActionType::KeyManagement, Weight::new(1))
+                .unwrap_err(),
+            SetThresholdFailure::KeyManagementThreshold,
+        );
+        assert_eq!(
+            account.action_thresholds(),
+            &ActionThresholds::new(Weight::new(2), Weight::new(2)).unwrap()
+        );
+    }
+
+    #[test]
+    fn removing_or_updating_heavy_key_should_keep_thresholds_reachable() {
+        let heavy_key = PublicKey::ed25519_from([2u8; 32]);
+
+        // The remaining weight equals the thresholds exactly.
+        let mut account = account_with_heavy_key(heavy_key, Weight::new(1), Weight::new(2));
+        account
+            .clone()
+            .update_associated_key(heavy_key, Weight::new(1))
+            .expect("should leave total weight equal to key management threshold");
+        assert_eq!(
+            account.remove_associated_key(heavy_key).unwrap_err(),
+            RemoveKeyFailure::ThresholdViolation,
+        );
+
+        let mut account = account_with_heavy_key(heavy_key, Weight::new(1), Weight::new(1));
+        account
+            .remove_associated_key(heavy_key)
+            .expect("should leave total weight equal to thresholds");
+
+        // The remaining weight would fall below the key management threshold.
+        let mut account = account_with_heavy_key(heavy_key, Weight::new(1), Weight::new(3));
+        assert_eq!(
+            account
+                .clone()
+                .update_associated_key(heavy_key, Weight::new(1))
+                .unwrap_err(),
+            UpdateKeyFailure::ThresholdViolation,
+        );
+        assert_eq!(
+            account.remove_associated_key(heavy_key).unwrap_err(),
+            RemoveKeyFailure::ThresholdViolation,
+        );
+    }
 }
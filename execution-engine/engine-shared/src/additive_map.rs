@@ -50,6 +50,17 @@ impl<K, V, S> AdditiveMap<K, V, S> {
     }
 }
 
+impl<K: Ord, V, S> AdditiveMap<K, V, S> {
+    /// Returns the entries in ascending order of their keys.  Unlike that of `iter` and
+    /// `into_iter`, the order doesn't depend on the hasher or on the order of insertion, so this
+    /// should be used wherever the entries are serialized or applied.
+    pub fn into_sorted_vec(self) -> Vec<(K, V)> {
+        let mut entries: Vec<(K, V)> = self.0.into_iter().collect();
+        entries.sort_unstable_by(|(left, _), (right, _)| left.cmp(right));
+        entries
+    }
+}
+
 impl<K: Eq + Hash, V, S: BuildHasher> AdditiveMap<K, V, S> {
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
@@ -166,4 +177,18 @@ mod tests {
         transform_map.insert_add(key, Transform::AddInt32(2));
         assert_eq!(Transform::AddInt32(3), transform_map[key]);
     }
+
+    #[test]
+    fn into_sorted_vec() {
+        let mut forward = AdditiveMap::new();
+        let mut backward = AdditiveMap::new();
+        for key in 0..32 {
+            forward.insert(key, key * 2);
+            backward.insert(31 - key, (31 - key) * 2);
+        }
+
+        let expected: Vec<(i32, i32)> = (0..32).map(|key| (key, key * 2)).collect();
+        assert_eq!(forward.into_sorted_vec(), expected);
+        assert_eq!(backward.into_sorted_vec(), expected);
+    }
 }
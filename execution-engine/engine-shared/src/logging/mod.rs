@@ -64,6 +64,12 @@ pub fn log_details(
     message_format: String,
     mut properties: BTreeMap<&str, String>,
 ) {
+    // As for the `log` macros, honour the global maximum level, which may be lower than the level
+    // the logger was initialized with.
+    if log_level > log::max_level() {
+        return;
+    }
+
     let logger = log::logger();
 
     let metadata = Metadata::builder()
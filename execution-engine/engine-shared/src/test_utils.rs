@@ -25,6 +25,12 @@ pub fn wasm_costs_mock() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 3,
         opcodes_div: 8,
+        storage_per_byte: 0,
+        native_transfer: 10_000,
+        hash_per_byte: 1,
+        max_initial_memory_pages: 64,
+        max_memory_pages: 64,
+        preprocess_per_byte: 0,
     }
 }
 
@@ -40,5 +46,11 @@ pub fn wasm_costs_free() -> WasmCosts {
         max_stack_height: 64 * 1024,
         opcodes_mul: 1,
         opcodes_div: 1,
+        storage_per_byte: 0,
+        native_transfer: 0,
+        hash_per_byte: 0,
+        max_initial_memory_pages: 64,
+        max_memory_pages: 64,
+        preprocess_per_byte: 0,
     }
 }
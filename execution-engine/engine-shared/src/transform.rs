@@ -10,7 +10,7 @@ use std::{
 use num::traits::{AsPrimitive, WrappingAdd};
 
 use types::{
-    bytesrepr::{self, FromBytes, ToBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
     CLType, CLTyped, CLValue, CLValueError, Key, U128, U256, U512,
 };
 
@@ -19,14 +19,16 @@ use crate::{stored_value::StoredValue, TypeMismatch};
 /// Error type for applying and combining transforms. A `TypeMismatch`
 /// occurs when a transform cannot be applied because the types are
 /// not compatible (e.g. trying to add a number to a string). An
-/// `Overflow` occurs if addition between numbers would result in the
-/// value overflowing its size in memory (e.g. if a, b are i32 and a +
-/// b > i32::MAX then a `AddInt32(a).apply(Value::Int32(b))` would
-/// cause an overflow).
+/// `Overflow` occurs if a non-negative addition to a `U512` would result
+/// in the value overflowing its size in memory (e.g. if a, b are U512
+/// and a + b > U512::MAX then `AddUInt512(a).apply(Value::UInt512(b))`
+/// would cause an overflow).  Negative additions to a `U512`, and
+/// additions to other numeric types, wrap.
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum Error {
     Serialization(bytesrepr::Error),
     TypeMismatch(TypeMismatch),
+    Overflow,
 }
 
 impl From<TypeMismatch> for Error {
@@ -59,6 +61,10 @@ pub enum Transform {
     AddUInt256(U256),
     AddUInt512(U512),
     AddKeys(BTreeMap<String, Key>),
+    /// Appends the given items, in order, to the `CLType::List` under the key.
+    ListAppend(Vec<CLValue>),
+    /// Removes the value under the key from global state.
+    Delete,
     Failure(Error),
 }
 
@@ -135,16 +141,84 @@ where
     Ok(StoredValue::CLValue(CLValue::from_t(result)?))
 }
 
+/// Attempts an addition of the non-negative `to_add` to `stored_value`, failing with
+/// `Error::Overflow` rather than wrapping if `stored_value` is a `U512`.  Additions to other
+/// integral types wrap, as for [`wrapping_addition`].
+fn u512_addition<Y>(stored_value: StoredValue, to_add: Y) -> Result<StoredValue, Error>
+where
+    Y: AsPrimitive<i32>
+        + AsPrimitive<i64>
+        + AsPrimitive<u8>
+        + AsPrimitive<u32>
+        + AsPrimitive<u64>
+        + AsPrimitive<U128>
+        + AsPrimitive<U256>
+        + AsPrimitive<U512>,
+{
+    let cl_value = CLValue::try_from(stored_value)?;
+    if *cl_value.cl_type() != CLType::U512 {
+        return wrapping_addition(StoredValue::CLValue(cl_value), to_add);
+    }
+    let value: U512 = cl_value.into_t()?;
+    let result = value.checked_add(to_add.as_()).ok_or(Error::Overflow)?;
+    Ok(StoredValue::CLValue(CLValue::from_t(result)?))
+}
+
+/// Combines two unsigned additions to a `U512`, failing rather than wrapping on overflow.
+fn checked_u512_transform_addition(i: U512, j: U512) -> Transform {
+    match i.checked_add(j) {
+        Some(sum) => Transform::AddUInt512(sum),
+        None => Transform::Failure(Error::Overflow),
+    }
+}
+
+/// Appends `items` to the list represented by `stored_value`, assuming each item has the list's
+/// element type.
+fn list_append(stored_value: StoredValue, items: Vec<CLValue>) -> Result<StoredValue, Error> {
+    let cl_value = CLValue::try_from(stored_value)?;
+    let element_type = match cl_value.cl_type() {
+        CLType::List(element_type) => (**element_type).clone(),
+        other => {
+            let expected = "List".to_string();
+            let found = format!("{:?}", other);
+            return Err(TypeMismatch::new(expected, found).into());
+        }
+    };
+
+    let (cl_type, mut bytes) = cl_value.destructure();
+    let (length, _) = u32::from_bytes(&bytes).map_err(Error::Serialization)?;
+    let new_length = u32::try_from(items.len())
+        .ok()
+        .and_then(|appended_length| length.checked_add(appended_length))
+        .ok_or(Error::Serialization(bytesrepr::Error::OutOfMemory))?;
+    bytes[..U32_SERIALIZED_LENGTH].copy_from_slice(&new_length.to_le_bytes());
+
+    for item in items {
+        if *item.cl_type() != element_type {
+            let expected = format!("{:?}", element_type);
+            let found = format!("{:?}", item.cl_type());
+            return Err(TypeMismatch::new(expected, found).into());
+        }
+        bytes.extend(item.inner_bytes());
+    }
+
+    Ok(StoredValue::CLValue(CLValue::from_components(
+        cl_type, bytes,
+    )))
+}
+
 impl Transform {
     pub fn apply(self, stored_value: StoredValue) -> Result<StoredValue, Error> {
         match self {
             Transform::Identity => Ok(stored_value),
             Transform::Write(new_value) => Ok(new_value),
-            Transform::AddInt32(to_add) => wrapping_addition(stored_value, to_add),
-            Transform::AddUInt64(to_add) => wrapping_addition(stored_value, to_add),
-            Transform::AddUInt128(to_add) => wrapping_addition(stored_value, to_add),
-            Transform::AddUInt256(to_add) => wrapping_addition(stored_value, to_add),
-            Transform::AddUInt512(to_add) => wrapping_addition(stored_value, to_add),
+            // a negative `AddInt32` is a subtraction, which wraps
+            Transform::AddInt32(to_add) if to_add < 0 => wrapping_addition(stored_value, to_add),
+            Transform::AddInt32(to_add) => u512_addition(stored_value, to_add),
+            Transform::AddUInt64(to_add) => u512_addition(stored_value, to_add),
+            Transform::AddUInt128(to_add) => u512_addition(stored_value, to_add),
+            Transform::AddUInt256(to_add) => u512_addition(stored_value, to_add),
+            Transform::AddUInt512(to_add) => u512_addition(stored_value, to_add),
             Transform::AddKeys(mut keys) => match stored_value {
                 StoredValue::Contract(mut contract) => {
                     contract.named_keys_append(&mut keys);
@@ -154,12 +228,14 @@ impl Transform {
                     account.named_keys_append(&mut keys);
                     Ok(StoredValue::Account(account))
                 }
-                StoredValue::CLValue(cl_value) => {
-                    let expected = "Contract or Account".to_string();
-                    let found = format!("{:?}", cl_value.cl_type());
-                    Err(TypeMismatch::new(expected, found).into())
-                }
+                StoredValue::CLValue(cl_value) => Err(add_keys_type_mismatch(&cl_value).into()),
             },
+            Transform::ListAppend(items) => list_append(stored_value, items),
+            Transform::Delete => {
+                let expected = "Identity, Write or Add transform".to_string();
+                let found = "Delete".to_string();
+                Err(TypeMismatch::new(expected, found).into())
+            }
             Transform::Failure(error) => Err(error),
         }
     }
@@ -197,6 +273,13 @@ where
     }
 }
 
+/// Returns the `TypeMismatch` raised when named keys are added to a value which can't hold them.
+fn add_keys_type_mismatch(cl_value: &CLValue) -> TypeMismatch {
+    let expected = "Contract or Account".to_string();
+    let found = format!("{:?}", cl_value.cl_type());
+    TypeMismatch::new(expected, found)
+}
+
 impl Add for Transform {
     type Output = Transform;
 
@@ -206,7 +289,16 @@ impl Add for Transform {
             (Transform::Identity, b) => b,
             (a @ Transform::Failure(_), _) => a,
             (_, b @ Transform::Failure(_)) => b,
+            // named keys can only have been added to an account or contract, which a `CLValue`
+            // can't replace
+            (Transform::AddKeys(_), Transform::Write(StoredValue::CLValue(cl_value))) => {
+                Transform::Failure(add_keys_type_mismatch(&cl_value).into())
+            }
             (_, b @ Transform::Write(_)) => b,
+            (_, b @ Transform::Delete) => b,
+            (Transform::Delete, b) => {
+                Transform::Failure(TypeMismatch::new("Write".to_owned(), format!("{:?}", b)).into())
+            }
             (Transform::Write(v), b) => {
                 // second transform changes value being written
                 match b.apply(v) {
@@ -214,6 +306,20 @@ impl Add for Transform {
                     Ok(new_value) => Transform::Write(new_value),
                 }
             }
+            // purse balances are `U512`s, so unsigned additions to them mustn't wrap
+            (Transform::AddUInt512(i), Transform::AddUInt512(j)) => {
+                checked_u512_transform_addition(i, j)
+            }
+            (Transform::AddUInt512(i), Transform::AddUInt64(j))
+            | (Transform::AddUInt64(j), Transform::AddUInt512(i)) => {
+                checked_u512_transform_addition(i, j.into())
+            }
+            (Transform::AddUInt512(i), Transform::AddInt32(j))
+            | (Transform::AddInt32(j), Transform::AddUInt512(i))
+                if j >= 0 =>
+            {
+                checked_u512_transform_addition(i, j.as_())
+            }
             (Transform::AddInt32(i), b) => match b {
                 Transform::AddInt32(j) => Transform::AddInt32(i.wrapping_add(j)),
                 Transform::AddUInt64(j) => Transform::AddUInt64(j.wrapping_add(i as u64)),
@@ -246,6 +352,15 @@ impl Add for Transform {
                     TypeMismatch::new("AddKeys".to_owned(), format!("{:?}", other)).into(),
                 ),
             },
+            (Transform::ListAppend(mut items1), b) => match b {
+                Transform::ListAppend(mut items2) => {
+                    items1.append(&mut items2);
+                    Transform::ListAppend(items1)
+                }
+                other => Transform::Failure(
+                    TypeMismatch::new("ListAppend".to_owned(), format!("{:?}", other)).into(),
+                ),
+            },
         }
     }
 }
@@ -271,12 +386,15 @@ impl Default for Transform {
 pub mod gens {
     use proptest::{collection::vec, prelude::*};
 
+    use types::CLValue;
+
     use super::Transform;
     use crate::stored_value::gens::stored_value_arb;
 
     pub fn transform_arb() -> impl Strategy<Value = Transform> {
         prop_oneof![
             Just(Transform::Identity),
+            Just(Transform::Delete),
             stored_value_arb().prop_map(Transform::Write),
             any::<i32>().prop_map(Transform::AddInt32),
             any::<u64>().prop_map(Transform::AddUInt64),
@@ -291,6 +409,13 @@ pub mod gens {
                 buf.copy_from_slice(&u);
                 Transform::AddUInt512(buf.into())
             }),
+            vec(any::<u64>(), 0..5).prop_map(|items| {
+                let items = items
+                    .into_iter()
+                    .map(|item| CLValue::from_t(item).expect("should create CLValue"))
+                    .collect();
+                Transform::ListAppend(items)
+            }),
         ]
     }
 }
@@ -419,7 +544,75 @@ mod tests {
 
     #[test]
     fn u512_overflow() {
-        uint_overflow_test::<U512>();
+        let max_value = StoredValue::CLValue(CLValue::from_t(MAX_U512).unwrap());
+        let min_value = StoredValue::CLValue(CLValue::from_t(ZERO_U512).unwrap());
+
+        let apply_overflow = Transform::AddInt32(1).apply(max_value.clone());
+        assert_eq!(apply_overflow, Err(Error::Overflow));
+        // A negative `AddInt32` is a subtraction, so it still wraps
+        let apply_underflow = Transform::AddInt32(-1).apply(min_value.clone());
+        assert_eq!(apply_underflow, Ok(max_value.clone()));
+
+        let apply_overflow_u64 = Transform::AddUInt64(ONE_U64).apply(max_value.clone());
+        assert_eq!(apply_overflow_u64, Err(Error::Overflow));
+        let apply_overflow_u128 = Transform::AddUInt128(U128::from(1)).apply(max_value.clone());
+        assert_eq!(apply_overflow_u128, Err(Error::Overflow));
+        let apply_overflow_u256 = Transform::AddUInt256(U256::from(1)).apply(max_value.clone());
+        assert_eq!(apply_overflow_u256, Err(Error::Overflow));
+
+        let apply_overflow_uint = Transform::AddUInt512(ONE_U512).apply(max_value.clone());
+        assert_eq!(apply_overflow_uint, Err(Error::Overflow));
+        let apply_max = Transform::AddUInt512(MAX_U512).apply(max_value);
+        assert_eq!(apply_max, Err(Error::Overflow));
+
+        let max_transform = Transform::AddUInt512(MAX_U512);
+        assert_eq!(
+            max_transform.clone() + Transform::AddInt32(1),
+            Transform::Failure(Error::Overflow)
+        );
+        assert_eq!(
+            Transform::AddInt32(1) + max_transform.clone(),
+            Transform::Failure(Error::Overflow)
+        );
+        assert_eq!(
+            max_transform.clone() + Transform::AddInt32(-1),
+            (MAX_U512 - 1).into()
+        );
+        assert_eq!(
+            max_transform.clone() + Transform::AddUInt128(U128::from(1)),
+            Transform::Failure(
+                TypeMismatch::new(
+                    "U512".to_string(),
+                    format!("{:?}", Transform::AddUInt128(U128::from(1)))
+                )
+                .into()
+            )
+        );
+        assert_eq!(
+            max_transform.clone() + Transform::AddUInt512(ONE_U512),
+            Transform::Failure(Error::Overflow)
+        );
+        assert_eq!(
+            max_transform.clone() + Transform::AddUInt64(ONE_U64),
+            Transform::Failure(Error::Overflow)
+        );
+        assert_eq!(
+            Transform::AddUInt64(ONE_U64) + max_transform,
+            Transform::Failure(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn u512_additions_up_to_max_should_succeed() {
+        let value = StoredValue::CLValue(CLValue::from_t(MAX_U512 - 2).unwrap());
+        let max_value = StoredValue::CLValue(CLValue::from_t(MAX_U512).unwrap());
+
+        let transform = Transform::AddUInt512(ONE_U512) + Transform::AddUInt64(ONE_U64);
+        assert_eq!(transform, Transform::AddUInt512(U512::from(2)));
+        assert_eq!(transform.clone().apply(value.clone()), Ok(max_value));
+
+        let transform = transform + Transform::AddUInt512(ONE_U512);
+        assert_eq!(transform.apply(value), Err(Error::Overflow));
     }
 
     #[test]
@@ -742,4 +935,197 @@ mod tests {
         assert_eq!(ZERO_U512, add(MAX_U512, ONE_U512));
         assert_eq!(MAX_U512 - 1, add(MAX_U512, MAX_U512));
     }
+
+    #[test]
+    fn delete_should_combine_like_write() {
+        let value = StoredValue::CLValue(CLValue::from_t(ONE_U512).expect("should create CLValue"));
+
+        assert_eq!(
+            Transform::Write(value.clone()) + Transform::Delete,
+            Transform::Delete
+        );
+        assert_eq!(
+            Transform::AddInt32(ONE_I32) + Transform::Delete,
+            Transform::Delete
+        );
+        assert_eq!(Transform::Delete + Transform::Identity, Transform::Delete);
+        assert_eq!(
+            Transform::Delete + Transform::Write(value.clone()),
+            Transform::Write(value)
+        );
+        let expected_error = TypeMismatch::new("Write".to_owned(), "AddInt32(1)".to_owned());
+        assert_eq!(
+            Transform::Delete + Transform::AddInt32(ONE_I32),
+            Transform::Failure(expected_error.into())
+        );
+    }
+
+    fn u64_list(items: &[u64]) -> StoredValue {
+        StoredValue::CLValue(CLValue::from_t(items.to_vec()).expect("should create CLValue"))
+    }
+
+    fn u64_list_append(items: &[u64]) -> Transform {
+        let items = items
+            .iter()
+            .map(|item| CLValue::from_t(*item).expect("should create CLValue"))
+            .collect();
+        Transform::ListAppend(items)
+    }
+
+    #[test]
+    fn list_append_should_extend_list() {
+        assert_eq!(
+            u64_list_append(&[3, 4]).apply(u64_list(&[1, 2])),
+            Ok(u64_list(&[1, 2, 3, 4]))
+        );
+        assert_eq!(
+            u64_list_append(&[1]).apply(u64_list(&[])),
+            Ok(u64_list(&[1]))
+        );
+    }
+
+    #[test]
+    fn list_appends_should_combine() {
+        assert_eq!(
+            u64_list_append(&[1]) + u64_list_append(&[2, 3]),
+            u64_list_append(&[1, 2, 3])
+        );
+        assert_eq!(
+            Transform::Write(u64_list(&[1])) + u64_list_append(&[2]),
+            Transform::Write(u64_list(&[1, 2]))
+        );
+        assert_eq!(
+            u64_list_append(&[1]) + Transform::Write(u64_list(&[2])),
+            Transform::Write(u64_list(&[2]))
+        );
+    }
+
+    #[test]
+    fn list_append_should_fail_for_non_list_or_mismatched_items() {
+        let not_a_list = StoredValue::CLValue(CLValue::from_t(ONE_U64).expect("should create"));
+        assert_eq!(
+            u64_list_append(&[1]).apply(not_a_list.clone()),
+            Err(TypeMismatch::new("List".to_string(), "U64".to_string()).into())
+        );
+        assert_eq!(
+            Transform::Write(not_a_list) + u64_list_append(&[1]),
+            Transform::Failure(TypeMismatch::new("List".to_string(), "U64".to_string()).into())
+        );
+
+        let string_item = CLValue::from_t(String::from(TEST_STR)).expect("should create");
+        assert_eq!(
+            Transform::ListAppend(vec![string_item]).apply(u64_list(&[1])),
+            Err(TypeMismatch::new("U64".to_string(), "String".to_string()).into())
+        );
+
+        let expected_error = TypeMismatch::new("ListAppend".to_owned(), "AddUInt64(1)".to_owned());
+        assert_eq!(
+            u64_list_append(&[1]) + Transform::AddUInt64(ONE_U64),
+            Transform::Failure(expected_error.into())
+        );
+        let found = format!("{:?}", u64_list_append(&[1]));
+        let expected_error = TypeMismatch::new("AddUInt64".to_owned(), found);
+        assert_eq!(
+            Transform::AddUInt64(ONE_U64) + u64_list_append(&[1]),
+            Transform::Failure(expected_error.into())
+        );
+    }
+
+    /// Returns a sample of each kind of transform, in the order of the rows and columns of
+    /// `ADDITION_MATRIX`.
+    fn transform_samples() -> Vec<Transform> {
+        let uref = URef::new(ZERO_ARRAY, AccessRights::READ);
+        let account = Account::new(
+            ZERO_PUBLIC_KEY,
+            BTreeMap::new(),
+            uref,
+            AssociatedKeys::default(),
+            ActionThresholds::default(),
+        );
+        let mut named_keys = BTreeMap::new();
+        named_keys.insert(TEST_STR.to_string(), Key::URef(uref));
+
+        vec![
+            Transform::Identity,
+            Transform::Write(StoredValue::CLValue(CLValue::from_t(ONE_U64).unwrap())),
+            Transform::Write(StoredValue::Account(account)),
+            Transform::AddInt32(ONE_I32),
+            Transform::AddUInt64(ONE_U64),
+            Transform::AddUInt128(ONE_U128),
+            Transform::AddUInt256(ONE_U256),
+            Transform::AddUInt512(ONE_U512),
+            Transform::AddKeys(named_keys),
+            u64_list_append(&[1]),
+            Transform::Delete,
+            Transform::Failure(Error::Serialization(bytesrepr::Error::Formatting)),
+        ]
+    }
+
+    fn kind(transform: &Transform) -> &'static str {
+        match transform {
+            Transform::Identity => "Id",
+            Transform::Write(StoredValue::CLValue(_)) => "WV",
+            Transform::Write(_) => "WA",
+            Transform::AddInt32(_) => "I32",
+            Transform::AddUInt64(_) => "U64",
+            Transform::AddUInt128(_) => "U128",
+            Transform::AddUInt256(_) => "U256",
+            Transform::AddUInt512(_) => "U512",
+            Transform::AddKeys(_) => "Keys",
+            Transform::ListAppend(_) => "List",
+            Transform::Delete => "Del",
+            Transform::Failure(_) => "Fail",
+        }
+    }
+
+    /// The kind of `row + column` for each pair of kinds of transform, where `WV` is a write of a
+    /// `CLValue` (a `u64`) and `WA` a write of an account. The columns are in the same order as the
+    /// rows.
+    const ADDITION_MATRIX: [&str; 12] = [
+        "Id   WV   WA   I32  U64  U128 U256 U512 Keys List Del  Fail", // Id
+        "WV   WV   WA   WV   WV   WV   WV   WV   Fail Fail Del  Fail", // WV
+        "WA   WV   WA   Fail Fail Fail Fail Fail WA   Fail Del  Fail", // WA
+        "I32  WV   WA   I32  U64  U128 U256 U512 Fail Fail Del  Fail", // I32
+        "U64  WV   WA   I32  U64  U128 U256 U512 Fail Fail Del  Fail", // U64
+        "U128 WV   WA   U128 U128 U128 Fail Fail Fail Fail Del  Fail", // U128
+        "U256 WV   WA   U256 U256 Fail U256 Fail Fail Fail Del  Fail", // U256
+        "U512 WV   WA   U512 U512 Fail Fail U512 Fail Fail Del  Fail", // U512
+        "Keys Fail WA   Fail Fail Fail Fail Fail Keys Fail Del  Fail", // Keys
+        "List WV   WA   Fail Fail Fail Fail Fail Fail List Del  Fail", // List
+        "Del  WV   WA   Fail Fail Fail Fail Fail Fail Fail Del  Fail", // Del
+        "Fail Fail Fail Fail Fail Fail Fail Fail Fail Fail Fail Fail", // Fail
+    ];
+
+    #[test]
+    fn pairwise_additions_should_match_matrix() {
+        let samples = transform_samples();
+        for (row, a) in samples.iter().enumerate() {
+            for (column, b) in samples.iter().enumerate() {
+                let sum = a.clone() + b.clone();
+                let expected = ADDITION_MATRIX[row]
+                    .split_whitespace()
+                    .nth(column)
+                    .expect("should have a column per kind");
+                assert_eq!(
+                    kind(&sum),
+                    expected,
+                    "{} + {} gave {:?}",
+                    kind(a),
+                    kind(b),
+                    sum
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn add_keys_then_write_of_cl_value_should_fail() {
+        let cl_value = CLValue::from_t(ONE_U64).unwrap();
+        let expected_error =
+            TypeMismatch::new("Contract or Account".to_string(), "U64".to_string());
+        assert_eq!(
+            Transform::AddKeys(BTreeMap::new()) + Transform::Write(StoredValue::CLValue(cl_value)),
+            Transform::Failure(expected_error.into())
+        );
+    }
 }
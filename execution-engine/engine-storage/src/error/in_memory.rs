@@ -11,6 +11,9 @@ pub enum Error {
 
     #[fail(display = "Another thread panicked while holding a lock")]
     Poison,
+
+    #[fail(display = "Unknown or invalidated checkpoint")]
+    UnknownCheckpoint,
 }
 
 impl From<bytesrepr::Error> for Error {
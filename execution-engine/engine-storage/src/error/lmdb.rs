@@ -17,6 +17,9 @@ pub enum Error {
 
     #[fail(display = "Another thread panicked while holding a lock")]
     Poison,
+
+    #[fail(display = "Unknown or invalidated checkpoint")]
+    UnknownCheckpoint,
 }
 
 impl wasmi::HostError for Error {}
@@ -44,6 +47,7 @@ impl From<in_memory::Error> for Error {
         match error {
             in_memory::Error::BytesRepr(error) => Error::BytesRepr(error),
             in_memory::Error::Poison => Error::Poison,
+            in_memory::Error::UnknownCheckpoint => Error::UnknownCheckpoint,
         }
     }
 }
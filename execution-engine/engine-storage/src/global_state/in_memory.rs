@@ -10,18 +10,18 @@ use types::{Key, ProtocolVersion};
 
 use crate::{
     error::{self, in_memory},
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, CommitResult, ProvingStateReader, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::in_memory::InMemoryProtocolDataStore,
     store::Store,
     transaction_source::{
-        in_memory::{InMemoryEnvironment, InMemoryReadTransaction},
+        in_memory::{CheckpointId, InMemoryEnvironment, InMemoryReadTransaction},
         Transaction, TransactionSource,
     },
-    trie::{operations::create_hashed_empty_trie, Trie},
+    trie::{operations::create_hashed_empty_trie, Trie, TrieMerkleProof},
     trie_store::{
         in_memory::InMemoryTrieStore,
-        operations::{self, read, ReadResult, WriteResult},
+        operations::{self, read, read_with_proof, ReadResult, WriteResult},
     },
 };
 
@@ -106,6 +106,35 @@ impl InMemoryGlobalState {
         }
         Ok((state, current_root))
     }
+
+    /// Creates a copy of this state backed by a new environment, so that anything committed to the
+    /// copy is not visible in the original and vice versa.
+    pub fn deep_clone(&self) -> Result<Self, error::Error> {
+        let environment = Arc::new(self.environment.deep_clone()?);
+        Ok(InMemoryGlobalState::new(
+            environment,
+            Arc::clone(&self.trie_store),
+            Arc::clone(&self.protocol_data_store),
+            self.empty_root_hash,
+        ))
+    }
+
+    /// Takes a cheap checkpoint of this state, which can later be restored with
+    /// [`rollback_to`](InMemoryGlobalState::rollback_to).
+    ///
+    /// Checkpoints can be nested.
+    pub fn checkpoint(&self) -> Result<CheckpointId, error::Error> {
+        self.environment.checkpoint().map_err(Into::into)
+    }
+
+    /// Restores this state to what it was when `checkpoint` was taken, discarding everything
+    /// committed since, including the tries of any post-state hash returned in the meantime.
+    ///
+    /// Invalidates the checkpoints taken after `checkpoint`.  Rolling back to an invalidated
+    /// checkpoint returns [`error::Error::UnknownCheckpoint`].
+    pub fn rollback_to(&self, checkpoint: CheckpointId) -> Result<(), error::Error> {
+        self.environment.rollback_to(checkpoint).map_err(Into::into)
+    }
 }
 
 impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
@@ -139,6 +168,35 @@ impl StateReader<Key, StoredValue> for InMemoryGlobalStateView {
     }
 }
 
+impl ProvingStateReader<Key, StoredValue> for InMemoryGlobalStateView {
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_with_proof::<
+            Key,
+            StoredValue,
+            InMemoryReadTransaction,
+            InMemoryTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            ReadResult::Found(proof) => Some(proof),
+            ReadResult::NotFound => None,
+            ReadResult::RootNotFound => panic!("InMemoryGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+}
+
 impl StateProvider for InMemoryGlobalState {
     type Error = error::Error;
 
@@ -201,9 +259,19 @@ impl StateProvider for InMemoryGlobalState {
 
 #[cfg(test)]
 mod tests {
-    use types::{account::PublicKey, CLValue};
+    use std::collections::{BTreeMap, HashMap};
+
+    use proptest::{
+        collection,
+        prelude::{any, proptest, Just, Strategy},
+        prop_oneof,
+    };
+
+    use engine_shared::TypeMismatch;
+    use types::{account::PublicKey, AccessRights, CLValue, URef};
 
     use super::*;
+    use crate::{trie, trie_store::operations::WriteStats};
 
     #[derive(Debug, Clone)]
     struct TestPair {
@@ -253,6 +321,33 @@ mod tests {
         .unwrap()
     }
 
+    fn commit_effects(
+        state: &InMemoryGlobalState,
+        prestate_hash: Blake2bHash,
+        effects: AdditiveMap<Key, Transform>,
+    ) -> Blake2bHash {
+        match state
+            .commit(CorrelationId::new(), prestate_hash, effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        }
+    }
+
+    fn commit_pairs(
+        state: &InMemoryGlobalState,
+        prestate_hash: Blake2bHash,
+        pairs: &[TestPair],
+    ) -> Blake2bHash {
+        let effects = pairs
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, Transform::Write(value)))
+            .collect();
+        commit_effects(state, prestate_hash, effects)
+    }
+
     #[test]
     fn reads_from_a_checkout_return_expected_values() {
         let correlation_id = CorrelationId::new();
@@ -263,6 +358,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reads_with_proof_from_a_checkout_return_valid_proofs() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            let proof = checkout
+                .read_with_proof(correlation_id, &key)
+                .unwrap()
+                .expect("should have proof");
+            assert_eq!(proof.value(), &value);
+            assert!(trie::validate_proof(&root_hash, &proof, &key, &value));
+        }
+
+        let missing_key = Key::Account(PublicKey::ed25519_from([3u8; 32]));
+        let maybe_proof = checkout
+            .read_with_proof(correlation_id, &missing_key)
+            .unwrap();
+        assert!(maybe_proof.is_none());
+    }
+
     #[test]
     fn checkout_fails_if_unknown_hash_is_given() {
         let (state, _) = create_test_state();
@@ -343,6 +459,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn commit_deletes_state() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs = create_test_pairs();
+
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            tmp.insert(test_pairs[0].key, Transform::Delete);
+            tmp
+        };
+
+        let updated_hash = match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        let updated_checkout = state.checkout(updated_hash).unwrap().unwrap();
+        assert_eq!(
+            None,
+            updated_checkout
+                .read(correlation_id, &test_pairs[0].key)
+                .unwrap()
+        );
+        assert_eq!(
+            Some(test_pairs[1].value.clone()),
+            updated_checkout
+                .read(correlation_id, &test_pairs[1].key)
+                .unwrap()
+        );
+
+        // The resulting state is the same as one in which the deleted key was never written
+        let (_, expected_hash) = InMemoryGlobalState::from_pairs(
+            correlation_id,
+            &[(test_pairs[1].key, test_pairs[1].value.clone())],
+        )
+        .unwrap();
+        assert_eq!(expected_hash, updated_hash);
+
+        // Deleting a missing key leaves the state unchanged
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            tmp.insert(test_pairs[0].key, Transform::Delete);
+            tmp
+        };
+        match state.commit(correlation_id, updated_hash, effects).unwrap() {
+            CommitResult::Success { state_root, .. } => assert_eq!(state_root, updated_hash),
+            _ => panic!("commit failed"),
+        }
+    }
+
+    #[test]
+    fn commit_fails_with_type_mismatch_if_named_keys_are_added_to_a_written_value() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs = create_test_pairs();
+
+        let (state, root_hash) = create_test_state();
+
+        let mut effects: AdditiveMap<Key, Transform> = AdditiveMap::new();
+        effects.insert_add(test_pairs[0].key, Transform::AddKeys(Default::default()));
+        effects.insert_add(
+            test_pairs[0].key,
+            Transform::Write(test_pairs[1].value.clone()),
+        );
+
+        match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::TypeMismatch { key, type_mismatch } => {
+                assert_eq!(key, test_pairs[0].key);
+                assert_eq!(
+                    type_mismatch,
+                    TypeMismatch::new("Contract or Account".to_string(), "I32".to_string())
+                );
+            }
+            other => panic!("expected type mismatch but got {}", other),
+        }
+    }
+
+    #[test]
+    fn commit_of_empty_effects_keeps_state_root_and_writes_nothing() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let effects: AdditiveMap<Key, Transform> = AdditiveMap::new();
+
+        match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success {
+                state_root, stats, ..
+            } => {
+                assert_eq!(state_root, root_hash);
+                assert_eq!(stats, WriteStats::default());
+            }
+            other => panic!("expected success but got {}", other),
+        }
+    }
+
+    #[test]
+    fn commit_of_non_empty_effects_changes_state_root_and_reports_written_nodes() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+
+        let effects: AdditiveMap<Key, Transform> = create_test_pairs_updated()
+            .iter()
+            .cloned()
+            .map(|TestPair { key, value }| (key, Transform::Write(value)))
+            .collect();
+
+        match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success {
+                state_root, stats, ..
+            } => {
+                assert_ne!(state_root, root_hash);
+                assert!(stats.new_nodes > 0);
+                assert!(stats.bytes_written > 0);
+            }
+            other => panic!("expected success but got {}", other),
+        }
+    }
+
+    #[test]
+    fn commit_fails_with_key_not_found_naming_the_missing_key() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let missing_key = Key::Account(PublicKey::ed25519_from([9u8; 32]));
+
+        let mut effects: AdditiveMap<Key, Transform> = AdditiveMap::new();
+        effects.insert(missing_key, Transform::AddInt32(1));
+
+        match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::KeyNotFound(key) => assert_eq!(key, missing_key),
+            other => panic!("expected key not found but got {}", other),
+        }
+    }
+
+    #[test]
+    fn commit_to_deep_clone_leaves_original_state_intact() {
+        let correlation_id = CorrelationId::new();
+        let test_pairs_updated = create_test_pairs_updated();
+
+        let (state, root_hash) = create_test_state();
+        let cloned_state = state.deep_clone().unwrap();
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &test_pairs_updated {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+
+        let updated_hash = match cloned_state
+            .commit(correlation_id, root_hash, effects)
+            .unwrap()
+        {
+            CommitResult::Success { state_root, .. } => state_root,
+            _ => panic!("commit failed"),
+        };
+
+        assert!(cloned_state.checkout(updated_hash).unwrap().is_some());
+        assert!(state.checkout(updated_hash).unwrap().is_none());
+
+        let original_checkout = cloned_state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            assert_eq!(
+                Some(value),
+                original_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+    }
+
     #[test]
     fn initial_state_has_the_expected_hash() {
         let correlation_id = CorrelationId::new();
@@ -353,4 +638,220 @@ mod tests {
         let (_, root_hash) = InMemoryGlobalState::from_pairs(correlation_id, &[]).unwrap();
         assert_eq!(expected_bytes, root_hash.to_vec())
     }
+
+    #[test]
+    fn rollback_discards_commits_since_checkpoint() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let checkpoint = state.checkpoint().unwrap();
+
+        let updated_hash = commit_pairs(&state, root_hash, &create_test_pairs_updated());
+        assert!(state.checkout(updated_hash).unwrap().is_some());
+
+        state.rollback_to(checkpoint).unwrap();
+        assert!(state.checkout(updated_hash).unwrap().is_none());
+        let original_checkout = state.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            assert_eq!(
+                Some(value),
+                original_checkout.read(correlation_id, &key).unwrap()
+            );
+        }
+
+        // The checkpoint can be rolled back to again
+        let updated_again_hash = commit_pairs(&state, root_hash, &create_test_pairs_updated());
+        assert_eq!(updated_again_hash, updated_hash);
+        state.rollback_to(checkpoint).unwrap();
+        assert!(state.checkout(updated_hash).unwrap().is_none());
+    }
+
+    #[test]
+    fn rollback_to_earlier_checkpoint_invalidates_later_ones() {
+        let test_pairs_updated = create_test_pairs_updated();
+        let (state, root_hash) = create_test_state();
+
+        let first_checkpoint = state.checkpoint().unwrap();
+        let first_hash = commit_pairs(&state, root_hash, &test_pairs_updated[..1]);
+        let second_checkpoint = state.checkpoint().unwrap();
+        let second_hash = commit_pairs(&state, first_hash, &test_pairs_updated[1..]);
+
+        state.rollback_to(second_checkpoint).unwrap();
+        assert!(state.checkout(first_hash).unwrap().is_some());
+        assert!(state.checkout(second_hash).unwrap().is_none());
+
+        let third_checkpoint = state.checkpoint().unwrap();
+        assert_eq!(
+            commit_pairs(&state, first_hash, &test_pairs_updated[1..]),
+            second_hash
+        );
+
+        state.rollback_to(first_checkpoint).unwrap();
+        assert!(state.checkout(root_hash).unwrap().is_some());
+        assert!(state.checkout(first_hash).unwrap().is_none());
+        assert!(state.checkout(second_hash).unwrap().is_none());
+
+        for checkpoint in &[second_checkpoint, third_checkpoint] {
+            assert_eq!(
+                state.rollback_to(*checkpoint),
+                Err(error::Error::UnknownCheckpoint)
+            );
+        }
+        state.rollback_to(first_checkpoint).unwrap();
+    }
+
+    #[test]
+    fn rollback_restores_protocol_data() {
+        let (state, _) = create_test_state();
+        let original = ProtocolData::default();
+        let updated = ProtocolData::partial_with_mint(URef::new([1; 32], AccessRights::READ));
+        let next_version = ProtocolVersion::from_parts(2, 0, 0);
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &original)
+            .unwrap();
+
+        let checkpoint = state.checkpoint().unwrap();
+        state
+            .put_protocol_data(ProtocolVersion::V1_0_0, &updated)
+            .unwrap();
+        state.put_protocol_data(next_version, &updated).unwrap();
+
+        state.rollback_to(checkpoint).unwrap();
+        assert_eq!(
+            state.get_protocol_data(ProtocolVersion::V1_0_0).unwrap(),
+            Some(original)
+        );
+        assert_eq!(state.get_protocol_data(next_version).unwrap(), None);
+    }
+
+    const TOTAL_ORACLE_KEYS: u8 = 4;
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Write(u8, i32),
+        Delete(u8),
+        Checkpoint,
+        Rollback(usize),
+    }
+
+    fn op_arb() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            (0..TOTAL_ORACLE_KEYS, any::<i32>()).prop_map(|(index, value)| Op::Write(index, value)),
+            (0..TOTAL_ORACLE_KEYS).prop_map(Op::Delete),
+            Just(Op::Checkpoint),
+            any::<usize>().prop_map(Op::Rollback),
+        ]
+    }
+
+    fn oracle_key(index: u8) -> Key {
+        Key::Account(PublicKey::ed25519_from([index; 32]))
+    }
+
+    /// A checkpoint of the oracle, which naively copies the whole environment.
+    struct Snapshot {
+        id: CheckpointId,
+        root_hash: Blake2bHash,
+        values: BTreeMap<u8, i32>,
+        data: Option<HashMap<Vec<u8>, Vec<u8>>>,
+    }
+
+    fn check_against_oracle(ops: Vec<Op>) {
+        let correlation_id = CorrelationId::new();
+        let state = InMemoryGlobalState::empty().unwrap();
+        let mut root_hash = state.empty_root_hash;
+        let mut values = BTreeMap::new();
+        let mut issued = Vec::new();
+        let mut snapshots: Vec<Snapshot> = Vec::new();
+
+        for op in ops {
+            match op {
+                Op::Write(index, value) => {
+                    let stored_value = StoredValue::CLValue(CLValue::from_t(value).unwrap());
+                    let mut effects = AdditiveMap::new();
+                    effects.insert(oracle_key(index), Transform::Write(stored_value));
+                    root_hash = commit_effects(&state, root_hash, effects);
+                    values.insert(index, value);
+                }
+                Op::Delete(index) => {
+                    let mut effects = AdditiveMap::new();
+                    effects.insert(oracle_key(index), Transform::Delete);
+                    root_hash = commit_effects(&state, root_hash, effects);
+                    values.remove(&index);
+                }
+                Op::Checkpoint => {
+                    let id = state.checkpoint().unwrap();
+                    issued.push(id);
+                    snapshots.push(Snapshot {
+                        id,
+                        root_hash,
+                        values: values.clone(),
+                        data: state.environment.data(None).unwrap(),
+                    });
+                }
+                Op::Rollback(_) if issued.is_empty() => (),
+                Op::Rollback(choice) => {
+                    let id = issued[choice % issued.len()];
+                    match snapshots.iter().position(|snapshot| snapshot.id == id) {
+                        Some(position) => {
+                            state.rollback_to(id).unwrap();
+                            snapshots.truncate(position + 1);
+                            let snapshot = &snapshots[position];
+                            root_hash = snapshot.root_hash;
+                            values = snapshot.values.clone();
+                            assert_eq!(state.environment.data(None).unwrap(), snapshot.data);
+                        }
+                        None => {
+                            assert_eq!(state.rollback_to(id), Err(error::Error::UnknownCheckpoint))
+                        }
+                    }
+                }
+            }
+
+            let checkout = state
+                .checkout(root_hash)
+                .unwrap()
+                .expect("should have root");
+            for index in 0..TOTAL_ORACLE_KEYS {
+                let expected = values
+                    .get(&index)
+                    .map(|value| StoredValue::CLValue(CLValue::from_t(*value).unwrap()));
+                let actual = checkout.read(correlation_id, &oracle_key(index)).unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_checkpoints_match_clone_oracle(ops in collection::vec(op_arb(), 0..32)) {
+            check_against_oracle(ops)
+        }
+
+        #[test]
+        fn prop_insertion_order_does_not_affect_post_state_hash(
+            values in collection::btree_map(any::<u8>(), any::<i32>(), 0..32),
+            rotation in any::<usize>(),
+        ) {
+            let writes: Vec<(Key, Transform)> = values
+                .into_iter()
+                .map(|(index, value)| {
+                    let value = StoredValue::CLValue(CLValue::from_t(value).unwrap());
+                    (oracle_key(index), Transform::Write(value))
+                })
+                .collect();
+            let mut rotated_writes = writes.clone();
+            if !rotated_writes.is_empty() {
+                let mid = rotation % rotated_writes.len();
+                rotated_writes.rotate_left(mid);
+            }
+
+            let (state, root_hash) = create_test_state();
+            let expected_hash = commit_effects(&state, root_hash, writes.iter().cloned().collect());
+            let rotated_hash =
+                commit_effects(&state, root_hash, rotated_writes.into_iter().collect());
+            let reversed_hash =
+                commit_effects(&state, root_hash, writes.into_iter().rev().collect());
+            assert_eq!(rotated_hash, expected_hash);
+            assert_eq!(reversed_hash, expected_hash);
+        }
+    }
 }
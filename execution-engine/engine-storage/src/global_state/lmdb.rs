@@ -1,4 +1,11 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Deref,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use lmdb::{Cursor, Database, DatabaseFlags, RoTransaction};
 
 use engine_shared::{
     additive_map::AdditiveMap,
@@ -6,27 +13,47 @@ use engine_shared::{
     stored_value::StoredValue,
     transform::Transform,
 };
-use types::{Key, ProtocolVersion};
+use types::{
+    bytesrepr::{self, ToBytes},
+    Key, ProtocolVersion,
+};
 
 use crate::{
     error,
-    global_state::{commit, CommitResult, StateProvider, StateReader},
+    global_state::{commit, CommitResult, ProvingStateReader, StateProvider, StateReader},
     protocol_data::ProtocolData,
     protocol_data_store::lmdb::LmdbProtocolDataStore,
     store::Store,
-    transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
-    trie::{operations::create_hashed_empty_trie, Trie},
+    transaction_source::{
+        lmdb::{LmdbEnvironment, StoreStats},
+        Readable, Transaction, TransactionSource, Writable,
+    },
+    trie::{operations::create_hashed_empty_trie, Pointer, Trie, TrieMerkleProof, RADIX},
     trie_store::{
         lmdb::LmdbTrieStore,
-        operations::{read, ReadResult},
+        operations::{read, read_with_proof, ReadResult},
     },
 };
 
+const ROOT_HISTORY_NAME: &str = "ROOT_HISTORY";
+
+/// The most tries [`LmdbGlobalState::prune_roots`] deletes in a single write transaction, so that
+/// no transaction has to hold the dirty pages of a whole prune.
+pub const PRUNE_BATCH_SIZE: usize = 1_000;
+
+#[derive(Clone)]
 pub struct LmdbGlobalState {
     pub environment: Arc<LmdbEnvironment>,
     pub trie_store: Arc<LmdbTrieStore>,
     pub protocol_data_store: Arc<LmdbProtocolDataStore>,
     pub empty_root_hash: Blake2bHash,
+    /// Maps each committed root to the sequence number of its latest commit, by which the oldest
+    /// roots are pruned, or to `None` once pruning has started deleting its tries.
+    root_history: Database,
+    roots: Arc<Mutex<RootRegistry>>,
+    /// Held by commits and by pruning, so that a commit can't reuse a trie which pruning is about
+    /// to delete.
+    commit_lock: Arc<Mutex<()>>,
 }
 
 /// Represents a "view" of global state at a particular root hash.
@@ -34,6 +61,53 @@ pub struct LmdbGlobalStateView {
     pub environment: Arc<LmdbEnvironment>,
     pub store: Arc<LmdbTrieStore>,
     pub root_hash: Blake2bHash,
+    /// Keeps the root from being pruned while the view, e.g. a tracking copy's, is in use.
+    _pin: RootPin,
+}
+
+/// The roots of an [`LmdbGlobalState`] which are pinned or being pruned.
+#[derive(Debug, Default)]
+struct RootRegistry {
+    /// The number of pins held on each pinned root.
+    pins: HashMap<Blake2bHash, usize>,
+    /// The roots whose tries [`LmdbGlobalState::prune_roots`] is deleting, or has started to
+    /// delete without finishing.
+    pruning: HashSet<Blake2bHash>,
+    /// The sequence number the next committed root is recorded with.
+    next_sequence: u64,
+}
+
+/// Keeps [`LmdbGlobalState::prune_roots`] from pruning a root for as long as it is held.
+#[derive(Debug)]
+pub struct RootPin {
+    roots: Arc<Mutex<RootRegistry>>,
+    root_hash: Blake2bHash,
+}
+
+impl Drop for RootPin {
+    fn drop(&mut self) {
+        // A poisoned registry is never pruned from again, so the pin needn't be released
+        if let Ok(mut roots) = self.roots.lock() {
+            if let Some(count) = roots.pins.get_mut(&self.root_hash) {
+                *count -= 1;
+                if *count == 0 {
+                    roots.pins.remove(&self.root_hash);
+                }
+            }
+        }
+    }
+}
+
+/// The outcome of [`LmdbGlobalState::prune_roots`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum PruneResult {
+    /// The roots were pruned, along with the tries reachable only from them.
+    Pruned {
+        pruned_roots: Vec<Blake2bHash>,
+        deleted_tries: usize,
+    },
+    /// Nothing was pruned, as the given root would have been but is pinned.
+    RootPinned(Blake2bHash),
 }
 
 impl LmdbGlobalState {
@@ -50,12 +124,7 @@ impl LmdbGlobalState {
             txn.commit()?;
             root_hash
         };
-        Ok(LmdbGlobalState::new(
-            environment,
-            trie_store,
-            protocol_data_store,
-            root_hash,
-        ))
+        LmdbGlobalState::new(environment, trie_store, protocol_data_store, root_hash)
     }
 
     /// Creates a state from an existing environment, store, and root_hash.
@@ -65,14 +134,313 @@ impl LmdbGlobalState {
         trie_store: Arc<LmdbTrieStore>,
         protocol_data_store: Arc<LmdbProtocolDataStore>,
         empty_root_hash: Blake2bHash,
-    ) -> Self {
-        LmdbGlobalState {
+    ) -> Result<Self, error::Error> {
+        let root_history = environment
+            .env()
+            .create_db(Some(ROOT_HISTORY_NAME), DatabaseFlags::empty())?;
+        let history = {
+            let mut txn = environment.create_read_write_txn()?;
+            let mut history = read_root_history(&txn, root_history)?;
+            if history.is_empty() {
+                // The store may predate the root history, so its roots are recorded, as committed
+                // before any root committed since
+                let sequence = Some(0u64).to_bytes()?;
+                for root_hash in find_roots(&txn, &trie_store)? {
+                    if root_hash != empty_root_hash {
+                        txn.write(root_history, &root_hash.to_bytes()?, &sequence)?;
+                        history.insert(root_hash, Some(0));
+                    }
+                }
+            }
+            txn.commit()?;
+            history
+        };
+        let next_sequence = history
+            .values()
+            .flatten()
+            .max()
+            .map_or(0, |sequence| sequence + 1);
+        // Left over from an interrupted prune, so may have lost tries already
+        let pruning = history
+            .iter()
+            .filter(|(_, sequence)| sequence.is_none())
+            .map(|(root_hash, _)| *root_hash)
+            .collect();
+        let roots = RootRegistry {
+            pruning,
+            next_sequence,
+            ..Default::default()
+        };
+        Ok(LmdbGlobalState {
             environment,
             trie_store,
             protocol_data_store,
             empty_root_hash,
+            root_history,
+            roots: Arc::new(Mutex::new(roots)),
+            commit_lock: Arc::default(),
+        })
+    }
+
+    /// Returns the statistics of the trie store.
+    pub fn stats(&self) -> Result<StoreStats, error::Error> {
+        let db =
+            <LmdbTrieStore as Store<Blake2bHash, Trie<Key, StoredValue>>>::handle(&self.trie_store);
+        self.environment.stats(db)
+    }
+
+    /// Writes a compacted copy of the environment, holding both the trie store and the protocol
+    /// data store, into the existing, empty directory `target_path`.
+    pub fn compact(&self, target_path: &Path) -> Result<(), error::Error> {
+        self.environment.compact(target_path)
+    }
+
+    /// Pins `root_hash`, so that [`LmdbGlobalState::prune_roots`] refuses to prune it until the
+    /// returned pin is dropped.  Views returned by `checkout` hold a pin of their own.
+    ///
+    /// Returns `None` if `root_hash` is being pruned.
+    pub fn pin_root(&self, root_hash: Blake2bHash) -> Result<Option<RootPin>, error::Error> {
+        let mut roots = self.roots.lock()?;
+        if roots.pruning.contains(&root_hash) {
+            return Ok(None);
+        }
+        *roots.pins.entry(root_hash).or_default() += 1;
+        Ok(Some(RootPin {
+            roots: Arc::clone(&self.roots),
+            root_hash,
+        }))
+    }
+
+    /// Prunes all but the `keep_latest` most recently committed roots, deleting the tries which
+    /// are reachable from pruned roots only.  The empty root is never pruned, and tries reachable
+    /// from a pinned root are kept.
+    ///
+    /// Nothing is pruned if a root which would be is pinned, e.g. by the view of a tracking copy.
+    /// Commits wait for pruning to finish, but reads go on, and tries are deleted in write
+    /// transactions of at most [`PRUNE_BATCH_SIZE`] tries each.
+    ///
+    /// The roots already in the store when the root history is created count as committed together,
+    /// before any root committed since, and roots committed together are kept or pruned together.
+    /// A root whose pruning is interrupted, e.g. by an error or a restart, is missing to `checkout`
+    /// and `commit` until a later prune finishes it.
+    pub fn prune_roots(&self, keep_latest: usize) -> Result<PruneResult, error::Error> {
+        let _commit_guard = self.commit_lock.lock()?;
+
+        let txn = self.environment.create_read_txn()?;
+        let mut history: Vec<(Blake2bHash, u64)> = Vec::new();
+        let mut interrupted_roots: Vec<Blake2bHash> = Vec::new();
+        for (root_hash, maybe_sequence) in read_root_history(&txn, self.root_history)? {
+            match maybe_sequence {
+                Some(sequence) => history.push((root_hash, sequence)),
+                None => interrupted_roots.push(root_hash),
+            }
+        }
+        // Latest first
+        history.sort_by(|(_, sequence_1), (_, sequence_2)| sequence_2.cmp(sequence_1));
+        let mut kept_count = keep_latest.min(history.len());
+        while kept_count > 0
+            && kept_count < history.len()
+            && history[kept_count].1 == history[kept_count - 1].1
+        {
+            kept_count += 1;
+        }
+        let (kept_history, pruned_history) = history.split_at(kept_count);
+        let pruned_roots: Vec<Blake2bHash> = pruned_history
+            .iter()
+            .map(|(root_hash, _)| *root_hash)
+            .chain(interrupted_roots)
+            .collect();
+        let mut kept_roots: Vec<Blake2bHash> = kept_history
+            .iter()
+            .map(|(root_hash, _)| *root_hash)
+            .collect();
+        kept_roots.push(self.empty_root_hash);
+
+        {
+            let mut roots = self.roots.lock()?;
+            if let Some(root_hash) = pruned_roots
+                .iter()
+                .find(|root_hash| roots.pins.contains_key(*root_hash))
+            {
+                return Ok(PruneResult::RootPinned(*root_hash));
+            }
+            kept_roots.extend(roots.pins.keys());
+            roots.pruning.extend(&pruned_roots);
         }
+
+        // Roots stay missing if deleting fails, as some of their tries may be deleted already
+        let deleted_tries = self.delete_pruned_tries(txn, &kept_roots, &pruned_roots)?;
+        self.roots.lock()?.pruning.clear();
+
+        Ok(PruneResult::Pruned {
+            pruned_roots,
+            deleted_tries,
+        })
+    }
+
+    /// Marks `pruned_roots` as pruning in the root history, deletes the tries reachable from them
+    /// but not from `kept_roots`, and then their entries in the root history, returning the number
+    /// of tries deleted.
+    fn delete_pruned_tries(
+        &self,
+        txn: RoTransaction,
+        kept_roots: &[Blake2bHash],
+        pruned_roots: &[Blake2bHash],
+    ) -> Result<usize, error::Error> {
+        let kept_tries = self.reachable_tries(&txn, kept_roots, &HashSet::new())?;
+        let pruned_tries: Vec<Blake2bHash> = self
+            .reachable_tries(&txn, pruned_roots, &kept_tries)?
+            .into_iter()
+            .collect();
+        txn.commit()?;
+
+        // Marked first, so that roots which lose tries are known to be missing after a restart too
+        let mut txn = self.environment.create_read_write_txn()?;
+        let pruning = None::<u64>.to_bytes()?;
+        for root_hash in pruned_roots {
+            txn.write(self.root_history, &root_hash.to_bytes()?, &pruning)?;
+        }
+        txn.commit()?;
+
+        let trie_db =
+            <LmdbTrieStore as Store<Blake2bHash, Trie<Key, StoredValue>>>::handle(&self.trie_store);
+        let mut deleted_tries = 0;
+        for batch in pruned_tries.chunks(PRUNE_BATCH_SIZE) {
+            let mut txn = self.environment.create_read_write_txn()?;
+            for trie_hash in batch {
+                match txn.del(trie_db, &trie_hash.to_bytes()?, None) {
+                    Ok(()) => deleted_tries += 1,
+                    Err(lmdb::Error::NotFound) => (),
+                    Err(error) => return Err(error.into()),
+                }
+            }
+            txn.commit()?;
+        }
+
+        // The history goes last, so that an interrupted prune is picked up again by the next one
+        let mut txn = self.environment.create_read_write_txn()?;
+        for root_hash in pruned_roots {
+            match txn.del(self.root_history, &root_hash.to_bytes()?, None) {
+                Ok(()) | Err(lmdb::Error::NotFound) => (),
+                Err(error) => return Err(error.into()),
+            }
+        }
+        txn.commit()?;
+
+        Ok(deleted_tries)
+    }
+
+    /// Returns the hashes of the tries reachable from `root_hashes`, without descending into those
+    /// in `excluded`.  Missing tries, e.g. those deleted by an interrupted prune, are skipped.
+    fn reachable_tries(
+        &self,
+        txn: &RoTransaction,
+        root_hashes: &[Blake2bHash],
+        excluded: &HashSet<Blake2bHash>,
+    ) -> Result<HashSet<Blake2bHash>, error::Error> {
+        let mut reachable = HashSet::new();
+        let mut pending = root_hashes.to_vec();
+        while let Some(trie_hash) = pending.pop() {
+            if excluded.contains(&trie_hash) || !reachable.insert(trie_hash) {
+                continue;
+            }
+            let maybe_trie: Option<Trie<Key, StoredValue>> =
+                self.trie_store.get(txn, &trie_hash)?;
+            let pointers: Vec<Pointer> = match maybe_trie {
+                Some(Trie::Node { pointer_block }) => (0..RADIX)
+                    .filter_map(|index| pointer_block[index])
+                    .collect(),
+                Some(Trie::Extension { pointer, .. }) => vec![pointer],
+                Some(Trie::Leaf { .. }) | None => continue,
+            };
+            for pointer in pointers {
+                match pointer {
+                    // Leaves have no children, so needn't be read
+                    Pointer::LeafPointer(leaf_hash) if !excluded.contains(&leaf_hash) => {
+                        reachable.insert(leaf_hash);
+                    }
+                    Pointer::LeafPointer(_) => (),
+                    Pointer::NodePointer(node_hash) => pending.push(node_hash),
+                }
+            }
+        }
+        Ok(reachable)
     }
+
+    /// Records `state_root` as committed from `prestate_hash`.  The prestate is recorded first if
+    /// it predates the root history, so that the tries reachable from it are known to pruning.
+    fn record_commit(
+        &self,
+        prestate_hash: Blake2bHash,
+        state_root: Blake2bHash,
+    ) -> Result<(), error::Error> {
+        let mut roots = self.roots.lock()?;
+        let mut txn = self.environment.create_read_write_txn()?;
+        let mut root_hashes = Vec::with_capacity(2);
+        if txn
+            .read(self.root_history, &prestate_hash.to_bytes()?)?
+            .is_none()
+        {
+            root_hashes.push(prestate_hash);
+        }
+        // Recommitting a root, e.g. with empty effects, makes it the latest again
+        root_hashes.push(state_root);
+        for root_hash in root_hashes {
+            if root_hash == self.empty_root_hash {
+                continue;
+            }
+            let sequence = Some(roots.next_sequence).to_bytes()?;
+            txn.write(self.root_history, &root_hash.to_bytes()?, &sequence)?;
+            roots.next_sequence += 1;
+        }
+        txn.commit()?;
+        Ok(())
+    }
+}
+
+/// Reads the root history, mapping each root to the sequence number of its latest commit, or to
+/// `None` if it is being pruned.
+fn read_root_history<T: lmdb::Transaction>(
+    txn: &T,
+    root_history: Database,
+) -> Result<HashMap<Blake2bHash, Option<u64>>, error::Error> {
+    let mut cursor = lmdb::Transaction::open_ro_cursor(txn, root_history)?;
+    cursor
+        .iter_start()
+        .map(|(key, value)| {
+            let root_hash = bytesrepr::deserialize(key.to_vec())?;
+            let sequence = bytesrepr::deserialize(value.to_vec())?;
+            Ok((root_hash, sequence))
+        })
+        .collect()
+}
+
+/// Returns the hashes of the nodes and extensions in `trie_store` which no other trie points to.
+fn find_roots<T: lmdb::Transaction>(
+    txn: &T,
+    trie_store: &LmdbTrieStore,
+) -> Result<HashSet<Blake2bHash>, error::Error> {
+    let trie_db = <LmdbTrieStore as Store<Blake2bHash, Trie<Key, StoredValue>>>::handle(trie_store);
+    let mut roots = HashSet::new();
+    let mut children = HashSet::new();
+    let mut cursor = lmdb::Transaction::open_ro_cursor(txn, trie_db)?;
+    for (key, value) in cursor.iter_start() {
+        let trie: Trie<Key, StoredValue> = bytesrepr::deserialize(value.to_vec())?;
+        match trie {
+            Trie::Node { pointer_block } => children.extend(
+                (0..RADIX)
+                    .filter_map(|index| pointer_block[index])
+                    .map(|pointer| *pointer.hash()),
+            ),
+            Trie::Extension { pointer, .. } => {
+                children.insert(*pointer.hash());
+            }
+            Trie::Leaf { .. } => continue,
+        }
+        roots.insert(bytesrepr::deserialize(key.to_vec())?);
+    }
+    roots.retain(|root_hash| !children.contains(root_hash));
+    Ok(roots)
 }
 
 impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
@@ -100,18 +468,52 @@ impl StateReader<Key, StoredValue> for LmdbGlobalStateView {
     }
 }
 
+impl ProvingStateReader<Key, StoredValue> for LmdbGlobalStateView {
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, Self::Error> {
+        let txn = self.environment.create_read_txn()?;
+        let ret = match read_with_proof::<
+            Key,
+            StoredValue,
+            lmdb::RoTransaction,
+            LmdbTrieStore,
+            Self::Error,
+        >(
+            correlation_id,
+            &txn,
+            self.store.deref(),
+            &self.root_hash,
+            key,
+        )? {
+            ReadResult::Found(proof) => Some(proof),
+            ReadResult::NotFound => None,
+            ReadResult::RootNotFound => panic!("LmdbGlobalState has invalid root"),
+        };
+        txn.commit()?;
+        Ok(ret)
+    }
+}
+
 impl StateProvider for LmdbGlobalState {
     type Error = error::Error;
 
     type Reader = LmdbGlobalStateView;
 
     fn checkout(&self, state_hash: Blake2bHash) -> Result<Option<Self::Reader>, Self::Error> {
+        let pin = match self.pin_root(state_hash)? {
+            Some(pin) => pin,
+            None => return Ok(None),
+        };
         let txn = self.environment.create_read_txn()?;
         let maybe_root: Option<Trie<Key, StoredValue>> = self.trie_store.get(&txn, &state_hash)?;
         let maybe_state = maybe_root.map(|_| LmdbGlobalStateView {
             environment: Arc::clone(&self.environment),
             store: Arc::clone(&self.trie_store),
             root_hash: state_hash,
+            _pin: pin,
         });
         txn.commit()?;
         Ok(maybe_state)
@@ -123,6 +525,11 @@ impl StateProvider for LmdbGlobalState {
         prestate_hash: Blake2bHash,
         effects: AdditiveMap<Key, Transform>,
     ) -> Result<CommitResult, Self::Error> {
+        let _commit_guard = self.commit_lock.lock()?;
+        // Left over from an interrupted prune, so may have lost tries already
+        if self.roots.lock()?.pruning.contains(&prestate_hash) {
+            return Ok(CommitResult::RootNotFound);
+        }
         let commit_result = commit::<LmdbEnvironment, LmdbTrieStore, _, Self::Error>(
             &self.environment,
             &self.trie_store,
@@ -130,6 +537,9 @@ impl StateProvider for LmdbGlobalState {
             prestate_hash,
             effects,
         )?;
+        if let CommitResult::Success { state_root, .. } = &commit_result {
+            self.record_commit(prestate_hash, *state_root)?;
+        }
         Ok(commit_result)
     }
 
@@ -161,7 +571,8 @@ impl StateProvider for LmdbGlobalState {
 
 #[cfg(test)]
 mod tests {
-    use lmdb::DatabaseFlags;
+    use std::iter;
+
     use tempfile::tempdir;
 
     use types::{account::PublicKey, CLValue};
@@ -249,6 +660,41 @@ mod tests {
         (ret, current_root)
     }
 
+    /// Commits `count` times on top of `root_hash`, each time overwriting the value of the first
+    /// test pair and adding a key, and returns the roots committed, oldest first.
+    fn commit_successive_roots(
+        state: &LmdbGlobalState,
+        root_hash: Blake2bHash,
+        count: u8,
+    ) -> Vec<Blake2bHash> {
+        let correlation_id = CorrelationId::new();
+        let mut current_root = root_hash;
+        let mut root_hashes = Vec::new();
+        for index in 0..count {
+            let value = StoredValue::CLValue(CLValue::from_t(i32::from(index)).unwrap());
+            let mut effects = AdditiveMap::new();
+            effects.insert(create_test_pairs()[0].key, Transform::Write(value.clone()));
+            effects.insert(Key::Hash([index; 32]), Transform::Write(value));
+            current_root = match state.commit(correlation_id, current_root, effects).unwrap() {
+                CommitResult::Success { state_root, .. } => state_root,
+                _ => panic!("commit failed"),
+            };
+            root_hashes.push(current_root);
+        }
+        root_hashes
+    }
+
+    /// Opens the environment of `state` again, as on a restart.
+    fn reopen(state: &LmdbGlobalState) -> LmdbGlobalState {
+        LmdbGlobalState::new(
+            Arc::clone(&state.environment),
+            Arc::clone(&state.trie_store),
+            Arc::clone(&state.protocol_data_store),
+            state.empty_root_hash,
+        )
+        .unwrap()
+    }
+
     #[test]
     fn reads_from_a_checkout_return_expected_values() {
         let correlation_id = CorrelationId::new();
@@ -259,6 +705,225 @@ mod tests {
         }
     }
 
+    #[test]
+    fn stats_count_tries_written() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let stats = state.stats().unwrap();
+        assert!(stats.entries > 0);
+        assert!(stats.leaf_pages > 0);
+        assert!(stats.map_size_used <= stats.map_size);
+
+        let effects: AdditiveMap<Key, Transform> = {
+            let mut tmp = AdditiveMap::new();
+            for TestPair { key, value } in &create_test_pairs_updated() {
+                tmp.insert(*key, Transform::Write(value.to_owned()));
+            }
+            tmp
+        };
+        match state.commit(correlation_id, root_hash, effects).unwrap() {
+            CommitResult::Success { .. } => (),
+            _ => panic!("commit failed"),
+        };
+
+        // Tries are never deleted, so the updated leaves and nodes are all additional entries
+        assert!(state.stats().unwrap().entries > stats.entries);
+    }
+
+    #[test]
+    fn compacted_copy_holds_same_state() {
+        let correlation_id = CorrelationId::new();
+        let (state, root_hash) = create_test_state();
+        let target_dir = tempdir().unwrap();
+        state.compact(target_dir.path()).unwrap();
+
+        let environment = Arc::new(
+            LmdbEnvironment::new(&target_dir.path().to_path_buf(), *TEST_MAP_SIZE).unwrap(),
+        );
+        let trie_store = Arc::new(LmdbTrieStore::open(&environment, None).unwrap());
+        let protocol_data_store =
+            Arc::new(LmdbProtocolDataStore::open(&environment, None).unwrap());
+        let compacted = LmdbGlobalState::new(
+            environment,
+            trie_store,
+            protocol_data_store,
+            state.empty_root_hash,
+        )
+        .unwrap();
+
+        assert_eq!(
+            compacted.stats().unwrap().entries,
+            state.stats().unwrap().entries
+        );
+        let checkout = compacted.checkout(root_hash).unwrap().unwrap();
+        for TestPair { key, value } in create_test_pairs().iter().cloned() {
+            assert_eq!(Some(value), checkout.read(correlation_id, &key).unwrap());
+        }
+    }
+
+    #[test]
+    fn prune_roots_deletes_tries_reachable_from_pruned_roots_only() {
+        let correlation_id = CorrelationId::new();
+        let (state, test_root) = create_test_state();
+        let root_hashes = commit_successive_roots(&state, test_root, 20);
+        let entries = state.stats().unwrap().entries;
+
+        let (pruned_roots, deleted_tries) = match state.prune_roots(5).unwrap() {
+            PruneResult::Pruned {
+                pruned_roots,
+                deleted_tries,
+            } => (pruned_roots, deleted_tries),
+            result => panic!("unexpected prune result: {:?}", result),
+        };
+        // The test state's root is recorded as the prestate of the first commit, so is pruned too
+        assert_eq!(pruned_roots.len(), 16);
+        assert!(pruned_roots.contains(&test_root));
+        assert!(deleted_tries > 0);
+        assert_eq!(state.stats().unwrap().entries, entries - deleted_tries);
+
+        let overwritten_key = create_test_pairs()[0].key;
+        let first_added_key = Key::Hash([0; 32]);
+        for (index, root_hash) in root_hashes.iter().enumerate().skip(15) {
+            let checkout = state.checkout(*root_hash).unwrap().unwrap();
+            let expected_value = StoredValue::CLValue(CLValue::from_t(index as i32).unwrap());
+            let value = checkout.read(correlation_id, &overwritten_key).unwrap();
+            assert_eq!(value, Some(expected_value));
+            // Tries written by pruned commits are kept while still reachable
+            assert!(checkout
+                .read(correlation_id, &first_added_key)
+                .unwrap()
+                .is_some());
+        }
+
+        for root_hash in root_hashes.iter().take(15).chain(iter::once(&test_root)) {
+            assert!(state.checkout(*root_hash).unwrap().is_none());
+            match state.commit(correlation_id, *root_hash, AdditiveMap::new()) {
+                Ok(CommitResult::RootNotFound) => (),
+                result => panic!("unexpected commit result: {:?}", result),
+            }
+        }
+        assert!(state.checkout(state.empty_root_hash).unwrap().is_some());
+
+        // Only the kept roots are left to prune
+        let result = state.prune_roots(5).unwrap();
+        let expected_result = PruneResult::Pruned {
+            pruned_roots: vec![],
+            deleted_tries: 0,
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn prune_roots_refuses_to_prune_pinned_root() {
+        let (state, test_root) = create_test_state();
+        let root_hashes = commit_successive_roots(&state, test_root, 3);
+
+        let view = state.checkout(root_hashes[0]).unwrap().unwrap();
+        let result = state.prune_roots(2).unwrap();
+        assert_eq!(result, PruneResult::RootPinned(root_hashes[0]));
+        assert!(state.checkout(test_root).unwrap().is_some());
+
+        drop(view);
+        match state.prune_roots(2).unwrap() {
+            PruneResult::Pruned { pruned_roots, .. } => {
+                assert_eq!(pruned_roots, vec![root_hashes[0], test_root])
+            }
+            result => panic!("unexpected prune result: {:?}", result),
+        }
+        assert!(state.checkout(root_hashes[0]).unwrap().is_none());
+        assert!(state.checkout(root_hashes[1]).unwrap().is_some());
+    }
+
+    #[test]
+    fn prune_roots_prunes_roots_predating_root_history_together() {
+        let correlation_id = CorrelationId::new();
+        let (state, test_root) = create_test_state();
+        let old_roots = commit_successive_roots(&state, test_root, 2);
+        // Emptied as if the roots were committed before the root history existed
+        {
+            let mut txn = state.environment.create_read_write_txn().unwrap();
+            for root_hash in read_root_history(&txn, state.root_history).unwrap().keys() {
+                txn.del(state.root_history, &root_hash.to_bytes().unwrap(), None)
+                    .unwrap();
+            }
+            txn.commit().unwrap();
+        }
+
+        let state = reopen(&state);
+        let new_roots = commit_successive_roots(&state, old_roots[1], 3);
+
+        // The old roots are kept together with the oldest kept root
+        let expected_result = PruneResult::Pruned {
+            pruned_roots: vec![],
+            deleted_tries: 0,
+        };
+        assert_eq!(state.prune_roots(4).unwrap(), expected_result);
+        let checkout = state.checkout(old_roots[0]).unwrap().unwrap();
+        let expected_value = StoredValue::CLValue(CLValue::from_t(0_i32).unwrap());
+        let value = checkout.read(correlation_id, &create_test_pairs()[0].key);
+        assert_eq!(value.unwrap(), Some(expected_value));
+        drop(checkout);
+
+        // The intermediate roots of the old commits are found and pruned too
+        let pruned_roots = match state.prune_roots(3).unwrap() {
+            PruneResult::Pruned { pruned_roots, .. } => pruned_roots,
+            result => panic!("unexpected prune result: {:?}", result),
+        };
+        for root_hash in iter::once(&test_root).chain(&old_roots) {
+            assert!(pruned_roots.contains(root_hash));
+            assert!(state.checkout(*root_hash).unwrap().is_none());
+        }
+        for root_hash in &new_roots {
+            assert!(!pruned_roots.contains(root_hash));
+            let checkout = state.checkout(*root_hash).unwrap().unwrap();
+            // Written by the old commits
+            for index in 0..2 {
+                let key = Key::Hash([index; 32]);
+                assert!(checkout.read(correlation_id, &key).unwrap().is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn interrupted_prune_leaves_root_missing_until_finished() {
+        let correlation_id = CorrelationId::new();
+        let (state, test_root) = create_test_state();
+        let root_hashes = commit_successive_roots(&state, test_root, 3);
+        // Marked as by a prune which stops before deleting any tries
+        {
+            let mut txn = state.environment.create_read_write_txn().unwrap();
+            let key = root_hashes[0].to_bytes().unwrap();
+            txn.write(state.root_history, &key, &None::<u64>.to_bytes().unwrap())
+                .unwrap();
+            txn.commit().unwrap();
+        }
+
+        let state = reopen(&state);
+        assert!(state.checkout(root_hashes[0]).unwrap().is_none());
+        match state.commit(correlation_id, root_hashes[0], AdditiveMap::new()) {
+            Ok(CommitResult::RootNotFound) => (),
+            result => panic!("unexpected commit result: {:?}", result),
+        }
+
+        let deleted_tries = match state.prune_roots(10).unwrap() {
+            PruneResult::Pruned {
+                pruned_roots,
+                deleted_tries,
+            } => {
+                assert_eq!(pruned_roots, vec![root_hashes[0]]);
+                deleted_tries
+            }
+            result => panic!("unexpected prune result: {:?}", result),
+        };
+        assert!(deleted_tries > 0);
+        let txn = state.environment.create_read_txn().unwrap();
+        let history = read_root_history(&txn, state.root_history).unwrap();
+        txn.commit().unwrap();
+        assert!(!history.contains_key(&root_hashes[0]));
+        assert!(state.checkout(root_hashes[0]).unwrap().is_none());
+        assert!(state.checkout(root_hashes[1]).unwrap().is_some());
+    }
+
     #[test]
     fn checkout_fails_if_unknown_hash_is_given() {
         let (state, _) = create_test_state();
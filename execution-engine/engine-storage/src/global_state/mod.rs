@@ -16,9 +16,11 @@ use types::{account::PublicKey, bytesrepr, Key, ProtocolVersion, U512};
 use crate::{
     protocol_data::ProtocolData,
     transaction_source::{Transaction, TransactionSource},
-    trie::Trie,
+    trie::{Trie, TrieMerkleProof},
     trie_store::{
-        operations::{read, write, ReadResult, WriteResult},
+        operations::{
+            delete, read, write_with_stats, DeleteResult, ReadResult, WriteResult, WriteStats,
+        },
         TrieStore,
     },
     GAUGE_METRIC_KEY,
@@ -40,16 +42,35 @@ pub trait StateReader<K, V> {
     fn read(&self, correlation_id: CorrelationId, key: &K) -> Result<Option<V>, Self::Error>;
 }
 
+/// A reader of state which can also prove the values it reads
+pub trait ProvingStateReader<K, V>: StateReader<K, V> {
+    /// Returns the state value from the corresponding key along with a proof of it against the
+    /// state root being read
+    fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &K,
+    ) -> Result<Option<TrieMerkleProof<K, V>>, Self::Error>;
+}
+
 #[derive(Debug)]
 pub enum CommitResult {
     RootNotFound,
     Success {
         state_root: Blake2bHash,
         bonded_validators: HashMap<PublicKey, U512>,
+        /// The trie nodes put into the store while committing.
+        stats: WriteStats,
     },
     KeyNotFound(Key),
-    TypeMismatch(TypeMismatch),
+    /// A transform couldn't be applied to the value under `key`.
+    TypeMismatch {
+        key: Key,
+        type_mismatch: TypeMismatch,
+    },
     Serialization(bytesrepr::Error),
+    /// An unsigned addition to a `U512` would have overflowed.
+    Overflow,
 }
 
 impl fmt::Display for CommitResult {
@@ -59,27 +80,30 @@ impl fmt::Display for CommitResult {
             CommitResult::Success {
                 state_root,
                 bonded_validators,
+                stats,
             } => write!(
                 f,
-                "Success: state_root: {}, bonded_validators: {:?}",
-                state_root, bonded_validators
+                "Success: state_root: {}, bonded_validators: {:?}, stats: {:?}",
+                state_root, bonded_validators, stats
             ),
             CommitResult::KeyNotFound(key) => write!(f, "Key not found: {}", key),
-            CommitResult::TypeMismatch(type_mismatch) => {
-                write!(f, "Type mismatch: {:?}", type_mismatch)
+            CommitResult::TypeMismatch { key, type_mismatch } => {
+                write!(f, "Type mismatch at {}: {:?}", key, type_mismatch)
             }
             CommitResult::Serialization(error) => write!(f, "Serialization: {:?}", error),
+            CommitResult::Overflow => write!(f, "Overflow"),
         }
     }
 }
 
-impl From<transform::Error> for CommitResult {
-    fn from(error: transform::Error) -> Self {
+impl CommitResult {
+    fn from_transform_error(key: Key, error: transform::Error) -> Self {
         match error {
             transform::Error::TypeMismatch(type_mismatch) => {
-                CommitResult::TypeMismatch(type_mismatch)
+                CommitResult::TypeMismatch { key, type_mismatch }
             }
             transform::Error::Serialization(error) => CommitResult::Serialization(error),
+            transform::Error::Overflow => CommitResult::Overflow,
         }
     }
 }
@@ -140,8 +164,11 @@ where
     let start = Instant::now();
     let mut reads: i32 = 0;
     let mut writes: i32 = 0;
+    let mut stats = WriteStats::default();
 
-    for (key, transform) in effects.into_iter() {
+    // The trie doesn't depend on the order of writes, but applying them in order of their keys
+    // keeps the reads and writes made here, and so the commit metrics, the same from run to run.
+    for (key, transform) in effects.into_sorted_vec() {
         let read_result = read::<_, _, _, _, E>(correlation_id, &txn, store, &state_root, &key)?;
 
         log_duration(
@@ -155,18 +182,49 @@ where
 
         let value = match (read_result, transform) {
             (ReadResult::NotFound, Transform::Write(new_value)) => new_value,
+            // The key may have been both written and deleted by the same effects
+            (ReadResult::NotFound, Transform::Delete) => continue,
+            // The effects conflicted before ever being applied
+            (ReadResult::NotFound, Transform::Failure(error)) => {
+                return Ok(CommitResult::from_transform_error(key, error));
+            }
             (ReadResult::NotFound, _) => {
                 return Ok(CommitResult::KeyNotFound(key));
             }
+            (ReadResult::Found(_), Transform::Delete) => {
+                match delete::<_, _, _, _, E>(
+                    correlation_id,
+                    &mut txn,
+                    store,
+                    &state_root,
+                    &key,
+                    &mut stats,
+                )? {
+                    DeleteResult::Deleted(root_hash) => {
+                        state_root = root_hash;
+                        writes += 1;
+                    }
+                    DeleteResult::DoesNotExist => (),
+                    DeleteResult::RootNotFound => return Ok(CommitResult::RootNotFound),
+                }
+                continue;
+            }
             (ReadResult::Found(current_value), transform) => match transform.apply(current_value) {
                 Ok(updated_value) => updated_value,
-                Err(err) => return Ok(err.into()),
+                Err(error) => return Ok(CommitResult::from_transform_error(key, error)),
             },
-            _x @ (ReadResult::RootNotFound, _) => panic!(stringify!(_x._1)),
+            (ReadResult::RootNotFound, _) => return Ok(CommitResult::RootNotFound),
         };
 
-        let write_result =
-            write::<_, _, _, _, E>(correlation_id, &mut txn, store, &state_root, &key, &value)?;
+        let write_result = write_with_stats::<_, _, _, _, E>(
+            correlation_id,
+            &mut txn,
+            store,
+            &state_root,
+            &key,
+            &value,
+            &mut stats,
+        )?;
 
         log_duration(
             correlation_id,
@@ -181,7 +239,7 @@ where
                 writes += 1;
             }
             WriteResult::AlreadyExists => (),
-            _x @ WriteResult::RootNotFound => panic!(stringify!(_x)),
+            WriteResult::RootNotFound => return Ok(CommitResult::RootNotFound),
         }
     }
 
@@ -215,5 +273,6 @@ where
     Ok(CommitResult::Success {
         state_root,
         bonded_validators,
+        stats,
     })
 }
@@ -1,4 +1,6 @@
-use engine_wasm_prep::wasm_costs::{WasmCosts, WASM_COSTS_SERIALIZED_LENGTH};
+use engine_wasm_prep::wasm_costs::{
+    WasmCosts, LEGACY_WASM_COSTS_SERIALIZED_LENGTH, WASM_COSTS_SERIALIZED_LENGTH,
+};
 use types::{
     bytesrepr::{self, FromBytes, ToBytes},
     AccessRights, URef, UREF_SERIALIZED_LENGTH,
@@ -6,6 +8,10 @@ use types::{
 
 const PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
     WASM_COSTS_SERIALIZED_LENGTH + 3 * UREF_SERIALIZED_LENGTH;
+/// The number of bytes in protocol data stored with its wasm costs in the legacy layout.  Protocol
+/// data in the current layout is always longer.
+const LEGACY_PROTOCOL_DATA_SERIALIZED_LENGTH: usize =
+    LEGACY_WASM_COSTS_SERIALIZED_LENGTH + 3 * UREF_SERIALIZED_LENGTH;
 const DEFAULT_UREF_ADDRESS: [u8; 32] = [0; 32];
 
 /// Represents a protocol's data. Intended to be associated with a given protocol version.
@@ -125,7 +131,12 @@ impl ToBytes for ProtocolData {
 
 impl FromBytes for ProtocolData {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (wasm_costs, rem) = WasmCosts::from_bytes(bytes)?;
+        // Protocol data is stored on its own, so its length tells which layout it was stored in
+        let (wasm_costs, rem) = if bytes.len() == LEGACY_PROTOCOL_DATA_SERIALIZED_LENGTH {
+            WasmCosts::from_legacy_bytes(bytes)?
+        } else {
+            WasmCosts::from_bytes(bytes)?
+        };
         let (mint, rem) = URef::from_bytes(rem)?;
         let (proof_of_stake, rem) = URef::from_bytes(rem)?;
         let (standard_payment, rem) = URef::from_bytes(rem)?;
@@ -171,8 +182,11 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
-    use engine_wasm_prep::wasm_costs::WasmCosts;
-    use types::{bytesrepr, AccessRights, URef};
+    use engine_wasm_prep::wasm_costs::{WasmCosts, LEGACY_WASM_COSTS_SERIALIZED_LENGTH};
+    use types::{
+        bytesrepr::{self, ToBytes},
+        AccessRights, URef,
+    };
 
     use super::{gens, ProtocolData};
 
@@ -188,6 +202,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            storage_per_byte: 1,
+            native_transfer: 10_000,
+            hash_per_byte: 1,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 1,
         }
     }
 
@@ -203,6 +223,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            storage_per_byte: 0,
+            native_transfer: 0,
+            hash_per_byte: 0,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 1,
         }
     }
 
@@ -236,6 +262,34 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&free);
     }
 
+    #[test]
+    fn should_deserialize_legacy_layout() {
+        let costs = wasm_costs_mock();
+        let mint_reference = URef::new([0u8; 32], AccessRights::READ_ADD_WRITE);
+        let proof_of_stake_reference = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+        let standard_payment_reference = URef::new([2u8; 32], AccessRights::READ_ADD_WRITE);
+
+        let mut bytes = costs.to_bytes().unwrap();
+        bytes.truncate(LEGACY_WASM_COSTS_SERIALIZED_LENGTH);
+        bytes.append(&mut mint_reference.to_bytes().unwrap());
+        bytes.append(&mut proof_of_stake_reference.to_bytes().unwrap());
+        bytes.append(&mut standard_payment_reference.to_bytes().unwrap());
+
+        let protocol_data: ProtocolData = bytesrepr::deserialize(bytes).unwrap();
+        let expected_costs = WasmCosts {
+            storage_per_byte: 0,
+            native_transfer: 0,
+            hash_per_byte: 0,
+            max_initial_memory_pages: 0,
+            max_memory_pages: 0,
+            preprocess_per_byte: 0,
+            ..costs
+        };
+        assert_eq!(*protocol_data.wasm_costs(), expected_costs);
+        assert_eq!(protocol_data.mint(), mint_reference);
+        assert_eq!(protocol_data.standard_payment(), standard_payment_reference);
+    }
+
     #[test]
     fn should_return_all_system_contracts() {
         let mint_reference = URef::new([197u8; 32], AccessRights::READ_ADD_WRITE);
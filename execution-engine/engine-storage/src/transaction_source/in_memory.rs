@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{self, Arc, Mutex, MutexGuard},
 };
 
@@ -18,6 +18,32 @@ type BytesMap = HashMap<Vec<u8>, Vec<u8>>;
 
 type PoisonError<'a> = sync::PoisonError<MutexGuard<'a, HashMap<Option<String>, BytesMap>>>;
 
+/// Identifies a checkpoint taken with [`InMemoryEnvironment::checkpoint`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CheckpointId(u64);
+
+/// The checkpoints of an [`InMemoryEnvironment`].
+///
+/// Rather than copying the data, a checkpoint records the length of an undo log, which holds the
+/// previous value of every entry overwritten while any checkpoint is active.  Rolling back replays
+/// the undo log backwards down to that length.
+#[derive(Default)]
+struct Checkpoints {
+    next_id: u64,
+    /// The checkpoints which can still be rolled back to, oldest first, along with the length of
+    /// the undo log when each was taken.
+    active: Vec<(CheckpointId, usize)>,
+    undo_log: Vec<(Option<String>, Vec<u8>, Option<Vec<u8>>)>,
+}
+
+impl Checkpoints {
+    fn record(&mut self, handle: Option<String>, key: Vec<u8>, previous: Option<Vec<u8>>) {
+        if !self.active.is_empty() {
+            self.undo_log.push((handle, key, previous));
+        }
+    }
+}
+
 /// A read transaction for the in-memory trie store.
 pub struct InMemoryReadTransaction {
     view: HashMap<Option<String>, BytesMap>,
@@ -57,7 +83,9 @@ impl Readable for InMemoryReadTransaction {
 /// A read-write transaction for the in-memory trie store.
 pub struct InMemoryReadWriteTransaction<'a> {
     view: HashMap<Option<String>, BytesMap>,
+    written: HashSet<(Option<String>, Vec<u8>)>,
     store_ref: Arc<Mutex<HashMap<Option<String>, BytesMap>>>,
+    checkpoints_ref: Arc<Mutex<Checkpoints>>,
     _write_lock: WriteLock<'a>,
 }
 
@@ -68,10 +96,13 @@ impl<'a> InMemoryReadWriteTransaction<'a> {
             let view_lock = store_ref.lock()?;
             view_lock.to_owned()
         };
+        let checkpoints_ref = Arc::clone(&store.checkpoints);
         let _write_lock = store.write_mutex.lock()?;
         Ok(InMemoryReadWriteTransaction {
             view,
+            written: HashSet::new(),
             store_ref,
+            checkpoints_ref,
             _write_lock,
         })
     }
@@ -84,7 +115,20 @@ impl<'a> Transaction for InMemoryReadWriteTransaction<'a> {
 
     fn commit(self) -> Result<(), Self::Error> {
         let mut store_ref_lock = self.store_ref.lock()?;
-        store_ref_lock.extend(self.view);
+        let mut checkpoints = self.checkpoints_ref.lock()?;
+        let mut view = self.view;
+        for (handle, key) in self.written {
+            if let Some(value) = view
+                .get_mut(&handle)
+                .and_then(|sub_view| sub_view.remove(&key))
+            {
+                let previous = store_ref_lock
+                    .entry(handle.clone())
+                    .or_default()
+                    .insert(key.clone(), value);
+                checkpoints.record(handle, key, previous);
+            }
+        }
         Ok(())
     }
 }
@@ -101,8 +145,9 @@ impl<'a> Readable for InMemoryReadWriteTransaction<'a> {
 
 impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
     fn write(&mut self, handle: Self::Handle, key: &[u8], value: &[u8]) -> Result<(), Self::Error> {
-        let sub_view = self.view.entry(handle).or_default();
+        let sub_view = self.view.entry(handle.clone()).or_default();
         sub_view.insert(key.to_vec(), value.to_vec());
+        self.written.insert((handle, key.to_vec()));
         Ok(())
     }
 }
@@ -110,6 +155,7 @@ impl<'a> Writable for InMemoryReadWriteTransaction<'a> {
 /// An environment for the in-memory trie store.
 pub struct InMemoryEnvironment {
     data: Arc<Mutex<HashMap<Option<String>, BytesMap>>>,
+    checkpoints: Arc<Mutex<Checkpoints>>,
     write_mutex: Arc<Mutex<WriteCapability>>,
 }
 
@@ -120,8 +166,13 @@ impl Default for InMemoryEnvironment {
             initial_map.insert(None, Default::default());
             Arc::new(Mutex::new(initial_map))
         };
+        let checkpoints = Default::default();
         let write_mutex = Arc::new(Mutex::new(WriteCapability));
-        InMemoryEnvironment { data, write_mutex }
+        InMemoryEnvironment {
+            data,
+            checkpoints,
+            write_mutex,
+        }
     }
 }
 
@@ -136,6 +187,60 @@ impl InMemoryEnvironment {
         let ret = data.get(&name).cloned();
         Ok(ret)
     }
+
+    /// Creates a new environment holding a copy of this environment's data.
+    ///
+    /// The copy has no checkpoints.
+    pub fn deep_clone(&self) -> Result<Self, Error> {
+        let data = self.data.lock()?.to_owned();
+        Ok(InMemoryEnvironment {
+            data: Arc::new(Mutex::new(data)),
+            checkpoints: Default::default(),
+            write_mutex: Arc::new(Mutex::new(WriteCapability)),
+        })
+    }
+
+    /// Takes a checkpoint of the data, which can later be restored with
+    /// [`rollback_to`](InMemoryEnvironment::rollback_to).
+    ///
+    /// Taking a checkpoint doesn't copy the data, but while any checkpoint is active, committing a
+    /// write also records the overwritten value.
+    pub fn checkpoint(&self) -> Result<CheckpointId, Error> {
+        let mut checkpoints = self.checkpoints.lock()?;
+        let id = CheckpointId(checkpoints.next_id);
+        checkpoints.next_id += 1;
+        let undo_log_len = checkpoints.undo_log.len();
+        checkpoints.active.push((id, undo_log_len));
+        Ok(id)
+    }
+
+    /// Restores the data to what it was when `checkpoint` was taken.
+    ///
+    /// `checkpoint` stays active, but any checkpoint taken after it is invalidated.  Returns
+    /// [`Error::UnknownCheckpoint`] if `checkpoint` was itself invalidated.
+    ///
+    /// Waits for any open read-write transaction to finish, so must not be called while the calling
+    /// thread holds one.
+    pub fn rollback_to(&self, checkpoint: CheckpointId) -> Result<(), Error> {
+        let _write_lock = self.write_mutex.lock()?;
+        let mut data = self.data.lock()?;
+        let mut checkpoints = self.checkpoints.lock()?;
+        let position = checkpoints
+            .active
+            .iter()
+            .position(|(id, _)| *id == checkpoint)
+            .ok_or(Error::UnknownCheckpoint)?;
+        let (_, undo_log_len) = checkpoints.active[position];
+        checkpoints.active.truncate(position + 1);
+        for (handle, key, previous) in checkpoints.undo_log.drain(undo_log_len..).rev() {
+            let sub_data = data.entry(handle).or_default();
+            match previous {
+                Some(value) => sub_data.insert(key, value),
+                None => sub_data.remove(&key),
+            };
+        }
+        Ok(())
+    }
 }
 
 impl<'a> TransactionSource<'a> for InMemoryEnvironment {
@@ -1,4 +1,9 @@
-use std::path::PathBuf;
+use std::{
+    ffi::CString,
+    mem::MaybeUninit,
+    os::{raw::c_int, unix::ffi::OsStrExt},
+    path::{Path, PathBuf},
+};
 
 use lmdb::{self, Database, Environment, RoTransaction, RwTransaction, WriteFlags};
 
@@ -55,6 +60,33 @@ impl<'a> Writable for RwTransaction<'a> {
     }
 }
 
+fn lmdb_result(return_code: c_int) -> Result<(), lmdb::Error> {
+    if return_code == lmdb_sys::MDB_SUCCESS {
+        Ok(())
+    } else {
+        Err(lmdb::Error::from_err_code(return_code))
+    }
+}
+
+/// Statistics of a database in an [`LmdbEnvironment`], along with the size of the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoreStats {
+    /// The size in bytes of a database page.
+    pub page_size: usize,
+    /// The depth of the database's B-tree.
+    pub depth: usize,
+    pub branch_pages: usize,
+    pub leaf_pages: usize,
+    pub overflow_pages: usize,
+    /// The number of entries in the database, e.g. the number of tries in a trie store.
+    pub entries: usize,
+    /// The size in bytes of the environment's memory map, i.e. the most it can grow to.
+    pub map_size: usize,
+    /// The number of bytes of the environment's memory map up to and including the last page in
+    /// use.  Freed pages are reused rather than returned, so this never shrinks.
+    pub map_size_used: usize,
+}
+
 /// The environment for an LMDB-backed trie store.
 ///
 /// Wraps [`lmdb::Environment`].
@@ -81,6 +113,58 @@ impl LmdbEnvironment {
     pub fn env(&self) -> &Environment {
         &self.env
     }
+
+    /// Returns the statistics of `database`.
+    ///
+    /// Only a read transaction is taken, so statistics can be polled while writes are in progress.
+    pub fn stats(&self, database: Database) -> Result<StoreStats, error::Error> {
+        let txn = self.env.begin_ro_txn()?;
+        let mut stat = MaybeUninit::<lmdb_sys::MDB_stat>::uninit();
+        let mut info = MaybeUninit::<lmdb_sys::MDB_envinfo>::uninit();
+        let (stat, info) = unsafe {
+            let txn_ptr = lmdb::Transaction::txn(&txn);
+            lmdb_result(lmdb_sys::mdb_stat(
+                txn_ptr,
+                database.dbi(),
+                stat.as_mut_ptr(),
+            ))?;
+            lmdb_result(lmdb_sys::mdb_env_info(self.env.env(), info.as_mut_ptr()))?;
+            (stat.assume_init(), info.assume_init())
+        };
+        txn.commit()?;
+
+        let page_size = stat.ms_psize as usize;
+        Ok(StoreStats {
+            page_size,
+            depth: stat.ms_depth as usize,
+            branch_pages: stat.ms_branch_pages,
+            leaf_pages: stat.ms_leaf_pages,
+            overflow_pages: stat.ms_overflow_pages,
+            entries: stat.ms_entries,
+            map_size: info.me_mapsize,
+            map_size_used: (info.me_last_pgno + 1) * page_size,
+        })
+    }
+
+    /// Copies the environment into the existing, empty directory `target_path`, leaving out free
+    /// pages and renumbering the rest, so that the copy takes up only the space of the data in use.
+    ///
+    /// The copy is made from a read transaction, so writes can continue while it runs, but don't
+    /// appear in the copy.
+    pub fn compact(&self, target_path: &Path) -> Result<(), error::Error> {
+        // Paths can't contain nul bytes on any platform LMDB runs on.
+        let target_path =
+            CString::new(target_path.as_os_str().as_bytes()).map_err(|_| lmdb::Error::Invalid)?;
+        let return_code = unsafe {
+            lmdb_sys::mdb_env_copy2(
+                self.env.env(),
+                target_path.as_ptr(),
+                lmdb_sys::MDB_CP_COMPACT,
+            )
+        };
+        lmdb_result(return_code)?;
+        Ok(())
+    }
 }
 
 impl<'a> TransactionSource<'a> for LmdbEnvironment {
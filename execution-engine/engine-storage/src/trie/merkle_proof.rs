@@ -0,0 +1,130 @@
+//! Merkle proofs of the presence of a key-value pair in a Merkle Trie
+
+use engine_shared::newtypes::Blake2bHash;
+use types::bytesrepr::{self, FromBytes, ToBytes};
+
+use crate::trie::Trie;
+
+/// A proof that a key maps to a value in the trie under a given root hash.
+///
+/// Holds the nodes and extensions on the path from the root down to, but excluding, the leaf
+/// holding the pair.  It can be checked with [`validate_proof`] without access to a store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProof<K, V> {
+    key: K,
+    value: V,
+    path: Vec<Trie<K, V>>,
+}
+
+impl<K, V> TrieMerkleProof<K, V> {
+    /// Constructs a proof from a key-value pair and the path leading to its leaf, root first.
+    pub fn new(key: K, value: V, path: Vec<Trie<K, V>>) -> Self {
+        TrieMerkleProof { key, value, path }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    pub fn path(&self) -> &[Trie<K, V>] {
+        &self.path
+    }
+
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+impl<K: ToBytes, V: ToBytes> ToBytes for TrieMerkleProof<K, V> {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::allocate_buffer(self)?;
+        ret.append(&mut self.key.to_bytes()?);
+        ret.append(&mut self.value.to_bytes()?);
+        ret.append(&mut self.path.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.key.serialized_length()
+            + self.value.serialized_length()
+            + self.path.serialized_length()
+    }
+}
+
+impl<K: FromBytes, V: FromBytes> FromBytes for TrieMerkleProof<K, V> {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (key, rem) = K::from_bytes(bytes)?;
+        let (value, rem) = V::from_bytes(rem)?;
+        let (path, rem) = Vec::<Trie<K, V>>::from_bytes(rem)?;
+        Ok((TrieMerkleProof { key, value, path }, rem))
+    }
+}
+
+fn hash_trie<K: ToBytes, V: ToBytes>(trie: &Trie<K, V>) -> Option<Blake2bHash> {
+    trie.to_bytes()
+        .ok()
+        .map(|trie_bytes| Blake2bHash::new(&trie_bytes))
+}
+
+/// Returns `true` if `proof` shows that `key` maps to `value` in the trie under `root_hash`.
+///
+/// Starting from `root_hash`, each element of the proof's path must hash to the pointer followed
+/// from its parent along the path given by the bytes of `key`, and the last pointer must be the
+/// hash of the leaf holding `key` and `value`.
+pub fn validate_proof<K, V>(
+    root_hash: &Blake2bHash,
+    proof: &TrieMerkleProof<K, V>,
+    key: &K,
+    value: &V,
+) -> bool
+where
+    K: ToBytes + Eq + Clone,
+    V: ToBytes + Eq + Clone,
+{
+    if proof.key != *key || proof.value != *value {
+        return false;
+    }
+    let key_bytes = match key.to_bytes() {
+        Ok(key_bytes) => key_bytes,
+        Err(_) => return false,
+    };
+
+    let mut expected_hash = *root_hash;
+    let mut depth: usize = 0;
+    for trie in proof.path.iter() {
+        if hash_trie(trie) != Some(expected_hash) {
+            return false;
+        }
+        let maybe_pointer = match trie {
+            // Leaves only terminate a path, so they can't appear in the middle of one
+            Trie::Leaf { .. } => None,
+            Trie::Node { pointer_block } => match key_bytes.get(depth) {
+                Some(index) => {
+                    depth += 1;
+                    pointer_block[usize::from(*index)]
+                }
+                None => None,
+            },
+            Trie::Extension { affix, pointer } => {
+                let sub_path = key_bytes.get(depth..depth + affix.len());
+                if sub_path == Some(affix.as_slice()) {
+                    depth += affix.len();
+                    Some(*pointer)
+                } else {
+                    None
+                }
+            }
+        };
+        match maybe_pointer {
+            Some(pointer) => expected_hash = *pointer.hash(),
+            None => return false,
+        }
+    }
+
+    let leaf = Trie::leaf(key.clone(), value.clone());
+    hash_trie(&leaf) == Some(expected_hash)
+}
@@ -10,6 +10,7 @@ mod tests;
 
 use engine_shared::newtypes::Blake2bHash;
 
+pub use self::operations::WriteStats;
 use crate::{store::Store, trie::Trie};
 
 const NAME: &str = "TRIE_STORE";
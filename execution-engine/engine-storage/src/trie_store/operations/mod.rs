@@ -11,21 +11,26 @@ use types::bytesrepr::{self, FromBytes, ToBytes};
 
 use crate::{
     transaction_source::{Readable, Writable},
-    trie::{self, Parents, Pointer, Trie, RADIX},
+    trie::{self, Parents, Pointer, Trie, TrieMerkleProof, RADIX},
     trie_store::TrieStore,
     GAUGE_METRIC_KEY,
 };
 
 const TRIE_STORE_READ_DURATION: &str = "trie_store_read_duration";
 const TRIE_STORE_READ_GETS: &str = "trie_store_read_gets";
+const TRIE_STORE_READ_WITH_PROOF_DURATION: &str = "trie_store_read_with_proof_duration";
 const TRIE_STORE_SCAN_DURATION: &str = "trie_store_scan_duration";
 const TRIE_STORE_SCAN_GETS: &str = "trie_store_scan_gets";
 const TRIE_STORE_WRITE_DURATION: &str = "trie_store_write_duration";
 const TRIE_STORE_WRITE_PUTS: &str = "trie_store_write_puts";
+const TRIE_STORE_DELETE_DURATION: &str = "trie_store_delete_duration";
+const TRIE_STORE_DELETE_PUTS: &str = "trie_store_delete_puts";
 const READ: &str = "read";
+const READ_WITH_PROOF: &str = "read_with_proof";
 const GET: &str = "get";
 const SCAN: &str = "scan";
 const WRITE: &str = "write";
+const DELETE: &str = "delete";
 const PUT: &str = "put";
 
 #[derive(Debug, PartialEq, Eq)]
@@ -199,6 +204,96 @@ where
     }
 }
 
+/// Returns a proof of the value under the corresponding key at a given root in a given store
+///
+/// The proof holds every node and extension visited on the way from the root to the leaf holding
+/// the value, and can be checked against the root with [`trie::validate_proof`].
+pub fn read_with_proof<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+) -> Result<ReadResult<TrieMerkleProof<K, V>>, E>
+where
+    K: ToBytes + FromBytes + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes,
+    T: Readable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let path: Vec<u8> = key.to_bytes()?;
+
+    let mut depth: usize = 0;
+    let mut current: Trie<K, V> = match store.get(txn, root)? {
+        Some(root) => root,
+        None => return Ok(ReadResult::RootNotFound),
+    };
+    let mut proof_path: Vec<Trie<K, V>> = Vec::new();
+
+    let start = Instant::now();
+
+    let result = loop {
+        let maybe_pointer: Option<Pointer> = match current {
+            Trie::Leaf {
+                key: leaf_key,
+                value: leaf_value,
+            } => {
+                if *key == leaf_key {
+                    let proof = TrieMerkleProof::new(leaf_key, leaf_value, proof_path);
+                    break ReadResult::Found(proof);
+                } else {
+                    // Keys may not match in the case of a compressed path from
+                    // a Node directly to a Leaf
+                    break ReadResult::NotFound;
+                }
+            }
+            Trie::Node { ref pointer_block } => {
+                let index: usize = {
+                    assert!(depth < path.len(), "depth must be < {}", path.len());
+                    path[depth].into()
+                };
+                depth += 1;
+                pointer_block[index]
+            }
+            Trie::Extension {
+                ref affix,
+                ref pointer,
+            } => {
+                let sub_path = path.get(depth..depth + affix.len());
+                if sub_path == Some(affix.as_slice()) {
+                    depth += affix.len();
+                    Some(*pointer)
+                } else {
+                    None
+                }
+            }
+        };
+        let pointer = match maybe_pointer {
+            Some(pointer) => pointer,
+            None => break ReadResult::NotFound,
+        };
+        proof_path.push(current);
+        current = match store.get(txn, pointer.hash())? {
+            Some(next) => next,
+            None => panic!(
+                "No trie value at key: {:?} (reading from key: {:?})",
+                pointer.hash(),
+                key
+            ),
+        };
+    };
+
+    log_duration(
+        correlation_id,
+        TRIE_STORE_READ_WITH_PROOF_DURATION,
+        READ_WITH_PROOF,
+        start.elapsed(),
+    );
+    Ok(result)
+}
+
 struct TrieScan<K, V> {
     tip: Trie<K, V>,
     parents: Parents<K, V>,
@@ -618,6 +713,22 @@ pub enum WriteResult {
     RootNotFound,
 }
 
+/// Summary of the trie nodes put into a store by one or more writes or deletes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WriteStats {
+    /// The number of trie nodes put into the store.
+    pub new_nodes: usize,
+    /// The total serialized length of those trie nodes.
+    pub bytes_written: u64,
+}
+
+impl WriteStats {
+    fn record<K: ToBytes, V: ToBytes>(&mut self, element: &Trie<K, V>) {
+        self.new_nodes += 1;
+        self.bytes_written += element.serialized_length() as u64;
+    }
+}
+
 pub fn write<K, V, T, S, E>(
     correlation_id: CorrelationId,
     txn: &mut T,
@@ -626,6 +737,28 @@ pub fn write<K, V, T, S, E>(
     key: &K,
     value: &V,
 ) -> Result<WriteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let mut stats = WriteStats::default();
+    write_with_stats(correlation_id, txn, store, root, key, value, &mut stats)
+}
+
+/// Like [`write`], but also adds the trie nodes it puts into the store to `stats`.
+pub fn write_with_stats<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+    value: &V,
+    stats: &mut WriteStats,
+) -> Result<WriteResult, E>
 where
     K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
     V: ToBytes + FromBytes + Clone + Eq,
@@ -711,6 +844,7 @@ where
             for (hash, element) in new_elements.iter() {
                 put_counter += 1;
                 store.put(txn, hash, element)?;
+                stats.record(element);
                 root_hash = *hash;
             }
             log_metric(
@@ -731,6 +865,135 @@ where
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum DeleteResult {
+    Deleted(Blake2bHash),
+    DoesNotExist,
+    RootNotFound,
+}
+
+/// Removes the leaf under `key` from the trie at `root`, adding the trie nodes it puts into the
+/// store to `stats`.
+///
+/// The surrounding nodes are collapsed so that the resulting trie has the same shape (and therefore
+/// the same root hash) as a trie into which the leaf was never written.
+pub fn delete<K, V, T, S, E>(
+    correlation_id: CorrelationId,
+    txn: &mut T,
+    store: &S,
+    root: &Blake2bHash,
+    key: &K,
+    stats: &mut WriteStats,
+) -> Result<DeleteResult, E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug,
+    V: ToBytes + FromBytes + Clone + Eq,
+    T: Readable<Handle = S::Handle> + Writable<Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<T::Error>,
+    E: From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let start = Instant::now();
+    let mut put_counter: i32 = 0;
+
+    let current_root = match store.get(txn, root)? {
+        Some(current_root) => current_root,
+        None => return Ok(DeleteResult::RootNotFound),
+    };
+    let path: Vec<u8> = key.to_bytes()?;
+    let TrieScan { tip, mut parents } =
+        scan::<K, V, T, S, E>(correlation_id, txn, store, &path, &current_root)?;
+    match tip {
+        Trie::Leaf {
+            key: ref leaf_key, ..
+        } if key == leaf_key => (),
+        _ => return Ok(DeleteResult::DoesNotExist),
+    }
+
+    // A leaf is always pointed to by a node, so remove the pointer to it.
+    let mut pointer_block = match parents.pop() {
+        Some((index, Trie::Node { mut pointer_block })) => {
+            pointer_block[index.into()] = None;
+            pointer_block
+        }
+        _ => panic!("A leaf should have a node for its parent"),
+    };
+
+    let only_child = {
+        let mut remaining_children = pointer_block[..]
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_pointer)| maybe_pointer.map(|pointer| (index, pointer)));
+        match (remaining_children.next(), remaining_children.next()) {
+            (Some(only_child), None) => Some(only_child),
+            _ => None,
+        }
+    };
+
+    let new_elements: Vec<(Blake2bHash, Trie<K, V>)> = match only_child {
+        // The root node is kept even if it has fewer than two children.  Any other node left with
+        // a single child has to be collapsed into its parents.
+        Some((child_index, child_pointer)) if !parents.is_empty() => {
+            // An extension above the collapsed node is merged into whatever replaces the node.
+            let mut affix = match parents.last() {
+                Some((_, Trie::Extension { .. })) => match parents.pop() {
+                    Some((_, Trie::Extension { affix, .. })) => affix,
+                    _ => unreachable!(),
+                },
+                _ => Vec::new(),
+            };
+            let child = match store.get(txn, child_pointer.hash())? {
+                Some(child) => child,
+                None => panic!("No trie value at key: {:?}", child_pointer.hash()),
+            };
+            match (child_pointer, child) {
+                // A lone leaf is pointed to directly by the nearest node above it.
+                (Pointer::LeafPointer(_), leaf) => rehash(leaf, parents)?,
+                // A lone extension absorbs the collapsed node's index and any extension above it.
+                (
+                    _,
+                    Trie::Extension {
+                        affix: child_affix,
+                        pointer,
+                    },
+                ) => {
+                    affix.push(child_index as u8);
+                    affix.extend(child_affix);
+                    rehash(Trie::extension(affix, pointer), parents)?
+                }
+                // A lone node is reached through an extension covering the collapsed node's index.
+                (_, _) => {
+                    affix.push(child_index as u8);
+                    rehash(Trie::extension(affix, child_pointer), parents)?
+                }
+            }
+        }
+        _ => rehash(Trie::Node { pointer_block }, parents)?,
+    };
+
+    let mut root_hash = root.to_owned();
+    for (hash, element) in new_elements.iter() {
+        put_counter += 1;
+        store.put(txn, hash, element)?;
+        stats.record(element);
+        root_hash = *hash;
+    }
+    log_metric(
+        correlation_id,
+        TRIE_STORE_DELETE_PUTS,
+        PUT,
+        GAUGE_METRIC_KEY,
+        f64::from(put_counter),
+    );
+    log_duration(
+        correlation_id,
+        TRIE_STORE_DELETE_DURATION,
+        DELETE,
+        start.elapsed(),
+    );
+    Ok(DeleteResult::Deleted(root_hash))
+}
+
 enum KeysIteratorState<K, V, S: TrieStore<K, V>> {
     /// Iterate normally
     Ok,
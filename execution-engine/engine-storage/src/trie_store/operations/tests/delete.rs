@@ -0,0 +1,103 @@
+use super::*;
+use crate::trie_store::operations::{delete, DeleteResult, WriteStats};
+
+/// Writes `test_leaves` one by one to an empty trie, then deletes them in reverse order, checking
+/// that each delete returns the trie to the state it was in before the leaf was written.
+fn deletes_restore_previous_states<'a, K, V, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    empty_root_hash: &Blake2bHash,
+    test_leaves: &[Trie<K, V>],
+) -> Result<(), E>
+where
+    K: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug + Ord,
+    V: ToBytes + FromBytes + Clone + Eq + std::fmt::Debug + Copy,
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<K, V>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let states = writes_to_n_leaf_empty_trie_had_expected_results::<_, _, _, _, E>(
+        correlation_id,
+        environment,
+        store,
+        &[*empty_root_hash],
+        test_leaves,
+    )?;
+
+    let mut txn = environment.create_read_write_txn()?;
+    let mut stats = WriteStats::default();
+    for (num_leaves, leaf) in test_leaves.iter().enumerate().rev() {
+        let key = leaf.key().expect("test leaves should contain only leaves");
+        let current_root = states[num_leaves + 1];
+        let root_hash = match delete::<_, _, _, _, E>(
+            correlation_id,
+            &mut txn,
+            store,
+            &current_root,
+            key,
+            &mut stats,
+        )? {
+            DeleteResult::Deleted(root_hash) => root_hash,
+            other => panic!("delete should succeed, got {:?}", other),
+        };
+        assert_eq!(root_hash, states[num_leaves]);
+        assert_eq!(
+            read::<_, _, _, _, E>(correlation_id, &txn, store, &root_hash, key)?,
+            ReadResult::NotFound
+        );
+        let stats_before = stats;
+        assert_eq!(
+            delete::<_, _, _, _, E>(correlation_id, &mut txn, store, &root_hash, key, &mut stats)?,
+            DeleteResult::DoesNotExist
+        );
+        assert_eq!(stats, stats_before);
+    }
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_deletes_restore_previous_states() {
+    for test_leaves in &[
+        TEST_LEAVES,
+        TEST_LEAVES_NON_COLLIDING,
+        TEST_LEAVES_ADJACENTS,
+    ] {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+
+        deletes_restore_previous_states::<_, _, _, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            test_leaves,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_deletes_restore_previous_states() {
+    for test_leaves in &[
+        TEST_LEAVES,
+        TEST_LEAVES_NON_COLLIDING,
+        TEST_LEAVES_ADJACENTS,
+    ] {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = TEST_TRIE_GENERATORS[0]().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+
+        deletes_restore_previous_states::<_, _, _, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            test_leaves,
+        )
+        .unwrap();
+    }
+}
@@ -1,7 +1,9 @@
+mod delete;
 mod ee_699;
 mod keys;
 mod proptests;
 mod read;
+mod read_with_proof;
 mod scan;
 mod write;
 
@@ -0,0 +1,183 @@
+//! This module contains tests for [`read_with_proof`](operations::read_with_proof) and for
+//! validating the proofs it returns with [`trie::validate_proof`].
+
+use super::*;
+use crate::{
+    error::{self, in_memory},
+    trie::{self, TrieMerkleProof},
+};
+
+fn mutate_hash(hash: &Blake2bHash) -> Blake2bHash {
+    let mut bytes: [u8; 32] = (*hash).into();
+    bytes[0] ^= 1;
+    bytes.into()
+}
+
+/// Asserts that flipping any single byte of any element of the path of `proof` yields a proof
+/// which fails to validate, unless the flipped element no longer parses.
+fn assert_tampered_proofs_invalid(
+    root: &Blake2bHash,
+    proof: &TrieMerkleProof<TestKey, TestValue>,
+    key: &TestKey,
+    value: &TestValue,
+) {
+    for (position, trie) in proof.path().iter().enumerate() {
+        let trie_bytes = trie.to_bytes().unwrap();
+        for index in 0..trie_bytes.len() {
+            let mut tampered_bytes = trie_bytes.clone();
+            tampered_bytes[index] ^= 1;
+            let tampered_trie = match bytesrepr::deserialize::<TestTrie>(tampered_bytes) {
+                Ok(tampered_trie) => tampered_trie,
+                Err(_) => continue,
+            };
+            let mut path = proof.path().to_vec();
+            path[position] = tampered_trie;
+            let tampered = TrieMerkleProof::new(*key, *value, path);
+            assert!(
+                !trie::validate_proof(root, &tampered, key, value),
+                "proof with byte {} of path element {} flipped should not validate",
+                index,
+                position
+            );
+        }
+    }
+}
+
+fn check_proofs<'a, R, S, E>(
+    correlation_id: CorrelationId,
+    environment: &'a R,
+    store: &S,
+    root: &Blake2bHash,
+    present: &[TestTrie],
+    absent: &[TestTrie],
+) -> Result<(), E>
+where
+    R: TransactionSource<'a, Handle = S::Handle>,
+    S: TrieStore<TestKey, TestValue>,
+    S::Error: From<R::Error>,
+    E: From<R::Error> + From<S::Error> + From<types::bytesrepr::Error>,
+{
+    let txn: R::ReadTransaction = environment.create_read_txn()?;
+
+    for leaf in present {
+        if let Trie::Leaf { key, value } = leaf {
+            let proof = match operations::read_with_proof::<_, _, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                root,
+                key,
+            )? {
+                ReadResult::Found(proof) => proof,
+                _ => panic!("should have proof for {:?}", key),
+            };
+            assert_eq!(proof.value(), value);
+            assert!(trie::validate_proof(root, &proof, key, value));
+            bytesrepr::test_serialization_roundtrip(&proof);
+
+            // The proof is tied to its root as well as to the key-value pair.
+            let mutated_root = mutate_hash(root);
+            assert!(!trie::validate_proof(&mutated_root, &proof, key, value));
+            let mut mutated_value = *value;
+            mutated_value.0[0] ^= 1;
+            assert!(!trie::validate_proof(root, &proof, key, &mutated_value));
+            let forged = TrieMerkleProof::new(*key, mutated_value, proof.path().to_vec());
+            assert!(!trie::validate_proof(root, &forged, key, &mutated_value));
+            assert!(!trie::validate_proof(root, &forged, key, value));
+            assert_tampered_proofs_invalid(root, &proof, key, value);
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+
+    for leaf in absent {
+        if let Trie::Leaf { key, .. } = leaf {
+            let read_result = operations::read_with_proof::<_, _, _, _, E>(
+                correlation_id,
+                &txn,
+                store,
+                root,
+                key,
+            )?;
+            assert_eq!(read_result, ReadResult::NotFound);
+        } else {
+            panic!("leaves should only contain leaves")
+        }
+    }
+
+    txn.commit()?;
+    Ok(())
+}
+
+#[test]
+fn lmdb_proofs_from_n_leaf_partial_trie_are_valid() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = LmdbTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, error::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn in_memory_proofs_from_n_leaf_partial_trie_are_valid() {
+    for (num_leaves, generator) in TEST_TRIE_GENERATORS.iter().enumerate() {
+        let correlation_id = CorrelationId::new();
+        let (root_hash, tries) = generator().unwrap();
+        let context = InMemoryTestContext::new(&tries).unwrap();
+        let test_leaves = TEST_LEAVES;
+        let (used, unused) = test_leaves.split_at(num_leaves);
+
+        check_proofs::<_, _, in_memory::Error>(
+            correlation_id,
+            &context.environment,
+            &context.store,
+            &root_hash,
+            used,
+            unused,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn proofs_do_not_validate_against_other_roots() {
+    let correlation_id = CorrelationId::new();
+    let context = InMemoryTestContext::new(EMPTY_HASHED_TEST_TRIES).unwrap();
+    let (first_root, first_tries) = create_1_leaf_trie().unwrap();
+    let (second_root, second_tries) = create_2_leaf_trie().unwrap();
+    context.update(&first_tries).unwrap();
+    context.update(&second_tries).unwrap();
+
+    let txn = context.environment.create_read_txn().unwrap();
+    if let Trie::Leaf { key, value } = &TEST_LEAVES[0] {
+        let proof = match operations::read_with_proof::<_, _, _, _, in_memory::Error>(
+            correlation_id,
+            &txn,
+            &context.store,
+            &first_root,
+            key,
+        )
+        .unwrap()
+        {
+            ReadResult::Found(proof) => proof,
+            _ => panic!("should have proof"),
+        };
+        assert!(trie::validate_proof(&first_root, &proof, key, value));
+        assert!(!trie::validate_proof(&second_root, &proof, key, value));
+    } else {
+        panic!("leaves should only contain leaves")
+    }
+    txn.commit().unwrap();
+}
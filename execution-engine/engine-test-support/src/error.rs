@@ -3,6 +3,8 @@ use std::result;
 use engine_shared::TypeMismatch;
 use types::CLValueError;
 
+use crate::internal::QueryError;
+
 /// The error type returned by any casperlabs-engine-test-support operation.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Hash, Debug)]
 pub struct Error {
@@ -23,6 +25,14 @@ impl From<CLValueError> for Error {
     }
 }
 
+impl From<QueryError> for Error {
+    fn from(error: QueryError) -> Self {
+        Error {
+            inner: format!("{:?}", error),
+        }
+    }
+}
+
 impl From<TypeMismatch> for Error {
     fn from(error: TypeMismatch) -> Self {
         Error {
@@ -0,0 +1,25 @@
+use std::fmt::{self, Display, Formatter};
+
+use engine_shared::transform;
+use types::Key;
+
+/// An error returned when looking up the effective state of an account after applying transforms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountLookupError {
+    /// No account is stored under the key, neither before nor after applying the transforms.
+    NotFound(Key),
+    /// The transform for the key doesn't apply to an account, e.g. it writes a different type of
+    /// value or deletes the account.
+    TransformMismatch(transform::Error),
+}
+
+impl Display for AccountLookupError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AccountLookupError::NotFound(key) => write!(f, "Failed to find account {}", key),
+            AccountLookupError::TransformMismatch(error) => {
+                write!(f, "Transform doesn't apply to an account: {:?}", error)
+            }
+        }
+    }
+}
@@ -2,7 +2,10 @@ use std::{collections::BTreeSet, path::Path};
 
 use contract::args_parser::ArgsParser;
 use engine_core::{
-    engine_state::{deploy_item::DeployItem, executable_deploy_item::ExecutableDeployItem},
+    engine_state::{
+        deploy_item::{Approval, DeployItem},
+        executable_deploy_item::ExecutableDeployItem,
+    },
     DeployHash,
 };
 use types::{account::PublicKey, bytesrepr::ToBytes, URef};
@@ -17,6 +20,7 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<PublicKey>,
     pub deploy_hash: DeployHash,
+    pub approvals: Vec<Approval>,
 }
 
 pub struct DeployItemBuilder {
@@ -137,6 +141,29 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Replaces the arguments of the session code set previously, e.g. with named arguments:
+    ///
+    /// ```rust,no_run
+    /// # use casperlabs_engine_test_support::internal::DeployItemBuilder;
+    /// # use casperlabs_types::{runtime_args, U512};
+    /// let deploy_item = DeployItemBuilder::new()
+    ///     .with_session_code("transfer.wasm", ())
+    ///     .with_session_args(runtime_args! { "amount" => U512::from(10) });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no session code was set.
+    pub fn with_session_args(mut self, args: impl ArgsParser) -> Self {
+        let session_code = self
+            .deploy_item
+            .session_code
+            .as_mut()
+            .expect("should set session code before its args");
+        *session_code.args_mut() = Self::serialize_args(args);
+        self
+    }
+
     pub fn with_authorization_keys<T: Clone + Into<PublicKey>>(
         mut self,
         authorization_keys: &[T],
@@ -159,6 +186,16 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Adds an approval of the deploy, i.e. `signature` over its deploy hash by `public_key`.
+    pub fn with_approval(mut self, public_key: &[u8], signature: &[u8], algorithm: &str) -> Self {
+        self.deploy_item.approvals.push(Approval {
+            public_key: public_key.to_vec(),
+            signature: signature.to_vec(),
+            algorithm: algorithm.to_string(),
+        });
+        self
+    }
+
     pub fn build(self) -> DeployItem {
         DeployItem {
             address: self
@@ -170,12 +207,13 @@ impl DeployItemBuilder {
             gas_price: self.deploy_item.gas_price,
             authorization_keys: self.deploy_item.authorization_keys,
             deploy_hash: self.deploy_item.deploy_hash,
+            approvals: self.deploy_item.approvals,
         }
     }
 
     fn serialize_args(args: impl ArgsParser) -> Vec<u8> {
         args.parse()
-            .expect("should convert to `Vec<CLValue>`")
+            .expect("should convert to `RuntimeArgs`")
             .into_bytes()
             .expect("should serialize args")
     }
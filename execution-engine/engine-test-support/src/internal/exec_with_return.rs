@@ -8,18 +8,18 @@ use engine_core::{
     },
     execution::{self, AddressGenerator},
     runtime::{self, Runtime},
-    runtime_context::RuntimeContext,
+    runtime_context::{self, RuntimeContext},
 };
 use engine_grpc_server::engine_server::ipc_grpc::ExecutionEngineService;
 use engine_shared::{gas::Gas, newtypes::CorrelationId};
 use engine_storage::{global_state::StateProvider, protocol_data::ProtocolData};
 use engine_wasm_prep::Preprocessor;
 use types::{
-    account::PublicKey, bytesrepr::FromBytes, BlockTime, CLTyped, CLValue, Key, Phase,
-    ProtocolVersion, URef, U512,
+    account::PublicKey, bytesrepr::FromBytes, BlockTime, CLTyped, Key, Phase, ProtocolVersion,
+    RuntimeArgs, URef, U512,
 };
 
-use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_COSTS};
+use crate::internal::{utils, WasmTestBuilder, DEFAULT_BLOCK_HEIGHT, DEFAULT_WASM_COSTS};
 
 const INIT_FN_STORE_ID: u32 = 0;
 
@@ -67,7 +67,7 @@ where
     let gas_limit = Gas::new(U512::from(std::u64::MAX));
     let protocol_version = ProtocolVersion::V1_0_0;
     let correlation_id = CorrelationId::new();
-    let arguments: Vec<CLValue> = args.parse().expect("should be able to serialize args");
+    let arguments: RuntimeArgs = args.parse().expect("should be able to serialize args");
     let base_key = Key::Account(address);
 
     let account = builder.get_account(address).expect("should find account");
@@ -96,7 +96,9 @@ where
         BTreeSet::new(),
         &account,
         base_key,
+        runtime_context::new_call_stack(&account, base_key),
         BlockTime::new(block_time),
+        DEFAULT_BLOCK_HEIGHT,
         deploy_hash,
         gas_limit,
         gas_counter,
@@ -6,7 +6,10 @@ use contract::args_parser::ArgsParser;
 use engine_core::engine_state::{deploy_item::DeployItem, execute_request::ExecuteRequest};
 use types::{account::PublicKey, ProtocolVersion};
 
-use crate::internal::{DeployItemBuilder, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT};
+use crate::internal::{
+    DeployItemBuilder, DEFAULT_BLOCK_HEIGHT, DEFAULT_BLOCK_TIME, DEFAULT_PAYMENT,
+    DEFAULT_PROTOCOL_VERSION,
+};
 
 pub struct ExecuteRequestBuilder {
     execute_request: ExecuteRequest,
@@ -26,6 +29,13 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn push_deploys<I: IntoIterator<Item = DeployItem>>(mut self, deploys: I) -> Self {
+        self.execute_request
+            .deploys
+            .extend(deploys.into_iter().map(Ok));
+        self
+    }
+
     pub fn with_pre_state_hash(mut self, pre_state_hash: &[u8]) -> Self {
         self.execute_request.parent_state_hash = pre_state_hash.try_into().unwrap();
         self
@@ -36,6 +46,13 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.execute_request.block_height = block_height;
+        self
+    }
+
+    /// Sets the protocol version the deploys are executed under.  Executing fails with a
+    /// precondition failure unless genesis or an upgrade installed that version.
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.execute_request.protocol_version = protocol_version;
         self
@@ -88,7 +105,8 @@ impl Default for ExecuteRequestBuilder {
     fn default() -> Self {
         let mut execute_request: ExecuteRequest = Default::default();
         execute_request.block_time = DEFAULT_BLOCK_TIME;
-        execute_request.protocol_version = ProtocolVersion::V1_0_0;
+        execute_request.block_height = DEFAULT_BLOCK_HEIGHT;
+        execute_request.protocol_version = *DEFAULT_PROTOCOL_VERSION;
         ExecuteRequestBuilder { execute_request }
     }
 }
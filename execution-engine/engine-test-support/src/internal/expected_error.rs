@@ -0,0 +1,112 @@
+use engine_core::execution::{self, TrapKind};
+use types::ApiError;
+
+/// An execution error which a test expects a deploy to fail with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedError {
+    /// Execution reverted with the given status.
+    Revert(u32),
+    /// Execution ran out of gas.
+    GasLimit,
+    /// A key was not found.
+    KeyNotFound,
+    /// An account was not found.
+    AccountNotFound,
+    /// A value had an unexpected type.
+    TypeMismatch,
+    /// An operation was attempted without the required access rights.
+    InvalidAccess,
+    /// A forged uref was used.
+    ForgedReference,
+    /// A named uref was not found.
+    URefNotFound,
+    /// A function was not found in the module.
+    FunctionNotFound,
+    /// An operation was attempted from an invalid context.
+    InvalidContext,
+    /// The Wasm code of a contract trapped with the given kind of trap.
+    Trap(TrapKind),
+}
+
+impl ExpectedError {
+    /// Returns `true` if `error`, or the host error wrapped by it, is of the expected variant.
+    pub fn matches(self, error: &execution::Error) -> bool {
+        match (self, unwrap_host_error(error)) {
+            (ExpectedError::Revert(expected), execution::Error::Revert(actual))
+            | (
+                ExpectedError::Revert(expected),
+                execution::Error::RevertWithPayload { status: actual, .. },
+            ) => expected == *actual,
+            (ExpectedError::Trap(expected), execution::Error::Trap(actual)) => expected == *actual,
+            (ExpectedError::GasLimit, execution::Error::GasLimit)
+            | (ExpectedError::KeyNotFound, execution::Error::KeyNotFound(_))
+            | (ExpectedError::AccountNotFound, execution::Error::AccountNotFound(_))
+            | (ExpectedError::TypeMismatch, execution::Error::TypeMismatch(_))
+            | (ExpectedError::InvalidAccess, execution::Error::InvalidAccess { .. })
+            | (ExpectedError::ForgedReference, execution::Error::ForgedReference(_))
+            | (ExpectedError::URefNotFound, execution::Error::URefNotFound(_))
+            | (ExpectedError::FunctionNotFound, execution::Error::FunctionNotFound(_))
+            | (ExpectedError::InvalidContext, execution::Error::InvalidContext) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<ApiError> for ExpectedError {
+    fn from(api_error: ApiError) -> Self {
+        ExpectedError::Revert(api_error.into())
+    }
+}
+
+/// Traps raised by host functions are wrapped in an interpreter error, so look inside it for the
+/// original error.
+fn unwrap_host_error(error: &execution::Error) -> &execution::Error {
+    if let execution::Error::Interpreter(interpreter_error) = error {
+        if let Some(host_error) = interpreter_error
+            .as_host_error()
+            .and_then(|host_error| host_error.downcast_ref::<execution::Error>())
+        {
+            return host_error;
+        }
+    }
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{account::PublicKey, Key};
+
+    use super::*;
+
+    #[test]
+    fn should_match_revert_status() {
+        let error = execution::Error::Revert(ApiError::User(100).into());
+        assert!(ExpectedError::from(ApiError::User(100)).matches(&error));
+        assert!(!ExpectedError::from(ApiError::User(101)).matches(&error));
+        assert!(!ExpectedError::GasLimit.matches(&error));
+    }
+
+    #[test]
+    fn should_match_revert_status_ignoring_payload() {
+        let error = execution::Error::RevertWithPayload {
+            status: ApiError::User(100).into(),
+            payload: vec![1, 2, 3],
+        };
+        assert!(ExpectedError::from(ApiError::User(100)).matches(&error));
+        assert!(!ExpectedError::from(ApiError::User(101)).matches(&error));
+    }
+
+    #[test]
+    fn should_match_trap_kind() {
+        let error = execution::Error::Trap(TrapKind::Unreachable);
+        assert!(ExpectedError::Trap(TrapKind::Unreachable).matches(&error));
+        assert!(!ExpectedError::Trap(TrapKind::StackOverflow).matches(&error));
+    }
+
+    #[test]
+    fn should_match_variant_ignoring_fields() {
+        let error = execution::Error::KeyNotFound(Key::Account(PublicKey::ed25519_from([1; 32])));
+        assert!(ExpectedError::KeyNotFound.matches(&error));
+        assert!(!ExpectedError::AccountNotFound.matches(&error));
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    path::PathBuf,
+};
+
+/// An error returned by
+/// [`create_genesis_config_from_file`](super::utils::create_genesis_config_from_file).
+#[derive(Debug)]
+pub enum GenesisFileError {
+    /// The file can't be found in any of the searched locations.
+    NotFound { attempted_paths: Vec<PathBuf> },
+    /// The file can't be read.
+    Read { path: PathBuf, error: io::Error },
+    /// The accounts in the file can't be parsed.
+    Parse {
+        path: PathBuf,
+        error: GenesisAccountsError,
+    },
+}
+
+impl Display for GenesisFileError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            GenesisFileError::NotFound { attempted_paths } => {
+                writeln!(
+                    f,
+                    "failed to find genesis file.  Tried the following locations:"
+                )?;
+                for attempted_path in attempted_paths {
+                    writeln!(f, "    - {}", attempted_path.display())?;
+                }
+                Ok(())
+            }
+            GenesisFileError::Read { path, error } => {
+                write!(f, "failed to read {}: {}", path.display(), error)
+            }
+            GenesisFileError::Parse { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl Error for GenesisFileError {}
+
+/// An error parsing the `[[accounts]]` tables of a genesis file.
+#[derive(Debug)]
+pub enum GenesisAccountsError {
+    /// The contents aren't valid TOML.
+    Toml(toml::de::Error),
+    /// `accounts` is set, but not to an array of tables.
+    AccountsNotArray,
+    /// A field of an account is missing or malformed.
+    InvalidAccount {
+        /// The index of the account among the `[[accounts]]` tables.
+        index: usize,
+        /// The name of the offending field.
+        field: &'static str,
+        /// The 1-based line on which the field is set, or on which the account's table starts if
+        /// it isn't set.
+        line: Option<usize>,
+        /// Why the field was rejected, e.g. `"missing"`.
+        reason: String,
+    },
+}
+
+impl Display for GenesisAccountsError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            GenesisAccountsError::Toml(error) => write!(f, "{}", error),
+            GenesisAccountsError::AccountsNotArray => {
+                write!(f, "`accounts` should be an array of tables")
+            }
+            GenesisAccountsError::InvalidAccount {
+                index,
+                field,
+                line,
+                reason,
+            } => {
+                if let Some(line) = line {
+                    write!(f, "line {}: ", line)?;
+                }
+                write!(f, "accounts[{}].{}: {}", index, field, reason)
+            }
+        }
+    }
+}
+
+impl Error for GenesisAccountsError {}
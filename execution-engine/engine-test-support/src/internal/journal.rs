@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use engine_core::{engine_state::execution_result::ExecutionResult, DeployHash};
+use engine_shared::{newtypes::Blake2bHash, transform::Transform};
+use types::bytesrepr::{self, ToBytes};
+
+/// Values which serialize to at most this many bytes are recorded in full in a journal.  Longer
+/// ones, such as contract bodies, are recorded only by their hash and length.
+const MAX_INLINE_VALUE_LENGTH: usize = 64;
+
+/// A record of every exec request run by a `WasmTestBuilder`, in the order they were run.
+///
+/// As the addresses of new `URef`s are derived from deploy hashes, journals are only reproducible
+/// across runs when every deploy is given a fixed deploy hash.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Journal {
+    pub exec_requests: Vec<ExecJournal>,
+}
+
+/// A record of a single exec request.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecJournal {
+    /// The hex-encoded state hash the request was executed against.
+    pub pre_state_hash: String,
+    pub deploys: Vec<DeployJournal>,
+}
+
+/// A record of the execution of a single deploy.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployJournal {
+    /// The hex-encoded deploy hash, or `None` if the deploy was rejected before it was executed.
+    pub deploy_hash: Option<String>,
+    /// The gas cost, in decimal.
+    pub cost: String,
+    pub error: Option<String>,
+    /// The transforms of the deploy's effect, keyed by the formatted key they apply to.
+    pub transforms: BTreeMap<String, TransformJournal>,
+}
+
+/// A record of a single transform.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransformJournal {
+    /// The name of the `Transform` variant, e.g. "Write".
+    pub kind: String,
+    /// The value written or added, or `None` for transforms without one.
+    pub value: Option<JournalValue>,
+}
+
+/// A value held by a transform.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalValue {
+    /// The hex-encoded serialized value.
+    Bytes(String),
+    /// The hex-encoded BLAKE2b hash and the length of a serialized value too long to record in
+    /// full.
+    Blob { hash: String, length: usize },
+    /// The formatted error of a `Transform::Failure`.
+    Error(String),
+}
+
+impl JournalValue {
+    fn new<T: ToBytes>(value: &T) -> Result<Self, bytesrepr::Error> {
+        let bytes = value.to_bytes()?;
+        if bytes.len() <= MAX_INLINE_VALUE_LENGTH {
+            return Ok(JournalValue::Bytes(base16::encode_lower(&bytes)));
+        }
+        Ok(JournalValue::Blob {
+            hash: base16::encode_lower(&Blake2bHash::new(&bytes).value()),
+            length: bytes.len(),
+        })
+    }
+}
+
+impl TransformJournal {
+    fn new(transform: &Transform) -> Result<Self, bytesrepr::Error> {
+        let (kind, value) = match transform {
+            Transform::Identity => ("Identity", None),
+            Transform::Write(stored_value) => ("Write", Some(JournalValue::new(stored_value)?)),
+            Transform::AddInt32(value) => ("AddInt32", Some(JournalValue::new(value)?)),
+            Transform::AddUInt64(value) => ("AddUInt64", Some(JournalValue::new(value)?)),
+            Transform::AddUInt128(value) => ("AddUInt128", Some(JournalValue::new(value)?)),
+            Transform::AddUInt256(value) => ("AddUInt256", Some(JournalValue::new(value)?)),
+            Transform::AddUInt512(value) => ("AddUInt512", Some(JournalValue::new(value)?)),
+            Transform::AddKeys(named_keys) => ("AddKeys", Some(JournalValue::new(named_keys)?)),
+            Transform::ListAppend(items) => ("ListAppend", Some(JournalValue::new(items)?)),
+            Transform::Delete => ("Delete", None),
+            Transform::Failure(error) => {
+                let value = JournalValue::Error(format!("{:?}", error));
+                ("Failure", Some(value))
+            }
+        };
+        Ok(TransformJournal {
+            kind: kind.to_string(),
+            value,
+        })
+    }
+}
+
+impl DeployJournal {
+    pub(crate) fn new(
+        deploy_hash: Option<DeployHash>,
+        execution_result: &ExecutionResult,
+    ) -> Result<Self, bytesrepr::Error> {
+        let mut transforms = BTreeMap::new();
+        for (key, transform) in execution_result.effect().transforms.iter() {
+            transforms.insert(key.to_string(), TransformJournal::new(transform)?);
+        }
+        Ok(DeployJournal {
+            deploy_hash: deploy_hash.map(|deploy_hash| base16::encode_lower(&deploy_hash)),
+            cost: execution_result.cost().to_string(),
+            error: execution_result.error().map(|error| format!("{:?}", error)),
+            transforms,
+        })
+    }
+}
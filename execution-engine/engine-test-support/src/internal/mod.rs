@@ -1,36 +1,67 @@
+mod account_lookup_error;
 mod additive_map_diff;
 mod deploy_item_builder;
 pub mod exec_with_return;
 mod execute_request_builder;
+mod expected_error;
+mod genesis_file_error;
+mod journal;
+mod query_error;
+mod temp_lmdb_global_state;
+mod test_account;
+mod transfer_request_builder;
+mod transform_matcher;
 mod upgrade_request_builder;
 pub mod utils;
+mod wasm_file_error;
 mod wasm_test_builder;
 
 use lazy_static::lazy_static;
 use num_traits::identities::Zero;
 
-use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig};
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, GenesisConfig},
+    EngineConfig, CONV_RATE,
+};
 use engine_shared::{motes::Motes, test_utils};
 use engine_wasm_prep::wasm_costs::WasmCosts;
 use types::{account::PublicKey, ProtocolVersion, U512};
 
 use super::{DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE};
+pub use account_lookup_error::AccountLookupError;
 pub use additive_map_diff::AdditiveMapDiff;
 pub use deploy_item_builder::DeployItemBuilder;
 pub use execute_request_builder::ExecuteRequestBuilder;
+pub use expected_error::ExpectedError;
+pub use genesis_file_error::{GenesisAccountsError, GenesisFileError};
+pub use journal::{DeployJournal, ExecJournal, Journal, JournalValue, TransformJournal};
+pub use query_error::QueryError;
+pub use temp_lmdb_global_state::TempLmdbGlobalState;
+pub use test_account::{account_name, GenesisAccountBuilder, TestAccount};
+pub use transfer_request_builder::TransferRequestBuilder;
+pub use transform_matcher::{transform_kind, KeyMatcher, TransformMatcher};
 pub use upgrade_request_builder::UpgradeRequestBuilder;
+pub use wasm_file_error::WasmFileError;
 pub use wasm_test_builder::{
-    InMemoryWasmTestBuilder, LmdbWasmTestBuilder, WasmTestBuilder, WasmTestResult,
+    InMemoryWasmTestBuilder, InstrumentationGuard, LmdbWasmTestBuilder, SnapshotGlobalState,
+    StateSnapshot, WasmTestBuilder, WasmTestResult,
 };
 
 pub const MINT_INSTALL_CONTRACT: &str = "mint_install.wasm";
 pub const POS_INSTALL_CONTRACT: &str = "pos_install.wasm";
 pub const STANDARD_PAYMENT_INSTALL_CONTRACT: &str = "standard_payment_install.wasm";
 pub const STANDARD_PAYMENT_CONTRACT: &str = "standard_payment.wasm";
+pub const TRANSFER_TO_ACCOUNT_CONTRACT: &str = "transfer_to_account_u512.wasm";
+pub const TRANSFER_TO_ACCOUNTS_CONTRACT: &str = "transfer_to_accounts_u512.wasm";
 
 pub const DEFAULT_CHAIN_NAME: &str = "gerald";
 pub const DEFAULT_GENESIS_TIMESTAMP: u64 = 0;
 pub const DEFAULT_BLOCK_TIME: u64 = 0;
+pub const DEFAULT_BLOCK_HEIGHT: u64 = 0;
+pub const DEFAULT_UNBONDING_DELAY: u64 = 0;
+pub const DEFAULT_GAS_PRICE: u64 = CONV_RATE;
+/// The number of accounts funded by each deploy of [`WasmTestBuilder::create_funded_accounts`].
+pub const DEFAULT_FUNDING_CHUNK_SIZE: usize = 10;
 pub const MOCKED_ACCOUNT_ADDRESS: PublicKey = PublicKey::ed25519_from([48u8; 32]);
 
 pub const DEFAULT_ACCOUNT_KEY: PublicKey = DEFAULT_ACCOUNT_ADDR;
@@ -49,6 +80,10 @@ lazy_static! {
     pub static ref DEFAULT_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
     pub static ref DEFAULT_PAYMENT: U512 = 100_000_000.into();
     pub static ref DEFAULT_WASM_COSTS: WasmCosts = test_utils::wasm_costs_mock();
+    /// The engine config used by the test builders unless another is given.  Bonding is turned
+    /// off, as in [`EngineConfig::default`].
+    pub static ref DEFAULT_ENGINE_CONFIG: EngineConfig =
+        EngineConfig::new().with_use_system_contracts(cfg!(feature = "use-system-contracts"));
     pub static ref DEFAULT_GENESIS_CONFIG: GenesisConfig = {
         let mint_installer_bytes;
         let pos_installer_bytes;
@@ -73,6 +108,9 @@ lazy_static! {
             standard_payment_installer_bytes,
             DEFAULT_ACCOUNTS.clone(),
             *DEFAULT_WASM_COSTS,
+            DEFAULT_UNBONDING_DELAY,
+            DEFAULT_GAS_PRICE,
+            Vec::new(),
         )
     };
 }
@@ -0,0 +1,67 @@
+use std::fmt::{self, Display, Formatter};
+
+use types::Key;
+
+/// An error returned by a query of global state via a base key and a path of named keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// The state hash queried against is not a root of global state.
+    RootNotFound,
+    /// No value is stored under the base key.
+    BaseKeyNotFound(Key),
+    /// `path[index]` is not one of the named keys of the account or contract reached via the
+    /// preceding segments.
+    NamedKeyNotFound { index: usize, name: String },
+    /// The value reached via the segments preceding `path[index]` is neither an account, a
+    /// contract nor a key to one, so it has no named keys to resolve `path[index]` against.
+    NotTraversable { index: usize, name: String },
+    /// `path[index]` resolved to `key`, but no value is stored under it.
+    ValueNotFound {
+        index: usize,
+        name: String,
+        key: Key,
+    },
+    /// Resolving the path led back to `key`, which had already been visited.
+    CircularReference(Key),
+}
+
+impl QueryError {
+    /// Returns the index and name of the path segment which failed to resolve, or `None` if the
+    /// failure wasn't caused by a particular segment.
+    pub fn failing_segment(&self) -> Option<(usize, &str)> {
+        match self {
+            QueryError::NamedKeyNotFound { index, name }
+            | QueryError::NotTraversable { index, name }
+            | QueryError::ValueNotFound { index, name, .. } => Some((*index, name.as_str())),
+            QueryError::RootNotFound
+            | QueryError::BaseKeyNotFound(_)
+            | QueryError::CircularReference(_) => None,
+        }
+    }
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            QueryError::RootNotFound => write!(f, "State hash is not a root of global state"),
+            QueryError::BaseKeyNotFound(key) => write!(f, "Failed to find base key {}", key),
+            QueryError::NamedKeyNotFound { index, name } => {
+                write!(f, "Path segment {} ({}) is not a named key", index, name)
+            }
+            QueryError::NotTraversable { index, name } => write!(
+                f,
+                "Path segment {} ({}) follows a value which is not an account, contract nor key \
+                 to such",
+                index, name
+            ),
+            QueryError::ValueNotFound { index, name, key } => write!(
+                f,
+                "Path segment {} ({}) resolved to {}, which has no value",
+                index, name, key
+            ),
+            QueryError::CircularReference(key) => {
+                write!(f, "Path leads back to already visited key {}", key)
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use engine_storage::global_state::lmdb::LmdbGlobalState;
+
+use crate::internal::wasm_test_builder;
+
+const TEMP_DIR_PREFIX: &str = "engine-test-support-";
+
+/// LMDB-backed global state held in a uniquely named temporary directory.
+///
+/// The directory is removed when this is dropped, including while unwinding from a panicking
+/// test, so concurrently running builders never share a database nor leave stale ones behind.
+pub struct TempLmdbGlobalState {
+    // Declared before `temp_dir` so that the LMDB environment is closed before the directory is
+    // removed.
+    global_state: LmdbGlobalState,
+    temp_dir: TempDir,
+}
+
+impl TempLmdbGlobalState {
+    /// Creates empty global state in a new temporary directory.
+    pub fn new() -> Self {
+        let temp_dir = tempfile::Builder::new()
+            .prefix(TEMP_DIR_PREFIX)
+            .tempdir()
+            .expect("should create temp dir");
+        let global_state = wasm_test_builder::create_lmdb_global_state(temp_dir.path());
+        TempLmdbGlobalState {
+            global_state,
+            temp_dir,
+        }
+    }
+
+    /// Returns the global state.
+    pub fn global_state(&self) -> &LmdbGlobalState {
+        &self.global_state
+    }
+
+    /// Returns the path of the temporary directory holding the database.
+    pub fn path(&self) -> &Path {
+        self.temp_dir.path()
+    }
+}
+
+impl Default for TempLmdbGlobalState {
+    fn default() -> Self {
+        TempLmdbGlobalState::new()
+    }
+}
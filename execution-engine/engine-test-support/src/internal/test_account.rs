@@ -0,0 +1,201 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use lazy_static::lazy_static;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::{motes::Motes, newtypes::Blake2bHash};
+use types::{account::PublicKey, U512};
+
+use crate::internal::utils::ToPublicKey;
+
+lazy_static! {
+    /// The names of all the test accounts constructed so far, by their public keys.
+    static ref ACCOUNT_NAMES: Mutex<HashMap<PublicKey, &'static str>> = Mutex::new(HashMap::new());
+}
+
+/// Records `name` as the name of `public_key`.
+///
+/// Panics if a different name has already been recorded for `public_key`.
+fn register(name: &'static str, public_key: PublicKey) {
+    let mut account_names = ACCOUNT_NAMES.lock().expect("should lock account names");
+    let existing_name = *account_names.entry(public_key).or_insert(name);
+    if existing_name != name {
+        panic!(
+            "test account names \"{}\" and \"{}\" map to the same public key {}",
+            existing_name, name, public_key
+        );
+    }
+}
+
+/// Returns the name of the [`TestAccount`] with the given public key, or `None` if no such test
+/// account has been constructed.
+pub fn account_name(public_key: PublicKey) -> Option<&'static str> {
+    ACCOUNT_NAMES
+        .lock()
+        .expect("should lock account names")
+        .get(&public_key)
+        .copied()
+}
+
+/// Replaces the hex encoding of the public key of each [`TestAccount`] in `message` with the name
+/// of the account.
+pub(crate) fn name_accounts(message: &str) -> String {
+    let account_names = ACCOUNT_NAMES.lock().expect("should lock account names");
+    account_names
+        .iter()
+        .fold(message.to_string(), |message, (public_key, name)| {
+            message.replace(&base16::encode_lower(public_key.as_bytes()), name)
+        })
+}
+
+/// An account identified by a readable name, whose public key is the Blake2b hash of the name.
+///
+/// The same name always yields the same public key, so tests can refer to e.g. "alice" rather
+/// than to a `[1u8; 32]` literal which is easily reused by accident.  Failures reported via
+/// [`utils::get_error_message`](crate::internal::utils::get_error_message) show the name in
+/// place of the public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestAccount {
+    name: &'static str,
+    public_key: PublicKey,
+}
+
+impl TestAccount {
+    /// Constructs the test account called `name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the public key derived from `name` is the same as that of another test account.
+    pub fn named(name: &'static str) -> Self {
+        let public_key = PublicKey::ed25519_from(Blake2bHash::new(name.as_bytes()).value());
+        register(name, public_key);
+        TestAccount { name, public_key }
+    }
+
+    /// Returns the name of the account.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the public key of the account.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+}
+
+impl From<TestAccount> for PublicKey {
+    fn from(test_account: TestAccount) -> Self {
+        test_account.public_key
+    }
+}
+
+impl ToPublicKey for TestAccount {
+    fn to_public_key(&self) -> Result<PublicKey, String> {
+        Ok(self.public_key)
+    }
+}
+
+/// Builds a [`GenesisAccount`] for a [`TestAccount`], with no balance and no bond unless given.
+///
+/// ```ignore
+/// let accounts = vec![
+///     GenesisAccountBuilder::named("alice").balance(1_000).bonded(100).build(),
+///     GenesisAccountBuilder::named("bob").balance(1_000).into(),
+/// ];
+/// let genesis_config = utils::create_genesis_config(accounts);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GenesisAccountBuilder {
+    account: TestAccount,
+    balance: U512,
+    bonded_amount: U512,
+}
+
+impl GenesisAccountBuilder {
+    /// Starts building the genesis account of the test account called `name`.
+    pub fn named(name: &'static str) -> Self {
+        Self::new(TestAccount::named(name))
+    }
+
+    /// Starts building the genesis account of `account`.
+    pub fn new(account: TestAccount) -> Self {
+        GenesisAccountBuilder {
+            account,
+            balance: U512::zero(),
+            bonded_amount: U512::zero(),
+        }
+    }
+
+    /// Sets the balance of the account's main purse.
+    pub fn balance<T: Into<U512>>(mut self, balance: T) -> Self {
+        self.balance = balance.into();
+        self
+    }
+
+    /// Sets the amount the account is bonded with at genesis.
+    pub fn bonded<T: Into<U512>>(mut self, bonded_amount: T) -> Self {
+        self.bonded_amount = bonded_amount.into();
+        self
+    }
+
+    /// Returns the [`GenesisAccount`] built so far.
+    pub fn build(self) -> GenesisAccount {
+        GenesisAccount::new(
+            self.account.public_key(),
+            Motes::new(self.balance),
+            Motes::new(self.bonded_amount),
+        )
+    }
+}
+
+impl From<GenesisAccountBuilder> for GenesisAccount {
+    fn from(builder: GenesisAccountBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_derive_same_public_key_from_same_name() {
+        let alice = TestAccount::named("alice");
+        assert_eq!(TestAccount::named("alice"), alice);
+        assert_ne!(TestAccount::named("bob").public_key(), alice.public_key());
+        assert_eq!(account_name(alice.public_key()), Some("alice"));
+        assert_eq!(account_name(PublicKey::ed25519_from([0; 32])), None);
+    }
+
+    #[test]
+    fn should_name_accounts_in_message() {
+        let carol = TestAccount::named("carol");
+        let message = format!("{:?} not found", carol.public_key());
+        assert_eq!(
+            name_accounts(&message),
+            "PublicKey(Ed25519(carol)) not found"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "map to the same public key")]
+    fn should_panic_on_colliding_names() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        register("dave", public_key);
+        register("eve", public_key);
+    }
+
+    #[test]
+    fn should_build_genesis_account() {
+        let genesis_account: GenesisAccount = GenesisAccountBuilder::named("frank")
+            .balance(1_000)
+            .bonded(10)
+            .into();
+        assert_eq!(
+            genesis_account.public_key(),
+            TestAccount::named("frank").public_key()
+        );
+        assert_eq!(genesis_account.balance(), Motes::new(1_000.into()));
+        assert_eq!(genesis_account.bonded_amount(), Motes::new(10.into()));
+    }
+}
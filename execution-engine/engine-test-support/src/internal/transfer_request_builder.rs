@@ -0,0 +1,67 @@
+use std::convert::TryInto;
+
+use rand::Rng;
+
+use engine_core::engine_state::transfer_request::TransferRequest;
+use types::{account::PublicKey, ProtocolVersion, U512};
+
+use crate::internal::{DEFAULT_BLOCK_HEIGHT, DEFAULT_BLOCK_TIME};
+
+/// Builds a [`TransferRequest`] for a native transfer, i.e. one executed without any Wasm.
+pub struct TransferRequestBuilder {
+    transfer_request: TransferRequest,
+}
+
+impl TransferRequestBuilder {
+    /// Returns a builder of a transfer of `amount` from `source` to `target`, authorized by
+    /// `source` alone and with a random deploy hash.
+    pub fn new(source: PublicKey, target: PublicKey, amount: U512) -> Self {
+        let mut rng = rand::thread_rng();
+        let transfer_request = TransferRequest {
+            parent_state_hash: [0u8; 32].into(),
+            block_time: DEFAULT_BLOCK_TIME,
+            block_height: DEFAULT_BLOCK_HEIGHT,
+            protocol_version: ProtocolVersion::V1_0_0,
+            source,
+            target,
+            amount,
+            authorization_keys: vec![source].into_iter().collect(),
+            deploy_hash: rng.gen(),
+        };
+        TransferRequestBuilder { transfer_request }
+    }
+
+    pub fn with_pre_state_hash(mut self, pre_state_hash: &[u8]) -> Self {
+        self.transfer_request.parent_state_hash = pre_state_hash.try_into().unwrap();
+        self
+    }
+
+    pub fn with_block_time(mut self, block_time: u64) -> Self {
+        self.transfer_request.block_time = block_time;
+        self
+    }
+
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.transfer_request.block_height = block_height;
+        self
+    }
+
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.transfer_request.protocol_version = protocol_version;
+        self
+    }
+
+    pub fn with_authorization_keys(mut self, authorization_keys: &[PublicKey]) -> Self {
+        self.transfer_request.authorization_keys = authorization_keys.iter().copied().collect();
+        self
+    }
+
+    pub fn with_deploy_hash(mut self, deploy_hash: [u8; 32]) -> Self {
+        self.transfer_request.deploy_hash = deploy_hash;
+        self
+    }
+
+    pub fn build(self) -> TransferRequest {
+        self.transfer_request
+    }
+}
@@ -0,0 +1,162 @@
+use engine_shared::{stored_value::StoredValue, transform::Transform};
+use types::{Key, U512};
+
+/// Matches the key of a transform, for use with
+/// [`WasmTestBuilder::expect_transform`](super::WasmTestBuilder::expect_transform).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMatcher {
+    /// Matches only the given key.
+    Exact(Key),
+    /// Matches any `Key::Account`.
+    AnyAccount,
+    /// Matches any `Key::Hash`.
+    AnyHash,
+    /// Matches any `Key::URef`.
+    AnyURef,
+    /// Matches any `Key::Local`.
+    AnyLocal,
+    /// Matches any key.
+    Any,
+}
+
+impl KeyMatcher {
+    /// Returns `true` if `key` is matched.
+    pub fn matches(self, key: &Key) -> bool {
+        match (self, key) {
+            (KeyMatcher::Exact(expected), actual) => expected == *actual,
+            (KeyMatcher::AnyAccount, Key::Account(_))
+            | (KeyMatcher::AnyHash, Key::Hash(_))
+            | (KeyMatcher::AnyURef, Key::URef(_))
+            | (KeyMatcher::AnyLocal, Key::Local { .. })
+            | (KeyMatcher::Any, _) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<Key> for KeyMatcher {
+    fn from(key: Key) -> Self {
+        KeyMatcher::Exact(key)
+    }
+}
+
+/// Matches a transform, for use with
+/// [`WasmTestBuilder::expect_transform`](super::WasmTestBuilder::expect_transform).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransformMatcher {
+    /// Matches only the given transform.
+    Exact(Transform),
+    /// Matches any transform of the given kind, as returned by [`transform_kind`], e.g.
+    /// `"Write(Account)"` or `"Delete"`.
+    Kind(&'static str),
+    /// Matches a `Transform::Write` of any contract.
+    WriteContract,
+    /// Matches a `Transform::AddUInt512` adding the given amount.
+    AddUInt512(U512),
+    /// Matches a `Transform::AddKeys` adding the given number of named keys.
+    AddKeys(usize),
+    /// Matches any transform.
+    Any,
+}
+
+impl TransformMatcher {
+    /// Returns `true` if `transform` is matched.
+    pub fn matches(&self, transform: &Transform) -> bool {
+        match (self, transform) {
+            (TransformMatcher::Exact(expected), actual) => expected == actual,
+            (TransformMatcher::Kind(kind), actual) => *kind == transform_kind(actual),
+            (TransformMatcher::WriteContract, Transform::Write(StoredValue::Contract(_))) => true,
+            (TransformMatcher::AddUInt512(expected), Transform::AddUInt512(actual)) => {
+                expected == actual
+            }
+            (TransformMatcher::AddKeys(count), Transform::AddKeys(named_keys)) => {
+                *count == named_keys.len()
+            }
+            (TransformMatcher::Any, _) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<Transform> for TransformMatcher {
+    fn from(transform: Transform) -> Self {
+        TransformMatcher::Exact(transform)
+    }
+}
+
+/// Returns the name of the variant of `transform`, including that of the value written by a
+/// `Transform::Write`, e.g. `"Write(Contract)"` or `"AddUInt512"`.
+pub fn transform_kind(transform: &Transform) -> &'static str {
+    match transform {
+        Transform::Identity => "Identity",
+        Transform::Write(StoredValue::CLValue(_)) => "Write(CLValue)",
+        Transform::Write(StoredValue::Account(_)) => "Write(Account)",
+        Transform::Write(StoredValue::Contract(_)) => "Write(Contract)",
+        Transform::AddInt32(_) => "AddInt32",
+        Transform::AddUInt64(_) => "AddUInt64",
+        Transform::AddUInt128(_) => "AddUInt128",
+        Transform::AddUInt256(_) => "AddUInt256",
+        Transform::AddUInt512(_) => "AddUInt512",
+        Transform::AddKeys(_) => "AddKeys",
+        Transform::ListAppend(_) => "ListAppend",
+        Transform::Delete => "Delete",
+        Transform::Failure(_) => "Failure",
+    }
+}
+
+/// Describes `transform` by its kind, along with the amount of an addition or the names of the
+/// keys added, but without the raw bytes of any value written.
+pub(crate) fn describe_transform(transform: &Transform) -> String {
+    match transform {
+        Transform::AddInt32(value) => format!("AddInt32({})", value),
+        Transform::AddUInt64(value) => format!("AddUInt64({})", value),
+        Transform::AddUInt128(value) => format!("AddUInt128({})", value),
+        Transform::AddUInt256(value) => format!("AddUInt256({})", value),
+        Transform::AddUInt512(value) => format!("AddUInt512({})", value),
+        Transform::AddKeys(named_keys) => {
+            let names: Vec<&str> = named_keys.keys().map(String::as_str).collect();
+            format!("AddKeys({})", names.join(", "))
+        }
+        Transform::Failure(error) => format!("Failure({:?})", error),
+        _ => transform_kind(transform).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use types::{account::PublicKey, AccessRights, CLValue, URef};
+
+    use super::*;
+
+    #[test]
+    fn should_match_key_kind() {
+        let account = Key::Account(PublicKey::ed25519_from([1; 32]));
+        let uref = Key::URef(URef::new([1; 32], AccessRights::READ));
+        assert!(KeyMatcher::from(account).matches(&account));
+        assert!(KeyMatcher::AnyAccount.matches(&account));
+        assert!(!KeyMatcher::AnyAccount.matches(&uref));
+        assert!(KeyMatcher::AnyURef.matches(&uref));
+        assert!(KeyMatcher::Any.matches(&uref));
+    }
+
+    #[test]
+    fn should_match_transform_kind() {
+        let write = Transform::Write(StoredValue::CLValue(CLValue::from_t(1).unwrap()));
+        assert!(TransformMatcher::Kind("Write(CLValue)").matches(&write));
+        assert!(!TransformMatcher::WriteContract.matches(&write));
+        assert!(TransformMatcher::from(write.clone()).matches(&write));
+
+        let add = Transform::AddUInt512(U512::from(10));
+        assert!(TransformMatcher::AddUInt512(U512::from(10)).matches(&add));
+        assert!(!TransformMatcher::AddUInt512(U512::from(11)).matches(&add));
+
+        let mut named_keys = BTreeMap::new();
+        named_keys.insert("a".to_string(), Key::Hash([1; 32]));
+        named_keys.insert("b".to_string(), Key::Hash([2; 32]));
+        let add_keys = Transform::AddKeys(named_keys);
+        assert!(TransformMatcher::AddKeys(2).matches(&add_keys));
+        assert_eq!(describe_transform(&add_keys), "AddKeys(a, b)");
+    }
+}
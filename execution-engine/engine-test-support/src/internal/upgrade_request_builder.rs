@@ -42,7 +42,7 @@ impl UpgradeRequestBuilder {
         self
     }
 
-    pub fn with_new_costs(mut self, wasm_costs: WasmCosts) -> Self {
+    pub fn with_new_wasm_costs(mut self, wasm_costs: WasmCosts) -> Self {
         let mut new_costs = ChainSpec_CostTable_WasmCosts::new();
         new_costs.set_regular(wasm_costs.regular);
         new_costs.set_opcodes_mul(wasm_costs.opcodes_mul);
@@ -54,6 +54,12 @@ impl UpgradeRequestBuilder {
         new_costs.set_max_stack_height(wasm_costs.max_stack_height);
         new_costs.set_mem(wasm_costs.mem);
         new_costs.set_memcpy(wasm_costs.memcpy);
+        new_costs.set_storage_per_byte(wasm_costs.storage_per_byte);
+        new_costs.set_native_transfer(wasm_costs.native_transfer);
+        new_costs.set_hash_per_byte(wasm_costs.hash_per_byte);
+        new_costs.set_max_initial_memory_pages(wasm_costs.max_initial_memory_pages);
+        new_costs.set_max_memory_pages(wasm_costs.max_memory_pages);
+        new_costs.set_preprocess_per_byte(wasm_costs.preprocess_per_byte);
         self.new_costs = Some(new_costs);
         self
     }
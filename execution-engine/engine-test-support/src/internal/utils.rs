@@ -2,23 +2,39 @@ use std::{
     env, fs,
     path::{Path, PathBuf},
     rc::Rc,
+    sync::RwLock,
 };
 
+use contract::args_parser::ArgsParser;
 use lazy_static::lazy_static;
+use num_traits::identities::Zero;
+use toml::Value;
 
-use engine_core::engine_state::{
-    execution_result::ExecutionResult,
-    genesis::{GenesisAccount, GenesisConfig},
+use engine_core::{
+    engine_state::{
+        self,
+        execution_result::{ExecutionResult, PhaseCosts},
+        genesis::{GenesisAccount, GenesisConfig, GenesisContract},
+    },
+    execution,
 };
+use engine_grpc_server::engine_server::ipc::CommitResponse;
 use engine_shared::{
-    account::Account, additive_map::AdditiveMap, gas::Gas, stored_value::StoredValue,
-    transform::Transform,
+    account::Account, additive_map::AdditiveMap, gas::Gas, motes::Motes, stored_value::StoredValue,
+    transform::Transform, TypeMismatch,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use mint::TransferRecord;
+use types::{
+    account::{PublicKey, ED25519_LENGTH},
+    ApiError, CLTyped, Key, URef, U512,
 };
-use types::Key;
 
 use crate::internal::{
-    DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_TIMESTAMP, DEFAULT_PROTOCOL_VERSION, DEFAULT_WASM_COSTS,
-    MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    test_account, AccountLookupError, GenesisAccountsError, GenesisFileError, WasmFileError,
+    DEFAULT_CHAIN_NAME, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG, DEFAULT_GENESIS_TIMESTAMP,
+    DEFAULT_PROTOCOL_VERSION, DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
+    POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
 };
 
 lazy_static! {
@@ -53,8 +69,16 @@ lazy_static! {
         .expect("CARGO_MANIFEST_DIR should have parent")
         .join("target-as");
     static ref WASM_PATHS: Vec<PathBuf> = get_compiled_wasm_paths();
+    // The locations registered via `set_wasm_search_paths` and `push_wasm_search_path`.
+    static ref REGISTERED_WASM_PATHS: RwLock<Vec<PathBuf>> = RwLock::new(Vec::new());
 }
 
+/// The environment variable which, if set, lists the directories to search for compiled Wasm files
+/// before any others.  The directories are separated as in the `PATH` environment variable.
+pub const WASM_SEARCH_PATHS_ENV_VAR: &str = "CL_WASM_SEARCH_PATHS";
+
+const WASM_FILE_EXTENSION: &str = "wasm";
+
 /// Constructs a list of paths that should be considered while looking for a compiled wasm file.
 fn get_compiled_wasm_paths() -> Vec<PathBuf> {
     let mut ret = vec![
@@ -70,45 +94,108 @@ fn get_compiled_wasm_paths() -> Vec<PathBuf> {
     ret
 }
 
-/// Reads a given compiled contract file based on path
-pub fn read_wasm_file_bytes<T: AsRef<Path>>(contract_file: T) -> Vec<u8> {
+/// Replaces the directories registered to be searched for compiled Wasm files, e.g. by a crate
+/// whose contracts are built outside the default locations.
+///
+/// Registered directories are searched after those listed in [`WASM_SEARCH_PATHS_ENV_VAR`], but
+/// before the default locations.
+pub fn set_wasm_search_paths(paths: Vec<PathBuf>) {
+    *REGISTERED_WASM_PATHS
+        .write()
+        .expect("should lock registered wasm paths") = paths;
+}
+
+/// Registers `path` to be searched for compiled Wasm files after the directories already
+/// registered.  See [`set_wasm_search_paths`].
+pub fn push_wasm_search_path<T: Into<PathBuf>>(path: T) {
+    REGISTERED_WASM_PATHS
+        .write()
+        .expect("should lock registered wasm paths")
+        .push(path.into());
+}
+
+/// Returns the directories searched for compiled Wasm files, in order of precedence: those listed
+/// in [`WASM_SEARCH_PATHS_ENV_VAR`], then the registered ones, then the default locations.
+pub fn wasm_search_paths() -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = env::var_os(WASM_SEARCH_PATHS_ENV_VAR)
+        .map(|paths| env::split_paths(&paths).collect())
+        .unwrap_or_default();
+    paths.extend(
+        REGISTERED_WASM_PATHS
+            .read()
+            .expect("should lock registered wasm paths")
+            .iter()
+            .cloned(),
+    );
+    paths.extend(WASM_PATHS.iter().cloned());
+    paths
+}
+
+/// Reads a given compiled contract file based on path, returning an error listing the attempted
+/// locations if it can't be found.
+///
+/// A relative path is looked for in each of [`wasm_search_paths`] in turn.  A path without an
+/// extension is given the `.wasm` one, so a contract can be given by its name.
+pub fn try_read_wasm_file_bytes<T: AsRef<Path>>(
+    contract_file: T,
+) -> Result<Vec<u8>, WasmFileError> {
+    let contract_file = contract_file.as_ref();
+    let contract_file = if contract_file.extension().is_none() {
+        contract_file.with_extension(WASM_FILE_EXTENSION)
+    } else {
+        contract_file.to_owned()
+    };
     let mut attempted_paths = vec![];
 
-    if contract_file.as_ref().is_relative() {
+    if contract_file.is_relative() {
         // Find first path to a given file found in a list of paths
-        for wasm_path in WASM_PATHS.iter() {
-            let mut filename = wasm_path.clone();
-            filename.push(contract_file.as_ref());
+        for wasm_path in wasm_search_paths() {
+            let filename = wasm_path.join(&contract_file);
             if let Ok(wasm_bytes) = fs::read(&filename) {
-                return wasm_bytes;
+                return Ok(wasm_bytes);
             }
             attempted_paths.push(filename);
         }
     }
     // Try just opening in case the arg is a valid path relative to current working dir, or is a
     // valid absolute path.
-    if let Ok(wasm_bytes) = fs::read(contract_file.as_ref()) {
-        return wasm_bytes;
+    if let Ok(wasm_bytes) = fs::read(&contract_file) {
+        return Ok(wasm_bytes);
     }
-    attempted_paths.push(contract_file.as_ref().to_owned());
+    attempted_paths.push(contract_file.clone());
 
-    let mut error_msg =
-        "\nFailed to open compiled Wasm file.  Tried the following locations:\n".to_string();
-    for attempted_path in attempted_paths {
-        error_msg = format!("{}    - {}\n", error_msg, attempted_path.display());
-    }
+    Err(WasmFileError::new(contract_file, attempted_paths))
+}
 
-    panic!("{}\n", error_msg);
+/// Reads a given compiled contract file based on path like [`try_read_wasm_file_bytes`], panicking
+/// with the attempted locations if it can't be found.
+pub fn read_wasm_file_bytes<T: AsRef<Path>>(contract_file: T) -> Vec<u8> {
+    try_read_wasm_file_bytes(contract_file).unwrap_or_else(|error| panic!("\n{}", error))
 }
 
 pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
+    create_custom_genesis_config(
+        accounts,
+        *DEFAULT_WASM_COSTS,
+        DEFAULT_UNBONDING_DELAY,
+        DEFAULT_GAS_PRICE,
+    )
+}
+
+/// Like [`create_genesis_config`], but with the given Wasm costs, unbonding delay and gas price in
+/// place of the defaults.
+pub fn create_custom_genesis_config(
+    accounts: Vec<GenesisAccount>,
+    wasm_costs: WasmCosts,
+    unbonding_delay: u64,
+    gas_price: u64,
+) -> GenesisConfig {
     let name = DEFAULT_CHAIN_NAME.to_string();
     let timestamp = DEFAULT_GENESIS_TIMESTAMP;
     let mint_installer_bytes = read_wasm_file_bytes(MINT_INSTALL_CONTRACT);
     let proof_of_stake_installer_bytes = read_wasm_file_bytes(POS_INSTALL_CONTRACT);
     let standard_payment_installer_bytes = read_wasm_file_bytes(STANDARD_PAYMENT_INSTALL_CONTRACT);
     let protocol_version = *DEFAULT_PROTOCOL_VERSION;
-    let wasm_costs = *DEFAULT_WASM_COSTS;
     GenesisConfig::new(
         name,
         timestamp,
@@ -118,9 +205,190 @@ pub fn create_genesis_config(accounts: Vec<GenesisAccount>) -> GenesisConfig {
         standard_payment_installer_bytes,
         accounts,
         wasm_costs,
+        unbonding_delay,
+        gas_price,
+        Vec::new(),
     )
 }
 
+/// Returns a copy of [`DEFAULT_GENESIS_CONFIG`] whose Wasm costs have been modified by
+/// `modify_wasm_costs`, e.g. `genesis_config_with(|wasm_costs| wasm_costs.storage_per_byte = 1)`.
+pub fn genesis_config_with(modify_wasm_costs: impl FnOnce(&mut WasmCosts)) -> GenesisConfig {
+    let mut wasm_costs = DEFAULT_GENESIS_CONFIG.wasm_costs();
+    modify_wasm_costs(&mut wasm_costs);
+    GenesisConfig::new(
+        DEFAULT_GENESIS_CONFIG.name().to_string(),
+        DEFAULT_GENESIS_CONFIG.timestamp(),
+        DEFAULT_GENESIS_CONFIG.protocol_version(),
+        DEFAULT_GENESIS_CONFIG.mint_installer_bytes().to_vec(),
+        DEFAULT_GENESIS_CONFIG
+            .proof_of_stake_installer_bytes()
+            .to_vec(),
+        DEFAULT_GENESIS_CONFIG
+            .standard_payment_installer_bytes()
+            .to_vec(),
+        DEFAULT_GENESIS_CONFIG.accounts().to_vec(),
+        wasm_costs,
+        DEFAULT_GENESIS_CONFIG.unbonding_delay(),
+        DEFAULT_GENESIS_CONFIG.gas_price(),
+        DEFAULT_GENESIS_CONFIG.genesis_contracts().to_vec(),
+    )
+}
+
+/// Creates a [`GenesisContract`] installed by the compiled contract file `installer_file` with
+/// `args`.  Add it to a config with [`GenesisConfig::with_genesis_contract`].
+pub fn create_genesis_contract<T: AsRef<Path>>(
+    name: &str,
+    installer_file: T,
+    args: impl ArgsParser,
+) -> GenesisContract {
+    let installer_bytes = read_wasm_file_bytes(installer_file);
+    let args = args
+        .parse()
+        .expect("should convert to `RuntimeArgs`")
+        .into_bytes()
+        .expect("should serialize args");
+    GenesisContract::new(name.to_string(), installer_bytes, args)
+}
+
+/// Creates a [`GenesisConfig`] with the default values, but with the accounts read from the
+/// chainspec-style TOML file at `path`.
+///
+/// A relative `path` is resolved against `CARGO_MANIFEST_DIR` of the running tests, then against
+/// the current working dir.  Each account is given as an `[[accounts]]` table with a hex-encoded
+/// `public_key`, and a `balance` and optional `bonded_amount` in motes, e.g.
+///
+/// ```toml
+/// [[accounts]]
+/// public_key = "0101010101010101010101010101010101010101010101010101010101010101"
+/// balance = "100000000000"
+/// bonded_amount = "0"
+/// ```
+pub fn create_genesis_config_from_file<T: AsRef<Path>>(
+    path: T,
+) -> Result<GenesisConfig, GenesisFileError> {
+    let path = resolve_genesis_file_path(path.as_ref())?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) => return Err(GenesisFileError::Read { path, error }),
+    };
+    match parse_genesis_accounts(&contents) {
+        Ok(accounts) => Ok(create_genesis_config(accounts)),
+        Err(error) => Err(GenesisFileError::Parse { path, error }),
+    }
+}
+
+fn resolve_genesis_file_path(path: &Path) -> Result<PathBuf, GenesisFileError> {
+    if path.is_absolute() {
+        return Ok(path.to_owned());
+    }
+
+    let mut attempted_paths = vec![];
+    let base_dirs = env::var("CARGO_MANIFEST_DIR")
+        .ok()
+        .map(PathBuf::from)
+        .into_iter()
+        .chain(env::current_dir().ok());
+    for base_dir in base_dirs {
+        let candidate = base_dir.join(path);
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+        attempted_paths.push(candidate);
+    }
+
+    Err(GenesisFileError::NotFound { attempted_paths })
+}
+
+fn parse_genesis_accounts(contents: &str) -> Result<Vec<GenesisAccount>, GenesisAccountsError> {
+    const ACCOUNTS: &str = "accounts";
+
+    let value: Value = contents.parse().map_err(GenesisAccountsError::Toml)?;
+    let entries = match value.get(ACCOUNTS) {
+        Some(Value::Array(entries)) => entries,
+        Some(_) => return Err(GenesisAccountsError::AccountsNotArray),
+        None => return Ok(Vec::new()),
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            parse_genesis_account(entry).map_err(|(field, reason)| {
+                GenesisAccountsError::InvalidAccount {
+                    index,
+                    field,
+                    line: find_account_field_line(contents, index, field),
+                    reason,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Parses a single account table, returning the name of the offending field on error.
+fn parse_genesis_account(entry: &Value) -> Result<GenesisAccount, (&'static str, String)> {
+    const PUBLIC_KEY: &str = "public_key";
+    const BALANCE: &str = "balance";
+    const BONDED_AMOUNT: &str = "bonded_amount";
+
+    let public_key = match entry.get(PUBLIC_KEY) {
+        Some(Value::String(hex)) => match public_key_from_hex(hex) {
+            Ok(public_key) => public_key,
+            Err(reason) => return Err((PUBLIC_KEY, reason)),
+        },
+        Some(_) => return Err((PUBLIC_KEY, "should be a hex string".to_string())),
+        None => return Err((PUBLIC_KEY, "missing".to_string())),
+    };
+    let balance = match entry.get(BALANCE) {
+        Some(value) => parse_motes(value).map_err(|reason| (BALANCE, reason))?,
+        None => return Err((BALANCE, "missing".to_string())),
+    };
+    let bonded_amount = match entry.get(BONDED_AMOUNT) {
+        Some(value) => parse_motes(value).map_err(|reason| (BONDED_AMOUNT, reason))?,
+        None => Motes::zero(),
+    };
+
+    Ok(GenesisAccount::new(public_key, balance, bonded_amount))
+}
+
+fn parse_motes(value: &Value) -> Result<Motes, String> {
+    match value {
+        Value::Integer(amount) if *amount >= 0 => Ok(Motes::new(U512::from(*amount as u64))),
+        Value::String(amount) => U512::from_dec_str(amount)
+            .map(Motes::new)
+            .map_err(|error| format!("invalid amount {:?}: {:?}", amount, error)),
+        _ => Err("should be a non-negative integer or a decimal string".to_string()),
+    }
+}
+
+/// Finds the 1-based line on which `field` is set in the `index`-th `[[accounts]]` table, falling
+/// back to the table header if the field isn't set.
+fn find_account_field_line(contents: &str, index: usize, field: &str) -> Option<usize> {
+    let mut header_line = None;
+    let mut tables_seen = 0;
+    for (line_index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            if header_line.is_some() {
+                break;
+            }
+            if line.starts_with("[[accounts]]") {
+                if tables_seen == index {
+                    header_line = Some(line_index + 1);
+                }
+                tables_seen += 1;
+            }
+        } else if header_line.is_some()
+            && line.starts_with(field)
+            && line[field.len()..].trim_start().starts_with('=')
+        {
+            return Some(line_index + 1);
+        }
+    }
+    header_line
+}
+
 pub fn get_exec_costs<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
     exec_response: I,
 ) -> Vec<Gas> {
@@ -130,6 +398,209 @@ pub fn get_exec_costs<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
         .collect()
 }
 
+/// Returns the costs of the payment, session and finalization phases of each deploy.
+pub fn get_phase_costs<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
+    exec_response: I,
+) -> Vec<PhaseCosts> {
+    exec_response
+        .into_iter()
+        .map(|res| res.as_ref().phase_costs().expect("should have phase costs"))
+        .collect()
+}
+
+/// The environment variable which, if set, makes [`GasLedger::assert_matches_baseline`] overwrite
+/// the baseline file with the recorded costs rather than compare against it.
+pub const REGENERATE_GAS_BASELINES_ENV_VAR: &str = "CL_REGENERATE_GAS_BASELINES";
+
+/// Asserts that `actual` is within `tolerance_percent` percent of `expected`.
+///
+/// A zero `expected` only matches a zero `actual`, whatever the tolerance.
+pub fn assert_gas_cost(actual: Gas, expected: Gas, tolerance_percent: f64) {
+    assert!(
+        is_within_tolerance(actual, expected, tolerance_percent),
+        "gas cost {} differs from expected {} by {}, which exceeds the tolerance of {}%",
+        actual.value(),
+        expected.value(),
+        format_gas_change(expected, actual),
+        tolerance_percent
+    );
+}
+
+fn is_within_tolerance(actual: Gas, expected: Gas, tolerance_percent: f64) -> bool {
+    let difference = if actual > expected {
+        actual - expected
+    } else {
+        expected - actual
+    };
+    gas_to_f64(difference) <= gas_to_f64(expected) * tolerance_percent / 100.0
+}
+
+fn gas_to_f64(gas: Gas) -> f64 {
+    gas.value()
+        .to_string()
+        .parse()
+        .expect("decimal U512 should parse as f64")
+}
+
+/// Formats the change from `old` to `new` as a signed percentage of `old`.
+fn format_gas_change(old: Gas, new: Gas) -> String {
+    if old == new {
+        return "0.00%".to_string();
+    }
+    if old.value().is_zero() {
+        return "+inf%".to_string();
+    }
+    let change = (gas_to_f64(new) - gas_to_f64(old)) / gas_to_f64(old) * 100.0;
+    format!("{:+.2}%", change)
+}
+
+/// Records the gas cost of each deploy of a scenario in order, so that the costs can be compared
+/// against a baseline recorded by an earlier run.
+///
+/// A baseline is stored as text, one `<deploy index> <cost>` line per deploy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GasLedger {
+    costs: Vec<Gas>,
+}
+
+impl GasLedger {
+    pub fn new() -> Self {
+        GasLedger::default()
+    }
+
+    /// Records the cost of the next deploy.
+    pub fn record(&mut self, cost: Gas) {
+        self.costs.push(cost);
+    }
+
+    /// Records the costs of the next deploys in order, e.g. as returned by [`get_exec_costs`].
+    pub fn record_all<I: IntoIterator<Item = Gas>>(&mut self, costs: I) {
+        self.costs.extend(costs);
+    }
+
+    pub fn costs(&self) -> &[Gas] {
+        &self.costs
+    }
+
+    /// Returns the recorded costs in the baseline format.
+    pub fn to_baseline(&self) -> String {
+        self.costs
+            .iter()
+            .enumerate()
+            .map(|(index, cost)| format!("{} {}\n", index, cost.value()))
+            .collect()
+    }
+
+    /// Parses a ledger from the baseline format, ignoring blank lines.
+    pub fn from_baseline(baseline: &str) -> Result<Self, String> {
+        let mut ledger = GasLedger::new();
+        let lines = baseline
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        for (line_index, line) in lines {
+            let mut fields = line.split_whitespace();
+            let index = fields.next().and_then(|index| index.parse::<usize>().ok());
+            let cost = fields.next().and_then(|cost| U512::from_dec_str(cost).ok());
+            match (index, cost, fields.next()) {
+                (Some(index), Some(cost), None) if index == ledger.costs.len() => {
+                    ledger.record(Gas::new(cost))
+                }
+                _ => {
+                    return Err(format!(
+                        "line {}: expected `{} <cost>`, but got {:?}",
+                        line_index + 1,
+                        ledger.costs.len(),
+                        line
+                    ))
+                }
+            }
+        }
+        Ok(ledger)
+    }
+
+    /// Returns a table of the deploys whose recorded cost isn't within `tolerance_percent` percent
+    /// of their cost in `baseline`, or `None` if all are.
+    ///
+    /// Deploys recorded in only one of the ledgers are always listed, with a `-` for the missing
+    /// cost.
+    pub fn diff(&self, baseline: &GasLedger, tolerance_percent: f64) -> Option<String> {
+        fn format_cost(cost: Option<&Gas>) -> String {
+            cost.map(|cost| cost.value().to_string())
+                .unwrap_or_else(|| "-".to_string())
+        }
+
+        let deploy_count = self.costs.len().max(baseline.costs.len());
+        let rows = (0..deploy_count)
+            .filter_map(|index| {
+                let old = baseline.costs.get(index);
+                let new = self.costs.get(index);
+                let change = match (old, new) {
+                    (Some(&old), Some(&new)) => {
+                        if is_within_tolerance(new, old, tolerance_percent) {
+                            return None;
+                        }
+                        format_gas_change(old, new)
+                    }
+                    _ => "-".to_string(),
+                };
+                Some(format!(
+                    "{:>6}  {:>20}  {:>20}  {:>10}\n",
+                    index,
+                    format_cost(old),
+                    format_cost(new),
+                    change
+                ))
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return None;
+        }
+
+        let header = format!(
+            "{:>6}  {:>20}  {:>20}  {:>10}\n",
+            "deploy", "old", "new", "change"
+        );
+        Some(header + &rows.concat())
+    }
+
+    /// Asserts that the recorded costs are within `tolerance_percent` percent of those in the
+    /// baseline file at `path`, panicking with a table of the differing deploys if not.
+    ///
+    /// If [`REGENERATE_GAS_BASELINES_ENV_VAR`] is set, the baseline file is instead overwritten
+    /// with the recorded costs.
+    pub fn assert_matches_baseline<T: AsRef<Path>>(&self, path: T, tolerance_percent: f64) {
+        let path = path.as_ref();
+        if env::var_os(REGENERATE_GAS_BASELINES_ENV_VAR).is_some() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).expect("should create baseline dir");
+            }
+            fs::write(path, self.to_baseline()).expect("should write baseline");
+            return;
+        }
+
+        let contents = fs::read_to_string(path).unwrap_or_else(|error| {
+            panic!(
+                "failed to read gas baseline {}: {}.  Set {} to create it.",
+                path.display(),
+                error,
+                REGENERATE_GAS_BASELINES_ENV_VAR
+            )
+        });
+        let baseline = GasLedger::from_baseline(&contents)
+            .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+        if let Some(diff) = self.diff(&baseline, tolerance_percent) {
+            panic!(
+                "gas costs differ from baseline {} by more than {}%.  Set {} to update it.\n{}",
+                path.display(),
+                tolerance_percent,
+                REGENERATE_GAS_BASELINES_ENV_VAR,
+                diff
+            );
+        }
+    }
+}
+
 pub fn get_success_result(response: &[Rc<ExecutionResult>]) -> &ExecutionResult {
     &*response.get(0).expect("should have a result")
 }
@@ -143,6 +614,18 @@ pub fn get_precondition_failure(response: &[Rc<ExecutionResult>]) -> String {
     format!("{}", result.error().expect("should have an error"))
 }
 
+pub fn get_commit_type_mismatch(commit_response: &CommitResponse) -> TypeMismatch {
+    assert!(
+        commit_response.has_type_mismatch(),
+        "should be a type mismatch"
+    );
+    let type_mismatch = commit_response.get_type_mismatch();
+    TypeMismatch::new(
+        type_mismatch.get_expected().to_string(),
+        type_mismatch.get_found().to_string(),
+    )
+}
+
 pub fn get_error_message<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
     execution_result: I,
 ) -> String {
@@ -151,7 +634,7 @@ pub fn get_error_message<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
         .enumerate()
         .filter_map(|(i, result)| {
             if let ExecutionResult::Failure { error, .. } = result.as_ref() {
-                Some(format!("{}: {:?}", i, error))
+                Some(test_account::name_accounts(&format!("{}: {:?}", i, error)))
             } else {
                 None
             }
@@ -160,6 +643,71 @@ pub fn get_error_message<T: AsRef<ExecutionResult>, I: IntoIterator<Item = T>>(
     errors.join("\n")
 }
 
+/// Returns the payload of the revert `execution_result` failed with, or `None` if it didn't fail
+/// with a revert carrying a payload.
+pub fn get_revert_payload(execution_result: &ExecutionResult) -> Option<&[u8]> {
+    match execution_result {
+        ExecutionResult::Failure {
+            error: engine_state::Error::Exec(execution::Error::RevertWithPayload { payload, .. }),
+            ..
+        } => Some(payload),
+        _ => None,
+    }
+}
+
+/// Returns the [`ApiError`] `execution_result` reverted with, or `None` if it didn't fail with a
+/// revert.
+pub fn get_revert_error(execution_result: &ExecutionResult) -> Option<ApiError> {
+    match execution_result {
+        ExecutionResult::Failure {
+            error: engine_state::Error::Exec(execution::Error::Revert(status)),
+            ..
+        }
+        | ExecutionResult::Failure {
+            error: engine_state::Error::Exec(execution::Error::RevertWithPayload { status, .. }),
+            ..
+        } => Some(ApiError::from(*status)),
+        _ => None,
+    }
+}
+
+/// Parses a hex-encoded Ed25519 public key, e.g. as printed by `base16::encode_lower`.
+pub fn public_key_from_hex(hex: &str) -> Result<PublicKey, String> {
+    let bytes = base16::decode(hex).map_err(|error| format!("invalid hex: {}", error))?;
+    PublicKey::ed25519_try_from(&bytes)
+        .map_err(|_| format!("expected {} bytes, but got {}", ED25519_LENGTH, bytes.len()))
+}
+
+/// Identifies an account either by its public key or by the hex encoding of one.
+pub trait ToPublicKey {
+    fn to_public_key(&self) -> Result<PublicKey, String>;
+}
+
+impl ToPublicKey for PublicKey {
+    fn to_public_key(&self) -> Result<PublicKey, String> {
+        Ok(*self)
+    }
+}
+
+impl ToPublicKey for &str {
+    fn to_public_key(&self) -> Result<PublicKey, String> {
+        public_key_from_hex(self)
+    }
+}
+
+impl ToPublicKey for String {
+    fn to_public_key(&self) -> Result<PublicKey, String> {
+        public_key_from_hex(self)
+    }
+}
+
+/// Returns the public key identified by `account`, panicking with the reason if it's malformed.
+pub(crate) fn expect_public_key<T: ToPublicKey>(account: T) -> PublicKey {
+    account
+        .to_public_key()
+        .unwrap_or_else(|reason| panic!("invalid public key: {}", reason))
+}
+
 #[allow(clippy::implicit_hasher)]
 pub fn get_account(transforms: &AdditiveMap<Key, Transform>, account: &Key) -> Option<Account> {
     transforms.get(account).and_then(|transform| {
@@ -170,3 +718,325 @@ pub fn get_account(transforms: &AdditiveMap<Key, Transform>, account: &Key) -> O
         }
     })
 }
+
+/// Returns the account under `account` as it is after applying `transforms` to
+/// `pre_state_account`, the account stored under that key before they were applied.
+///
+/// Unlike [`get_account`], this also reflects accounts which are only mutated by additive
+/// transforms such as `AddKeys` rather than written wholesale.
+#[allow(clippy::implicit_hasher)]
+pub fn get_effective_account(
+    pre_state_account: Option<Account>,
+    transforms: &AdditiveMap<Key, Transform>,
+    account: &Key,
+) -> Result<Account, AccountLookupError> {
+    let transform = match transforms.get(account) {
+        Some(Transform::Write(StoredValue::Account(account))) => return Ok(account.to_owned()),
+        Some(transform) => transform.clone(),
+        None => return pre_state_account.ok_or_else(|| AccountLookupError::NotFound(*account)),
+    };
+    let pre_state_account =
+        pre_state_account.ok_or_else(|| AccountLookupError::NotFound(*account))?;
+    match transform.apply(StoredValue::Account(pre_state_account)) {
+        Ok(StoredValue::Account(account)) => Ok(account),
+        Ok(other) => {
+            let expected = "Account".to_string();
+            let type_mismatch = TypeMismatch::new(expected, other.type_name());
+            Err(AccountLookupError::TransformMismatch(type_mismatch.into()))
+        }
+        Err(error) => Err(AccountLookupError::TransformMismatch(error)),
+    }
+}
+
+/// Returns the account written by `transforms` under the key of the account identified by
+/// `account`, which may be a [`PublicKey`] or its hex encoding.
+///
+/// Panics if `account` is malformed hex or isn't the length of a public key.
+#[allow(clippy::implicit_hasher)]
+pub fn get_account_by_public_key<T: ToPublicKey>(
+    transforms: &AdditiveMap<Key, Transform>,
+    account: T,
+) -> Option<Account> {
+    get_account(transforms, &Key::Account(expect_public_key(account)))
+}
+
+/// Returns the ids of the transfer records made via `transfer_from_purse_to_purse_with_id` which
+/// the given execution result wrote to the local state of `mint`, i.e. the mint contract's uref.
+/// Transfers made without an id aren't recorded.
+///
+/// Each transfer has a record of its own, so an id appears once per transfer made in the execution.
+/// The order of the returned ids is unspecified.
+pub fn get_transfer_ids(execution_result: &ExecutionResult, mint: URef) -> Vec<u64> {
+    let mint_seed = Key::URef(mint).local_seed();
+    let record_type = TransferRecord::cl_type();
+    execution_result
+        .effect()
+        .transforms
+        .iter()
+        .filter_map(|(key, transform)| match (key, transform) {
+            (Key::Local { seed, .. }, Transform::Write(StoredValue::CLValue(cl_value)))
+                if *seed == mint_seed && *cl_value.cl_type() == record_type =>
+            {
+                cl_value.clone().into_t::<TransferRecord>().ok()
+            }
+            _ => None,
+        })
+        .map(|(_purse_addr, id, _amount)| id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, iter};
+
+    use types::{AccessRights, CLValue, URef};
+
+    use super::*;
+
+    const PUBLIC_KEY_HEX: &str = "0101010101010101010101010101010101010101010101010101010101010101";
+
+    #[test]
+    fn should_parse_genesis_accounts() {
+        let contents = format!(
+            "[[accounts]]\npublic_key = \"{}\"\nbalance = \"100\"\n\n\
+             [[accounts]]\npublic_key = \"{}\"\nbalance = 5\nbonded_amount = \"2\"\n",
+            PUBLIC_KEY_HEX, PUBLIC_KEY_HEX
+        );
+        let accounts = parse_genesis_accounts(&contents).expect("should parse accounts");
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].public_key(), PublicKey::ed25519_from([1; 32]));
+        assert_eq!(accounts[0].balance(), Motes::new(U512::from(100)));
+        assert_eq!(accounts[0].bonded_amount(), Motes::zero());
+        assert_eq!(accounts[1].balance(), Motes::new(U512::from(5)));
+        assert_eq!(accounts[1].bonded_amount(), Motes::new(U512::from(2)));
+    }
+
+    #[test]
+    fn should_name_line_and_field_of_malformed_entry() {
+        let contents = format!(
+            "[[accounts]]\npublic_key = \"{}\"\nbalance = \"100\"\n\n\
+             [[accounts]]\npublic_key = \"{}\"\nbalance = \"lots\"\n",
+            PUBLIC_KEY_HEX, PUBLIC_KEY_HEX
+        );
+        let error = parse_genesis_accounts(&contents)
+            .expect_err("should fail to parse")
+            .to_string();
+        assert!(
+            error.starts_with("line 7: accounts[1].balance: "),
+            "unexpected error: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn should_parse_public_key_from_hex() {
+        assert_eq!(
+            public_key_from_hex(PUBLIC_KEY_HEX),
+            Ok(PublicKey::ed25519_from([1; 32]))
+        );
+        assert_eq!(
+            PUBLIC_KEY_HEX.to_public_key(),
+            Ok(PublicKey::ed25519_from([1; 32]))
+        );
+    }
+
+    #[test]
+    fn should_report_malformed_public_key_hex() {
+        let error = public_key_from_hex("not hex").unwrap_err();
+        assert!(
+            error.starts_with("invalid hex: "),
+            "unexpected error: {}",
+            error
+        );
+
+        assert_eq!(
+            public_key_from_hex(&PUBLIC_KEY_HEX[2..]),
+            Err("expected 32 bytes, but got 31".to_string())
+        );
+    }
+
+    #[test]
+    fn should_report_missing_public_key() {
+        let error = parse_genesis_accounts("[[accounts]]\nbalance = 1\n")
+            .expect_err("should fail to parse");
+        match &error {
+            GenesisAccountsError::InvalidAccount {
+                index: 0,
+                field: "public_key",
+                line: Some(1),
+                ..
+            } => (),
+            _ => panic!("unexpected error: {:?}", error),
+        }
+        assert_eq!(error.to_string(), "line 1: accounts[0].public_key: missing");
+    }
+
+    #[test]
+    fn should_pass_gas_cost_within_tolerance() {
+        assert_gas_cost(Gas::new(100.into()), Gas::new(100.into()), 0.0);
+        assert_gas_cost(Gas::new(105.into()), Gas::new(100.into()), 5.0);
+        assert_gas_cost(Gas::new(95.into()), Gas::new(100.into()), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "gas cost 106 differs from expected 100 by +6.00%")]
+    fn should_fail_gas_cost_outside_tolerance() {
+        assert_gas_cost(Gas::new(106.into()), Gas::new(100.into()), 5.0);
+    }
+
+    #[test]
+    fn should_pass_zero_gas_cost_only_if_zero_expected() {
+        assert_gas_cost(Gas::default(), Gas::default(), 0.0);
+        assert!(!is_within_tolerance(
+            Gas::new(1.into()),
+            Gas::default(),
+            100.0
+        ));
+        assert_eq!(
+            format_gas_change(Gas::default(), Gas::new(1.into())),
+            "+inf%"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "gas cost 1 differs from expected 0 by +inf%")]
+    fn should_fail_nonzero_gas_cost_if_zero_expected() {
+        assert_gas_cost(Gas::new(1.into()), Gas::default(), 50.0);
+    }
+
+    #[test]
+    fn should_roundtrip_gas_ledger_baseline() {
+        let mut ledger = GasLedger::new();
+        ledger.record(Gas::new(10.into()));
+        ledger.record_all(vec![Gas::default(), Gas::new(U512::max_value())]);
+
+        let baseline = ledger.to_baseline();
+        assert!(baseline.starts_with("0 10\n1 0\n2 "));
+        assert_eq!(GasLedger::from_baseline(&baseline), Ok(ledger));
+
+        let error = GasLedger::from_baseline("0 10\n\n2 20\n").unwrap_err();
+        assert!(error.starts_with("line 3: "), "unexpected error: {}", error);
+    }
+
+    #[test]
+    fn should_diff_gas_ledger_against_baseline() {
+        let mut baseline = GasLedger::new();
+        baseline.record_all(vec![
+            Gas::new(100.into()),
+            Gas::new(200.into()),
+            Gas::default(),
+        ]);
+        let mut ledger = GasLedger::new();
+        ledger.record_all(vec![Gas::new(101.into()), Gas::new(150.into())]);
+
+        assert_eq!(baseline.diff(&baseline, 0.0), None);
+
+        let diff = ledger.diff(&baseline, 1.0).expect("should differ");
+        let rows: Vec<Vec<&str>> = diff
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["deploy", "old", "new", "change"],
+                vec!["1", "200", "150", "-25.00%"],
+                vec!["2", "0", "-", "-"],
+            ]
+        );
+    }
+
+    #[test]
+    fn should_compare_gas_ledger_against_baseline_file() {
+        let temp_dir = tempfile::tempdir().expect("should create temp dir");
+        let path = temp_dir.path().join("gas.baseline");
+        fs::write(&path, "0 100\n").expect("should write baseline");
+
+        let mut ledger = GasLedger::new();
+        ledger.record(Gas::new(102.into()));
+        ledger.assert_matches_baseline(&path, 2.0);
+
+        let result = std::panic::catch_unwind(|| ledger.assert_matches_baseline(&path, 1.0));
+        assert!(result.is_err());
+    }
+
+    fn account_with_named_keys(named_keys: BTreeMap<String, Key>) -> Account {
+        let main_purse = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+        Account::create(PublicKey::ed25519_from([1; 32]), named_keys, main_purse)
+    }
+
+    #[test]
+    fn should_apply_added_keys_to_pre_state_account() {
+        let account_key = Key::Account(PublicKey::ed25519_from([1; 32]));
+        let mut added_keys = BTreeMap::new();
+        added_keys.insert("added".to_string(), Key::Hash([3; 32]));
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(account_key, Transform::AddKeys(added_keys.clone()));
+
+        let pre_state_account = account_with_named_keys(BTreeMap::new());
+        assert_eq!(get_account(&transforms, &account_key), None);
+
+        let account = get_effective_account(Some(pre_state_account), &transforms, &account_key)
+            .expect("should apply added keys");
+        assert_eq!(account.named_keys(), &added_keys);
+    }
+
+    #[test]
+    fn should_distinguish_missing_account_from_transform_mismatch() {
+        let account_key = Key::Account(PublicKey::ed25519_from([1; 32]));
+        let mut transforms = AdditiveMap::new();
+        transforms.insert(account_key, Transform::AddKeys(BTreeMap::new()));
+
+        assert_eq!(
+            get_effective_account(None, &transforms, &account_key),
+            Err(AccountLookupError::NotFound(account_key))
+        );
+
+        let cl_value = CLValue::from_t(1_u64).unwrap();
+        transforms.insert(
+            account_key,
+            Transform::Write(StoredValue::CLValue(cl_value)),
+        );
+        let pre_state_account = account_with_named_keys(BTreeMap::new());
+        match get_effective_account(Some(pre_state_account), &transforms, &account_key) {
+            Err(AccountLookupError::TransformMismatch(_)) => (),
+            other => panic!("expected a transform mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_search_wasm_paths_in_order_of_precedence() {
+        // All the cases share one test, as the search paths are global to the test binary.
+        const CONTRACT_NAME: &str = "wasm_search_path_test";
+        let contract_file = format!("{}.wasm", CONTRACT_NAME);
+        let env_dir = tempfile::tempdir().unwrap();
+        let registered_dir = tempfile::tempdir().unwrap();
+        let pushed_dir = tempfile::tempdir().unwrap();
+        fs::write(registered_dir.path().join(&contract_file), b"registered").unwrap();
+
+        set_wasm_search_paths(vec![registered_dir.path().to_owned()]);
+        push_wasm_search_path(pushed_dir.path());
+        // A contract name resolves to its Wasm file, and registered paths precede the defaults.
+        assert_eq!(read_wasm_file_bytes(CONTRACT_NAME), b"registered");
+
+        // Paths in the environment variable precede registered paths.
+        fs::write(env_dir.path().join(&contract_file), b"env").unwrap();
+        env::set_var(WASM_SEARCH_PATHS_ENV_VAR, env_dir.path());
+        assert_eq!(try_read_wasm_file_bytes(&contract_file).unwrap(), b"env");
+
+        let error = try_read_wasm_file_bytes("missing").unwrap_err();
+        let searched_dirs = [env_dir.path(), registered_dir.path(), pushed_dir.path()];
+        let expected_paths: Vec<PathBuf> = searched_dirs
+            .iter()
+            .map(|dir| dir.join("missing.wasm"))
+            .chain(WASM_PATHS.iter().map(|dir| dir.join("missing.wasm")))
+            .chain(iter::once(PathBuf::from("missing.wasm")))
+            .collect();
+        assert_eq!(error.contract_file(), Path::new("missing.wasm"));
+        assert_eq!(error.attempted_paths(), expected_paths.as_slice());
+
+        env::remove_var(WASM_SEARCH_PATHS_ENV_VAR);
+        set_wasm_search_paths(Vec::new());
+        assert_eq!(wasm_search_paths(), *WASM_PATHS);
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+};
+
+/// An error returned when a compiled Wasm file can't be found in any of the searched locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmFileError {
+    contract_file: PathBuf,
+    attempted_paths: Vec<PathBuf>,
+}
+
+impl WasmFileError {
+    pub(crate) fn new(contract_file: PathBuf, attempted_paths: Vec<PathBuf>) -> Self {
+        WasmFileError {
+            contract_file,
+            attempted_paths,
+        }
+    }
+
+    /// Returns the contract file which was looked for.
+    pub fn contract_file(&self) -> &Path {
+        &self.contract_file
+    }
+
+    /// Returns the paths which were tried, in the order they were tried.
+    pub fn attempted_paths(&self) -> &[PathBuf] {
+        &self.attempted_paths
+    }
+}
+
+impl Display for WasmFileError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Failed to open compiled Wasm file {}.  Tried the following locations:",
+            self.contract_file.display()
+        )?;
+        for attempted_path in &self.attempted_paths {
+            writeln!(f, "    - {}", attempted_path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for WasmFileError {}
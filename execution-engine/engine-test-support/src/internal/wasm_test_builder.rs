@@ -1,9 +1,9 @@
 use std::{
-    collections::HashMap,
-    convert::{TryFrom, TryInto},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    convert::TryInto,
     ffi::OsStr,
     fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::Arc,
 };
@@ -12,24 +12,32 @@ use grpc::RequestOptions;
 use lmdb::DatabaseFlags;
 use log::LevelFilter;
 
+use contract::args_parser::ArgsParser;
 use engine_core::{
     engine_state::{
-        execute_request::ExecuteRequest, execution_result::ExecutionResult, genesis::GenesisConfig,
+        self,
+        batch::DeployOutcome,
+        contract_event::ContractEvent,
+        deploy_observer::{DeployMetrics, MetricsObserver},
+        effects_summary::EffectsSummary,
+        execute_request::ExecuteRequest,
+        execution_result::{ExecutionResult, PhaseCosts},
+        genesis::{GenesisConfig, POS_REWARDS_PURSE},
+        host_function_trace::HostFunctionCall,
+        op::Op,
+        transfer_request::TransferRequest,
         EngineConfig, EngineState, SYSTEM_ACCOUNT_ADDR,
     },
-    execution,
+    execution, DeployHash,
 };
 use engine_grpc_server::engine_server::{
-    ipc::{
-        CommitRequest, CommitResponse, GenesisResponse, QueryRequest, UpgradeRequest,
-        UpgradeResponse,
-    },
+    ipc::{CommitRequest, CommitResponse, GenesisResponse, UpgradeRequest, UpgradeResponse},
     ipc_grpc::ExecutionEngineService,
     mappings::{MappingError, TransformMap},
     transforms::TransformEntry,
 };
 use engine_shared::{
-    account::Account,
+    account::{Account, ActionThresholds},
     additive_map::AdditiveMap,
     contract::Contract,
     gas::Gas,
@@ -40,18 +48,33 @@ use engine_shared::{
     transform::Transform,
 };
 use engine_storage::{
-    global_state::{in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, StateProvider},
+    global_state::{
+        in_memory::InMemoryGlobalState, lmdb::LmdbGlobalState, ProvingStateReader, StateProvider,
+        StateReader,
+    },
     protocol_data_store::lmdb::LmdbProtocolDataStore,
-    transaction_source::lmdb::LmdbEnvironment,
-    trie_store::lmdb::LmdbTrieStore,
+    transaction_source::lmdb::{LmdbEnvironment, StoreStats},
+    trie::TrieMerkleProof,
+    trie_store::{lmdb::LmdbTrieStore, WriteStats},
 };
 use types::{
-    account::PublicKey,
-    bytesrepr::{self, ToBytes},
-    CLValue, Key, URef, U512,
+    account::{PublicKey, Weight},
+    bytesrepr::{FromBytes, ToBytes},
+    ApiError, CLTyped, CLValue, Key, ProtocolVersion, URef, KEY_HASH_LENGTH, KEY_LOCAL_SEED_LENGTH,
+    U512,
 };
 
-use crate::internal::utils;
+use crate::{
+    internal::{
+        transform_matcher::{self, KeyMatcher, TransformMatcher},
+        utils::{self, ToPublicKey},
+        AccountLookupError, DeployJournal, ExecJournal, ExecuteRequestBuilder, ExpectedError,
+        Journal, QueryError, TempLmdbGlobalState, DEFAULT_ACCOUNTS, DEFAULT_ENGINE_CONFIG,
+        DEFAULT_FUNDING_CHUNK_SIZE, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+        TRANSFER_TO_ACCOUNTS_CONTRACT, TRANSFER_TO_ACCOUNT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
 
 /// LMDB initial map size is calculated based on DEFAULT_LMDB_PAGES and systems page size.
 ///
@@ -69,11 +92,27 @@ pub type LmdbWasmTestBuilder = WasmTestBuilder<LmdbGlobalState>;
 pub struct WasmTestBuilder<S> {
     /// [`EngineState`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     engine_state: Rc<EngineState<S>>,
+    /// Installed as the observer of `engine_state`, recording the metrics of every deploy
+    deploy_metrics: MetricsObserver,
     /// [`ExecutionResult`] is wrapped in [`Rc`] to work around a missing [`Clone`] implementation
     exec_responses: Vec<Vec<Rc<ExecutionResult>>>,
+    /// The state hashes which each of the `exec_responses` was executed against
+    exec_pre_state_hashes: Vec<Vec<u8>>,
+    /// The hashes of the deploys of each of the `exec_responses`, or `None` for invalid deploys
+    exec_deploy_hashes: Vec<Vec<Option<DeployHash>>>,
+    /// The outcomes of the deploys of the latest batch run via [`WasmTestBuilder::exec_batch`]
+    batch_outcomes: Vec<DeployOutcome>,
+    /// The trie write stats of the latest commit made via [`WasmTestBuilder::commit_effects`]
+    last_commit_stats: Option<WriteStats>,
+    /// The protocol version set by genesis or by the latest successful upgrade, under which
+    /// effects are committed
+    protocol_version: ProtocolVersion,
     upgrade_responses: Vec<UpgradeResponse>,
     genesis_hash: Option<Vec<u8>>,
     post_state_hash: Option<Vec<u8>>,
+    /// Every post-state hash the builder has moved to, oldest first, as returned by
+    /// [`WasmTestBuilder::post_state_hash_at`]
+    committed_roots: Vec<Blake2bHash>,
     /// Cached transform maps after subsequent successful runs i.e. `transforms[0]` is for first
     /// exec call etc.
     transforms: Vec<AdditiveMap<Key, Transform>>,
@@ -88,6 +127,21 @@ pub struct WasmTestBuilder<S> {
     pos_contract_uref: Option<URef>,
     /// Standard payment contract uref
     standard_payment_uref: Option<URef>,
+    /// Temporary database backing `engine_state`, if any.  Declared after `engine_state` so that
+    /// the directory outlives the engine's handles to it.
+    temp_global_state: Option<Arc<TempLmdbGlobalState>>,
+}
+
+/// Turns logging back on when dropped.  Returned by
+/// [`WasmTestBuilder::scoped_instrumentation_off`].
+pub struct InstrumentationGuard {
+    previous_max_level: LevelFilter,
+}
+
+impl Drop for InstrumentationGuard {
+    fn drop(&mut self) {
+        log::set_max_level(self.previous_max_level);
+    }
 }
 
 impl<S> WasmTestBuilder<S> {
@@ -95,24 +149,79 @@ impl<S> WasmTestBuilder<S> {
         let log_settings = Settings::new(LevelFilter::Warn).with_style(Style::HumanReadable);
         let _ = logging::initialize(log_settings);
     }
+
+    /// Turns off logging until the returned guard is dropped, e.g. so that it doesn't skew the
+    /// results of benchmarks.
+    ///
+    /// The maximum log level is global, so this affects every builder in the process.
+    pub fn scoped_instrumentation_off() -> InstrumentationGuard {
+        // Install the logger first, as doing so later would reset the maximum log level.
+        Self::initialize_logging();
+        let previous_max_level = log::max_level();
+        log::set_max_level(LevelFilter::Off);
+        InstrumentationGuard { previous_max_level }
+    }
+}
+
+/// Creates an engine which records the metrics of the deploys it executes in `deploy_metrics`.
+fn new_engine_state<S>(
+    global_state: S,
+    engine_config: EngineConfig,
+    deploy_metrics: &MetricsObserver,
+) -> EngineState<S>
+where
+    S: StateProvider,
+    S::Error: Into<execution::Error>,
+{
+    let mut engine_state = EngineState::new(global_state, engine_config);
+    engine_state.set_observer(Box::new(deploy_metrics.clone()));
+    engine_state
+}
+
+/// Creates empty LMDB-backed global state in `global_state_dir`.
+pub(crate) fn create_lmdb_global_state(global_state_dir: &Path) -> LmdbGlobalState {
+    let page_size = get_page_size().expect("should get page size");
+    let environment = Arc::new(
+        LmdbEnvironment::new(
+            &global_state_dir.to_path_buf(),
+            page_size * DEFAULT_LMDB_PAGES,
+        )
+        .expect("should create LmdbEnvironment"),
+    );
+    let trie_store = Arc::new(
+        LmdbTrieStore::new(&environment, None, DatabaseFlags::empty())
+            .expect("should create LmdbTrieStore"),
+    );
+    let protocol_data_store = Arc::new(
+        LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty())
+            .expect("should create LmdbProtocolDataStore"),
+    );
+    LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
+        .expect("should create LmdbGlobalState")
 }
 
 impl Default for InMemoryWasmTestBuilder {
     fn default() -> Self {
         Self::initialize_logging();
-        let engine_config = EngineConfig::new()
-            .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
-            .with_enable_bonding(cfg!(feature = "enable-bonding"));
+        let engine_config = *DEFAULT_ENGINE_CONFIG;
 
         let global_state = InMemoryGlobalState::empty().expect("should create global state");
-        let engine_state = EngineState::new(global_state, engine_config);
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, engine_config, &deploy_metrics);
 
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
+            deploy_metrics,
             exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: *DEFAULT_PROTOCOL_VERSION,
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
+            committed_roots: Vec::new(),
             transforms: Vec::new(),
             bonded_validators: Vec::new(),
             genesis_account: None,
@@ -120,6 +229,7 @@ impl Default for InMemoryWasmTestBuilder {
             mint_contract_uref: None,
             pos_contract_uref: None,
             standard_payment_uref: None,
+            temp_global_state: None,
         }
     }
 }
@@ -130,10 +240,17 @@ impl<S> Clone for WasmTestBuilder<S> {
     fn clone(&self) -> Self {
         WasmTestBuilder {
             engine_state: Rc::clone(&self.engine_state),
+            deploy_metrics: self.deploy_metrics.clone(),
             exec_responses: self.exec_responses.clone(),
+            exec_pre_state_hashes: self.exec_pre_state_hashes.clone(),
+            exec_deploy_hashes: self.exec_deploy_hashes.clone(),
+            batch_outcomes: self.batch_outcomes.clone(),
+            last_commit_stats: self.last_commit_stats,
+            protocol_version: self.protocol_version,
             upgrade_responses: self.upgrade_responses.clone(),
             genesis_hash: self.genesis_hash.clone(),
             post_state_hash: self.post_state_hash.clone(),
+            committed_roots: self.committed_roots.clone(),
             transforms: self.transforms.clone(),
             bonded_validators: self.bonded_validators.clone(),
             genesis_account: self.genesis_account.clone(),
@@ -141,6 +258,7 @@ impl<S> Clone for WasmTestBuilder<S> {
             mint_contract_uref: self.mint_contract_uref,
             pos_contract_uref: self.pos_contract_uref,
             standard_payment_uref: self.standard_payment_uref,
+            temp_global_state: self.temp_global_state.clone(),
         }
     }
 }
@@ -163,45 +281,77 @@ impl InMemoryWasmTestBuilder {
         post_state_hash: Vec<u8>,
     ) -> Self {
         Self::initialize_logging();
-        let engine_state = EngineState::new(global_state, engine_config);
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, engine_config, &deploy_metrics);
+        let root = to_root(&post_state_hash);
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
+            deploy_metrics,
             genesis_hash: Some(post_state_hash.clone()),
             post_state_hash: Some(post_state_hash),
+            committed_roots: vec![root],
             ..Default::default()
         }
     }
 }
 
+/// Creates a builder backed by a [`TempLmdbGlobalState`], which is removed once the builder and
+/// any clones or snapshots of it are dropped.
+impl Default for LmdbWasmTestBuilder {
+    fn default() -> Self {
+        Self::initialize_logging();
+        let temp_global_state = Arc::new(TempLmdbGlobalState::new());
+        let global_state = temp_global_state.global_state().snapshot_global_state();
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, *DEFAULT_ENGINE_CONFIG, &deploy_metrics);
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            deploy_metrics,
+            exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: *DEFAULT_PROTOCOL_VERSION,
+            upgrade_responses: Vec::new(),
+            genesis_hash: None,
+            post_state_hash: None,
+            committed_roots: Vec::new(),
+            transforms: Vec::new(),
+            bonded_validators: Vec::new(),
+            genesis_account: None,
+            genesis_transforms: None,
+            mint_contract_uref: None,
+            pos_contract_uref: None,
+            standard_payment_uref: None,
+            temp_global_state: Some(temp_global_state),
+        }
+    }
+}
+
 impl LmdbWasmTestBuilder {
     pub fn new_with_config<T: AsRef<OsStr> + ?Sized>(
         data_dir: &T,
         engine_config: EngineConfig,
     ) -> Self {
         Self::initialize_logging();
-        let page_size = get_page_size().expect("should get page size");
         let global_state_dir = Self::create_and_get_global_state_dir(data_dir);
-        let environment = Arc::new(
-            LmdbEnvironment::new(&global_state_dir, page_size * DEFAULT_LMDB_PAGES)
-                .expect("should create LmdbEnvironment"),
-        );
-        let trie_store = Arc::new(
-            LmdbTrieStore::new(&environment, None, DatabaseFlags::empty())
-                .expect("should create LmdbTrieStore"),
-        );
-        let protocol_data_store = Arc::new(
-            LmdbProtocolDataStore::new(&environment, None, DatabaseFlags::empty())
-                .expect("should create LmdbProtocolDataStore"),
-        );
-        let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
-            .expect("should create LmdbGlobalState");
-        let engine_state = EngineState::new(global_state, engine_config);
+        let global_state = create_lmdb_global_state(&global_state_dir);
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, engine_config, &deploy_metrics);
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
+            deploy_metrics,
             exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: *DEFAULT_PROTOCOL_VERSION,
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: None,
+            committed_roots: Vec::new(),
             transforms: Vec::new(),
             bonded_validators: Vec::new(),
             genesis_account: None,
@@ -209,6 +359,7 @@ impl LmdbWasmTestBuilder {
             mint_contract_uref: None,
             pos_contract_uref: None,
             standard_payment_uref: None,
+            temp_global_state: None,
         }
     }
 
@@ -216,6 +367,39 @@ impl LmdbWasmTestBuilder {
         Self::new_with_config(data_dir, Default::default())
     }
 
+    /// Creates a builder backed by the database in `data_dir`, creating it if it doesn't exist.
+    ///
+    /// Unlike the database of a builder created via `default()`, this is left in place when the
+    /// builder is dropped.
+    pub fn with_existing_db<T: AsRef<OsStr> + ?Sized>(data_dir: &T) -> Self {
+        Self::new_with_config(data_dir, *DEFAULT_ENGINE_CONFIG)
+    }
+
+    /// Returns the path of the temporary database directory if the builder was created via
+    /// `default()`.
+    pub fn temp_data_dir(&self) -> Option<&Path> {
+        self.temp_global_state
+            .as_ref()
+            .map(|temp_global_state| temp_global_state.path())
+    }
+
+    /// Returns the statistics of the trie store holding global state.
+    pub fn lmdb_stats(&self) -> StoreStats {
+        self.engine_state
+            .state()
+            .stats()
+            .expect("should get LMDB stats")
+    }
+
+    /// Writes a compacted copy of the database into the existing, empty directory `target_path`.
+    pub fn compact_lmdb(&self, target_path: &Path) -> &Self {
+        self.engine_state
+            .state()
+            .compact(target_path)
+            .expect("should compact LMDB");
+        self
+    }
+
     /// Creates new instance of builder and applies values only which allows the engine state to be
     /// swapped with a new one, possibly after running genesis once and reusing existing database
     /// (i.e. LMDB).
@@ -228,6 +412,8 @@ impl LmdbWasmTestBuilder {
         // Applies existing properties from gi
         builder.genesis_hash = result.0.genesis_hash.clone();
         builder.post_state_hash = result.0.post_state_hash.clone();
+        builder.protocol_version = result.0.protocol_version;
+        builder.committed_roots = result.0.committed_roots.clone();
         builder.bonded_validators = result.0.bonded_validators.clone();
         builder.mint_contract_uref = result.0.mint_contract_uref;
         builder.pos_contract_uref = result.0.pos_contract_uref;
@@ -256,13 +442,22 @@ impl LmdbWasmTestBuilder {
         );
         let global_state = LmdbGlobalState::empty(environment, trie_store, protocol_data_store)
             .expect("should create LmdbGlobalState");
-        let engine_state = EngineState::new(global_state, engine_config);
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, engine_config, &deploy_metrics);
+        let root = to_root(&post_state_hash);
         WasmTestBuilder {
             engine_state: Rc::new(engine_state),
+            deploy_metrics,
             exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: *DEFAULT_PROTOCOL_VERSION,
             upgrade_responses: Vec::new(),
             genesis_hash: None,
             post_state_hash: Some(post_state_hash),
+            committed_roots: vec![root],
             transforms: Vec::new(),
             bonded_validators: Vec::new(),
             genesis_account: None,
@@ -270,6 +465,7 @@ impl LmdbWasmTestBuilder {
             mint_contract_uref: None,
             pos_contract_uref: None,
             standard_payment_uref: None,
+            temp_global_state: None,
         }
     }
 
@@ -285,20 +481,224 @@ impl LmdbWasmTestBuilder {
     }
 }
 
+/// Global state which can be captured in a [`StateSnapshot`].
+pub trait SnapshotGlobalState {
+    /// Returns a handle to global state holding the same data as `self`, which can be given to a
+    /// new builder without its commits being visible through `self`.
+    fn snapshot_global_state(&self) -> Self;
+}
+
+impl SnapshotGlobalState for InMemoryGlobalState {
+    fn snapshot_global_state(&self) -> Self {
+        self.deep_clone().expect("should clone global state")
+    }
+}
+
+impl SnapshotGlobalState for LmdbGlobalState {
+    /// Tries are content-addressed and never modified in place, so the stores can be shared: a
+    /// builder restored from a snapshot only ever sees the tries reachable from its own post-state
+    /// hash.  Pruning is the exception, which is why the clone shares the pins of the original's
+    /// roots too.
+    fn snapshot_global_state(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// The state of a builder captured by [`WasmTestBuilder::snapshot`].
+///
+/// Intended to be built once, e.g. in a `lazy_static` after running genesis and any common setup,
+/// and then restored for each test with [`WasmTestBuilder::from_snapshot`].
+pub struct StateSnapshot<S> {
+    global_state: S,
+    engine_config: EngineConfig,
+    genesis_hash: Option<Vec<u8>>,
+    post_state_hash: Option<Vec<u8>>,
+    protocol_version: ProtocolVersion,
+    committed_roots: Vec<Blake2bHash>,
+    bonded_validators: Vec<HashMap<PublicKey, U512>>,
+    genesis_account: Option<Account>,
+    genesis_transforms: Option<AdditiveMap<Key, Transform>>,
+    mint_contract_uref: Option<URef>,
+    pos_contract_uref: Option<URef>,
+    standard_payment_uref: Option<URef>,
+    temp_global_state: Option<Arc<TempLmdbGlobalState>>,
+}
+
+impl<S> StateSnapshot<S> {
+    /// Returns the post-state hash at the time the snapshot was taken.
+    pub fn post_state_hash(&self) -> Option<&[u8]> {
+        self.post_state_hash.as_deref()
+    }
+}
+
+impl<S> WasmTestBuilder<S>
+where
+    S: StateProvider + SnapshotGlobalState,
+    S::Error: Into<execution::Error>,
+    EngineState<S>: ExecutionEngineService,
+{
+    /// Captures the global state and the values used by the query helpers, so that further
+    /// builders can be started from this point with [`WasmTestBuilder::from_snapshot`].
+    pub fn snapshot(&self) -> StateSnapshot<S> {
+        StateSnapshot {
+            global_state: self.engine_state.state().snapshot_global_state(),
+            engine_config: *self.engine_state.config(),
+            genesis_hash: self.genesis_hash.clone(),
+            post_state_hash: self.post_state_hash.clone(),
+            protocol_version: self.protocol_version,
+            committed_roots: self.committed_roots.clone(),
+            bonded_validators: self.bonded_validators.clone(),
+            genesis_account: self.genesis_account.clone(),
+            genesis_transforms: self.genesis_transforms.clone(),
+            mint_contract_uref: self.mint_contract_uref,
+            pos_contract_uref: self.pos_contract_uref,
+            standard_payment_uref: self.standard_payment_uref,
+            temp_global_state: self.temp_global_state.clone(),
+        }
+    }
+
+    /// Creates a builder starting from `snapshot`.  Commits made by the new builder don't affect
+    /// the snapshot, so it can be restored any number of times.
+    pub fn from_snapshot(snapshot: &StateSnapshot<S>) -> Self {
+        Self::initialize_logging();
+        let global_state = snapshot.global_state.snapshot_global_state();
+        let deploy_metrics = MetricsObserver::new();
+        let engine_state = new_engine_state(global_state, snapshot.engine_config, &deploy_metrics);
+        WasmTestBuilder {
+            engine_state: Rc::new(engine_state),
+            deploy_metrics,
+            exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: snapshot.protocol_version,
+            upgrade_responses: Vec::new(),
+            genesis_hash: snapshot.genesis_hash.clone(),
+            post_state_hash: snapshot.post_state_hash.clone(),
+            committed_roots: snapshot.committed_roots.clone(),
+            transforms: Vec::new(),
+            bonded_validators: snapshot.bonded_validators.clone(),
+            genesis_account: snapshot.genesis_account.clone(),
+            genesis_transforms: snapshot.genesis_transforms.clone(),
+            mint_contract_uref: snapshot.mint_contract_uref,
+            pos_contract_uref: snapshot.pos_contract_uref,
+            standard_payment_uref: snapshot.standard_payment_uref,
+            temp_global_state: snapshot.temp_global_state.clone(),
+        }
+    }
+
+    /// Replaces the engine's configuration for subsequent requests, keeping the global state and
+    /// the values used by the query helpers.
+    ///
+    /// This allows e.g. running the same deploy with a feature turned on and off in one test.
+    pub fn with_engine_config(&mut self, engine_config: EngineConfig) -> &mut Self {
+        let global_state = self.engine_state.state().snapshot_global_state();
+        let engine_state = new_engine_state(global_state, engine_config, &self.deploy_metrics);
+        self.engine_state = Rc::new(engine_state);
+        self
+    }
+}
+
 impl<S> WasmTestBuilder<S>
 where
     S: StateProvider,
     S::Error: Into<execution::Error>,
+    engine_state::Error: From<S::Error>,
     EngineState<S>: ExecutionEngineService,
 {
+    /// Returns the configuration the engine is running with.
+    pub fn engine_config(&self) -> EngineConfig {
+        *self.engine_state.config()
+    }
+
+    /// Turns on tracing of the host functions called by subsequent requests, which
+    /// [`WasmTestBuilder::last_host_trace`] returns.
+    pub fn with_host_tracing(&mut self) -> &mut Self {
+        let engine_config = self.engine_config().with_trace_host_functions(true);
+        self.with_engine_config(engine_config)
+    }
+
+    /// Returns the host functions called by the first deploy of the last exec request, in the
+    /// order they were called.  Empty unless [`WasmTestBuilder::with_host_tracing`] was called
+    /// before the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no request has been executed.
+    pub fn last_host_trace(&self) -> &[HostFunctionCall] {
+        let exec_response = self
+            .exec_responses
+            .last()
+            .expect("should have exec response");
+        exec_response
+            .first()
+            .expect("should have execution result")
+            .host_trace()
+    }
+
+    /// Turns on recording of the tracking copy's cache hits and misses for subsequent requests,
+    /// which [`WasmTestBuilder::last_cache_stats`] returns.
+    pub fn with_cache_stats(&mut self) -> &mut Self {
+        let engine_config = self.engine_config().with_record_cache_stats(true);
+        self.with_engine_config(engine_config)
+    }
+
+    /// Returns the cache hits and misses of the first deploy of the last exec request, in that
+    /// order.  `None` unless [`WasmTestBuilder::with_cache_stats`] was called before the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no request has been executed.
+    pub fn last_cache_stats(&self) -> Option<(u64, u64)> {
+        let exec_response = self
+            .exec_responses
+            .last()
+            .expect("should have exec response");
+        exec_response
+            .first()
+            .expect("should have execution result")
+            .cache_stats()
+    }
+
+    /// Returns the events emitted by the first deploy of the last exec request, in the order they
+    /// were emitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no request has been executed.
+    pub fn last_events(&self) -> &[ContractEvent] {
+        self.exec_responses
+            .last()
+            .expect("should have exec response")
+            .first()
+            .expect("should have execution result")
+            .events()
+    }
+
+    /// Returns the events emitted under `topic` by the first deploy of the last exec request.
+    pub fn last_events_with_topic(&self, topic: &str) -> Vec<&ContractEvent> {
+        self.last_events()
+            .iter()
+            .filter(|event| event.topic == topic)
+            .collect()
+    }
+
     /// Carries on attributes from TestResult for further executions
     pub fn from_result(result: WasmTestResult<S>) -> Self {
         WasmTestBuilder {
             engine_state: result.0.engine_state,
+            deploy_metrics: result.0.deploy_metrics,
             exec_responses: Vec::new(),
+            exec_pre_state_hashes: Vec::new(),
+            exec_deploy_hashes: Vec::new(),
+            batch_outcomes: Vec::new(),
+            last_commit_stats: None,
+            protocol_version: result.0.protocol_version,
             upgrade_responses: Vec::new(),
             genesis_hash: result.0.genesis_hash,
             post_state_hash: result.0.post_state_hash,
+            committed_roots: result.0.committed_roots,
             transforms: Vec::new(),
             bonded_validators: result.0.bonded_validators,
             genesis_account: result.0.genesis_account,
@@ -306,6 +706,7 @@ where
             pos_contract_uref: result.0.pos_contract_uref,
             standard_payment_uref: result.0.standard_payment_uref,
             genesis_transforms: result.0.genesis_transforms,
+            temp_global_state: result.0.temp_global_state,
         }
     }
 
@@ -348,6 +749,8 @@ where
 
         self.genesis_hash = Some(state_root_hash.to_vec());
         self.post_state_hash = Some(state_root_hash.to_vec());
+        self.protocol_version = genesis_config.protocol_version();
+        self.committed_roots.push(state_root_hash);
         self.mint_contract_uref = Some(protocol_data.mint());
         self.pos_contract_uref = Some(protocol_data.proof_of_stake());
         self.standard_payment_uref = Some(protocol_data.standard_payment());
@@ -356,43 +759,168 @@ where
         self
     }
 
+    /// Queries the value reached by starting at `base_key` and following `path` hop by hop.
+    ///
+    /// Each segment of `path` names a key in the named keys of the account or contract reached so
+    /// far.  A `CLValue` holding a `Key` is followed without consuming a segment, as the node's
+    /// query endpoint does.
     pub fn query(
         &self,
         maybe_post_state: Option<Vec<u8>>,
         base_key: Key,
         path: &[&str],
-    ) -> Result<StoredValue, String> {
+    ) -> Result<StoredValue, QueryError> {
         let post_state = maybe_post_state
             .or_else(|| self.post_state_hash.clone())
             .expect("builder must have a post-state hash");
+        let state_hash: Blake2bHash = post_state
+            .as_slice()
+            .try_into()
+            .map_err(|_| QueryError::RootNotFound)?;
 
-        let path_vec: Vec<String> = path.iter().map(|s| String::from(*s)).collect();
+        let mut tracking_copy = self
+            .engine_state
+            .tracking_copy(state_hash)
+            .expect("should checkout state")
+            .ok_or(QueryError::RootNotFound)?;
+        let correlation_id = CorrelationId::new();
+
+        let mut visited_keys = BTreeSet::new();
+        let mut current_key = base_key.normalize();
+        // The number of segments of `path` resolved so far.
+        let mut resolved = 0;
+        loop {
+            if !visited_keys.insert(current_key) {
+                return Err(QueryError::CircularReference(current_key));
+            }
+
+            let stored_value = tracking_copy
+                .read(correlation_id, &current_key)
+                .map_err(Into::<execution::Error>::into)
+                .expect("should read global state");
+            let stored_value = match (stored_value, resolved.checked_sub(1)) {
+                (Some(stored_value), _) => stored_value,
+                (None, None) => return Err(QueryError::BaseKeyNotFound(current_key)),
+                (None, Some(index)) => {
+                    return Err(QueryError::ValueNotFound {
+                        index,
+                        name: path[index].to_string(),
+                        key: current_key,
+                    })
+                }
+            };
+
+            let name = match path.get(resolved) {
+                Some(name) => *name,
+                None => return Ok(stored_value),
+            };
+
+            let next_key = match stored_value {
+                StoredValue::Account(account) => account.named_keys().get(name).cloned(),
+                StoredValue::Contract(contract) => contract.named_keys().get(name).cloned(),
+                StoredValue::CLValue(cl_value) => match cl_value.into_t::<Key>() {
+                    Ok(key) => {
+                        current_key = key.normalize();
+                        continue;
+                    }
+                    Err(_) => {
+                        return Err(QueryError::NotTraversable {
+                            index: resolved,
+                            name: name.to_string(),
+                        })
+                    }
+                },
+            };
+
+            current_key = next_key
+                .ok_or_else(|| QueryError::NamedKeyNotFound {
+                    index: resolved,
+                    name: name.to_string(),
+                })?
+                .normalize();
+            resolved += 1;
+        }
+    }
 
-        let query_request = create_query_request(post_state, base_key, path_vec);
+    /// Queries the value stored in the context-local partition of global state under `key_bytes`
+    /// hashed with `seed`, e.g. an account's public key or a contract's address.
+    pub fn query_local(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        seed: [u8; KEY_LOCAL_SEED_LENGTH],
+        key_bytes: &[u8],
+    ) -> Result<StoredValue, QueryError> {
+        self.query(maybe_post_state, Key::local(seed, key_bytes), &[])
+    }
 
-        let mut query_response = self
-            .engine_state
-            .query(RequestOptions::new(), query_request)
-            .wait_drop_metadata()
-            .expect("should get query response");
+    /// Queries the value the account or contract stored under `owner` wrote to its local state
+    /// under `local_key`, as by `storage::write_local(local_key, value)`.
+    ///
+    /// Returns [`QueryError::BaseKeyNotFound`] if nothing is stored under the local key.
+    pub fn query_local_of<T: ToBytes>(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        owner: Key,
+        local_key: &T,
+    ) -> Result<StoredValue, QueryError> {
+        let key_bytes = local_key.to_bytes().expect("should serialize local key");
+        self.query_local(maybe_post_state, owner.local_seed(), &key_bytes)
+    }
 
-        if query_response.has_failure() {
-            return Err(query_response.take_failure());
-        }
+    /// Queries the value stored under `key` along with a proof of it against the post-state hash,
+    /// which can be checked with [`validate_proof`](engine_storage::trie::validate_proof) without
+    /// access to global state.
+    pub fn query_with_proof(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        key: Key,
+    ) -> Result<TrieMerkleProof<Key, StoredValue>, QueryError>
+    where
+        S::Reader: ProvingStateReader<Key, StoredValue>,
+    {
+        let post_state = maybe_post_state
+            .or_else(|| self.post_state_hash.clone())
+            .expect("builder must have a post-state hash");
+        let state_hash: Blake2bHash = post_state
+            .as_slice()
+            .try_into()
+            .map_err(|_| QueryError::RootNotFound)?;
 
-        bytesrepr::deserialize(query_response.take_success()).map_err(|err| format!("{}", err))
+        let tracking_copy = self
+            .engine_state
+            .tracking_copy(state_hash)
+            .expect("should checkout state")
+            .ok_or(QueryError::RootNotFound)?;
+
+        let key = key.normalize();
+        tracking_copy
+            .reader()
+            .read_with_proof(CorrelationId::new(), &key)
+            .map_err(Into::<execution::Error>::into)
+            .expect("should read global state")
+            .ok_or(QueryError::BaseKeyNotFound(key))
     }
 
-    pub fn exec(&mut self, mut exec_request: ExecuteRequest) -> &mut Self {
-        let exec_request = {
-            let hash = self
-                .post_state_hash
-                .clone()
-                .expect("expected post_state_hash");
-            exec_request.parent_state_hash =
-                hash.as_slice().try_into().expect("expected a valid hash");
-            exec_request
-        };
+    /// Sets the parent state hash of `exec_request` to the latest post-state hash and records it
+    /// along with the request's deploy hashes.
+    fn prepare_exec_request(&mut self, mut exec_request: ExecuteRequest) -> ExecuteRequest {
+        let hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        exec_request.parent_state_hash = hash.as_slice().try_into().expect("expected a valid hash");
+        self.exec_pre_state_hashes.push(hash);
+        let deploy_hashes = exec_request
+            .deploys
+            .iter()
+            .map(|deploy| deploy.as_ref().ok().map(|item| item.deploy_hash))
+            .collect();
+        self.exec_deploy_hashes.push(deploy_hashes);
+        exec_request
+    }
+
+    pub fn exec(&mut self, exec_request: ExecuteRequest) -> &mut Self {
+        let exec_request = self.prepare_exec_request(exec_request);
         let exec_response = self
             .engine_state
             .run_execute(CorrelationId::new(), exec_request);
@@ -410,6 +938,58 @@ where
         self
     }
 
+    /// Executes the deploys of `exec_request` as a batch via [`EngineState::run_deploys`].
+    ///
+    /// The results of the deploys are recorded in the same way as by [`WasmTestBuilder::exec`],
+    /// but only the merged transforms of the batch are cached, so that a following
+    /// [`WasmTestBuilder::commit`] commits the effects of all executed deploys.  The outcomes of
+    /// the deploys are available via [`WasmTestBuilder::get_batch_outcomes`].
+    pub fn exec_batch(&mut self, exec_request: ExecuteRequest) -> &mut Self {
+        let exec_request = self.prepare_exec_request(exec_request);
+        let batch_result = self
+            .engine_state
+            .run_deploys(CorrelationId::new(), exec_request)
+            .expect("should find pre-state hash");
+        self.transforms.push(batch_result.transforms().clone());
+        self.batch_outcomes = batch_result.outcomes().to_vec();
+        self.exec_responses.push(
+            batch_result
+                .into_execution_results()
+                .into_iter()
+                .map(Rc::new)
+                .collect(),
+        );
+        self
+    }
+
+    /// Returns the outcomes of the deploys of the latest batch run via
+    /// [`WasmTestBuilder::exec_batch`], in the order of the deploys.
+    pub fn get_batch_outcomes(&self) -> &[DeployOutcome] {
+        &self.batch_outcomes
+    }
+
+    /// Executes `transfer_request` natively against the latest post-state hash.  Its result is
+    /// recorded in the same way as that of a single deploy passed to [`WasmTestBuilder::exec`].
+    pub fn exec_transfer(&mut self, mut transfer_request: TransferRequest) -> &mut Self {
+        let hash = self
+            .post_state_hash
+            .clone()
+            .expect("expected post_state_hash");
+        transfer_request.parent_state_hash =
+            hash.as_slice().try_into().expect("expected a valid hash");
+        self.exec_pre_state_hashes.push(hash);
+        self.exec_deploy_hashes
+            .push(vec![Some(transfer_request.deploy_hash)]);
+        let execution_result = self
+            .engine_state
+            .transfer(CorrelationId::new(), transfer_request)
+            .expect("should find pre-state hash");
+        self.transforms
+            .push(execution_result.effect().transforms.clone());
+        self.exec_responses.push(vec![Rc::new(execution_result)]);
+        self
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self
@@ -422,7 +1002,8 @@ where
         self.commit_effects(prestate_hash, effects)
     }
 
-    /// Sends raw commit request to the current engine response.
+    /// Sends raw commit request to the current engine response, under the builder's current
+    /// protocol version.
     ///
     /// Can be used where result is not necessary
     pub fn commit_transforms(
@@ -430,7 +1011,7 @@ where
         prestate_hash: Vec<u8>,
         effects: AdditiveMap<Key, Transform>,
     ) -> CommitResponse {
-        let commit_request = create_commit_request(&prestate_hash, &effects);
+        let commit_request = create_commit_request(&prestate_hash, &effects, self.protocol_version);
 
         self.engine_state
             .commit(RequestOptions::new(), commit_request)
@@ -438,8 +1019,11 @@ where
             .expect("Should have commit response")
     }
 
-    /// Runs a commit request, expects a successful response, and
-    /// overwrites existing cached post state hash with a new one.
+    /// Sends commit request to the current engine response, expects success, and overwrites
+    /// existing cached post state hash with a new one.
+    ///
+    /// The trie write stats of the commit are available via
+    /// [`WasmTestBuilder::get_last_commit_stats`].
     pub fn commit_effects(
         &mut self,
         prestate_hash: Vec<u8>,
@@ -453,17 +1037,26 @@ where
             );
         }
         let mut commit_success = commit_response.take_success();
-        self.post_state_hash = Some(commit_success.take_poststate_hash().to_vec());
+        let post_state_hash = commit_success.take_poststate_hash().to_vec();
+        self.committed_roots.push(to_root(&post_state_hash));
+        self.post_state_hash = Some(post_state_hash);
         let bonded_validators = commit_success
             .take_bonded_validators()
             .into_iter()
             .map(TryInto::try_into)
             .collect::<Result<HashMap<PublicKey, U512>, MappingError>>()
-            .unwrap();
+            .expect("should parse bonded validators");
         self.bonded_validators.push(bonded_validators);
+        self.last_commit_stats = Some(commit_success.take_stats().into());
         self
     }
 
+    /// Returns the trie write stats of the latest commit made via
+    /// [`WasmTestBuilder::commit_effects`].
+    pub fn get_last_commit_stats(&self) -> Option<WriteStats> {
+        self.last_commit_stats
+    }
+
     pub fn upgrade_with_upgrade_request(
         &mut self,
         upgrade_request: &mut UpgradeRequest,
@@ -483,12 +1076,123 @@ where
             .expect("should upgrade");
 
         let upgrade_success = upgrade_response.get_success();
-        self.post_state_hash = Some(upgrade_success.get_post_state_hash().to_vec());
+        let post_state_hash = upgrade_success.get_post_state_hash().to_vec();
+        if upgrade_response.has_success() {
+            self.committed_roots.push(to_root(&post_state_hash));
+            self.protocol_version = upgrade_request
+                .get_upgrade_point()
+                .get_protocol_version()
+                .clone()
+                .into();
+        }
+        self.post_state_hash = Some(post_state_hash);
 
         self.upgrade_responses.push(upgrade_response.clone());
         self
     }
 
+    /// Runs genesis with a config created by [`utils::create_genesis_config`] from
+    /// `DEFAULT_ACCOUNTS`.
+    pub fn run_genesis_default(&mut self) -> &mut Self {
+        let genesis_config = utils::create_genesis_config(DEFAULT_ACCOUNTS.clone());
+        self.run_genesis(&genesis_config)
+    }
+
+    /// Executes `exec_request` and commits the effects of its last deploy.
+    ///
+    /// Panics if any of its deploys fail, giving the deploy hashes and the execution errors.
+    pub fn exec_commit(&mut self, exec_request: ExecuteRequest) -> &mut Self {
+        let deploy_hashes: Vec<String> = exec_request
+            .deploys
+            .iter()
+            .map(|deploy| match deploy {
+                Ok(deploy_item) => base16::encode_lower(&deploy_item.deploy_hash),
+                Err(_) => "<invalid deploy>".to_string(),
+            })
+            .collect();
+
+        self.exec(exec_request);
+
+        let exec_response = self
+            .exec_responses
+            .last()
+            .expect("should have an exec response");
+        if exec_response.iter().any(|result| result.is_failure()) {
+            panic!(
+                "Expected successful execution of deploys {:?}, but got errors:\n{}",
+                deploy_hashes,
+                utils::get_error_message(exec_response)
+            );
+        }
+        self.commit()
+    }
+
+    /// Transfers `amount` from the main purse of `source` to that of `target` via the standard
+    /// transfer contract, creating the `target` account if it doesn't exist, and commits the
+    /// result.
+    pub fn transfer(&mut self, source: PublicKey, target: PublicKey, amount: U512) -> &mut Self {
+        let exec_request =
+            ExecuteRequestBuilder::standard(source, TRANSFER_TO_ACCOUNT_CONTRACT, (target, amount))
+                .build();
+        self.exec_commit(exec_request)
+    }
+
+    /// Executes `session_file` with `session_args` from the system account and commits its
+    /// effects.
+    ///
+    /// The system account holds no motes of its own, so the default account first transfers it
+    /// [`DEFAULT_PAYMENT`] to pay for the deploy.  Panics if either deploy fails.
+    pub fn exec_commit_as_system_account(
+        &mut self,
+        session_file: &str,
+        session_args: impl ArgsParser,
+    ) -> &mut Self {
+        self.transfer(DEFAULT_ACCOUNT_ADDR, SYSTEM_ACCOUNT_ADDR, *DEFAULT_PAYMENT);
+        let exec_request =
+            ExecuteRequestBuilder::standard(SYSTEM_ACCOUNT_ADDR, session_file, session_args)
+                .build();
+        self.exec_commit(exec_request)
+    }
+
+    /// Creates `count` accounts funded with `balance` each by the default account, transferring to
+    /// [`DEFAULT_FUNDING_CHUNK_SIZE`] accounts per deploy, and returns their public keys.
+    ///
+    /// See [`WasmTestBuilder::create_funded_accounts_in_chunks`].
+    pub fn create_funded_accounts(&mut self, count: usize, balance: U512) -> Vec<PublicKey> {
+        self.create_funded_accounts_in_chunks(count, balance, DEFAULT_FUNDING_CHUNK_SIZE)
+    }
+
+    /// Creates `count` accounts funded with `balance` each by the default account, and returns
+    /// their public keys.
+    ///
+    /// The public keys are derived from the accounts' indices, so they are the same on every run,
+    /// and calling this again funds the same accounts again.  Each deploy transfers to up to
+    /// `chunk_size` accounts and is committed before the next one is executed.  The default
+    /// account pays for these deploys on top of the `count * balance` transferred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero or if any of the transfers fail.
+    pub fn create_funded_accounts_in_chunks(
+        &mut self,
+        count: usize,
+        balance: U512,
+        chunk_size: usize,
+    ) -> Vec<PublicKey> {
+        assert!(chunk_size > 0, "chunk size should be greater than zero");
+        let public_keys: Vec<PublicKey> = (0..count).map(ephemeral_public_key).collect();
+        for chunk in public_keys.chunks(chunk_size) {
+            let exec_request = ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                TRANSFER_TO_ACCOUNTS_CONTRACT,
+                (chunk.to_vec(), balance),
+            )
+            .build();
+            self.exec_commit(exec_request);
+        }
+        public_keys
+    }
+
     /// Expects a successful run and caches transformations
     pub fn expect_success(&mut self) -> &mut Self {
         // Check first result, as only first result is interesting for a simple test
@@ -509,6 +1213,40 @@ where
         self
     }
 
+    /// Expects the last run to fail with an execution error matching `expected`
+    pub fn expect_error<T: Into<ExpectedError>>(&mut self, expected: T) -> &mut Self {
+        let expected = expected.into();
+        let index = self
+            .exec_responses
+            .len()
+            .checked_sub(1)
+            .expect("Expected to be called after run()");
+        match self.get_exec_error(index) {
+            Some(error) if expected.matches(error) => self,
+            Some(error) => panic!(
+                "Expected execution error:\n    {:?}\nbut the deploy failed with a different \
+                 error:\n    {:?}",
+                expected, error
+            ),
+            None => panic!(
+                "Expected execution error:\n    {:?}\nbut the deploy didn't fail during \
+                 execution, its result was:\n    {:?}",
+                expected,
+                self.exec_responses[index].get(0)
+            ),
+        }
+    }
+
+    /// Gets the execution error of the first deploy of the exec call at `index`, if it failed
+    /// during execution
+    pub fn get_exec_error(&self, index: usize) -> Option<&execution::Error> {
+        let exec_result = self.exec_responses.get(index)?.get(0)?;
+        match exec_result.error()? {
+            engine_state::Error::Exec(error) => Some(error),
+            _ => None,
+        }
+    }
+
     pub fn is_error(&self) -> bool {
         let exec_response = self
             .exec_responses
@@ -525,7 +1263,59 @@ where
         self.transforms.clone()
     }
 
-    pub fn get_bonded_validators(&self) -> Vec<HashMap<PublicKey, U512>> {
+    /// Expects the transforms of the last run to include one matching `transform_matcher` under a
+    /// key matching `key_matcher`
+    pub fn expect_transform<K: Into<KeyMatcher>, T: Into<TransformMatcher>>(
+        &mut self,
+        key_matcher: K,
+        transform_matcher: T,
+    ) -> &mut Self {
+        let key_matcher = key_matcher.into();
+        let transform_matcher = transform_matcher.into();
+        let transforms = self.last_transforms();
+        let found = transforms.iter().any(|(key, transform)| {
+            key_matcher.matches(key) && transform_matcher.matches(transform)
+        });
+        if !found {
+            let mut lines: Vec<String> = transforms
+                .iter()
+                .map(|(key, transform)| {
+                    let description = transform_matcher::describe_transform(transform);
+                    format!("    {}: {}", key, description)
+                })
+                .collect();
+            lines.sort();
+            panic!(
+                "Expected transform:\n    {:?} under {:?}\nbut instead got:\n{}",
+                transform_matcher,
+                key_matcher,
+                lines.join("\n")
+            );
+        }
+        self
+    }
+
+    /// Returns the number of transforms of the last run of each kind, as named by
+    /// [`transform_kind`](transform_matcher::transform_kind), e.g. `"Write(Contract)"`.
+    pub fn transform_count_by_kind(&self) -> BTreeMap<&'static str, usize> {
+        let mut counts = BTreeMap::new();
+        for (_key, transform) in self.last_transforms().iter() {
+            *counts
+                .entry(transform_matcher::transform_kind(transform))
+                .or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn last_transforms(&self) -> &AdditiveMap<Key, Transform> {
+        self.transforms
+            .last()
+            .expect("Expected to be called after run()")
+    }
+
+    /// Gets the bonded validators reported after each commit, i.e. the first element is for the
+    /// first commit etc.
+    pub fn get_bonded_validators_history(&self) -> Vec<HashMap<PublicKey, U512>> {
         self.bonded_validators.clone()
     }
 
@@ -570,6 +1360,18 @@ where
             .expect("Should have post-state hash.")
     }
 
+    /// Returns the post-state hash the builder had after its `block_index`-th commit, or `None` if
+    /// it hasn't made as many.
+    ///
+    /// Index 0 is the state the builder started from, i.e. the genesis post-state hash or the one
+    /// the builder was created with.  Each commit and successful upgrade adds a further hash, so
+    /// the result can be passed to e.g. [`WasmTestBuilder::query`] to inspect an earlier state.
+    pub fn post_state_hash_at(&self, block_index: usize) -> Option<Vec<u8>> {
+        self.committed_roots
+            .get(block_index)
+            .map(|root| root.to_vec())
+    }
+
     pub fn get_engine_state(&self) -> &EngineState<S> {
         &self.engine_state
     }
@@ -582,6 +1384,107 @@ where
         self.exec_responses.len()
     }
 
+    /// Returns the operations which the first deploy of the exec request at `index` performed on
+    /// each key it touched, so that tests can check e.g. that a deploy only read a key.
+    pub fn get_exec_ops(&self, index: usize) -> Option<AdditiveMap<Key, Op>> {
+        let exec_response = self.get_exec_response(index)?;
+        let execution_result = exec_response.get(0)?;
+        Some(execution_result.effect().ops.clone())
+    }
+
+    /// Summarizes the effects of the last deploy of the last exec call, against the state it was
+    /// executed on.
+    pub fn last_effects_summary(&self) -> EffectsSummary {
+        let exec_result = self
+            .exec_responses
+            .last()
+            .and_then(|exec_results| exec_results.last())
+            .expect("should have exec result");
+        let pre_state_hash: Blake2bHash = self
+            .exec_pre_state_hashes
+            .last()
+            .and_then(|hash| hash.as_slice().try_into().ok())
+            .expect("should have pre-state hash");
+        let tracking_copy = self
+            .engine_state
+            .tracking_copy(pre_state_hash)
+            .expect("should checkout pre-state")
+            .expect("should have pre-state");
+        let mint = self.get_mint_contract_uref();
+        exec_result
+            .effects_summary(CorrelationId::new(), mint, &tracking_copy)
+            .map_err(Into::<execution::Error>::into)
+            .expect("should summarize effects")
+    }
+
+    /// Returns the metrics of the deploys executed via this builder and its clones so far.
+    pub fn deploy_metrics(&self) -> DeployMetrics {
+        self.deploy_metrics.metrics()
+    }
+
+    /// Returns the value the session code of the last deploy of the last exec call returned via
+    /// `runtime::ret`, or `None` if it failed or didn't call it.
+    pub fn last_return_value(&self) -> Option<CLValue> {
+        self.exec_responses
+            .last()
+            .and_then(|exec_results| exec_results.last())
+            .and_then(|exec_result| exec_result.ret().cloned())
+    }
+
+    /// Like `last_return_value`, but also parses the value as `T`, panicking if it is of another
+    /// type.
+    pub fn last_return_value_as<T: CLTyped + FromBytes>(&self) -> Option<T> {
+        self.last_return_value()
+            .map(|value| value.into_t().expect("should parse return value"))
+    }
+
+    /// Returns a journal of every exec call so far, recording for each deploy its hash, cost,
+    /// error and transforms.
+    pub fn journal(&self) -> Journal {
+        let exec_requests = self
+            .exec_responses
+            .iter()
+            .zip(&self.exec_pre_state_hashes)
+            .zip(&self.exec_deploy_hashes)
+            .map(|((exec_results, pre_state_hash), deploy_hashes)| {
+                let deploys = exec_results
+                    .iter()
+                    .zip(deploy_hashes)
+                    .map(|(exec_result, deploy_hash)| DeployJournal::new(*deploy_hash, exec_result))
+                    .collect::<Result<_, _>>()
+                    .expect("should serialize transform values");
+                ExecJournal {
+                    pre_state_hash: base16::encode_lower(pre_state_hash),
+                    deploys,
+                }
+            })
+            .collect();
+        Journal { exec_requests }
+    }
+
+    /// Writes the [`journal`](WasmTestBuilder::journal) of every exec call so far to `path` as
+    /// pretty-printed JSON.
+    pub fn write_journal<P: AsRef<Path>>(&self, path: P) -> &Self {
+        let json = serde_json::to_string_pretty(&self.journal()).expect("should serialize journal");
+        fs::write(path, json).expect("should write journal");
+        self
+    }
+
+    /// Asserts that the [`journal`](WasmTestBuilder::journal) of every exec call so far matches
+    /// the one previously written to `path` by [`WasmTestBuilder::write_journal`].
+    pub fn assert_journal_matches<P: AsRef<Path>>(&self, path: P) -> &Self {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).expect("should read journal");
+        let expected: Journal = serde_json::from_str(&json).expect("should deserialize journal");
+        assert_eq!(
+            self.journal(),
+            expected,
+            "journal doesn't match {}",
+            path.display()
+        );
+        self
+    }
+
     pub fn get_upgrade_response(&self, index: usize) -> Option<&UpgradeResponse> {
         self.upgrade_responses.get(index)
     }
@@ -596,40 +1499,222 @@ where
             .expect("should have pos contract uref")
             .into();
         self.query(None, pos_contract, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| v.try_into().map_err(|error| format!("{:?}", error)))
             .expect("should find PoS URef")
     }
 
+    /// Returns the PoS contract including the effects of the latest exec, even if they haven't been
+    /// committed yet.
+    fn get_pending_pos_contract(&self) -> Contract {
+        let pos_contract = self.get_pos_contract();
+        if self.exec_pre_state_hashes.last() != self.post_state_hash.as_ref() {
+            return pos_contract;
+        }
+        let pos_key = Key::URef(self.get_pos_contract_uref()).normalize();
+        match self
+            .transforms
+            .last()
+            .and_then(|transforms| transforms.get(&pos_key))
+        {
+            Some(transform) => match transform.clone().apply(StoredValue::Contract(pos_contract)) {
+                Ok(StoredValue::Contract(contract)) => contract,
+                other => panic!(
+                    "should apply pending transform to PoS contract: {:?}",
+                    other
+                ),
+            },
+            None => pos_contract,
+        }
+    }
+
+    /// Returns the validators bonded with the PoS contract and their stakes.
+    ///
+    /// Reflects the latest exec even when it hasn't been committed yet, so the effects of a bond or
+    /// unbond deploy can be checked before calling [`WasmTestBuilder::commit`].
+    pub fn get_bonded_validators(&self) -> BTreeMap<PublicKey, U512> {
+        self.get_pending_pos_contract()
+            .named_keys()
+            .keys()
+            .filter_map(|name| engine_state::utils::pos_validator_key_name_to_tuple(name))
+            .collect()
+    }
+
+    /// Returns the stake of `validator`, or `None` if it isn't bonded.
+    pub fn get_validator_stake(&self, validator: PublicKey) -> Option<U512> {
+        self.get_bonded_validators().remove(&validator)
+    }
+
+    /// Returns the commission rate of `validator` in basis points, which is zero if it was never
+    /// set.
+    pub fn get_validator_commission(&self, validator: PublicKey) -> u32 {
+        let pos_contract: Key = self.get_pos_contract_uref().into();
+        let name = proof_of_stake::commission_key_name(&validator);
+        match self.query(None, pos_contract, &[&name]) {
+            Ok(stored_value) => {
+                let cl_value: CLValue = stored_value.try_into().expect("should be a CLValue");
+                cl_value
+                    .into_t::<proof_of_stake::Commission>()
+                    .expect("should be a commission")
+                    .rate
+            }
+            Err(QueryError::NamedKeyNotFound { .. }) => 0,
+            Err(error) => panic!("should query commission: {:?}", error),
+        }
+    }
+
+    /// Returns the balance of `purse`, read directly from the mint's records at the current
+    /// post-state hash.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mint has no balance record for `purse`.
     pub fn get_purse_balance(&self, purse: URef) -> U512 {
+        self.get_purse_balance_at(None, purse)
+            .unwrap_or_else(|error| panic!("purse {:?} has no balance: {}", purse, error))
+    }
+
+    /// Returns the balance of `purse` at `maybe_post_state`, or at the current post-state hash if
+    /// `None`.
+    ///
+    /// Returns [`QueryError::RootNotFound`] if `maybe_post_state` is not a root of global state,
+    /// and [`QueryError::BaseKeyNotFound`] if the mint had no balance record for `purse` there.
+    pub fn get_purse_balance_at(
+        &self,
+        maybe_post_state: Option<Vec<u8>>,
+        purse: URef,
+    ) -> Result<U512, QueryError> {
         let mint = self.get_mint_contract_uref();
         let purse_addr = purse.addr();
         let purse_bytes =
             ToBytes::to_bytes(&purse_addr).expect("should be able to serialize purse bytes");
         let balance_mapping_key = Key::local(mint.addr(), &purse_bytes);
-        let base_key = self
-            .query(None, balance_mapping_key, &[])
-            .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
-            .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
-            .expect("should find balance uref");
+        let balance_record = self.query(maybe_post_state.clone(), balance_mapping_key, &[])?;
+        let balance_key: Key = match balance_record {
+            StoredValue::CLValue(cl_value) => cl_value.into_t().expect("should parse balance key"),
+            value => panic!("balance key should be a CLValue, not {:?}", value),
+        };
 
-        self.query(None, base_key, &[])
-            .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
-            .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
-            .expect("should parse balance into a U512")
+        match self.query(maybe_post_state, balance_key, &[])? {
+            StoredValue::CLValue(cl_value) => {
+                Ok(cl_value.into_t().expect("should parse balance into a U512"))
+            }
+            value => panic!("balance should be a CLValue, not {:?}", value),
+        }
     }
 
-    pub fn get_account(&self, public_key: PublicKey) -> Option<Account> {
-        let account_value = self
-            .query(None, Key::Account(public_key), &[])
-            .expect("should query account");
+    /// Returns the purse bound to the contract with the given hash by
+    /// `system::get_or_create_contract_purse`, with full access rights, or `None` if it hasn't
+    /// been created yet.
+    pub fn get_contract_purse(&self, contract_hash: [u8; KEY_HASH_LENGTH]) -> Option<URef> {
+        let mint = self.get_mint_contract_uref();
+        let local_key = mint::contract_purse_local_key(contract_hash);
+        match self.query_local_of(None, mint.into(), &local_key) {
+            Ok(StoredValue::CLValue(cl_value)) => {
+                Some(cl_value.into_t().expect("should parse contract purse"))
+            }
+            Ok(value) => panic!("contract purse should be a CLValue, not {:?}", value),
+            Err(QueryError::BaseKeyNotFound(_)) => None,
+            Err(error) => panic!("should query contract purse: {}", error),
+        }
+    }
 
-        if let StoredValue::Account(account) = account_value {
-            Some(account)
-        } else {
-            None
+    /// Returns the balance of the main purse of the given account.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the account doesn't exist.
+    pub fn get_account_balance<T: ToPublicKey>(&self, account: T) -> U512 {
+        let public_key = utils::expect_public_key(account);
+        let main_purse = self
+            .get_account(public_key)
+            .unwrap_or_else(|| panic!("account {} should exist", public_key))
+            .main_purse();
+        self.get_purse_balance(main_purse)
+    }
+
+    /// Returns the balance of the PoS contract's rewards purse, which the validators are paid from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if genesis hasn't been run.
+    pub fn get_pos_rewards_purse_balance(&self) -> U512 {
+        let rewards_purse = self
+            .get_pos_contract()
+            .named_keys()
+            .get(POS_REWARDS_PURSE)
+            .and_then(Key::as_uref)
+            .cloned()
+            .expect("should find PoS rewards purse");
+        self.get_purse_balance(rewards_purse)
+    }
+
+    /// Returns the total supply of tokens as recorded by the mint, which is zero if no tokens have
+    /// been minted.
+    pub fn total_supply(&self) -> U512 {
+        let mint_contract_uref = self.get_mint_contract_uref();
+        match self.query(None, mint_contract_uref.into(), &[mint::TOTAL_SUPPLY_KEY]) {
+            Ok(StoredValue::CLValue(cl_value)) => cl_value
+                .into_t()
+                .expect("should parse total supply into a U512"),
+            Ok(value) => panic!("total supply should be a CLValue, not {:?}", value),
+            Err(error) => panic!("should query total supply: {}", error),
+        }
+    }
+
+    /// Returns the account under the given public key, or `None` if it doesn't exist.
+    ///
+    /// The public key can also be given as a hex-encoded string, as used in genesis configs.
+    pub fn get_account<T: ToPublicKey>(&self, account: T) -> Option<Account> {
+        let public_key = utils::expect_public_key(account);
+        match self.query(None, Key::Account(public_key), &[]) {
+            Ok(StoredValue::Account(account)) => Some(account),
+            Ok(_) | Err(QueryError::BaseKeyNotFound(_)) => None,
+            Err(error) => panic!("should query account: {}", error),
         }
     }
 
+    /// Returns the account under the given public key including the effects of the latest exec,
+    /// even if they haven't been committed yet.
+    ///
+    /// The account is read from the committed state and the pending transform for it, if any, is
+    /// applied on top, so accounts only mutated via `AddKeys` are reflected as well.
+    pub fn get_effective_account<T: ToPublicKey>(
+        &self,
+        account: T,
+    ) -> Result<Account, AccountLookupError> {
+        let public_key = utils::expect_public_key(account);
+        let committed_account = self.get_account(public_key);
+        let account_key = Key::Account(public_key);
+        let is_pending = self.exec_pre_state_hashes.last() == self.post_state_hash.as_ref();
+        match self.transforms.last() {
+            Some(transforms) if is_pending => {
+                utils::get_effective_account(committed_account, transforms, &account_key)
+            }
+            _ => committed_account.ok_or(AccountLookupError::NotFound(account_key)),
+        }
+    }
+
+    /// Returns the weights of the associated keys of the given account, or `None` if the account
+    /// doesn't exist.
+    pub fn get_account_weights(
+        &self,
+        public_key: PublicKey,
+    ) -> Option<BTreeMap<PublicKey, Weight>> {
+        self.get_account(public_key).map(|account| {
+            account
+                .get_associated_keys()
+                .map(|(public_key, weight)| (*public_key, *weight))
+                .collect()
+        })
+    }
+
+    /// Returns the action thresholds of the given account, or `None` if the account doesn't exist.
+    pub fn get_action_thresholds(&self, public_key: PublicKey) -> Option<ActionThresholds> {
+        self.get_account(public_key)
+            .map(|account| account.action_thresholds().clone())
+    }
+
     pub fn get_contract(&self, contract_uref: URef) -> Option<Contract> {
         let contract_value: StoredValue = self
             .query(None, Key::URef(contract_uref), &[])
@@ -649,11 +1734,37 @@ where
         utils::get_exec_costs(exec_response)
     }
 
+    pub fn exec_phase_costs(&self, index: usize) -> Vec<PhaseCosts> {
+        let exec_response = self
+            .get_exec_response(index)
+            .expect("should have exec response");
+        utils::get_phase_costs(exec_response)
+    }
+
     pub fn exec_error_message(&self, index: usize) -> Option<String> {
         let response = self.get_exec_response(index)?;
         Some(utils::get_error_message(response))
     }
 
+    /// Returns the payload of the revert the first failed deploy of the exec request at `index`
+    /// failed with, or `None` if none of its deploys failed with a revert carrying a payload.
+    pub fn exec_revert_payload(&self, index: usize) -> Option<Vec<u8>> {
+        self.get_exec_response(index)?
+            .iter()
+            .find(|execution_result| execution_result.is_failure())
+            .and_then(|execution_result| utils::get_revert_payload(execution_result))
+            .map(<[u8]>::to_vec)
+    }
+
+    /// Returns the [`ApiError`] the first failed deploy of the exec request at `index` reverted
+    /// with, or `None` if none of its deploys failed with a revert.
+    pub fn exec_revert_error(&self, index: usize) -> Option<ApiError> {
+        self.get_exec_response(index)?
+            .iter()
+            .find(|execution_result| execution_result.is_failure())
+            .and_then(|execution_result| utils::get_revert_error(execution_result))
+    }
+
     pub fn exec_commit_finish(&mut self, execute_request: ExecuteRequest) -> WasmTestResult<S> {
         self.exec(execute_request)
             .expect_success()
@@ -662,20 +1773,23 @@ where
     }
 }
 
-fn create_query_request(post_state: Vec<u8>, base_key: Key, path: Vec<String>) -> QueryRequest {
-    let mut query_request = QueryRequest::new();
-
-    query_request.set_state_hash(post_state);
-    query_request.set_base_key(base_key.into());
-    query_request.set_path(path.into());
+/// Returns the public key of the account at `index` created by
+/// [`WasmTestBuilder::create_funded_accounts`].
+fn ephemeral_public_key(index: usize) -> PublicKey {
+    let mut seed = b"ephemeral-account".to_vec();
+    seed.extend_from_slice(&(index as u64).to_le_bytes());
+    PublicKey::ed25519_from(Blake2bHash::new(&seed).value())
+}
 
-    query_request
+fn to_root(post_state_hash: &[u8]) -> Blake2bHash {
+    post_state_hash.try_into().expect("expected a valid hash")
 }
 
 #[allow(clippy::implicit_hasher)]
 fn create_commit_request(
     prestate_hash: &[u8],
     effects: &AdditiveMap<Key, Transform>,
+    protocol_version: ProtocolVersion,
 ) -> CommitRequest {
     let effects: Vec<TransformEntry> = effects
         .iter()
@@ -685,6 +1799,7 @@ fn create_commit_request(
     let mut commit_request = CommitRequest::new();
     commit_request.set_prestate_hash(prestate_hash.to_vec());
     commit_request.set_effects(effects.into());
+    commit_request.set_protocol_version(protocol_version.into());
     commit_request
 }
 
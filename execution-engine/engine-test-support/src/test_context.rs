@@ -6,7 +6,7 @@ use types::{AccessRights, Key, URef, U512};
 
 use crate::{
     internal::{InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
-    Error, PublicKey, Result, Session, URefAddr, Value,
+    Error, Hash, PublicKey, Result, Session, URefAddr, Value,
 };
 
 /// Context in which to run a test of a Wasm smart contract.
@@ -37,6 +37,17 @@ impl TestContext {
             .map_err(Error::from)
     }
 
+    /// Queries for a [`Value`] stored in the context-local partition of global state under the
+    /// given `key_bytes` hashed with `seed`.  For an account, `seed` is its public key's bytes.
+    ///
+    /// Returns an [`Error`] if not found.
+    pub fn query_local(&self, seed: Hash, key_bytes: &[u8]) -> Result<Value> {
+        self.inner
+            .query_local(None, seed, key_bytes)
+            .map(Value::new)
+            .map_err(Error::from)
+    }
+
     /// Gets the balance of the purse under the given [`URefAddr`].
     ///
     /// Note that this requires performing an earlier query to retrieve `purse_addr`.
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_RETURN_LARGE_BLOB: &str = "return_large_blob.wasm";
+const CONTRACT_RETURN_LARGE_BLOB_CALLER: &str = "return_large_blob_caller.wasm";
+const BLOB_SIZE: u32 = 64 * 1024;
+/// The serialized length prefix of a `Vec<u8>`.
+const LENGTH_PREFIX_SIZE: u32 = 4;
+/// Number of calls made by each deploy.
+const CALLS_PER_DEPLOY: u32 = 10;
+
+fn bootstrap() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_RETURN_LARGE_BLOB, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn call_returning_large_blob(builder: &mut InMemoryWasmTestBuilder, buffer_size: u32) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RETURN_LARGE_BLOB_CALLER,
+        (BLOB_SIZE, buffer_size, CALLS_PER_DEPLOY),
+    )
+    .build();
+    builder.exec(exec_request).expect_success();
+}
+
+pub fn call_contract_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("call_contract_64kib_result");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+    group.throughput(Throughput::Elements(u64::from(CALLS_PER_DEPLOY)));
+
+    // A zero-sized buffer never fits the result, so it's always read back via the host buffer.
+    let mut builder = bootstrap();
+    group.bench_function("via_host_buffer", |b| {
+        b.iter(|| call_returning_large_blob(&mut builder, 0))
+    });
+
+    let mut builder = bootstrap();
+    group.bench_function("into_caller_buffer", |b| {
+        b.iter(|| call_returning_large_blob(&mut builder, BLOB_SIZE + LENGTH_PREFIX_SIZE))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, call_contract_bench);
+criterion_main!(benches);
@@ -0,0 +1,260 @@
+//! Benchmarks of the executor's hot paths, each run against in-memory global state with logging
+//! turned off.
+//!
+//! Run with `cargo bench --bench exec_bench -- --help` to list the available flags.  To compare two
+//! branches, run with `--save-baseline <name>` on the first and with `--baseline <name>` on the
+//! second.
+
+use std::time::Duration;
+
+use clap::{App, Arg, ArgMatches};
+use criterion::{measurement::WallTime, BenchmarkGroup, Criterion, Throughput};
+
+use engine_core::engine_state::{
+    engine_config::DEFAULT_MODULE_CACHE_SIZE, execute_request::ExecuteRequest, EngineConfig,
+};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_CALL_STORED_CONTRACT: &str = "call_stored_contract.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_WRITE_UREFS: &str = "write_urefs.wasm";
+
+const DO_NOTHING_STORED_NAME: &str = "do_nothing_stored";
+const DO_NOTHING_STORED_DESTINATION: &str = "hash";
+const TARGET_ADDR: PublicKey = PublicKey::ed25519_from([127; 32]);
+const TRANSFER_AMOUNT: u64 = 1;
+
+const ARG_BENCH: &str = "bench";
+const ARG_FILTER: &str = "filter";
+const ARG_SAMPLE_SIZE: &str = "sample-size";
+const ARG_MEASUREMENT_TIME: &str = "measurement-time";
+const ARG_WARM_UP_TIME: &str = "warm-up-time";
+const ARG_SAVE_BASELINE: &str = "save-baseline";
+const ARG_BASELINE: &str = "baseline";
+const ARG_TOTAL_UREFS: &str = "total-urefs";
+const ARG_TOTAL_CALLS: &str = "total-calls";
+
+struct Settings {
+    total_urefs: u32,
+    total_calls: u32,
+}
+
+fn parse_u32(arg_matches: &ArgMatches, name: &str) -> u32 {
+    let value = arg_matches.value_of(name).expect("should have default");
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("--{} should be an integer, but is {}", name, value))
+}
+
+fn parse_seconds(arg_matches: &ArgMatches, name: &str) -> Duration {
+    Duration::from_secs(u64::from(parse_u32(arg_matches, name)))
+}
+
+fn get_arg_matches() -> ArgMatches<'static> {
+    App::new("exec_bench")
+        .about("Benchmarks genesis, transfers, storage and contract calls on in-memory state")
+        .arg(
+            Arg::with_name(ARG_BENCH)
+                .long(ARG_BENCH)
+                .hidden(true)
+                .help("Passed by `cargo bench`; the benchmarks are skipped without it"),
+        )
+        .arg(
+            Arg::with_name(ARG_FILTER)
+                .index(1)
+                .help("Only runs the benchmarks whose names contain this string"),
+        )
+        .arg(
+            Arg::with_name(ARG_SAMPLE_SIZE)
+                .long(ARG_SAMPLE_SIZE)
+                .value_name("COUNT")
+                .default_value("20")
+                .help("Number of samples taken of each benchmark"),
+        )
+        .arg(
+            Arg::with_name(ARG_MEASUREMENT_TIME)
+                .long(ARG_MEASUREMENT_TIME)
+                .value_name("SECONDS")
+                .default_value("10")
+                .help("Time spent taking the samples of each benchmark"),
+        )
+        .arg(
+            Arg::with_name(ARG_WARM_UP_TIME)
+                .long(ARG_WARM_UP_TIME)
+                .value_name("SECONDS")
+                .default_value("3")
+                .help("Time spent running each benchmark before taking samples"),
+        )
+        .arg(
+            Arg::with_name(ARG_SAVE_BASELINE)
+                .long(ARG_SAVE_BASELINE)
+                .value_name("NAME")
+                .conflicts_with(ARG_BASELINE)
+                .help("Saves the results under the given name for later comparison"),
+        )
+        .arg(
+            Arg::with_name(ARG_BASELINE)
+                .long(ARG_BASELINE)
+                .value_name("NAME")
+                .help("Compares the results against the ones saved under the given name"),
+        )
+        .arg(
+            Arg::with_name(ARG_TOTAL_UREFS)
+                .long(ARG_TOTAL_UREFS)
+                .value_name("COUNT")
+                .default_value("100")
+                .help("Number of URefs created, read and written by the storage benchmark"),
+        )
+        .arg(
+            Arg::with_name(ARG_TOTAL_CALLS)
+                .long(ARG_TOTAL_CALLS)
+                .value_name("COUNT")
+                .default_value("50")
+                .help("Number of stored contract calls made by the call_contract benchmark"),
+        )
+        .get_matches()
+}
+
+fn bootstrap() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        (String::from(DO_NOTHING_STORED_DESTINATION),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+/// Executes `exec_request` against the same pre-state on each iteration, without committing.
+fn bench_exec(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    exec_request: impl Fn() -> ExecuteRequest,
+) {
+    bench_exec_with_config(group, name, EngineConfig::default(), exec_request)
+}
+
+/// Like `bench_exec`, but with the engine running with `engine_config`.
+fn bench_exec_with_config(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    engine_config: EngineConfig,
+    exec_request: impl Fn() -> ExecuteRequest,
+) {
+    let mut builder = bootstrap();
+    builder.with_engine_config(engine_config);
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            builder.exec(exec_request()).expect_success();
+        })
+    });
+}
+
+fn exec_bench(criterion: &mut Criterion, settings: &Settings) {
+    let mut group = criterion.benchmark_group("exec");
+
+    group.bench_function("genesis", |b| {
+        b.iter_with_large_drop(|| {
+            let mut builder = InMemoryWasmTestBuilder::default();
+            builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+            builder
+        })
+    });
+
+    bench_exec(&mut group, "transfer_to_account", || {
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            (TARGET_ADDR, U512::from(TRANSFER_AMOUNT)),
+        )
+        .build()
+    });
+
+    let total_urefs = settings.total_urefs;
+    group.throughput(Throughput::Elements(u64::from(total_urefs)));
+    bench_exec(&mut group, &format!("write_urefs/{}", total_urefs), || {
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_WRITE_UREFS, (total_urefs,))
+            .build()
+    });
+
+    let total_calls = settings.total_calls;
+    group.throughput(Throughput::Elements(u64::from(total_calls)));
+    bench_exec(
+        &mut group,
+        &format!("call_contract/{}", total_calls),
+        || {
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_CALL_STORED_CONTRACT,
+                (String::from(DO_NOTHING_STORED_NAME), total_calls),
+            )
+            .build()
+        },
+    );
+
+    group.finish();
+}
+
+/// Compares repeatedly executing the same session code with and without the module cache, which
+/// spares preprocessing it on every execution.
+fn module_cache_bench(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("module_cache");
+
+    for (name, module_cache_size) in &[("disabled", 0), ("enabled", DEFAULT_MODULE_CACHE_SIZE)] {
+        let engine_config = EngineConfig::default().with_module_cache_size(*module_cache_size);
+        bench_exec_with_config(&mut group, name, engine_config, || {
+            ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_TRANSFER_TO_ACCOUNT,
+                (TARGET_ADDR, U512::from(TRANSFER_AMOUNT)),
+            )
+            .build()
+        });
+    }
+
+    group.finish();
+}
+
+fn main() {
+    let arg_matches = get_arg_matches();
+    if !arg_matches.is_present(ARG_BENCH) {
+        // Built and run by `cargo test`, so there's nothing to measure.
+        return;
+    }
+
+    let mut criterion = Criterion::default()
+        .sample_size(parse_u32(&arg_matches, ARG_SAMPLE_SIZE) as usize)
+        .measurement_time(parse_seconds(&arg_matches, ARG_MEASUREMENT_TIME))
+        .warm_up_time(parse_seconds(&arg_matches, ARG_WARM_UP_TIME));
+    if let Some(filter) = arg_matches.value_of(ARG_FILTER) {
+        criterion = criterion.with_filter(filter);
+    }
+    if let Some(baseline) = arg_matches.value_of(ARG_SAVE_BASELINE) {
+        criterion = criterion.save_baseline(baseline.to_string());
+    }
+    if let Some(baseline) = arg_matches.value_of(ARG_BASELINE) {
+        criterion = criterion.retain_baseline(baseline.to_string());
+    }
+
+    let settings = Settings {
+        total_urefs: parse_u32(&arg_matches, ARG_TOTAL_UREFS),
+        total_calls: parse_u32(&arg_matches, ARG_TOTAL_CALLS),
+    };
+
+    let _instrumentation_guard = InMemoryWasmTestBuilder::scoped_instrumentation_off();
+    exec_bench(&mut criterion, &settings);
+    module_cache_bench(&mut criterion);
+    criterion.final_summary();
+}
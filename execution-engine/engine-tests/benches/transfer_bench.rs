@@ -8,8 +8,8 @@ use tempfile::TempDir;
 use engine_core::engine_state::EngineConfig;
 use engine_test_support::{
     internal::{
-        DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT,
+        DeployItemBuilder, ExecuteRequestBuilder, LmdbWasmTestBuilder, TransferRequestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -47,9 +47,8 @@ fn bootstrap(data_dir: &Path, accounts: &[PublicKey], amount: U512) -> LmdbWasmT
     )
     .build();
 
-    let engine_config = EngineConfig::new()
-        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
-        .with_enable_bonding(cfg!(feature = "enable-bonding"));
+    let engine_config =
+        EngineConfig::new().with_use_system_contracts(cfg!(feature = "use-system-contracts"));
 
     let mut builder = LmdbWasmTestBuilder::new_with_config(data_dir, engine_config);
 
@@ -122,6 +121,26 @@ fn transfer_to_account_multiple_execs(
     }
 }
 
+/// Uses multiple native transfers, i.e. ones executed without Wasm, to transfer tokens. Executes
+/// all transfers in batch determined by value of TRANSFER_BATCH_SIZE.
+fn transfer_to_account_multiple_native_transfers(
+    builder: &mut LmdbWasmTestBuilder,
+    account: PublicKey,
+    should_commit: bool,
+) {
+    let amount = U512::one();
+
+    for _ in 0..TRANSFER_BATCH_SIZE {
+        let transfer_request =
+            TransferRequestBuilder::new(DEFAULT_ACCOUNT_ADDR, account, amount).build();
+
+        let builder = builder.exec_transfer(transfer_request).expect_success();
+        if should_commit {
+            builder.commit();
+        }
+    }
+}
+
 /// Executes multiple deploys per single exec with based on TRANSFER_BATCH_SIZE.
 fn transfer_to_account_multiple_deploys(
     builder: &mut LmdbWasmTestBuilder,
@@ -238,6 +257,26 @@ pub fn transfer_to_existing_accounts(group: &mut BenchmarkGroup<WallTime>, shoul
             })
         },
     );
+
+    let data_dir = TempDir::new().expect("should create temp dir");
+    let mut builder = bootstrap(data_dir.path(), &bootstrap_accounts, U512::one());
+
+    group.bench_function(
+        format!(
+            "transfer_to_existing_account_multiple_native_transfers/{}/{}",
+            TRANSFER_BATCH_SIZE, should_commit
+        ),
+        |b| {
+            b.iter(|| {
+                // Execute multiple transfers natively
+                transfer_to_account_multiple_native_transfers(
+                    &mut builder,
+                    target_account,
+                    should_commit,
+                )
+            })
+        },
+    );
 }
 
 pub fn transfer_to_existing_purses(group: &mut BenchmarkGroup<WallTime>, should_commit: bool) {
@@ -0,0 +1,152 @@
+use engine_test_support::{
+    internal::{
+        utils, AccountLookupError, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const DO_NOTHING_STORED_NAME: &str = "do_nothing_stored";
+const DO_NOTHING_STORED_DESTINATION: &str = "hash";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const ACCOUNT_1_HEX: &str = "2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a";
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+
+fn default_account_hex() -> String {
+    base16::encode_lower(&DEFAULT_ACCOUNT_ADDR.value())
+}
+
+#[ignore]
+#[test]
+fn should_get_genesis_account_by_hex() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let by_hex = builder
+        .get_account(default_account_hex())
+        .expect("should find genesis account by hex");
+    let by_public_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should find genesis account by public key");
+
+    assert_eq!(by_hex, by_public_key);
+    assert_eq!(by_hex.public_key(), DEFAULT_ACCOUNT_ADDR);
+}
+
+#[ignore]
+#[test]
+fn should_get_account_created_mid_scenario_by_hex() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    let exec_request_2 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(exec_request_1)
+        .expect_success();
+
+    let transforms = &builder.get_transforms()[0];
+    let from_transforms = utils::get_account_by_public_key(transforms, ACCOUNT_1_HEX)
+        .expect("should find new account in transforms by hex");
+    assert_eq!(from_transforms.public_key(), ACCOUNT_1_ADDR);
+
+    builder.exec_commit(exec_request_2).expect_success();
+
+    let account = builder
+        .get_account(ACCOUNT_1_HEX)
+        .expect("should find new account by hex");
+    assert_eq!(account.public_key(), ACCOUNT_1_ADDR);
+    assert_eq!(account.main_purse(), from_transforms.main_purse());
+}
+
+#[ignore]
+#[test]
+fn should_not_get_nonexistent_account_by_hex() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    assert!(builder.get_account(ACCOUNT_1_HEX).is_none());
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "invalid public key: expected 32 bytes, but got 2")]
+fn should_panic_on_malformed_public_key_hex() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    builder.get_account("2a2a");
+}
+
+#[ignore]
+#[test]
+fn should_get_effective_account_with_only_added_named_keys() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        (String::from(DO_NOTHING_STORED_DESTINATION),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success();
+
+    let pre_state_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert!(!pre_state_account
+        .named_keys()
+        .contains_key(DO_NOTHING_STORED_NAME));
+
+    let transforms = &builder.get_transforms()[0];
+    let from_transforms = utils::get_effective_account(
+        Some(pre_state_account),
+        transforms,
+        &Key::Account(DEFAULT_ACCOUNT_ADDR),
+    )
+    .expect("should apply transforms to account");
+    assert!(from_transforms
+        .named_keys()
+        .contains_key(DO_NOTHING_STORED_NAME));
+
+    let pending_account = builder
+        .get_effective_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have pending account");
+    assert_eq!(pending_account, from_transforms);
+
+    builder.commit();
+
+    let committed_account = builder
+        .get_effective_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have committed account");
+    assert_eq!(committed_account, from_transforms);
+    assert_eq!(
+        builder.get_account(DEFAULT_ACCOUNT_ADDR),
+        Some(committed_account)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_get_effective_account_which_does_not_exist() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    assert_eq!(
+        builder.get_effective_account(ACCOUNT_1_ADDR),
+        Err(AccountLookupError::NotFound(Key::Account(ACCOUNT_1_ADDR)))
+    );
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use engine_shared::{
+    additive_map::AdditiveMap, stored_value::StoredValue, transform::Transform, TypeMismatch,
+};
+use engine_test_support::{
+    internal::{utils, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const NAMED_KEY: &str = "named_key";
+
+#[ignore]
+#[test]
+fn should_reject_write_of_cl_value_over_added_named_keys_as_type_mismatch() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    let mut named_keys = BTreeMap::new();
+    named_keys.insert(NAMED_KEY.to_string(), Key::Hash([1; 32]));
+    let cl_value = CLValue::from_t(1u64).expect("should create CLValue");
+
+    let mut effects: AdditiveMap<Key, Transform> = AdditiveMap::new();
+    effects.insert_add(account_key, Transform::AddKeys(named_keys));
+    effects.insert_add(
+        account_key,
+        Transform::Write(StoredValue::CLValue(cl_value)),
+    );
+
+    let prestate_hash = builder.get_post_state_hash();
+    let commit_response = builder.commit_transforms(prestate_hash, effects);
+
+    assert_eq!(
+        utils::get_commit_type_mismatch(&commit_response),
+        TypeMismatch::new("Contract or Account".to_string(), "U64".to_string())
+    );
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("account should be left in place");
+    assert!(!account.named_keys().contains_key(NAMED_KEY));
+}
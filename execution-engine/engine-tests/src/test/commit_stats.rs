@@ -0,0 +1,39 @@
+use engine_shared::{additive_map::AdditiveMap, stored_value::StoredValue, transform::Transform};
+use engine_storage::trie_store::WriteStats;
+use engine_test_support::internal::{InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG};
+use types::{CLValue, Key};
+
+#[ignore]
+#[test]
+fn should_keep_post_state_hash_when_committing_no_effects() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let prestate_hash = builder.get_post_state_hash();
+
+    builder.commit_effects(prestate_hash.clone(), AdditiveMap::new());
+
+    assert_eq!(builder.get_post_state_hash(), prestate_hash);
+    assert_eq!(builder.get_last_commit_stats(), Some(WriteStats::default()));
+}
+
+#[ignore]
+#[test]
+fn should_change_post_state_hash_when_committing_effects() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let prestate_hash = builder.get_post_state_hash();
+
+    let cl_value = CLValue::from_t(1u64).expect("should create CLValue");
+    let transform = Transform::Write(StoredValue::CLValue(cl_value));
+    let mut effects: AdditiveMap<Key, Transform> = AdditiveMap::new();
+    effects.insert(Key::Hash([1; 32]), transform);
+
+    builder.commit_effects(prestate_hash.clone(), effects);
+
+    assert_ne!(builder.get_post_state_hash(), prestate_hash);
+    let stats = builder
+        .get_last_commit_stats()
+        .expect("should have commit stats");
+    assert!(stats.new_nodes > 0);
+    assert!(stats.bytes_written > 0);
+}
@@ -1,8 +1,9 @@
-use engine_core::{engine_state, execution};
+use engine_core::engine_state;
+use engine_shared::account::AuthorizationError;
 use engine_test_support::{
     internal::{
-        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -11,6 +12,12 @@ use types::account::{PublicKey, Weight};
 const CONTRACT_ADD_UPDATE_ASSOCIATED_KEY: &str = "add_update_associated_key.wasm";
 const CONTRACT_AUTHORIZED_KEYS: &str = "authorized_keys.wasm";
 
+/// Returns the message of the precondition failure raised for a deploy whose authorization keys
+/// fail with `error`.
+fn authorization_failure(error: AuthorizationError) -> String {
+    engine_state::Error::InvalidAuthorization(error).to_string()
+}
+
 #[ignore]
 #[test]
 fn should_deploy_with_authorized_identity_key() {
@@ -32,7 +39,7 @@ fn should_deploy_with_authorized_identity_key() {
 #[test]
 fn should_raise_auth_failure_with_invalid_key() {
     // tests that authorized keys that does not belong to account raises
-    // Error::Authorization
+    // AuthorizationError::InvalidAssociatedKey
     let key_1 = PublicKey::ed25519_from([254; 32]);
     assert_ne!(DEFAULT_ACCOUNT_ADDR, key_1);
 
@@ -68,14 +75,15 @@ fn should_raise_auth_failure_with_invalid_key() {
     );
     let message = format!("{}", deploy_result.error().unwrap());
 
-    assert_eq!(message, format!("{}", engine_state::Error::Authorization))
+    let expected_message = authorization_failure(AuthorizationError::InvalidAssociatedKey);
+    assert_eq!(message, expected_message)
 }
 
 #[ignore]
 #[test]
 fn should_raise_auth_failure_with_invalid_keys() {
     // tests that authorized keys that does not belong to account raises
-    // Error::Authorization
+    // AuthorizationError::InvalidAssociatedKey
     let key_1 = PublicKey::ed25519_from([254; 32]);
     let key_2 = PublicKey::ed25519_from([253; 32]);
     let key_3 = PublicKey::ed25519_from([252; 32]);
@@ -111,7 +119,8 @@ fn should_raise_auth_failure_with_invalid_keys() {
     assert!(deploy_result.has_precondition_failure());
     let message = format!("{}", deploy_result.error().unwrap());
 
-    assert_eq!(message, format!("{}", engine_state::Error::Authorization))
+    let expected_message = authorization_failure(AuthorizationError::InvalidAssociatedKey);
+    assert_eq!(message, expected_message)
 }
 
 #[ignore]
@@ -198,10 +207,8 @@ fn should_raise_deploy_authorization_failure() {
 
         assert!(deploy_result.has_precondition_failure());
         let message = format!("{}", deploy_result.error().unwrap());
-        assert!(message.contains(&format!(
-            "{}",
-            execution::Error::DeploymentAuthorizationFailure
-        )))
+        let expected_message = authorization_failure(AuthorizationError::InsufficientWeight);
+        assert!(message.contains(&expected_message))
     }
     let exec_request_6 = {
         let deploy = DeployItemBuilder::new()
@@ -245,10 +252,8 @@ fn should_raise_deploy_authorization_failure() {
 
         assert!(deploy_result.has_precondition_failure());
         let message = format!("{}", deploy_result.error().unwrap());
-        assert!(message.contains(&format!(
-            "{}",
-            execution::Error::DeploymentAuthorizationFailure
-        )))
+        let expected_message = authorization_failure(AuthorizationError::InsufficientWeight);
+        assert!(message.contains(&expected_message))
     }
 
     let exec_request_8 = {
@@ -388,8 +393,133 @@ fn should_not_authorize_deploy_with_duplicated_keys() {
         deploy_result
     );
     let message = format!("{}", deploy_result.error().unwrap());
-    assert!(message.contains(&format!(
-        "{}",
-        execution::Error::DeploymentAuthorizationFailure
-    )))
+    let expected_message = authorization_failure(AuthorizationError::InsufficientWeight);
+    assert!(message.contains(&expected_message))
+}
+
+#[ignore]
+#[test]
+fn should_require_combined_weight_of_two_keys_to_meet_deployment_threshold() {
+    let key_1 = PublicKey::ed25519_from([254; 32]);
+    let key_2 = PublicKey::ed25519_from([253; 32]);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    for key in &[key_1, key_2] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+            (*key,),
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUTHORIZED_KEYS,
+        (Weight::new(4), Weight::new(3)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let weights = builder
+        .get_account_weights(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(weights.get(&DEFAULT_ACCOUNT_ADDR), Some(&Weight::new(1)));
+    assert_eq!(weights.get(&key_1), Some(&Weight::new(2)));
+    assert_eq!(weights.get(&key_2), Some(&Weight::new(2)));
+
+    let thresholds = builder
+        .get_action_thresholds(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(thresholds.deployment(), &Weight::new(3));
+    assert_eq!(thresholds.key_management(), &Weight::new(4));
+
+    let exec_request_with_keys = |deploy_hash: [u8; 32], keys: &[PublicKey]| {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+            .with_session_code(CONTRACT_AUTHORIZED_KEYS, (Weight::new(0), Weight::new(0)))
+            .with_deploy_hash(deploy_hash)
+            .with_authorization_keys(keys)
+            .build();
+        ExecuteRequestBuilder::from_deploy_item(deploy).build()
+    };
+
+    // key_1 alone (w: 2) doesn't meet the deployment threshold of 3
+    builder
+        .exec(exec_request_with_keys([1; 32], &[key_1]))
+        .commit();
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have exec response");
+    let deploy_result = response.get(0).expect("should have a deploy result");
+    assert!(deploy_result.has_precondition_failure());
+    let message = format!("{}", deploy_result.error().unwrap());
+    let expected_message = authorization_failure(AuthorizationError::InsufficientWeight);
+    assert!(message.contains(&expected_message));
+
+    // key_1 and key_2 combined (w: 4) do
+    builder
+        .exec(exec_request_with_keys([2; 32], &[key_1, key_2]))
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_authorize_deploy_only_with_associated_keys_meeting_deployment_threshold() {
+    let key_1 = PublicKey::ed25519_from([254; 32]);
+    let unknown_key = PublicKey::ed25519_from([252; 32]);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_UPDATE_ASSOCIATED_KEY,
+        (key_1,),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    // identity key (w: 1) and key_1 (w: 2), with a deployment threshold of 3
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_AUTHORIZED_KEYS,
+        (Weight::new(3), Weight::new(3)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_with_keys = |builder: &mut InMemoryWasmTestBuilder, keys: &[PublicKey]| {
+        let deploy_hash = [builder.get_exec_responses_count() as u8; 32];
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+            .with_session_code(CONTRACT_AUTHORIZED_KEYS, (Weight::new(0), Weight::new(0)))
+            .with_deploy_hash(deploy_hash)
+            .with_authorization_keys(keys)
+            .build();
+        builder.exec(ExecuteRequestBuilder::from_deploy_item(deploy).build());
+    };
+    let precondition_failure = |builder: &InMemoryWasmTestBuilder| {
+        let index = builder.get_exec_responses_count() - 1;
+        let response = builder
+            .get_exec_response(index)
+            .expect("should have a response");
+        utils::get_precondition_failure(response)
+    };
+    let insufficient_weight = authorization_failure(AuthorizationError::InsufficientWeight);
+    let invalid_associated_key = authorization_failure(AuthorizationError::InvalidAssociatedKey);
+
+    // key_1 alone (w: 2) is below the threshold, however many times it signs
+    exec_with_keys(&mut builder, &[key_1, key_1]);
+    assert_eq!(precondition_failure(&builder), insufficient_weight);
+
+    // A key not associated with the account is rejected rather than ignored
+    exec_with_keys(&mut builder, &[DEFAULT_ACCOUNT_ADDR, key_1, unknown_key]);
+    assert_eq!(precondition_failure(&builder), invalid_associated_key);
+
+    // identity key and key_1 combined (w: 3) are exactly at the threshold
+    exec_with_keys(&mut builder, &[DEFAULT_ACCOUNT_ADDR, key_1]);
+    builder.expect_success();
 }
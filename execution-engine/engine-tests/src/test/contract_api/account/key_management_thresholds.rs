@@ -5,7 +5,10 @@ use engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::account::PublicKey;
+use types::{
+    account::{PublicKey, Weight},
+    ApiError,
+};
 
 const CONTRACT_KEY_MANAGEMENT_THRESHOLDS: &str = "key_management_thresholds.wasm";
 
@@ -70,3 +73,50 @@ fn should_verify_key_management_permission_with_sufficient_weight() {
         .expect_success()
         .commit();
 }
+
+#[ignore]
+#[test]
+fn should_prevent_thresholds_becoming_unreachable() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_KEY_MANAGEMENT_THRESHOLDS,
+        (String::from("test-lockout-prevented"),),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(*account.action_thresholds().deployment(), Weight::new(2));
+    assert_eq!(
+        *account.action_thresholds().key_management(),
+        Weight::new(2)
+    );
+    assert_eq!(
+        account.get_associated_key_weight(PublicKey::ed25519_from([45; 32])),
+        Some(&Weight::new(1))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_surface_insufficient_total_weight_in_execution_error() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_KEY_MANAGEMENT_THRESHOLDS,
+        (String::from("test-insufficient-total-weight"),),
+    )
+    .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_error(ApiError::InsufficientTotalWeight);
+}
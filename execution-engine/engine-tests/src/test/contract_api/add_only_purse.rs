@@ -0,0 +1,108 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{system_contract_errors::mint, AccessRights, ApiError, URef, U512};
+
+const CONTRACT_ADD_ONLY_PURSE_HOLDER: &str = "add_only_purse_holder.wasm";
+const CONTRACT_ADD_ONLY_PURSE_HOLDER_CALLER: &str = "add_only_purse_holder_caller.wasm";
+const DEPOSIT_PURSE_NAME: &str = "deposit_purse";
+const COMMAND_DEPOSIT: &str = "deposit";
+const COMMAND_WITHDRAW: &str = "withdraw";
+const COMMAND_WITHDRAW_VIA_HOLDER: &str = "withdraw_via_holder";
+const COMMAND_WITHDRAW_ESCALATED: &str = "withdraw_escalated";
+const DEPOSIT_AMOUNT: u64 = 100;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_ADD_ONLY_PURSE_HOLDER, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis_default()
+        .exec_commit(exec_request)
+        .expect_success();
+    builder
+}
+
+fn deposit_purse(builder: &InMemoryWasmTestBuilder) -> URef {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DEPOSIT_PURSE_NAME)
+        .and_then(|key| key.into_uref())
+        .expect("should have deposit purse")
+}
+
+fn call_holder<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    command: &str,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_ONLY_PURSE_HOLDER_CALLER,
+        (String::from(command), U512::from(DEPOSIT_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).commit()
+}
+
+/// Deposits into the add-only purse, then asserts that `command` fails to withdraw from it with
+/// `expected_error` and that the deposit remains in the purse.
+fn assert_withdrawal_fails_with(command: &str, expected_error: ApiError) {
+    let mut builder = setup();
+    call_holder(&mut builder, COMMAND_DEPOSIT).expect_success();
+
+    call_holder(&mut builder, command).expect_error(expected_error);
+
+    let balance = builder.get_purse_balance(deposit_purse(&builder));
+    assert_eq!(balance, U512::from(DEPOSIT_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_create_purse_with_add_rights_only() {
+    let builder = setup();
+
+    let purse = deposit_purse(&builder);
+    assert_eq!(purse.access_rights(), AccessRights::ADD);
+    assert_eq!(builder.get_purse_balance(purse), U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_deposit_into_add_only_purse() {
+    let mut builder = setup();
+
+    call_holder(&mut builder, COMMAND_DEPOSIT).expect_success();
+    call_holder(&mut builder, COMMAND_DEPOSIT).expect_success();
+
+    let balance = builder.get_purse_balance(deposit_purse(&builder));
+    assert_eq!(balance, U512::from(2 * DEPOSIT_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_withdraw_from_add_only_purse() {
+    assert_withdrawal_fails_with(
+        COMMAND_WITHDRAW,
+        ApiError::from(mint::Error::InvalidAccessRights),
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_withdraw_from_add_only_purse_via_holder() {
+    assert_withdrawal_fails_with(
+        COMMAND_WITHDRAW_VIA_HOLDER,
+        ApiError::from(mint::Error::InvalidAccessRights),
+    );
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_withdraw_from_add_only_purse_with_escalated_rights() {
+    assert_withdrawal_fails_with(COMMAND_WITHDRAW_ESCALATED, ApiError::Transfer);
+}
@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+const CONTRACT_ALLOWANCE_TABLE: &str = "allowance_table.wasm";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const CAROL: &str = "carol";
+const ALICE_ALLOWANCE: u64 = 100;
+const BOB_ALLOWANCE: u64 = 250;
+const OTHER_SPENDER_ADDR: PublicKey = PublicKey::ed25519_from([7u8; 32]);
+
+#[repr(u16)]
+enum Error {
+    CallerNotSpender = 0,
+    UnexpectedAllowance = 1,
+}
+
+fn allowances() -> BTreeMap<String, U512> {
+    let mut allowances = BTreeMap::new();
+    allowances.insert(ALICE.to_string(), U512::from(ALICE_ALLOWANCE));
+    allowances.insert(BOB.to_string(), U512::from(BOB_ALLOWANCE));
+    allowances
+}
+
+fn check_allowance(
+    spenders: Vec<PublicKey>,
+    name: &str,
+    expected_allowance: Option<u64>,
+) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ALLOWANCE_TABLE,
+        (
+            allowances(),
+            spenders,
+            name.to_string(),
+            expected_allowance.map(U512::from),
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_find_entries_of_allowance_table() {
+    let spenders = vec![OTHER_SPENDER_ADDR, DEFAULT_ACCOUNT_ADDR];
+    check_allowance(spenders.clone(), ALICE, Some(ALICE_ALLOWANCE)).expect_success();
+    check_allowance(spenders.clone(), BOB, Some(BOB_ALLOWANCE)).expect_success();
+    check_allowance(spenders, CAROL, None).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_revert_on_unexpected_allowance() {
+    let spenders = vec![DEFAULT_ACCOUNT_ADDR];
+    check_allowance(spenders.clone(), ALICE, Some(BOB_ALLOWANCE))
+        .expect_error(ApiError::User(Error::UnexpectedAllowance as u16));
+    check_allowance(spenders, CAROL, Some(ALICE_ALLOWANCE))
+        .expect_error(ApiError::User(Error::UnexpectedAllowance as u16));
+}
+
+#[ignore]
+#[test]
+fn should_revert_if_caller_not_in_spenders() {
+    check_allowance(vec![OTHER_SPENDER_ADDR], ALICE, Some(ALICE_ALLOWANCE))
+        .expect_error(ApiError::User(Error::CallerNotSpender as u16));
+}
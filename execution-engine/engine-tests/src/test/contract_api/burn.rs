@@ -0,0 +1,73 @@
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{system_contract_errors::mint, ApiError, U512};
+
+const CONTRACT_BURN: &str = "burn.wasm";
+const BURN_AMOUNT: u64 = 1_000_000;
+
+#[ignore]
+#[test]
+fn should_burn_from_purse_and_total_supply() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    let initial_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+    let initial_total_supply = builder.total_supply();
+
+    let burn_amount = U512::from(BURN_AMOUNT);
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_BURN, (burn_amount, false))
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    // Paying for the deploy moves motes between purses, so only the burn changes the total supply.
+    assert_eq!(builder.total_supply(), initial_total_supply - burn_amount);
+
+    let gas_cost =
+        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    assert_eq!(
+        builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        initial_balance - gas_cost.value() - burn_amount
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_burn_more_than_balance() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    let initial_total_supply = builder.total_supply();
+
+    let burn_amount = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR) + U512::one();
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_BURN, (burn_amount, false))
+            .build();
+    builder
+        .exec(exec_request)
+        .expect_error(ApiError::from(mint::Error::InsufficientFunds))
+        .commit();
+
+    assert_eq!(builder.total_supply(), initial_total_supply);
+}
+
+#[ignore]
+#[test]
+fn should_not_burn_with_add_only_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    let initial_total_supply = builder.total_supply();
+
+    let burn_amount = U512::from(BURN_AMOUNT);
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_BURN, (burn_amount, true))
+            .build();
+    builder
+        .exec(exec_request)
+        .expect_error(ApiError::NoAccessRights)
+        .commit();
+
+    assert_eq!(builder.total_supply(), initial_total_supply);
+}
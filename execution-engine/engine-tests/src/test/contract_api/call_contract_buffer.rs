@@ -0,0 +1,67 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::Phase;
+
+const CONTRACT_RETURN_LARGE_BLOB: &str = "return_large_blob.wasm";
+const CONTRACT_RETURN_LARGE_BLOB_CALLER: &str = "return_large_blob_caller.wasm";
+const LARGE_BLOB_SIZE: u32 = 64 * 1024;
+/// The serialized length prefix of a `Vec<u8>`.
+const LENGTH_PREFIX_SIZE: u32 = 4;
+
+/// Calls `return_large_blob` with a buffer of `buffer_size` bytes for the result, returning the
+/// host functions the caller called after the stored contract returned.
+fn call_with_buffer(blob_size: u32, buffer_size: u32) -> Vec<&'static str> {
+    let exec_request_1 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_RETURN_LARGE_BLOB, ())
+            .build();
+    // The caller reverts unless it receives the blob intact.
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RETURN_LARGE_BLOB_CALLER,
+        (blob_size, buffer_size, 1u32),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .with_host_tracing()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    let names: Vec<&'static str> = builder
+        .last_host_trace()
+        .iter()
+        .filter(|call| call.phase == Phase::Session)
+        .map(|call| call.name)
+        .collect();
+    assert!(names.contains(&"call_contract_into"));
+    let ret_index = names
+        .iter()
+        .rposition(|name| *name == "ret")
+        .expect("stored contract should return");
+    names[ret_index + 1..].to_vec()
+}
+
+#[ignore]
+#[test]
+fn should_write_result_directly_into_large_enough_buffer() {
+    let calls_after_return =
+        call_with_buffer(LARGE_BLOB_SIZE, LARGE_BLOB_SIZE + LENGTH_PREFIX_SIZE);
+    assert!(calls_after_return.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_fall_back_to_host_buffer_when_buffer_is_too_small() {
+    for buffer_size in &[LARGE_BLOB_SIZE + LENGTH_PREFIX_SIZE - 1, 0] {
+        let calls_after_return = call_with_buffer(LARGE_BLOB_SIZE, *buffer_size);
+        assert_eq!(calls_after_return, vec!["read_host_buffer"]);
+    }
+}
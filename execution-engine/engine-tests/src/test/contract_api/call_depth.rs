@@ -0,0 +1,88 @@
+use engine_core::engine_state::{engine_config::DEFAULT_MAX_CALL_DEPTH, EngineConfig};
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_RECURSIVE_CALL: &str = "recursive_call.wasm";
+
+fn builder_with_max_call_depth(max_call_depth: usize) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_max_call_depth(max_call_depth);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    let mut builder = InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash);
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    builder
+}
+
+/// Runs the session code which nests calls to a stored contract until it reaches `call_depth`.
+fn exec_recursive_call(builder: &mut InMemoryWasmTestBuilder, call_depth: usize) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RECURSIVE_CALL,
+        (call_depth as u32,),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+/// Asserts that the last exec request failed as it exceeded the maximum call depth.
+fn assert_call_depth_exceeded(builder: &InMemoryWasmTestBuilder) {
+    assert!(builder.is_error());
+    let error_message = builder
+        .exec_error_message(builder.get_exec_responses_count() - 1)
+        .expect("should have an error message");
+    assert!(
+        error_message.contains("CallDepthExceeded"),
+        "unexpected error: {}",
+        error_message
+    );
+}
+
+#[ignore]
+#[test]
+fn should_allow_calls_up_to_default_max_call_depth() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    exec_recursive_call(&mut builder, DEFAULT_MAX_CALL_DEPTH);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_fail_calls_exceeding_default_max_call_depth() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    exec_recursive_call(&mut builder, DEFAULT_MAX_CALL_DEPTH + 1);
+    assert_call_depth_exceeded(&builder);
+}
+
+#[ignore]
+#[test]
+fn should_count_session_as_depth_one() {
+    let mut builder = builder_with_max_call_depth(1);
+
+    exec_recursive_call(&mut builder, 1);
+    builder.expect_success();
+
+    exec_recursive_call(&mut builder, 2);
+    assert_call_depth_exceeded(&builder);
+}
+
+#[ignore]
+#[test]
+fn should_respect_configured_max_call_depth() {
+    const MAX_CALL_DEPTH: usize = 3;
+    let mut builder = builder_with_max_call_depth(MAX_CALL_DEPTH);
+
+    exec_recursive_call(&mut builder, MAX_CALL_DEPTH);
+    builder.expect_success();
+
+    exec_recursive_call(&mut builder, MAX_CALL_DEPTH + 1);
+    assert_call_depth_exceeded(&builder);
+}
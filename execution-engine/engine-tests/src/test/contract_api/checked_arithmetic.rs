@@ -0,0 +1,143 @@
+use std::convert::TryFrom;
+
+use engine_core::execution;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::FromBytes, ApiError, CLTyped, CLValue, U512};
+
+const CONTRACT_ADD_U512: &str = "add_u512.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_PURSE: &str = "transfer_purse_to_purse.wasm";
+const PURSE_MAIN: &str = "purse:main";
+const PURSE_SECONDARY: &str = "purse:secondary";
+const PURSE_TRANSFER_RESULT: &str = "purse_transfer_result";
+const SECONDARY_BALANCE: u64 = 42;
+const TOTAL_KEY: &str = "total";
+
+fn transfer_purse_to_purse(
+    builder: &mut InMemoryWasmTestBuilder,
+    source: &str,
+    target: &str,
+    amount: U512,
+) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE,
+        (source.to_string(), target.to_string(), amount),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+/// Runs genesis, then funds a new secondary purse of the default account with
+/// `SECONDARY_BALANCE` motes.
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let amount = U512::from(SECONDARY_BALANCE);
+    transfer_purse_to_purse(&mut builder, PURSE_MAIN, PURSE_SECONDARY, amount);
+    builder
+}
+
+fn get_named_value<T: CLTyped + FromBytes>(
+    builder: &InMemoryWasmTestBuilder,
+    name: &str,
+) -> Option<T> {
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let key = account.named_keys().get(name)?.normalize();
+    let stored_value = builder.query(None, key, &[]).expect("should have value");
+    let cl_value = CLValue::try_from(stored_value).expect("should be a CLValue");
+    Some(cl_value.into_t().expect("should have expected type"))
+}
+
+fn get_secondary_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[PURSE_SECONDARY]
+        .into_uref()
+        .expect("should be a uref");
+    builder.get_purse_balance(purse)
+}
+
+fn get_transfer_result(builder: &InMemoryWasmTestBuilder) -> String {
+    get_named_value(builder, PURSE_TRANSFER_RESULT).expect("should have transfer result")
+}
+
+#[ignore]
+#[test]
+fn should_transfer_full_balance() {
+    let mut builder = setup();
+    let amount = U512::from(SECONDARY_BALANCE);
+    transfer_purse_to_purse(&mut builder, PURSE_SECONDARY, PURSE_MAIN, amount);
+
+    let expected_result = format!("{:?}", Result::<(), ApiError>::Ok(()));
+    assert_eq!(get_transfer_result(&builder), expected_result);
+    assert_eq!(get_secondary_balance(&builder), U512::zero());
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_more_than_balance() {
+    let mut builder = setup();
+    let amount = U512::from(SECONDARY_BALANCE + 1);
+    transfer_purse_to_purse(&mut builder, PURSE_SECONDARY, PURSE_MAIN, amount);
+
+    let expected_result = format!("{:?}", Result::<(), ApiError>::Err(ApiError::Transfer));
+    assert_eq!(get_transfer_result(&builder), expected_result);
+    assert_eq!(
+        get_secondary_balance(&builder),
+        U512::from(SECONDARY_BALANCE)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_accumulate_adds_up_to_max_u512() {
+    let initial = U512::max_value() - 3;
+    let amounts = vec![U512::one(); 3];
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_U512,
+        (initial, amounts),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let total: U512 = get_named_value(&builder, TOTAL_KEY).expect("should have total");
+    assert_eq!(total, U512::max_value());
+}
+
+#[ignore]
+#[test]
+fn should_fail_adds_overflowing_u512() {
+    let initial = U512::max_value() - 3;
+    let amounts = vec![U512::one(); 4];
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_ADD_U512,
+        (initial, amounts),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    match builder.get_exec_error(0) {
+        Some(execution::Error::Overflow) => (),
+        other => panic!("expected overflow, got {:?}", other),
+    }
+    assert!(get_named_value::<U512>(&builder, TOTAL_KEY).is_none());
+}
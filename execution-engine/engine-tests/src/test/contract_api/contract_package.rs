@@ -0,0 +1,127 @@
+use engine_core::engine_state::execute_request::ExecuteRequest;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{ApiError, ContractVersion};
+
+const CONTRACT_PACKAGE: &str = "contract_package.wasm";
+const COMMAND_INSTALL_V1: &str = "install_v1";
+const COMMAND_INSTALL_V2: &str = "install_v2";
+const COMMAND_DISABLE: &str = "disable";
+const COMMAND_CALL: &str = "call";
+
+fn install_request(command: &str) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PACKAGE, (command,)).build()
+}
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(install_request(COMMAND_INSTALL_V1))
+        .expect_success()
+        .exec_commit(install_request(COMMAND_INSTALL_V2))
+        .expect_success();
+    builder
+}
+
+fn call_request(maybe_version: Option<ContractVersion>, expected_result: &str) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PACKAGE,
+        (COMMAND_CALL, maybe_version, expected_result),
+    )
+    .build()
+}
+
+fn disable_request(version: ContractVersion) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PACKAGE,
+        (COMMAND_DISABLE, version),
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_call_latest_version_by_default() {
+    let mut builder = setup();
+    builder
+        .exec_commit(call_request(None, "v2"))
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_call_pinned_version() {
+    let mut builder = setup();
+    builder
+        .exec_commit(call_request(Some(1), "v1"))
+        .expect_success()
+        .exec_commit(call_request(Some(2), "v2"))
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_keep_calling_version_1_after_version_2_is_added() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(install_request(COMMAND_INSTALL_V1))
+        .expect_success()
+        .exec_commit(call_request(None, "v1"))
+        .expect_success()
+        .exec_commit(call_request(Some(1), "v1"))
+        .expect_success()
+        .exec_commit(install_request(COMMAND_INSTALL_V2))
+        .expect_success()
+        // Callers pinned to version 1 are unaffected, while the others move on to version 2.
+        .exec_commit(call_request(Some(1), "v1"))
+        .expect_success()
+        .exec_commit(call_request(None, "v2"))
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_call_disabled_version() {
+    let mut builder = setup();
+    builder
+        .exec_commit(disable_request(2))
+        .expect_success()
+        .exec(call_request(Some(2), "v2"))
+        .expect_error(ApiError::ContractVersionDisabled)
+        .commit()
+        .exec_commit(call_request(None, "v1"))
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_find_missing_version() {
+    let mut builder = setup();
+    builder
+        .exec(call_request(Some(3), "v3"))
+        .expect_error(ApiError::ContractVersionNotFound)
+        .commit()
+        .exec(disable_request(3))
+        .expect_error(ApiError::ContractVersionNotFound)
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_call_package_without_enabled_versions() {
+    let mut builder = setup();
+    builder
+        .exec_commit(disable_request(1))
+        .expect_success()
+        .exec_commit(disable_request(2))
+        .expect_success()
+        .exec(call_request(None, "v2"))
+        .expect_error(ApiError::ContractVersionNotFound)
+        .commit();
+}
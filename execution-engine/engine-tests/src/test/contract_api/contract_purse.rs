@@ -0,0 +1,134 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{system_contract_errors::mint, ApiError, KEY_HASH_LENGTH, U512};
+
+const CONTRACT_PURSE_HOLDER: &str = "contract_purse_holder.wasm";
+const CONTRACT_PURSE_HOLDER_CALLER: &str = "contract_purse_holder_caller.wasm";
+const CONTRACT_NAME: &str = "contract_purse_holder";
+const COMMAND_DEPOSIT: &str = "deposit";
+const COMMAND_WITHDRAW: &str = "withdraw";
+const COMMAND_WITHDRAW_VIA_HOLDER: &str = "withdraw_via_holder";
+const COMMAND_DEPOSIT_VIA_UREF: &str = "deposit_via_uref";
+const DEPOSIT_AMOUNT: u64 = 100;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_PURSE_HOLDER, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis_default()
+        .exec_commit(exec_request)
+        .expect_success();
+    builder
+}
+
+fn contract_hash(builder: &InMemoryWasmTestBuilder) -> [u8; KEY_HASH_LENGTH] {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_NAME)
+        .and_then(|key| key.into_hash())
+        .expect("should have contract hash")
+}
+
+fn call_holder<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    command: &str,
+    amount: u64,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PURSE_HOLDER_CALLER,
+        (String::from(command), U512::from(amount)),
+    )
+    .build();
+    builder.exec(exec_request).commit()
+}
+
+fn deposit(builder: &mut InMemoryWasmTestBuilder) {
+    call_holder(builder, COMMAND_DEPOSIT, DEPOSIT_AMOUNT).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_create_contract_purse_on_first_deposit() {
+    let mut builder = setup();
+    let contract_hash = contract_hash(&builder);
+    assert_eq!(builder.get_contract_purse(contract_hash), None);
+
+    deposit(&mut builder);
+
+    let purse = builder
+        .get_contract_purse(contract_hash)
+        .expect("should have contract purse");
+    assert_eq!(builder.get_purse_balance(purse), U512::from(DEPOSIT_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_return_same_contract_purse_on_every_call() {
+    let mut builder = setup();
+    let contract_hash = contract_hash(&builder);
+
+    deposit(&mut builder);
+    let purse = builder.get_contract_purse(contract_hash);
+
+    deposit(&mut builder);
+    assert_eq!(builder.get_contract_purse(contract_hash), purse);
+
+    let balance = builder.get_purse_balance(purse.expect("should have contract purse"));
+    assert_eq!(balance, U512::from(2 * DEPOSIT_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_let_contract_withdraw_from_its_purse() {
+    let mut builder = setup();
+    let contract_hash = contract_hash(&builder);
+    deposit(&mut builder);
+
+    let withdrawal = DEPOSIT_AMOUNT / 4;
+    call_holder(&mut builder, COMMAND_WITHDRAW_VIA_HOLDER, withdrawal).expect_success();
+
+    let purse = builder
+        .get_contract_purse(contract_hash)
+        .expect("should have contract purse");
+    let balance = builder.get_purse_balance(purse);
+    assert_eq!(balance, U512::from(DEPOSIT_AMOUNT - withdrawal));
+}
+
+#[ignore]
+#[test]
+fn should_not_let_caller_withdraw_from_contract_purse() {
+    let mut builder = setup();
+    let contract_hash = contract_hash(&builder);
+    deposit(&mut builder);
+
+    call_holder(&mut builder, COMMAND_WITHDRAW, DEPOSIT_AMOUNT)
+        .expect_error(ApiError::from(mint::Error::InvalidAccessRights));
+
+    let purse = builder
+        .get_contract_purse(contract_hash)
+        .expect("should have contract purse");
+    assert_eq!(builder.get_purse_balance(purse), U512::from(DEPOSIT_AMOUNT));
+}
+
+#[ignore]
+#[test]
+fn should_return_same_contract_purse_for_uref_of_contract_address() {
+    let mut builder = setup();
+    let contract_hash = contract_hash(&builder);
+    deposit(&mut builder);
+
+    call_holder(&mut builder, COMMAND_DEPOSIT_VIA_UREF, DEPOSIT_AMOUNT).expect_success();
+
+    let purse = builder
+        .get_contract_purse(contract_hash)
+        .expect("should have contract purse");
+    let balance = builder.get_purse_balance(purse);
+    assert_eq!(balance, U512::from(2 * DEPOSIT_AMOUNT));
+}
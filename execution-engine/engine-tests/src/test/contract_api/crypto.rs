@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::{gas::Gas, newtypes::Blake2bHash};
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key, U512};
+
+const CONTRACT_HASH_KNOWN_VECTORS: &str = "hash_known_vectors.wasm";
+const CONTRACT_BLAKE2B_HASH: &str = "blake2b_hash.wasm";
+const DIGEST_NAME: &str = "digest";
+const MODE_HOST: &str = "host";
+const MODE_WASM: &str = "wasm";
+const DATA_LENGTH: usize = 4096;
+const HASH_PER_BYTE: u32 = 1_000;
+
+fn data() -> Vec<u8> {
+    (0..DATA_LENGTH).map(|i| i as u8).collect()
+}
+
+/// Hashes `data()` with the blake2b-hash contract in the given mode, returning the cost of the
+/// deploy and the digest it stored.
+fn blake2b_hash(genesis_config: &GenesisConfig, mode: &str) -> (Gas, [u8; 32]) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_BLAKE2B_HASH,
+        (String::from(mode), data()),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[DIGEST_NAME])
+        .expect("should have digest");
+    let digest = CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be digest");
+
+    (builder.exec_costs(0)[0], digest)
+}
+
+#[ignore]
+#[test]
+fn should_hash_known_vectors_via_host_functions() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_HASH_KNOWN_VECTORS, ())
+            .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_charge_less_for_host_blake2b_than_wasm_blake2b() {
+    let (host_cost, host_digest) = blake2b_hash(&DEFAULT_GENESIS_CONFIG, MODE_HOST);
+    let (wasm_cost, wasm_digest) = blake2b_hash(&DEFAULT_GENESIS_CONFIG, MODE_WASM);
+
+    let expected_digest = Blake2bHash::new(&data()).value();
+    assert_eq!(host_digest, expected_digest);
+    assert_eq!(wasm_digest, expected_digest);
+
+    assert!(
+        host_cost < wasm_cost,
+        "host hashing cost {:?} should be less than wasm hashing cost {:?}",
+        host_cost,
+        wasm_cost
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_per_byte_hashed() {
+    let (free_hashing_cost, _) = blake2b_hash(
+        &utils::genesis_config_with(|wasm_costs| wasm_costs.hash_per_byte = 0),
+        MODE_HOST,
+    );
+    let (hashing_cost, _) = blake2b_hash(
+        &utils::genesis_config_with(|wasm_costs| wasm_costs.hash_per_byte = HASH_PER_BYTE),
+        MODE_HOST,
+    );
+
+    let expected_hashing_cost = Gas::new(U512::from(HASH_PER_BYTE) * U512::from(DATA_LENGTH));
+    assert_eq!(hashing_cost, free_hashing_cost + expected_hashing_cost);
+}
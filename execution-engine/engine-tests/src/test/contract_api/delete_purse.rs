@@ -0,0 +1,148 @@
+use std::convert::TryFrom;
+
+use engine_shared::transform::Transform;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, ApiError, CLValue, Key, URef, U512};
+
+const CONTRACT_DELETE_PURSE: &str = "delete_purse.wasm";
+const PURSE_TO_DELETE: &str = "purse:to_delete";
+const METHOD_CREATE: &str = "create";
+const METHOD_DELETE: &str = "delete";
+const METHOD_DELETE_ADD_ONLY: &str = "delete_add_only";
+const METHOD_DELETE_ACCOUNT: &str = "delete_account";
+const PURSE_AMOUNT: u64 = 42;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELETE_PURSE,
+        (String::from(METHOD_CREATE), U512::from(PURSE_AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_delete_purse_and_reject_transfers_to_it() {
+    let mut builder = setup();
+
+    let purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[PURSE_TO_DELETE]
+        .into_uref()
+        .expect("should be uref");
+    assert_eq!(builder.get_purse_balance(purse), U512::from(PURSE_AMOUNT));
+
+    // The contract reverts if a transfer to the deleted purse succeeds
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELETE_PURSE,
+        (String::from(METHOD_DELETE), U512::from(PURSE_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let balance_mapping_key = balance_mapping_key(&builder, purse);
+    assert!(builder.query(None, balance_mapping_key, &[]).is_err());
+}
+
+#[ignore]
+#[test]
+fn should_record_delete_transforms_for_deleted_purse() {
+    let mut builder = setup();
+
+    let purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[PURSE_TO_DELETE]
+        .into_uref()
+        .expect("should be uref");
+    let balance_mapping_key = balance_mapping_key(&builder, purse);
+    let balance_key: Key = builder
+        .query(None, balance_mapping_key, &[])
+        .map_err(|error| format!("{:?}", error))
+        .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
+        .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
+        .expect("should find balance uref");
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELETE_PURSE,
+        (String::from(METHOD_DELETE), U512::from(PURSE_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let transforms = &builder.get_transforms()[1];
+    assert_eq!(
+        transforms.get(&balance_key.normalize()),
+        Some(&Transform::Delete)
+    );
+    assert_eq!(
+        transforms.get(&balance_mapping_key.normalize()),
+        Some(&Transform::Delete)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_delete_purse_without_full_access_rights() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELETE_PURSE,
+        (
+            String::from(METHOD_DELETE_ADD_ONLY),
+            U512::from(PURSE_AMOUNT),
+        ),
+    )
+    .build();
+
+    setup()
+        .exec(exec_request)
+        .expect_error(ApiError::NoAccessRights);
+}
+
+#[ignore]
+#[test]
+fn should_not_delete_own_account() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DELETE_PURSE,
+        (
+            String::from(METHOD_DELETE_ACCOUNT),
+            U512::from(PURSE_AMOUNT),
+        ),
+    )
+    .build();
+
+    let mut builder = setup();
+    builder
+        .exec(exec_request)
+        .expect_error(ExpectedError::InvalidContext)
+        .commit();
+    assert!(builder.get_account(DEFAULT_ACCOUNT_ADDR).is_some());
+}
+
+/// Returns the local key under which the mint maps `purse` to its balance uref.
+fn balance_mapping_key(builder: &InMemoryWasmTestBuilder, purse: URef) -> Key {
+    let mint = builder.get_mint_contract_uref();
+    let purse_bytes = purse
+        .addr()
+        .to_bytes()
+        .expect("should serialize purse addr");
+    Key::local(mint.addr(), &purse_bytes)
+}
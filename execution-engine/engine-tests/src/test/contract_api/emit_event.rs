@@ -0,0 +1,82 @@
+use engine_core::engine_state::EngineConfig;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, ApiError, Key, Phase};
+
+const CONTRACT_EMIT_EVENTS: &str = "emit_events.wasm";
+const METHOD_EMIT: &str = "emit";
+const METHOD_FLOOD: &str = "flood";
+const METHOD_EMIT_AND_REVERT: &str = "emit_and_revert";
+const METHOD_EMIT_AND_CALL_REVERTING: &str = "emit_and_call_reverting";
+const TOPIC_FLOOD: &str = "flood";
+
+fn run(builder: &mut InMemoryWasmTestBuilder, method: &str, count: u32) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_EMIT_EVENTS,
+        (String::from(method), count),
+    )
+    .build();
+    builder.exec(exec_request);
+}
+
+#[ignore]
+#[test]
+fn should_record_events_in_emission_order() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    run(&mut builder, METHOD_EMIT, 42);
+    builder.expect_success();
+
+    let events = builder.last_events();
+    let topics: Vec<&str> = events.iter().map(|event| event.topic.as_str()).collect();
+    assert_eq!(topics, vec!["first", "nested", "last"]);
+
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(events[0].emitter, account_key);
+    assert_eq!(events[0].phase, Phase::Session);
+    assert_eq!(events[0].data, 42u32.to_bytes().unwrap());
+    // The nested event is attributed to the stored contract rather than the account
+    assert_ne!(events[1].emitter, account_key);
+    assert_eq!(events[2].emitter, account_key);
+}
+
+#[ignore]
+#[test]
+fn should_fail_when_exceeding_event_limit() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    let max_events = EngineConfig::new().max_events() as u32;
+
+    run(&mut builder, METHOD_FLOOD, max_events);
+    builder.expect_success();
+    let flood_events = builder.last_events_with_topic(TOPIC_FLOOD);
+    assert_eq!(flood_events.len(), max_events as usize);
+
+    run(&mut builder, METHOD_FLOOD, max_events + 1);
+    builder.expect_error(ApiError::EventLimitExceeded);
+    assert!(builder.last_events().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_report_events_of_failed_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    run(&mut builder, METHOD_EMIT_AND_REVERT, 0);
+    builder.expect_error(ApiError::User(1));
+    assert!(builder.last_events().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_report_events_of_reverted_nested_call() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    // A reverted call can't be recovered from, so the revert fails the whole deploy
+    run(&mut builder, METHOD_EMIT_AND_CALL_REVERTING, 0);
+    builder.expect_error(ApiError::User(1));
+    assert!(builder.last_events().is_empty());
+}
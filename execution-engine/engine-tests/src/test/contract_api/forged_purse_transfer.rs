@@ -0,0 +1,158 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder, DEFAULT_PAYMENT},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, URef, U512};
+
+const CONTRACT_FORGED_PURSE_TRANSFER: &str = "forged_purse_transfer.wasm";
+const CONTRACT_TRANSFER_MAIN_PURSE_TO_NEW_PURSE: &str = "transfer_main_purse_to_new_purse.wasm";
+const COMMAND_PASSED: &str = "passed";
+const COMMAND_FORGED: &str = "forged";
+const COMMAND_FORGED_ADD_ONLY: &str = "forged_add_only";
+const PURSE_NAME: &str = "purse";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000;
+
+/// Funds account 1 and has it create a purse of its own under `PURSE_NAME`, returning that purse.
+fn setup() -> (InMemoryWasmTestBuilder, URef) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default().transfer(
+        DEFAULT_ACCOUNT_ADDR,
+        ACCOUNT_1_ADDR,
+        *DEFAULT_PAYMENT * 10,
+    );
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_TRANSFER_MAIN_PURSE_TO_NEW_PURSE,
+        (PURSE_NAME, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let purse = match builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(PURSE_NAME)
+    {
+        Some(Key::URef(purse)) => *purse,
+        key => panic!("expected a purse under {}, got {:?}", PURSE_NAME, key),
+    };
+    (builder, purse)
+}
+
+fn default_main_purse(builder: &InMemoryWasmTestBuilder) -> URef {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .main_purse()
+}
+
+#[ignore]
+#[test]
+fn should_transfer_from_purse_passed_in_args() {
+    let (mut builder, purse) = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_FORGED_PURSE_TRANSFER,
+        (
+            COMMAND_PASSED,
+            purse,
+            ACCOUNT_2_ADDR,
+            U512::from(TRANSFER_AMOUNT),
+        ),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.get_purse_balance(purse), U512::zero());
+    assert_eq!(
+        builder.get_account_balance(ACCOUNT_2_ADDR),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_purse_of_other_account_passed_in_args() {
+    let (mut builder, _) = setup();
+    let main_purse = default_main_purse(&builder);
+    let balance_before = builder.get_purse_balance(main_purse);
+
+    // Passing a purse in the arguments doesn't grant access to it.
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_FORGED_PURSE_TRANSFER,
+        (
+            COMMAND_PASSED,
+            main_purse,
+            ACCOUNT_2_ADDR,
+            U512::from(TRANSFER_AMOUNT),
+        ),
+    )
+    .build();
+    builder
+        .exec(exec_request)
+        .expect_error(ExpectedError::ForgedReference)
+        .commit();
+
+    assert_eq!(builder.get_purse_balance(main_purse), balance_before);
+    assert!(builder.get_account(ACCOUNT_2_ADDR).is_none());
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_forged_purse() {
+    let (mut builder, _) = setup();
+    let main_purse = default_main_purse(&builder);
+    let balance_before = builder.get_purse_balance(main_purse);
+
+    // Account 1 never received the default account's main purse, so it can only fabricate it from
+    // the purse's address.
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_FORGED_PURSE_TRANSFER,
+        (
+            COMMAND_FORGED,
+            main_purse.addr(),
+            ACCOUNT_2_ADDR,
+            U512::from(TRANSFER_AMOUNT),
+        ),
+    )
+    .build();
+    builder
+        .exec(exec_request)
+        .expect_error(ExpectedError::ForgedReference)
+        .commit();
+
+    assert_eq!(builder.get_purse_balance(main_purse), balance_before);
+    assert!(builder.get_account(ACCOUNT_2_ADDR).is_none());
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_from_forged_purse_to_its_own_account() {
+    let (mut builder, _) = setup();
+
+    // Forged with the same rights as the default account's add-only main purse, a transfer to the
+    // default account returns before the mint is called, so the forged purse has to be caught by
+    // the host function itself.
+    let exec_request = ExecuteRequestBuilder::standard(
+        ACCOUNT_1_ADDR,
+        CONTRACT_FORGED_PURSE_TRANSFER,
+        (
+            COMMAND_FORGED_ADD_ONLY,
+            default_main_purse(&builder).addr(),
+            DEFAULT_ACCOUNT_ADDR,
+            U512::from(TRANSFER_AMOUNT),
+        ),
+    )
+    .build();
+    builder
+        .exec(exec_request)
+        .expect_error(ExpectedError::ForgedReference)
+        .commit();
+}
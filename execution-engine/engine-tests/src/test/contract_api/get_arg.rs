@@ -1,9 +1,11 @@
+use std::convert::TryFrom;
+
 use contract::args_parser::ArgsParser;
 use engine_test_support::{
     internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{ApiError, U512};
+use types::{bytesrepr::FromBytes, ApiError, CLTyped, CLValue, Key, U512};
 
 #[derive(Debug)]
 #[repr(u16)]
@@ -15,6 +17,9 @@ enum GetArgContractError {
 }
 
 const CONTRACT_GET_ARG: &str = "get_arg.wasm";
+const CONTRACT_GET_ARGS_LEN: &str = "get_args_len.wasm";
+const ARGS_LEN_KEY: &str = "args_len";
+const ARG_SIZES_KEY: &str = "arg_sizes";
 const ARG0_VALUE: &str = "Hello, world!";
 const ARG1_VALUE: u64 = 42;
 
@@ -43,6 +48,35 @@ fn call_get_arg(args: impl ArgsParser) -> Result<(), String> {
     Err(error_message)
 }
 
+/// Calls get_args_len contract and returns the number of args and their sizes as reported to it
+fn call_get_args_len(args: impl ArgsParser) -> (u32, Vec<u32>) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_ARGS_LEN, args).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    (
+        query_named_value(&builder, ARGS_LEN_KEY),
+        query_named_value(&builder, ARG_SIZES_KEY),
+    )
+}
+
+fn query_named_value<T>(builder: &InMemoryWasmTestBuilder, name: &str) -> T
+where
+    T: CLTyped + FromBytes,
+{
+    builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name])
+        .map_err(|error| format!("{:?}", error))
+        .and_then(|stored_value| CLValue::try_from(stored_value).map_err(|e| format!("{:?}", e)))
+        .and_then(|cl_value| cl_value.into_t().map_err(|e| format!("{:?}", e)))
+        .expect("should have named value")
+}
+
 #[ignore]
 #[test]
 fn should_use_passed_argument() {
@@ -86,3 +120,18 @@ fn should_revert_with_invalid_argument() {
         u32::from(ApiError::User(GetArgContractError::InvalidArgument1 as u16))
     )));
 }
+
+#[ignore]
+#[test]
+fn should_report_args_len_and_sizes() {
+    assert_eq!(call_get_args_len(()), (0, vec![]));
+
+    assert_eq!(call_get_args_len((U512::from(ARG1_VALUE),)), (1, vec![2]));
+
+    // `()` serializes to no bytes at all, but is still reported as a present argument.  The empty
+    // byte string only holds its length prefix.
+    assert_eq!(
+        call_get_args_len((String::from(ARG0_VALUE), (), Vec::<u8>::new())),
+        (3, vec![ARG0_VALUE.len() as u32 + 4, 0, 4])
+    );
+}
@@ -0,0 +1,57 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{ApiError, U512};
+
+const CONTRACT_GET_BALANCE: &str = "get_balance.wasm";
+const TRANSFER_AMOUNT: u64 = 42;
+
+#[ignore]
+#[test]
+fn should_get_balance_of_funded_purse() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_BALANCE,
+        (U512::from(TRANSFER_AMOUNT), false),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_get_balance_with_add_only_purse() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_BALANCE,
+        (U512::from(TRANSFER_AMOUNT), true),
+    )
+    .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(ApiError::NoAccessRights);
+}
+
+#[ignore]
+#[test]
+fn should_get_account_balance_of_main_purse() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .main_purse();
+
+    let account_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(account_balance, builder.get_purse_balance(main_purse));
+    assert_eq!(account_balance, U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE));
+}
@@ -1,9 +1,16 @@
 use engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_BLOCK_TIME, DEFAULT_GENESIS_CONFIG,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
+use types::ApiError;
 
 const CONTRACT_GET_BLOCKTIME: &str = "get_blocktime.wasm";
+const CONTRACT_BLOCK_TIME_DEADLINE: &str = "block_time_deadline.wasm";
+const COMMAND_SET_DEADLINE: &str = "set_deadline";
+const COMMAND_CHECK_DEADLINE: &str = "check_deadline";
+const DEADLINE_NOT_PASSED: u16 = 0;
 
 #[ignore]
 #[test]
@@ -22,3 +29,58 @@ fn should_run_get_blocktime_contract() {
         .commit()
         .expect_success();
 }
+
+#[ignore]
+#[test]
+fn should_run_with_default_blocktime() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_BLOCKTIME,
+        (DEFAULT_BLOCK_TIME,),
+    )
+    .build();
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_pass_deadline_after_advancing_blocktime() {
+    let deadline = DEFAULT_BLOCK_TIME + 100;
+    let set_deadline_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_BLOCK_TIME_DEADLINE,
+        (String::from(COMMAND_SET_DEADLINE), deadline),
+    )
+    .build();
+    let check_deadline_request = |block_time: u64| {
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_BLOCK_TIME_DEADLINE,
+            (String::from(COMMAND_CHECK_DEADLINE),),
+        )
+        .with_block_time(block_time)
+        .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(set_deadline_request)
+        .expect_success()
+        .commit();
+
+    // At the deadline itself, it has not passed yet.
+    builder
+        .exec(check_deadline_request(deadline))
+        .commit()
+        .expect_error(ApiError::User(DEADLINE_NOT_PASSED));
+
+    builder
+        .exec(check_deadline_request(deadline + 1))
+        .expect_success()
+        .commit();
+}
@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, CLValue, Key};
+
+const CONTRACT_GET_DEPLOY_HASH: &str = "get_deploy_hash.wasm";
+const DEPLOY_HASH_KEY: &str = "deploy_hash";
+const ACCOUNT_PUBLIC_KEY_KEY: &str = "account_public_key";
+const DEPLOY_HASH: [u8; 32] = [42; 32];
+
+#[ignore]
+#[test]
+fn should_get_deploy_hash_and_account_public_key() {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_GET_DEPLOY_HASH, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(DEPLOY_HASH)
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    // The contract also reverts unless a stored contract it calls sees the same values.
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let query = |name: &str| {
+        let stored_value = builder
+            .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name])
+            .expect("should have named key");
+        CLValue::try_from(stored_value).expect("should be CLValue")
+    };
+
+    let deploy_hash: [u8; 32] = query(DEPLOY_HASH_KEY)
+        .into_t()
+        .expect("should be deploy hash");
+    assert_eq!(deploy_hash, DEPLOY_HASH);
+
+    let account_public_key: PublicKey = query(ACCOUNT_PUBLIC_KEY_KEY)
+        .into_t()
+        .expect("should be public key");
+    assert_eq!(account_public_key, DEFAULT_ACCOUNT_ADDR);
+}
@@ -0,0 +1,125 @@
+use contract::args_parser::ArgsParser;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, ApiError, U512};
+
+#[derive(Debug)]
+#[repr(u16)]
+enum GetNamedArgContractError {
+    MissingGreeting = 0,
+    MissingAmount,
+    InvalidGreeting,
+    InvalidAmount,
+    GreetingNotAtPosition0,
+    AmountNotAtPosition1,
+}
+
+const CONTRACT_GET_NAMED_ARG: &str = "get_named_arg.wasm";
+const CONTRACT_GET_ARG: &str = "get_arg.wasm";
+const ARG_GREETING: &str = "greeting";
+const ARG_AMOUNT: &str = "amount";
+const GREETING: &str = "Hello, world!";
+const AMOUNT: u64 = 42;
+
+/// Runs `contract` as session code with `args` set via `DeployItemBuilder::with_session_args`.
+fn run_with_session_args(contract: &str, args: impl ArgsParser) -> InMemoryWasmTestBuilder {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(contract, ())
+        .with_session_args(args)
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+fn assert_reverted_with(builder: &InMemoryWasmTestBuilder, error: GetNamedArgContractError) {
+    assert_eq!(
+        builder.exec_revert_error(0),
+        Some(ApiError::User(error as u16))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_get_named_args_by_name_and_by_position() {
+    let args = runtime_args! {
+        ARG_GREETING => String::from(GREETING),
+        ARG_AMOUNT => U512::from(AMOUNT),
+    };
+    let mut builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, args);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_get_positions_of_named_args_in_insertion_order() {
+    let args = runtime_args! {
+        ARG_AMOUNT => U512::from(AMOUNT),
+        ARG_GREETING => String::from(GREETING),
+    };
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, args);
+    assert_reverted_with(&builder, GetNamedArgContractError::GreetingNotAtPosition0);
+
+    let args = runtime_args! {
+        ARG_GREETING => String::from(GREETING),
+        "padding" => 1u8,
+        ARG_AMOUNT => U512::from(AMOUNT),
+    };
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, args);
+    assert_reverted_with(&builder, GetNamedArgContractError::AmountNotAtPosition1);
+}
+
+#[ignore]
+#[test]
+fn should_get_named_args_positionally() {
+    let args = runtime_args! {
+        ARG_GREETING => String::from(GREETING),
+        ARG_AMOUNT => U512::from(AMOUNT),
+    };
+    let mut builder = run_with_session_args(CONTRACT_GET_ARG, args);
+    builder.expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_get_positional_args_by_name() {
+    let args = (String::from(GREETING), U512::from(AMOUNT));
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, args);
+    assert_reverted_with(&builder, GetNamedArgContractError::MissingGreeting);
+}
+
+#[ignore]
+#[test]
+fn should_distinguish_missing_named_arg_from_invalid_one() {
+    let missing = runtime_args! { ARG_GREETING => String::from(GREETING) };
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, missing);
+    assert_reverted_with(&builder, GetNamedArgContractError::MissingAmount);
+
+    let invalid = runtime_args! {
+        ARG_GREETING => String::from(GREETING),
+        ARG_AMOUNT => String::from("this is expected to be U512"),
+    };
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, invalid);
+    assert_reverted_with(&builder, GetNamedArgContractError::InvalidAmount);
+
+    let invalid = runtime_args! {
+        ARG_GREETING => U512::from(AMOUNT),
+        ARG_AMOUNT => U512::from(AMOUNT),
+    };
+    let builder = run_with_session_args(CONTRACT_GET_NAMED_ARG, invalid);
+    assert_reverted_with(&builder, GetNamedArgContractError::InvalidGreeting);
+}
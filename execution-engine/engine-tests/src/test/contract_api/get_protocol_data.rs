@@ -0,0 +1,42 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::ApiError;
+
+const CONTRACT_GET_PROTOCOL_DATA: &str = "get_protocol_data.wasm";
+
+fn run_get_protocol_data(expected_chain_name: &str) -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_PROTOCOL_DATA,
+        (String::from(expected_chain_name),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_protocol_major_version() {
+    let mut builder = run_get_protocol_data(DEFAULT_CHAIN_NAME);
+
+    // The contract deliberately reverts with the major version as its user error code
+    let major = DEFAULT_PROTOCOL_VERSION.value().major;
+    builder.expect_error(ApiError::User(major as u16));
+}
+
+#[ignore]
+#[test]
+fn should_read_chain_name_from_genesis() {
+    run_get_protocol_data("not-the-chain-name").expect_error(ApiError::User(u16::max_value()));
+}
@@ -0,0 +1,29 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr, ApiError, WasmCosts};
+
+const CONTRACT_GET_WASM_COSTS: &str = "get_wasm_costs.wasm";
+
+#[ignore]
+#[test]
+fn should_get_wasm_costs() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_GET_WASM_COSTS, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(ApiError::User(DEFAULT_WASM_COSTS.regular as u16))
+        .commit();
+
+    let payload = builder
+        .exec_revert_payload(0)
+        .expect("should revert with payload");
+    let wasm_costs: WasmCosts = bytesrepr::deserialize(payload).expect("should deserialize");
+    assert_eq!(wasm_costs, *DEFAULT_WASM_COSTS);
+}
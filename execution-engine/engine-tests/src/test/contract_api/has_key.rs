@@ -0,0 +1,57 @@
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_HAS_KEY: &str = "has_key.wasm";
+const COMMAND_CHECK: &str = "check";
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_HAS_KEY: &str = "has_key";
+const COMMAND_LIST_NAMED_KEYS: &str = "list_named_keys";
+const EXTRA_KEY_COUNT: u32 = 100;
+
+fn exec_cost(builder: &mut InMemoryWasmTestBuilder, command: &str) -> Gas {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_HAS_KEY, (command,)).build();
+    builder.exec(exec_request).expect_success().commit();
+    let index = builder.get_exec_responses_count() - 1;
+    builder.exec_costs(index)[0]
+}
+
+#[ignore]
+#[test]
+fn should_report_key_existence_across_put_and_remove() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_HAS_KEY, (COMMAND_CHECK,))
+            .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_charge_constant_gas_for_has_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let has_key_cost = exec_cost(&mut builder, COMMAND_HAS_KEY);
+    let list_named_keys_cost = exec_cost(&mut builder, COMMAND_LIST_NAMED_KEYS);
+    assert!(has_key_cost < list_named_keys_cost);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_HAS_KEY,
+        (COMMAND_PUT_KEYS, EXTRA_KEY_COUNT),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    // Unlike `list_named_keys`, the cost of `has_key` doesn't grow with the number of named keys.
+    assert_eq!(exec_cost(&mut builder, COMMAND_HAS_KEY), has_key_cost);
+    assert!(exec_cost(&mut builder, COMMAND_LIST_NAMED_KEYS) > list_named_keys_cost);
+}
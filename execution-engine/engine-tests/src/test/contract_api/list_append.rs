@@ -0,0 +1,171 @@
+use std::convert::TryFrom;
+
+use engine_shared::{additive_map::AdditiveMap, transform::Transform, TypeMismatch};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_LIST_APPEND: &str = "list_append.wasm";
+const LIST_NAME: &str = "list";
+const COMMAND_CREATE: &str = "create";
+const COMMAND_APPEND: &str = "append";
+const COMMAND_OVERWRITE: &str = "overwrite";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_APPEND,
+        (String::from(COMMAND_CREATE),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn get_list(builder: &InMemoryWasmTestBuilder) -> Vec<u64> {
+    let stored_value = builder
+        .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[LIST_NAME])
+        .expect("should have list");
+    CLValue::try_from(stored_value)
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be list of u64")
+}
+
+/// Executes the given `(command, value)` pairs as separate deploys in a single execute request,
+/// each against the same pre-state, then commits their combined effects.
+fn exec_deploys_and_commit_merged(
+    builder: &mut InMemoryWasmTestBuilder,
+    commands: &[(&str, u64)],
+) -> bool {
+    let mut exec_request_builder = ExecuteRequestBuilder::new();
+    for (index, (command, value)) in commands.iter().enumerate() {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(CONTRACT_LIST_APPEND, (String::from(*command), *value))
+            .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+            .with_deploy_hash([index as u8 + 1; 32])
+            .build();
+        exec_request_builder = exec_request_builder.push_deploy(deploy);
+    }
+    builder.exec(exec_request_builder.build());
+
+    let exec_response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response")
+        .to_owned();
+    assert_eq!(exec_response.len(), commands.len());
+    assert!(exec_response.iter().all(|result| !result.is_failure()));
+
+    let transforms = builder.get_transforms();
+    let mut merged: AdditiveMap<Key, Transform> = AdditiveMap::new();
+    for deploy_transforms in &transforms[transforms.len() - commands.len()..] {
+        for (key, transform) in deploy_transforms.iter() {
+            merged.insert_add(*key, transform.clone());
+        }
+    }
+
+    let prestate_hash = builder.get_post_state_hash();
+    let commit_response = builder.commit_transforms(prestate_hash.clone(), merged.clone());
+    if !commit_response.has_success() {
+        return false;
+    }
+    builder.commit_effects(prestate_hash, merged);
+    true
+}
+
+#[ignore]
+#[test]
+fn should_append_to_list() {
+    let mut builder = setup();
+    for value in 1u64..=3 {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_LIST_APPEND,
+            (String::from(COMMAND_APPEND), value),
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+    assert_eq!(get_list(&builder), vec![1, 2, 3]);
+}
+
+#[ignore]
+#[test]
+fn should_land_appends_from_two_deploys_in_one_exec_request() {
+    let mut builder = setup();
+    let committed =
+        exec_deploys_and_commit_merged(&mut builder, &[(COMMAND_APPEND, 1), (COMMAND_APPEND, 2)]);
+    assert!(committed);
+    assert_eq!(get_list(&builder), vec![1, 2]);
+}
+
+#[ignore]
+#[test]
+fn should_reject_append_after_plain_write_as_type_mismatch() {
+    let mut builder = setup();
+    let transforms_count = builder.get_transforms().len();
+    let committed = exec_deploys_and_commit_merged(
+        &mut builder,
+        &[(COMMAND_OVERWRITE, 1), (COMMAND_APPEND, 2)],
+    );
+    assert!(!committed);
+
+    let transforms = builder.get_transforms();
+    let list_key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(LIST_NAME)
+        .expect("should have list")
+        .normalize();
+    let write = transforms[transforms_count][&list_key].clone();
+    let append = transforms[transforms_count + 1][&list_key].clone();
+    let type_mismatch = TypeMismatch::new("List".to_string(), "U64".to_string());
+    assert_eq!(write + append, Transform::Failure(type_mismatch.into()));
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_append_to_non_list() {
+    let mut builder = setup();
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_APPEND,
+        (String::from(COMMAND_OVERWRITE), 1u64),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LIST_APPEND,
+        (String::from(COMMAND_APPEND), 2u64),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    let type_mismatch = TypeMismatch::new("List".to_string(), "U64".to_string());
+    assert!(
+        error_message.contains(&format!("{:?}", type_mismatch)),
+        "{}",
+        error_message
+    );
+}
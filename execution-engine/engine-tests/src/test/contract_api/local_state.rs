@@ -1,17 +1,26 @@
 use std::convert::TryInto;
 
+use engine_core::engine_state::op::Op;
 use engine_shared::{stored_value::StoredValue, transform::Transform};
 use engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, QueryError, DEFAULT_GENESIS_CONFIG,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{bytesrepr::ToBytes, CLValue, Key};
+use types::{bytesrepr::ToBytes, ApiError, CLValue, Key};
 
 const CONTRACT_LOCAL_STATE: &str = "local_state.wasm";
+const CONTRACT_LOCAL_STATE_LONG_KEY: &str = "local_state_long_key.wasm";
+const CONTRACT_LOCAL_STATE_STORED: &str = "local_state_stored.wasm";
+const CONTRACT_LOCAL_STATE_STORED_CALLER: &str = "local_state_stored_caller.wasm";
+const LOCAL_STATE_STORED_NAME: &str = "local_state_stored";
+const LOCAL_KEY: [u8; 32] = [66u8; 32];
 
 const CONTRACT_LOCAL_STATE_ADD: &str = "local_state_add.wasm";
 const CMD_WRITE: &str = "write";
 const CMD_ADD: &str = "add";
+const CMD_READ: &str = "read";
 
 #[ignore]
 #[test]
@@ -115,3 +124,148 @@ fn should_add_to_local_state() {
         "local key should have u64 5"
     );
 }
+
+#[ignore]
+#[test]
+fn should_query_local_state_by_seed_and_key_bytes() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let value = builder
+        .query_local(
+            None,
+            DEFAULT_ACCOUNT_ADDR.value(),
+            &[66u8; 32].to_bytes().unwrap(),
+        )
+        .expect("should have local value");
+
+    assert_eq!(
+        value,
+        StoredValue::CLValue(CLValue::from_t(String::from("Hello, world!")).unwrap())
+    );
+}
+
+#[ignore]
+#[test]
+fn should_query_local_state_of_stored_contract() {
+    let store_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE_STORED, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(store_request)
+        .expect_success();
+
+    let stored_uref = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(LOCAL_STATE_STORED_NAME)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have stored uref");
+
+    let call_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_LOCAL_STATE_STORED_CALLER,
+        (stored_uref,),
+    )
+    .build();
+    builder.exec_commit(call_request).expect_success();
+
+    let value = builder
+        .query_local_of(None, Key::URef(stored_uref), &LOCAL_KEY)
+        .expect("should have local value of stored contract");
+    assert_eq!(
+        value,
+        StoredValue::CLValue(CLValue::from_t(String::from("Hello, world!")).unwrap())
+    );
+
+    // The local state was written in the context of the stored contract, not of its caller.
+    let caller = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    let caller_local_key = Key::local(caller.local_seed(), &LOCAL_KEY.to_bytes().unwrap());
+    assert_eq!(
+        builder.query_local_of(None, caller, &LOCAL_KEY),
+        Err(QueryError::BaseKeyNotFound(caller_local_key))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_find_unknown_local_key() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let seed = DEFAULT_ACCOUNT_ADDR.value();
+    let key_bytes = LOCAL_KEY.to_bytes().unwrap();
+    assert_eq!(
+        builder.query_local(None, seed, &key_bytes),
+        Err(QueryError::BaseKeyNotFound(Key::local(seed, &key_bytes)))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_on_too_long_local_key() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE_LONG_KEY, ())
+            .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(ApiError::LocalKeyTooLong);
+}
+
+#[ignore]
+#[test]
+fn should_record_ops_on_local_state() {
+    let exec_request = |command: &str| {
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE_ADD, (command,))
+            .build()
+    };
+
+    let read_and_write_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_LOCAL_STATE, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(exec_request(CMD_WRITE))
+        .expect_success()
+        .exec_commit(exec_request(CMD_READ))
+        .expect_success()
+        .exec_commit(exec_request(CMD_ADD))
+        .expect_success()
+        .exec_commit(read_and_write_request)
+        .expect_success();
+
+    let local_key = Key::local(
+        DEFAULT_ACCOUNT_ADDR.value(),
+        &[66u8; 32].to_bytes().unwrap(),
+    );
+    let op_of = |index: usize| {
+        builder
+            .get_exec_ops(index)
+            .expect("should have ops")
+            .get(&local_key)
+            .cloned()
+    };
+
+    assert_eq!(op_of(0), Some(Op::Write));
+    // A deploy which only queries the local key reads it without transforming it.
+    assert_eq!(op_of(1), Some(Op::Read));
+    assert!(builder.get_transforms()[1].get(&local_key).is_none());
+    assert_eq!(op_of(2), Some(Op::Add));
+    // Reading and then writing the local key within one deploy is recorded as a write.
+    assert_eq!(op_of(3), Some(Op::Write));
+}
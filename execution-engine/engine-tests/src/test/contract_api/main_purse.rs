@@ -1,16 +1,33 @@
+use contract::args_parser::ArgsParser;
+use engine_core::execution::TrapKind;
 use engine_shared::stored_value::StoredValue;
 use engine_test_support::{
     internal::{
-        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+        ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{account::PublicKey, Key};
+use types::{account::PublicKey, AccessRights, ApiError, Key, URef, U512};
 
 const CONTRACT_MAIN_PURSE: &str = "main_purse.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 
+/// Runs the main purse contract with `args`, asserting it reverts with `expected_error`.
+fn assert_main_purse_reverts_with(args: impl ArgsParser, expected_error: ApiError) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_MAIN_PURSE, args).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    assert_eq!(builder.exec_revert_error(0), Some(expected_error));
+}
+
 #[ignore]
 #[test]
 fn should_run_main_purse_contract_default_account() {
@@ -67,3 +84,30 @@ fn should_run_main_purse_contract_account_1() {
 
     builder.exec(exec_request_2).expect_success().commit();
 }
+
+#[ignore]
+#[test]
+fn should_revert_main_purse_contract_without_purse_arg() {
+    assert_main_purse_reverts_with((), ApiError::MissingArgument);
+}
+
+#[ignore]
+#[test]
+fn should_revert_main_purse_contract_with_mistyped_purse_arg() {
+    assert_main_purse_reverts_with((U512::from(1),), ApiError::InvalidArgument);
+}
+
+#[ignore]
+#[test]
+fn should_trap_with_unreachable_when_main_purse_assertion_fails() {
+    let other_purse = URef::new([1; 32], AccessRights::READ_ADD_WRITE);
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_MAIN_PURSE, (other_purse,))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(ExpectedError::Trap(TrapKind::Unreachable));
+}
@@ -1,17 +1,50 @@
 mod account;
+mod add_only_purse;
+mod allowance_table;
+mod burn;
+mod call_contract_buffer;
+mod call_contract_with_payment;
+mod call_depth;
+mod checked_arithmetic;
+mod contract_package;
+mod contract_purse;
 mod create_purse;
+mod crypto;
+mod delete_purse;
+mod emit_event;
+mod forged_purse_transfer;
 mod get_arg;
+mod get_balance;
 mod get_blocktime;
 mod get_caller;
+mod get_deploy_hash;
+mod get_named_arg;
 mod get_phase;
+mod get_protocol_data;
+mod get_wasm_costs;
+mod has_key;
+mod list_append;
 mod list_named_keys;
 mod local_state;
 mod main_purse;
 mod mint_purse;
+mod named_key_limits;
+mod named_keys_iter;
+mod native_transfer;
+mod prune_unreachable_urefs;
+mod put_key_versioned;
+mod random_bytes;
+mod return_value;
 mod revert;
+mod storage_costs;
+mod store_function_at_hash;
+mod sub_purse;
 mod subcall;
 mod transfer;
 mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
+mod transfer_to_account_with_config;
 mod transfer_u512_stored;
+mod update_key;
+mod uref_access_rights;
@@ -0,0 +1,175 @@
+use contract::args_parser::ArgsParser;
+use engine_core::engine_state::EngineConfig;
+use engine_shared::stored_value::StoredValue;
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::ApiError;
+
+const CONTRACT_NAMED_KEY_LIMITS: &str = "named_key_limits.wasm";
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_PUT_KEY_WITH_NAME_LENGTH: &str = "put_key_with_name_length";
+const COMMAND_REMOVE_AND_PUT: &str = "remove_and_put";
+const COMMAND_PUT_KEYS_NESTED: &str = "put_keys_nested";
+const COMMAND_STORE_WITH_NAMED_KEYS: &str = "store_with_named_keys";
+const COMMAND_CREATE_PURSES: &str = "create_purses";
+const FIRST_KEY_NAME: &str = "key_0";
+const REPLACEMENT_KEY_NAME: &str = "replacement_key";
+const MAX_NAMED_KEYS: usize = 20;
+const MAX_NAMED_KEY_NAME_LENGTH: usize = 32;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    setup_with_system_contracts(cfg!(feature = "use-system-contracts"))
+}
+
+fn setup_with_system_contracts(use_system_contracts: bool) -> InMemoryWasmTestBuilder {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(use_system_contracts)
+        .with_max_named_keys(MAX_NAMED_KEYS)
+        .with_max_named_key_name_length(MAX_NAMED_KEY_NAME_LENGTH);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    let mut builder = InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash);
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    builder
+}
+
+fn exec<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    args: impl ArgsParser,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_NAMED_KEY_LIMITS, args)
+            .build();
+    builder.exec(exec_request).commit()
+}
+
+fn named_keys_count(builder: &InMemoryWasmTestBuilder) -> usize {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .len()
+}
+
+/// Returns the number of keys which can be added to the default account before reaching the limit.
+fn free_capacity(builder: &InMemoryWasmTestBuilder) -> u32 {
+    let count = named_keys_count(builder);
+    assert!(count < MAX_NAMED_KEYS);
+    (MAX_NAMED_KEYS - count) as u32
+}
+
+#[ignore]
+#[test]
+fn should_allow_named_keys_up_to_limit() {
+    let mut builder = setup();
+    let free = free_capacity(&builder);
+
+    exec(&mut builder, (COMMAND_PUT_KEYS, free));
+    builder.expect_success();
+    assert_eq!(named_keys_count(&builder), MAX_NAMED_KEYS);
+
+    // Replacing existing keys doesn't count towards the limit
+    exec(&mut builder, (COMMAND_PUT_KEYS, free));
+    builder.expect_success();
+    assert_eq!(named_keys_count(&builder), MAX_NAMED_KEYS);
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_exceed_named_keys_limit() {
+    let mut builder = setup();
+    let initial_count = named_keys_count(&builder);
+    let free = free_capacity(&builder);
+
+    exec(&mut builder, (COMMAND_PUT_KEYS, free + 1)).expect_error(ApiError::TooManyNamedKeys);
+    assert_eq!(named_keys_count(&builder), initial_count);
+}
+
+#[ignore]
+#[test]
+fn should_free_capacity_by_removing_key() {
+    let mut builder = setup();
+    let free = free_capacity(&builder);
+
+    exec(&mut builder, (COMMAND_PUT_KEYS, free));
+    builder.expect_success();
+
+    exec(&mut builder, (COMMAND_REMOVE_AND_PUT,));
+    builder.expect_success();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(account.named_keys().len(), MAX_NAMED_KEYS);
+    assert!(!account.named_keys().contains_key(FIRST_KEY_NAME));
+    assert!(account.named_keys().contains_key(REPLACEMENT_KEY_NAME));
+}
+
+#[ignore]
+#[test]
+fn should_count_keys_put_by_nested_calls() {
+    let mut builder = setup();
+    let keys_per_call = (MAX_NAMED_KEYS / 2) as u32;
+
+    // Each deploy stores a new contract, which starts out with no named keys
+    exec(&mut builder, (COMMAND_PUT_KEYS_NESTED, 2u32, keys_per_call));
+    builder.expect_success();
+
+    exec(&mut builder, (COMMAND_PUT_KEYS_NESTED, 3u32, keys_per_call))
+        .expect_error(ApiError::TooManyNamedKeys);
+}
+
+#[ignore]
+#[test]
+fn should_limit_named_keys_of_stored_contract() {
+    let mut builder = setup();
+    let max_named_keys = MAX_NAMED_KEYS as u32;
+
+    exec(
+        &mut builder,
+        (COMMAND_STORE_WITH_NAMED_KEYS, max_named_keys),
+    )
+    .expect_success();
+
+    exec(
+        &mut builder,
+        (COMMAND_STORE_WITH_NAMED_KEYS, max_named_keys + 1),
+    )
+    .expect_error(ApiError::TooManyNamedKeys);
+}
+
+#[ignore]
+#[test]
+fn should_limit_named_key_name_length() {
+    let mut builder = setup();
+    let max_length = MAX_NAMED_KEY_NAME_LENGTH as u32;
+
+    exec(&mut builder, (COMMAND_PUT_KEY_WITH_NAME_LENGTH, max_length));
+    builder.expect_success();
+
+    exec(
+        &mut builder,
+        (COMMAND_PUT_KEY_WITH_NAME_LENGTH, max_length + 1),
+    )
+    .expect_error(ApiError::NamedKeyNameTooLong);
+}
+
+#[ignore]
+#[test]
+fn should_not_limit_named_keys_of_wasm_mint() {
+    let mut builder = setup_with_system_contracts(true);
+    let purse_count = MAX_NAMED_KEYS as u32;
+
+    exec(&mut builder, (COMMAND_CREATE_PURSES, purse_count)).expect_success();
+
+    let mint = builder
+        .query(None, builder.get_mint_contract_uref().into(), &[])
+        .expect("should query mint");
+    match mint {
+        StoredValue::Contract(contract) => assert!(contract.named_keys().len() > MAX_NAMED_KEYS),
+        other => panic!("mint should be a contract, not {:?}", other),
+    }
+}
@@ -0,0 +1,76 @@
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const CONTRACT_NAMED_KEYS_ITER: &str = "named_keys_iter.wasm";
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const COMMAND_CHECK_CHUNKS: &str = "check_chunks";
+const COMMAND_TAKE_THREE: &str = "take_three";
+const COMMAND_LIST_NAMED_KEYS: &str = "list_named_keys";
+const EXTRA_KEY_COUNT: u32 = 500;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_NAMED_KEYS_ITER,
+        (COMMAND_PUT_KEYS, EXTRA_KEY_COUNT),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn exec_cost(builder: &mut InMemoryWasmTestBuilder, command: &str) -> Gas {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_NAMED_KEYS_ITER, (command,))
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+    let index = builder.get_exec_responses_count() - 1;
+    builder.exec_costs(index)[0]
+}
+
+#[ignore]
+#[test]
+fn should_read_named_keys_in_chunks() {
+    let mut builder = setup();
+    let total_keys = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .len() as u32;
+    assert!(total_keys > EXTRA_KEY_COUNT);
+
+    // Chunk sizes giving a final partial chunk, chunks ending exactly at the last key, a chunk
+    // per key and a single chunk holding every key.
+    let partial = 64;
+    assert_ne!(total_keys % partial, 0);
+    let exact = total_keys / 2;
+    assert_eq!(total_keys % exact, 0);
+    for chunk_size in &[partial, exact, 1, total_keys + 1] {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_NAMED_KEYS_ITER,
+            (COMMAND_CHECK_CHUNKS, *chunk_size),
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+}
+
+#[ignore]
+#[test]
+fn should_charge_only_for_chunks_read() {
+    let mut builder = setup();
+
+    let take_three_cost = exec_cost(&mut builder, COMMAND_TAKE_THREE);
+    let list_named_keys_cost = exec_cost(&mut builder, COMMAND_LIST_NAMED_KEYS);
+    assert!(take_three_cost < list_named_keys_cost);
+}
@@ -0,0 +1,208 @@
+use std::time::Instant;
+
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::{gas::Gas, motes::Motes};
+use engine_test_support::{
+    internal::{utils, InMemoryWasmTestBuilder, TransferRequestBuilder, DEFAULT_WASM_COSTS},
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const TRANSFER_AMOUNT: u64 = 250_000_000 + 1000;
+const TRANSFER_COUNT: usize = 10;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default();
+    builder
+}
+
+/// Natively transfers `amount` from the default account to `ACCOUNT_1_ADDR`.
+fn native_transfer(builder: &mut InMemoryWasmTestBuilder, amount: U512) {
+    let transfer_request =
+        TransferRequestBuilder::new(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, amount).build();
+    builder
+        .exec_transfer(transfer_request)
+        .expect_success()
+        .commit();
+}
+
+fn last_cost_in_motes(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let index = builder.get_exec_responses_count() - 1;
+    let cost = builder.exec_costs(index)[0];
+    Motes::from_gas(cost, CONV_RATE)
+        .expect("should convert gas to motes")
+        .value()
+}
+
+fn main_purse_balance(builder: &InMemoryWasmTestBuilder, public_key: PublicKey) -> U512 {
+    let account = builder.get_account(public_key).expect("should get account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+#[ignore]
+#[test]
+fn should_charge_fixed_native_transfer_cost() {
+    let mut builder = setup();
+    native_transfer(&mut builder, U512::from(TRANSFER_AMOUNT));
+
+    let expected_cost = Gas::new(U512::from(DEFAULT_WASM_COSTS.native_transfer));
+    assert_eq!(builder.exec_costs(0), vec![expected_cost]);
+}
+
+#[ignore]
+#[test]
+fn should_match_state_of_wasm_transfer_to_new_account() {
+    let amount = U512::from(TRANSFER_AMOUNT);
+    let initial_balance = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+
+    let mut wasm_builder = setup();
+    wasm_builder.transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, amount);
+    let wasm_cost = last_cost_in_motes(&wasm_builder);
+
+    let mut native_builder = setup();
+    native_transfer(&mut native_builder, amount);
+    let native_cost = last_cost_in_motes(&native_builder);
+
+    let wasm_target = wasm_builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should create target account via Wasm");
+    let native_target = native_builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should create target account natively");
+    assert_eq!(native_target.public_key(), wasm_target.public_key());
+    assert_eq!(native_target.named_keys(), wasm_target.named_keys());
+    assert_eq!(
+        native_target.get_associated_keys().collect::<Vec<_>>(),
+        wasm_target.get_associated_keys().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        native_target.action_thresholds(),
+        wasm_target.action_thresholds()
+    );
+
+    assert_eq!(main_purse_balance(&wasm_builder, ACCOUNT_1_ADDR), amount);
+    assert_eq!(main_purse_balance(&native_builder, ACCOUNT_1_ADDR), amount);
+
+    assert_eq!(
+        main_purse_balance(&wasm_builder, DEFAULT_ACCOUNT_ADDR),
+        initial_balance - amount - wasm_cost
+    );
+    assert_eq!(
+        main_purse_balance(&native_builder, DEFAULT_ACCOUNT_ADDR),
+        initial_balance - amount - native_cost
+    );
+    assert!(native_cost < wasm_cost);
+
+    assert_eq!(native_builder.total_supply(), wasm_builder.total_supply());
+}
+
+#[ignore]
+#[test]
+fn should_match_state_of_wasm_transfer_to_existing_account() {
+    let amount = U512::from(TRANSFER_AMOUNT);
+
+    let mut wasm_builder = setup();
+    wasm_builder
+        .transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, amount)
+        .transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, amount);
+
+    let mut native_builder = setup();
+    native_transfer(&mut native_builder, amount);
+    native_transfer(&mut native_builder, amount);
+
+    let expected_balance = amount * 2;
+    assert_eq!(
+        main_purse_balance(&wasm_builder, ACCOUNT_1_ADDR),
+        expected_balance
+    );
+    assert_eq!(
+        main_purse_balance(&native_builder, ACCOUNT_1_ADDR),
+        expected_balance
+    );
+}
+
+#[ignore]
+#[test]
+fn should_keep_only_cost_payment_when_native_transfer_fails() {
+    let mut builder = setup();
+    let total_supply = builder.total_supply();
+    let initial_balance = U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE);
+
+    let transfer_request =
+        TransferRequestBuilder::new(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, initial_balance).build();
+    builder.exec_transfer(transfer_request).commit();
+    assert!(builder.is_error());
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response")
+        .to_owned();
+    let error_message = utils::get_error_message(response);
+    assert!(error_message.contains("InsufficientFunds"), error_message);
+
+    let cost = last_cost_in_motes(&builder);
+    assert_eq!(
+        main_purse_balance(&builder, DEFAULT_ACCOUNT_ADDR),
+        initial_balance - cost
+    );
+    assert_eq!(builder.get_account(ACCOUNT_1_ADDR), None);
+    assert_eq!(builder.total_supply(), total_supply);
+}
+
+#[ignore]
+#[test]
+fn should_not_execute_native_transfer_without_authorization() {
+    let mut builder = setup();
+
+    let transfer_request = TransferRequestBuilder::new(
+        DEFAULT_ACCOUNT_ADDR,
+        ACCOUNT_1_ADDR,
+        U512::from(TRANSFER_AMOUNT),
+    )
+    .with_authorization_keys(&[ACCOUNT_2_ADDR])
+    .build();
+    builder.exec_transfer(transfer_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    assert!(response[0].has_precondition_failure());
+    assert_eq!(
+        main_purse_balance(&builder, DEFAULT_ACCOUNT_ADDR),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_transfer_natively_much_faster_than_via_wasm() {
+    let amount = U512::one();
+
+    let mut wasm_builder = setup();
+    let start = Instant::now();
+    for _ in 0..TRANSFER_COUNT {
+        wasm_builder.transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, amount);
+    }
+    let wasm_elapsed = start.elapsed();
+
+    let mut native_builder = setup();
+    let start = Instant::now();
+    for _ in 0..TRANSFER_COUNT {
+        native_transfer(&mut native_builder, amount);
+    }
+    let native_elapsed = start.elapsed();
+
+    assert_eq!(
+        main_purse_balance(&native_builder, ACCOUNT_1_ADDR),
+        main_purse_balance(&wasm_builder, ACCOUNT_1_ADDR)
+    );
+    assert!(
+        native_elapsed * 2 < wasm_elapsed,
+        "native transfers took {:?}, Wasm transfers took {:?}",
+        native_elapsed,
+        wasm_elapsed
+    );
+}
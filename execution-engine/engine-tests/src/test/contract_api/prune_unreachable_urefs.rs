@@ -0,0 +1,69 @@
+use engine_core::engine_state::EngineConfig;
+use engine_shared::{stored_value::StoredValue, transform::Transform};
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLType, Key};
+
+const CONTRACT_CREATE_UNNAMED_UREFS: &str = "create_unnamed_urefs.wasm";
+const NAMED_UREF_NAME: &str = "named_uref";
+
+/// Runs the contract which creates three `u64` `URef`s but only names one, and returns the keys of
+/// the `u64` `URef` writes it committed along with the key of the named one.
+fn run_create_unnamed_urefs(prune_unreachable_urefs: bool) -> (Vec<Key>, Key) {
+    let engine_config = EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_prune_unreachable_urefs(prune_unreachable_urefs);
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let empty_root_hash = global_state.empty_root_hash.to_vec();
+    let mut builder = InMemoryWasmTestBuilder::new(global_state, engine_config, empty_root_hash);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_CREATE_UNNAMED_UREFS, ())
+            .build();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let transforms = builder.get_transforms();
+    let written_u64_urefs = transforms[0]
+        .iter()
+        .filter_map(|(key, transform)| match (key, transform) {
+            (Key::URef(_), Transform::Write(StoredValue::CLValue(cl_value)))
+                if *cl_value.cl_type() == CLType::U64 =>
+            {
+                Some(*key)
+            }
+            _ => None,
+        })
+        .collect();
+
+    let named_uref = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(NAMED_UREF_NAME)
+        .expect("should have named uref")
+        .normalize();
+
+    (written_u64_urefs, named_uref)
+}
+
+#[ignore]
+#[test]
+fn should_commit_all_created_urefs_by_default() {
+    let (written_u64_urefs, named_uref) = run_create_unnamed_urefs(false);
+    assert_eq!(written_u64_urefs.len(), 3);
+    assert!(written_u64_urefs.contains(&named_uref));
+}
+
+#[ignore]
+#[test]
+fn should_only_commit_reachable_created_urefs_when_pruning() {
+    let (written_u64_urefs, named_uref) = run_create_unnamed_urefs(true);
+    assert_eq!(written_u64_urefs, vec![named_uref]);
+}
@@ -0,0 +1,124 @@
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{key_versions_name, versioned_key_name, ApiError, Key};
+
+const CONTRACT_PUT_KEY_VERSIONED: &str = "put_key_versioned.wasm";
+const CONFIG_NAME: &str = "config";
+const COMMAND_PUT: &str = "put";
+const COMMAND_REMOVE: &str = "remove";
+const COMMAND_WRITE_VERSIONS: &str = "write_versions";
+const COMMAND_PUT_VERSIONS: &str = "put_versions";
+const COMMAND_REMOVE_VERSIONS: &str = "remove_versions";
+const COMMAND_PUT_ARCHIVED: &str = "put_archived";
+const CONFIG_VERSIONS: [Key; 3] = [Key::Hash([1; 32]), Key::Hash([2; 32]), Key::Hash([3; 32])];
+
+fn put_config_versions() -> InMemoryWasmTestBuilder {
+    // The contract reverts unless versions 1 to 3 and the latest read back as written.
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PUT_KEY_VERSIONED,
+        (COMMAND_PUT,),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_archive_previous_versions_of_named_key() {
+    let builder = put_config_versions();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let named_keys = account.named_keys();
+    assert_eq!(named_keys.get(CONFIG_NAME), Some(&CONFIG_VERSIONS[2]));
+    for (index, key) in CONFIG_VERSIONS[..2].iter().enumerate() {
+        let archived_name = versioned_key_name(CONFIG_NAME, index as u32 + 1);
+        assert_eq!(named_keys.get(&archived_name), Some(key));
+    }
+    // The latest version isn't archived.
+    let archived_name = versioned_key_name(CONFIG_NAME, 3);
+    assert!(!named_keys.contains_key(&archived_name));
+    assert!(named_keys.contains_key(&key_versions_name(CONFIG_NAME)));
+}
+
+#[ignore]
+#[test]
+fn should_remove_archived_versions_with_named_key() {
+    let mut builder = put_config_versions();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PUT_KEY_VERSIONED,
+        (COMMAND_REMOVE,),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let named_keys = account.named_keys();
+    assert!(!named_keys.contains_key(CONFIG_NAME));
+    for version in 1..=2 {
+        let archived_name = versioned_key_name(CONFIG_NAME, version);
+        assert!(!named_keys.contains_key(&archived_name));
+    }
+    assert!(!named_keys.contains_key(&key_versions_name(CONFIG_NAME)));
+}
+
+#[ignore]
+#[test]
+fn should_not_tamper_with_archived_versions() {
+    let mut builder = put_config_versions();
+    let named_keys_before = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .clone();
+
+    let exec_tamper = |builder: &mut InMemoryWasmTestBuilder, command: &str| {
+        let exec_request = ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_PUT_KEY_VERSIONED,
+            (command,),
+        )
+        .build();
+        builder.exec(exec_request).commit();
+    };
+
+    // The version count is only readable by contract code.
+    exec_tamper(&mut builder, COMMAND_WRITE_VERSIONS);
+    builder.expect_error(ExpectedError::InvalidAccess);
+
+    // Neither it nor the archived versions can be replaced or removed by name.
+    for command in &[
+        COMMAND_PUT_VERSIONS,
+        COMMAND_REMOVE_VERSIONS,
+        COMMAND_PUT_ARCHIVED,
+    ] {
+        exec_tamper(&mut builder, command);
+        builder.expect_error(ApiError::PermissionDenied);
+    }
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(account.named_keys(), &named_keys_before);
+
+    // Removing the key still removes each of its versions.
+    exec_tamper(&mut builder, COMMAND_REMOVE);
+    builder.expect_success();
+}
@@ -0,0 +1,75 @@
+use std::{collections::BTreeSet, convert::TryFrom};
+
+use engine_core::engine_state::execute_request::ExecuteRequest;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{CLValue, Key};
+
+const CONTRACT_RANDOM_BYTES: &str = "random_bytes.wasm";
+const RANDOM_1_KEY: &str = "random_1";
+const RANDOM_2_KEY: &str = "random_2";
+const NESTED_1_KEY: &str = "nested_1";
+const NESTED_2_KEY: &str = "nested_2";
+const DEPLOY_HASH_1: [u8; 32] = [1; 32];
+const DEPLOY_HASH_2: [u8; 32] = [2; 32];
+
+fn exec_request(deploy_hash: [u8; 32]) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(CONTRACT_RANDOM_BYTES, ())
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+/// Runs the contract in a deploy with `deploy_hash` right after genesis, and returns the random
+/// bytes it stored from two calls in the session code followed by those from two nested calls.
+fn run_random_bytes(deploy_hash: [u8; 32]) -> Vec<[u8; 32]> {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request(deploy_hash))
+        .expect_success()
+        .commit();
+
+    [RANDOM_1_KEY, RANDOM_2_KEY, NESTED_1_KEY, NESTED_2_KEY]
+        .iter()
+        .map(|name| {
+            let stored_value = builder
+                .query(None, Key::Account(DEFAULT_ACCOUNT_ADDR), &[name])
+                .expect("should have named key");
+            CLValue::try_from(stored_value)
+                .expect("should be CLValue")
+                .into_t()
+                .expect("should be random bytes")
+        })
+        .collect()
+}
+
+#[ignore]
+#[test]
+fn should_return_different_random_bytes_on_each_call_and_in_nested_calls() {
+    let random_bytes = run_random_bytes(DEPLOY_HASH_1);
+    let distinct: BTreeSet<[u8; 32]> = random_bytes.iter().cloned().collect();
+    assert_eq!(distinct.len(), random_bytes.len());
+}
+
+#[ignore]
+#[test]
+fn should_return_same_random_bytes_when_deploy_is_replayed() {
+    assert_eq!(
+        run_random_bytes(DEPLOY_HASH_1),
+        run_random_bytes(DEPLOY_HASH_1)
+    );
+    assert_ne!(
+        run_random_bytes(DEPLOY_HASH_1),
+        run_random_bytes(DEPLOY_HASH_2)
+    );
+}
@@ -0,0 +1,58 @@
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::U512;
+
+const CONTRACT_RETURN_BALANCE_SUM: &str = "return_balance_sum.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const FIRST_AMOUNT: u64 = 100;
+const SECOND_AMOUNT: u64 = 23;
+
+#[ignore]
+#[test]
+fn should_return_value_from_session() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_RETURN_BALANCE_SUM,
+        (U512::from(FIRST_AMOUNT), U512::from(SECOND_AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let balance_sum = U512::from(FIRST_AMOUNT + SECOND_AMOUNT);
+    assert_eq!(builder.last_return_value_as::<U512>(), Some(balance_sum));
+
+    // Returning from the session doesn't skip charging for the deploy and refunding the rest of
+    // the payment.
+    let gas_cost =
+        Motes::from_gas(builder.exec_costs(0)[0], CONV_RATE).expect("should convert gas to motes");
+    assert_eq!(
+        builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - gas_cost.value() - balance_sum
+    );
+}
+
+#[ignore]
+#[test]
+fn should_have_no_return_value_if_session_does_not_return() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    assert_eq!(builder.last_return_value(), None);
+}
@@ -2,8 +2,24 @@ use engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
     DEFAULT_ACCOUNT_ADDR,
 };
+use types::{ApiError, MAX_REVERT_PAYLOAD_LENGTH};
 
 const REVERT_WASM: &str = "revert.wasm";
+const REVERT_WITH_PAYLOAD_WASM: &str = "revert_with_payload.wasm";
+const REVERT_WITH_PAYLOAD_ERROR: ApiError = ApiError::User(0);
+
+fn revert_with_payload(payload: Vec<u8>) -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, REVERT_WITH_PAYLOAD_WASM, (payload,))
+            .build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(REVERT_WITH_PAYLOAD_ERROR)
+        .commit();
+    builder
+}
 
 #[ignore]
 #[test]
@@ -16,3 +32,50 @@ fn should_revert() {
         .commit()
         .is_error();
 }
+
+#[ignore]
+#[test]
+fn should_revert_without_payload() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, REVERT_WASM, ()).build();
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    assert_eq!(builder.exec_revert_payload(0), None);
+}
+
+#[ignore]
+#[test]
+fn should_revert_with_payload() {
+    let payload = b"amount".to_vec();
+    let builder = revert_with_payload(payload.clone());
+
+    assert_eq!(builder.exec_revert_payload(0), Some(payload));
+}
+
+#[ignore]
+#[test]
+fn should_truncate_long_revert_payload() {
+    let payload: Vec<u8> = (0..=u8::max_value()).collect();
+    let builder = revert_with_payload(payload.clone());
+
+    assert_eq!(
+        builder.exec_revert_payload(0),
+        Some(payload[..MAX_REVERT_PAYLOAD_LENGTH].to_vec())
+    );
+}
+
+#[ignore]
+#[test]
+fn should_revert_without_payload_if_payload_empty() {
+    let builder = revert_with_payload(Vec::new());
+
+    assert_eq!(builder.exec_revert_payload(0), None);
+    assert_eq!(
+        builder.exec_revert_error(0),
+        Some(REVERT_WITH_PAYLOAD_ERROR)
+    );
+}
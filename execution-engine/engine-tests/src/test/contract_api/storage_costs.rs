@@ -0,0 +1,65 @@
+use engine_core::engine_state::genesis::GenesisConfig;
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{bytesrepr::ToBytes, CLValue, U512};
+
+const CONTRACT_WRITE_BYTES: &str = "write_bytes.wasm";
+const SMALL_LENGTH: u32 = 10;
+const LARGE_LENGTH: u32 = 10_000;
+const STORAGE_PER_BYTE: u32 = 1_000;
+
+fn write_bytes_cost(genesis_config: &GenesisConfig, length: u32) -> Gas {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_WRITE_BYTES, (length,))
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    builder.exec_costs(0)[0]
+}
+
+#[ignore]
+#[test]
+fn should_charge_more_for_writing_larger_values() {
+    let genesis_config =
+        utils::genesis_config_with(|wasm_costs| wasm_costs.storage_per_byte = STORAGE_PER_BYTE);
+    let small_write_cost = write_bytes_cost(&genesis_config, SMALL_LENGTH);
+    let large_write_cost = write_bytes_cost(&genesis_config, LARGE_LENGTH);
+
+    assert!(
+        large_write_cost > small_write_cost,
+        "writing a larger value should cost more"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_per_byte_of_written_values() {
+    let free_storage_cost = write_bytes_cost(
+        &utils::genesis_config_with(|wasm_costs| wasm_costs.storage_per_byte = 0),
+        LARGE_LENGTH,
+    );
+    let storage_cost = write_bytes_cost(
+        &utils::genesis_config_with(|wasm_costs| wasm_costs.storage_per_byte = STORAGE_PER_BYTE),
+        LARGE_LENGTH,
+    );
+
+    // The contract writes `()` to a new uref and then overwrites it with the bytes
+    let written_bytes = CLValue::from_t(()).unwrap().serialized_length()
+        + CLValue::from_t(vec![0u8; LARGE_LENGTH as usize])
+            .unwrap()
+            .serialized_length();
+    let expected_storage_cost = Gas::new(U512::from(STORAGE_PER_BYTE) * U512::from(written_bytes));
+
+    // Payment code may also write to global state, so the difference can exceed the cost of the
+    // session's writes
+    assert!(storage_cost >= free_storage_cost + expected_storage_cost);
+}
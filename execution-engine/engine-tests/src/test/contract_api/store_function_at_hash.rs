@@ -0,0 +1,74 @@
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::Key;
+
+const CONTRACT_STORE_AT_HASH: &str = "store_at_hash.wasm";
+const CONTRACT_STORE_AT_HASH_CALLER: &str = "store_at_hash_caller.wasm";
+const CONTRACT_NAME: &str = "store_at_hash";
+const GREETING_KEY: &str = "greeting";
+const MARKER_KEY: &str = "marker";
+const MARKER: Key = Key::Hash([7; 32]);
+const NEW_GREETING: &str = "goodbye";
+
+#[ignore]
+#[test]
+fn should_call_contract_stored_at_hash_by_hash_bytes() {
+    let exec_request_1 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_STORE_AT_HASH, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit();
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let contract_key = *account
+        .named_keys()
+        .get(CONTRACT_NAME)
+        .expect("should have stored contract key");
+    let contract_hash = *contract_key.as_hash().expect("should be stored at a hash");
+
+    let contract = match builder.query(None, contract_key, &[]) {
+        Ok(StoredValue::Contract(contract)) => contract,
+        other => panic!("should have contract, got {:?}", other),
+    };
+    assert_eq!(contract.named_keys().len(), 2);
+    assert_eq!(contract.named_keys().get(MARKER_KEY), Some(&MARKER));
+    let greeting_key = *contract
+        .named_keys()
+        .get(GREETING_KEY)
+        .expect("should have greeting key");
+
+    // The stored contract reverts unless it sees the named keys it was stored with.
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_STORE_AT_HASH_CALLER,
+        (contract_hash, NEW_GREETING.to_string()),
+    )
+    .build();
+    builder.exec(exec_request_2).expect_success().commit();
+
+    let contract = match builder.query(None, Key::Hash(contract_hash), &[]) {
+        Ok(StoredValue::Contract(contract)) => contract,
+        other => panic!("should have contract, got {:?}", other),
+    };
+    assert_eq!(contract.named_keys().get(GREETING_KEY), Some(&greeting_key));
+
+    let stored_value = builder
+        .query(None, Key::Hash(contract_hash), &[GREETING_KEY])
+        .expect("should have greeting");
+    let greeting: String = stored_value
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be String");
+    assert_eq!(greeting, NEW_GREETING);
+}
@@ -0,0 +1,155 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{system_contract_errors::mint, ApiError, URef, U512};
+
+const CONTRACT_SUB_PURSE: &str = "sub_purse.wasm";
+const PARENT_PURSE: &str = "purse:parent";
+const SUB_PURSE: &str = "purse:sub";
+const RECIPIENT_PURSE: &str = "purse:recipient";
+const METHOD_CREATE: &str = "create";
+const METHOD_FUND: &str = "fund";
+const METHOD_SPEND: &str = "spend";
+const METHOD_REVOKE: &str = "revoke";
+const METHOD_NEST: &str = "nest";
+const METHOD_DELETE: &str = "delete";
+const PARENT_BALANCE: u64 = 1_000;
+const CAP: u64 = 300;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SUB_PURSE,
+        (
+            String::from(METHOD_CREATE),
+            U512::from(PARENT_BALANCE),
+            U512::from(CAP),
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+fn call(builder: &mut InMemoryWasmTestBuilder, method: &str, amount: u64) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_SUB_PURSE,
+        (String::from(method), U512::from(amount)),
+    )
+    .build();
+    builder.exec(exec_request);
+}
+
+fn get_purse(builder: &InMemoryWasmTestBuilder, name: &str) -> URef {
+    builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()[name]
+        .into_uref()
+        .expect("should be uref")
+}
+
+/// Asserts the balances of the parent purse, the sub-purse and the recipient purse, in that order.
+fn assert_balances(builder: &InMemoryWasmTestBuilder, expected_balances: [u64; 3]) {
+    let names = [PARENT_PURSE, SUB_PURSE, RECIPIENT_PURSE];
+    for (name, expected_balance) in names.iter().zip(expected_balances.iter()) {
+        let balance = builder.get_purse_balance(get_purse(builder, name));
+        let expected_balance = U512::from(*expected_balance);
+        assert_eq!(balance, expected_balance, "balance of {}", name);
+    }
+}
+
+#[ignore]
+#[test]
+fn should_pull_from_parent_until_cap_is_exhausted() {
+    let mut builder = setup();
+    assert_balances(&builder, [PARENT_BALANCE, 0, 0]);
+
+    call(&mut builder, METHOD_SPEND, 200);
+    builder.expect_success().commit();
+    assert_balances(&builder, [800, 0, 200]);
+
+    // Only 100 more can be pulled from the parent, and the sub-purse holds nothing of its own
+    call(&mut builder, METHOD_SPEND, 200);
+    builder.expect_error(ApiError::Transfer).commit();
+    assert_balances(&builder, [800, 0, 200]);
+
+    // The rest of the cap is pulled first, and the remainder taken from the sub-purse itself
+    call(&mut builder, METHOD_FUND, 50);
+    builder.expect_success().commit();
+    call(&mut builder, METHOD_SPEND, 140);
+    builder.expect_success().commit();
+    assert_balances(&builder, [700, 10, 340]);
+
+    call(&mut builder, METHOD_SPEND, 11);
+    builder.expect_error(ApiError::Transfer).commit();
+    assert_balances(&builder, [700, 10, 340]);
+}
+
+#[ignore]
+#[test]
+fn should_stop_pulling_from_parent_once_revoked() {
+    let mut builder = setup();
+
+    call(&mut builder, METHOD_SPEND, 200);
+    builder.expect_success().commit();
+    call(&mut builder, METHOD_REVOKE, 0);
+    builder.expect_success().commit();
+
+    // The motes already moved stay put, but the remaining cap can no longer be drawn on
+    assert_balances(&builder, [800, 0, 200]);
+    call(&mut builder, METHOD_SPEND, 50);
+    builder.expect_error(ApiError::Transfer).commit();
+    assert_balances(&builder, [800, 0, 200]);
+
+    // A revoked sub-purse can still spend its own balance
+    call(&mut builder, METHOD_FUND, 50);
+    builder.expect_success().commit();
+    call(&mut builder, METHOD_SPEND, 50);
+    builder.expect_success().commit();
+    assert_balances(&builder, [800, 0, 250]);
+
+    call(&mut builder, METHOD_REVOKE, 0);
+    builder
+        .expect_error(ApiError::from(mint::Error::SubPurseNotFound))
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_create_nested_sub_purse() {
+    let mut builder = setup();
+
+    call(&mut builder, METHOD_NEST, CAP);
+    builder
+        .expect_error(ApiError::from(mint::Error::NestedSubPurse))
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_not_pull_from_parent_when_deleting_sub_purse() {
+    let mut builder = setup();
+
+    call(&mut builder, METHOD_FUND, 50);
+    builder.expect_success().commit();
+    let sub_purse = get_purse(&builder, SUB_PURSE);
+    assert_eq!(builder.get_purse_balance(sub_purse), U512::from(50));
+
+    call(&mut builder, METHOD_DELETE, 0);
+    builder.expect_success().commit();
+
+    let parent_purse = get_purse(&builder, PARENT_PURSE);
+    assert_eq!(
+        builder.get_purse_balance(parent_purse),
+        U512::from(PARENT_BALANCE)
+    );
+}
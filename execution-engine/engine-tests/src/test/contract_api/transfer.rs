@@ -4,12 +4,12 @@ use engine_core::engine_state::CONV_RATE;
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, KeyMatcher, TransformMatcher,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
-use types::{account::PublicKey, U512};
+use types::{account::PublicKey, Key, U512};
 
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
@@ -82,6 +82,49 @@ fn should_transfer_to_account() {
     assert_eq!(account_1_balance, transfer_amount,);
 }
 
+#[ignore]
+#[test]
+fn should_write_new_account_and_add_to_existing_purse() {
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, *TRANSFER_1_AMOUNT),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .expect_transform(
+            Key::Account(ACCOUNT_1_ADDR),
+            TransformMatcher::Kind("Write(Account)"),
+        )
+        .commit();
+    let counts = builder.transform_count_by_kind();
+    assert_eq!(counts.get("Write(Account)"), Some(&1));
+
+    // Account 1 now exists, so the second transfer only adds to the balance of its main purse
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, *TRANSFER_2_AMOUNT),
+    )
+    .build();
+
+    builder
+        .exec(exec_request_2)
+        .expect_success()
+        .expect_transform(
+            KeyMatcher::AnyURef,
+            TransformMatcher::AddUInt512(*TRANSFER_2_AMOUNT),
+        )
+        .commit();
+    let counts = builder.transform_count_by_kind();
+    assert_eq!(counts.get("Write(Account)"), None);
+}
+
 #[ignore]
 #[test]
 fn should_transfer_from_account_to_account() {
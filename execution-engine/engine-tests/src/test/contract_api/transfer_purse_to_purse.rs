@@ -4,13 +4,17 @@ use types::{ApiError, CLValue, Key, U512};
 
 use engine_test_support::{
     internal::{
-        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
 
 const CONTRACT_TRANSFER_PURSE_TO_PURSE: &str = "transfer_purse_to_purse.wasm";
+const CONTRACT_TRANSFER_PURSE_TO_PURSE_WITH_ID: &str = "transfer_purse_to_purse_with_id.wasm";
 const PURSE_TO_PURSE_AMOUNT: u64 = 42;
+const TRANSFER_ID: u64 = 7;
+const TRANSFER_COUNT: u32 = 2;
 
 #[ignore]
 #[test]
@@ -65,33 +69,10 @@ fn should_run_purse_to_purse_transfer() {
     .expect("should be U512");
 
     // Assert secondary purse value after successful transfer
-    let purse_secondary_key = default_account.named_keys()["purse:secondary"];
-    let _purse_main_key = default_account.named_keys()["purse:main"];
-
-    // Lookup key used to find the actual purse uref
-    // TODO: This should be more consistent
-    let purse_secondary_lookup_key = purse_secondary_key
-        .as_uref()
-        .unwrap()
-        .remove_access_rights()
-        .as_string();
-
-    let mint_contract_uref = builder.get_mint_contract_uref().remove_access_rights();
-    let mint_contract = builder
-        .get_contract(mint_contract_uref)
-        .expect("should have mint contract");
-
-    // Find `purse:secondary`.
-    let purse_secondary_uref = mint_contract.named_keys()[&purse_secondary_lookup_key];
-    let purse_secondary_key: Key = purse_secondary_uref.normalize();
-    let purse_secondary_balance = CLValue::try_from(
-        builder
-            .query(None, purse_secondary_key, &[])
-            .expect("should have main purse balance"),
-    )
-    .expect("should be a CLValue")
-    .into_t::<U512>()
-    .expect("should be U512");
+    let purse_secondary = default_account.named_keys()["purse:secondary"]
+        .into_uref()
+        .expect("should be a uref");
+    let purse_secondary_balance = builder.get_purse_balance(purse_secondary);
 
     // Final balance of the destination purse
     assert_eq!(purse_secondary_balance, U512::from(PURSE_TO_PURSE_AMOUNT));
@@ -191,3 +172,82 @@ fn should_run_purse_to_purse_transfer_with_error() {
         U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE) - *DEFAULT_PAYMENT
     );
 }
+
+#[ignore]
+#[test]
+fn should_record_each_transfer_with_the_same_id() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_PURSE_WITH_ID,
+        (
+            U512::from(PURSE_TO_PURSE_AMOUNT),
+            Some(TRANSFER_ID),
+            TRANSFER_COUNT,
+        ),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response")
+        .clone();
+    let result = utils::get_success_result(&response);
+
+    // Both transfers share an id, but each one should have its own record
+    let transfer_ids = utils::get_transfer_ids(result, builder.get_mint_contract_uref());
+    assert_eq!(transfer_ids, vec![TRANSFER_ID; TRANSFER_COUNT as usize]);
+
+    let default_account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account");
+    let target_purse = default_account.named_keys()["purse:target"]
+        .into_uref()
+        .expect("should be uref");
+    assert_eq!(
+        builder.get_purse_balance(target_purse),
+        U512::from(PURSE_TO_PURSE_AMOUNT * u64::from(TRANSFER_COUNT))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_only_report_transfer_ids_of_the_given_execution() {
+    let exec_request = || {
+        ExecuteRequestBuilder::standard(
+            DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_PURSE_TO_PURSE_WITH_ID,
+            (
+                U512::from(PURSE_TO_PURSE_AMOUNT),
+                Some(TRANSFER_ID),
+                TRANSFER_COUNT,
+            ),
+        )
+        .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request())
+        .expect_success()
+        .commit()
+        .exec(exec_request())
+        .expect_success()
+        .commit();
+
+    // The second deploy transfers into the same purse under the same id as the first
+    let response = builder
+        .get_exec_response(1)
+        .expect("there should be a response")
+        .clone();
+    let result = utils::get_success_result(&response);
+    let transfer_ids = utils::get_transfer_ids(result, builder.get_mint_contract_uref());
+    assert_eq!(transfer_ids, vec![TRANSFER_ID; TRANSFER_COUNT as usize]);
+}
@@ -0,0 +1,101 @@
+use lazy_static::lazy_static;
+
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::{PublicKey, Weight},
+    ApiError, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT_WITH_CONFIG: &str = "transfer_to_account_with_config.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+
+lazy_static! {
+    static ref TRANSFER_AMOUNT: U512 = *DEFAULT_PAYMENT + *DEFAULT_PAYMENT;
+}
+
+fn transfer_request(
+    initial_weight: u8,
+    deployment_threshold: u8,
+    key_management_threshold: u8,
+) -> ExecuteRequestBuilder {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT_WITH_CONFIG,
+        (
+            ACCOUNT_1_ADDR,
+            *TRANSFER_AMOUNT,
+            initial_weight,
+            deployment_threshold,
+            key_management_threshold,
+        ),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_create_account_with_config() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(transfer_request(3, 2, 2).build())
+        .expect_success();
+
+    let weights = builder
+        .get_account_weights(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    assert_eq!(weights.len(), 1);
+    assert_eq!(weights.get(&ACCOUNT_1_ADDR), Some(&Weight::new(3)));
+
+    let action_thresholds = builder
+        .get_action_thresholds(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    assert_eq!(action_thresholds.deployment(), &Weight::new(2));
+    assert_eq!(action_thresholds.key_management(), &Weight::new(2));
+
+    assert_eq!(
+        builder.get_account_balance(ACCOUNT_1_ADDR),
+        *TRANSFER_AMOUNT
+    );
+
+    // The weight of the only associated key meets the deployment threshold.
+    let exec_request =
+        ExecuteRequestBuilder::standard(ACCOUNT_1_ADDR, CONTRACT_DO_NOTHING, ()).build();
+    builder.exec_commit(exec_request).expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_transfer_with_invalid_thresholds() {
+    let invalid_configs = [
+        (1, 2, 2, ApiError::InsufficientTotalWeight),
+        (3, 3, 2, ApiError::DeploymentThreshold),
+    ];
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    for (index, (weight, deployment, key_management, error)) in invalid_configs.iter().enumerate() {
+        let balance_before = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+
+        builder
+            .exec(transfer_request(*weight, *deployment, *key_management).build())
+            .expect_error(*error)
+            .commit();
+
+        assert!(builder.get_account(ACCOUNT_1_ADDR).is_none());
+        let gas_cost = Motes::from_gas(builder.exec_costs(index)[0], CONV_RATE)
+            .expect("should convert gas to motes");
+        assert_eq!(
+            builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+            balance_before - gas_cost.value()
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use engine_shared::stored_value::StoredValue;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::Key;
+
+const CONTRACT_UPDATE_KEY_STORED: &str = "update_key_stored.wasm";
+const CONTRACT_UPDATE_KEY_STORED_CALLER: &str = "update_key_stored_caller.wasm";
+const CONTRACT_NAME: &str = "update_key_stored";
+const ENTRY_NAME: &str = "entry";
+const NESTED_ENTRY: Key = Key::Hash([2; 32]);
+
+#[ignore]
+#[test]
+fn should_fail_outer_update_after_conflicting_nested_update() {
+    let exec_request_1 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_UPDATE_KEY_STORED, ())
+            .build();
+    // The stored contract reverts unless its outer `update_key` call observes the mismatch
+    // caused by the nested call.
+    let exec_request_2 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_UPDATE_KEY_STORED_CALLER,
+        (),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    let contract = match builder.query(None, account_key, &[CONTRACT_NAME]) {
+        Ok(StoredValue::Contract(contract)) => contract,
+        other => panic!("should have contract, got {:?}", other),
+    };
+    // The nested update wasn't overwritten by the outer one.
+    assert_eq!(contract.named_keys().get(ENTRY_NAME), Some(&NESTED_ENTRY));
+}
@@ -0,0 +1,85 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{AccessRights, Key};
+
+const CONTRACT_DOWNGRADE_UREF_STORED: &str = "downgrade_uref_stored.wasm";
+const CONTRACT_DOWNGRADE_UREF_STORED_CALLER: &str = "downgrade_uref_stored_caller.wasm";
+const READ_ONLY_UREF_NAME: &str = "read_only_uref";
+const COMMAND_READ: &str = "read";
+const COMMAND_WRITE: &str = "write";
+const COMMAND_WRITE_ESCALATED: &str = "write_escalated";
+const COMMAND_ESCALATE: &str = "escalate";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DOWNGRADE_UREF_STORED, ())
+            .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis_default().exec_commit(exec_request);
+    builder
+}
+
+fn call_stored(builder: &mut InMemoryWasmTestBuilder, command: &str) -> Option<String> {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DOWNGRADE_UREF_STORED_CALLER,
+        (String::from(command),),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let response_index = builder.get_exec_responses_count() - 1;
+    let response = builder
+        .get_exec_response(response_index)
+        .expect("should have a response");
+    if response.iter().any(|result| result.is_failure()) {
+        builder.exec_error_message(response_index)
+    } else {
+        None
+    }
+}
+
+fn assert_call_fails_with(command: &str, expected_error: &str) {
+    let mut builder = setup();
+
+    let error_message = call_stored(&mut builder, command).expect("should fail");
+    assert!(error_message.contains(expected_error), "{}", error_message);
+}
+
+#[ignore]
+#[test]
+fn should_return_read_only_uref_from_stored_contract() {
+    let mut builder = setup();
+
+    assert_eq!(call_stored(&mut builder, COMMAND_READ), None);
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    let uref = match account.named_keys().get(READ_ONLY_UREF_NAME) {
+        Some(Key::URef(uref)) => *uref,
+        other => panic!("expected read-only uref, got {:?}", other),
+    };
+    assert_eq!(uref.access_rights(), AccessRights::READ);
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_write_to_returned_read_only_uref() {
+    assert_call_fails_with(COMMAND_WRITE, "InvalidAccess");
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_escalate_returned_read_only_uref_in_caller() {
+    assert_call_fails_with(COMMAND_WRITE_ESCALATED, "ForgedReference");
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_return_escalated_uref_from_callee() {
+    assert_call_fails_with(COMMAND_ESCALATE, "ForgedReference");
+}
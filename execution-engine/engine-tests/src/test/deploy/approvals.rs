@@ -0,0 +1,117 @@
+use engine_core::engine_state::{
+    self,
+    execute_request::ExecuteRequest,
+    genesis::GenesisAccount,
+    signature::{SignatureAlgorithm, SignatureError},
+};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ENGINE_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use types::{account::PublicKey, U512};
+
+const DEPLOY_HASH: [u8; 32] = [7; 32];
+const ED25519: &str = "ed25519";
+/// The Ed25519 public key of the secret key `[42; 32]`.
+const PUBLIC_KEY: &str = "197f6b23e16c8532c6abc838facd5ea789be0c76b2920334039bfa8b3d368d61";
+/// The signature of `DEPLOY_HASH` by the secret key `[42; 32]`.
+const SIGNATURE: &str = "c1bebfce49acc5510c438fe76480abcd037bacd0a6e2b69b911c4937abcf436d\
+                         676ba5e6ac0091cc0f2b3f4d502edef6e01343f495d74ce020657c1d94209d09";
+
+fn signer() -> PublicKey {
+    let public_key = base16::decode(PUBLIC_KEY).expect("should decode public key");
+    PublicKey::ed25519_try_from(&public_key).expect("should be an account key")
+}
+
+/// Returns a builder verifying approvals, after a genesis which also creates the signer's account.
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut accounts = DEFAULT_ACCOUNTS.clone();
+    accounts.push(GenesisAccount::new(
+        signer(),
+        Motes::new(U512::from(DEFAULT_ACCOUNT_INITIAL_BALANCE)),
+        Motes::zero(),
+    ));
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_verify_approvals(true))
+        .run_genesis(&utils::create_genesis_config(accounts));
+    builder
+}
+
+/// Returns a request for a deploy by and authorized as `account`, approved by `signature` if any.
+fn approved_request(account: PublicKey, signature: Option<&[u8]>) -> ExecuteRequest {
+    let public_key = base16::decode(PUBLIC_KEY).expect("should decode public key");
+    let mut deploy = DeployItemBuilder::new()
+        .with_address(account)
+        .with_deploy_hash(DEPLOY_HASH)
+        .with_session_code("do_nothing.wasm", ())
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[account]);
+    if let Some(signature) = signature {
+        deploy = deploy.with_approval(&public_key, signature, ED25519);
+    }
+    ExecuteRequestBuilder::new()
+        .push_deploy(deploy.build())
+        .build()
+}
+
+fn assert_signature_error(builder: &InMemoryWasmTestBuilder, expected: SignatureError) {
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let result = response.get(0).expect("should have a result");
+    assert!(result.has_precondition_failure());
+    match result.error() {
+        Some(engine_state::Error::SignatureError(error)) if *error == expected => (),
+        error => panic!("expected {:?}, got {:?}", expected, error),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_exec_deploy_with_valid_approval() {
+    let signature = base16::decode(SIGNATURE).expect("should decode signature");
+    setup()
+        .exec(approved_request(signer(), Some(&signature)))
+        .expect_success()
+        .commit();
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_invalid_approval() {
+    let mut signature = base16::decode(SIGNATURE).expect("should decode signature");
+    signature[0] ^= 1;
+
+    let mut builder = setup();
+    builder.exec(approved_request(signer(), Some(&signature)));
+
+    assert_signature_error(
+        &builder,
+        SignatureError::VerificationFailed(SignatureAlgorithm::Ed25519),
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_approval_by_other_key() {
+    let signature = base16::decode(SIGNATURE).expect("should decode signature");
+
+    let mut builder = setup();
+    builder.exec(approved_request(DEFAULT_ACCOUNT_ADDR, Some(&signature)));
+
+    assert_signature_error(&builder, SignatureError::UnauthorizedSigner(signer()));
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_missing_approval() {
+    let mut builder = setup();
+    builder.exec(approved_request(signer(), None));
+
+    assert_signature_error(&builder, SignatureError::MissingApproval(signer()));
+}
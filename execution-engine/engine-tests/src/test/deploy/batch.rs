@@ -0,0 +1,101 @@
+use engine_core::engine_state::{batch::DeployOutcome, deploy_item::DeployItem};
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+fn transfer_deploy(source: PublicKey, target: PublicKey, amount: U512, hash: u8) -> DeployItem {
+    DeployItemBuilder::new()
+        .with_address(source)
+        .with_session_code(CONTRACT_TRANSFER_TO_ACCOUNT, (target, amount))
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[source])
+        .with_deploy_hash([hash; 32])
+        .build()
+}
+
+/// Runs genesis and funds `ACCOUNT_1_ADDR`, so that it can send deploys of its own.
+fn setup() -> InMemoryWasmTestBuilder {
+    let funding_amount = *DEFAULT_PAYMENT * 10;
+    let deploy = transfer_deploy(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, funding_amount, 1);
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis_default()
+        .exec_commit(exec_request)
+        .expect_success();
+    builder
+}
+
+fn balance_of(builder: &InMemoryWasmTestBuilder, account: PublicKey) -> U512 {
+    let account = builder.get_account(account).expect("should have account");
+    builder.get_purse_balance(account.main_purse())
+}
+
+#[ignore]
+#[test]
+fn should_merge_effects_of_deploys_of_different_accounts() {
+    let mut builder = setup();
+    let amount = U512::from(TRANSFER_AMOUNT);
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploys(vec![
+            transfer_deploy(DEFAULT_ACCOUNT_ADDR, ACCOUNT_2_ADDR, amount, 2),
+            transfer_deploy(ACCOUNT_1_ADDR, ACCOUNT_3_ADDR, amount, 3),
+        ])
+        .build();
+    builder.exec_batch(exec_request).expect_success().commit();
+
+    assert_eq!(
+        builder.get_batch_outcomes(),
+        &[DeployOutcome::Executed, DeployOutcome::Executed]
+    );
+    // Both deploys' results are recorded by index and both transfers are committed
+    let exec_response = builder.get_exec_response(1).expect("should have response");
+    assert_eq!(exec_response.len(), 2);
+    assert_eq!(balance_of(&builder, ACCOUNT_2_ADDR), amount);
+    assert_eq!(balance_of(&builder, ACCOUNT_3_ADDR), amount);
+}
+
+#[ignore]
+#[test]
+fn should_skip_conflicting_deploys_of_the_same_account() {
+    let mut builder = setup();
+    let amount = U512::from(TRANSFER_AMOUNT);
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploys(vec![
+            transfer_deploy(DEFAULT_ACCOUNT_ADDR, ACCOUNT_2_ADDR, amount, 2),
+            transfer_deploy(ACCOUNT_1_ADDR, ACCOUNT_2_ADDR, amount, 3),
+            transfer_deploy(DEFAULT_ACCOUNT_ADDR, ACCOUNT_3_ADDR, amount, 4),
+        ])
+        .build();
+    builder.exec_batch(exec_request).commit();
+
+    let outcomes = builder.get_batch_outcomes();
+    assert_eq!(outcomes.len(), 3);
+    assert!(outcomes[0].is_executed());
+    // Both of the later deploys' writes conflict with those of the first one: the second also
+    // creates `ACCOUNT_2_ADDR` and the third also spends from the default account's main purse
+    for outcome in &outcomes[1..] {
+        match outcome {
+            DeployOutcome::Skipped {
+                conflicting_deploy, ..
+            } => assert_eq!(*conflicting_deploy, 0),
+            DeployOutcome::Executed => panic!("should have skipped deploy"),
+        }
+    }
+
+    assert_eq!(balance_of(&builder, ACCOUNT_2_ADDR), amount);
+    assert!(builder.get_account(ACCOUNT_3_ADDR).is_none());
+}
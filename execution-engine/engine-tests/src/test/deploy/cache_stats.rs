@@ -0,0 +1,44 @@
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000;
+
+fn run_transfer(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+}
+
+#[ignore]
+#[test]
+fn should_record_cache_stats() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .with_cache_stats();
+    run_transfer(&mut builder);
+
+    // The mint reads the balance of the main purse more than once
+    let (hits, misses) = builder.last_cache_stats().expect("should have cache stats");
+    assert!(hits > 0, "should have cache hits");
+    assert!(misses > 0, "should have cache misses");
+}
+
+#[ignore]
+#[test]
+fn should_not_record_cache_stats_by_default() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    run_transfer(&mut builder);
+
+    assert_eq!(builder.last_cache_stats(), None);
+}
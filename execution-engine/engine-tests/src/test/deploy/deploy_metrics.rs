@@ -0,0 +1,87 @@
+use engine_core::engine_state::CONV_RATE;
+use engine_shared::{gas::Gas, motes::Motes};
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, ApiError, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+
+#[ignore]
+#[test]
+fn should_record_metrics_of_executed_deploys() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    assert_eq!(builder.deploy_metrics().deploys_started, 0);
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    let do_nothing_request =
+        ExecuteRequestBuilder::standard(ACCOUNT_1_ADDR, CONTRACT_DO_NOTHING, ()).build();
+    let revert_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_REVERT, ()).build();
+    let another_do_nothing_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+
+    builder
+        .exec_commit(transfer_request)
+        .expect_success()
+        .exec_commit(do_nothing_request)
+        .expect_success()
+        .exec(revert_request)
+        .expect_error(ApiError::User(100))
+        .commit()
+        .exec_commit(another_do_nothing_request)
+        .expect_success();
+
+    let metrics = builder.deploy_metrics();
+    assert_eq!(metrics.deploys_started, 4);
+    assert_eq!(metrics.deploys_finished(), 4);
+    assert_eq!(metrics.deploys_succeeded, 3);
+    assert_eq!(metrics.deploys_failed, 1);
+    assert_eq!(metrics.duration_histogram.iter().sum::<u64>(), 4);
+    assert!(metrics.total_transforms > 0);
+
+    let total_gas = (0..4)
+        .map(|index| builder.exec_costs(index)[0])
+        .fold(Gas::default(), |total, cost| total + cost);
+    assert_eq!(metrics.total_gas, total_gas);
+
+    // None of the deploys used up its payment.
+    let payment_gas = Gas::from_motes(Motes::new(*DEFAULT_PAYMENT), CONV_RATE)
+        .expect("should convert motes to gas");
+    assert!(metrics.max_gas < payment_gas);
+    assert!(metrics.total_gas < payment_gas * Gas::new(U512::from(4)));
+}
+
+#[ignore]
+#[test]
+fn should_keep_metrics_when_changing_engine_config() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+    builder.exec_commit(exec_request).expect_success();
+
+    let engine_config = builder.engine_config().with_module_cache_size(0);
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_DO_NOTHING, ()).build();
+    builder
+        .with_engine_config(engine_config)
+        .exec_commit(exec_request)
+        .expect_success();
+
+    assert_eq!(builder.deploy_metrics().deploys_succeeded, 2);
+}
@@ -0,0 +1,88 @@
+use engine_core::engine_state::effects_summary::BalanceDelta;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
+const STORE_AT_HASH: &str = "hash";
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+#[ignore]
+#[test]
+fn should_summarize_stored_contract_and_named_key() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_DO_NOTHING_STORED,
+        (STORE_AT_HASH.to_string(),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let contract_key: Key = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(DO_NOTHING_STORED_CONTRACT_NAME)
+        .cloned()
+        .expect("should have stored contract");
+
+    let summary = builder.last_effects_summary();
+
+    let contracts_stored: Vec<Key> = summary.contracts_stored().collect();
+    assert_eq!(contracts_stored, vec![contract_key]);
+
+    let named_keys_added: Vec<(PublicKey, &str, Key)> = summary.named_keys_added().collect();
+    assert_eq!(
+        named_keys_added,
+        vec![(
+            DEFAULT_ACCOUNT_ADDR,
+            DO_NOTHING_STORED_CONTRACT_NAME,
+            contract_key
+        )]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_summarize_transfer_balance_changes() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let summary = builder.last_effects_summary();
+
+    // The new account's purse is created holding the transferred amount
+    assert!(summary
+        .balance_changes()
+        .any(|(_, delta)| delta == BalanceDelta::Increase(U512::from(TRANSFER_AMOUNT))));
+    // The sender's purse pays for the transfer and the deploy
+    assert!(summary.balance_changes().any(|(_, delta)| match delta {
+        BalanceDelta::Decrease(amount) => amount > U512::from(TRANSFER_AMOUNT),
+        BalanceDelta::Increase(_) => false,
+    }));
+    // Creating the new account doesn't fit any of the known patterns
+    assert!(summary
+        .others()
+        .any(|(key, _)| key == Key::Account(ACCOUNT_1_ADDR)));
+}
@@ -1,3 +1,15 @@
+mod approvals;
+mod batch;
+mod cache_stats;
+mod deploy_metrics;
+mod effects_summary;
+mod host_function_trace;
 mod non_standard_payment;
+mod phase_costs;
 mod preconditions;
+mod preprocessing_cost;
+mod session_gas_limit;
+mod simulate;
 mod stored_contracts;
+mod traps;
+mod wasm_limits;
@@ -90,12 +90,14 @@ fn should_charge_non_main_purse() {
         let balance_mapping_key = Key::local(mint.addr(), &purse_bytes);
         let balance_uref = builder
             .query(None, balance_mapping_key, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should find balance uref");
 
         let balance: U512 = builder
             .query(None, balance_uref, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should parse balance into a U512");
@@ -152,12 +154,14 @@ fn should_charge_non_main_purse() {
         let balance_mapping_key = Key::local(mint.addr(), &purse_bytes);
         let balance_uref = builder
             .query(None, balance_mapping_key, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should find balance uref");
 
         let balance: U512 = builder
             .query(None, balance_uref, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .and_then(|cl_value| cl_value.into_t().map_err(|error| format!("{:?}", error)))
             .expect("should parse balance into a U512");
@@ -0,0 +1,69 @@
+use engine_shared::gas::Gas;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, Key, U512};
+
+const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
+const CONTRACT_REVERT: &str = "revert.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000;
+
+#[ignore]
+#[test]
+fn should_attribute_transfer_costs_and_effects_to_phases() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let phase_costs = builder.exec_phase_costs(0)[0];
+    assert!(phase_costs.payment > Gas::default());
+    assert!(phase_costs.session > Gas::default());
+    let total = phase_costs.payment + phase_costs.session + phase_costs.finalize;
+    assert_eq!(phase_costs.total(), total);
+    // Whatever finalization spends, it isn't charged for
+    assert_eq!(phase_costs.charged(), builder.exec_costs(0)[0]);
+    assert_eq!(builder.exec_costs(0)[0] + phase_costs.finalize, total);
+
+    let result = &builder.get_exec_response(0).expect("should have response")[0];
+    let session_effect = result.session_effect().expect("should have phases");
+    let account_1_key = Key::Account(ACCOUNT_1_ADDR);
+    assert!(session_effect.transforms.get(&account_1_key).is_some());
+    let payment_effect = result.payment_effect().expect("should have phases");
+    assert!(payment_effect.transforms.get(&account_1_key).is_none());
+    let finalize_effect = result.finalize_effect().expect("should have phases");
+    assert!(!finalize_effect.transforms.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_keep_payment_cost_of_reverted_session() {
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_REVERT, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .commit();
+
+    let result = &builder.get_exec_response(0).expect("should have response")[0];
+    assert!(result.is_failure());
+    let payment_cost = result.payment_cost().expect("should have payment cost");
+    assert!(payment_cost > Gas::default());
+    let session_effect = result.session_effect().expect("should have phases");
+    assert!(session_effect.transforms.is_empty());
+    let finalize_effect = result.finalize_effect().expect("should have phases");
+    assert!(!finalize_effect.transforms.is_empty());
+}
@@ -0,0 +1,154 @@
+use engine_core::engine_state::{execute_request::ExecuteRequest, preprocessing_cost};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_ACCOUNT_KEY, DEFAULT_GAS_PRICE, DEFAULT_PAYMENT, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use engine_wasm_prep::wasm_costs::WasmCosts;
+use types::U512;
+
+const CONTRACT_DO_NOTHING: &str = "do_nothing.wasm";
+const CONTRACT_LOCAL_STATE: &str = "local_state.wasm";
+const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
+const PREPROCESS_PER_BYTE: u32 = 1_000;
+
+fn wasm_costs_with_preprocessing_cost(preprocess_per_byte: u32) -> WasmCosts {
+    WasmCosts {
+        preprocess_per_byte,
+        ..*DEFAULT_WASM_COSTS
+    }
+}
+
+fn session_request(session_file: &str, payment_amount: U512) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(session_file, ())
+        .with_empty_payment_bytes((payment_amount,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn do_nothing_request(payment_amount: U512) -> ExecuteRequest {
+    session_request(CONTRACT_DO_NOTHING, payment_amount)
+}
+
+fn module_length(session_file: &str) -> usize {
+    utils::read_wasm_file_bytes(session_file).len()
+}
+
+fn do_nothing_length() -> usize {
+    module_length(CONTRACT_DO_NOTHING)
+}
+
+fn minimum_payment() -> U512 {
+    let wasm_costs = wasm_costs_with_preprocessing_cost(PREPROCESS_PER_BYTE);
+    let module_length = do_nothing_length();
+    preprocessing_cost::estimated_minimum_payment(&wasm_costs, module_length, DEFAULT_GAS_PRICE)
+        .value()
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_when_payment_cannot_cover_preprocessing() {
+    let genesis_config = utils::genesis_config_with(|wasm_costs| {
+        wasm_costs.preprocess_per_byte = PREPROCESS_PER_BYTE
+    });
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let exec_request = do_nothing_request(minimum_payment() - 1);
+    builder.exec(exec_request);
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("should have a response");
+    assert_eq!(
+        utils::get_precondition_failure(response),
+        "Insufficient payment"
+    );
+
+    let exec_request = do_nothing_request(minimum_payment());
+    builder.exec(exec_request);
+    let response = builder
+        .get_exec_response(1)
+        .expect("should have a response");
+    assert!(!utils::get_success_result(response).has_precondition_failure());
+}
+
+#[ignore]
+#[test]
+fn should_charge_session_for_preprocessing() {
+    let mut free_builder = InMemoryWasmTestBuilder::default();
+    free_builder
+        .run_genesis(&utils::genesis_config_with(|wasm_costs| {
+            wasm_costs.preprocess_per_byte = 0
+        }))
+        .exec(do_nothing_request(*DEFAULT_PAYMENT))
+        .expect_success();
+    let free_cost = free_builder.exec_costs(0)[0];
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&utils::genesis_config_with(|wasm_costs| {
+            wasm_costs.preprocess_per_byte = PREPROCESS_PER_BYTE
+        }))
+        .exec(do_nothing_request(*DEFAULT_PAYMENT))
+        .expect_success();
+    let cost = builder.exec_costs(0)[0];
+
+    let wasm_costs = wasm_costs_with_preprocessing_cost(PREPROCESS_PER_BYTE);
+    let expected_cost = preprocessing_cost::preprocessing_cost(&wasm_costs, do_nothing_length());
+    assert_eq!(cost - free_cost, expected_cost);
+}
+
+#[ignore]
+#[test]
+fn should_not_estimate_more_than_charged() {
+    let wasm_costs = wasm_costs_with_preprocessing_cost(PREPROCESS_PER_BYTE);
+    let genesis_config = utils::genesis_config_with(|wasm_costs| {
+        wasm_costs.preprocess_per_byte = PREPROCESS_PER_BYTE
+    });
+
+    let mut module_lengths = Vec::new();
+    for session_file in &[
+        CONTRACT_DO_NOTHING,
+        CONTRACT_LOCAL_STATE,
+        CONTRACT_COUNTER_DEFINE,
+    ] {
+        let mut builder = InMemoryWasmTestBuilder::default();
+        builder
+            .run_genesis(&genesis_config)
+            .exec(session_request(session_file, *DEFAULT_PAYMENT))
+            .expect_success();
+
+        let module_length = module_length(session_file);
+        module_lengths.push(module_length);
+        let estimate = preprocessing_cost::estimated_minimum_payment(
+            &wasm_costs,
+            module_length,
+            DEFAULT_GAS_PRICE,
+        );
+
+        let response = builder
+            .get_exec_response(0)
+            .expect("should have a response");
+        let cost = utils::get_success_result(response).cost();
+        let charged = Motes::from_gas(cost, DEFAULT_GAS_PRICE).expect("should convert");
+        assert!(
+            estimate <= charged,
+            "{}: estimated {:?}, charged {:?}",
+            session_file,
+            estimate,
+            charged
+        );
+    }
+
+    module_lengths.sort();
+    module_lengths.dedup();
+    assert_eq!(module_lengths.len(), 3, "modules should differ in size");
+}
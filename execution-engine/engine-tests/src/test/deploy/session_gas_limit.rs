@@ -0,0 +1,74 @@
+use engine_core::{engine_state::execute_request::ExecuteRequest, execution};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::U512;
+
+const CONTRACT_HAS_KEY: &str = "has_key.wasm";
+const COMMAND_PUT_KEYS: &str = "put_keys";
+const AFFORDABLE_KEY_COUNT: u32 = 500;
+const KEY_COUNT: u32 = 1000;
+
+fn put_keys_request(key_count: u32, payment_amount: U512) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(CONTRACT_HAS_KEY, (COMMAND_PUT_KEYS, key_count))
+        .with_empty_payment_bytes((payment_amount,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+#[ignore]
+#[test]
+fn should_only_commit_payment_effects_when_session_runs_out_of_gas() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let initial_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+
+    // Pay for exactly what writing the affordable number of keys costs, without committing it.
+    let exec_request = put_keys_request(AFFORDABLE_KEY_COUNT, *DEFAULT_PAYMENT);
+    builder.exec(exec_request).expect_success();
+    let affordable_cost = builder.exec_costs(0)[0];
+    let payment_amount = Motes::from_gas(affordable_cost, DEFAULT_GAS_PRICE)
+        .expect("should convert gas to motes")
+        .value();
+
+    let exec_request = put_keys_request(KEY_COUNT, payment_amount);
+    builder.exec(exec_request).commit();
+
+    match builder.get_exec_error(1) {
+        Some(execution::Error::GasLimit) => (),
+        other => panic!("should run out of gas, but got {:?}", other),
+    }
+
+    // None of the keys written before running out of gas are left in place.
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    for index in 0..KEY_COUNT {
+        let name = format!("key-{}", index);
+        assert!(
+            !account.named_keys().contains_key(&name),
+            "{} should not have been committed",
+            name
+        );
+    }
+
+    // The validator is paid for all of the gas spent, and only that is taken from the account.
+    let cost = Motes::from_gas(builder.exec_costs(1)[0], DEFAULT_GAS_PRICE)
+        .expect("should convert gas to motes")
+        .value();
+    assert!(cost > U512::zero());
+    assert_eq!(builder.get_pos_rewards_purse_balance(), cost);
+    assert_eq!(
+        initial_balance - builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        cost
+    );
+}
@@ -0,0 +1,82 @@
+use std::convert::TryInto;
+
+use engine_shared::newtypes::CorrelationId;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_BLOCK_HEIGHT,
+        DEFAULT_BLOCK_TIME, DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, BlockTime, Key, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const TRANSFER_TO_ACCOUNT_WASM: &str = "transfer_to_account_u512.wasm";
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+#[ignore]
+#[test]
+fn should_simulate_deploy_without_changing_state() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(
+            TRANSFER_TO_ACCOUNT_WASM,
+            (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+        )
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .build();
+
+    let pre_state_hash = builder.get_post_state_hash();
+    let simulation_result = builder
+        .get_engine_state()
+        .simulate_deploy(
+            CorrelationId::new(),
+            *DEFAULT_PROTOCOL_VERSION,
+            pre_state_hash
+                .as_slice()
+                .try_into()
+                .expect("should be a valid hash"),
+            BlockTime::new(DEFAULT_BLOCK_TIME),
+            DEFAULT_BLOCK_HEIGHT,
+            deploy_item,
+        )
+        .expect("should simulate deploy");
+
+    assert!(simulation_result.is_success());
+    assert!(!simulation_result.cost().value().is_zero());
+    assert!(simulation_result
+        .effect()
+        .transforms()
+        .get(&Key::Account(ACCOUNT_1_ADDR))
+        .is_some());
+
+    // Nothing should have been written by the simulation
+    assert_eq!(builder.get_post_state_hash(), pre_state_hash);
+    assert!(builder
+        .query(None, Key::Account(ACCOUNT_1_ADDR), &[])
+        .is_err());
+
+    // The simulated cost should match the cost of actually running the same deploy
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(
+            DeployItemBuilder::new()
+                .with_address(DEFAULT_ACCOUNT_ADDR)
+                .with_deploy_hash([1; 32])
+                .with_session_code(
+                    TRANSFER_TO_ACCOUNT_WASM,
+                    (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+                )
+                .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+                .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+                .build(),
+        )
+        .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    assert_eq!(builder.exec_costs(0)[0], simulation_result.cost());
+}
@@ -6,7 +6,8 @@ use engine_shared::{motes::Motes, stored_value::StoredValue, transform::Transfor
 use engine_test_support::{
     internal::{
         utils, AdditiveMapDiff, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder,
-        UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY, DEFAULT_GENESIS_CONFIG,
+        KeyMatcher, TransformMatcher, UpgradeRequestBuilder, DEFAULT_ACCOUNT_KEY,
+        DEFAULT_GENESIS_CONFIG,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
@@ -204,6 +205,31 @@ fn should_exec_stored_code_by_hash() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_write_one_contract_and_add_one_named_key_when_storing_code_at_hash() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        &format!("{}_stored.wasm", STANDARD_PAYMENT_CONTRACT_NAME),
+        (STORE_AT_HASH.to_string(),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&*DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .expect_transform(KeyMatcher::AnyHash, TransformMatcher::WriteContract)
+        .expect_transform(
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            TransformMatcher::AddKeys(1),
+        );
+
+    let counts = builder.transform_count_by_kind();
+    assert_eq!(counts.get("Write(Contract)"), Some(&1));
+}
+
 #[ignore]
 #[test]
 fn should_exec_stored_code_by_named_hash() {
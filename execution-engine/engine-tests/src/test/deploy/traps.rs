@@ -0,0 +1,99 @@
+use engine_core::execution::TrapKind;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const UNREACHABLE_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      unreachable)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+const MEMORY_OUT_OF_BOUNDS_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      i32.const 65536
+      i32.load
+      drop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+const DIVISION_BY_ZERO_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      i32.const 1
+      i32.const 0
+      i32.div_u
+      drop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+const RECURSIVE_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      call 0)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+/// Runs `wat` as session code, asserting it traps with `expected_trap_kind`.
+fn assert_session_traps(wat: &str, expected_trap_kind: TrapKind) {
+    let wasm_binary = wabt::wat2wasm(wat).expect("should parse");
+
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_bytes(wasm_binary, ())
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1; 32])
+        .build();
+    let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_error(ExpectedError::Trap(expected_trap_kind));
+}
+
+#[ignore]
+#[test]
+fn should_classify_unreachable_trap() {
+    assert_session_traps(UNREACHABLE_WAT, TrapKind::Unreachable);
+}
+
+#[ignore]
+#[test]
+fn should_classify_memory_out_of_bounds_trap() {
+    assert_session_traps(MEMORY_OUT_OF_BOUNDS_WAT, TrapKind::MemoryOutOfBounds);
+}
+
+#[ignore]
+#[test]
+fn should_classify_division_by_zero_trap() {
+    assert_session_traps(DIVISION_BY_ZERO_WAT, TrapKind::DivisionByZero);
+}
+
+#[ignore]
+#[test]
+fn should_classify_stack_overflow_trap() {
+    // The stack height limiter injected during preprocessing is exceeded well before the
+    // interpreter's own stack limits.
+    assert_session_traps(RECURSIVE_WAT, TrapKind::StackOverflow);
+}
@@ -0,0 +1,77 @@
+use engine_core::{
+    engine_state::{execute_request::ExecuteRequest, genesis::GenesisConfig},
+    execution::TrapKind,
+};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, ExpectedError, InMemoryWasmTestBuilder,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+
+const MAX_STACK_HEIGHT: u32 = 1024;
+
+const RECURSIVE_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      call 0)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+const EXCESSIVE_MEMORY_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      nop)
+    (memory (;0;) 10000)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+fn session_wat_request(wat: &str) -> ExecuteRequest {
+    let wasm_binary = wabt::wat2wasm(wat).expect("should parse");
+
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_bytes(wasm_binary, ())
+        .with_payment_code(STANDARD_PAYMENT_CONTRACT, (*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([1; 32])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn exec_session_wat(genesis_config: &GenesisConfig, wat: &str) -> String {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(genesis_config)
+        .exec(session_wat_request(wat))
+        .commit();
+    builder.exec_error_message(0).expect("should fail")
+}
+
+#[ignore]
+#[test]
+fn should_trap_on_exceeding_max_stack_height() {
+    let genesis_config =
+        utils::genesis_config_with(|wasm_costs| wasm_costs.max_stack_height = MAX_STACK_HEIGHT);
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&genesis_config)
+        .exec(session_wat_request(RECURSIVE_WAT))
+        .expect_error(ExpectedError::Trap(TrapKind::StackOverflow));
+}
+
+#[ignore]
+#[test]
+fn should_reject_module_declaring_excessive_memory() {
+    let message = exec_session_wat(&DEFAULT_GENESIS_CONFIG, EXCESSIVE_MEMORY_WAT);
+    assert!(
+        message.contains("InitialMemoryTooLarge { declared: 10000, limit: 64 }"),
+        "Error message {:?} does not contain expected pattern",
+        message
+    );
+}
@@ -0,0 +1,80 @@
+use engine_core::engine_state::EngineConfig;
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{system_contract_errors::pos, ApiError, EngineFeature, U512};
+
+const CONTRACT_IS_FEATURE_ENABLED: &str = "is_feature_enabled.wasm";
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const TEST_BOND: &str = "bond";
+const BOND_AMOUNT: u64 = 100_000;
+
+fn engine_config(enable_bonding: bool) -> EngineConfig {
+    EngineConfig::new()
+        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
+        .with_enable_bonding(enable_bonding)
+}
+
+fn exec_bond(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(BOND_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+#[ignore]
+#[test]
+fn should_bond_only_with_bonding_enabled() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .with_engine_config(engine_config(false))
+        .run_genesis(&DEFAULT_GENESIS_CONFIG);
+    assert!(!builder.engine_config().enable_bonding());
+
+    exec_bond(&mut builder);
+    assert_eq!(
+        builder.exec_revert_error(0),
+        Some(ApiError::from(pos::Error::BondingDisabled))
+    );
+    assert_eq!(builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR), None);
+
+    builder.with_engine_config(engine_config(true));
+    assert!(builder.engine_config().enable_bonding());
+
+    exec_bond(&mut builder);
+    builder.expect_success();
+    assert_eq!(
+        builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR),
+        Some(U512::from(BOND_AMOUNT))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_query_engine_features_from_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    for enable_bonding in &[false, true] {
+        let config = engine_config(*enable_bonding);
+        builder.with_engine_config(config);
+
+        for feature in &[
+            EngineFeature::UseSystemContracts,
+            EngineFeature::EnableBonding,
+        ] {
+            let expected = config.is_feature_enabled(*feature);
+            let exec_request = ExecuteRequestBuilder::standard(
+                DEFAULT_ACCOUNT_ADDR,
+                CONTRACT_IS_FEATURE_ENABLED,
+                (u32::from(*feature), expected),
+            )
+            .build();
+            builder.exec(exec_request).expect_success().commit();
+        }
+    }
+}
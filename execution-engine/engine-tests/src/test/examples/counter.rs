@@ -1,5 +1,7 @@
 use engine_test_support::{
-    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, QueryError, DEFAULT_GENESIS_CONFIG,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
 use types::Key;
@@ -8,6 +10,27 @@ const CONTRACT_COUNTER_CALL: &str = "counter_call.wasm";
 const CONTRACT_COUNTER_DEFINE: &str = "counter_define.wasm";
 const COUNTER_KEY: &str = "counter";
 const COUNT_KEY: &str = "count";
+const MISSING_KEY: &str = "missing";
+
+fn setup_counter() -> InMemoryWasmTestBuilder {
+    let exec_request_1 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_COUNTER_DEFINE, ()).build();
+
+    let exec_request_2 =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_COUNTER_CALL, ()).build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit();
+
+    builder.exec(exec_request_2).expect_success().commit();
+
+    builder
+}
 
 #[ignore]
 #[test]
@@ -53,3 +76,54 @@ fn should_run_counter_example_contract() {
         .expect("should cast CLValue to integer");
     assert_eq!(value, 2);
 }
+
+#[ignore]
+#[test]
+fn should_query_counter_through_account_and_contract_named_keys() {
+    let builder = setup_counter();
+
+    // account -> "counter" contract -> "count" uref
+    let stored_value = builder
+        .query(
+            None,
+            Key::Account(DEFAULT_ACCOUNT_ADDR),
+            &[COUNTER_KEY, COUNT_KEY],
+        )
+        .expect("should have counter value");
+    let cl_value = stored_value.as_cl_value().expect("should be CLValue");
+    let value: i32 = cl_value
+        .clone()
+        .into_t()
+        .expect("should cast CLValue to integer");
+    assert_eq!(value, 1);
+}
+
+#[ignore]
+#[test]
+fn should_report_failing_path_segment() {
+    let builder = setup_counter();
+    let account_key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+
+    let error = builder
+        .query(None, account_key, &[COUNTER_KEY, MISSING_KEY, COUNT_KEY])
+        .expect_err("should fail to resolve middle segment");
+    assert_eq!(
+        error,
+        QueryError::NamedKeyNotFound {
+            index: 1,
+            name: MISSING_KEY.to_string(),
+        }
+    );
+    assert_eq!(error.failing_segment(), Some((1, MISSING_KEY)));
+
+    let error = builder
+        .query(None, account_key, &[COUNTER_KEY, COUNT_KEY, MISSING_KEY])
+        .expect_err("should fail to traverse past the count value");
+    assert_eq!(
+        error,
+        QueryError::NotTraversable {
+            index: 2,
+            name: MISSING_KEY.to_string(),
+        }
+    );
+}
@@ -47,6 +47,7 @@ impl ERC20Test {
         let value: CLValue = self
             .builder
             .query(None, account_key, &[name])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .expect("should have named uref.");
         let key: Key = value.into_t().unwrap();
@@ -274,6 +275,7 @@ impl ERC20Test {
         let value: CLValue = self
             .builder
             .query(None, balance_key.clone(), &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .expect("should have local value.");
         let balance: U512 = value.into_t().unwrap();
@@ -291,6 +293,7 @@ impl ERC20Test {
         let value: CLValue = self
             .builder
             .query(None, total_supply_key.clone(), &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .expect("should have total supply key.");
         let total_supply: U512 = value.into_t().unwrap();
@@ -317,6 +320,7 @@ impl ERC20Test {
         let value: CLValue = self
             .builder
             .query(None, allowance_key.clone(), &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .expect("should have allowance key.");
         let allowance: U512 = value.into_t().unwrap();
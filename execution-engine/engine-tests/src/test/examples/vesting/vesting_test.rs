@@ -125,6 +125,7 @@ impl VestingTest {
         let value: CLValue = self
             .builder
             .query(None, account_key, &[name])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .expect("should have named uref in the account space.");
         let key: Key = value.into_t().unwrap();
@@ -270,6 +271,7 @@ impl VestingTest {
         let value = self
             .builder
             .query(None, *key_uref, &[])
+            .map_err(|error| format!("{:?}", error))
             .and_then(|v| CLValue::try_from(v).map_err(|error| format!("{:?}", error)))
             .unwrap_or_else(|error| {
                 panic!("should have local value for {} key - {:?}", name, error)
@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{InMemoryWasmTestBuilder, DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_COUNT: usize = 50;
+const CHUNK_SIZE: usize = 8;
+const ACCOUNT_BALANCE: u64 = 1_000_000;
+
+/// Returns the motes paid for all deploys executed by `builder` so far.
+fn total_cost(builder: &InMemoryWasmTestBuilder) -> U512 {
+    (0..builder.get_exec_responses_count())
+        .flat_map(|index| builder.exec_costs(index))
+        .map(|gas| {
+            Motes::from_gas(gas, DEFAULT_GAS_PRICE)
+                .expect("should convert gas to motes")
+                .value()
+        })
+        .fold(U512::zero(), |total, cost| total + cost)
+}
+
+#[ignore]
+#[test]
+fn should_create_funded_accounts() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let initial_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+
+    let balance = U512::from(ACCOUNT_BALANCE);
+    let public_keys = builder.create_funded_accounts_in_chunks(ACCOUNT_COUNT, balance, CHUNK_SIZE);
+
+    let unique_keys: BTreeSet<PublicKey> = public_keys.iter().copied().collect();
+    assert_eq!(unique_keys.len(), ACCOUNT_COUNT);
+    assert!(!unique_keys.contains(&DEFAULT_ACCOUNT_ADDR));
+    for public_key in &public_keys {
+        assert_eq!(builder.get_account_balance(*public_key), balance);
+    }
+
+    // One deploy per chunk, rounding up.
+    let deploy_count = (ACCOUNT_COUNT + CHUNK_SIZE - 1) / CHUNK_SIZE;
+    assert_eq!(builder.get_exec_responses_count(), deploy_count);
+
+    let transferred = balance * U512::from(ACCOUNT_COUNT);
+    let cost = total_cost(&builder);
+    assert!(cost > U512::zero());
+    assert_eq!(
+        initial_balance - builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        transferred + cost
+    );
+}
+
+#[ignore]
+#[test]
+fn should_create_the_same_accounts_on_every_run() {
+    let balance = U512::from(ACCOUNT_BALANCE);
+
+    let mut builder_1 = InMemoryWasmTestBuilder::default();
+    builder_1.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let public_keys_1 = builder_1.create_funded_accounts(ACCOUNT_COUNT, balance);
+
+    let mut builder_2 = InMemoryWasmTestBuilder::default();
+    builder_2.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let public_keys_2 = builder_2.create_funded_accounts(ACCOUNT_COUNT, balance);
+
+    assert_eq!(public_keys_1, public_keys_2);
+}
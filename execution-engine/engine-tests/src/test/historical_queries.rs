@@ -0,0 +1,91 @@
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, InMemoryWasmTestBuilder, QueryError, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_1_INITIAL_BALANCE: u64 = 1_000_000;
+const TRANSFER_1_AMOUNT: u64 = 1_000;
+const TRANSFER_2_AMOUNT: u64 = 2_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut accounts = DEFAULT_ACCOUNTS.clone();
+    accounts.push(GenesisAccount::new(
+        ACCOUNT_1_ADDR,
+        Motes::new(ACCOUNT_1_INITIAL_BALANCE.into()),
+        Motes::new(U512::zero()),
+    ));
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_query_balance_at_each_committed_root() {
+    let mut builder = setup();
+    let transfer_1 = U512::from(TRANSFER_1_AMOUNT);
+    let transfer_2 = U512::from(TRANSFER_2_AMOUNT);
+    builder
+        .transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, transfer_1)
+        .transfer(DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR, transfer_2);
+
+    let purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account")
+        .main_purse();
+    let balance_at = |block_index| {
+        let post_state_hash = builder
+            .post_state_hash_at(block_index)
+            .expect("should have post-state hash");
+        builder
+            .get_purse_balance_at(Some(post_state_hash), purse)
+            .expect("should get balance")
+    };
+
+    assert_eq!(balance_at(0), U512::from(ACCOUNT_1_INITIAL_BALANCE));
+    assert_eq!(
+        balance_at(1),
+        U512::from(ACCOUNT_1_INITIAL_BALANCE + TRANSFER_1_AMOUNT)
+    );
+    assert_eq!(
+        balance_at(2),
+        U512::from(ACCOUNT_1_INITIAL_BALANCE + TRANSFER_1_AMOUNT + TRANSFER_2_AMOUNT)
+    );
+
+    assert_eq!(
+        builder.post_state_hash_at(0),
+        Some(builder.get_genesis_hash())
+    );
+    assert_eq!(
+        builder.post_state_hash_at(2),
+        Some(builder.get_post_state_hash())
+    );
+    assert_eq!(builder.post_state_hash_at(3), None);
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_query_balance_at_unknown_root() {
+    let builder = setup();
+    let purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account")
+        .main_purse();
+
+    let unknown_root = vec![255u8; 32];
+    assert_eq!(
+        builder.get_purse_balance_at(Some(unknown_root), purse),
+        Err(QueryError::RootNotFound)
+    );
+    let malformed_root = vec![1u8; 3];
+    assert_eq!(
+        builder.get_purse_balance_at(Some(malformed_root), purse),
+        Err(QueryError::RootNotFound)
+    );
+}
@@ -0,0 +1,118 @@
+use tempfile::TempDir;
+
+use contract::args_parser::ArgsParser;
+use engine_core::engine_state::execute_request::ExecuteRequest;
+use engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, JournalValue,
+        DEFAULT_GENESIS_CONFIG, DEFAULT_PAYMENT,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_DO_NOTHING_STORED: &str = "do_nothing_stored.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+const JOURNAL_FILE_NAME: &str = "journal.json";
+
+/// Builds an exec request with a fixed deploy hash, so that its journal is reproducible.
+fn exec_request(session_file: &str, args: impl ArgsParser, deploy_hash: u8) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(session_file, args)
+        .with_empty_payment_bytes((*DEFAULT_PAYMENT,))
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([deploy_hash; 32])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn transfer(amount: u64) -> InMemoryWasmTestBuilder {
+    let exec_request = exec_request(
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(amount)),
+        1,
+    );
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_match_journal_of_identical_execution() {
+    let temp_dir = TempDir::new().expect("should create temp dir");
+    let journal_path = temp_dir.path().join(JOURNAL_FILE_NAME);
+
+    transfer(TRANSFER_AMOUNT).write_journal(&journal_path);
+    transfer(TRANSFER_AMOUNT).assert_journal_matches(&journal_path);
+}
+
+#[ignore]
+#[test]
+#[should_panic(expected = "journal doesn't match")]
+fn should_not_match_journal_of_different_execution() {
+    let temp_dir = TempDir::new().expect("should create temp dir");
+    let journal_path = temp_dir.path().join(JOURNAL_FILE_NAME);
+
+    transfer(TRANSFER_AMOUNT).write_journal(&journal_path);
+    transfer(TRANSFER_AMOUNT + 1).assert_journal_matches(&journal_path);
+}
+
+#[ignore]
+#[test]
+fn should_journal_each_deploy() {
+    let builder = transfer(TRANSFER_AMOUNT);
+
+    let journal = builder.journal();
+    assert_eq!(journal.exec_requests.len(), 1);
+
+    let exec_journal = &journal.exec_requests[0];
+    assert_eq!(
+        exec_journal.pre_state_hash,
+        base16::encode_lower(&builder.get_genesis_hash())
+    );
+    assert_eq!(exec_journal.deploys.len(), 1);
+
+    let deploy_journal = &exec_journal.deploys[0];
+    assert_eq!(
+        deploy_journal.deploy_hash,
+        Some(base16::encode_lower(&[1u8; 32]))
+    );
+    assert_eq!(deploy_journal.cost, builder.exec_costs(0)[0].to_string());
+    assert_eq!(deploy_journal.error, None);
+    assert_eq!(
+        deploy_journal.transforms.len(),
+        builder.get_transforms()[0].len()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_journal_large_values_by_hash() {
+    let exec_request = exec_request(CONTRACT_DO_NOTHING_STORED, (), 1);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success();
+
+    let journal = builder.journal();
+    let transforms = &journal.exec_requests[0].deploys[0].transforms;
+    // The stored contract's body is too long to record in full.
+    assert!(transforms.values().any(|transform| {
+        transform.kind == "Write"
+            && match transform.value {
+                Some(JournalValue::Blob { .. }) => true,
+                _ => false,
+            }
+    }));
+}
@@ -0,0 +1,78 @@
+use std::fs;
+
+use tempfile::TempDir;
+
+use engine_core::engine_state::{execute_request::ExecuteRequest, EngineConfig};
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+const LMDB_DATA_FILE: &str = "data.mdb";
+
+fn transfer_request() -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_count_more_entries_after_commit() {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let genesis_stats = builder.lmdb_stats();
+    assert!(genesis_stats.entries > 0);
+    assert!(genesis_stats.depth > 0);
+    assert!(genesis_stats.map_size_used <= genesis_stats.map_size);
+
+    builder.exec_commit(transfer_request()).expect_success();
+
+    let stats = builder.lmdb_stats();
+    assert!(stats.entries > genesis_stats.entries);
+    assert!(stats.map_size_used >= genesis_stats.map_size_used);
+    assert_eq!(stats.page_size, genesis_stats.page_size);
+}
+
+#[ignore]
+#[test]
+fn should_compact_into_openable_copy() {
+    let mut builder = LmdbWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec_commit(transfer_request())
+        .expect_success();
+
+    let target_dir = TempDir::new().expect("should create temp dir");
+    let global_state_dir = target_dir.path().join("global_state");
+    fs::create_dir(&global_state_dir).expect("should create global state dir");
+    builder.compact_lmdb(&global_state_dir);
+
+    let data_dir = builder.temp_data_dir().expect("should have temp data dir");
+    let original_size = fs::metadata(data_dir.join(LMDB_DATA_FILE))
+        .expect("should read metadata")
+        .len();
+    let compacted_size = fs::metadata(global_state_dir.join(LMDB_DATA_FILE))
+        .expect("should read metadata")
+        .len();
+    assert!(compacted_size <= original_size);
+
+    let compacted = LmdbWasmTestBuilder::open(
+        target_dir.path(),
+        EngineConfig::default(),
+        builder.get_post_state_hash(),
+    );
+    assert_eq!(compacted.lmdb_stats().entries, builder.lmdb_stats().entries);
+    let account = compacted
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should find account in compacted copy");
+    assert_eq!(Some(account), builder.get_account(ACCOUNT_1_ADDR));
+}
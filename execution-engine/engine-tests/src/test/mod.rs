@@ -1,6 +1,17 @@
+mod account_lookup;
+mod commit_conflicts;
+mod commit_stats;
 mod contract_api;
 mod deploy;
+mod engine_config;
 mod examples;
+mod funded_accounts;
+mod historical_queries;
+mod journal;
+mod lmdb_stats;
+mod query_with_proof;
 mod regression;
+mod snapshot;
 mod system_contracts;
+mod temp_lmdb;
 mod upgrade;
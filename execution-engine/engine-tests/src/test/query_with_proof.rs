@@ -0,0 +1,115 @@
+use std::convert::TryInto;
+
+use engine_shared::newtypes::Blake2bHash;
+use engine_storage::trie::{self, TrieMerkleProof};
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, QueryError, DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, ToBytes},
+    Key, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000;
+
+fn get_post_state_hash(builder: &InMemoryWasmTestBuilder) -> Blake2bHash {
+    builder
+        .get_post_state_hash()
+        .as_slice()
+        .try_into()
+        .expect("should be a valid hash")
+}
+
+#[ignore]
+#[test]
+fn should_query_with_proof_valid_against_post_state_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+
+    let key = Key::Account(DEFAULT_ACCOUNT_ADDR);
+    let proof = builder
+        .query_with_proof(None, key)
+        .expect("should query with proof");
+    let value = builder.query(None, key, &[]).expect("should query");
+    assert_eq!(proof.value(), &value);
+
+    let state_hash = get_post_state_hash(&builder);
+    assert!(trie::validate_proof(&state_hash, &proof, &key, &value));
+
+    let mut mutated_bytes: [u8; 32] = state_hash.into();
+    mutated_bytes[0] ^= 1;
+    let mutated_hash = Blake2bHash::from(mutated_bytes);
+    assert!(!trie::validate_proof(&mutated_hash, &proof, &key, &value));
+
+    // A different value, e.g. the mint contract, doesn't match the proof.
+    let mint_value = builder
+        .query(None, Key::from(builder.get_mint_contract_uref()), &[])
+        .expect("should query mint");
+    assert!(!trie::validate_proof(
+        &state_hash,
+        &proof,
+        &key,
+        &mint_value
+    ));
+    let forged = TrieMerkleProof::new(key, mint_value.clone(), proof.path().to_vec());
+    assert!(!trie::validate_proof(
+        &state_hash,
+        &forged,
+        &key,
+        &mint_value
+    ));
+
+    // Nor does a proof with any byte of its root node flipped.
+    let root_bytes = proof.path()[0].to_bytes().expect("should serialize");
+    let mut tampered_count = 0;
+    for index in 0..root_bytes.len() {
+        let mut tampered_bytes = root_bytes.clone();
+        tampered_bytes[index] ^= 1;
+        let tampered_root = match bytesrepr::deserialize(tampered_bytes) {
+            Ok(tampered_root) => tampered_root,
+            Err(_) => continue,
+        };
+        let mut path = proof.path().to_vec();
+        path[0] = tampered_root;
+        let tampered = TrieMerkleProof::new(key, value.clone(), path);
+        assert!(!trie::validate_proof(&state_hash, &tampered, &key, &value));
+        tampered_count += 1;
+    }
+    assert!(tampered_count > 0);
+}
+
+#[ignore]
+#[test]
+fn should_not_validate_proof_against_earlier_post_state_hash() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_GENESIS_CONFIG);
+    let genesis_hash = get_post_state_hash(&builder);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let key = Key::Account(ACCOUNT_1_ADDR);
+    let proof = builder
+        .query_with_proof(None, key)
+        .expect("should query with proof");
+    let value = proof.value();
+    let state_hash = get_post_state_hash(&builder);
+    assert!(trie::validate_proof(&state_hash, &proof, &key, value));
+    assert!(!trie::validate_proof(&genesis_hash, &proof, &key, value));
+
+    let error = builder
+        .query_with_proof(Some(genesis_hash.to_vec()), key)
+        .expect_err("account should not exist at genesis");
+    assert_eq!(error, QueryError::BaseKeyNotFound(key));
+}
@@ -1,8 +1,11 @@
 use engine_test_support::{
-    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ENGINE_CONFIG,
+        DEFAULT_GENESIS_CONFIG,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::ApiError;
+use types::{system_contract_errors::pos, ApiError};
 
 const CONTRACT_EE_597_REGRESSION: &str = "ee_597_regression.wasm";
 
@@ -13,26 +16,10 @@ fn should_fail_when_bonding_amount_is_zero_ee_597_regression() {
         ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_EE_597_REGRESSION, ())
             .build();
 
-    let result = InMemoryWasmTestBuilder::default()
+    InMemoryWasmTestBuilder::default()
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
         .run_genesis(&DEFAULT_GENESIS_CONFIG)
         .exec(exec_request)
         .commit()
-        .finish();
-
-    let response = result
-        .builder()
-        .get_exec_response(0)
-        .expect("should have a response")
-        .to_owned();
-
-    let error_message = utils::get_error_message(response);
-
-    if !cfg!(feature = "enable-bonding") {
-        assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::Unhandled))));
-    } else {
-        // Error::BondTooSmall => 5,
-        assert!(
-            error_message.contains(&format!("Revert({})", u32::from(ApiError::ProofOfStake(5))))
-        );
-    }
+        .expect_error(ApiError::from(pos::Error::BondTooSmall));
 }
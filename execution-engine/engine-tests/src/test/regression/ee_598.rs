@@ -5,11 +5,11 @@ use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
         utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
-        DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
+        DEFAULT_ENGINE_CONFIG, DEFAULT_PAYMENT, STANDARD_PAYMENT_CONTRACT,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::{account::PublicKey, ApiError, U512};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, U512};
 
 const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([7u8; 32]);
@@ -58,28 +58,13 @@ fn should_fail_unboding_more_than_it_was_staked_ee_598_regression() {
         ExecuteRequestBuilder::from_deploy_item(deploy).build()
     };
 
-    let result = InMemoryWasmTestBuilder::default()
+    InMemoryWasmTestBuilder::default()
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
         .run_genesis(&genesis_config)
         .exec(exec_request_1)
         .expect_success()
         .commit()
         .exec(exec_request_2)
         .commit()
-        .finish();
-
-    let response = result
-        .builder()
-        .get_exec_response(1)
-        .expect("should have a response")
-        .to_owned();
-    let error_message = utils::get_error_message(response);
-
-    if !cfg!(feature = "enable-bonding") {
-        assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::Unhandled))));
-    } else {
-        // Error::UnbondTooLarge => 7,
-        assert!(
-            error_message.contains(&format!("Revert({})", u32::from(ApiError::ProofOfStake(7))))
-        );
-    }
+        .expect_error(ApiError::from(pos::Error::UnbondTooLarge));
 }
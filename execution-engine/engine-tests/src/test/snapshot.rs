@@ -0,0 +1,101 @@
+use lazy_static::lazy_static;
+use tempfile::TempDir;
+
+use engine_core::engine_state::execute_request::ExecuteRequest;
+use engine_storage::global_state::in_memory::InMemoryGlobalState;
+use engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, LmdbWasmTestBuilder, StateSnapshot,
+        DEFAULT_GENESIS_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
+const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+
+lazy_static! {
+    static ref SNAPSHOT: StateSnapshot<InMemoryGlobalState> = InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(transfer_request(ACCOUNT_1_ADDR))
+        .expect_success()
+        .commit()
+        .snapshot();
+}
+
+fn transfer_request(target: PublicKey) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        (target, U512::from(TRANSFER_AMOUNT)),
+    )
+    .build()
+}
+
+#[ignore]
+#[test]
+fn should_query_restored_snapshot() {
+    let builder = InMemoryWasmTestBuilder::from_snapshot(&SNAPSHOT);
+
+    assert_eq!(
+        Some(builder.get_post_state_hash().as_slice()),
+        SNAPSHOT.post_state_hash()
+    );
+    assert_eq!(
+        builder.get_genesis_account().public_key(),
+        DEFAULT_ACCOUNT_ADDR
+    );
+    assert!(!builder.get_genesis_transforms().is_empty());
+
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    assert_eq!(
+        builder.get_purse_balance(account_1.main_purse()),
+        U512::from(TRANSFER_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_share_commits_between_restored_builders() {
+    let mut builder_1 = InMemoryWasmTestBuilder::from_snapshot(&SNAPSHOT);
+    builder_1
+        .exec(transfer_request(ACCOUNT_2_ADDR))
+        .expect_success()
+        .commit();
+    assert!(builder_1.get_account(ACCOUNT_2_ADDR).is_some());
+
+    let builder_2 = InMemoryWasmTestBuilder::from_snapshot(&SNAPSHOT);
+    assert!(builder_2.get_account(ACCOUNT_2_ADDR).is_none());
+    assert_eq!(
+        Some(builder_2.get_post_state_hash().as_slice()),
+        SNAPSHOT.post_state_hash()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_restore_lmdb_snapshot() {
+    let data_dir = TempDir::new().expect("should create temp dir");
+    let snapshot = LmdbWasmTestBuilder::new(data_dir.path())
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(transfer_request(ACCOUNT_1_ADDR))
+        .expect_success()
+        .commit()
+        .snapshot();
+
+    let mut builder_1 = LmdbWasmTestBuilder::from_snapshot(&snapshot);
+    builder_1
+        .exec(transfer_request(ACCOUNT_2_ADDR))
+        .expect_success()
+        .commit();
+    assert!(builder_1.get_account(ACCOUNT_2_ADDR).is_some());
+
+    let builder_2 = LmdbWasmTestBuilder::from_snapshot(&snapshot);
+    assert!(builder_2.get_account(ACCOUNT_1_ADDR).is_some());
+    assert!(builder_2.get_account(ACCOUNT_2_ADDR).is_none());
+}
@@ -0,0 +1,110 @@
+use engine_core::engine_state::{
+    execute_request::ExecuteRequest,
+    genesis::{GenesisConfig, POS_GAS_PRICE},
+};
+use engine_shared::{motes::Motes, stored_value::StoredValue};
+use engine_test_support::{
+    internal::{
+        utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ACCOUNT_KEY, DEFAULT_UNBONDING_DELAY, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{runtime_args, CLValue, U512};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const ENDLESS_LOOP_WASM: &str = "endless_loop.wasm";
+const ARG_GAS: &str = "gas";
+const GAS_PRICE: u64 = 3;
+
+fn gas_price_genesis_config(gas_price: u64) -> GenesisConfig {
+    utils::create_custom_genesis_config(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_COSTS,
+        DEFAULT_UNBONDING_DELAY,
+        gas_price,
+    )
+}
+
+fn pay_for_gas_request(session_file: &str, gas: u64) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(DEFAULT_ACCOUNT_ADDR)
+        .with_deploy_hash([1; 32])
+        .with_session_code(session_file, ())
+        .with_empty_payment_bytes(runtime_args! { ARG_GAS => U512::from(gas) })
+        .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+        .build();
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+#[ignore]
+#[test]
+fn should_store_gas_price_set_at_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&gas_price_genesis_config(GAS_PRICE));
+
+    let gas_price_key = *builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_GAS_PRICE)
+        .expect("should have gas price key");
+    let stored_value = builder
+        .query(None, gas_price_key, &[])
+        .expect("should query gas price");
+
+    assert_eq!(
+        stored_value,
+        StoredValue::CLValue(CLValue::from_t(GAS_PRICE).unwrap())
+    );
+}
+
+#[ignore]
+#[test]
+fn should_charge_gas_price_times_gas_paid_for() {
+    const GAS: u64 = 5000;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&gas_price_genesis_config(GAS_PRICE));
+    let initial_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+
+    // The session never finishes, so all of the gas paid for is spent.
+    let exec_request = pay_for_gas_request(ENDLESS_LOOP_WASM, GAS);
+    builder.exec(exec_request).commit();
+
+    let expected_cost = U512::from(GAS_PRICE) * U512::from(GAS);
+    assert_eq!(
+        initial_balance - builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        expected_cost
+    );
+    assert_eq!(builder.get_pos_rewards_purse_balance(), expected_cost);
+}
+
+#[ignore]
+#[test]
+fn should_finalize_payment_at_gas_price() {
+    const GAS: u64 = 1_000_000;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&gas_price_genesis_config(GAS_PRICE));
+    let initial_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+
+    let exec_request = pay_for_gas_request(DO_NOTHING_WASM, GAS);
+    builder.exec(exec_request).expect_success().commit();
+
+    let response = builder
+        .get_exec_response(0)
+        .expect("there should be a response");
+    let cost = utils::get_exec_costs(response)[0];
+    let expected_cost = Motes::from_gas(cost, GAS_PRICE).expect("should convert gas to motes");
+
+    // Only the gas actually spent is charged, the rest is refunded.
+    assert!(expected_cost.value() < U512::from(GAS_PRICE) * U512::from(GAS));
+    assert_eq!(
+        initial_balance - builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        expected_cost.value()
+    );
+    assert_eq!(
+        builder.get_pos_rewards_purse_balance(),
+        expected_cost.value()
+    );
+}
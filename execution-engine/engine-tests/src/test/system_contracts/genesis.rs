@@ -1,11 +1,15 @@
+use std::collections::BTreeMap;
+
 use engine_core::engine_state::{
-    genesis::{GenesisAccount, GenesisConfig},
+    self,
+    genesis::{GenesisAccount, GenesisConfig, GenesisError},
     SYSTEM_ACCOUNT_ADDR,
 };
-use engine_shared::{motes::Motes, stored_value::StoredValue};
+use engine_shared::{motes::Motes, newtypes::CorrelationId, stored_value::StoredValue};
 use engine_test_support::internal::{
-    utils, InMemoryWasmTestBuilder, DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT,
-    POS_INSTALL_CONTRACT, STANDARD_PAYMENT_INSTALL_CONTRACT,
+    utils, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_GAS_PRICE, DEFAULT_UNBONDING_DELAY,
+    DEFAULT_WASM_COSTS, MINT_INSTALL_CONTRACT, POS_INSTALL_CONTRACT,
+    STANDARD_PAYMENT_INSTALL_CONTRACT,
 };
 use types::{account::PublicKey, Key, ProtocolVersion, U512};
 
@@ -16,6 +20,8 @@ const CHAIN_NAME: &str = "Jeremiah";
 const TIMESTAMP: u64 = 0;
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
+const ACCOUNT_3_ADDR: PublicKey = PublicKey::ed25519_from([3u8; 32]);
+const ACCOUNT_4_ADDR: PublicKey = PublicKey::ed25519_from([4u8; 32]);
 const ACCOUNT_1_BONDED_AMOUNT: u64 = 1_000_000;
 const ACCOUNT_2_BONDED_AMOUNT: u64 = 2_000_000;
 const ACCOUNT_1_BALANCE: u64 = 1_000_000_000;
@@ -54,6 +60,8 @@ fn should_run_genesis() {
     let accounts = vec![account_1, account_2];
     let protocol_version = ProtocolVersion::V1_0_0;
     let wasm_costs = *DEFAULT_WASM_COSTS;
+    let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let gas_price = DEFAULT_GAS_PRICE;
 
     let genesis_config = GenesisConfig::new(
         name,
@@ -64,6 +72,9 @@ fn should_run_genesis() {
         standard_payment_installer_bytes,
         accounts,
         wasm_costs,
+        unbonding_delay,
+        gas_price,
+        Vec::new(),
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -106,6 +117,110 @@ fn should_run_genesis() {
     }
 }
 
+fn genesis_account(public_key: PublicKey, balance: u64, bonded_amount: u64) -> GenesisAccount {
+    GenesisAccount::new(
+        public_key,
+        Motes::new(balance.into()),
+        Motes::new(bonded_amount.into()),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_run_genesis_with_independent_balances_and_bonds() {
+    let accounts = vec![
+        genesis_account(ACCOUNT_1_ADDR, 0, 0),
+        genesis_account(ACCOUNT_2_ADDR, 0, ACCOUNT_2_BONDED_AMOUNT),
+        genesis_account(ACCOUNT_3_ADDR, ACCOUNT_1_BALANCE, 0),
+        genesis_account(ACCOUNT_4_ADDR, ACCOUNT_2_BALANCE, ACCOUNT_1_BONDED_AMOUNT),
+    ];
+    let genesis_config = utils::create_genesis_config(accounts.clone());
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    for account in &accounts {
+        let main_purse = builder
+            .get_account(account.public_key())
+            .expect("should have account")
+            .main_purse();
+        let balance = builder.get_purse_balance(main_purse);
+        assert_eq!(balance, account.balance().value());
+    }
+
+    let mut expected_bonds = BTreeMap::new();
+    expected_bonds.insert(ACCOUNT_2_ADDR, U512::from(ACCOUNT_2_BONDED_AMOUNT));
+    expected_bonds.insert(ACCOUNT_4_ADDR, U512::from(ACCOUNT_1_BONDED_AMOUNT));
+    assert_eq!(builder.get_bonded_validators(), expected_bonds);
+
+    let expected_total_supply = U512::from(ACCOUNT_1_BALANCE)
+        + U512::from(ACCOUNT_2_BALANCE)
+        + U512::from(ACCOUNT_1_BONDED_AMOUNT)
+        + U512::from(ACCOUNT_2_BONDED_AMOUNT);
+    assert_eq!(builder.total_supply(), expected_total_supply);
+}
+
+#[ignore]
+#[test]
+fn should_top_up_purse_of_listed_validator() {
+    let accounts = vec![
+        genesis_account(ACCOUNT_1_ADDR, 0, ACCOUNT_1_BONDED_AMOUNT),
+        genesis_account(ACCOUNT_1_ADDR, ACCOUNT_1_BALANCE, 0),
+    ];
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+
+    let main_purse = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account")
+        .main_purse();
+    let balance = builder.get_purse_balance(main_purse);
+    assert_eq!(balance, U512::from(ACCOUNT_1_BALANCE));
+
+    let stake = builder.get_validator_stake(ACCOUNT_1_ADDR);
+    assert_eq!(stake, Some(U512::from(ACCOUNT_1_BONDED_AMOUNT)));
+}
+
+#[ignore]
+#[test]
+fn should_fail_genesis_with_zero_gas_price() {
+    let genesis_config = utils::create_custom_genesis_config(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_COSTS,
+        DEFAULT_UNBONDING_DELAY,
+        0,
+    );
+
+    // The gRPC mapping replaces a gas price of zero with the default, so commit directly.
+    let builder = InMemoryWasmTestBuilder::default();
+    let result = builder
+        .get_engine_state()
+        .commit_genesis(CorrelationId::new(), genesis_config);
+
+    match result {
+        Err(engine_state::Error::Genesis(GenesisError::ZeroGasPrice)) => (),
+        Err(error) => panic!("unexpected error: {:?}", error),
+        Ok(_) => panic!("genesis should fail with a zero gas price"),
+    }
+}
+
+#[ignore]
+#[should_panic(expected = "Invalid genesis account PublicKey")]
+#[test]
+fn should_fail_genesis_if_amounts_overflow() {
+    let max_balance = Motes::new(U512::max_value());
+    let accounts = vec![
+        GenesisAccount::new(ACCOUNT_1_ADDR, max_balance, Motes::new(U512::zero())),
+        genesis_account(ACCOUNT_2_ADDR, 0, ACCOUNT_2_BONDED_AMOUNT),
+    ];
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+}
+
 #[cfg(feature = "use-system-contracts")]
 #[ignore]
 #[should_panic]
@@ -140,6 +255,8 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+        let gas_price = DEFAULT_GAS_PRICE;
 
         GenesisConfig::new(
             name,
@@ -150,6 +267,9 @@ fn should_fail_if_bad_mint_install_contract_is_provided() {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            unbonding_delay,
+            gas_price,
+            Vec::new(),
         )
     };
 
@@ -192,6 +312,8 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
         let accounts = vec![account_1, account_2];
         let protocol_version = ProtocolVersion::V1_0_0;
         let wasm_costs = *DEFAULT_WASM_COSTS;
+        let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+        let gas_price = DEFAULT_GAS_PRICE;
 
         GenesisConfig::new(
             name,
@@ -202,6 +324,9 @@ fn should_fail_if_bad_pos_install_contract_is_provided() {
             standard_payment_installer_bytes,
             accounts,
             wasm_costs,
+            unbonding_delay,
+            gas_price,
+            Vec::new(),
         )
     };
 
@@ -1,5 +1,8 @@
+mod gas_price;
 mod genesis;
+mod genesis_contracts;
 mod mint_install;
+mod mint_total_supply;
 mod pos_install;
 mod proof_of_stake;
 mod standard_payment;
@@ -4,7 +4,7 @@ use engine_core::engine_state::EngineConfig;
 use engine_test_support::{
     internal::{
         exec_with_return, ExecuteRequestBuilder, WasmTestBuilder, DEFAULT_BLOCK_TIME,
-        DEFAULT_GENESIS_CONFIG,
+        DEFAULT_GAS_PRICE, DEFAULT_GENESIS_CONFIG, DEFAULT_UNBONDING_DELAY,
     },
     DEFAULT_ACCOUNT_ADDR,
 };
@@ -16,10 +16,12 @@ const SYSTEM_ADDR: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
 const N_VALIDATORS: u8 = 5;
 
-// one named_key for each validator and three for the purses
-const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 3;
+// one named_key for each validator, four for the purses, one for the unbonding delay and one for
+// the gas price
+const EXPECTED_KNOWN_KEYS_LEN: usize = (N_VALIDATORS as usize) + 6;
 
 const POS_BONDING_PURSE: &str = "pos_bonding_purse";
+const POS_COMMUNITY_PURSE: &str = "pos_community_purse";
 const POS_PAYMENT_PURSE: &str = "pos_payment_purse";
 const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
 
@@ -27,9 +29,8 @@ const POS_REWARDS_PURSE: &str = "pos_rewards_purse";
 #[test]
 fn should_run_pos_install_contract() {
     let mut builder = WasmTestBuilder::default();
-    let engine_config = EngineConfig::new()
-        .with_use_system_contracts(cfg!(feature = "use-system-contracts"))
-        .with_enable_bonding(cfg!(feature = "enable-bonding"));
+    let engine_config =
+        EngineConfig::new().with_use_system_contracts(cfg!(feature = "use-system-contracts"));
 
     let exec_request = ExecuteRequestBuilder::standard(
         DEFAULT_ACCOUNT_ADDR,
@@ -57,7 +58,12 @@ fn should_run_pos_install_contract() {
         "pos_install.wasm",
         DEFAULT_BLOCK_TIME,
         DEPLOY_HASH_2,
-        (mint_uref, genesis_validators),
+        (
+            mint_uref,
+            genesis_validators,
+            DEFAULT_UNBONDING_DELAY,
+            DEFAULT_GAS_PRICE,
+        ),
         vec![mint_uref],
     )
     .expect("should run successfully");
@@ -96,6 +102,13 @@ fn should_run_pos_install_contract() {
 
     let rewards_purse_balance = builder.get_purse_balance(rewards_purse);
     assert_eq!(rewards_purse_balance, U512::zero());
+
+    // community purse starts out empty
+    let community_purse = get_purse(named_keys, POS_COMMUNITY_PURSE)
+        .expect("should find community purse in named_keys");
+
+    let community_purse_balance = builder.get_purse_balance(community_purse);
+    assert_eq!(community_purse_balance, U512::zero());
 }
 
 fn get_purse(named_keys: &BTreeMap<String, Key>, name: &str) -> Option<URef> {
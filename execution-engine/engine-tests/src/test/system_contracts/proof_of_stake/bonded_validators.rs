@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+use engine_core::engine_state::genesis::GenesisAccount;
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ENGINE_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+const GENESIS_VALIDATOR_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const ACCOUNT_STAKE: u64 = 100_000;
+
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account = GenesisAccount::new(
+            GENESIS_VALIDATOR_ADDR,
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        );
+        tmp.push(account);
+        tmp
+    };
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
+        .run_genesis(&genesis_config);
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_get_genesis_validators() {
+    let builder = setup();
+
+    let expected: BTreeMap<PublicKey, U512> =
+        vec![(GENESIS_VALIDATOR_ADDR, U512::from(GENESIS_VALIDATOR_STAKE))]
+            .into_iter()
+            .collect();
+    assert_eq!(builder.get_bonded_validators(), expected);
+    assert_eq!(
+        builder.get_validator_stake(GENESIS_VALIDATOR_ADDR),
+        Some(U512::from(GENESIS_VALIDATOR_STAKE))
+    );
+    assert_eq!(builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR), None);
+}
+
+#[ignore]
+#[test]
+fn should_get_new_bonder_before_and_after_commit() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(ACCOUNT_STAKE)),
+    )
+    .build();
+    builder.exec(exec_request).expect_success();
+
+    // The bond is visible before it's committed
+    assert_eq!(
+        builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR),
+        Some(U512::from(ACCOUNT_STAKE))
+    );
+    assert_eq!(builder.get_bonded_validators().len(), 2);
+
+    builder.commit();
+
+    assert_eq!(
+        builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR),
+        Some(U512::from(ACCOUNT_STAKE))
+    );
+    assert_eq!(
+        builder.get_validator_stake(GENESIS_VALIDATOR_ADDR),
+        Some(U512::from(GENESIS_VALIDATOR_STAKE))
+    );
+}
+
+#[ignore]
+#[test]
+fn should_drop_fully_unbonded_validator() {
+    let mut builder = setup();
+
+    let bond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(ACCOUNT_STAKE)),
+    )
+    .build();
+    builder.exec(bond_request).expect_success().commit();
+
+    let unbond_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNBOND), None as Option<U512>),
+    )
+    .build();
+    builder.exec(unbond_request).expect_success();
+
+    // The unbond is visible before it's committed
+    assert_eq!(builder.get_validator_stake(DEFAULT_ACCOUNT_ADDR), None);
+
+    builder.commit();
+
+    let expected: BTreeMap<PublicKey, U512> =
+        vec![(GENESIS_VALIDATOR_ADDR, U512::from(GENESIS_VALIDATOR_STAKE))]
+            .into_iter()
+            .collect();
+    assert_eq!(builder.get_bonded_validators(), expected);
+}
@@ -5,11 +5,12 @@ use engine_core::engine_state::{
 use engine_shared::motes::Motes;
 use engine_test_support::{
     internal::{
-        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_PAYMENT,
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ENGINE_CONFIG, DEFAULT_PAYMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
-use types::{account::PublicKey, ApiError, Key, URef, U512};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, Key, URef, U512};
 
 const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
@@ -61,7 +62,10 @@ fn should_run_successful_bond_and_unbond() {
     let genesis_config = utils::create_genesis_config(accounts);
 
     let mut builder = InMemoryWasmTestBuilder::default();
-    let result = builder.run_genesis(&genesis_config).finish();
+    let result = builder
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
+        .run_genesis(&genesis_config)
+        .finish();
 
     let default_account = builder
         .get_account(DEFAULT_ACCOUNT_ADDR)
@@ -78,12 +82,11 @@ fn should_run_successful_bond_and_unbond() {
 
     let mut builder = InMemoryWasmTestBuilder::from_result(result);
 
-    let result = builder.exec(exec_request_1);
-    if !cfg!(feature = "enable-bonding") && result.is_error() {
-        return;
-    }
-
-    let result = builder.expect_success().commit().finish();
+    let result = builder
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .finish();
 
     let exec_response = builder
         .get_exec_response(0)
@@ -454,30 +457,14 @@ fn should_fail_bonding_with_insufficient_funds() {
     )
     .build();
 
-    let result = InMemoryWasmTestBuilder::default()
+    InMemoryWasmTestBuilder::default()
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
         .run_genesis(&genesis_config)
         .exec(exec_request_1)
         .commit()
         .exec(exec_request_2)
         .commit()
-        .finish();
-
-    let response = result
-        .builder()
-        .get_exec_response(1)
-        .expect("should have a response")
-        .to_owned();
-
-    let error_message = utils::get_error_message(response);
-
-    if !cfg!(feature = "enable-bonding") {
-        assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::Unhandled))));
-    } else {
-        // pos::Error::BondTransferFailed => 8
-        assert!(
-            error_message.contains(&format!("Revert({})", u32::from(ApiError::ProofOfStake(8))))
-        );
-    }
+        .expect_error(ApiError::from(pos::Error::BondTransferFailed));
 }
 
 #[ignore]
@@ -503,26 +490,10 @@ fn should_fail_unbonding_validator_without_bonding_first() {
     )
     .build();
 
-    let result = InMemoryWasmTestBuilder::default()
+    InMemoryWasmTestBuilder::default()
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
         .run_genesis(&genesis_config)
         .exec(exec_request)
         .commit()
-        .finish();
-
-    let response = result
-        .builder()
-        .get_exec_response(0)
-        .expect("should have a response")
-        .to_owned();
-
-    let error_message = utils::get_error_message(response);
-
-    if !cfg!(feature = "enable-bonding") {
-        assert!(error_message.contains(&format!("Revert({})", u32::from(ApiError::Unhandled))));
-    } else {
-        // pos::Error::NotBonded => 0
-        assert!(
-            error_message.contains(&format!("Revert({})", u32::from(ApiError::ProofOfStake(0))))
-        );
-    }
+        .expect_error(ApiError::from(pos::Error::NotBonded));
 }
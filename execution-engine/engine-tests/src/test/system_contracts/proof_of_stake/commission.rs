@@ -0,0 +1,239 @@
+use std::{collections::BTreeMap, convert::TryFrom};
+
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, GenesisConfig, POS_REWARDS_PURSE},
+    SYSTEM_ACCOUNT_ADDR,
+};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT},
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
+};
+use proof_of_stake::{ERA_LENGTH, MAX_COMMISSION_RATE};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, CLValue, Key, URef, U512};
+
+const CONTRACT_POS_SET_COMMISSION: &str = "pos_set_commission.wasm";
+const CONTRACT_POS_DISTRIBUTE_REWARDS: &str = "pos_distribute_rewards.wasm";
+const REWARD_SPLITS_KEY: &str = "reward_splits";
+
+const UNBONDED_ACCOUNT_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const VALIDATOR_2_ADDR: PublicKey = PublicKey::ed25519_from([43; 32]);
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const VALIDATOR_2_STAKE: u64 = 150_000;
+
+fn genesis_config() -> GenesisConfig {
+    let validator = GenesisAccount::new(
+        DEFAULT_ACCOUNT_ADDR,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+    );
+    let validator_2 = GenesisAccount::new(
+        VALIDATOR_2_ADDR,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::new(VALIDATOR_2_STAKE.into()),
+    );
+    let unbonded_account = GenesisAccount::new(
+        UNBONDED_ACCOUNT_ADDR,
+        Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+        Motes::zero(),
+    );
+    utils::create_genesis_config(vec![validator, validator_2, unbonded_account])
+}
+
+fn set_commission_at(
+    builder: &mut InMemoryWasmTestBuilder,
+    account: PublicKey,
+    rate: u32,
+    block_height: u64,
+) {
+    let exec_request =
+        ExecuteRequestBuilder::standard(account, CONTRACT_POS_SET_COMMISSION, (rate,))
+            .with_block_height(block_height)
+            .build();
+    builder.exec(exec_request).commit();
+}
+
+/// Distributes the rewards purse as the system account, and returns the balance of the rewards
+/// purse beforehand along with each validator's reward split into its commission and its
+/// delegators' share.
+fn distribute_rewards(
+    builder: &mut InMemoryWasmTestBuilder,
+) -> (U512, BTreeMap<PublicKey, (U512, U512)>) {
+    // Funds the system account from an account which isn't a validator, so that the validators'
+    // balances only change by their rewards.
+    builder.transfer(UNBONDED_ACCOUNT_ADDR, SYSTEM_ACCOUNT_ADDR, *DEFAULT_PAYMENT);
+    let total_reward = get_rewards_purse_balance(builder);
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(SYSTEM_ACCOUNT_ADDR, CONTRACT_POS_DISTRIBUTE_REWARDS, ())
+            .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let stored_value = builder
+        .query(
+            None,
+            Key::Account(SYSTEM_ACCOUNT_ADDR),
+            &[REWARD_SPLITS_KEY],
+        )
+        .expect("should have reward splits");
+    let splits = CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be the reward splits");
+    (total_reward, splits)
+}
+
+fn get_rewards_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_REWARDS_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should find rewards purse");
+    builder.get_purse_balance(purse)
+}
+
+#[ignore]
+#[test]
+fn should_default_to_zero_commission() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    assert_eq!(builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR), 0);
+}
+
+#[ignore]
+#[test]
+fn should_set_zero_and_full_commission() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    set_commission_at(&mut builder, DEFAULT_ACCOUNT_ADDR, 0, 0);
+    builder.expect_success();
+    assert_eq!(builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR), 0);
+
+    set_commission_at(
+        &mut builder,
+        DEFAULT_ACCOUNT_ADDR,
+        MAX_COMMISSION_RATE,
+        ERA_LENGTH,
+    );
+    builder.expect_success();
+    assert_eq!(
+        builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR),
+        MAX_COMMISSION_RATE
+    );
+}
+
+#[ignore]
+#[test]
+fn should_change_commission_once_per_era() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    set_commission_at(&mut builder, DEFAULT_ACCOUNT_ADDR, 500, 1);
+    builder.expect_success();
+    assert_eq!(builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR), 500);
+
+    // A second change within the same era is rejected and leaves the rate as it was.
+    set_commission_at(&mut builder, DEFAULT_ACCOUNT_ADDR, 1_000, ERA_LENGTH - 1);
+    builder.expect_error(ApiError::from(pos::Error::CommissionAlreadySetThisEra));
+    assert_eq!(builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR), 500);
+
+    set_commission_at(&mut builder, DEFAULT_ACCOUNT_ADDR, 1_000, ERA_LENGTH);
+    builder.expect_success();
+    assert_eq!(
+        builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR),
+        1_000
+    );
+}
+
+#[ignore]
+#[test]
+fn should_reject_commission_above_max_rate() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    set_commission_at(
+        &mut builder,
+        DEFAULT_ACCOUNT_ADDR,
+        MAX_COMMISSION_RATE + 1,
+        0,
+    );
+    builder.expect_error(ApiError::from(pos::Error::CommissionRateTooHigh));
+    assert_eq!(builder.get_validator_commission(DEFAULT_ACCOUNT_ADDR), 0);
+}
+
+#[ignore]
+#[test]
+fn should_reject_commission_of_unbonded_account() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    set_commission_at(&mut builder, UNBONDED_ACCOUNT_ADDR, 500, 0);
+    builder.expect_error(ApiError::from(pos::Error::NotBonded));
+    assert_eq!(builder.get_validator_commission(UNBONDED_ACCOUNT_ADDR), 0);
+}
+
+#[ignore]
+#[test]
+fn should_split_rewards_by_commission_rate() {
+    const COMMISSION_RATE: u32 = 2_500;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+    set_commission_at(&mut builder, DEFAULT_ACCOUNT_ADDR, COMMISSION_RATE, 0);
+    builder.expect_success();
+
+    let validator_1_balance = builder.get_account_balance(DEFAULT_ACCOUNT_ADDR);
+    let validator_2_balance = builder.get_account_balance(VALIDATOR_2_ADDR);
+
+    let (total_reward, splits) = distribute_rewards(&mut builder);
+    assert!(!total_reward.is_zero());
+
+    // Each validator's share of the rewards is proportional to its stake.
+    let total_stake = U512::from(GENESIS_VALIDATOR_STAKE + VALIDATOR_2_STAKE);
+    let validator_1_share = total_reward * U512::from(GENESIS_VALIDATOR_STAKE) / total_stake;
+    let validator_2_share = total_reward * U512::from(VALIDATOR_2_STAKE) / total_stake;
+
+    // Validator 1 takes a quarter of its share as commission, rounded down.
+    let validator_1_commission = validator_1_share / 4;
+    let mut expected_splits = BTreeMap::new();
+    expected_splits.insert(
+        DEFAULT_ACCOUNT_ADDR,
+        (
+            validator_1_commission,
+            validator_1_share - validator_1_commission,
+        ),
+    );
+    expected_splits.insert(VALIDATOR_2_ADDR, (U512::zero(), validator_2_share));
+    assert_eq!(splits, expected_splits);
+
+    // Without delegators, each validator is paid its whole share.
+    assert_eq!(
+        builder.get_account_balance(DEFAULT_ACCOUNT_ADDR),
+        validator_1_balance + validator_1_share
+    );
+    assert_eq!(
+        builder.get_account_balance(VALIDATOR_2_ADDR),
+        validator_2_balance + validator_2_share
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_distribute_rewards_from_user_account() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config());
+
+    let exec_request =
+        ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, CONTRACT_POS_DISTRIBUTE_REWARDS, ())
+            .build();
+    builder
+        .exec(exec_request)
+        .commit()
+        .expect_error(ApiError::from(
+            pos::Error::SystemFunctionCalledByUserAccount,
+        ));
+}
@@ -4,17 +4,18 @@ use std::collections::HashMap;
 use engine_core::engine_state::genesis::GenesisAccount;
 use engine_shared::motes::Motes;
 use engine_test_support::{
-    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    internal::{
+        utils, ExecuteRequestBuilder, GenesisAccountBuilder, InMemoryWasmTestBuilder,
+        DEFAULT_ACCOUNTS,
+    },
     DEFAULT_ACCOUNT_ADDR,
 };
 use types::{account::PublicKey, U512};
 
 const CONTRACT_LOCAL_STATE: &str = "local_state.wasm";
-const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 const ACCOUNT_1_BALANCE: u64 = 2000;
 const ACCOUNT_1_BOND: u64 = 1000;
 
-const ACCOUNT_2_ADDR: PublicKey = PublicKey::ed25519_from([2u8; 32]);
 const ACCOUNT_2_BALANCE: u64 = 2000;
 const ACCOUNT_2_BOND: u64 = 200;
 
@@ -23,16 +24,14 @@ const ACCOUNT_2_BOND: u64 = 200;
 fn should_return_bonded_validators() {
     let accounts = {
         let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
-        let account_1 = GenesisAccount::new(
-            ACCOUNT_1_ADDR,
-            Motes::new(ACCOUNT_1_BALANCE.into()),
-            Motes::new(ACCOUNT_1_BOND.into()),
-        );
-        let account_2 = GenesisAccount::new(
-            ACCOUNT_2_ADDR,
-            Motes::new(ACCOUNT_2_BALANCE.into()),
-            Motes::new(ACCOUNT_2_BOND.into()),
-        );
+        let account_1 = GenesisAccountBuilder::named("alice")
+            .balance(ACCOUNT_1_BALANCE)
+            .bonded(ACCOUNT_1_BOND)
+            .build();
+        let account_2 = GenesisAccountBuilder::named("bob")
+            .balance(ACCOUNT_2_BALANCE)
+            .bonded(ACCOUNT_2_BOND)
+            .build();
         tmp.push(account_1);
         tmp.push(account_2);
         tmp
@@ -47,7 +46,7 @@ fn should_return_bonded_validators() {
         .run_genesis(&genesis_config)
         .exec(exec_request)
         .commit()
-        .get_bonded_validators()[0]
+        .get_bonded_validators_history()[0]
         .clone();
 
     let expected: HashMap<PublicKey, U512> = {
@@ -200,6 +200,7 @@ fn get_named_account_balance(
 
     let account: Account = builder
         .query(None, account_key, &[])
+        .map_err(|error| format!("{:?}", error))
         .and_then(|v| v.try_into().map_err(|error| format!("{:?}", error)))
         .expect("should find balance uref");
 
@@ -1,5 +1,9 @@
+mod bonded_validators;
 mod bonding;
+mod commission;
 mod commit_validators;
 mod finalize_payment;
 mod get_payment_purse;
 mod refund_purse;
+mod slashing;
+mod unbonding_delay;
@@ -0,0 +1,160 @@
+use std::convert::TryFrom;
+
+use engine_core::engine_state::{
+    genesis::{GenesisAccount, POS_BONDING_PURSE, POS_COMMUNITY_PURSE},
+    SYSTEM_ACCOUNT_ADDR,
+};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, CLValue, Key, URef, U512};
+
+const CONTRACT_POS_SLASH: &str = "pos_slash.wasm";
+const SLASHED_AMOUNTS_KEY: &str = "slashed_amounts";
+
+const VALIDATOR_1_ADDR: PublicKey = PublicKey::ed25519_from([42; 32]);
+const VALIDATOR_2_ADDR: PublicKey = PublicKey::ed25519_from([43; 32]);
+const VALIDATOR_3_ADDR: PublicKey = PublicKey::ed25519_from([44; 32]);
+const UNKNOWN_ADDR: PublicKey = PublicKey::ed25519_from([45; 32]);
+const VALIDATOR_1_STAKE: u64 = 50_000;
+const VALIDATOR_2_STAKE: u64 = 70_000;
+const VALIDATOR_3_STAKE: u64 = 90_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut accounts: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+    for &(public_key, stake) in &[
+        (VALIDATOR_1_ADDR, VALIDATOR_1_STAKE),
+        (VALIDATOR_2_ADDR, VALIDATOR_2_STAKE),
+        (VALIDATOR_3_ADDR, VALIDATOR_3_STAKE),
+    ] {
+        let stake = Motes::new(stake.into());
+        accounts.push(GenesisAccount::new(public_key, stake, stake));
+    }
+    let genesis_config = utils::create_genesis_config(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&genesis_config);
+    builder
+}
+
+fn get_pos_purse_balance(builder: &InMemoryWasmTestBuilder, purse_name: &str) -> U512 {
+    let purse: URef = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(purse_name)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should find PoS purse");
+    builder.get_purse_balance(purse)
+}
+
+fn slash(builder: &mut InMemoryWasmTestBuilder, validators: Vec<PublicKey>) -> Vec<U512> {
+    builder.exec_commit_as_system_account(CONTRACT_POS_SLASH, (validators,));
+    let system_account = Key::Account(SYSTEM_ACCOUNT_ADDR);
+    let stored_value = builder
+        .query(None, system_account, &[SLASHED_AMOUNTS_KEY])
+        .expect("should have slashed amounts");
+    CLValue::try_from(stored_value)
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be the slashed amounts")
+}
+
+#[ignore]
+#[test]
+fn should_slash_single_validator() {
+    let mut builder = setup();
+    let bonding_purse_balance = get_pos_purse_balance(&builder, POS_BONDING_PURSE);
+    assert!(get_pos_purse_balance(&builder, POS_COMMUNITY_PURSE).is_zero());
+
+    let slashed_amounts = slash(&mut builder, vec![VALIDATOR_1_ADDR]);
+
+    let stake = U512::from(VALIDATOR_1_STAKE);
+    assert_eq!(slashed_amounts, vec![stake]);
+    assert_eq!(builder.get_validator_stake(VALIDATOR_1_ADDR), None);
+    assert_eq!(
+        builder.get_validator_stake(VALIDATOR_2_ADDR),
+        Some(U512::from(VALIDATOR_2_STAKE))
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_BONDING_PURSE),
+        bonding_purse_balance - stake
+    );
+    assert_eq!(get_pos_purse_balance(&builder, POS_COMMUNITY_PURSE), stake);
+}
+
+#[ignore]
+#[test]
+fn should_slash_batch_of_validators() {
+    let mut builder = setup();
+
+    let slashed_amounts = slash(&mut builder, vec![VALIDATOR_1_ADDR, VALIDATOR_2_ADDR]);
+
+    let stakes = vec![U512::from(VALIDATOR_1_STAKE), U512::from(VALIDATOR_2_STAKE)];
+    assert_eq!(slashed_amounts, stakes);
+    assert_eq!(builder.get_validator_stake(VALIDATOR_1_ADDR), None);
+    assert_eq!(builder.get_validator_stake(VALIDATOR_2_ADDR), None);
+    assert_eq!(
+        builder.get_validator_stake(VALIDATOR_3_ADDR),
+        Some(U512::from(VALIDATOR_3_STAKE))
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_COMMUNITY_PURSE),
+        stakes[0] + stakes[1]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_record_zero_for_unknown_and_already_slashed_validators() {
+    let mut builder = setup();
+    slash(&mut builder, vec![VALIDATOR_1_ADDR]);
+
+    let slashed_amounts = slash(
+        &mut builder,
+        vec![UNKNOWN_ADDR, VALIDATOR_1_ADDR, VALIDATOR_2_ADDR],
+    );
+
+    let stake_1 = U512::from(VALIDATOR_1_STAKE);
+    let stake_2 = U512::from(VALIDATOR_2_STAKE);
+    assert_eq!(slashed_amounts, vec![U512::zero(), U512::zero(), stake_2]);
+    assert_eq!(builder.get_validator_stake(VALIDATOR_2_ADDR), None);
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_COMMUNITY_PURSE),
+        stake_1 + stake_2
+    );
+
+    let slashed_amounts = slash(&mut builder, vec![UNKNOWN_ADDR]);
+
+    assert_eq!(slashed_amounts, vec![U512::zero()]);
+    assert_eq!(
+        builder.get_validator_stake(VALIDATOR_3_ADDR),
+        Some(U512::from(VALIDATOR_3_STAKE))
+    );
+    assert_eq!(
+        get_pos_purse_balance(&builder, POS_COMMUNITY_PURSE),
+        stake_1 + stake_2
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_slash_when_called_by_user_account() {
+    let mut builder = setup();
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_SLASH,
+        (vec![VALIDATOR_1_ADDR],),
+    )
+    .build();
+    let expected_error = ApiError::from(pos::Error::SystemFunctionCalledByUserAccount);
+    builder.exec(exec_request).expect_error(expected_error);
+
+    assert_eq!(
+        builder.get_validator_stake(VALIDATOR_1_ADDR),
+        Some(U512::from(VALIDATOR_1_STAKE))
+    );
+}
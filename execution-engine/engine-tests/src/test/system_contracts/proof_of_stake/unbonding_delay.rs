@@ -0,0 +1,160 @@
+use engine_core::engine_state::genesis::{GenesisAccount, GenesisConfig, POS_BONDING_PURSE};
+use engine_shared::motes::Motes;
+use engine_test_support::{
+    internal::{
+        utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
+        DEFAULT_ENGINE_CONFIG, DEFAULT_GAS_PRICE, DEFAULT_WASM_COSTS,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, system_contract_errors::pos, ApiError, Key, U512};
+
+const CONTRACT_POS_BONDING: &str = "pos_bonding.wasm";
+
+const UNBONDING_DELAY: u64 = 10;
+
+const GENESIS_VALIDATOR_STAKE: u64 = 50_000;
+const GENESIS_ACCOUNT_STAKE: u64 = 100_000;
+const GENESIS_ACCOUNT_UNBOND_1: u64 = 30_000;
+const GENESIS_ACCOUNT_UNBOND_2: u64 = 20_000;
+
+const TEST_BOND: &str = "bond";
+const TEST_UNBOND: &str = "unbond";
+const TEST_WITHDRAW_UNBONDED: &str = "withdraw_unbonded";
+
+fn genesis_config_with_unbonding_delay(unbonding_delay: u64) -> GenesisConfig {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account = GenesisAccount::new(
+            PublicKey::ed25519_from([42; 32]),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()) * Motes::new(2.into()),
+            Motes::new(GENESIS_VALIDATOR_STAKE.into()),
+        );
+        tmp.push(account);
+        tmp
+    };
+    utils::create_custom_genesis_config(
+        accounts,
+        *DEFAULT_WASM_COSTS,
+        unbonding_delay,
+        DEFAULT_GAS_PRICE,
+    )
+}
+
+fn get_pos_bonding_purse_balance(builder: &InMemoryWasmTestBuilder) -> U512 {
+    let purse = builder
+        .get_pos_contract()
+        .named_keys()
+        .get(POS_BONDING_PURSE)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should find PoS bonding purse");
+    builder.get_purse_balance(purse)
+}
+
+fn unbond_at(builder: &mut InMemoryWasmTestBuilder, amount: u64, block_height: u64) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_UNBOND), Some(U512::from(amount))),
+    )
+    .with_block_height(block_height)
+    .build();
+    builder.exec_commit(exec_request);
+}
+
+fn withdraw_at(builder: &mut InMemoryWasmTestBuilder, block_height: u64) -> bool {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_WITHDRAW_UNBONDED),),
+    )
+    .with_block_height(block_height)
+    .build();
+    builder.exec(exec_request).commit().is_error()
+}
+
+fn assert_no_withdrawals_due(builder: &mut InMemoryWasmTestBuilder) {
+    builder.expect_error(ApiError::from(pos::Error::NoWithdrawalsDue));
+}
+
+/// Runs genesis with the given unbonding delay and bonds `GENESIS_ACCOUNT_STAKE` from the default
+/// account.
+fn setup_bonded(unbonding_delay: u64) -> InMemoryWasmTestBuilder {
+    let genesis_config = genesis_config_with_unbonding_delay(unbonding_delay);
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_POS_BONDING,
+        (String::from(TEST_BOND), U512::from(GENESIS_ACCOUNT_STAKE)),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .with_engine_config(DEFAULT_ENGINE_CONFIG.with_enable_bonding(true))
+        .run_genesis(&genesis_config)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_fail_to_withdraw_before_unbonding_delay() {
+    let mut builder = setup_bonded(UNBONDING_DELAY);
+    let bonded_balance = get_pos_bonding_purse_balance(&builder);
+
+    unbond_at(&mut builder, GENESIS_ACCOUNT_UNBOND_1, 5);
+    // The stake is queued for withdrawal rather than paid out.
+    assert_eq!(get_pos_bonding_purse_balance(&builder), bonded_balance);
+
+    assert!(withdraw_at(&mut builder, 5));
+    assert_no_withdrawals_due(&mut builder);
+    assert!(withdraw_at(&mut builder, 5 + UNBONDING_DELAY - 1));
+    assert_no_withdrawals_due(&mut builder);
+    assert_eq!(get_pos_bonding_purse_balance(&builder), bonded_balance);
+}
+
+#[ignore]
+#[test]
+fn should_withdraw_exactly_at_unbonding_delay() {
+    let mut builder = setup_bonded(UNBONDING_DELAY);
+    let bonded_balance = get_pos_bonding_purse_balance(&builder);
+
+    unbond_at(&mut builder, GENESIS_ACCOUNT_UNBOND_1, 5);
+    assert!(!withdraw_at(&mut builder, 5 + UNBONDING_DELAY));
+    assert_eq!(
+        get_pos_bonding_purse_balance(&builder),
+        bonded_balance - GENESIS_ACCOUNT_UNBOND_1
+    );
+
+    // Nothing is left to withdraw.
+    assert!(withdraw_at(&mut builder, 5 + UNBONDING_DELAY));
+    assert_no_withdrawals_due(&mut builder);
+}
+
+#[ignore]
+#[test]
+fn should_withdraw_multiple_unbondings_as_they_become_due() {
+    let mut builder = setup_bonded(UNBONDING_DELAY);
+    let bonded_balance = get_pos_bonding_purse_balance(&builder);
+
+    unbond_at(&mut builder, GENESIS_ACCOUNT_UNBOND_1, 5);
+    unbond_at(&mut builder, GENESIS_ACCOUNT_UNBOND_2, 8);
+
+    assert!(!withdraw_at(&mut builder, 5 + UNBONDING_DELAY));
+    assert_eq!(
+        get_pos_bonding_purse_balance(&builder),
+        bonded_balance - GENESIS_ACCOUNT_UNBOND_1
+    );
+
+    assert!(withdraw_at(&mut builder, 7 + UNBONDING_DELAY));
+    assert_no_withdrawals_due(&mut builder);
+
+    assert!(!withdraw_at(&mut builder, 8 + UNBONDING_DELAY));
+    assert_eq!(
+        get_pos_bonding_purse_balance(&builder),
+        bonded_balance - GENESIS_ACCOUNT_UNBOND_1 - GENESIS_ACCOUNT_UNBOND_2
+    );
+}
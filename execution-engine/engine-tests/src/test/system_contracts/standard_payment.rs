@@ -7,13 +7,15 @@ use engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE,
 };
-use types::{account::PublicKey, Key, URef, U512};
+use types::{account::PublicKey, AccessRights, Key, URef, U512};
 
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([42u8; 32]);
 const DO_NOTHING_WASM: &str = "do_nothing.wasm";
 const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
 const REVERT_WASM: &str = "revert.wasm";
 const ENDLESS_LOOP_WASM: &str = "endless_loop.wasm";
+const CREATE_PURSE_01_WASM: &str = "create_purse_01.wasm";
+const REFUND_PURSE_NAME: &str = "refund_purse";
 
 #[ignore]
 #[test]
@@ -701,3 +703,143 @@ fn independent_standard_payments_should_not_write_the_same_keys() {
 
     assert_eq!(common_write_keys.count(), 0);
 }
+
+/// Runs genesis and creates a purse named `REFUND_PURSE_NAME` in the default account.
+fn setup_refund_purse() -> (InMemoryWasmTestBuilder, URef) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CREATE_PURSE_01_WASM,
+        (String::from(REFUND_PURSE_NAME),),
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request)
+        .expect_success()
+        .commit();
+
+    let refund_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .named_keys()
+        .get(REFUND_PURSE_NAME)
+        .and_then(Key::as_uref)
+        .cloned()
+        .expect("should have refund purse");
+    (builder, refund_purse)
+}
+
+fn exec_with_refund_purse(
+    builder: &mut InMemoryWasmTestBuilder,
+    payment_amount: U512,
+    refund_purse: URef,
+    deploy_hash: [u8; 32],
+) -> Motes {
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, ())
+            .with_empty_payment_bytes((payment_amount, refund_purse))
+            .with_authorization_keys(&[DEFAULT_ACCOUNT_KEY])
+            .with_deploy_hash(deploy_hash)
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("there should be a response");
+    let gas = utils::get_success_result(response).cost();
+    Motes::from_gas(gas, CONV_RATE).expect("should have motes")
+}
+
+fn get_last_notes(builder: &InMemoryWasmTestBuilder) -> Vec<String> {
+    let response = builder
+        .get_exec_response(builder.get_exec_responses_count() - 1)
+        .expect("there should be a response");
+    utils::get_success_result(response).notes().to_vec()
+}
+
+#[ignore]
+#[test]
+fn should_refund_unspent_payment_to_refund_purse() {
+    let payment_amount = U512::from(10_000_000);
+    let (mut builder, refund_purse) = setup_refund_purse();
+
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .main_purse();
+    let main_purse_balance_before = builder.get_purse_balance(main_purse);
+    assert!(builder.get_purse_balance(refund_purse).is_zero());
+
+    let cost = exec_with_refund_purse(&mut builder, payment_amount, refund_purse, [1; 32]);
+
+    assert_eq!(
+        builder.get_purse_balance(refund_purse),
+        payment_amount - cost.value(),
+        "unspent payment should go to the refund purse"
+    );
+    assert_eq!(
+        builder.get_purse_balance(main_purse),
+        main_purse_balance_before - payment_amount,
+        "main purse should only be charged the payment amount"
+    );
+    assert!(get_last_notes(&builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_refund_to_main_purse_when_refund_purse_is_invalid() {
+    let payment_amount = U512::from(10_000_000);
+    let (mut builder, refund_purse) = setup_refund_purse();
+    let invalid_purse = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+
+    let main_purse = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should get genesis account")
+        .main_purse();
+    let main_purse_balance_before = builder.get_purse_balance(main_purse);
+
+    let cost = exec_with_refund_purse(&mut builder, payment_amount, invalid_purse, [1; 32]);
+
+    assert_eq!(
+        builder.get_purse_balance(main_purse),
+        main_purse_balance_before - cost.value(),
+        "unspent payment should go back to the main purse"
+    );
+    assert!(builder.get_purse_balance(refund_purse).is_zero());
+
+    let notes = get_last_notes(&builder);
+    assert_eq!(notes.len(), 1, "expected a single note, got: {:?}", notes);
+    assert!(
+        notes[0].contains("is invalid"),
+        "unexpected note: {}",
+        notes[0]
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_refund_when_payment_is_fully_spent() {
+    let (mut builder, refund_purse) = setup_refund_purse();
+
+    // Measure the cost of the deploy, then pay exactly that much
+    let cost = exec_with_refund_purse(&mut builder, U512::from(10_000_000), refund_purse, [1; 32]);
+    let refund_purse_balance = builder.get_purse_balance(refund_purse);
+
+    let exact_cost = exec_with_refund_purse(&mut builder, cost.value(), refund_purse, [2; 32]);
+
+    assert_eq!(exact_cost, cost);
+    assert_eq!(
+        builder.get_purse_balance(refund_purse),
+        refund_purse_balance,
+        "nothing should have been refunded"
+    );
+    assert!(get_last_notes(&builder).is_empty());
+}
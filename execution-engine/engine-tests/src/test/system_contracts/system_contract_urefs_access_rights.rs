@@ -10,6 +10,7 @@ use types::{account::PublicKey, U512};
 
 const CONTRACT_CHECK_SYSTEM_CONTRACT_UREFS_ACCESS_RIGHTS: &str =
     "check_system_contract_urefs_access_rights.wasm";
+const CONTRACT_GET_SYSTEM_CONTRACT: &str = "get_system_contract.wasm";
 const CONTRACT_TRANSFER_PURSE_TO_ACCOUNT: &str = "transfer_purse_to_account.wasm";
 const ACCOUNT_1_ADDR: PublicKey = PublicKey::ed25519_from([1u8; 32]);
 
@@ -44,3 +45,35 @@ fn should_have_read_only_access_to_system_contract_urefs() {
         .commit()
         .expect_success();
 }
+
+#[ignore]
+#[test]
+fn should_get_read_only_system_contracts_without_named_keys() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    let exec_request_1 = ExecuteRequestBuilder::standard(
+        DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_PURSE_TO_ACCOUNT,
+        (ACCOUNT_1_ADDR, *ACCOUNT_1_INITIAL_BALANCE),
+    )
+    .build();
+
+    let exec_request_2 =
+        ExecuteRequestBuilder::standard(ACCOUNT_1_ADDR, CONTRACT_GET_SYSTEM_CONTRACT, ()).build();
+
+    builder
+        .run_genesis(&DEFAULT_GENESIS_CONFIG)
+        .exec(exec_request_1)
+        .expect_success()
+        .commit()
+        .exec(exec_request_2)
+        .expect_success()
+        .commit();
+
+    // The contract removed the account's named keys referring to the system contracts before
+    // getting and calling them
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account");
+    assert!(account_1.named_keys().is_empty());
+}
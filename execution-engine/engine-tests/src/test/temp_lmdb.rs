@@ -0,0 +1,82 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    thread,
+};
+
+use engine_test_support::{
+    internal::{ExecuteRequestBuilder, LmdbWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use types::{account::PublicKey, U512};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const TRANSFER_AMOUNT: u64 = 1_000_000_000;
+const THREAD_COUNT: u8 = 16;
+
+/// Returns the account which the thread at `index` transfers to.  The addresses start well above
+/// those of the default and system accounts so that none of them is an existing account.
+fn target_of(index: u8) -> PublicKey {
+    PublicKey::ed25519_from([index + 100; 32])
+}
+
+#[ignore]
+#[test]
+fn should_run_genesis_concurrently_in_isolated_databases() {
+    let handles: Vec<_> = (0..THREAD_COUNT)
+        .map(|index| {
+            thread::spawn(move || {
+                let target = target_of(index);
+                let exec_request = ExecuteRequestBuilder::standard(
+                    DEFAULT_ACCOUNT_ADDR,
+                    CONTRACT_TRANSFER_TO_ACCOUNT,
+                    (target, U512::from(TRANSFER_AMOUNT)),
+                )
+                .build();
+
+                let mut builder = LmdbWasmTestBuilder::default();
+                builder
+                    .run_genesis(&DEFAULT_GENESIS_CONFIG)
+                    .exec(exec_request)
+                    .expect_success()
+                    .commit();
+
+                // Only this thread's transfer should be visible
+                for other_index in (0..THREAD_COUNT).filter(|other| *other != index) {
+                    assert!(builder.get_account(target_of(other_index)).is_none());
+                }
+                assert!(builder.get_account(target).is_some());
+
+                builder
+                    .temp_data_dir()
+                    .expect("should have temp data dir")
+                    .to_path_buf()
+            })
+        })
+        .collect();
+
+    let data_dirs: Vec<PathBuf> = handles
+        .into_iter()
+        .map(|handle| handle.join().expect("thread should not panic"))
+        .collect();
+
+    for (index, data_dir) in data_dirs.iter().enumerate() {
+        assert!(!data_dir.exists(), "temp dir should be removed on drop");
+        assert!(!data_dirs[index + 1..].contains(data_dir));
+    }
+}
+
+#[ignore]
+#[test]
+fn should_remove_temp_dir_when_test_panics() {
+    let mut data_dir = None;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let builder = LmdbWasmTestBuilder::default();
+        data_dir = builder.temp_data_dir().map(PathBuf::from);
+        panic!("test failure");
+    }));
+    assert!(result.is_err());
+
+    let data_dir = data_dir.expect("should have temp data dir");
+    assert!(!data_dir.exists(), "temp dir should be removed on unwind");
+}
@@ -3,8 +3,12 @@ use engine_test_support::{
     internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_CONFIG},
     DEFAULT_ACCOUNT_ADDR,
 };
-use types::CLValue;
+use types::{CLValue, Key, URef};
 
+const COUNT_KEY: &str = "count";
+const COUNTER_STORED_CALLER_CONTRACT_NAME: &str = "counter_stored_caller";
+const COUNTER_STORED_CONTRACT_NAME: &str = "counter_stored";
+const COUNTER_STORED_UPGRADER_CONTRACT_NAME: &str = "counter_stored_upgrader";
 const DO_NOTHING_STORED_CALLER_CONTRACT_NAME: &str = "do_nothing_stored_caller";
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
 const DO_NOTHING_STORED_UPGRADER_CONTRACT_NAME: &str = "do_nothing_stored_upgrader";
@@ -19,6 +23,7 @@ const PURSE_1: &str = "purse_1";
 const PURSE_HOLDER_STORED_CALLER_CONTRACT_NAME: &str = "purse_holder_stored_caller";
 const PURSE_HOLDER_STORED_CONTRACT_NAME: &str = "purse_holder_stored";
 const PURSE_HOLDER_STORED_UPGRADER_CONTRACT_NAME: &str = "purse_holder_stored_upgrader";
+const STEP_KEY: &str = "step";
 const STORE_AT_UREF: &str = "uref";
 const TOTAL_PURSES: usize = 3;
 
@@ -474,3 +479,91 @@ fn should_maintain_local_state_across_upgrade() {
         "local state should include elements from the original version and the upgraded version"
     );
 }
+
+fn get_count(builder: &InMemoryWasmTestBuilder, counter_uref: URef) -> i32 {
+    let count = builder
+        .query(None, counter_uref.into(), &[COUNT_KEY])
+        .expect("should have count");
+    match count {
+        StoredValue::CLValue(cl_value) => cl_value.into_t().expect("should be i32"),
+        _ => panic!("count should be a CLValue"),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_upgrade_contract_behaviour_in_place() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&*DEFAULT_GENESIS_CONFIG);
+
+    // store counter contract
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", COUNTER_STORED_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, &contract_name, ()).build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let counter_uref = *builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(COUNTER_STORED_CONTRACT_NAME)
+        .expect("should have stored uref")
+        .as_uref()
+        .expect("should have uref");
+
+    let call_counter = |builder: &mut InMemoryWasmTestBuilder| {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", COUNTER_STORED_CALLER_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, &contract_name, (counter_uref,))
+                .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    };
+
+    call_counter(&mut builder);
+    assert_eq!(get_count(&builder, counter_uref), 1);
+
+    let original_contract = builder
+        .get_contract(counter_uref)
+        .expect("should have contract");
+
+    // upgrade counter contract to increment by two
+    {
+        let exec_request = {
+            let contract_name = format!("{}.wasm", COUNTER_STORED_UPGRADER_CONTRACT_NAME);
+            ExecuteRequestBuilder::standard(DEFAULT_ACCOUNT_ADDR, &contract_name, (counter_uref,))
+                .build()
+        };
+
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    let upgraded_contract = builder
+        .get_contract(counter_uref)
+        .expect("should still have contract under the same uref");
+    assert_ne!(upgraded_contract.bytes(), original_contract.bytes());
+    assert_eq!(
+        upgraded_contract.named_keys().get(COUNT_KEY),
+        original_contract.named_keys().get(COUNT_KEY),
+        "upgraded contract should keep its named keys"
+    );
+    assert!(upgraded_contract.named_keys().contains_key(STEP_KEY));
+
+    // the same uref now refers to the upgraded contract
+    call_counter(&mut builder);
+    assert_eq!(get_count(&builder, counter_uref), 3);
+
+    let account = builder
+        .get_account(DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+    assert_eq!(
+        account.named_keys().get(COUNTER_STORED_CONTRACT_NAME),
+        Some(&Key::URef(counter_uref))
+    );
+}
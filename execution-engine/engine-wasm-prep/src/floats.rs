@@ -0,0 +1,134 @@
+use parity_wasm::elements::{
+    BlockType, External, FunctionType, ImportCountType, Instruction, Module, Type, ValueType,
+};
+
+use crate::PreprocessingError;
+
+fn is_float_type(value_type: ValueType) -> bool {
+    match value_type {
+        ValueType::F32 | ValueType::F64 => true,
+        _ => false,
+    }
+}
+
+fn is_float_block_type(block_type: BlockType) -> bool {
+    match block_type {
+        BlockType::Value(value_type) => is_float_type(value_type),
+        BlockType::NoResult => false,
+    }
+}
+
+fn is_float_signature(function_type: &FunctionType) -> bool {
+    function_type.params().iter().cloned().any(is_float_type)
+        || function_type.return_type().map_or(false, is_float_type)
+}
+
+fn is_float_instruction(instruction: &Instruction) -> bool {
+    use Instruction::*;
+
+    match instruction {
+        Block(block_type) | Loop(block_type) | If(block_type) => is_float_block_type(*block_type),
+
+        F32Load(..) | F64Load(..) | F32Store(..) | F64Store(..) | F32Const(_) | F64Const(_) => true,
+
+        F32Eq | F32Ne | F32Lt | F32Gt | F32Le | F32Ge | F64Eq | F64Ne | F64Lt | F64Gt | F64Le
+        | F64Ge => true,
+
+        F32Abs | F32Neg | F32Ceil | F32Floor | F32Trunc | F32Nearest | F32Sqrt | F32Add
+        | F32Sub | F32Mul | F32Div | F32Min | F32Max | F32Copysign => true,
+
+        F64Abs | F64Neg | F64Ceil | F64Floor | F64Trunc | F64Nearest | F64Sqrt | F64Add
+        | F64Sub | F64Mul | F64Div | F64Min | F64Max | F64Copysign => true,
+
+        I32TruncSF32 | I32TruncUF32 | I32TruncSF64 | I32TruncUF64 | I64TruncSF32 | I64TruncUF32
+        | I64TruncSF64 | I64TruncUF64 => true,
+
+        F32ConvertSI32 | F32ConvertUI32 | F32ConvertSI64 | F32ConvertUI64 | F32DemoteF64
+        | F64ConvertSI32 | F64ConvertUI32 | F64ConvertSI64 | F64ConvertUI64 | F64PromoteF32 => true,
+
+        I32ReinterpretF32 | I64ReinterpretF64 | F32ReinterpretI32 | F64ReinterpretI64 => true,
+
+        _ => false,
+    }
+}
+
+/// Returns an error if `module` contains any floating point instructions or types.
+///
+/// Function indices in the returned errors are in the module's function index space, i.e. they
+/// count imported functions first.
+pub(crate) fn ensure_no_floats(module: &Module) -> Result<(), PreprocessingError> {
+    let types = module
+        .type_section()
+        .map(|type_section| type_section.types())
+        .unwrap_or_default();
+    let is_float_type_ref = |type_ref: u32| match types.get(type_ref as usize) {
+        Some(Type::Function(function_type)) => is_float_signature(function_type),
+        None => false,
+    };
+
+    let imported_signatures = module
+        .import_section()
+        .map(|import_section| import_section.entries())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|import_entry| match import_entry.external() {
+            External::Function(type_ref) => Some(*type_ref),
+            _ => None,
+        });
+    for (function_index, type_ref) in imported_signatures.enumerate() {
+        if is_float_type_ref(type_ref) {
+            return Err(PreprocessingError::FloatType { function_index });
+        }
+    }
+
+    let imported_function_count = module.import_count(ImportCountType::Function);
+
+    let functions = module
+        .function_section()
+        .map(|function_section| function_section.entries())
+        .unwrap_or_default();
+    for (index, function) in functions.iter().enumerate() {
+        if is_float_type_ref(function.type_ref()) {
+            let function_index = imported_function_count + index;
+            return Err(PreprocessingError::FloatType { function_index });
+        }
+    }
+
+    let bodies = module
+        .code_section()
+        .map(|code_section| code_section.bodies())
+        .unwrap_or_default();
+    for (index, body) in bodies.iter().enumerate() {
+        let function_index = imported_function_count + index;
+        if body
+            .locals()
+            .iter()
+            .any(|local| is_float_type(local.value_type()))
+        {
+            return Err(PreprocessingError::FloatType { function_index });
+        }
+        let float_instruction = body
+            .code()
+            .elements()
+            .iter()
+            .find(|instruction| is_float_instruction(instruction));
+        if let Some(instruction) = float_instruction {
+            return Err(PreprocessingError::FloatInstruction {
+                function_index,
+                opcode: instruction.to_string(),
+            });
+        }
+    }
+
+    let globals = module
+        .global_section()
+        .map(|global_section| global_section.entries())
+        .unwrap_or_default();
+    for (global_index, global) in globals.iter().enumerate() {
+        if is_float_type(global.global_type().content_type()) {
+            return Err(PreprocessingError::FloatGlobal { global_index });
+        }
+    }
+
+    Ok(())
+}
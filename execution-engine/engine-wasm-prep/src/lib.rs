@@ -1,3 +1,6 @@
+mod floats;
+mod memory_limits;
+mod stack_overflow;
 pub mod wasm_costs;
 
 use std::fmt::{self, Display, Formatter};
@@ -15,6 +18,31 @@ pub enum PreprocessingError {
     Deserialize(String),
     OperationForbiddenByGasRules,
     StackLimiter,
+    /// The function at `function_index` contains the floating point instruction `opcode`.
+    FloatInstruction {
+        function_index: usize,
+        opcode: String,
+    },
+    /// The signature or locals of the function at `function_index` use a floating point type.
+    FloatType {
+        function_index: usize,
+    },
+    /// The global at `global_index` has a floating point type.
+    FloatGlobal {
+        global_index: usize,
+    },
+    /// A memory of the module declares an initial size of `declared` pages, above the limit of
+    /// `limit` pages.
+    InitialMemoryTooLarge {
+        declared: u32,
+        limit: u32,
+    },
+    /// A memory of the module declares a maximum size of `declared` pages, above the limit of
+    /// `limit` pages.
+    MaximumMemoryTooLarge {
+        declared: u32,
+        limit: u32,
+    },
 }
 
 impl From<elements::Error> for PreprocessingError {
@@ -29,6 +57,34 @@ impl Display for PreprocessingError {
             PreprocessingError::Deserialize(error) => write!(f, "Deserialization error: {}", error),
             PreprocessingError::OperationForbiddenByGasRules => write!(f, "Encountered operation forbidden by gas rules. Consult instruction -> metering config map"),
             PreprocessingError::StackLimiter => write!(f, "Stack limiter error"),
+            PreprocessingError::FloatInstruction {
+                function_index,
+                opcode,
+            } => write!(
+                f,
+                "Function {} contains forbidden floating point instruction {}",
+                function_index, opcode
+            ),
+            PreprocessingError::FloatType { function_index } => write!(
+                f,
+                "Function {} uses a forbidden floating point type",
+                function_index
+            ),
+            PreprocessingError::FloatGlobal { global_index } => write!(
+                f,
+                "Global {} has a forbidden floating point type",
+                global_index
+            ),
+            PreprocessingError::InitialMemoryTooLarge { declared, limit } => write!(
+                f,
+                "Initial memory of {} pages exceeds the limit of {} pages",
+                declared, limit
+            ),
+            PreprocessingError::MaximumMemoryTooLarge { declared, limit } => write!(
+                f,
+                "Maximum memory of {} pages exceeds the limit of {} pages",
+                declared, limit
+            ),
         }
     }
 }
@@ -37,6 +93,8 @@ pub struct Preprocessor {
     wasm_costs: WasmCosts,
     // Number of memory pages.
     mem_pages: u32,
+    // Whether floating point instructions and types are accepted.
+    allow_floats: bool,
 }
 
 impl Preprocessor {
@@ -44,17 +102,46 @@ impl Preprocessor {
         Self {
             wasm_costs,
             mem_pages: MEM_PAGES,
+            allow_floats: false,
         }
     }
 
+    /// Sets whether modules using floating point instructions or types are accepted.
+    ///
+    /// Floating point behavior isn't deterministic across Wasm runtimes, so this should only be
+    /// enabled in test environments.
+    pub fn with_allow_floats(mut self, allow_floats: bool) -> Self {
+        self.allow_floats = allow_floats;
+        self
+    }
+
+    /// Returns the costs modules are metered with.
+    pub fn wasm_costs(&self) -> &WasmCosts {
+        &self.wasm_costs
+    }
+
+    /// Returns whether modules using floating point instructions or types are accepted.
+    pub fn allow_floats(&self) -> bool {
+        self.allow_floats
+    }
+
     pub fn preprocess(&self, module_bytes: &[u8]) -> Result<Module, PreprocessingError> {
         let module = deserialize(module_bytes)?;
+        if !self.allow_floats {
+            floats::ensure_no_floats(&module)?;
+        }
+        memory_limits::ensure_memory_limits(
+            &module,
+            self.wasm_costs.max_initial_memory_pages,
+            self.wasm_costs.max_memory_pages,
+        )?;
         let module = pwasm_utils::externalize_mem(module, None, self.mem_pages);
-        let module = pwasm_utils::inject_gas_counter(module, &self.wasm_costs.to_set())
+        let rules = wasm_costs::to_set(&self.wasm_costs, self.allow_floats);
+        let module = pwasm_utils::inject_gas_counter(module, &rules)
             .map_err(|_| PreprocessingError::OperationForbiddenByGasRules)?;
         let module = stack_height::inject_limiter(module, self.wasm_costs.max_stack_height)
             .map_err(|_| PreprocessingError::StackLimiter)?;
-        Ok(module)
+        stack_overflow::externalize_stack_overflow(module)
     }
 }
 
@@ -62,3 +149,226 @@ impl Preprocessor {
 pub fn deserialize(module_bytes: &[u8]) -> Result<Module, PreprocessingError> {
     parity_wasm::deserialize_buffer::<Module>(module_bytes).map_err(Into::into)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The `unreachable` makes the operand stack polymorphic, so `f64.add` is the first float
+    // instruction in the function.
+    const F64_ADD_WAT: &str = r#"
+(module
+    (type (;0;) (func (param i32)))
+    (type (;1;) (func))
+    (import "env" "revert" (func (;0;) (type 0)))
+    (func (;1;) (type 1)
+      unreachable
+      f64.add
+      drop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 1)))
+"#;
+
+    const F64_ARITHMETIC_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      f64.const 1
+      f64.const 2
+      f64.add
+      drop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+    const F32_PARAM_WAT: &str = r#"
+(module
+    (type (;0;) (func (param f32)))
+    (func (;0;) (type 0)
+      nop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+    const INTEGER_ONLY_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      i64.const 1
+      i64.const 2
+      i64.add
+      drop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+    // Calling a function with locals from an exported one makes the limiter instrument the call.
+    const LIMITED_CALL_WAT: &str = r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      call 1
+      unreachable)
+    (func (;1;) (type 0)
+      (local i64 i64)
+      nop)
+    (memory (;0;) 1)
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#;
+
+    fn memory_wat(limits: &str) -> String {
+        format!(
+            r#"
+(module
+    (type (;0;) (func))
+    (func (;0;) (type 0)
+      nop)
+    (memory (;0;) {})
+    (export "memory" (memory 0))
+    (export "call" (func 0)))
+"#,
+            limits
+        )
+    }
+
+    fn wasm_costs() -> WasmCosts {
+        WasmCosts {
+            regular: 1,
+            div: 16,
+            mul: 4,
+            mem: 2,
+            initial_mem: 4096,
+            grow_mem: 8192,
+            memcpy: 1,
+            max_stack_height: 64 * 1024,
+            opcodes_mul: 3,
+            opcodes_div: 8,
+            storage_per_byte: 0,
+            native_transfer: 0,
+            hash_per_byte: 0,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 0,
+        }
+    }
+
+    fn preprocess(wat: &str, allow_floats: bool) -> Result<Module, PreprocessingError> {
+        let module_bytes = wabt::wat2wasm(wat).expect("should parse wat");
+        Preprocessor::new(wasm_costs())
+            .with_allow_floats(allow_floats)
+            .preprocess(&module_bytes)
+    }
+
+    #[test]
+    fn should_reject_float_instruction() {
+        match preprocess(F64_ADD_WAT, false) {
+            Err(PreprocessingError::FloatInstruction {
+                function_index,
+                opcode,
+            }) => {
+                // Imported functions come first in the function index space.
+                assert_eq!(function_index, 1);
+                assert_eq!(opcode, "f64.add");
+            }
+            other => panic!("expected float instruction error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_float_signature() {
+        match preprocess(F32_PARAM_WAT, false) {
+            Err(PreprocessingError::FloatType { function_index }) => assert_eq!(function_index, 0),
+            other => panic!("expected float type error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_accept_floats_when_allowed() {
+        assert!(preprocess(F64_ARITHMETIC_WAT, false).is_err());
+        assert!(preprocess(F64_ARITHMETIC_WAT, true).is_ok());
+        assert!(preprocess(F32_PARAM_WAT, true).is_ok());
+    }
+
+    #[test]
+    fn should_accept_integer_only_module() {
+        assert!(preprocess(INTEGER_ONLY_WAT, false).is_ok());
+    }
+
+    #[test]
+    fn should_reject_excessive_initial_memory() {
+        match preprocess(&memory_wat("10000"), false) {
+            Err(error @ PreprocessingError::InitialMemoryTooLarge { .. }) => assert_eq!(
+                error.to_string(),
+                "Initial memory of 10000 pages exceeds the limit of 64 pages"
+            ),
+            other => panic!("expected initial memory error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_reject_excessive_maximum_memory() {
+        match preprocess(&memory_wat("1 65"), false) {
+            Err(PreprocessingError::MaximumMemoryTooLarge { declared, limit }) => {
+                assert_eq!(declared, 65);
+                assert_eq!(limit, 64);
+            }
+            other => panic!("expected maximum memory error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_accept_memory_within_limits() {
+        assert!(preprocess(&memory_wat("64"), false).is_ok());
+        assert!(preprocess(&memory_wat("1 64"), false).is_ok());
+    }
+
+    #[test]
+    fn should_call_stack_overflow_when_stack_height_limit_is_exceeded() {
+        use parity_wasm::elements::{External, Instruction};
+
+        let module = preprocess(LIMITED_CALL_WAT, false).expect("should preprocess");
+
+        let imports = module
+            .import_section()
+            .expect("should have imports")
+            .entries();
+        let stack_overflow_index = imports
+            .iter()
+            .filter(|entry| match entry.external() {
+                External::Function(_) => true,
+                _ => false,
+            })
+            .position(|entry| entry.field() == stack_overflow::STACK_OVERFLOW_FUNCTION)
+            .expect("should import stack_overflow") as u32;
+
+        let code = module.code_section().expect("should have code").bodies();
+        let count = |expected: &Instruction| {
+            code.iter()
+                .flat_map(|body| body.code().elements())
+                .filter(|instruction| *instruction == expected)
+                .count()
+        };
+        assert!(count(&Instruction::Call(stack_overflow_index)) > 0);
+        // Only the contract's own `unreachable` is left.
+        assert_eq!(count(&Instruction::Unreachable), 1);
+    }
+
+    #[test]
+    fn should_not_limit_memory_when_limits_are_zero() {
+        let wasm_costs = WasmCosts {
+            max_initial_memory_pages: 0,
+            max_memory_pages: 0,
+            preprocess_per_byte: 0,
+            ..wasm_costs()
+        };
+        let module_bytes = wabt::wat2wasm(memory_wat("100 200")).expect("should parse wat");
+        assert!(Preprocessor::new(wasm_costs)
+            .preprocess(&module_bytes)
+            .is_ok());
+    }
+}
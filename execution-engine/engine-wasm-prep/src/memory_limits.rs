@@ -0,0 +1,50 @@
+use parity_wasm::elements::{External, MemoryType, Module};
+
+use crate::PreprocessingError;
+
+/// Returns the memories the module defines itself followed by the ones it imports.
+fn declared_memories(module: &Module) -> impl Iterator<Item = &MemoryType> {
+    let own_memories = module
+        .memory_section()
+        .map(|section| section.entries())
+        .unwrap_or(&[])
+        .iter();
+    let imported_memories = module
+        .import_section()
+        .map(|section| section.entries())
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(|entry| match entry.external() {
+            External::Memory(memory_type) => Some(memory_type),
+            _ => None,
+        });
+    own_memories.chain(imported_memories)
+}
+
+/// Ensures no memory of the module declares more than `max_initial_pages` as its initial size or
+/// more than `max_pages` as its maximum size, where a limit of zero means no limit.
+pub(crate) fn ensure_memory_limits(
+    module: &Module,
+    max_initial_pages: u32,
+    max_pages: u32,
+) -> Result<(), PreprocessingError> {
+    for memory_type in declared_memories(module) {
+        let limits = memory_type.limits();
+        if max_initial_pages != 0 && limits.initial() > max_initial_pages {
+            return Err(PreprocessingError::InitialMemoryTooLarge {
+                declared: limits.initial(),
+                limit: max_initial_pages,
+            });
+        }
+        match limits.maximum() {
+            Some(maximum) if max_pages != 0 && maximum > max_pages => {
+                return Err(PreprocessingError::MaximumMemoryTooLarge {
+                    declared: maximum,
+                    limit: max_pages,
+                });
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
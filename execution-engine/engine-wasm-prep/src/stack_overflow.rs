@@ -0,0 +1,124 @@
+use parity_wasm::elements::{
+    BlockType, External, FunctionType, ImportCountType, ImportEntry, Instruction, Internal, Module,
+    Section, Type,
+};
+
+use crate::PreprocessingError;
+
+/// The name of the host function called by the stack height limiter when the limit is exceeded.
+pub(crate) const STACK_OVERFLOW_FUNCTION: &str = "stack_overflow";
+
+const ENV_MODULE: &str = "env";
+
+/// Adds an import of [`STACK_OVERFLOW_FUNCTION`] taking and returning nothing, returning its
+/// function index.
+fn add_import(module: &mut Module) -> Result<u32, PreprocessingError> {
+    let function_index = module.import_count(ImportCountType::Function) as u32;
+
+    let types = module
+        .type_section_mut()
+        .ok_or(PreprocessingError::StackLimiter)?
+        .types_mut();
+    let signature = Type::Function(FunctionType::new(Vec::new(), None));
+    let type_index = match types.iter().position(|existing| *existing == signature) {
+        Some(type_index) => type_index,
+        None => {
+            types.push(signature);
+            types.len() - 1
+        }
+    };
+
+    module
+        .import_section_mut()
+        .ok_or(PreprocessingError::StackLimiter)?
+        .entries_mut()
+        .push(ImportEntry::new(
+            ENV_MODULE.to_string(),
+            STACK_OVERFLOW_FUNCTION.to_string(),
+            External::Function(type_index as u32),
+        ));
+
+    Ok(function_index)
+}
+
+/// Makes room for a function imported at `inserted_index` in the function index space.
+fn shift(function_index: &mut u32, inserted_index: u32) {
+    if *function_index >= inserted_index {
+        *function_index += 1;
+    }
+}
+
+/// Replaces the `unreachable` executed by the limiter's check of `stack_height_global`, i.e.
+/// `get_global; i32.const; i32.gt_u; if; unreachable`, with a call to `stack_overflow_function`.
+fn replace_limiter_traps(
+    instructions: &mut [Instruction],
+    stack_height_global: u32,
+    stack_overflow_function: u32,
+) {
+    use Instruction::{GetGlobal, I32Const, I32GtU, If, Unreachable};
+
+    for index in 4..instructions.len() {
+        let is_limiter_trap = match &instructions[index - 4..=index] {
+            [GetGlobal(global), I32Const(_), I32GtU, If(BlockType::NoResult), Unreachable] => {
+                *global == stack_height_global
+            }
+            _ => false,
+        };
+        if is_limiter_trap {
+            instructions[index] = Instruction::Call(stack_overflow_function);
+        }
+    }
+}
+
+/// Makes the limiter injected by `pwasm_utils::stack_height` call the imported
+/// [`STACK_OVERFLOW_FUNCTION`] when the stack height limit is exceeded, rather than trapping with
+/// `unreachable`, so that the host can tell a stack overflow apart from a contract's own
+/// `unreachable`.
+///
+/// Must be applied right after the limiter is injected, as its stack height counter is then the
+/// last global of the module.
+pub(crate) fn externalize_stack_overflow(mut module: Module) -> Result<Module, PreprocessingError> {
+    let stack_height_global = match module.global_section() {
+        Some(section) if !section.entries().is_empty() => section.entries().len() as u32 - 1,
+        _ => return Err(PreprocessingError::StackLimiter),
+    };
+    let stack_overflow_function = add_import(&mut module)?;
+
+    for section in module.sections_mut() {
+        match section {
+            Section::Code(code_section) => {
+                for body in code_section.bodies_mut() {
+                    let instructions = body.code_mut().elements_mut();
+                    for instruction in instructions.iter_mut() {
+                        if let Instruction::Call(function_index) = instruction {
+                            shift(function_index, stack_overflow_function);
+                        }
+                    }
+                    replace_limiter_traps(
+                        instructions,
+                        stack_height_global,
+                        stack_overflow_function,
+                    );
+                }
+            }
+            Section::Export(export_section) => {
+                for entry in export_section.entries_mut() {
+                    if let Internal::Function(function_index) = entry.internal_mut() {
+                        shift(function_index, stack_overflow_function);
+                    }
+                }
+            }
+            Section::Element(element_section) => {
+                for segment in element_section.entries_mut() {
+                    for function_index in segment.members_mut() {
+                        shift(function_index, stack_overflow_function);
+                    }
+                }
+            }
+            Section::Start(function_index) => shift(function_index, stack_overflow_function),
+            _ => (),
+        }
+    }
+
+    Ok(module)
+}
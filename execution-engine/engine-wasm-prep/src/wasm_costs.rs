@@ -2,101 +2,23 @@ use std::collections::BTreeMap;
 
 use pwasm_utils::rules::{InstructionType, Metering, Set};
 
-use types::bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH};
-
-const NUM_FIELDS: usize = 10;
-pub const WASM_COSTS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U32_SERIALIZED_LENGTH;
-
-// Taken (partially) from parity-ethereum
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
-pub struct WasmCosts {
-    /// Default opcode cost
-    pub regular: u32,
-    /// Div operations multiplier.
-    pub div: u32,
-    /// Mul operations multiplier.
-    pub mul: u32,
-    /// Memory (load/store) operations multiplier.
-    pub mem: u32,
-    /// Memory stipend. Amount of free memory (in 64kb pages) each contract can
-    /// use for stack.
-    pub initial_mem: u32,
-    /// Grow memory cost, per page (64kb)
-    pub grow_mem: u32,
-    /// Memory copy cost, per byte
-    pub memcpy: u32,
-    /// Max stack height (native WebAssembly stack limiter)
-    pub max_stack_height: u32,
-    /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
-    /// `opcodes_div`
-    pub opcodes_mul: u32,
-    /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
-    /// `opcodes_div`
-    pub opcodes_div: u32,
-}
-
-impl WasmCosts {
-    pub(crate) fn to_set(&self) -> Set {
-        let meterings = {
-            let mut tmp = BTreeMap::new();
-            tmp.insert(InstructionType::Load, Metering::Fixed(self.mem));
-            tmp.insert(InstructionType::Store, Metering::Fixed(self.mem));
-            tmp.insert(InstructionType::Div, Metering::Fixed(self.div));
-            tmp.insert(InstructionType::Mul, Metering::Fixed(self.mul));
-            tmp
-        };
-        Set::new(self.regular, meterings)
-            .with_grow_cost(self.grow_mem)
-            .with_forbidden_floats()
-    }
-}
-
-impl ToBytes for WasmCosts {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-        ret.append(&mut self.regular.to_bytes()?);
-        ret.append(&mut self.div.to_bytes()?);
-        ret.append(&mut self.mul.to_bytes()?);
-        ret.append(&mut self.mem.to_bytes()?);
-        ret.append(&mut self.initial_mem.to_bytes()?);
-        ret.append(&mut self.grow_mem.to_bytes()?);
-        ret.append(&mut self.memcpy.to_bytes()?);
-        ret.append(&mut self.max_stack_height.to_bytes()?);
-        ret.append(&mut self.opcodes_mul.to_bytes()?);
-        ret.append(&mut self.opcodes_div.to_bytes()?);
-        Ok(ret)
-    }
-
-    fn serialized_length(&self) -> usize {
-        WASM_COSTS_SERIALIZED_LENGTH
-    }
-}
-
-impl FromBytes for WasmCosts {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (regular, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
-        let (div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (initial_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (grow_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (memcpy, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (max_stack_height, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (opcodes_mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let (opcodes_div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
-        let wasm_costs = WasmCosts {
-            regular,
-            div,
-            mul,
-            mem,
-            initial_mem,
-            grow_mem,
-            memcpy,
-            max_stack_height,
-            opcodes_mul,
-            opcodes_div,
-        };
-        Ok((wasm_costs, rem))
+pub use types::{WasmCosts, LEGACY_WASM_COSTS_SERIALIZED_LENGTH, WASM_COSTS_SERIALIZED_LENGTH};
+
+/// Returns the gas rules for injecting the gas counter into a module.
+pub(crate) fn to_set(wasm_costs: &WasmCosts, allow_floats: bool) -> Set {
+    let meterings = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(InstructionType::Load, Metering::Fixed(wasm_costs.mem));
+        tmp.insert(InstructionType::Store, Metering::Fixed(wasm_costs.mem));
+        tmp.insert(InstructionType::Div, Metering::Fixed(wasm_costs.div));
+        tmp.insert(InstructionType::Mul, Metering::Fixed(wasm_costs.mul));
+        tmp
+    };
+    let set = Set::new(wasm_costs.regular, meterings).with_grow_cost(wasm_costs.grow_mem);
+    if allow_floats {
+        set
+    } else {
+        set.with_forbidden_floats()
     }
 }
 
@@ -117,6 +39,12 @@ pub mod gens {
             max_stack_height in num::u32::ANY,
             opcodes_mul in num::u32::ANY,
             opcodes_div in num::u32::ANY,
+            storage_per_byte in num::u32::ANY,
+            native_transfer in num::u32::ANY,
+            hash_per_byte in num::u32::ANY,
+            max_initial_memory_pages in num::u32::ANY,
+            max_memory_pages in num::u32::ANY,
+            preprocess_per_byte in num::u32::ANY,
         ) -> WasmCosts {
             WasmCosts {
                 regular,
@@ -129,6 +57,12 @@ pub mod gens {
                 max_stack_height,
                 opcodes_mul,
                 opcodes_div,
+                storage_per_byte,
+                native_transfer,
+                hash_per_byte,
+                max_initial_memory_pages,
+                max_memory_pages,
+                preprocess_per_byte,
             }
         }
     }
@@ -155,6 +89,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 3,
             opcodes_div: 8,
+            storage_per_byte: 1,
+            native_transfer: 10_000,
+            hash_per_byte: 1,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 1,
         }
     }
 
@@ -170,6 +110,12 @@ mod tests {
             max_stack_height: 64 * 1024,
             opcodes_mul: 1,
             opcodes_div: 1,
+            storage_per_byte: 0,
+            native_transfer: 0,
+            hash_per_byte: 0,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 1,
         }
     }
 
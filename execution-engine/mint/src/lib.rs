@@ -5,12 +5,72 @@ mod storage_provider;
 
 use core::convert::TryFrom;
 
-use types::{account::PublicKey, system_contract_errors::mint::Error, Key, URef, U512};
+use types::{
+    account::PublicKey, system_contract_errors::mint::Error, AccessRights, CallStackElement, Key,
+    URef, KEY_HASH_LENGTH, U512, UREF_ADDR_LENGTH,
+};
 
 pub use crate::{runtime_provider::RuntimeProvider, storage_provider::StorageProvider};
 
 const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0; 32]);
 
+/// The name of the mint's named key under which the total supply of tokens is held.
+pub const TOTAL_SUPPLY_KEY: &str = "total_supply";
+
+/// A record of a transfer made into a purse under an id via [`Mint::transfer_with_id`], holding
+/// the address of the target purse, the id and the amount transferred.
+pub type TransferRecord = ([u8; UREF_ADDR_LENGTH], u64, U512);
+
+/// Tags the local keys of the mint under which contract purses are recorded, keeping them apart
+/// from the purse addresses the balances are recorded under.
+const CONTRACT_PURSE_TAG: u8 = 0;
+
+/// Returns the local key of the mint under which the purse bound to the contract with the given
+/// hash is recorded by [`Mint::get_or_create_contract_purse`].
+pub fn contract_purse_local_key(
+    contract_hash: [u8; KEY_HASH_LENGTH],
+) -> (u8, [u8; KEY_HASH_LENGTH]) {
+    (CONTRACT_PURSE_TAG, contract_hash)
+}
+
+/// Tags the local keys of the mint under which sub-purses are recorded.
+const SUB_PURSE_TAG: u8 = 1;
+
+/// A record of a sub-purse created via [`Mint::create_sub_purse`], holding the address of its
+/// parent purse and the amount which may still be pulled from the parent.
+pub type SubPurseRecord = ([u8; UREF_ADDR_LENGTH], U512);
+
+/// Returns the local key of the mint under which the [`SubPurseRecord`] of the sub-purse with the
+/// given address is held.
+pub fn sub_purse_local_key(sub_purse_addr: [u8; UREF_ADDR_LENGTH]) -> (u8, [u8; UREF_ADDR_LENGTH]) {
+    (SUB_PURSE_TAG, sub_purse_addr)
+}
+
+/// Tags the local keys of the mint under which transfer records are held.
+const TRANSFER_RECORD_TAG: u8 = 2;
+
+/// Returns the local key of the mint under which the [`TransferRecord`] of the `index`th transfer
+/// made into the purse with the given address under `id` is held, counting from zero.
+pub fn transfer_record_local_key(
+    purse_addr: [u8; UREF_ADDR_LENGTH],
+    id: u64,
+    index: u64,
+) -> (u8, [u8; UREF_ADDR_LENGTH], (u64, u64)) {
+    (TRANSFER_RECORD_TAG, purse_addr, (id, index))
+}
+
+/// Tags the local keys of the mint under which the numbers of transfers made under an id are held.
+const TRANSFER_COUNT_TAG: u8 = 3;
+
+/// Returns the local key of the mint under which the number of transfers made into the purse with
+/// the given address under `id` is held.
+pub fn transfer_count_local_key(
+    purse_addr: [u8; UREF_ADDR_LENGTH],
+    id: u64,
+) -> (u8, [u8; UREF_ADDR_LENGTH], u64) {
+    (TRANSFER_COUNT_TAG, purse_addr, id)
+}
+
 pub trait Mint: RuntimeProvider + StorageProvider {
     fn mint(&mut self, initial_balance: U512) -> Result<URef, Error> {
         let caller = self.get_caller();
@@ -28,9 +88,59 @@ pub trait Mint: RuntimeProvider + StorageProvider {
         // store association between purse id and balance uref
         self.write_local(purse_key.addr(), balance_uref);
 
+        self.increase_total_supply(initial_balance)?;
+
         Ok(purse_key)
     }
 
+    /// Returns the total supply of tokens, i.e. the sum of the balances of all purses.  Fails with
+    /// [`Error::Storage`] if there is no record of the total supply.
+    fn total_supply(&mut self) -> Result<U512, Error> {
+        let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::Storage),
+        };
+        match self.read(total_supply_uref)? {
+            Some(total_supply) => Ok(total_supply),
+            None => Err(Error::Storage),
+        }
+    }
+
+    /// Adds `amount` to the total supply, creating the record of it if this is the first time
+    /// a purse has been minted, even with a zero balance.
+    fn increase_total_supply(&mut self, amount: U512) -> Result<(), Error> {
+        match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(_) if amount.is_zero() => Ok(()),
+            Some(key) => {
+                let total_supply_uref: URef =
+                    TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?;
+                self.add(total_supply_uref, amount)
+            }
+            None => {
+                let total_supply_uref = self.new_uref(amount);
+                self.put_key(TOTAL_SUPPLY_KEY, total_supply_uref.into());
+                Ok(())
+            }
+        }
+    }
+
+    /// Subtracts `amount` from the total supply.  Fails with [`Error::Storage`] if there is no
+    /// record of the total supply.
+    fn decrease_total_supply(&mut self, amount: U512) -> Result<(), Error> {
+        let total_supply_uref: URef = match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::Storage),
+        };
+        let total_supply: U512 = match self.read(total_supply_uref)? {
+            Some(total_supply) => total_supply,
+            None => return Err(Error::Storage),
+        };
+        let remaining_supply = total_supply
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientFunds)?;
+        self.write(total_supply_uref, remaining_supply)
+    }
+
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let balance_uref: URef = match self.read_local(&purse.addr())? {
             Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
@@ -54,15 +164,184 @@ pub trait Mint: RuntimeProvider + StorageProvider {
             Some(source_value) => source_value,
             None => return Err(Error::SourceNotFound),
         };
-        if amount > source_value {
-            return Err(Error::InsufficientFunds);
+        // A sub-purse pulls what it can towards the amount from its parent first, as far as the
+        // cap and the parent's balance allow, and pays the rest out of its own balance.
+        let sub_purse_key = sub_purse_local_key(source.addr());
+        let maybe_sub_purse_record: Option<SubPurseRecord> = self.read_local(&sub_purse_key)?;
+        let mut pulled = U512::zero();
+        let mut parent_update = None;
+        if let Some((parent_addr, remaining_cap)) = maybe_sub_purse_record {
+            let maybe_parent_bal: Option<Key> = self.read_local(&parent_addr)?;
+            if let Some(key) = maybe_parent_bal {
+                let parent_bal: URef =
+                    TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?;
+                let parent_value: U512 = self.read(parent_bal)?.unwrap_or_default();
+                pulled = amount.min(remaining_cap).min(parent_value);
+                if !pulled.is_zero() {
+                    let record: SubPurseRecord = (parent_addr, remaining_cap - pulled);
+                    parent_update = Some((parent_bal, parent_value - pulled, record));
+                }
+            }
         }
+        // The source balance is only written once the subtraction is known not to underflow, so a
+        // transfer of more than the balance leaves no transforms behind.
+        let remaining_value = source_value
+            .checked_sub(amount - pulled)
+            .ok_or(Error::InsufficientFunds)?;
         let dest_bal: URef = match self.read_local(&dest.addr())? {
             Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
             None => return Err(Error::DestNotFound),
         };
-        self.write(source_bal, source_value - amount)?;
+        if let Some((parent_bal, remaining_parent_value, record)) = parent_update {
+            self.write(parent_bal, remaining_parent_value)?;
+            self.write_local(sub_purse_key, record);
+        }
+        self.write(source_bal, remaining_value)?;
         self.add(dest_bal, amount)?;
         Ok(())
     }
+
+    /// Transfers `amount` from `source` to `dest` and, if given an `id`, records the transfer under
+    /// that id.  Repeated ids are all kept, as each transfer is given a record of its own.
+    fn transfer_with_id(
+        &mut self,
+        source: URef,
+        dest: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<(), Error> {
+        self.transfer(source, dest, amount)?;
+        if let Some(id) = id {
+            let count_key = transfer_count_local_key(dest.addr(), id);
+            let index: u64 = self.read_local(&count_key)?.unwrap_or_default();
+            let record: TransferRecord = (dest.addr(), id, amount);
+            self.write_local(transfer_record_local_key(dest.addr(), id, index), record);
+            self.write_local(count_key, index + 1);
+        }
+        Ok(())
+    }
+
+    /// Deletes `purse`, moving its remaining balance to `refund_to`.  The purse's balance is
+    /// removed from global state, so the purse can't be used again afterwards.
+    fn delete_purse(&mut self, purse: URef, refund_to: URef) -> Result<(), Error> {
+        if purse.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Err(Error::InvalidAccessRights);
+        }
+        if purse.addr() == refund_to.addr() {
+            return Err(Error::RefundToDeletedPurse);
+        }
+        let balance_uref: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::PurseNotFound),
+        };
+        let balance: U512 = match self.read(balance_uref)? {
+            Some(balance) => balance,
+            None => return Err(Error::PurseNotFound),
+        };
+        // Deleting a sub-purse revokes it first, so that the refund doesn't pull from the parent.
+        let sub_purse_key = sub_purse_local_key(purse.addr());
+        let maybe_sub_purse_record: Option<SubPurseRecord> = self.read_local(&sub_purse_key)?;
+        if maybe_sub_purse_record.is_some() {
+            self.delete_local(sub_purse_key);
+        }
+        self.transfer(purse, refund_to, balance)?;
+        self.delete(balance_uref)?;
+        self.delete_local(purse.addr());
+        self.remove_key(&purse.remove_access_rights().as_string());
+        Ok(())
+    }
+
+    /// Destroys `amount` motes held in `purse`, reducing the total supply by the same amount.
+    ///
+    /// Requires full access rights on `purse`.  Burning more than its balance fails with
+    /// [`Error::InsufficientFunds`], as a transfer would, and leaves no transforms behind.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        if purse.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance_uref: URef = match self.read_local(&purse.addr())? {
+            Some(key) => TryFrom::<Key>::try_from(key).map_err(|_| Error::InvalidAccessRights)?,
+            None => return Err(Error::PurseNotFound),
+        };
+        let balance: U512 = match self.read(balance_uref)? {
+            Some(balance) => balance,
+            None => return Err(Error::PurseNotFound),
+        };
+        let remaining_balance = balance
+            .checked_sub(amount)
+            .ok_or(Error::InsufficientFunds)?;
+        self.decrease_total_supply(amount)?;
+        self.write(balance_uref, remaining_balance)
+    }
+
+    /// Returns the purse bound to the contract with the given hash, creating an empty one the
+    /// first time it's asked for.
+    ///
+    /// Only the contract itself, when calling the mint directly, gets the purse with full access
+    /// rights.  Every other caller gets it with [`AccessRights::ADD`], enough to transfer into it.
+    fn get_or_create_contract_purse(
+        &mut self,
+        contract_hash: [u8; KEY_HASH_LENGTH],
+    ) -> Result<URef, Error> {
+        let local_key = contract_purse_local_key(contract_hash);
+        let purse: URef = match self.read_local(&local_key)? {
+            Some(purse) => purse,
+            None => {
+                let purse = self.mint(U512::zero())?;
+                self.write_local(local_key, purse);
+                purse
+            }
+        };
+        match self.get_immediate_caller() {
+            Some(CallStackElement::Contract {
+                contract_hash: caller_hash,
+                ..
+            }) if caller_hash == contract_hash => Ok(purse),
+            _ => Ok(purse.into_add_only()),
+        }
+    }
+
+    /// Creates an empty sub-purse of `parent`.  Transfers out of the sub-purse pull from `parent`
+    /// first, until `cap` motes in total have been pulled, and only then from the sub-purse's own
+    /// balance.
+    ///
+    /// Requires full access rights on `parent`.  Fails with [`Error::NestedSubPurse`] if `parent`
+    /// is itself a sub-purse.
+    fn create_sub_purse(&mut self, parent: URef, cap: U512) -> Result<URef, Error> {
+        if parent.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Err(Error::InvalidAccessRights);
+        }
+        let maybe_parent_bal: Option<Key> = self.read_local(&parent.addr())?;
+        if maybe_parent_bal.is_none() {
+            return Err(Error::PurseNotFound);
+        }
+        let maybe_parent_record: Option<SubPurseRecord> =
+            self.read_local(&sub_purse_local_key(parent.addr()))?;
+        if maybe_parent_record.is_some() {
+            return Err(Error::NestedSubPurse);
+        }
+        let sub_purse = self.mint(U512::zero())?;
+        let record: SubPurseRecord = (parent.addr(), cap);
+        self.write_local(sub_purse_local_key(sub_purse.addr()), record);
+        Ok(sub_purse)
+    }
+
+    /// Revokes `sub_purse`, so that transfers out of it no longer pull from `parent`.  Motes
+    /// already pulled from `parent` are left where they are.
+    ///
+    /// Requires full access rights on `parent`.  Fails with [`Error::SubPurseNotFound`] unless
+    /// `sub_purse` is an unrevoked sub-purse of `parent`.
+    fn revoke_sub_purse(&mut self, parent: URef, sub_purse: URef) -> Result<(), Error> {
+        if parent.access_rights() != AccessRights::READ_ADD_WRITE {
+            return Err(Error::InvalidAccessRights);
+        }
+        let local_key = sub_purse_local_key(sub_purse.addr());
+        match self.read_local::<_, SubPurseRecord>(&local_key)? {
+            Some((parent_addr, _)) if parent_addr == parent.addr() => {
+                self.delete_local(local_key);
+                Ok(())
+            }
+            _ => Err(Error::SubPurseNotFound),
+        }
+    }
 }
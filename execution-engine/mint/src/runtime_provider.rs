@@ -1,7 +1,15 @@
-use types::{account::PublicKey, Key};
+use types::{account::PublicKey, CallStackElement, Key};
 
 pub trait RuntimeProvider {
     fn get_caller(&self) -> PublicKey;
 
+    /// Returns the entity which called the mint directly, i.e. the second-to-last element of the
+    /// call stack, or `None` if the mint is executing at the top of the call stack.
+    fn get_immediate_caller(&self) -> Option<CallStackElement>;
+
+    fn get_key(&self, name: &str) -> Option<Key>;
+
     fn put_key(&mut self, name: &str, key: Key);
+
+    fn remove_key(&mut self, name: &str);
 }
@@ -19,4 +19,8 @@ pub trait StorageProvider {
     fn write<T: CLTyped + ToBytes>(&mut self, uref: URef, value: T) -> Result<(), Error>;
 
     fn add<T: CLTyped + ToBytes>(&mut self, uref: URef, value: T) -> Result<(), Error>;
+
+    fn delete_local<K: ToBytes>(&mut self, key: K);
+
+    fn delete(&mut self, uref: URef) -> Result<(), Error>;
 }
@@ -0,0 +1,152 @@
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::result;
+
+use types::{
+    account::PublicKey,
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U64_SERIALIZED_LENGTH},
+    system_contract_errors::pos::{Error, Result},
+    CLType, CLTyped, U512,
+};
+
+/// The prefix of the named keys under which the validators' commission rates are stored.
+const NAME_PREFIX: &str = "commission_";
+
+/// The highest commission rate, in basis points, i.e. 100%.
+pub const MAX_COMMISSION_RATE: u32 = 10_000;
+
+/// The number of blocks in an era.  A validator can set its commission rate at most once per era.
+pub const ERA_LENGTH: u64 = 100;
+
+/// Returns the era which the block at `block_height` belongs to.
+pub fn era_of(block_height: u64) -> u64 {
+    block_height / ERA_LENGTH
+}
+
+/// Returns the name of the named key under which `validator`'s commission rate is stored, in the
+/// form "commission_{validator_pk}".
+pub fn commission_key_name(validator: &PublicKey) -> String {
+    format!(
+        "{}{}",
+        NAME_PREFIX,
+        base16::encode_lower(&validator.as_bytes())
+    )
+}
+
+/// A validator's commission rate, and the era in which it was set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Commission {
+    /// The share of rewards taken by the validator, in basis points.
+    pub rate: u32,
+    pub era: u64,
+}
+
+impl Commission {
+    /// Returns the commission rate of `rate` basis points set in the block at `block_height`,
+    /// replacing `previous`.
+    pub fn new(previous: Option<Commission>, rate: u32, block_height: u64) -> Result<Commission> {
+        if rate > MAX_COMMISSION_RATE {
+            return Err(Error::CommissionRateTooHigh);
+        }
+        let era = era_of(block_height);
+        if let Some(previous) = previous {
+            if previous.era == era {
+                return Err(Error::CommissionAlreadySetThisEra);
+            }
+        }
+        Ok(Commission { rate, era })
+    }
+
+    /// Splits `reward` into the validator's commission and the remainder to be shared among its
+    /// delegators in proportion to their delegated stake.  The commission is rounded down.
+    pub fn split(&self, reward: U512) -> (U512, U512) {
+        let max_rate = U512::from(MAX_COMMISSION_RATE);
+        let rate = U512::from(self.rate);
+        // Divides first so that the multiplication can't overflow.
+        let commission = reward / max_rate * rate + reward % max_rate * rate / max_rate;
+        (commission, reward - commission)
+    }
+}
+
+impl ToBytes for Commission {
+    fn to_bytes(&self) -> result::Result<Vec<u8>, bytesrepr::Error> {
+        let mut bytes = bytesrepr::allocate_buffer(self)?;
+        bytes.append(&mut self.rate.to_bytes()?);
+        bytes.append(&mut self.era.to_bytes()?);
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U32_SERIALIZED_LENGTH + U64_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for Commission {
+    fn from_bytes(bytes: &[u8]) -> result::Result<(Self, &[u8]), bytesrepr::Error> {
+        let (rate, bytes) = u32::from_bytes(bytes)?;
+        let (era, bytes) = u64::from_bytes(bytes)?;
+        Ok((Commission { rate, era }, bytes))
+    }
+}
+
+impl CLTyped for Commission {
+    fn cl_type() -> CLType {
+        CLType::Tuple2([Box::new(CLType::U32), Box::new(CLType::U64)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{bytesrepr, system_contract_errors::pos::Error, U512};
+
+    use super::{Commission, ERA_LENGTH, MAX_COMMISSION_RATE};
+
+    fn split(rate: u32, reward: u64) -> (U512, U512) {
+        Commission { rate, era: 0 }.split(U512::from(reward))
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split(0, 1_000), (U512::zero(), U512::from(1_000)));
+        assert_eq!(
+            split(MAX_COMMISSION_RATE, 1_000),
+            (U512::from(1_000), U512::zero())
+        );
+        assert_eq!(split(2_500, 1_000), (U512::from(250), U512::from(750)));
+        // Rounded down in favour of the delegators
+        assert_eq!(split(3_333, 10), (U512::from(3), U512::from(7)));
+
+        let (commission, rest) = Commission { rate: 1, era: 0 }.split(U512::max_value());
+        assert_eq!(commission + rest, U512::max_value());
+    }
+
+    #[test]
+    fn test_set_once_per_era() {
+        let commission = Commission::new(None, 500, 1).expect("set in era 0");
+        assert_eq!(commission, Commission { rate: 500, era: 0 });
+        assert_eq!(
+            Commission::new(Some(commission), 600, ERA_LENGTH - 1),
+            Err(Error::CommissionAlreadySetThisEra)
+        );
+        assert_eq!(
+            Commission::new(Some(commission), 600, ERA_LENGTH),
+            Ok(Commission { rate: 600, era: 1 })
+        );
+    }
+
+    #[test]
+    fn test_set_out_of_range() {
+        assert_eq!(
+            Commission::new(None, MAX_COMMISSION_RATE + 1, 0),
+            Err(Error::CommissionRateTooHigh)
+        );
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let commission = Commission {
+            rate: MAX_COMMISSION_RATE,
+            era: 250,
+        };
+        bytesrepr::test_serialization_roundtrip(&commission);
+    }
+}
@@ -0,0 +1,12 @@
+use types::account::PublicKey;
+
+use crate::{commissions::Commission, Result};
+
+/// A `CommissionsProvider` that reads and writes the validators' commission rates to/from the
+/// contract's known urefs.
+pub trait CommissionsProvider {
+    /// Reads `validator`'s commission rate, or `None` if it never set one.
+    fn read_commission(&mut self, validator: &PublicKey) -> Result<Option<Commission>>;
+
+    fn write_commission(&mut self, validator: &PublicKey, commission: Commission);
+}
@@ -0,0 +1,7 @@
+use crate::Result;
+
+/// A `GasPriceProvider` that reads the gas price from the contract's known urefs.
+pub trait GasPriceProvider {
+    /// Reads the number of motes charged per unit of gas, set at genesis.
+    fn read_gas_price(&mut self) -> Result<u64>;
+}
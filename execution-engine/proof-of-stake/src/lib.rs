@@ -2,28 +2,50 @@
 
 extern crate alloc;
 
+mod commissions;
+mod commissions_provider;
+mod gas_price_provider;
 mod mint_provider;
 mod queue;
 mod queue_provider;
 mod runtime_provider;
 mod stakes;
 mod stakes_provider;
+mod withdrawals;
+mod withdrawals_provider;
 
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::marker::Sized;
 
 use types::{
     account::PublicKey,
     system_contract_errors::pos::{Error, Result},
-    AccessRights, TransferredTo, URef, U512,
+    AccessRights, URef, U512,
 };
 
 pub use crate::{
-    mint_provider::MintProvider, queue::Queue, queue_provider::QueueProvider,
-    runtime_provider::RuntimeProvider, stakes::Stakes, stakes_provider::StakesProvider,
+    commissions::{commission_key_name, era_of, Commission, ERA_LENGTH, MAX_COMMISSION_RATE},
+    commissions_provider::CommissionsProvider,
+    gas_price_provider::GasPriceProvider,
+    mint_provider::MintProvider,
+    queue::Queue,
+    queue_provider::QueueProvider,
+    runtime_provider::RuntimeProvider,
+    stakes::Stakes,
+    stakes_provider::StakesProvider,
+    withdrawals::Withdrawals,
+    withdrawals_provider::WithdrawalsProvider,
 };
 
 pub trait ProofOfStake:
-    MintProvider + QueueProvider + RuntimeProvider + StakesProvider + Sized
+    CommissionsProvider
+    + GasPriceProvider
+    + MintProvider
+    + QueueProvider
+    + RuntimeProvider
+    + StakesProvider
+    + WithdrawalsProvider
+    + Sized
 {
     fn bond(&mut self, validator: PublicKey, amount: U512, source: URef) -> Result<()> {
         if amount.is_zero() {
@@ -38,33 +60,45 @@ pub trait ProofOfStake:
         internal::bond(self, amount, validator, timestamp)?;
 
         // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            let _: TransferredTo = self
-                .transfer_purse_to_account(source, entry.validator, entry.amount)
-                .map_err(|_| Error::BondTransferFailed)?;
-        }
-        Ok(())
+        internal::step(self, timestamp)
     }
 
+    /// Decreases the validator's stake immediately, queueing the unbonded amount for withdrawal
+    /// via `withdraw_unbonded` once the unbonding delay has passed.  If the unbonding delay is
+    /// zero, the unbonded amount is paid out straight away.
     fn unbond(&mut self, validator: PublicKey, maybe_amount: Option<U512>) -> Result<()> {
-        let pos_purse = internal::get_bonding_purse(self)?;
-        let timestamp = self.get_block_time();
-        internal::unbond(self, maybe_amount, validator, timestamp)?;
+        let block_height = self.get_block_height();
+        internal::unbond(self, maybe_amount, validator, block_height)?;
 
-        // TODO: Remove this and set nonzero delays once the system calls `step` in each block.
-        let unbonds = internal::step(self, timestamp)?;
-        for entry in unbonds {
-            self.transfer_purse_to_account(pos_purse, entry.validator, entry.amount)
-                .map_err(|_| Error::UnbondTransferFailed)?;
+        if self.read_unbonding_delay()? == 0 {
+            let amount = internal::withdraw_unbonded(self, validator, block_height)?;
+            internal::pay_out(self, validator, amount)?;
         }
         Ok(())
     }
 
+    /// Pays out all of the validator's unbonded stake which has been queued for at least the
+    /// unbonding delay.
+    fn withdraw_unbonded(&mut self, validator: PublicKey) -> Result<()> {
+        let block_height = self.get_block_height();
+        let amount = internal::withdraw_unbonded(self, validator, block_height)?;
+        if amount.is_zero() {
+            return Err(Error::NoWithdrawalsDue);
+        }
+        internal::pay_out(self, validator, amount)
+    }
+
+    /// Sets the share of rewards the validator takes as commission before the rest is shared among
+    /// its delegators, in basis points.  The rate can be changed at most once per era.
+    fn set_commission(&mut self, validator: PublicKey, rate: u32) -> Result<()> {
+        let block_height = self.get_block_height();
+        internal::set_commission(self, validator, rate, block_height)
+    }
+
     fn get_payment_purse(&self) -> Result<URef> {
         let purse = internal::get_payment_purse(self)?;
         // Limit the access rights so only balance query and deposit are allowed.
-        Ok(URef::new(purse.addr(), AccessRights::READ_ADD))
+        Ok(purse.with_access_rights(AccessRights::READ_ADD))
     }
 
     fn set_refund_purse(&mut self, purse: URef) -> Result<()> {
@@ -82,23 +116,44 @@ pub trait ProofOfStake:
     fn finalize_payment(&mut self, amount_spent: U512, account: PublicKey) -> Result<()> {
         internal::finalize_payment(self, amount_spent, account)
     }
+
+    /// Removes the whole stake of each of `validators`, moving it to the community purse, and
+    /// returns the amounts slashed in the same order.  Only the system account may slash.  A
+    /// validator which isn't bonded is slashed by zero rather than failing the whole batch.
+    fn slash(&mut self, validators: Vec<PublicKey>) -> Result<Vec<U512>> {
+        internal::slash(self, &validators)
+    }
+
+    /// Shares the balance of the rewards purse among the bonded validators in proportion to their
+    /// stakes and pays out each share.  Returns each validator's share split into its commission
+    /// and the remainder due to its delegators.  Only the system account may distribute rewards.
+    fn distribute_rewards(&mut self) -> Result<BTreeMap<PublicKey, (U512, U512)>> {
+        internal::distribute_rewards(self)
+    }
+
+    /// Returns the number of motes charged per unit of gas.
+    fn get_gas_price(&mut self) -> Result<u64> {
+        self.read_gas_price()
+    }
 }
 
 mod internal {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use types::{
         account::PublicKey,
         system_contract_errors::pos::{Error, PurseLookupError, Result},
-        BlockTime, Key, Phase, URef, U512,
+        BlockTime, Key, Phase, TransferredTo, URef, U512,
     };
 
     use crate::{
-        mint_provider::MintProvider, queue::QueueEntry, queue_provider::QueueProvider,
-        runtime_provider::RuntimeProvider, stakes_provider::StakesProvider,
+        commissions::Commission, commissions_provider::CommissionsProvider,
+        mint_provider::MintProvider, queue_provider::QueueProvider,
+        runtime_provider::RuntimeProvider, stakes::Stakes, stakes_provider::StakesProvider,
+        withdrawals_provider::WithdrawalsProvider,
     };
 
-    /// Account used to run system functions (in particular `finalize_payment`).
+    /// Account used to run system functions (in particular `finalize_payment` and `slash`).
     const SYSTEM_ACCOUNT: PublicKey = PublicKey::ed25519_from([0u8; 32]);
 
     /// The uref name where the PoS purse is stored. It contains all staked motes, and all unbonded
@@ -111,6 +166,9 @@ mod internal {
     /// The uref name where the PoS holds validator earnings before distributing them.
     const REWARDS_PURSE_KEY: &str = "pos_rewards_purse";
 
+    /// The uref name where the PoS holds slashed stakes.
+    const COMMUNITY_PURSE_KEY: &str = "pos_community_purse";
+
     /// The uref name where the PoS will refund unused payment back to the user. The uref this name
     /// corresponds to is set by the user.
     const REFUND_PURSE_KEY: &str = "pos_refund_purse";
@@ -118,14 +176,12 @@ mod internal {
     /// The time from a bonding request until the bond becomes effective and part of the stake.
     const BOND_DELAY: u64 = 0;
 
-    /// The time from an unbonding request until the stakes are paid out.
-    const UNBOND_DELAY: u64 = 0;
-
     /// The maximum number of pending bonding requests.
     const MAX_BOND_LEN: usize = 100;
 
-    /// The maximum number of pending unbonding requests.
-    const MAX_UNBOND_LEN: usize = 1000;
+    /// The maximum number of block heights at which a single validator can have unbonded stake
+    /// awaiting withdrawal.
+    const MAX_WITHDRAWALS_LEN: usize = 100;
 
     /// Enqueues the deploy's creator for becoming a validator. The bond `amount` is paid from the
     /// purse `source`.
@@ -153,45 +209,130 @@ mod internal {
     }
 
     /// Enqueues the deploy's creator for unbonding. Their vote weight as a validator is decreased
-    /// immediately, but the funds will only be released after a delay. If `maybe_amount` is `None`,
-    /// all funds are enqueued for withdrawal, terminating the validator status.
-    pub fn unbond<P: QueueProvider + StakesProvider>(
+    /// immediately, but the funds are only queued for withdrawal, keyed by `block_height`. If
+    /// `maybe_amount` is `None`, all funds are enqueued for withdrawal, terminating the validator
+    /// status.
+    pub fn unbond<P: StakesProvider + WithdrawalsProvider>(
         provider: &mut P,
         maybe_amount: Option<U512>,
         validator: PublicKey,
-        timestamp: BlockTime,
+        block_height: u64,
     ) -> Result<()> {
-        let mut queue = provider.read_unbonding();
-        if queue.0.len() >= MAX_UNBOND_LEN {
+        let mut withdrawals = provider.read_withdrawals()?;
+        if withdrawals.queue_len(&validator) >= MAX_WITHDRAWALS_LEN {
             return Err(Error::TooManyEventsInQueue);
         }
 
         let mut stakes = provider.read()?;
         let payout = stakes.unbond(&validator, maybe_amount)?;
         provider.write(&stakes);
-        // TODO: Make sure the destination is valid and the amount can be paid. The actual payment
-        // will be made later, after the unbonding delay. contract_api::transfer_dry_run(POS_PURSE,
-        // dest, amount)?;
-        queue.push(validator, payout, timestamp)?;
-        provider.write_unbonding(queue);
+        withdrawals.push(validator, payout, block_height);
+        provider.write_withdrawals(&withdrawals);
         Ok(())
     }
 
-    /// Removes all due requests from the queues and applies them.
+    /// Removes the validator's withdrawals which have been queued for at least the unbonding delay
+    /// as of `block_height`, and returns their total amount.
+    pub fn withdraw_unbonded<P: WithdrawalsProvider>(
+        provider: &mut P,
+        validator: PublicKey,
+        block_height: u64,
+    ) -> Result<U512> {
+        let unbonding_delay = provider.read_unbonding_delay()?;
+        let due_height = match block_height.checked_sub(unbonding_delay) {
+            Some(due_height) => due_height,
+            None => return Ok(U512::zero()),
+        };
+
+        let mut withdrawals = provider.read_withdrawals()?;
+        let amount = withdrawals.pop_due(&validator, due_height);
+        provider.write_withdrawals(&withdrawals);
+        Ok(amount)
+    }
+
+    /// Transfers `amount` of withdrawn stake from the bonding purse to the validator's account.
+    pub fn pay_out<P: MintProvider + RuntimeProvider>(
+        provider: &mut P,
+        validator: PublicKey,
+        amount: U512,
+    ) -> Result<()> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+        let pos_purse = get_bonding_purse(provider)?;
+        let _: TransferredTo = provider
+            .transfer_purse_to_account(pos_purse, validator, amount)
+            .map_err(|_| Error::UnbondTransferFailed)?;
+        Ok(())
+    }
+
+    /// Sets the commission rate of `validator`, which must be bonded, as of `block_height`.
+    pub fn set_commission<P: CommissionsProvider + StakesProvider>(
+        provider: &mut P,
+        validator: PublicKey,
+        rate: u32,
+        block_height: u64,
+    ) -> Result<()> {
+        let stakes = provider.read()?;
+        if !stakes.0.contains_key(&validator) {
+            return Err(Error::NotBonded);
+        }
+
+        let previous = provider.read_commission(&validator)?;
+        let commission = Commission::new(previous, rate, block_height)?;
+        provider.write_commission(&validator, commission);
+        Ok(())
+    }
+
+    /// Shares the balance of the rewards purse among the bonded validators in proportion to their
+    /// stakes, rounding down, and splits each share by the validator's commission rate.  As
+    /// delegation isn't supported yet, a validator's own bond is the only stake delegated to it,
+    /// so both its commission and its delegators' share are paid to its account.
+    pub fn distribute_rewards<
+        P: CommissionsProvider + MintProvider + RuntimeProvider + StakesProvider,
+    >(
+        provider: &mut P,
+    ) -> Result<BTreeMap<PublicKey, (U512, U512)>> {
+        require_system_caller(provider)?;
+
+        let rewards_purse = get_rewards_purse(provider)?;
+        let total_reward = provider
+            .balance(rewards_purse)
+            .ok_or(Error::RewardsPurseBalanceNotFound)?;
+        let stakes = match provider.read() {
+            Ok(stakes) => stakes,
+            // Every validator may have been slashed.
+            Err(Error::StakesNotFound) => Stakes::new(BTreeMap::new()),
+            Err(error) => return Err(error),
+        };
+        let total_stake = stakes.total_bonds();
+
+        let mut splits = BTreeMap::new();
+        if total_stake.is_zero() {
+            return Ok(splits);
+        }
+        for (validator, stake) in stakes.iter() {
+            let share = total_reward * *stake / total_stake;
+            let commission = provider.read_commission(validator)?.unwrap_or_default();
+            let (validator_commission, delegators_share) = commission.split(share);
+            if !share.is_zero() {
+                let _: TransferredTo = provider
+                    .transfer_purse_to_account(rewards_purse, *validator, share)
+                    .map_err(|_| Error::RewardTransferFailed)?;
+            }
+            splits.insert(*validator, (validator_commission, delegators_share));
+        }
+        Ok(splits)
+    }
+
+    /// Removes all due requests from the bonding queue and applies them.
     pub fn step<P: QueueProvider + StakesProvider>(
         provider: &mut P,
         timestamp: BlockTime,
-    ) -> Result<Vec<QueueEntry>> {
+    ) -> Result<()> {
         let mut bonding_queue = provider.read_bonding();
-        let mut unbonding_queue = provider.read_unbonding();
 
         let bonds = bonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(BOND_DELAY)));
-        let unbonds =
-            unbonding_queue.pop_due(timestamp.saturating_sub(BlockTime::new(UNBOND_DELAY)));
-
-        if !unbonds.is_empty() {
-            provider.write_unbonding(unbonding_queue);
-        }
 
         if !bonds.is_empty() {
             provider.write_bonding(bonding_queue);
@@ -202,7 +343,50 @@ mod internal {
             provider.write(&stakes);
         }
 
-        Ok(unbonds)
+        Ok(())
+    }
+
+    /// Removes the stakes of `validators` and transfers them from the bonding purse to the
+    /// community purse.  Returns the amount slashed from each of `validators`, in the same order,
+    /// with zero for those which weren't bonded.
+    pub fn slash<P: MintProvider + RuntimeProvider + StakesProvider>(
+        provider: &mut P,
+        validators: &[PublicKey],
+    ) -> Result<Vec<U512>> {
+        require_system_caller(provider)?;
+
+        let mut stakes = match provider.read() {
+            Ok(stakes) => stakes,
+            // Every validator may have been slashed already.
+            Err(Error::StakesNotFound) => Stakes::new(BTreeMap::new()),
+            Err(error) => return Err(error),
+        };
+        let amounts: Vec<U512> = validators
+            .iter()
+            .map(|validator| stakes.slash(validator))
+            .collect();
+        let total = amounts
+            .iter()
+            .fold(U512::zero(), |total, amount| total + *amount);
+        if total.is_zero() {
+            return Ok(amounts);
+        }
+        provider.write(&stakes);
+
+        let bonding_purse = get_bonding_purse(provider)?;
+        let community_purse = get_community_purse(provider)?;
+        provider
+            .transfer_purse_to_purse(bonding_purse, community_purse, total)
+            .map_err(|_| Error::SlashTransferFailed)?;
+        Ok(amounts)
+    }
+
+    /// Returns an error unless the caller is the system account.
+    fn require_system_caller<R: RuntimeProvider>(runtime_provider: &R) -> Result<()> {
+        if runtime_provider.get_caller() != SYSTEM_ACCOUNT {
+            return Err(Error::SystemFunctionCalledByUserAccount);
+        }
+        Ok(())
     }
 
     /// Attempts to look up a purse from the named_keys
@@ -234,6 +418,11 @@ mod internal {
         get_purse::<R>(runtime_provider, REWARDS_PURSE_KEY).map_err(PurseLookupError::rewards)
     }
 
+    /// Returns the purse for holding slashed stakes
+    pub fn get_community_purse<R: RuntimeProvider>(runtime_provider: &R) -> Result<URef> {
+        get_purse::<R>(runtime_provider, COMMUNITY_PURSE_KEY).map_err(PurseLookupError::community)
+    }
+
     /// Sets the purse where refunds (excess funds not spent to pay for computation) will be sent.
     /// Note that if this function is never called, the default location is the main purse of the
     /// deployer's account.
@@ -263,10 +452,7 @@ mod internal {
         amount_spent: U512,
         account: PublicKey,
     ) -> Result<()> {
-        let caller = provider.get_caller();
-        if caller != SYSTEM_ACCOUNT {
-            return Err(Error::SystemFunctionCalledByUserAccount);
-        }
+        require_system_caller(provider)?;
 
         let payment_purse = get_payment_purse(provider)?;
         let total = match provider.balance(payment_purse) {
@@ -328,18 +514,20 @@ mod internal {
 
         use types::{account::PublicKey, system_contract_errors::pos::Result, BlockTime, U512};
 
-        use super::{bond, step, unbond, BOND_DELAY, UNBOND_DELAY};
+        use super::{bond, step, unbond, withdraw_unbonded, BOND_DELAY};
         use crate::{
             queue::Queue, queue_provider::QueueProvider, stakes::Stakes,
-            stakes_provider::StakesProvider,
+            stakes_provider::StakesProvider, withdrawals::Withdrawals,
+            withdrawals_provider::WithdrawalsProvider,
         };
 
         const KEY1: [u8; 32] = [1; 32];
         const KEY2: [u8; 32] = [2; 32];
+        const UNBONDING_DELAY: u64 = 10;
 
         thread_local! {
             static BONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
-            static UNBONDING: RefCell<Queue> = RefCell::new(Queue(Default::default()));
+            static WITHDRAWALS: RefCell<Withdrawals> = RefCell::new(Withdrawals::default());
             static STAKES: RefCell<Stakes> = RefCell::new(
                 Stakes(iter::once((PublicKey::ed25519_from(KEY1), U512::from(1_000))).collect())
             );
@@ -352,17 +540,9 @@ mod internal {
                 BONDING.with(|b| b.borrow().clone())
             }
 
-            fn read_unbonding(&mut self) -> Queue {
-                UNBONDING.with(|ub| ub.borrow().clone())
-            }
-
             fn write_bonding(&mut self, queue: Queue) {
                 BONDING.with(|b| b.replace(queue));
             }
-
-            fn write_unbonding(&mut self, queue: Queue) {
-                UNBONDING.with(|ub| ub.replace(queue));
-            }
         }
 
         impl StakesProvider for Provider {
@@ -375,6 +555,20 @@ mod internal {
             }
         }
 
+        impl WithdrawalsProvider for Provider {
+            fn read_withdrawals(&self) -> Result<Withdrawals> {
+                WITHDRAWALS.with(|w| Ok(w.borrow().clone()))
+            }
+
+            fn write_withdrawals(&mut self, withdrawals: &Withdrawals) {
+                WITHDRAWALS.with(|w| w.replace(withdrawals.clone()));
+            }
+
+            fn read_unbonding_delay(&mut self) -> Result<u64> {
+                Ok(UNBONDING_DELAY)
+            }
+        }
+
         fn assert_stakes(stakes: &[([u8; 32], usize)]) {
             let expected = Stakes(
                 stakes
@@ -385,6 +579,21 @@ mod internal {
             assert_eq!(Ok(expected), Provider.read());
         }
 
+        fn unbond_validator_1(amount: u64, block_height: u64) {
+            unbond::<Provider>(
+                &mut Provider,
+                Some(U512::from(amount)),
+                PublicKey::ed25519_from(KEY1),
+                block_height,
+            )
+            .expect("unbond validator 1");
+        }
+
+        fn withdraw_validator_1(block_height: u64) -> U512 {
+            withdraw_unbonded(&mut Provider, PublicKey::ed25519_from(KEY1), block_height)
+                .expect("withdraw validator 1")
+        }
+
         #[test]
         fn test_bond_step_unbond() {
             let mut provider = Provider;
@@ -403,18 +612,41 @@ mod internal {
             step(&mut provider, BlockTime::new(1 + BOND_DELAY)).expect("step 2");
             assert_stakes(&[(KEY1, 1_000), (KEY2, 500)]);
 
-            unbond::<Provider>(
-                &mut provider,
-                Some(U512::from(500)),
-                PublicKey::ed25519_from(KEY1),
-                BlockTime::new(2),
-            )
-            .expect("partly unbond validator 1");
+            unbond_validator_1(500, 2);
 
             // Unbonding becomes effective immediately.
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
-            step::<Provider>(&mut provider, BlockTime::new(2 + UNBOND_DELAY)).expect("step 3");
+            step::<Provider>(&mut provider, BlockTime::new(3)).expect("step 3");
             assert_stakes(&[(KEY1, 500), (KEY2, 500)]);
         }
+
+        #[test]
+        fn test_withdraw_too_early() {
+            unbond_validator_1(100, 5);
+            assert_eq!(withdraw_validator_1(0), U512::zero());
+            assert_eq!(withdraw_validator_1(5 + UNBONDING_DELAY - 1), U512::zero());
+
+            // Nothing was withdrawn, so the stake is still queued.
+            assert_eq!(withdraw_validator_1(5 + UNBONDING_DELAY), U512::from(100));
+        }
+
+        #[test]
+        fn test_withdraw_at_boundary() {
+            unbond_validator_1(100, 5);
+            assert_eq!(withdraw_validator_1(5 + UNBONDING_DELAY), U512::from(100));
+            assert_eq!(withdraw_validator_1(5 + UNBONDING_DELAY), U512::zero());
+        }
+
+        #[test]
+        fn test_withdraw_multiple_unbondings() {
+            unbond_validator_1(100, 5);
+            unbond_validator_1(200, 5);
+            unbond_validator_1(300, 8);
+            assert_stakes(&[(KEY1, 400)]);
+
+            assert_eq!(withdraw_validator_1(5 + UNBONDING_DELAY), U512::from(300));
+            assert_eq!(withdraw_validator_1(7 + UNBONDING_DELAY), U512::zero());
+            assert_eq!(withdraw_validator_1(8 + UNBONDING_DELAY), U512::from(300));
+        }
     }
 }
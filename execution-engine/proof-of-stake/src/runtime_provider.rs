@@ -11,5 +11,7 @@ pub trait RuntimeProvider {
 
     fn get_block_time(&self) -> BlockTime;
 
+    fn get_block_height(&self) -> u64;
+
     fn get_caller(&self) -> PublicKey;
 }
@@ -109,6 +109,12 @@ impl Stakes {
         Ok(stake)
     }
 
+    /// Removes all the validator's stakes regardless of the limits on unbonding, and returns the
+    /// amount removed, which is zero if the validator wasn't bonded.
+    pub fn slash(&mut self, validator: &PublicKey) -> U512 {
+        self.0.remove(validator).unwrap_or_else(U512::zero)
+    }
+
     /// Adds `amount` to the validator's stakes.
     pub fn bond(&mut self, validator: &PublicKey, amount: U512) {
         self.0
@@ -259,6 +265,19 @@ mod tests {
         assert_eq!(new_stakes(&[(KEY1, 46)]), stakes);
     }
 
+    #[test]
+    fn test_slash() {
+        let mut stakes = new_stakes(&[(KEY1, 5), (KEY2, 100)]);
+        assert_eq!(
+            U512::from(100),
+            stakes.slash(&PublicKey::ed25519_from(KEY2))
+        );
+        assert_eq!(new_stakes(&[(KEY1, 5)]), stakes);
+        assert_eq!(U512::zero(), stakes.slash(&PublicKey::ed25519_from(KEY2)));
+        assert_eq!(U512::from(5), stakes.slash(&PublicKey::ed25519_from(KEY1)));
+        assert_eq!(new_stakes(&[]), stakes);
+    }
+
     #[test]
     fn test_unbond_too_much_rel() {
         let mut stakes = new_stakes(&[(KEY1, 999), (KEY2, 1)]);
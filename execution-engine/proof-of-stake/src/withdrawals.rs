@@ -0,0 +1,140 @@
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+use types::{
+    account::PublicKey,
+    system_contract_errors::pos::{Error, Result},
+    U512,
+};
+
+/// The prefix of the named keys which encode the queued withdrawals.
+const NAME_PREFIX: &str = "w";
+
+/// The unbonded stakes awaiting withdrawal, queued per validator by the block height at which they
+/// were unbonded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Withdrawals(pub BTreeMap<PublicKey, BTreeMap<u64, U512>>);
+
+impl Withdrawals {
+    /// Parses the withdrawals from the names of the contract's named keys, ignoring names which
+    /// don't encode a withdrawal.
+    pub fn from_names<'a, I: IntoIterator<Item = &'a str>>(names: I) -> Result<Withdrawals> {
+        let mut withdrawals = Withdrawals::default();
+        for name in names {
+            let mut split_name = name.split('_');
+            if Some(NAME_PREFIX) != split_name.next() {
+                continue;
+            }
+            let hex_key = split_name
+                .next()
+                .ok_or(Error::WithdrawalsDeserializationFailed)?;
+            if hex_key.len() != 64 {
+                return Err(Error::WithdrawalsDeserializationFailed);
+            }
+            let mut key_bytes = [0u8; 32];
+            let _bytes_written = base16::decode_slice(hex_key, &mut key_bytes)
+                .map_err(|_| Error::WithdrawalsDeserializationFailed)?;
+            debug_assert!(_bytes_written == key_bytes.len());
+            let validator = PublicKey::ed25519_from(key_bytes);
+            let block_height = split_name
+                .next()
+                .and_then(|height| height.parse().ok())
+                .ok_or(Error::WithdrawalsDeserializationFailed)?;
+            let amount = split_name
+                .next()
+                .and_then(|amount| U512::from_dec_str(amount).ok())
+                .ok_or(Error::WithdrawalsDeserializationFailed)?;
+            withdrawals.push(validator, amount, block_height);
+        }
+        Ok(withdrawals)
+    }
+
+    /// Encodes the withdrawals as names of named keys, in the form
+    /// "w_{validator_pk}_{block_height}_{amount}".
+    pub fn strings(&self) -> impl Iterator<Item = String> + '_ {
+        self.0.iter().flat_map(|(validator, queue)| {
+            let hex_key = base16::encode_lower(&validator.as_bytes());
+            queue.iter().map(move |(block_height, amount)| {
+                format!("{}_{}_{}_{}", NAME_PREFIX, hex_key, block_height, amount)
+            })
+        })
+    }
+
+    /// Returns the number of block heights at which `validator` has stake awaiting withdrawal.
+    pub fn queue_len(&self, validator: &PublicKey) -> usize {
+        self.0.get(validator).map_or(0, BTreeMap::len)
+    }
+
+    /// Queues `amount` for withdrawal by `validator`, unbonded at `block_height`.
+    pub fn push(&mut self, validator: PublicKey, amount: U512, block_height: u64) {
+        self.0
+            .entry(validator)
+            .or_default()
+            .entry(block_height)
+            .and_modify(|queued| *queued += amount)
+            .or_insert(amount);
+    }
+
+    /// Removes all of `validator`'s stake unbonded at or before `block_height`, returning its
+    /// total.
+    pub fn pop_due(&mut self, validator: &PublicKey, block_height: u64) -> U512 {
+        let queue = match self.0.get_mut(validator) {
+            Some(queue) => queue,
+            None => return U512::zero(),
+        };
+        let due_heights: Vec<u64> = queue
+            .range(..=block_height)
+            .map(|(height, _)| *height)
+            .collect();
+        let total = due_heights
+            .iter()
+            .filter_map(|height| queue.remove(height))
+            .fold(U512::zero(), |total, amount| total + amount);
+        if queue.is_empty() {
+            self.0.remove(validator);
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use types::{account::PublicKey, U512};
+
+    use super::Withdrawals;
+
+    const KEY1: [u8; 32] = [1; 32];
+    const KEY2: [u8; 32] = [2; 32];
+
+    #[test]
+    fn test_pop_due() {
+        let val1 = PublicKey::ed25519_from(KEY1);
+        let val2 = PublicKey::ed25519_from(KEY2);
+        let mut withdrawals = Withdrawals::default();
+        withdrawals.push(val1, U512::from(5), 10);
+        withdrawals.push(val1, U512::from(6), 11);
+        withdrawals.push(val1, U512::from(7), 11);
+        withdrawals.push(val2, U512::from(8), 10);
+        assert_eq!(withdrawals.queue_len(&val1), 2);
+
+        assert_eq!(withdrawals.pop_due(&val1, 9), U512::zero());
+        assert_eq!(withdrawals.pop_due(&val1, 10), U512::from(5));
+        assert_eq!(withdrawals.pop_due(&val1, 10), U512::zero());
+        assert_eq!(withdrawals.pop_due(&val1, 20), U512::from(13));
+        assert_eq!(withdrawals.queue_len(&val1), 0);
+        assert_eq!(withdrawals.queue_len(&val2), 1);
+    }
+
+    #[test]
+    fn test_names_roundtrip() {
+        let mut withdrawals = Withdrawals::default();
+        withdrawals.push(PublicKey::ed25519_from(KEY1), U512::from(5), 10);
+        withdrawals.push(PublicKey::ed25519_from(KEY1), U512::from(6), 11);
+        withdrawals.push(PublicKey::ed25519_from(KEY2), U512::from(7), 10);
+        let mut names: Vec<String> = withdrawals.strings().collect();
+        names.push("v_stake".into());
+        let parsed = Withdrawals::from_names(names.iter().map(String::as_str));
+        assert_eq!(parsed, Ok(withdrawals));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{withdrawals::Withdrawals, Result};
+
+/// A `WithdrawalsProvider` that reads and writes the queued withdrawals to/from the contract's
+/// known urefs.
+pub trait WithdrawalsProvider {
+    fn read_withdrawals(&self) -> Result<Withdrawals>;
+
+    fn write_withdrawals(&mut self, withdrawals: &Withdrawals);
+
+    /// Reads the number of blocks after which unbonded stake can be withdrawn.
+    fn read_unbonding_delay(&mut self) -> Result<u64>;
+}
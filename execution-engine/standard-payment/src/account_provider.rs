@@ -2,4 +2,7 @@ use types::{ApiError, URef};
 
 pub trait AccountProvider {
     fn get_main_purse(&self) -> Result<URef, ApiError>;
+
+    /// Returns `true` if the account has `ADD` access to `purse`.
+    fn can_add_to_purse(&self, purse: URef) -> bool;
 }
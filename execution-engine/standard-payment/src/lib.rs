@@ -6,7 +6,7 @@ mod proof_of_stake_provider;
 
 use core::marker::Sized;
 
-use types::{ApiError, U512};
+use types::{AccessRights, ApiError, URef, U512};
 
 pub use crate::{
     account_provider::AccountProvider, mint_provider::MintProvider,
@@ -14,10 +14,31 @@ pub use crate::{
 };
 
 pub trait StandardPayment: AccountProvider + MintProvider + ProofOfStakeProvider + Sized {
-    fn pay(&mut self, amount: U512) -> Result<(), ApiError> {
+    /// Transfers `amount` from the account's main purse to the payment purse.
+    ///
+    /// Unspent payment is refunded to `refund_purse` if given and the account has `ADD` access to
+    /// it, otherwise it's refunded to the main purse.
+    fn pay(&mut self, amount: U512, refund_purse: Option<URef>) -> Result<(), ApiError> {
         let main_purse = self.get_main_purse()?;
         let payment_purse = self.get_payment_purse()?;
         self.transfer_purse_to_purse(main_purse, payment_purse, amount)
-            .map_err(|_| ApiError::Transfer)
+            .map_err(|_| ApiError::Transfer)?;
+
+        match refund_purse {
+            Some(refund_purse) if self.can_add_to_purse(refund_purse) => {
+                self.set_refund_purse(URef::new(refund_purse.addr(), AccessRights::ADD))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Transfers the cost of `gas` at the current gas price from the account's main purse to the
+    /// payment purse, refunding unspent payment as for [`pay`](StandardPayment::pay).
+    fn pay_gas(&mut self, gas: U512, refund_purse: Option<URef>) -> Result<(), ApiError> {
+        let gas_price = self.get_gas_price()?;
+        let amount = gas
+            .checked_mul(U512::from(gas_price))
+            .ok_or(ApiError::InvalidArgument)?;
+        self.pay(amount, refund_purse)
     }
 }
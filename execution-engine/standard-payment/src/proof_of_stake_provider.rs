@@ -2,4 +2,9 @@ use types::{ApiError, URef};
 
 pub trait ProofOfStakeProvider {
     fn get_payment_purse(&mut self) -> Result<URef, ApiError>;
+
+    fn set_refund_purse(&mut self, purse: URef) -> Result<(), ApiError>;
+
+    /// Returns the number of motes charged per unit of gas.
+    fn get_gas_price(&mut self) -> Result<u64, ApiError>;
 }
@@ -17,7 +17,7 @@ use crate::{
 // This error type is not intended to be used by third party crates.
 #[doc(hidden)]
 #[derive(Debug, Eq, PartialEq)]
-pub struct TryFromIntError(());
+pub struct TryFromIntError(pub(crate) ());
 
 /// Associated error type of `TryFrom<&[u8]>` for [`PublicKey`].
 #[derive(Debug)]
@@ -27,6 +27,10 @@ const POS_ERROR_OFFSET: u32 = RESERVED_ERROR_MAX - u8::MAX as u32; // 65280..=65
 /// added to them when being converted to a `u32`.
 const MINT_ERROR_OFFSET: u32 = (POS_ERROR_OFFSET - 1) - u8::MAX as u32; // 65024..=65279
 
+/// The maximum length in bytes of the payload a contract can revert with.  Longer payloads are
+/// truncated to this length.
+pub const MAX_REVERT_PAYLOAD_LENGTH: usize = 64;
+
 /// Errors which can be encountered while running a smart contract.
 ///
 /// An `ApiError` can be converted to a `u32` in order to be passed via the execution engine's
@@ -154,6 +158,27 @@ const MINT_ERROR_OFFSET: u32 = (POS_ERROR_OFFSET - 1) - u8::MAX as u32; // 65024
 /// # show_and_check!(
 /// 34 => HostBufferFull
 /// # );
+/// # show_and_check!(
+/// 35 => AllocLayout
+/// # );
+/// # show_and_check!(
+/// 36 => LocalKeyTooLong
+/// # );
+/// # show_and_check!(
+/// 37 => ContractVersionNotFound
+/// # );
+/// # show_and_check!(
+/// 38 => ContractVersionDisabled
+/// # );
+/// # show_and_check!(
+/// 39 => EventLimitExceeded
+/// # );
+/// # show_and_check!(
+/// 40 => TooManyNamedKeys
+/// # );
+/// # show_and_check!(
+/// 41 => NamedKeyNameTooLong
+/// # );
 ///
 /// // Mint errors:
 /// use casperlabs_types::system_contract_errors::mint::Error as MintError;
@@ -265,6 +290,45 @@ const MINT_ERROR_OFFSET: u32 = (POS_ERROR_OFFSET - 1) - u8::MAX as u32; // 65024
 /// # show_and_check!(
 /// 65_306 => PosError::SetRefundPurseCalledOutsidePayment
 /// # );
+/// # show_and_check!(
+/// 65_307 => PosError::NoWithdrawalsDue
+/// # );
+/// # show_and_check!(
+/// 65_308 => PosError::WithdrawalsDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_309 => PosError::UnbondingDelayDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_310 => PosError::CommissionRateTooHigh
+/// # );
+/// # show_and_check!(
+/// 65_311 => PosError::CommissionAlreadySetThisEra
+/// # );
+/// # show_and_check!(
+/// 65_312 => PosError::CommissionsDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_313 => PosError::GasPriceDeserializationFailed
+/// # );
+/// # show_and_check!(
+/// 65_314 => PosError::BondingDisabled
+/// # );
+/// # show_and_check!(
+/// 65_315 => PosError::CommunityPurseNotFound
+/// # );
+/// # show_and_check!(
+/// 65_316 => PosError::CommunityPurseKeyUnexpectedType
+/// # );
+/// # show_and_check!(
+/// 65_317 => PosError::SlashTransferFailed
+/// # );
+/// # show_and_check!(
+/// 65_318 => PosError::RewardsPurseBalanceNotFound
+/// # );
+/// # show_and_check!(
+/// 65_319 => PosError::RewardTransferFailed
+/// # );
 ///
 /// // User-defined errors:
 /// # show_and_check!(
@@ -382,6 +446,22 @@ pub enum ApiError {
     HostBufferFull,
     /// Could not lay out an array in memory
     AllocLayout,
+    /// The key for a value in the context-local partition of global state is longer than
+    /// [`KEY_LOCAL_MAX_KEY_BYTES`](crate::KEY_LOCAL_MAX_KEY_BYTES).
+    LocalKeyTooLong,
+    /// The requested version of a [`ContractPackage`](crate::ContractPackage) doesn't exist, or
+    /// the latest version was requested and none is enabled.
+    ContractVersionNotFound,
+    /// The requested version of a [`ContractPackage`](crate::ContractPackage) has been disabled.
+    ContractVersionDisabled,
+    /// Emitting the event would exceed the maximum number of events, or of bytes of events, that
+    /// an execution may emit.
+    EventLimitExceeded,
+    /// Adding the named key would exceed the maximum number of named keys of an account or
+    /// contract.
+    TooManyNamedKeys,
+    /// The name of the named key is longer than the maximum length of a name.
+    NamedKeyNameTooLong,
     /// Error specific to Mint contract.
     Mint(u8),
     /// Error specific to Proof of Stake contract.
@@ -396,7 +476,7 @@ impl From<bytesrepr::Error> for ApiError {
         match error {
             bytesrepr::Error::EarlyEndOfStream => ApiError::EarlyEndOfStream,
             bytesrepr::Error::Formatting => ApiError::Formatting,
-            bytesrepr::Error::LeftOverBytes => ApiError::LeftOverBytes,
+            bytesrepr::Error::LeftOverBytes { .. } => ApiError::LeftOverBytes,
             bytesrepr::Error::OutOfMemory => ApiError::OutOfMemory,
         }
     }
@@ -516,6 +596,12 @@ impl From<ApiError> for u32 {
             ApiError::HostBufferEmpty => 33,
             ApiError::HostBufferFull => 34,
             ApiError::AllocLayout => 35,
+            ApiError::LocalKeyTooLong => 36,
+            ApiError::ContractVersionNotFound => 37,
+            ApiError::ContractVersionDisabled => 38,
+            ApiError::EventLimitExceeded => 39,
+            ApiError::TooManyNamedKeys => 40,
+            ApiError::NamedKeyNameTooLong => 41,
             ApiError::Mint(value) => MINT_ERROR_OFFSET + u32::from(value),
             ApiError::ProofOfStake(value) => POS_ERROR_OFFSET + u32::from(value),
             ApiError::User(value) => RESERVED_ERROR_MAX + 1 + u32::from(value),
@@ -523,6 +609,18 @@ impl From<ApiError> for u32 {
     }
 }
 
+impl From<u32> for ApiError {
+    /// Decodes the exit code of a reverted deploy back into the `ApiError` it was reverted with.
+    ///
+    /// Codes no `ApiError` converts to, including `0`, decode to `ApiError::Unhandled`.
+    fn from(value: u32) -> Self {
+        // Codes above `i32::MAX` wrap to negative values, none of which are valid
+        result_from(value as i32)
+            .err()
+            .unwrap_or(ApiError::Unhandled)
+    }
+}
+
 impl Debug for ApiError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -563,6 +661,12 @@ impl Debug for ApiError {
             ApiError::HostBufferEmpty => write!(f, "ApiError::HostBufferEmpty")?,
             ApiError::HostBufferFull => write!(f, "ApiError::HostBufferFull")?,
             ApiError::AllocLayout => write!(f, "ApiError::AllocLayout")?,
+            ApiError::LocalKeyTooLong => write!(f, "ApiError::LocalKeyTooLong")?,
+            ApiError::ContractVersionNotFound => write!(f, "ApiError::ContractVersionNotFound")?,
+            ApiError::ContractVersionDisabled => write!(f, "ApiError::ContractVersionDisabled")?,
+            ApiError::EventLimitExceeded => write!(f, "ApiError::EventLimitExceeded")?,
+            ApiError::TooManyNamedKeys => write!(f, "ApiError::TooManyNamedKeys")?,
+            ApiError::NamedKeyNameTooLong => write!(f, "ApiError::NamedKeyNameTooLong")?,
             ApiError::Mint(value) => write!(f, "ApiError::Mint({})", value)?,
             ApiError::ProofOfStake(value) => write!(f, "ApiError::ProofOfStake({})", value)?,
             ApiError::User(value) => write!(f, "ApiError::User({})", value)?,
@@ -621,6 +725,12 @@ pub fn result_from(value: i32) -> Result<(), ApiError> {
         33 => Err(ApiError::HostBufferEmpty),
         34 => Err(ApiError::HostBufferFull),
         35 => Err(ApiError::AllocLayout),
+        36 => Err(ApiError::LocalKeyTooLong),
+        37 => Err(ApiError::ContractVersionNotFound),
+        38 => Err(ApiError::ContractVersionDisabled),
+        39 => Err(ApiError::EventLimitExceeded),
+        40 => Err(ApiError::TooManyNamedKeys),
+        41 => Err(ApiError::NamedKeyNameTooLong),
         _ => {
             if value > RESERVED_ERROR_MAX as i32 && value <= (2 * RESERVED_ERROR_MAX + 1) as i32 {
                 Err(ApiError::User(value as u16))
@@ -725,6 +835,12 @@ mod tests {
         round_trip(Err(ApiError::HostBufferEmpty));
         round_trip(Err(ApiError::HostBufferFull));
         round_trip(Err(ApiError::AllocLayout));
+        round_trip(Err(ApiError::LocalKeyTooLong));
+        round_trip(Err(ApiError::ContractVersionNotFound));
+        round_trip(Err(ApiError::ContractVersionDisabled));
+        round_trip(Err(ApiError::EventLimitExceeded));
+        round_trip(Err(ApiError::TooManyNamedKeys));
+        round_trip(Err(ApiError::NamedKeyNameTooLong));
         round_trip(Err(ApiError::Mint(0)));
         round_trip(Err(ApiError::Mint(u8::MAX)));
         round_trip(Err(ApiError::ProofOfStake(0)));
@@ -732,4 +848,22 @@ mod tests {
         round_trip(Err(ApiError::User(0)));
         round_trip(Err(ApiError::User(u16::MAX)));
     }
+
+    #[test]
+    fn should_decode_exit_code() {
+        for error in &[
+            ApiError::MissingArgument,
+            ApiError::Transfer,
+            ApiError::EventLimitExceeded,
+            ApiError::Mint(u8::MAX),
+            ApiError::ProofOfStake(0),
+            ApiError::User(0),
+            ApiError::User(u16::MAX),
+        ] {
+            assert_eq!(ApiError::from(u32::from(*error)), *error);
+        }
+
+        assert_eq!(ApiError::from(0), ApiError::Unhandled);
+        assert_eq!(ApiError::from(u32::max_value()), ApiError::Unhandled);
+    }
 }
@@ -81,17 +81,19 @@ pub fn allocate_buffer<T: ToBytes>(to_be_serialized: &T) -> Result<Vec<u8>, Erro
 
 /// Serialization and deserialization errors.
 #[derive(Debug, Fail, PartialEq, Eq, Clone)]
-#[repr(u8)]
 pub enum Error {
     /// Early end of stream while deserializing.
     #[fail(display = "Deserialization error: early end of stream")]
-    EarlyEndOfStream = 0,
+    EarlyEndOfStream,
     /// Formatting error while deserializing.
     #[fail(display = "Deserialization error: formatting")]
     Formatting,
     /// Not all input bytes were consumed in [`deserialize`].
-    #[fail(display = "Deserialization error: left-over bytes")]
-    LeftOverBytes,
+    #[fail(display = "Deserialization error: {} left-over bytes", remaining)]
+    LeftOverBytes {
+        /// The number of input bytes which remained unconsumed.
+        remaining: usize,
+    },
     /// Out of memory error.
     #[fail(display = "Serialization error: out of memory")]
     OutOfMemory,
@@ -112,7 +114,9 @@ pub fn deserialize<T: FromBytes>(bytes: Vec<u8>) -> Result<T, Error> {
     if remainder.is_empty() {
         Ok(t)
     } else {
-        Err(Error::LeftOverBytes)
+        Err(Error::LeftOverBytes {
+            remaining: remainder.len(),
+        })
     }
 }
 
@@ -699,7 +703,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::cell::RefCell;
+    use std::{cell::RefCell, string::ToString};
 
     use super::*;
 
@@ -748,6 +752,36 @@ mod tests {
         // Assert the `INSTANCE_COUNT` has dropped to zero again.
         INSTANCE_COUNT.with(|count| assert_eq!(0, *count.borrow()));
     }
+
+    #[test]
+    fn should_report_early_end_of_stream() {
+        let error = deserialize::<u32>(vec![1, 2]).unwrap_err();
+        assert_eq!(error, Error::EarlyEndOfStream);
+        assert_eq!(
+            error.to_string(),
+            "Deserialization error: early end of stream"
+        );
+    }
+
+    #[test]
+    fn should_report_number_of_left_over_bytes() {
+        let error = deserialize::<u8>(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(error, Error::LeftOverBytes { remaining: 2 });
+        assert_eq!(
+            error.to_string(),
+            "Deserialization error: 2 left-over bytes"
+        );
+    }
+
+    #[test]
+    fn should_report_formatting_error() {
+        assert_eq!(deserialize::<bool>(vec![2]), Err(Error::Formatting));
+        // An `Option` tag other than 0 or 1.
+        assert_eq!(
+            deserialize::<Option<u8>>(vec![7, 1]),
+            Err(Error::Formatting)
+        );
+    }
 }
 
 #[cfg(test)]
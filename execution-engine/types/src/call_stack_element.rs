@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+
+use crate::{
+    account::PublicKey,
+    bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, KEY_HASH_LENGTH, KEY_LOCAL_SEED_LENGTH,
+};
+
+const ACCOUNT_TAG: u8 = 0;
+const CONTRACT_TAG: u8 = 1;
+
+/// The type which a [`CallStackElement`] is serialized as: the tags of an account and a contract
+/// match those of the error and the value of a `Result` respectively.
+type SerializedCallStackElement =
+    Result<([u8; KEY_HASH_LENGTH], [u8; KEY_LOCAL_SEED_LENGTH]), PublicKey>;
+
+/// An entity on the call stack of the currently executing code.
+///
+/// The first element of a call stack is always the account under which the deploy is executing.
+/// Each call to a stored contract via `call_contract` pushes a further element, which is popped
+/// again once the called contract returns.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CallStackElement {
+    /// An account.
+    Account(PublicKey),
+    /// A stored contract.
+    Contract {
+        /// The address of the key under which the contract is stored.
+        contract_hash: [u8; KEY_HASH_LENGTH],
+        /// The seed used to derive the contract's local keys.
+        seed: [u8; KEY_LOCAL_SEED_LENGTH],
+    },
+}
+
+impl ToBytes for CallStackElement {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::unchecked_allocate_buffer(self);
+        match self {
+            CallStackElement::Account(public_key) => {
+                result.push(ACCOUNT_TAG);
+                result.append(&mut public_key.to_bytes()?);
+            }
+            CallStackElement::Contract {
+                contract_hash,
+                seed,
+            } => {
+                result.push(CONTRACT_TAG);
+                result.append(&mut contract_hash.to_bytes()?);
+                result.append(&mut seed.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                CallStackElement::Account(public_key) => public_key.serialized_length(),
+                CallStackElement::Contract {
+                    contract_hash,
+                    seed,
+                } => contract_hash.serialized_length() + seed.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for CallStackElement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ACCOUNT_TAG => {
+                let (public_key, remainder) = PublicKey::from_bytes(remainder)?;
+                Ok((CallStackElement::Account(public_key), remainder))
+            }
+            CONTRACT_TAG => {
+                let (contract_hash, remainder) = <[u8; KEY_HASH_LENGTH]>::from_bytes(remainder)?;
+                let (seed, remainder) = <[u8; KEY_LOCAL_SEED_LENGTH]>::from_bytes(remainder)?;
+                let element = CallStackElement::Contract {
+                    contract_hash,
+                    seed,
+                };
+                Ok((element, remainder))
+            }
+            _ => Err(Error::Formatting),
+        }
+    }
+}
+
+impl CLTyped for CallStackElement {
+    fn cl_type() -> CLType {
+        SerializedCallStackElement::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let account = CallStackElement::Account(PublicKey::ed25519_from([1; 32]));
+        bytesrepr::test_serialization_roundtrip(&account);
+
+        let contract = CallStackElement::Contract {
+            contract_hash: [2; KEY_HASH_LENGTH],
+            seed: [3; KEY_LOCAL_SEED_LENGTH],
+        };
+        bytesrepr::test_serialization_roundtrip(&contract);
+
+        let call_stack = vec![account, contract];
+        bytesrepr::test_serialization_roundtrip(&call_stack);
+    }
+
+    #[test]
+    fn should_serialize_as_cl_type() {
+        let public_key = PublicKey::ed25519_from([1; 32]);
+        let bytes = CallStackElement::Account(public_key).to_bytes().unwrap();
+        let serialized: SerializedCallStackElement = bytesrepr::deserialize(bytes).unwrap();
+        assert_eq!(serialized, Err(public_key));
+
+        let contract = CallStackElement::Contract {
+            contract_hash: [2; KEY_HASH_LENGTH],
+            seed: [3; KEY_LOCAL_SEED_LENGTH],
+        };
+        let serialized: SerializedCallStackElement =
+            bytesrepr::deserialize(contract.to_bytes().unwrap()).unwrap();
+        assert_eq!(
+            serialized,
+            Ok(([2; KEY_HASH_LENGTH], [3; KEY_LOCAL_SEED_LENGTH]))
+        );
+    }
+}
@@ -458,9 +458,16 @@ impl<T1: CLTyped, T2: CLTyped, T3: CLTyped> CLTyped for (T1, T2, T3) {
 mod tests {
     use std::{fmt::Debug, string::ToString};
 
+    use proptest::{
+        collection::{btree_map, vec},
+        option,
+        prelude::*,
+    };
+
     use super::*;
     use crate::{
         bytesrepr::{FromBytes, ToBytes},
+        gens::*,
         AccessRights, CLValue,
     };
 
@@ -682,4 +689,43 @@ mod tests {
         let any = Any("Any test".to_string());
         round_trip(&any);
     }
+
+    proptest! {
+        #[test]
+        fn vec_of_public_keys_should_work(public_keys in vec(public_key_arb(), 0..10)) {
+            round_trip(&public_keys);
+        }
+
+        #[test]
+        fn map_of_u512_should_work(map in btree_map("\\PC*", u512_arb(), 0..10)) {
+            round_trip(&map);
+        }
+
+        #[test]
+        fn option_of_vec_should_work(maybe_keys in option::of(vec(key_arb(), 0..10))) {
+            round_trip(&maybe_keys);
+        }
+
+        #[test]
+        fn tuple_of_composites_should_work(
+            tuple in (
+                vec(uref_arb(), 0..10),
+                named_keys_arb(10),
+                option::of((any::<u64>(), "\\PC*")),
+            )
+        ) {
+            round_trip(&tuple);
+        }
+
+        #[test]
+        fn byte_arrays_should_work(
+            array_8 in any::<[u8; 8]>(),
+            array_16 in any::<[u8; 16]>(),
+            array_32 in any::<[u8; 32]>(),
+        ) {
+            round_trip(&array_8);
+            round_trip(&array_16);
+            round_trip(&array_32);
+        }
+    }
 }
@@ -1,10 +1,14 @@
 use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
 
 use crate::{
-    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    bytesrepr::{self, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
     CLType, CLTyped,
 };
 
+const ENVELOPE_TAG_UNTYPED: u8 = 0;
+const ENVELOPE_TAG_TYPED: u8 = 1;
+
 /// Error while converting a [`CLValue`] into a given type.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct CLTypeMismatch {
@@ -15,6 +19,12 @@ pub struct CLTypeMismatch {
     pub found: CLType,
 }
 
+impl Display for CLTypeMismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "expected {:?} but found {:?}", self.expected, self.found)
+    }
+}
+
 /// Error relating to [`CLValue`] operations.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum CLValueError {
@@ -24,6 +34,15 @@ pub enum CLValueError {
     Type(CLTypeMismatch),
 }
 
+impl Display for CLValueError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CLValueError::Serialization(error) => write!(f, "CLValue error: {}", error),
+            CLValueError::Type(mismatch) => write!(f, "CLValue type mismatch: {}", mismatch),
+        }
+    }
+}
+
 /// A CasperLabs value, i.e. a value which can be stored and manipulated by smart contracts.
 ///
 /// It holds the underlying data as a type-erased, serialized `Vec<u8>` and also holds the
@@ -115,3 +134,177 @@ impl FromBytes for CLValue {
         Ok((cl_value, remainder))
     }
 }
+
+/// A value passed from the host to a contract, prefixed with a byte identifying the version of its
+/// format.
+///
+/// The typed format carries the [`CLType`] of the value along with its data, allowing a contract to
+/// tell a mistyped value from a missing or malformed one.  The untyped format holds only the
+/// serialized data, as passed to contracts before the type was included.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CLValueEnvelope {
+    /// The serialized data of a value of unknown type.
+    Untyped(Vec<u8>),
+    /// A value along with its type.
+    Typed(CLValue),
+}
+
+impl CLValueEnvelope {
+    /// The [`CLType`] of the enclosed value, or `None` if the envelope is untyped.
+    pub fn cl_type(&self) -> Option<&CLType> {
+        match self {
+            CLValueEnvelope::Untyped(_) => None,
+            CLValueEnvelope::Typed(cl_value) => Some(cl_value.cl_type()),
+        }
+    }
+
+    /// Consumes and converts `self` into the enclosed value.
+    ///
+    /// The type of a typed value is checked against `T` before deserializing it, while an untyped
+    /// value is deserialized as a `T` regardless.
+    pub fn into_t<T: CLTyped + FromBytes>(self) -> Result<T, CLValueError> {
+        match self {
+            CLValueEnvelope::Untyped(bytes) => {
+                bytesrepr::deserialize(bytes).map_err(CLValueError::Serialization)
+            }
+            CLValueEnvelope::Typed(cl_value) => cl_value.into_t(),
+        }
+    }
+}
+
+impl ToBytes for CLValueEnvelope {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            CLValueEnvelope::Untyped(bytes) => {
+                result.push(ENVELOPE_TAG_UNTYPED);
+                result.append(&mut bytes.to_bytes()?);
+            }
+            CLValueEnvelope::Typed(cl_value) => {
+                result.push(ENVELOPE_TAG_TYPED);
+                result.append(&mut cl_value.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                CLValueEnvelope::Untyped(bytes) => bytes.serialized_length(),
+                CLValueEnvelope::Typed(cl_value) => cl_value.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for CLValueEnvelope {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ENVELOPE_TAG_UNTYPED => {
+                let (bytes, remainder) = Vec::<u8>::from_bytes(remainder)?;
+                Ok((CLValueEnvelope::Untyped(bytes), remainder))
+            }
+            ENVELOPE_TAG_TYPED => {
+                let (cl_value, remainder) = CLValue::from_bytes(remainder)?;
+                Ok((CLValueEnvelope::Typed(cl_value), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+    use crate::U512;
+
+    #[test]
+    fn envelope_should_roundtrip() {
+        let typed = CLValueEnvelope::Typed(CLValue::from_t(U512::from(1)).unwrap());
+        let untyped = CLValueEnvelope::Untyped(U512::from(1).into_bytes().unwrap());
+        for envelope in &[typed, untyped] {
+            let bytes = envelope.to_bytes().unwrap();
+            assert_eq!(bytes.len(), envelope.serialized_length());
+            assert_eq!(
+                &bytesrepr::deserialize::<CLValueEnvelope>(bytes).unwrap(),
+                envelope
+            );
+        }
+    }
+
+    #[test]
+    fn typed_envelope_should_report_type_mismatch() {
+        let envelope = CLValueEnvelope::Typed(CLValue::from_t(U512::from(1)).unwrap());
+        assert_eq!(envelope.cl_type(), Some(&CLType::U512));
+        assert_eq!(
+            envelope.into_t::<bool>(),
+            Err(CLValueError::Type(CLTypeMismatch {
+                expected: CLType::Bool,
+                found: CLType::U512,
+            }))
+        );
+    }
+
+    #[test]
+    fn untyped_envelope_should_deserialize_without_type_check() {
+        let envelope = CLValueEnvelope::Untyped(U512::from(1).into_bytes().unwrap());
+        assert_eq!(envelope.cl_type(), None);
+        assert_eq!(envelope.into_t::<U512>(), Ok(U512::from(1)));
+    }
+
+    #[test]
+    fn should_fail_to_parse_envelope_of_unknown_version() {
+        let mut bytes = CLValueEnvelope::Untyped(Vec::new()).to_bytes().unwrap();
+        bytes[0] = 2;
+        assert_eq!(
+            bytesrepr::deserialize::<CLValueEnvelope>(bytes),
+            Err(bytesrepr::Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn should_report_each_class_of_conversion_failure() {
+        // Too few bytes for a `U512` of declared byte-length 4.
+        let short = CLValue::from_components(CLType::U512, vec![4, 1, 2]);
+        assert_eq!(
+            short.into_t::<U512>(),
+            Err(CLValueError::Serialization(
+                bytesrepr::Error::EarlyEndOfStream
+            ))
+        );
+
+        let trailing = CLValue::from_components(CLType::U8, vec![1, 2, 3]);
+        let error = trailing.into_t::<u8>().unwrap_err();
+        assert_eq!(
+            error,
+            CLValueError::Serialization(bytesrepr::Error::LeftOverBytes { remaining: 2 })
+        );
+        assert_eq!(
+            error.to_string(),
+            "CLValue error: Deserialization error: 2 left-over bytes"
+        );
+
+        let malformed = CLValue::from_components(CLType::Bool, vec![2]);
+        assert_eq!(
+            malformed.into_t::<bool>(),
+            Err(CLValueError::Serialization(bytesrepr::Error::Formatting))
+        );
+
+        let mismatched = CLValue::from_t(1u32).unwrap();
+        let error = mismatched.into_t::<bool>().unwrap_err();
+        assert_eq!(
+            error,
+            CLValueError::Type(CLTypeMismatch {
+                expected: CLType::Bool,
+                found: CLType::U32,
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "CLValue type mismatch: expected Bool but found U32"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes},
+    ApiError, CLType, CLTyped, Key, URef,
+};
+
+/// The version of a contract within a [`ContractPackage`].  Versions are numbered from 1, in the
+/// order they were added to the package.
+pub type ContractVersion = u32;
+
+/// The type which a [`ContractPackage`] is serialized as: its access key, followed by the key of
+/// each version along with whether it's enabled.
+type SerializedContractPackage = (URef, Vec<(Key, bool)>);
+
+/// The versions of a contract, stored in global state under the hash of the package.
+///
+/// A package is managed by whoever holds its access key, a [`URef`] handed out when the package
+/// is created.  Each version can be disabled, after which calls pinned to it fail rather than
+/// being passed on to another version.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ContractPackage {
+    access_key: URef,
+    versions: Vec<(Key, bool)>,
+}
+
+impl ContractPackage {
+    /// Constructs a new `ContractPackage` without any versions, managed by `access_key`.
+    pub fn new(access_key: URef) -> Self {
+        ContractPackage {
+            access_key,
+            versions: Vec::new(),
+        }
+    }
+
+    /// Returns the key which has to be presented to add or disable versions.
+    pub fn access_key(&self) -> URef {
+        self.access_key
+    }
+
+    /// Returns the number of versions in the package, including disabled ones.
+    pub fn version_count(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Adds the contract under `contract_key` as a new, enabled version and returns its version.
+    pub fn add_version(&mut self, contract_key: Key) -> ContractVersion {
+        self.versions.push((contract_key, true));
+        // For all practical purposes the number of versions fits in a u32.
+        self.versions.len() as ContractVersion
+    }
+
+    /// Disables `version`.  Fails with [`ApiError::ContractVersionNotFound`] if there is no such
+    /// version.
+    pub fn disable_version(&mut self, version: ContractVersion) -> Result<(), ApiError> {
+        let index = Self::index(version).ok_or(ApiError::ContractVersionNotFound)?;
+        match self.versions.get_mut(index) {
+            Some((_, enabled)) => {
+                *enabled = false;
+                Ok(())
+            }
+            None => Err(ApiError::ContractVersionNotFound),
+        }
+    }
+
+    /// Returns `true` if `version` exists and is enabled.
+    pub fn is_version_enabled(&self, version: ContractVersion) -> bool {
+        Self::index(version)
+            .and_then(|index| self.versions.get(index))
+            .map_or(false, |(_, enabled)| *enabled)
+    }
+
+    /// Returns the highest enabled version, or `None` if all versions are disabled.
+    pub fn latest_enabled_version(&self) -> Option<ContractVersion> {
+        self.versions
+            .iter()
+            .rposition(|(_, enabled)| *enabled)
+            .map(|index| index as ContractVersion + 1)
+    }
+
+    /// Returns the key of the contract to call for `maybe_version`, which is the highest enabled
+    /// version if `None`.
+    ///
+    /// Fails with [`ApiError::ContractVersionDisabled`] if the given version is disabled, and with
+    /// [`ApiError::ContractVersionNotFound`] if there is no such version, or no enabled one.
+    pub fn resolve(&self, maybe_version: Option<ContractVersion>) -> Result<Key, ApiError> {
+        let version = maybe_version
+            .or_else(|| self.latest_enabled_version())
+            .ok_or(ApiError::ContractVersionNotFound)?;
+        let index = Self::index(version).ok_or(ApiError::ContractVersionNotFound)?;
+        match self.versions.get(index) {
+            Some((contract_key, true)) => Ok(*contract_key),
+            Some((_, false)) => Err(ApiError::ContractVersionDisabled),
+            None => Err(ApiError::ContractVersionNotFound),
+        }
+    }
+
+    fn index(version: ContractVersion) -> Option<usize> {
+        version.checked_sub(1).map(|index| index as usize)
+    }
+}
+
+impl ToBytes for ContractPackage {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.access_key.to_bytes()?);
+        result.append(&mut self.versions.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.access_key.serialized_length() + self.versions.serialized_length()
+    }
+}
+
+impl FromBytes for ContractPackage {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (access_key, remainder) = URef::from_bytes(bytes)?;
+        let (versions, remainder) = Vec::<(Key, bool)>::from_bytes(remainder)?;
+        let package = ContractPackage {
+            access_key,
+            versions,
+        };
+        Ok((package, remainder))
+    }
+}
+
+impl CLTyped for ContractPackage {
+    fn cl_type() -> CLType {
+        SerializedContractPackage::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AccessRights;
+
+    const CONTRACT_1: Key = Key::Hash([1; 32]);
+    const CONTRACT_2: Key = Key::Hash([2; 32]);
+
+    fn package_with_two_versions() -> ContractPackage {
+        let access_key = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let mut package = ContractPackage::new(access_key);
+        assert_eq!(package.add_version(CONTRACT_1), 1);
+        assert_eq!(package.add_version(CONTRACT_2), 2);
+        package
+    }
+
+    #[test]
+    fn should_resolve_latest_enabled_version() {
+        let mut package = package_with_two_versions();
+        assert_eq!(package.resolve(None), Ok(CONTRACT_2));
+        assert_eq!(package.resolve(Some(1)), Ok(CONTRACT_1));
+
+        package.disable_version(2).unwrap();
+        assert!(!package.is_version_enabled(2));
+        assert_eq!(package.resolve(None), Ok(CONTRACT_1));
+        assert_eq!(
+            package.resolve(Some(2)),
+            Err(ApiError::ContractVersionDisabled)
+        );
+
+        package.disable_version(1).unwrap();
+        assert_eq!(package.latest_enabled_version(), None);
+        assert_eq!(
+            package.resolve(None),
+            Err(ApiError::ContractVersionNotFound)
+        );
+    }
+
+    #[test]
+    fn should_not_find_missing_versions() {
+        let mut package = package_with_two_versions();
+        let not_found = ApiError::ContractVersionNotFound;
+        assert_eq!(package.resolve(Some(0)), Err(not_found));
+        assert_eq!(package.resolve(Some(3)), Err(not_found));
+        assert_eq!(package.disable_version(3), Err(not_found));
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut package = package_with_two_versions();
+        package.disable_version(1).unwrap();
+        bytesrepr::test_serialization_roundtrip(&package);
+    }
+
+    #[test]
+    fn should_serialize_as_cl_type() {
+        let mut package = package_with_two_versions();
+        package.disable_version(1).unwrap();
+        let serialized: SerializedContractPackage =
+            bytesrepr::deserialize(package.to_bytes().unwrap()).unwrap();
+        assert_eq!(
+            serialized,
+            (
+                package.access_key(),
+                vec![(CONTRACT_1, false), (CONTRACT_2, true)]
+            )
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use core::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::ApiError;
+
+/// Features of the execution engine which are turned on or off in its configuration.
+///
+/// Used by converting to a `u32` and passing as the `feature` argument of
+/// `ext_ffi::is_feature_enabled()`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EngineFeature {
+    /// The system contracts are executed as Wasm rather than by host-side logic.
+    UseSystemContracts,
+    /// Validators can bond with and unbond from the Proof of Stake contract.
+    EnableBonding,
+}
+
+impl From<EngineFeature> for u32 {
+    fn from(engine_feature: EngineFeature) -> u32 {
+        match engine_feature {
+            EngineFeature::UseSystemContracts => 0,
+            EngineFeature::EnableBonding => 1,
+        }
+    }
+}
+
+// This conversion is not intended to be used by third party crates.
+#[doc(hidden)]
+impl TryFrom<u32> for EngineFeature {
+    type Error = ApiError;
+    fn try_from(value: u32) -> Result<EngineFeature, Self::Error> {
+        match value {
+            0 => Ok(EngineFeature::UseSystemContracts),
+            1 => Ok(EngineFeature::EnableBonding),
+            _ => Err(ApiError::InvalidArgument),
+        }
+    }
+}
+
+impl Display for EngineFeature {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            EngineFeature::UseSystemContracts => write!(f, "use-system-contracts"),
+            EngineFeature::EnableBonding => write!(f, "enable-bonding"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_to_and_from_u32() {
+        for engine_feature in &[
+            EngineFeature::UseSystemContracts,
+            EngineFeature::EnableBonding,
+        ] {
+            let index = u32::from(*engine_feature);
+            assert_eq!(EngineFeature::try_from(index), Ok(*engine_feature));
+        }
+        assert_eq!(EngineFeature::try_from(2), Err(ApiError::InvalidArgument));
+    }
+}
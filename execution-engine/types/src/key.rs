@@ -1,14 +1,18 @@
 use alloc::{format, string::String, vec::Vec};
-use core::fmt::{self, Debug, Display, Formatter};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Debug, Display, Formatter},
+};
 
 use blake2::{
     digest::{Input, VariableOutput},
     VarBlake2b,
 };
+use failure::Fail;
 use hex_fmt::HexFmt;
 
 use crate::{
-    account::PublicKey,
+    account::{PublicKey, TryFromIntError},
     bytesrepr::{self, Error, FromBytes, ToBytes},
     ContractRef, URef, UREF_SERIALIZED_LENGTH,
 };
@@ -26,6 +30,9 @@ pub const KEY_HASH_LENGTH: usize = 32;
 pub const KEY_LOCAL_LENGTH: usize = 64;
 /// The number of bytes in the seed for a new [`Key::Local`].
 pub const KEY_LOCAL_SEED_LENGTH: usize = 32;
+/// The maximum number of key bytes which can be hashed with a seed to form a [`Key::Local`] from
+/// within a contract.
+pub const KEY_LOCAL_MAX_KEY_BYTES: usize = 1024;
 
 const KEY_ID_SERIALIZED_LENGTH: usize = 1; // u8 used to determine the ID
 const KEY_HASH_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
@@ -140,6 +147,15 @@ impl Key {
         }
     }
 
+    /// Returns a reference to the inner bytes of `self` if `self` is of type [`Key::Hash`],
+    /// otherwise returns `None`.
+    pub fn as_hash(&self) -> Option<&[u8; KEY_HASH_LENGTH]> {
+        match self {
+            Key::Hash(hash) => Some(hash),
+            _ => None,
+        }
+    }
+
     /// Returns a reference to the inner [`URef`] if `self` is of type [`Key::URef`], otherwise
     /// returns `None`.
     pub fn as_uref(&self) -> Option<&URef> {
@@ -170,6 +186,17 @@ impl Key {
             _ => None,
         }
     }
+
+    /// Returns the seed of the [`Key::Local`]s belonging to the account or contract stored under
+    /// `self`, i.e. the seed its local keys are constructed from by [`Key::local`].
+    pub fn local_seed(&self) -> [u8; KEY_LOCAL_SEED_LENGTH] {
+        match self {
+            Key::Account(PublicKey::Ed25519(bytes)) => bytes.value(),
+            Key::Hash(bytes) => *bytes,
+            Key::URef(uref) => uref.addr(),
+            Key::Local { seed, .. } => *seed,
+        }
+    }
 }
 
 impl Display for Key {
@@ -257,6 +284,45 @@ impl FromBytes for Key {
     }
 }
 
+/// Errors that can occur while updating a named key via compare-and-swap.
+#[derive(Fail, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum UpdateKeyError {
+    /// The named key exists, but its current value is not the expected one, or a value was
+    /// expected to be absent.
+    #[fail(display = "Unable to update named key because its value is not the expected one")]
+    KeyMismatch = 1,
+    /// A value was expected, but there is no named key with the given name.
+    #[fail(display = "Unable to update named key because it does not exist")]
+    KeyMissing = 2,
+}
+
+// This conversion is not intended to be used by third party crates.
+#[doc(hidden)]
+impl TryFrom<i32> for UpdateKeyError {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            d if d == UpdateKeyError::KeyMismatch as i32 => Ok(UpdateKeyError::KeyMismatch),
+            d if d == UpdateKeyError::KeyMissing as i32 => Ok(UpdateKeyError::KeyMissing),
+            _ => Err(TryFromIntError(())),
+        }
+    }
+}
+
+/// Returns the name under which `put_key_versioned` archives version `version` of the named key
+/// `name`, i.e. `name#<version>`.
+pub fn versioned_key_name(name: &str, version: u32) -> String {
+    format!("{}#{}", name, version)
+}
+
+/// Returns the name under which `put_key_versioned` stores the [`URef`] of the version count of
+/// the named key `name`, i.e. `name#versions`.
+pub fn key_versions_name(name: &str) -> String {
+    format!("{}#versions", name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,6 +425,7 @@ mod tests {
         let key1 = Key::Account(public_key);
         assert_eq!(key1.into_account(), Some(public_key));
         assert!(key1.into_hash().is_none());
+        assert!(key1.as_hash().is_none());
         assert!(key1.as_uref().is_none());
         assert!(key1.into_local().is_none());
     }
@@ -369,6 +436,7 @@ mod tests {
         let key1 = Key::Hash(hash);
         assert!(key1.into_account().is_none());
         assert_eq!(key1.into_hash(), Some(hash));
+        assert_eq!(key1.as_hash(), Some(&hash));
         assert!(key1.as_uref().is_none());
         assert!(key1.into_local().is_none());
     }
@@ -379,6 +447,7 @@ mod tests {
         let key1 = Key::URef(uref);
         assert!(key1.into_account().is_none());
         assert!(key1.into_hash().is_none());
+        assert!(key1.as_hash().is_none());
         assert_eq!(key1.as_uref(), Some(&uref));
         assert!(key1.into_local().is_none());
     }
@@ -392,10 +461,22 @@ mod tests {
         };
         assert!(key1.into_account().is_none());
         assert!(key1.into_hash().is_none());
+        assert!(key1.as_hash().is_none());
         assert!(key1.as_uref().is_none());
         assert_eq!(key1.into_local().map(|x| x.to_vec()), Some(local.to_vec()));
     }
 
+    #[test]
+    fn check_key_local_seeds() {
+        let account = Key::Account(PublicKey::ed25519_from([1; 32]));
+        assert_eq!(account.local_seed(), [1; KEY_LOCAL_SEED_LENGTH]);
+        assert_eq!(Key::Hash([2; 32]).local_seed(), [2; KEY_LOCAL_SEED_LENGTH]);
+        let uref = Key::URef(URef::new([3; 32], AccessRights::READ));
+        assert_eq!(uref.local_seed(), [3; KEY_LOCAL_SEED_LENGTH]);
+        let local = Key::local([4; KEY_LOCAL_SEED_LENGTH], &[5; 32]);
+        assert_eq!(local.local_seed(), [4; KEY_LOCAL_SEED_LENGTH]);
+    }
+
     #[test]
     fn key_max_serialized_length() {
         let key_account = Key::Account(PublicKey::ed25519_from([42; 32]));
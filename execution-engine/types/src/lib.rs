@@ -25,36 +25,51 @@ pub mod account;
 pub mod api_error;
 mod block_time;
 pub mod bytesrepr;
+mod call_stack_element;
 mod cl_type;
 mod cl_value;
+mod contract_package;
 mod contract_ref;
+mod engine_feature;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
 mod key;
 mod phase;
+mod protocol_info;
 mod protocol_version;
+mod runtime_args;
 mod semver;
 pub mod system_contract_errors;
 mod system_contract_type;
 mod transfer_result;
 mod uint;
 mod uref;
+mod wasm_costs;
 
 pub use crate::uint::{UIntParseError, U128, U256, U512};
 pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
-pub use api_error::ApiError;
+pub use api_error::{ApiError, MAX_REVERT_PAYLOAD_LENGTH};
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
+pub use call_stack_element::CallStackElement;
 pub use cl_type::{named_key_type, CLType, CLTyped};
-pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
+pub use cl_value::{CLTypeMismatch, CLValue, CLValueEnvelope, CLValueError};
+pub use contract_package::{ContractPackage, ContractVersion};
 pub use contract_ref::ContractRef;
+pub use engine_feature::EngineFeature;
 #[doc(inline)]
 pub use key::{
-    Key, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_SEED_LENGTH,
+    key_versions_name, versioned_key_name, Key, UpdateKeyError, BLAKE2B_DIGEST_LENGTH,
+    KEY_HASH_LENGTH, KEY_LOCAL_LENGTH, KEY_LOCAL_MAX_KEY_BYTES, KEY_LOCAL_SEED_LENGTH,
 };
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
+pub use protocol_info::ProtocolInfo;
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
+pub use runtime_args::{RuntimeArgs, PAYMENT_PURSE_ARG};
 pub use semver::SemVer;
 pub use system_contract_type::SystemContractType;
 pub use transfer_result::{TransferResult, TransferredTo};
 pub use uref::{URef, UREF_ADDR_LENGTH, UREF_SERIALIZED_LENGTH};
+pub use wasm_costs::{
+    WasmCosts, LEGACY_WASM_COSTS_SERIALIZED_LENGTH, WASM_COSTS_SERIALIZED_LENGTH,
+};
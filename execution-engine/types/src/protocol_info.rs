@@ -0,0 +1,89 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes},
+    CLType, CLTyped, ProtocolVersion,
+};
+
+/// The type which a [`ProtocolInfo`] is serialized as: the major, minor and patch parts of the
+/// protocol version, followed by the chain name.
+type SerializedProtocolInfo = ((u32, u32, u32), String);
+
+/// The protocol version and chain name of the network on which a contract is executing.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProtocolInfo {
+    protocol_version: ProtocolVersion,
+    chain_name: String,
+}
+
+impl ProtocolInfo {
+    /// Constructs a new `ProtocolInfo`.
+    pub fn new(protocol_version: ProtocolVersion, chain_name: String) -> Self {
+        ProtocolInfo {
+            protocol_version,
+            chain_name,
+        }
+    }
+
+    /// Returns the protocol version currently in effect.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the name of the chain as given in its genesis configuration.
+    pub fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+}
+
+impl ToBytes for ProtocolInfo {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.protocol_version.to_bytes()?);
+        result.append(&mut self.chain_name.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.protocol_version.serialized_length() + self.chain_name.serialized_length()
+    }
+}
+
+impl FromBytes for ProtocolInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (protocol_version, remainder) = ProtocolVersion::from_bytes(bytes)?;
+        let (chain_name, remainder) = String::from_bytes(remainder)?;
+        Ok((ProtocolInfo::new(protocol_version, chain_name), remainder))
+    }
+}
+
+impl CLTyped for ProtocolInfo {
+    fn cl_type() -> CLType {
+        SerializedProtocolInfo::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let protocol_info = ProtocolInfo::new(
+            ProtocolVersion::from_parts(1, 2, 3),
+            String::from("casperlabs-testnet"),
+        );
+        bytesrepr::test_serialization_roundtrip(&protocol_info);
+    }
+
+    #[test]
+    fn should_serialize_as_cl_type() {
+        let protocol_info = ProtocolInfo::new(
+            ProtocolVersion::from_parts(1, 2, 3),
+            String::from("casperlabs-testnet"),
+        );
+        let serialized: SerializedProtocolInfo =
+            bytesrepr::deserialize(protocol_info.to_bytes().unwrap()).unwrap();
+        assert_eq!(serialized, ((1, 2, 3), String::from("casperlabs-testnet")));
+    }
+}
@@ -0,0 +1,259 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLTyped, CLValue,
+};
+
+/// The name of the argument under which a contract called with a payment receives the temporary
+/// purse funding it.
+pub const PAYMENT_PURSE_ARG: &str = "payment_purse";
+
+/// The tag preceding the names of the arguments in their serialized form.
+const NAMES_TAG: u8 = 1;
+
+/// The arguments passed to a contract, each of which can be accessed by its position and, if it
+/// was given one, by its name.
+///
+/// Named arguments are kept in insertion order, so that the i-th named argument is also the i-th
+/// positional argument.
+///
+/// The arguments serialize as the `Vec` of their values, followed by a tag byte and the `Vec` of
+/// their names if any argument is named.  Hence positional arguments serialize exactly as a
+/// `Vec<CLValue>`, and any bytes following them which don't start with the tag are left unparsed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeArgs {
+    values: Vec<CLValue>,
+    /// The names of the values, where an empty name marks a positional argument.
+    names: Vec<String>,
+}
+
+impl RuntimeArgs {
+    /// Constructs an empty `RuntimeArgs`.
+    pub fn new() -> Self {
+        RuntimeArgs::default()
+    }
+
+    /// Appends an argument named `name` with the given `value`, or replaces the value of an
+    /// existing argument of that name in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` can't be converted to a [`CLValue`].
+    pub fn insert<K: Into<String>, V: CLTyped + ToBytes>(&mut self, name: K, value: V) {
+        let cl_value = CLValue::from_t(value).expect("should create CLValue");
+        self.insert_cl_value(name, cl_value);
+    }
+
+    /// Appends an argument named `name` with the given `cl_value`, or replaces the value of an
+    /// existing argument of that name in place.
+    pub fn insert_cl_value<K: Into<String>>(&mut self, name: K, cl_value: CLValue) {
+        let name = name.into();
+        if let Some(index) = self.index_of(&name) {
+            self.values[index] = cl_value;
+            return;
+        }
+        self.values.push(cl_value);
+        self.names.push(name);
+    }
+
+    /// Returns the value of the argument named `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&CLValue> {
+        let index = self.index_of(name)?;
+        self.values.get(index)
+    }
+
+    /// Returns the position of the argument named `name`, if any.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        if name.is_empty() {
+            return None;
+        }
+        self.names.iter().position(|existing| existing == name)
+    }
+
+    /// Returns the value of the argument at position `index`, if any.
+    pub fn get_by_index(&self, index: usize) -> Option<&CLValue> {
+        self.values.get(index)
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the values of the arguments in order.
+    pub fn values(&self) -> &[CLValue] {
+        &self.values
+    }
+
+    /// Consumes `self`, returning the values of the arguments in order.
+    pub fn into_values(self) -> Vec<CLValue> {
+        self.values
+    }
+
+    fn is_named(&self) -> bool {
+        self.names.iter().any(|name| !name.is_empty())
+    }
+}
+
+impl From<Vec<CLValue>> for RuntimeArgs {
+    fn from(values: Vec<CLValue>) -> Self {
+        let names = values.iter().map(|_| String::new()).collect();
+        RuntimeArgs { values, names }
+    }
+}
+
+impl ToBytes for RuntimeArgs {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::unchecked_allocate_buffer(self);
+        result.append(&mut self.values.to_bytes()?);
+        if self.is_named() {
+            result.push(NAMES_TAG);
+            result.append(&mut self.names.to_bytes()?);
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        let names_length = if self.is_named() {
+            U8_SERIALIZED_LENGTH + self.names.serialized_length()
+        } else {
+            0
+        };
+        self.values.serialized_length() + names_length
+    }
+}
+
+impl FromBytes for RuntimeArgs {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (values, remainder) = Vec::<CLValue>::from_bytes(bytes)?;
+        let remainder = match remainder.split_first() {
+            Some((&NAMES_TAG, remainder)) => remainder,
+            _ => return Ok((RuntimeArgs::from(values), remainder)),
+        };
+        let (names, remainder) = Vec::<String>::from_bytes(remainder)?;
+        if names.len() != values.len() {
+            return Err(Error::Formatting);
+        }
+        Ok((RuntimeArgs { values, names }, remainder))
+    }
+}
+
+/// Constructs a [`RuntimeArgs`] from a comma-separated list of `name => value` pairs, where each
+/// value is of a type implementing [`ToBytes`] and [`CLTyped`].
+///
+/// ```rust
+/// # use casperlabs_types::{runtime_args, U512};
+/// let args = runtime_args! {
+///     "amount" => U512::from(10),
+///     "note" => "rent",
+/// };
+/// assert_eq!(args.len(), 2);
+/// assert_eq!(args.get("amount"), args.get_by_index(0));
+/// ```
+#[macro_export]
+macro_rules! runtime_args {
+    () => {
+        $crate::RuntimeArgs::new()
+    };
+    ($($name:expr => $value:expr),+ $(,)?) => {{
+        let mut named_args = $crate::RuntimeArgs::new();
+        $(named_args.insert($name, $value);)+
+        named_args
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+    use crate::U512;
+
+    #[test]
+    fn positional_args_should_serialize_as_vec_of_values() {
+        let values = vec![
+            CLValue::from_t(1u64).unwrap(),
+            CLValue::from_t(true).unwrap(),
+        ];
+        let args = RuntimeArgs::from(values.clone());
+        assert_eq!(args.to_bytes().unwrap(), values.to_bytes().unwrap());
+        assert_eq!(args.to_bytes().unwrap().len(), args.serialized_length());
+
+        let parsed: RuntimeArgs = bytesrepr::deserialize(values.to_bytes().unwrap()).unwrap();
+        assert_eq!(parsed, args);
+        assert_eq!(parsed.get(""), None);
+    }
+
+    #[test]
+    fn named_args_should_roundtrip() {
+        let args = runtime_args! {
+            "amount" => U512::from(10),
+            "target" => "alice".to_string(),
+        };
+        let bytes = args.to_bytes().unwrap();
+        assert_eq!(bytes.len(), args.serialized_length());
+
+        let parsed: RuntimeArgs = bytesrepr::deserialize(bytes).unwrap();
+        assert_eq!(parsed, args);
+        assert_eq!(
+            parsed.get("amount"),
+            Some(&CLValue::from_t(U512::from(10)).unwrap())
+        );
+        assert_eq!(parsed.get("target"), parsed.get_by_index(1));
+        assert_eq!(parsed.get("missing"), None);
+    }
+
+    #[test]
+    fn should_replace_value_of_existing_name_in_place() {
+        let mut args = runtime_args! { "first" => 1u32, "second" => 2u32 };
+        args.insert("first", 3u32);
+
+        assert_eq!(args.len(), 2);
+        assert_eq!(args.get_by_index(0), Some(&CLValue::from_t(3u32).unwrap()));
+    }
+
+    #[test]
+    fn should_reject_mismatched_names() {
+        let values = vec![CLValue::from_t(1u64).unwrap()];
+        let names = vec!["first".to_string(), "second".to_string()];
+        let mut bytes = values.to_bytes().unwrap();
+        bytes.push(NAMES_TAG);
+        bytes.append(&mut names.to_bytes().unwrap());
+
+        assert_eq!(
+            bytesrepr::deserialize::<RuntimeArgs>(bytes),
+            Err(Error::Formatting)
+        );
+    }
+
+    #[test]
+    fn should_not_parse_trailing_bytes_of_positional_args_as_names() {
+        let values = vec![CLValue::from_t(1u64).unwrap()];
+        let trailing = vec!["not".to_string(), "names".to_string()]
+            .to_bytes()
+            .unwrap();
+        let mut bytes = values.to_bytes().unwrap();
+        bytes.extend_from_slice(&trailing);
+
+        let (parsed, remainder) = RuntimeArgs::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, RuntimeArgs::from(values));
+        assert_eq!(remainder, trailing.as_slice());
+    }
+
+    #[test]
+    fn should_leave_bytes_following_named_args() {
+        let args = runtime_args! { "amount" => U512::from(10) };
+        let mut bytes = args.to_bytes().unwrap();
+        bytes.extend_from_slice(&[2, 3]);
+
+        let (parsed, remainder) = RuntimeArgs::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, args);
+        assert_eq!(remainder, &[2, 3]);
+    }
+}
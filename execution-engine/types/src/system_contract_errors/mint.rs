@@ -14,7 +14,7 @@ use crate::{
 #[derive(Fail, Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u8)]
 pub enum Error {
-    /// Insufficient funds to complete the transfer.
+    /// Insufficient funds to complete the transfer or burn.
     #[fail(display = "Insufficient funds")]
     InsufficientFunds = 0,
     /// Source purse not found.
@@ -38,6 +38,15 @@ pub enum Error {
     /// Purse not found while trying to get balance.
     #[fail(display = "Purse not found")]
     PurseNotFound = 7,
+    /// Tried to refund the balance of a purse being deleted into the same purse.
+    #[fail(display = "Refund purse is the deleted purse")]
+    RefundToDeletedPurse = 8,
+    /// Tried to create a sub-purse of a purse which is itself a sub-purse.
+    #[fail(display = "Nested sub-purse")]
+    NestedSubPurse = 9,
+    /// The purse is not a sub-purse of the given parent, or has already been revoked.
+    #[fail(display = "Sub-purse not found")]
+    SubPurseNotFound = 10,
 }
 
 impl From<PurseError> for Error {
@@ -79,6 +88,11 @@ impl TryFrom<u8> for Error {
             d if d == Error::InvalidNonEmptyPurseCreation as u8 => {
                 Ok(Error::InvalidNonEmptyPurseCreation)
             }
+            d if d == Error::Storage as u8 => Ok(Error::Storage),
+            d if d == Error::PurseNotFound as u8 => Ok(Error::PurseNotFound),
+            d if d == Error::RefundToDeletedPurse as u8 => Ok(Error::RefundToDeletedPurse),
+            d if d == Error::NestedSubPurse as u8 => Ok(Error::NestedSubPurse),
+            d if d == Error::SubPurseNotFound as u8 => Ok(Error::SubPurseNotFound),
             _ => Err(TryFromU8ForError(())),
         }
     }
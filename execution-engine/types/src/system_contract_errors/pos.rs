@@ -77,6 +77,36 @@ pub enum Error {
     /// PoS contract's "set_refund_purse" method can only be called by the payment code of a
     /// deploy, but was called by the session code.
     SetRefundPurseCalledOutsidePayment,
+    /// None of the validator's unbonded stake has been queued for at least the unbonding delay.
+    NoWithdrawalsDue,
+    /// Internal error: failed to deserialize the queue of unbonded stake awaiting withdrawal.
+    WithdrawalsDeserializationFailed,
+    /// Internal error: failed to deserialize the unbonding delay.
+    UnbondingDelayDeserializationFailed,
+    /// Attempted to set a commission rate above 10,000 basis points.
+    CommissionRateTooHigh,
+    /// The validator has already set its commission rate in the current era.
+    CommissionAlreadySetThisEra,
+    /// Internal error: failed to deserialize the validators' commission rates.
+    CommissionsDeserializationFailed,
+    /// Internal error: failed to find or deserialize the gas price.
+    GasPriceDeserializationFailed,
+    /// Attempted to bond, unbond or withdraw unbonded stake while bonding is disabled in the
+    /// engine's configuration.
+    BondingDisabled,
+    /// Internal error: the PoS contract's community purse wasn't found.
+    CommunityPurseNotFound,
+    /// Internal error: the PoS contract's community purse key was the wrong type.
+    CommunityPurseKeyUnexpectedType,
+    /// Internal error: while slashing, the transfer of the slashed stake from the PoS contract's
+    /// bonding purse to its community purse failed.
+    SlashTransferFailed,
+    /// Internal error: while distributing rewards, failed to read the balance of the PoS
+    /// contract's rewards purse.
+    RewardsPurseBalanceNotFound,
+    /// Internal error: while distributing rewards, the transfer of a validator's reward from the
+    /// PoS contract's rewards purse to its account failed.
+    RewardTransferFailed,
 }
 
 impl CLTyped for Error {
@@ -129,4 +159,11 @@ impl PurseLookupError {
             PurseLookupError::KeyUnexpectedType => Error::RewardsPurseKeyUnexpectedType,
         }
     }
+
+    pub fn community(err: PurseLookupError) -> Error {
+        match err {
+            PurseLookupError::KeyNotFound => Error::CommunityPurseNotFound,
+            PurseLookupError::KeyUnexpectedType => Error::CommunityPurseKeyUnexpectedType,
+        }
+    }
 }
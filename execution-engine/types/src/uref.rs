@@ -37,9 +37,25 @@ impl URef {
         self.1
     }
 
-    /// Returns a new [`URef`] with the same address and updated access rights.
+    /// Returns a new [`URef`] with the same address and only those of `access_rights` which this
+    /// `URef` already has.
+    ///
+    /// Rights can only be removed this way, never added.  Constructing a `URef` with rights its
+    /// holder doesn't have amounts to forging it, which the host rejects when the `URef` is used.
     pub fn with_access_rights(self, access_rights: AccessRights) -> Self {
-        URef(self.0, access_rights)
+        URef(self.0, self.1 & access_rights)
+    }
+
+    /// Returns a new [`URef`] with the same address and at most [`AccessRights::READ`]
+    /// permission.
+    pub fn into_read_only(self) -> URef {
+        self.with_access_rights(AccessRights::READ)
+    }
+
+    /// Returns a new [`URef`] with the same address and at most [`AccessRights::ADD`]
+    /// permission.
+    pub fn into_add_only(self) -> URef {
+        self.with_access_rights(AccessRights::ADD)
     }
 
     /// Removes the access rights from this [`URef`].
@@ -165,4 +181,24 @@ mod tests {
             "uref-0000000000000000000000000000000000000000000000000000000000000000-000"
         );
     }
+
+    #[test]
+    fn should_only_remove_access_rights() {
+        let addr_array = [0u8; 32];
+        let uref = URef::new(addr_array, AccessRights::READ_ADD_WRITE);
+
+        assert_eq!(uref.into_read_only().access_rights(), AccessRights::READ);
+        assert_eq!(uref.into_add_only().access_rights(), AccessRights::ADD);
+        let add_write = uref.with_access_rights(AccessRights::ADD_WRITE);
+        assert_eq!(add_write.access_rights(), AccessRights::ADD_WRITE);
+
+        let read_only = uref.into_read_only();
+        let escalated = read_only.with_access_rights(AccessRights::READ_ADD_WRITE);
+        assert_eq!(escalated.access_rights(), AccessRights::READ);
+        assert_eq!(
+            read_only.into_add_only().access_rights(),
+            AccessRights::NONE
+        );
+        assert_eq!(read_only.into_add_only().addr(), addr_array);
+    }
 }
@@ -0,0 +1,216 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{self, Error, FromBytes, ToBytes, U32_SERIALIZED_LENGTH},
+    CLType, CLTyped,
+};
+
+const NUM_FIELDS: usize = 16;
+/// The number of bytes in a serialized [`WasmCosts`].
+pub const WASM_COSTS_SERIALIZED_LENGTH: usize = NUM_FIELDS * U32_SERIALIZED_LENGTH;
+
+/// The number of fields serialized in the legacy layout, i.e. those up to and including
+/// `opcodes_div`.
+const LEGACY_NUM_FIELDS: usize = 10;
+/// The number of bytes in a [`WasmCosts`] serialized in the legacy layout, as held in the protocol
+/// data stored by engines which predate the fields following `opcodes_div`.
+pub const LEGACY_WASM_COSTS_SERIALIZED_LENGTH: usize = LEGACY_NUM_FIELDS * U32_SERIALIZED_LENGTH;
+
+/// The costs of executing Wasm, charged as gas, along with limits on the modules which can be
+/// executed.
+///
+/// The active costs are part of the protocol data of the current protocol version, and can be
+/// read by contracts via `runtime::get_wasm_costs` in the contract API.
+// Taken (partially) from parity-ethereum
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct WasmCosts {
+    /// Default opcode cost
+    pub regular: u32,
+    /// Div operations multiplier.
+    pub div: u32,
+    /// Mul operations multiplier.
+    pub mul: u32,
+    /// Memory (load/store) operations multiplier.
+    pub mem: u32,
+    /// Memory stipend. Amount of free memory (in 64kb pages) each contract can
+    /// use for stack.
+    pub initial_mem: u32,
+    /// Grow memory cost, per page (64kb)
+    pub grow_mem: u32,
+    /// Memory copy cost, per byte
+    pub memcpy: u32,
+    /// Max stack height (native WebAssembly stack limiter)
+    pub max_stack_height: u32,
+    /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
+    /// `opcodes_div`
+    pub opcodes_mul: u32,
+    /// Cost of wasm opcode is calculated as TABLE_ENTRY_COST * `opcodes_mul` /
+    /// `opcodes_div`
+    pub opcodes_div: u32,
+    /// Storage write cost, per byte of the value written.  Zero means writes are only charged for
+    /// the opcodes executed.
+    pub storage_per_byte: u32,
+    /// Fixed cost of a native transfer, i.e. one executed without any Wasm.
+    pub native_transfer: u32,
+    /// Cost of hashing via the `blake2b256` and `keccak256` host functions, per byte of the
+    /// input hashed.
+    pub hash_per_byte: u32,
+    /// Max initial memory (in 64kb pages) a module may declare.  Zero means no limit.
+    pub max_initial_memory_pages: u32,
+    /// Max maximum memory (in 64kb pages) a module may declare.  Zero means no limit.
+    pub max_memory_pages: u32,
+    /// Cost of preprocessing a Wasm module supplied as session code, per byte of the module.
+    pub preprocess_per_byte: u32,
+}
+
+impl WasmCosts {
+    /// Deserializes a [`WasmCosts`] from the legacy layout, which holds only the fields up to and
+    /// including `opcodes_div`.  The fields added since are zero, so that nothing more is charged
+    /// or limited than before they were added.
+    pub fn from_legacy_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (regular, rem): (u32, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (initial_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (grow_mem, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (memcpy, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_stack_height, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (opcodes_mul, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (opcodes_div, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let wasm_costs = WasmCosts {
+            regular,
+            div,
+            mul,
+            mem,
+            initial_mem,
+            grow_mem,
+            memcpy,
+            max_stack_height,
+            opcodes_mul,
+            opcodes_div,
+            ..WasmCosts::default()
+        };
+        Ok((wasm_costs, rem))
+    }
+}
+
+impl ToBytes for WasmCosts {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.append(&mut self.regular.to_bytes()?);
+        ret.append(&mut self.div.to_bytes()?);
+        ret.append(&mut self.mul.to_bytes()?);
+        ret.append(&mut self.mem.to_bytes()?);
+        ret.append(&mut self.initial_mem.to_bytes()?);
+        ret.append(&mut self.grow_mem.to_bytes()?);
+        ret.append(&mut self.memcpy.to_bytes()?);
+        ret.append(&mut self.max_stack_height.to_bytes()?);
+        ret.append(&mut self.opcodes_mul.to_bytes()?);
+        ret.append(&mut self.opcodes_div.to_bytes()?);
+        ret.append(&mut self.storage_per_byte.to_bytes()?);
+        ret.append(&mut self.native_transfer.to_bytes()?);
+        ret.append(&mut self.hash_per_byte.to_bytes()?);
+        ret.append(&mut self.max_initial_memory_pages.to_bytes()?);
+        ret.append(&mut self.max_memory_pages.to_bytes()?);
+        ret.append(&mut self.preprocess_per_byte.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        WASM_COSTS_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for WasmCosts {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (legacy_wasm_costs, rem) = WasmCosts::from_legacy_bytes(bytes)?;
+        let (storage_per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (native_transfer, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (hash_per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_initial_memory_pages, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (max_memory_pages, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (preprocess_per_byte, rem): (u32, &[u8]) = FromBytes::from_bytes(rem)?;
+        let wasm_costs = WasmCosts {
+            storage_per_byte,
+            native_transfer,
+            hash_per_byte,
+            max_initial_memory_pages,
+            max_memory_pages,
+            preprocess_per_byte,
+            ..legacy_wasm_costs
+        };
+        Ok((wasm_costs, rem))
+    }
+}
+
+impl CLTyped for WasmCosts {
+    fn cl_type() -> CLType {
+        // Serialized as its fields in order, all of them `u32`s.
+        <[u32; NUM_FIELDS]>::cl_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let wasm_costs = WasmCosts {
+            regular: 1,
+            div: 16,
+            mul: 4,
+            mem: 2,
+            initial_mem: 4096,
+            grow_mem: 8192,
+            memcpy: 1,
+            max_stack_height: 64 * 1024,
+            opcodes_mul: 3,
+            opcodes_div: 8,
+            storage_per_byte: 1,
+            native_transfer: 10_000,
+            hash_per_byte: 1,
+            max_initial_memory_pages: 64,
+            max_memory_pages: 64,
+            preprocess_per_byte: 1,
+        };
+        bytesrepr::test_serialization_roundtrip(&wasm_costs);
+        assert_eq!(
+            wasm_costs.to_bytes().unwrap().len(),
+            WASM_COSTS_SERIALIZED_LENGTH
+        );
+
+        let fields: [u32; NUM_FIELDS] =
+            bytesrepr::deserialize(wasm_costs.to_bytes().unwrap()).unwrap();
+        assert_eq!(fields[0], wasm_costs.regular);
+        assert_eq!(fields[NUM_FIELDS - 1], wasm_costs.preprocess_per_byte);
+    }
+
+    #[test]
+    fn should_deserialize_legacy_layout() {
+        let legacy_fields: [u32; LEGACY_NUM_FIELDS] = [1, 16, 4, 2, 4096, 8192, 1, 65536, 3, 8];
+        let mut bytes = Vec::new();
+        for field in legacy_fields.iter() {
+            bytes.append(&mut field.to_bytes().unwrap());
+        }
+        assert_eq!(bytes.len(), LEGACY_WASM_COSTS_SERIALIZED_LENGTH);
+
+        let (wasm_costs, rem) = WasmCosts::from_legacy_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        let expected_wasm_costs = WasmCosts {
+            regular: 1,
+            div: 16,
+            mul: 4,
+            mem: 2,
+            initial_mem: 4096,
+            grow_mem: 8192,
+            memcpy: 1,
+            max_stack_height: 64 * 1024,
+            opcodes_mul: 3,
+            opcodes_div: 8,
+            ..WasmCosts::default()
+        };
+        assert_eq!(wasm_costs, expected_wasm_costs);
+    }
+}